@@ -8,25 +8,37 @@ use libadwaita as adw;
 use libadwaita::prelude::*;
 use once_cell::sync::Lazy;
 use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::sync::Arc;
 use std::thread;
 use tokio::sync::Semaphore;
 
-use crate::atproto::client::{ClientError, UnreadCounts};
+use crate::atproto::client::{ClientError, UnreadActivity};
+use crate::atproto::interactions::{InteractionEvent, InteractionQueue};
+use crate::atproto::moderation::ModerationEdit;
+use crate::atproto::notifications::{MENTION_REASONS, NotificationPrefEdit, NotificationPrefs};
+use crate::atproto::search_query::{SearchQuery, SearchSort};
 use crate::atproto::{
-    ChatMessage, Conversation, HangarClient, Notification, Post, Profile, SavedFeed, Session,
+    ChatMessage, Conversation, FeedGenerator, HangarClient, ListMember, ListPurpose,
+    ModerationPrefs, MutedWord, Notification, Post, Profile, SavedFeed, ServerDescription, Session,
+    StarterPack, UserList,
+};
+use crate::cache::{
+    CacheDb, FEED_BOOKMARKS, FEED_HOME, FEED_LIKES, FeedCache, FeedGap, GAP_FILL_SIZE, PostSearch,
+    ProfileCache, TopMerge, author_key, search_key, thread_key,
 };
-use crate::cache::{CacheDb, FeedCache, FeedState, PostCache, ProfileCache};
 use crate::config;
 use crate::runtime;
-use crate::state::SessionManager;
 use crate::state::oauth::OAuthManager;
+use crate::state::{Account, AccountList, SessionManager};
 use crate::ui::avatar_cache;
+use crate::ui::onboarding::Onboarding;
 use crate::ui::post_row::PostRow;
 use crate::ui::{
-    ComposeDialog, FollowListKind, FollowListPage, FollowListPush, HangarWindow, LoginDialog,
-    MessagePage, MessagePush, NavItem, QuoteContext, ReplyContext,
+    ComposeDialog, FeedBrowser, FollowListKind, FollowListPage, FollowListPush, HangarWindow,
+    ListTab, ListsPage, LoginDialog, MessagePage, MessagePush, NavItem, QuoteContext, ReplyContext,
+    SignupDialog,
 };
 
 /// Limit concurrent API requests to prevent overwhelming the server during rapid scrolling
@@ -35,6 +47,10 @@ static API_SEMAPHORE: Lazy<Arc<Semaphore>> = Lazy::new(|| Arc::new(Semaphore::ne
 /// Seconds between polls for new messages while a conversation is open.
 const MESSAGE_POLL_SECS: u32 = 5;
 
+/// Pages a gap filled from below may walk before it gives up looking for
+/// the posts under it and shows what it has.
+const GAP_FILL_UP_PAGES: usize = 5;
+
 /// Which turn of events owns some shared state.
 ///
 /// Every reset bumps it. A fetch carries the token it started under and
@@ -61,14 +77,66 @@ impl Generation {
     }
 }
 
+/// Where the feed on screen gets its pages.
+///
+/// Everything that pages a feed (the first load, scrolling down, the poll
+/// for new posts, filling a gap) goes through here, so a saved search is
+/// paged, polled and cached exactly like a feed generator, and so is a
+/// pinned list.
+#[derive(Clone)]
+enum FeedSource {
+    Timeline,
+    Generator(String),
+    List(String),
+    Search(SearchQuery),
+}
+
+impl FeedSource {
+    /// One page, from the top or from `cursor`.
+    async fn page(
+        &self,
+        client: &HangarClient,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
+        match self {
+            FeedSource::Timeline => client.get_timeline(cursor).await,
+            FeedSource::Generator(uri) => client.get_feed(uri, cursor).await,
+            FeedSource::List(uri) => client.get_list_feed(uri, cursor).await,
+            FeedSource::Search(query) => client.search_posts(query, cursor).await,
+        }
+    }
+
+    /// Whether its pages run newest first, so a fresh page meets the
+    /// cache by time. A generator ranks as it likes.
+    fn is_chronological(&self) -> bool {
+        match self {
+            FeedSource::Timeline | FeedSource::List(_) => true,
+            FeedSource::Generator(_) => false,
+            FeedSource::Search(query) => query.sort == SearchSort::Latest,
+        }
+    }
+}
+
+/// Feedback waiting for the custom feed on the home timeline, while that
+/// feed takes interactions.
+pub(crate) struct FeedFeedback {
+    /// The generator's own service, which `sendInteractions` is proxied to
+    service_did: String,
+    queue: InteractionQueue,
+}
+
+/// How often queued sightings go out while nobody asks for more or less.
+const FEED_FEEDBACK_SECS: u32 = 30;
+
 /// Why a stored session could not be resumed on launch.
 ///
 /// The receiving end used to discard the error and put up a bare login dialog
 /// either way, so a permanently refused session looked like a first run.
 enum RestoreFailure {
     /// Dead session: the tokens were refused, or the `client_id` they were
-    /// issued under cannot be rebuilt. Clear the keyring too.
-    Reauth,
+    /// issued under cannot be rebuilt. Clear that account's keyring entry
+    /// too.
+    Reauth(String),
     /// Everything else: nothing stored yet, the keyring unavailable, the
     /// network down mid-restore. The stored session may well still be good.
     Other(String),
@@ -90,6 +158,12 @@ mod imp {
         /// Pending new posts that arrived while user was scrolled away
         /// Whether we're currently checking for new posts
         pub checking_new_posts: RefCell<bool>,
+        /// Whether a gap row's posts are already being fetched
+        pub filling_gap: RefCell<bool>,
+        /// Feeds whose saved reading position this window has put back.
+        /// Later paints of them, a refresh or a new post, leave the
+        /// reader where they are.
+        pub restored_positions: RefCell<HashSet<String>>,
         /// A poll already said it is failing; stay quiet until it recovers.
         pub new_posts_poll_failed: RefCell<bool>,
         pub unread_poll_failed: RefCell<bool>,
@@ -101,6 +175,8 @@ mod imp {
         /// Activity state
         pub activity_cursor: RefCell<Option<String>>,
         pub activity_loading_more: RefCell<bool>,
+        /// Which filter and mode the in-flight activity fetches belong to
+        pub(crate) activity_generation: Generation,
         /// Chat state
         pub chat_cursor: RefCell<Option<String>>,
         pub chat_loading_more: RefCell<bool>,
@@ -117,7 +193,7 @@ mod imp {
         pub bookmarks_loading_more: RefCell<bool>,
         pub(crate) bookmarks_generation: Generation,
         /// Search state
-        pub search_query: RefCell<Option<String>>,
+        pub search_query: RefCell<Option<SearchQuery>>,
         pub search_cursor: RefCell<Option<String>>,
         pub search_loading_more: RefCell<bool>,
         /// People results page on its own cursor
@@ -144,9 +220,37 @@ mod imp {
         /// with nothing to stop an earlier feed landing after a later one.
         pub feed_loading: RefCell<bool>,
         pub(crate) feed_generation: Generation,
+        /// Set while the home timeline shows a feed that takes feedback
+        pub(crate) feed_feedback: RefCell<Option<FeedFeedback>>,
         /// The connectivity handler on gio's process-wide monitor. Held so
         /// shutdown can take it back off; the monitor outlives us.
         pub network_handler: RefCell<Option<glib::SignalHandlerId>>,
+        /// Resumed clients for the signed-in accounts not on screen, by
+        /// DID. They keep the account menu's unread counts current, and a
+        /// switch to one picks its client up without another sign-in.
+        pub accounts: RefCell<HashMap<String, Arc<HangarClient>>>,
+        /// Unread per account not on screen, for the account menu.
+        pub account_unread: RefCell<HashMap<String, u32>>,
+        /// Whether the background accounts' unread fetch is already out
+        pub checking_account_unread: RefCell<bool>,
+        /// Bumped on every switch, so a fetch the previous account started
+        /// cannot land in the next one's window or cache
+        pub(crate) account_generation: Generation,
+        /// What the desktop notifications have already announced
+        pub notify_tracker: RefCell<crate::notify::Tracker>,
+        /// What the desktop notifications are filtered by, kept here as
+        /// well as on the window: the background poll has no window.
+        pub muted_words: RefCell<Vec<MutedWord>>,
+        pub moderation_prefs: RefCell<ModerationPrefs>,
+        /// Launched with `--background`: the first activation signs in and
+        /// polls without building a window
+        pub start_in_background: RefCell<bool>,
+        /// Held while the app runs on with no window, so closing the last
+        /// one does not end it
+        pub background_hold: RefCell<Option<gio::ApplicationHoldGuard>>,
+        /// Bumped when the background poll stops, so its timer ends rather
+        /// than doubling up with the next one
+        pub(crate) background_generation: Generation,
     }
 
     #[glib::object_subclass]
@@ -232,6 +336,8 @@ mod imp {
         }
 
         fn shutdown(&self) {
+            self.obj().save_reading_position();
+
             // The monitor is a singleton that would otherwise keep calling
             // into a torn-down application.
             if let Some(handler) = self.network_handler.take() {
@@ -253,6 +359,18 @@ mod imp {
                 return;
             }
 
+            // Settings are kept per account. Point them at the one about to
+            // restore before the window reads them.
+            crate::state::AppSettings::use_account(AccountList::load().active.as_deref());
+
+            // Started at login: sign in and poll, and build the window only
+            // when someone asks for it.
+            if self.start_in_background.take() {
+                app.enter_background();
+                app.try_restore_session();
+                return;
+            }
+
             // Create main window
             let window = HangarWindow::new(app.upcast_ref::<adw::Application>());
             if config::IS_DEVEL {
@@ -274,6 +392,12 @@ mod imp {
                 }
             });
 
+            let app_clone = app.clone();
+            window.connect_close_request(move |window| {
+                app_clone.window_closing(window);
+                glib::Propagation::Proceed
+            });
+
             let app_clone = app.clone();
             window.set_load_more_callback(move || {
                 app_clone.fetch_timeline_more();
@@ -284,6 +408,11 @@ mod imp {
                 app_clone.fetch_timeline();
             });
 
+            let app_clone = app.clone();
+            window.set_gap_callback(move |boundary_uri, from_below| {
+                app_clone.fill_timeline_gap(boundary_uri, from_below);
+            });
+
             let app_clone = app.clone();
             window.set_like_callback(move |post, post_row_weak| {
                 app_clone.toggle_like(&post, post_row_weak);
@@ -349,6 +478,26 @@ mod imp {
                 app_clone.open_report_for_account(profile);
             });
 
+            let app_clone = app.clone();
+            window.set_muted_word_save_callback(move |word| {
+                app_clone.save_muted_word(word);
+            });
+
+            let app_clone = app.clone();
+            window.set_muted_word_remove_callback(move |word| {
+                app_clone.remove_muted_word(word);
+            });
+
+            let app_clone = app.clone();
+            window.set_moderation_edit_callback(move |edit| {
+                app_clone.edit_moderation(edit);
+            });
+
+            let app_clone = app.clone();
+            window.set_notification_pref_edit_callback(move |edit| {
+                app_clone.edit_notification_pref(edit);
+            });
+
             let app_clone = app.clone();
             window.set_clear_cache_callback(move || app_clone.clear_cache());
 
@@ -387,6 +536,116 @@ mod imp {
                 app_clone.open_profile_by_handle(handle);
             });
 
+            let app_clone = app.clone();
+            window.set_tag_clicked_callback(move |tag| {
+                app_clone.open_hashtag_view(tag);
+            });
+
+            let app_clone = app.clone();
+            window.set_hashtag_tab_callback(move |ctx, first_page| {
+                app_clone.fetch_hashtag_tab(ctx, first_page);
+            });
+
+            let app_clone = app.clone();
+            window.set_pin_tag_callback(move |tag| {
+                app_clone.toggle_pinned_tag(tag);
+            });
+
+            let app_clone = app.clone();
+            window.set_lists_clicked_callback(move |profile| {
+                app_clone.open_lists_for(profile);
+            });
+
+            let app_clone = app.clone();
+            window.set_list_opened_callback(move |list| {
+                app_clone.open_list(list);
+            });
+
+            let app_clone = app.clone();
+            window.set_list_tab_callback(move |ctx, first_page| {
+                app_clone.fetch_list_tab(ctx, first_page);
+            });
+
+            let app_clone = app.clone();
+            window.set_new_list_callback(move || {
+                app_clone.new_list();
+            });
+
+            let app_clone = app.clone();
+            window.set_edit_list_callback(move |ctx| {
+                app_clone.edit_list(ctx);
+            });
+
+            let app_clone = app.clone();
+            window.set_delete_list_callback(move |list| {
+                app_clone.confirm_delete_list(list);
+            });
+
+            let app_clone = app.clone();
+            window.set_pin_list_callback(move |list| {
+                app_clone.toggle_pinned_list(list);
+            });
+
+            let app_clone = app.clone();
+            window.set_add_to_lists_callback(move |profile| {
+                app_clone.open_add_to_lists(profile);
+            });
+
+            let app_clone = app.clone();
+            window.set_list_mute_callback(move |list, ctx| {
+                app_clone.toggle_list_mute(list, ctx);
+            });
+
+            let app_clone = app.clone();
+            window.set_list_block_callback(move |list, ctx| {
+                app_clone.toggle_list_block(list, ctx);
+            });
+
+            let app_clone = app.clone();
+            window.set_make_starter_pack_callback(move |list| {
+                app_clone.new_starter_pack(list);
+            });
+
+            let app_clone = app.clone();
+            window.set_starter_pack_opened_callback(move |pack| {
+                app_clone.open_starter_pack(pack);
+            });
+
+            let app_clone = app.clone();
+            window.set_starter_pack_members_callback(move |ctx, first_page| {
+                app_clone.fetch_starter_pack_members(ctx, first_page);
+            });
+
+            let app_clone = app.clone();
+            window.set_follow_all_callback(move |ctx| {
+                app_clone.follow_starter_pack(ctx);
+            });
+
+            let app_clone = app.clone();
+            window.set_discover_feeds_callback(move || {
+                app_clone.open_feed_browser();
+            });
+
+            let app_clone = app.clone();
+            window.set_feed_opened_callback(move |feed| {
+                app_clone.open_feed(feed);
+            });
+
+            let app_clone = app.clone();
+            window.set_feed_like_callback(move |ctx| {
+                app_clone.toggle_feed_like(ctx);
+            });
+
+            let app_clone = app.clone();
+            window.set_feed_interaction_callback(move |post, event| {
+                app_clone.record_feed_interaction(post, event);
+            });
+
+            let app_clone = app.clone();
+            window.set_saved_feed_list_callback(move |feeds| {
+                app_clone.save_feed_list(feeds);
+            });
+
             let app_clone = app.clone();
             window.set_nav_changed_callback(move |item| {
                 app_clone.handle_nav_change(item);
@@ -402,6 +661,11 @@ mod imp {
                 app_clone.fetch_activity_more();
             });
 
+            let app_clone = app.clone();
+            window.set_activity_filter_callback(move || {
+                app_clone.fetch_activity();
+            });
+
             let app_clone = app.clone();
             window.set_chat_load_more_callback(move || {
                 app_clone.fetch_chat_more();
@@ -437,6 +701,16 @@ mod imp {
                 app_clone.execute_search(query);
             });
 
+            let app_clone = app.clone();
+            window.set_save_search_callback(move |input| {
+                app_clone.toggle_saved_search(input);
+            });
+
+            let app_clone = app.clone();
+            window.set_history_search_callback(move || {
+                app_clone.search_history();
+            });
+
             // Avatar menu callbacks: My Profile, Settings, Sign Out
             let app_clone = app.clone();
             window.set_my_profile_clicked_callback(move || {
@@ -451,6 +725,9 @@ mod imp {
                 if let Some(window) = app_clone.imp().window.borrow().as_ref() {
                     window.show_settings_page();
                 }
+                // Lists can be muted or blocked from other apps, and from
+                // their pages here, so each visit reads them fresh.
+                app_clone.fetch_moderation_lists();
             });
 
             let app_clone = app.clone();
@@ -463,6 +740,21 @@ mod imp {
                 app_clone.sign_out();
             });
 
+            let app_clone = app.clone();
+            window.set_switch_account_callback(move |did| {
+                app_clone.switch_account(did);
+            });
+
+            let app_clone = app.clone();
+            window.set_add_account_callback(move || {
+                app_clone.show_add_account_dialog();
+            });
+
+            let app_clone = app.clone();
+            window.set_remove_account_callback(move |did| {
+                app_clone.remove_account(did);
+            });
+
             // Apply saved settings on startup
             let saved_settings = crate::state::AppSettings::load();
             window.apply_font_size(saved_settings.font_size);
@@ -472,6 +764,11 @@ mod imp {
             }
 
             window.present();
+            // The window keeps the app alive from here.
+            app.leave_background();
+
+            // Drawn from disk, so the menu is whole before any restore.
+            app.refresh_account_menu();
 
             app.try_restore_session();
         }
@@ -564,7 +861,36 @@ impl HangarApplication {
             .activate(|app: &Self, _, _| app.show_about())
             .build();
 
-        self.add_action_entries([refresh, compose, search, back, nav, shortcuts, about]);
+        // With Run in Background on, closing the window no longer quits.
+        let quit = gio::ActionEntry::builder("quit")
+            .activate(|app: &Self, _, _| app.quit())
+            .build();
+
+        // What a desktop notification opens, its target encoded by
+        // `notify::Target`.
+        let open_notification = gio::ActionEntry::builder("open-notification")
+            .parameter_type(Some(&String::static_variant_type()))
+            .activate(|app: &Self, _, parameter| {
+                let target = parameter
+                    .and_then(|v| v.get::<String>())
+                    .and_then(|s| crate::notify::Target::decode(&s));
+                if let Some(target) = target {
+                    app.open_notification_target(target);
+                }
+            })
+            .build();
+
+        self.add_action_entries([
+            refresh,
+            compose,
+            search,
+            back,
+            nav,
+            shortcuts,
+            about,
+            quit,
+            open_notification,
+        ]);
 
         self.set_accels_for_action("app.refresh", &["F5", "<primary>r"]);
         self.set_accels_for_action("app.compose", &["<primary>n"]);
@@ -577,6 +903,7 @@ impl HangarApplication {
             );
         }
         self.set_accels_for_action("app.shortcuts", &["<primary>question"]);
+        self.set_accels_for_action("app.quit", &["<primary>q"]);
     }
 
     /// The row handlers that live in statics rather than on a window.
@@ -602,11 +929,23 @@ impl HangarApplication {
             app.toggle_follow_row(profile, row_weak);
         });
 
+        // Add to Lists from people rows, the same flow as the profile menu.
+        let app = self.clone();
+        crate::ui::actor_row::set_add_to_lists_handler(move |profile| {
+            app.open_add_to_lists(profile);
+        });
+
         let app = self.clone();
         crate::ui::post_row::set_report_post_handler(move |post| {
             app.open_report_for_post(post);
         });
 
+        // Show More/Less Like This, for the feed on the home timeline.
+        let app = self.clone();
+        crate::ui::post_row::set_feed_feedback_handler(move |post, event| {
+            app.record_feed_interaction(post, event);
+        });
+
         // Feed-level moderation starts from a clean cell: mute always
         // mutes, block always confirms then blocks. The profile page
         // owns the stateful undo side.
@@ -657,19 +996,37 @@ impl HangarApplication {
     fn try_restore_session(&self) {
         let (tx, rx) = std::sync::mpsc::channel::<Result<Session, RestoreFailure>>();
         let client = self.client();
+        let active = AccountList::load().active;
 
         thread::spawn(move || {
             // Use the shared runtime for network operations so the HTTP client
             // context is consistent across all API calls
             let result = runtime::block_on(async {
-                let session = SessionManager::load()
-                    .await
-                    .map_err(|e| RestoreFailure::Other(e.to_string()))?;
+                // A switch or an Add Account hands over a client that is
+                // already signed in. Its keyring entry may still be on its
+                // way to the keyring, so do not go looking for it there.
+                if let Some(session) = client.session().await {
+                    if active.as_deref().is_none_or(|did| did == session.did) {
+                        return Ok(session);
+                    }
+                }
+                // No active account recorded yet: an install from before
+                // there could be several. Its one session is the first.
+                let stored = match &active {
+                    Some(did) => SessionManager::load_for(did).await.ok(),
+                    None => None,
+                };
+                let session = match stored {
+                    Some(session) => session,
+                    None => SessionManager::load()
+                        .await
+                        .map_err(|e| RestoreFailure::Other(e.to_string()))?,
+                };
                 match client.resume_session(&session).await {
                     Ok(()) => Ok(session),
                     // Permanent: drop the stored session and ask for a new
                     // sign-in.
-                    Err(ClientError::ReauthRequired) => Err(RestoreFailure::Reauth),
+                    Err(ClientError::ReauthRequired) => Err(RestoreFailure::Reauth(session.did)),
                     Err(e) => Err(RestoreFailure::Other(e.to_string())),
                 }
             });
@@ -701,24 +1058,42 @@ impl HangarApplication {
                         }
                     }
 
+                    app.remember_account(&session);
+
+                    // Also with no window: it sets the account the
+                    // background poll reads and makes the first poll.
+                    // The muted words and label preferences too: they
+                    // filter the desktop notifications.
+                    app.fetch_user_profile(&session.did);
+                    app.fetch_muted_words();
+                    app.fetch_moderation_prefs();
                     if app.imp().window.borrow().as_ref().is_some() {
-                        app.fetch_user_profile(&session.did);
                         app.fetch_saved_feeds();
+                        app.fetch_notification_prefs();
                         app.fetch_timeline();
                     }
                     // After the restore resolved, so the account in use is
-                    // known and cannot be swept.
-                    Self::sweep_stale_credentials(Some(session.did.clone()));
+                    // on the list and cannot be swept.
+                    Self::sweep_stale_credentials();
+                    app.resume_other_accounts(&session.did);
                     glib::ControlFlow::Break
                 }
                 Ok(Err(failure)) => {
                     let notice = match &failure {
-                        RestoreFailure::Reauth => {
+                        RestoreFailure::Reauth(did) => {
                             // The OAuth row on disk is already gone, but the
                             // keyring copy makes the next launch land here
-                            // again. Clear it, or this repeats forever.
+                            // again. Clear it, or this repeats forever. The
+                            // other accounts stay signed in.
+                            let did = did.clone();
+                            let mut accounts = AccountList::load();
+                            accounts.remove(&did);
+                            if let Err(e) = accounts.save() {
+                                eprintln!("hangar: could not save the account list: {e}");
+                            }
+                            app.refresh_account_menu();
                             thread::spawn(move || {
-                                if let Err(e) = runtime::block_on(SessionManager::clear()) {
+                                if let Err(e) = runtime::block_on(SessionManager::clear_for(&did)) {
                                     eprintln!("hangar: could not clear the expired session: {e}");
                                 }
                             });
@@ -737,15 +1112,18 @@ impl HangarApplication {
                     if let Some(window) = app.imp().window.borrow().as_ref() {
                         app.show_login_dialog_with_notice(window, notice);
                     }
-                    // Nothing restored, so nothing stored is in use.
-                    Self::sweep_stale_credentials(None);
+                    // Started at login with nobody to sign in: nothing to
+                    // poll for, so let the app end.
+                    app.leave_background();
+                    Self::sweep_stale_credentials();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
                     if let Some(window) = app.imp().window.borrow().as_ref() {
                         app.show_login_dialog(window);
                     }
-                    Self::sweep_stale_credentials(None);
+                    app.leave_background();
+                    Self::sweep_stale_credentials();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
@@ -753,7 +1131,8 @@ impl HangarApplication {
         });
     }
 
-    /// Drop any stored credential that is not the account in use.
+    /// Drop any stored credential that is not one of the signed-in
+    /// accounts.
     ///
     /// Sign out only started revoking and deleting in 0.8.2, so anyone who
     /// signed out before that still has a live refresh token and its
@@ -762,9 +1141,14 @@ impl HangarApplication {
     /// Sweeping at startup means the fix reaches those installs without
     /// asking anyone to go delete a file by hand.
     ///
-    /// `keep` is the account that just restored, if any. With nobody
-    /// signed in, every row is a leftover.
-    fn sweep_stale_credentials(keep: Option<String>) {
+    /// The account list is what is kept. An account that restored is on it
+    /// by the time this runs; with nobody on it, every row is a leftover.
+    fn sweep_stale_credentials() {
+        let keep: Vec<String> = AccountList::load()
+            .accounts
+            .into_iter()
+            .map(|account| account.did)
+            .collect();
         let store = crate::state::session_store::FileSessionStore::new();
         let stored = match store.stored_dids() {
             Ok(dids) => dids,
@@ -776,7 +1160,7 @@ impl HangarApplication {
 
         let stale: Vec<_> = stored
             .into_iter()
-            .filter(|did| keep.as_deref() != Some(did.as_str()))
+            .filter(|did| !keep.iter().any(|kept| kept == did.as_str()))
             .collect();
         if stale.is_empty() {
             return;
@@ -868,7 +1252,8 @@ impl HangarApplication {
         }
     }
 
-    /// Sign out: clear session, close window, and restart fresh
+    /// Sign out of the account on screen, and hand the window to the next
+    /// signed-in account, or to the login dialog when none is left.
     fn sign_out(&self) {
         // Drop the live agent first; closing the window does not. The client
         // and the 30-second poll both hang off the application, so the old
@@ -876,88 +1261,532 @@ impl HangarApplication {
         // over the session file the new sign-in is writing into.
         self.client().sign_out();
 
-        // Read before the per-account state below clears it; the revoke
-        // and the file row are both keyed by DID.
-        let did_for_revoke = self.imp().user_did.borrow().clone();
+        // Read before the per-account state clears it; the revoke and the
+        // file row are both keyed by DID. A restore that failed leaves no
+        // DID on screen, but the list still says whose it was.
+        let mut accounts = AccountList::load();
+        let did_for_revoke = self
+            .imp()
+            .user_did
+            .borrow()
+            .clone()
+            .or_else(|| accounts.active.clone());
 
-        // Per-account state that outlives the window. The poll checks
-        // `newest_post_uri` before doing anything, so clearing it quiets it.
-        let imp = self.imp();
-        imp.newest_post_uri.replace(None);
-        imp.timeline_cursor.replace(None);
-        imp.current_feed.replace(None);
-        imp.user_did.replace(None);
-        imp.cache.replace(None);
-        // The next account must not inherit this one's Delete offers.
-        crate::ui::post_row::set_current_user_did(None);
-        crate::ui::actor_row::set_viewer_did(None);
+        if let Some(did) = &did_for_revoke {
+            accounts.remove(did);
+        }
+        if let Err(e) = accounts.save() {
+            eprintln!("hangar: could not save the account list: {e}");
+        }
 
         // Clear the stored session: the keyring row, the server-side
         // grant, and the OAuth credential file. The keyring alone is not
         // enough. For an OAuth login it carries only the DID and handle
         // while the DPoP key and refresh token live in oauth-sessions.json,
         // so stopping there left a working credential on disk.
-        let signed_out_did = did_for_revoke;
+        if let Some(did) = did_for_revoke {
+            thread::spawn(move || {
+                if let Err(e) = runtime::block_on(SessionManager::clear_for(&did)) {
+                    eprintln!("Failed to clear the keyring entry: {e}");
+                }
+                Self::revoke_and_forget(&did);
+            });
+        }
+
+        // The next account on the list takes over, already signed in if its
+        // client resumed behind the scenes.
+        let incoming = accounts.active.as_ref().and_then(|next| {
+            self.imp().account_unread.borrow_mut().remove(next);
+            self.imp().accounts.borrow_mut().remove(next)
+        });
+        self.imp().client.replace(Some(
+            incoming.unwrap_or_else(|| Arc::new(HangarClient::new())),
+        ));
+
+        self.restart_window();
+    }
+
+    /// Sign out of one account from its row in the account menu.
+    ///
+    /// The account on screen goes through `sign_out`. Any other has no
+    /// window to tear down: drop its background client and its
+    /// credentials, and take its row off the menu.
+    fn remove_account(&self, did: String) {
+        let on_screen = match self.imp().user_did.borrow().as_deref() {
+            Some(current) => current == did,
+            None => AccountList::load().active.as_deref() == Some(did.as_str()),
+        };
+        if on_screen {
+            self.sign_out();
+            return;
+        }
+
+        let mut accounts = AccountList::load();
+        accounts.remove(&did);
+        if let Err(e) = accounts.save() {
+            eprintln!("hangar: could not save the account list: {e}");
+        }
+
+        if let Some(client) = self.imp().accounts.borrow_mut().remove(&did) {
+            client.sign_out();
+        }
+        self.imp().account_unread.borrow_mut().remove(&did);
+        if let Some(sidebar) = self.sidebar() {
+            sidebar.set_account_unread(&did, 0);
+        }
+        self.refresh_account_menu();
+
         thread::spawn(move || {
-            if let Err(e) = runtime::block_on(SessionManager::clear()) {
+            if let Err(e) = runtime::block_on(SessionManager::clear_for(&did)) {
                 eprintln!("Failed to clear the keyring entry: {e}");
             }
-            if let Some(did) = signed_out_did {
-                Self::revoke_and_forget(&did);
-            }
+            Self::revoke_and_forget(&did);
         });
+    }
 
-        // Closing the current window drops all UI state. close() runs the
-        // handler that saves the geometry; destroy() is what takes the
-        // window off the application, which the guard in activate() reads,
-        // and close() alone does nothing to a window that never realized.
-        if let Some(window) = self.imp().window.take() {
-            window.close();
-            window.destroy();
+    /// Put another signed-in account on screen.
+    fn switch_account(&self, did: String) {
+        if self.imp().user_did.borrow().as_deref() == Some(did.as_str()) {
+            return;
         }
+        let incoming = self.imp().accounts.borrow_mut().remove(&did);
+        self.hand_over(did, incoming);
+    }
 
-        // Re-activate the app, which creates a fresh window and
-        // runs try_restore_session (which will fail → login dialog)
-        gio::prelude::ApplicationExt::activate(self.upcast_ref::<gio::Application>());
+    /// Finish an Add Account sign-in by switching to it.
+    fn adopt_account(&self, client: Arc<HangarClient>, session: Session) {
+        // Signing in again to an account already here replaces its client.
+        if let Some(previous) = self.imp().accounts.borrow_mut().remove(&session.did) {
+            previous.sign_out();
+        }
+        let mut accounts = AccountList::load();
+        accounts.upsert(Account {
+            did: session.did.clone(),
+            handle: session.handle.clone(),
+            ..Default::default()
+        });
+        if let Err(e) = accounts.save() {
+            eprintln!("hangar: could not save the account list: {e}");
+        }
+        self.hand_over(session.did, Some(client));
     }
 
-    /// Say so once when a request died of an expired session.
+    /// Make `did` the active account and rebuild the window around it.
     ///
-    /// The client latches a refusal it could not refresh past: nothing
-    /// downstream of `e.to_string()` can tell that apart from the server being
-    /// briefly unreachable. Every failing request re-arms that latch, so the
-    /// `session_dead` flag is what holds this to one toast per dead session.
-    /// The same flag parks the polls; `fetch_user_profile` lifts it on the
-    /// next successful sign-in.
-    fn report_session_expiry(&self) {
-        if !self.client().take_session_expired() {
-            return;
+    /// The account being left stays signed in behind the scenes: its client
+    /// moves into `accounts`, where the unread poll keeps its menu row
+    /// current, and a switch back picks it up as it is. `incoming` is the
+    /// new account's client when one is already signed in; without one,
+    /// the restore resumes it from the keyring.
+    fn hand_over(&self, did: String, incoming: Option<Arc<HangarClient>>) {
+        let imp = self.imp();
+        let outgoing = self.client();
+        let outgoing_did = imp.user_did.borrow().clone();
+
+        match outgoing_did {
+            Some(previous) if previous != did => {
+                // Its unread moves from the nav badges onto its menu row.
+                let unread = [NavItem::Mentions, NavItem::Activity, NavItem::Chat]
+                    .into_iter()
+                    .map(|item| self.badge_count(item))
+                    .sum();
+                imp.account_unread
+                    .borrow_mut()
+                    .insert(previous.clone(), unread);
+                Self::persist_session(outgoing.clone());
+                imp.accounts.borrow_mut().insert(previous, outgoing);
+            }
+            // Nobody on screen, or the same account signing in afresh.
+            _ => outgoing.sign_out(),
         }
-        if *self.imp().session_dead.borrow() {
+
+        imp.account_unread.borrow_mut().remove(&did);
+        imp.client.replace(Some(
+            incoming.unwrap_or_else(|| Arc::new(HangarClient::new())),
+        ));
+
+        let mut accounts = AccountList::load();
+        accounts.active = Some(did);
+        if let Err(e) = accounts.save() {
+            eprintln!("hangar: could not save the account list: {e}");
+        }
+
+        self.restart_window();
+    }
+
+    /// Write an app-password client's current tokens back to the keyring.
+    ///
+    /// Its agent refreshes in memory only, and the server retires a refresh
+    /// token once it has been used, so the copy stored at sign-in stops
+    /// working after the first refresh. An OAuth client keeps its own file
+    /// current.
+    fn persist_session(client: Arc<HangarClient>) {
+        thread::spawn(move || {
+            runtime::block_on(async move {
+                let Some(session) = client.session().await else {
+                    return;
+                };
+                if session.is_oauth() {
+                    return;
+                }
+                if let Err(e) = SessionManager::store(&session).await {
+                    eprintln!("hangar: could not save the switched-away session: {e}");
+                }
+            });
+        });
+    }
+
+    /// The window is closing. With Run in Background on and someone signed
+    /// in, the app stays up for its notifications; otherwise this is the
+    /// last window and the app ends with it.
+    fn window_closing(&self, window: &HangarWindow) {
+        // `restart_window` takes its window before closing it; that close
+        // is a rebuild, not the user leaving.
+        let ours = self.imp().window.borrow().as_ref() == Some(window);
+        if !ours {
             return;
         }
-        self.imp().session_dead.replace(true);
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.show_toast("Your session expired. Sign out and sign in again");
+        self.save_reading_position();
+        let signed_in = self.imp().user_did.borrow().is_some();
+        if !signed_in || !crate::state::AppSettings::shared_run_in_background() {
+            return;
         }
+        // The window and everything it holds goes; the session stays.
+        self.imp().window.replace(None);
+        self.imp().restored_positions.borrow_mut().clear();
+        self.enter_background();
+
+        let notification = gio::Notification::new("Hangar is running in the background");
+        notification.set_body(Some(
+            "Notifications and messages keep arriving while the window is closed.",
+        ));
+        notification.add_button("Quit", "app.quit");
+        self.send_notification(Some("background"), &notification);
     }
 
-    fn show_login_dialog(&self, window: &HangarWindow) {
-        self.show_login_dialog_with_notice(window, None);
+    /// Hold the application and poll for notifications at the slower
+    /// background pace until a window comes back.
+    fn enter_background(&self) {
+        let imp = self.imp();
+        if imp.background_hold.borrow().is_some() {
+            return;
+        }
+        imp.background_hold.replace(Some(self.hold()));
+
+        let token = imp.background_generation.token();
+        let app = self.clone();
+        glib::timeout_add_seconds_local(crate::background::POLL_SECS, move || {
+            if !app.imp().background_generation.is_current(token) {
+                return glib::ControlFlow::Break;
+            }
+            app.check_unread_counts();
+            glib::ControlFlow::Continue
+        });
     }
 
-    /// Show the login dialog, with a reason when there is one.
-    fn show_login_dialog_with_notice(&self, window: &HangarWindow, notice: Option<&str>) {
-        let dialog = LoginDialog::new();
+    /// Release the hold and stop the background poll. A window keeps the app
+    /// alive from here, or nothing does and it ends.
+    fn leave_background(&self) {
+        if self.imp().background_hold.take().is_some() {
+            self.imp().background_generation.bump();
+            self.withdraw_notification("background");
+        }
+    }
 
-        let app = self.clone();
-        let dialog_weak = dialog.downgrade();
+    /// Called by `main` before `run`, for a launch with `--background`.
+    pub fn set_start_in_background(&self, background: bool) {
+        self.imp().start_in_background.replace(background);
+    }
 
-        dialog.connect_login(move |dlg| {
-            let handle = dlg.handle();
-            let password = dlg.password();
+    /// Drop the window and what the account on screen left on the
+    /// application, then build a fresh window for the active account.
+    fn restart_window(&self) {
+        // Taken while this account's cache and window are still here.
+        self.save_reading_position();
 
-            if handle.is_empty() || password.is_empty() {
+        // Per-account state that outlives the window. The poll checks
+        // `newest_post_uri` before doing anything, so clearing it quiets it.
+        let imp = self.imp();
+        imp.restored_positions.borrow_mut().clear();
+        imp.newest_post_uri.replace(None);
+        imp.timeline_cursor.replace(None);
+        imp.current_feed.replace(None);
+        // Unsent sightings belong to the last account's feed; let them go.
+        imp.feed_feedback.replace(None);
+        imp.user_did.replace(None);
+        imp.cache.replace(None);
+        // The next account primes its own; this one's would read as new.
+        imp.notify_tracker.borrow_mut().reset();
+        imp.muted_words.replace(Vec::new());
+        imp.moderation_prefs.replace(ModerationPrefs::default());
+        // Anything the last account still has in flight lands nowhere.
+        imp.account_generation.bump();
+        // The next account must not inherit this one's Delete offers.
+        crate::ui::post_row::set_current_user_did(None);
+        crate::ui::actor_row::set_viewer_did(None);
+
+        // Closing the current window drops all UI state. close() runs the
+        // handler that saves the geometry; destroy() is what takes the
+        // window off the application, which the guard in activate() reads,
+        // and close() alone does nothing to a window that never realized.
+        if let Some(window) = self.imp().window.take() {
+            window.close();
+            window.destroy();
+        }
+
+        // Re-activate the app, which creates a fresh window and runs
+        // try_restore_session for whichever account is active now, or
+        // shows the login dialog when there is none.
+        gio::prelude::ApplicationExt::activate(self.upcast_ref::<gio::Application>());
+    }
+
+    /// Record `session` as a signed-in account and the one on screen.
+    fn remember_account(&self, session: &Session) {
+        let mut accounts = AccountList::load();
+        accounts.upsert(Account {
+            did: session.did.clone(),
+            handle: session.handle.clone(),
+            ..Default::default()
+        });
+        accounts.active = Some(session.did.clone());
+        if let Err(e) = accounts.save() {
+            eprintln!("hangar: could not save the account list: {e}");
+        }
+        crate::state::AppSettings::use_account(Some(&session.did));
+        self.refresh_account_menu();
+    }
+
+    /// Keep the menu row's name and picture in step with the profile.
+    fn note_account_profile(&self, profile: &Profile) {
+        let mut accounts = AccountList::load();
+        // A profile fetch finishing after a sign-out must not add it back.
+        if !accounts.contains(&profile.did) {
+            return;
+        }
+        let before = accounts.clone();
+        accounts.upsert(Account {
+            did: profile.did.clone(),
+            handle: profile.handle.clone(),
+            display_name: profile.display_name.clone(),
+            avatar: profile.avatar.clone(),
+        });
+        if accounts == before {
+            return;
+        }
+        if let Err(e) = accounts.save() {
+            eprintln!("hangar: could not save the account list: {e}");
+        }
+        self.refresh_account_menu();
+    }
+
+    /// Redraw the account rows from the list on disk.
+    fn refresh_account_menu(&self) {
+        let Some(sidebar) = self.sidebar() else {
+            return;
+        };
+        let accounts = AccountList::load();
+        let active = self
+            .imp()
+            .user_did
+            .borrow()
+            .clone()
+            .or(accounts.active.clone());
+        sidebar.set_accounts(&accounts.accounts, active.as_deref());
+        for (did, count) in self.imp().account_unread.borrow().iter() {
+            sidebar.set_account_unread(did, *count);
+        }
+    }
+
+    /// Sign the other accounts in behind the scenes, so their unread reaches
+    /// the account menu and a switch to one is immediate.
+    fn resume_other_accounts(&self, active: &str) {
+        let pending: Vec<String> = AccountList::load()
+            .accounts
+            .into_iter()
+            .map(|account| account.did)
+            .filter(|did| did != active && !self.imp().accounts.borrow().contains_key(did))
+            .collect();
+        if pending.is_empty() {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel::<(String, Arc<HangarClient>)>();
+        thread::spawn(move || {
+            for did in pending {
+                let client = Arc::new(HangarClient::new());
+                let resumed = runtime::block_on(async {
+                    let session = SessionManager::load_for(&did)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    client
+                        .resume_session(&session)
+                        .await
+                        .map_err(|e| e.to_string())
+                });
+                match resumed {
+                    Ok(()) => {
+                        let _ = tx.send((did, client));
+                    }
+                    // Not fatal: a switch to it resumes again, and says why
+                    // if that fails too.
+                    Err(e) => eprintln!("hangar: a signed-in account did not resume: {e}"),
+                }
+            }
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            loop {
+                match rx.try_recv() {
+                    Ok((did, client)) => {
+                        let imp = app.imp();
+                        // Switched to, or signed out of, while it resumed.
+                        let wanted = imp.user_did.borrow().as_deref() != Some(did.as_str())
+                            && !imp.accounts.borrow().contains_key(&did)
+                            && AccountList::load().contains(&did);
+                        if wanted {
+                            imp.accounts.borrow_mut().insert(did, client);
+                        } else {
+                            client.sign_out();
+                        }
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => {
+                        return glib::ControlFlow::Continue;
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        app.check_other_accounts_unread();
+                        return glib::ControlFlow::Break;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Refresh the unread counts on the other accounts' menu rows.
+    ///
+    /// Rides the 30-second timer with `check_unread_counts`, one round trip
+    /// per account. Failures stay quiet: the row keeps its last count, and
+    /// the account's own poll says what is wrong once it is on screen.
+    fn check_other_accounts_unread(&self) {
+        if *self.imp().checking_account_unread.borrow() || *self.imp().offline.borrow() {
+            return;
+        }
+        let clients: Vec<(String, Arc<HangarClient>)> = self
+            .imp()
+            .accounts
+            .borrow()
+            .iter()
+            .map(|(did, client)| (did.clone(), client.clone()))
+            .collect();
+        if clients.is_empty() {
+            return;
+        }
+        self.imp().checking_account_unread.replace(true);
+
+        let (tx, rx) = std::sync::mpsc::channel::<(String, u32)>();
+        thread::spawn(move || {
+            for (did, client) in clients {
+                match runtime::block_on(async { client.get_unread_counts().await }) {
+                    Ok(counts) => {
+                        let total = counts
+                            .mentions
+                            .saturating_add(counts.activity)
+                            .saturating_add(counts.chat);
+                        let _ = tx.send((did, total));
+                    }
+                    Err(e) => eprintln!("Failed to check another account's unread: {e}"),
+                }
+            }
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            loop {
+                match rx.try_recv() {
+                    Ok((did, total)) => {
+                        // Switched to while the fetch was out: its unread is
+                        // the nav badges' now.
+                        if !app.imp().accounts.borrow().contains_key(&did) {
+                            continue;
+                        }
+                        app.imp()
+                            .account_unread
+                            .borrow_mut()
+                            .insert(did.clone(), total);
+                        if let Some(sidebar) = app.sidebar() {
+                            sidebar.set_account_unread(&did, total);
+                        }
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => {
+                        return glib::ControlFlow::Continue;
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        app.imp().checking_account_unread.replace(false);
+                        return glib::ControlFlow::Break;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Say so once when a request died of an expired session.
+    ///
+    /// The client latches a refusal it could not refresh past: nothing
+    /// downstream of `e.to_string()` can tell that apart from the server being
+    /// briefly unreachable. Every failing request re-arms that latch, so the
+    /// `session_dead` flag is what holds this to one toast per dead session.
+    /// The same flag parks the polls; `fetch_user_profile` lifts it on the
+    /// next successful sign-in.
+    fn report_session_expiry(&self) {
+        if !self.client().take_session_expired() {
+            return;
+        }
+        if *self.imp().session_dead.borrow() {
+            return;
+        }
+        self.imp().session_dead.replace(true);
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.show_toast("Your session expired. Sign out and sign in again");
+        }
+    }
+
+    fn show_login_dialog(&self, window: &HangarWindow) {
+        self.show_login_dialog_with_notice(window, None);
+    }
+
+    /// Show the login dialog, with a reason when there is one.
+    fn show_login_dialog_with_notice(&self, window: &HangarWindow, notice: Option<&str>) {
+        self.present_login_dialog(window, notice, false);
+    }
+
+    /// Sign in to one more account. Cancelling leaves the one on screen as
+    /// it was.
+    fn show_add_account_dialog(&self) {
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+        self.present_login_dialog(&window, None, true);
+    }
+
+    /// The login dialog. `adding` signs in on a client of its own and
+    /// switches to it on success, rather than signing in the client the
+    /// window is using.
+    fn present_login_dialog(&self, window: &HangarWindow, notice: Option<&str>, adding: bool) {
+        let dialog = LoginDialog::new();
+        if adding {
+            dialog.set_title("Add an Account");
+        }
+
+        let app = self.clone();
+        let dialog_weak = dialog.downgrade();
+
+        dialog.connect_login(move |dlg| {
+            let handle = dlg.handle();
+            let password = dlg.password();
+            let server = dlg.server();
+            let code = dlg.auth_factor_token();
+
+            if handle.is_empty() || password.is_empty() {
                 return;
             }
 
@@ -965,17 +1794,21 @@ impl HangarApplication {
             dlg.hide_error();
 
             // Get a channel for sending results back
-            let (tx, rx) = std::sync::mpsc::channel::<Result<Session, String>>();
+            let (tx, rx) = std::sync::mpsc::channel::<Result<Session, ClientError>>();
 
-            let client = app.client();
+            let client = if adding {
+                Arc::new(HangarClient::new())
+            } else {
+                app.client()
+            };
+            let signed_in = client.clone();
             thread::spawn(move || {
                 // Use the shared runtime for network operations so the HTTP client
                 // context is consistent across all API calls
                 let result = runtime::block_on(async {
                     let session = client
-                        .login(&handle, &password)
-                        .await
-                        .map_err(|e| e.to_string())?;
+                        .login(&handle, &password, server.as_deref(), code.as_deref())
+                        .await?;
                     // Store session in a separate task to not block login
                     // SecretService can be slow, so we fire and forget
                     let session_for_store = session.clone();
@@ -991,7 +1824,7 @@ impl HangarApplication {
                             );
                         }
                     });
-                    Ok(session)
+                    Ok::<_, ClientError>(session)
                 });
                 let _ = tx.send(result);
             });
@@ -1008,34 +1841,35 @@ impl HangarApplication {
                             dialog.close();
                         }
 
-                        // Initialize cache for this user
-                        match CacheDb::open(&session.did) {
-                            Ok(cache) => {
-                                // Clean up stale entries in background to not block UI
-                                let cache_for_cleanup = cache.clone();
-                                std::thread::spawn(move || {
-                                    if let Err(e) = cache_for_cleanup.cleanup_stale() {
-                                        eprintln!("Cache cleanup failed: {}", e);
-                                    }
-                                });
-                                // Initialize image cache with database reference
-                                avatar_cache::init(Arc::new(cache.clone()));
-                                avatar_cache::cleanup_cache();
-                                app.imp().cache.replace(Some(cache));
-                            }
-                            Err(e) => {
-                                eprintln!("Failed to open cache: {}", e);
-                            }
+                        if adding {
+                            app.adopt_account(signed_in.clone(), session);
+                        } else {
+                            app.begin_session(&session);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(ClientError::AuthFactorTokenRequired)) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.ask_for_code();
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(ClientError::AuthFactorTokenExpired)) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.clear_code();
+                            dialog.show_error(
+                                "That code has expired. Sign in again to have a new one sent.",
+                            );
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(ClientError::Auth(message))) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.show_error(&message);
                         }
-
-                        // Fetch user profile for sidebar avatar
-                        app.fetch_user_profile(&session.did);
-
-                        // Fetch saved feeds for feed selector
-                        app.fetch_saved_feeds();
-
-                        // Fetch timeline
-                        app.fetch_timeline();
                         glib::ControlFlow::Break
                     }
                     Ok(Err(e)) => {
@@ -1074,7 +1908,12 @@ impl HangarApplication {
             dlg.hide_error();
 
             let (tx, rx) = std::sync::mpsc::channel::<Result<Session, String>>();
-            let hangar_client = app2.client();
+            let hangar_client = if adding {
+                Arc::new(HangarClient::new())
+            } else {
+                app2.client()
+            };
+            let signed_in = hangar_client.clone();
 
             thread::spawn(move || {
                 let result = runtime::block_on(async {
@@ -1133,27 +1972,11 @@ impl HangarApplication {
                             dialog.close();
                         }
 
-                        // Initialize cache for this user
-                        match CacheDb::open(&session.did) {
-                            Ok(cache) => {
-                                let cache_for_cleanup = cache.clone();
-                                std::thread::spawn(move || {
-                                    if let Err(e) = cache_for_cleanup.cleanup_stale() {
-                                        eprintln!("Cache cleanup failed: {}", e);
-                                    }
-                                });
-                                avatar_cache::init(Arc::new(cache.clone()));
-                                avatar_cache::cleanup_cache();
-                                app.imp().cache.replace(Some(cache));
-                            }
-                            Err(e) => {
-                                eprintln!("Failed to open cache: {}", e);
-                            }
+                        if adding {
+                            app.adopt_account(signed_in.clone(), session);
+                        } else {
+                            app.begin_session(&session);
                         }
-
-                        app.fetch_user_profile(&session.did);
-                        app.fetch_saved_feeds();
-                        app.fetch_timeline();
                         glib::ControlFlow::Break
                     }
                     Ok(Err(e)) => {
@@ -1180,6 +2003,11 @@ impl HangarApplication {
             dlg.set_oauth_waiting(false);
         });
 
+        let app3 = self.clone();
+        dialog.connect_create_account(move |dlg| {
+            app3.present_signup_dialog(dlg, adding);
+        });
+
         if let Some(notice) = notice {
             dialog.show_error(notice);
         }
@@ -1187,181 +2015,881 @@ impl HangarApplication {
         dialog.present(Some(window));
     }
 
-    fn fetch_user_profile(&self, did: &str) {
-        // Store the user DID for later use
-        self.imp().user_did.replace(Some(did.to_string()));
-        // Rows check post authorship against this before offering Delete
-        crate::ui::post_row::set_current_user_did(Some(did));
-        // People rows hide the follow button on your own row
-        crate::ui::actor_row::set_viewer_did(Some(did));
+    /// Create an account from the login dialog. The new account signs in
+    /// as it is made, the way `login` would have, and its email step and
+    /// onboarding follow.
+    fn present_signup_dialog(&self, login: &LoginDialog, adding: bool) {
+        let signup = SignupDialog::new(login.server().as_deref());
+        let client = if adding {
+            Arc::new(HangarClient::new())
+        } else {
+            self.client()
+        };
+        // The account made, once it is: onboarding starts when the dialog
+        // goes away, whether after the email step or by skipping it.
+        let created: Rc<RefCell<Option<Profile>>> = Rc::new(RefCell::new(None));
+
+        fn failure(e: &ClientError) -> String {
+            match e {
+                ClientError::Auth(message) => message.clone(),
+                e => format!("Couldn't reach the server: {}", e),
+            }
+        }
 
-        // Every path here follows a successful sign-in, so the polls a dead
-        // session parked may run again.
-        self.imp().session_dead.replace(false);
+        signup.connect_server_chosen(move |dlg| {
+            let Some(service_url) = HangarClient::normalize_server(&dlg.server()) else {
+                dlg.show_error("That isn't a server address");
+                return;
+            };
+            dlg.set_loading(true);
+            dlg.hide_error();
 
-        // Badges should not wait out the first 30-second tick.
-        self.check_unread_counts();
+            let (tx, rx) = std::sync::mpsc::channel::<Result<ServerDescription, ClientError>>();
+            let url = service_url.clone();
+            thread::spawn(move || {
+                let result = runtime::block_on(HangarClient::describe_server(&url));
+                let _ = tx.send(result);
+            });
 
-        // Try cache first for instant display
-        let mut skip_fetch = false;
-        if let Some(cache) = self.imp().cache.borrow().as_ref() {
-            let profile_cache = ProfileCache::new(cache);
-            if let Ok(cached_profile) = profile_cache.get(did) {
-                if let Some(window) = self.imp().window.borrow().as_ref() {
-                    let display_name = cached_profile
-                        .display_name
-                        .as_deref()
-                        .unwrap_or(&cached_profile.handle);
-                    window.set_user_avatar(display_name, cached_profile.avatar.as_deref());
-                    window.set_current_user_did(did);
-                    window.update_profile_header(&cached_profile);
-                }
-                // Skip network fetch if cache is fresh (< 5 minutes)
-                if profile_cache.has_fresh_full(did, 300).unwrap_or(false) {
-                    skip_fetch = true;
+            let dialog_weak = dlg.downgrade();
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                let Some(dialog) = dialog_weak.upgrade() else {
+                    return glib::ControlFlow::Break;
+                };
+                match rx.try_recv() {
+                    Ok(Ok(description)) => {
+                        dialog.set_loading(false);
+                        if description.user_domains.is_empty() {
+                            dialog.show_error("This server isn't taking new accounts");
+                        } else {
+                            dialog.show_account_form(&service_url, description);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(e)) => {
+                        eprintln!("Failed to describe {}: {}", service_url, e);
+                        dialog.set_loading(false);
+                        dialog.show_error("Couldn't reach that server. Check the address.");
+                        glib::ControlFlow::Break
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        dialog.set_loading(false);
+                        glib::ControlFlow::Break
+                    }
                 }
+            });
+        });
+
+        signup.connect_send_phone_code(move |dlg| {
+            let (Some(service_url), phone) = (dlg.chosen_server(), dlg.phone()) else {
+                return;
+            };
+            if phone.is_empty() {
+                dlg.show_error("Enter a phone number to send the code to");
+                return;
             }
-        }
+            dlg.set_loading(true);
+            dlg.hide_error();
 
-        // Don't fetch if we have fresh data
-        if skip_fetch {
-            return;
-        }
-
-        let (tx, rx) = std::sync::mpsc::channel::<Result<Profile, String>>();
+            let (tx, rx) = std::sync::mpsc::channel::<Result<(), ClientError>>();
+            thread::spawn(move || {
+                let result = runtime::block_on(HangarClient::request_phone_verification(
+                    &service_url,
+                    &phone,
+                ));
+                let _ = tx.send(result);
+            });
 
-        let client = self.client();
-        let did = did.to_string();
-        let did_for_window = did.clone();
-        thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_profile(&did).await });
-            let _ = tx.send(result.map_err(|e| e.to_string()));
+            let dialog_weak = dlg.downgrade();
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                let Some(dialog) = dialog_weak.upgrade() else {
+                    return glib::ControlFlow::Break;
+                };
+                match rx.try_recv() {
+                    Ok(Ok(())) => {
+                        dialog.set_loading(false);
+                        dialog.show_toast("Code sent");
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(e)) => {
+                        dialog.set_loading(false);
+                        dialog.show_error(&failure(&e));
+                        glib::ControlFlow::Break
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        dialog.set_loading(false);
+                        glib::ControlFlow::Break
+                    }
+                }
+            });
         });
 
         let app = self.clone();
-        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-            match rx.try_recv() {
-                Ok(Ok(profile)) => {
-                    // Store in cache
-                    if let Some(cache) = app.imp().cache.borrow().as_ref() {
-                        let profile_cache = ProfileCache::new(cache);
-                        let _ = profile_cache.store_full(&profile);
-                    }
+        let signup_client = client.clone();
+        let login_weak = login.downgrade();
+        let made = created.clone();
+        signup.connect_create(move |dlg| {
+            let Some(service_url) = dlg.chosen_server() else {
+                return;
+            };
+            let account = match dlg.new_account() {
+                Ok(account) => account,
+                Err(reason) => {
+                    dlg.show_error(reason);
+                    return;
+                }
+            };
+            dlg.set_loading(true);
+            dlg.hide_error();
 
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        let display_name =
-                            profile.display_name.as_deref().unwrap_or(&profile.handle);
-                        window.set_user_avatar(display_name, profile.avatar.as_deref());
-                        // Set current user DID for filtering conversations
-                        window.set_current_user_did(&did_for_window);
-                        // Update the profile page header
-                        window.update_profile_header(&profile);
+            let (tx, rx) = std::sync::mpsc::channel::<Result<Session, ClientError>>();
+            let client = signup_client.clone();
+            let email = account.email.clone();
+            thread::spawn(move || {
+                let result = runtime::block_on(async {
+                    let session = client.create_account(&service_url, &account).await?;
+                    // As at sign-in: SecretService can be slow, and the
+                    // account exists whether or not this lands.
+                    let session_for_store = session.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = SessionManager::store(&session_for_store).await {
+                            eprintln!(
+                                "hangar: account created, but the session could not be saved \
+                                 and you will have to sign in again next launch.\n  {}",
+                                e
+                            );
+                        }
+                    });
+                    Ok::<_, ClientError>(session)
+                });
+                let _ = tx.send(result);
+            });
+
+            let app = app.clone();
+            let signed_in = signup_client.clone();
+            let login_weak = login_weak.clone();
+            let made = made.clone();
+            let dialog_weak = dlg.downgrade();
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                match rx.try_recv() {
+                    Ok(Ok(session)) => {
+                        println!("Created account: {} ({})", session.handle, session.did);
+                        made.replace(Some(Profile::minimal(
+                            session.did.clone(),
+                            session.handle.clone(),
+                            None,
+                            None,
+                        )));
+                        if let Some(login) = login_weak.upgrade() {
+                            login.close();
+                        }
+                        if adding {
+                            app.adopt_account(signed_in.clone(), session);
+                        } else {
+                            app.begin_session(&session);
+                        }
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.show_email_step(&email);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(e)) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.show_error(&failure(&e));
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.show_error("Sign up failed: connection lost");
+                        }
+                        glib::ControlFlow::Break
                     }
-                    glib::ControlFlow::Break
-                }
-                Ok(Err(e)) => {
-                    eprintln!("Failed to fetch profile: {}", e);
-                    glib::ControlFlow::Break
-                }
-                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch profile: connection lost");
-                    glib::ControlFlow::Break
                 }
+            });
+        });
+
+        let app = self.clone();
+        let verify_client = client.clone();
+        signup.connect_verify_email(move |dlg| {
+            let code = dlg.email_code();
+            if code.is_empty() {
+                return;
             }
+            dlg.set_loading(true);
+            dlg.hide_error();
+
+            let (tx, rx) = std::sync::mpsc::channel::<Result<(), ClientError>>();
+            let client = verify_client.clone();
+            let email = dlg.email();
+            thread::spawn(move || {
+                let result = runtime::block_on(client.confirm_email(&email, &code));
+                let _ = tx.send(result);
+            });
+
+            let app = app.clone();
+            let dialog_weak = dlg.downgrade();
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                let Some(dialog) = dialog_weak.upgrade() else {
+                    return glib::ControlFlow::Break;
+                };
+                match rx.try_recv() {
+                    Ok(Ok(())) => {
+                        dialog.set_loading(false);
+                        dialog.close();
+                        if let Some(window) = app.imp().window.borrow().as_ref() {
+                            window.show_toast("Email verified");
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(e)) => {
+                        dialog.set_loading(false);
+                        dialog.show_error(&failure(&e));
+                        glib::ControlFlow::Break
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        dialog.set_loading(false);
+                        glib::ControlFlow::Break
+                    }
+                }
+            });
         });
-    }
 
-    fn fetch_timeline(&self) {
-        // Try cache first for instant display
-        if let Some(cache) = self.imp().cache.borrow().as_ref() {
-            let feed_cache = FeedCache::new(cache);
-            if let Ok(cached_posts) = feed_cache.get_page("home", 0, 50) {
-                if !cached_posts.is_empty() {
-                    // Restore feed state from cache
-                    if let Ok(state) = feed_cache.get_state("home") {
-                        self.imp()
-                            .timeline_cursor
-                            .replace(state.oldest_cursor.clone());
-                        self.imp()
-                            .newest_post_uri
-                            .replace(state.newest_post_uri.clone());
+        let resend_client = client;
+        signup.connect_resend_email(move |dlg| {
+            dlg.hide_error();
+            let (tx, rx) = std::sync::mpsc::channel::<Result<(), ClientError>>();
+            let client = resend_client.clone();
+            thread::spawn(move || {
+                let result = runtime::block_on(client.request_email_confirmation());
+                let _ = tx.send(result);
+            });
+
+            let dialog_weak = dlg.downgrade();
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                let Some(dialog) = dialog_weak.upgrade() else {
+                    return glib::ControlFlow::Break;
+                };
+                match rx.try_recv() {
+                    Ok(Ok(())) => {
+                        dialog.show_toast("Sent a new code");
+                        glib::ControlFlow::Break
                     }
-                    if let Some(window) = self.imp().window.borrow().as_ref() {
-                        window.set_posts(cached_posts);
+                    Ok(Err(e)) => {
+                        dialog.show_error(&failure(&e));
+                        glib::ControlFlow::Break
                     }
-                    // Continue to fetch fresh data in background
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
                 }
-            }
-        }
+            });
+        });
 
-        // With no cached page shown, the wait gets skeleton rows.
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.set_timeline_loading(true);
-        }
+        let app = self.clone();
+        signup.connect_closed(move |_| {
+            if let Some(profile) = created.borrow_mut().take() {
+                app.start_onboarding(profile);
+            }
+        });
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+        signup.present(Some(login));
+    }
 
-        let client = self.client();
-        thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_timeline(None).await });
-            let _ = tx.send(result.map_err(|e| e.to_string()));
+    /// Walk a new account through its profile, interests, follows, and
+    /// feeds. Every step can be skipped by closing it.
+    fn start_onboarding(&self, profile: Profile) {
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+        let app = self.clone();
+        let dialog = crate::ui::edit_profile::present(&window, &profile, move |edits| {
+            app.save_profile(edits);
         });
+        let app = self.clone();
+        dialog.connect_closed(move |_| app.present_onboarding());
+    }
+
+    /// Interests, then people to follow suggested from them, then feeds to
+    /// pin. Each Next writes what was picked and loads the next page.
+    fn present_onboarding(&self) {
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+        let onboarding = Onboarding::new();
 
         let app = self.clone();
-        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-            match rx.try_recv() {
-                Ok(Ok((posts, next_cursor))) => {
-                    app.imp().timeline_cursor.replace(next_cursor.clone());
-                    // Set anchor to the newest post
-                    if let Some(first) = posts.first() {
-                        app.imp().newest_post_uri.replace(Some(first.uri.clone()));
+        let ob = onboarding.clone();
+        onboarding.interests_next.connect_clicked(move |_| {
+            ob.set_busy(true);
+            let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<Profile>, String>>();
+            let client = app.client();
+            let interests = ob.chosen_interests();
+            thread::spawn(move || {
+                let result = runtime::block_on(async {
+                    if !interests.is_empty() {
+                        // Suggestions still come without them.
+                        if let Err(e) = client.set_interests(&interests).await {
+                            eprintln!("Failed to save interests: {}", e);
+                        }
                     }
-                    // Clear any pending new posts since we just refreshed
+                    client.get_suggestions(25).await
+                });
+                let _ = tx.send(result.map_err(|e| e.to_string()));
+            });
 
-                    // Store in cache
-                    if let Some(cache) = app.imp().cache.borrow().as_ref() {
-                        let post_cache = PostCache::new(cache);
-                        let feed_cache = FeedCache::new(cache);
-                        // Clear old feed items and store new ones
-                        let _ = feed_cache.clear_feed("home");
-                        let _ = post_cache.store_batch(&posts);
-                        let _ = feed_cache.store_page("home", &posts, 0);
-                        // Update feed state
-                        let state = FeedState {
-                            oldest_cursor: next_cursor,
-                            has_more: true,
-                            newest_post_uri: posts.first().map(|p| p.uri.clone()),
-                            newest_sort_timestamp: posts.first().map(|p| p.indexed_at.clone()),
-                            last_refresh_at: Some(CacheDb::now()),
-                        };
-                        let _ = feed_cache.set_state("home", &state);
+            let app = app.clone();
+            let ob = ob.clone();
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                match rx.try_recv() {
+                    Ok(Ok(profiles)) => {
+                        ob.set_busy(false);
+                        ob.show_follows(&profiles);
+                        glib::ControlFlow::Break
                     }
-
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.hide_new_posts_banner();
-                        window.set_posts(posts);
+                    Ok(Err(e)) => {
+                        eprintln!("Failed to load suggestions: {}", e);
+                        app.report_session_expiry();
+                        ob.set_busy(false);
+                        ob.show_follows(&[]);
+                        glib::ControlFlow::Break
                     }
-                    // Start background polling for new posts
-                    app.start_new_posts_polling();
-                    glib::ControlFlow::Break
-                }
-                Ok(Err(e)) => {
-                    eprintln!("Failed to fetch timeline: {}", e);
-                    app.toast_unless_offline("Couldn't load the feed");
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_timeline_load_failed();
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        ob.set_busy(false);
+                        glib::ControlFlow::Break
                     }
-                    app.report_session_expiry();
-                    glib::ControlFlow::Break
                 }
-                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch timeline: connection lost");
-                    glib::ControlFlow::Break
+            });
+        });
+
+        let app = self.clone();
+        let ob = onboarding.clone();
+        onboarding.follows_next.connect_clicked(move |_| {
+            ob.set_busy(true);
+            let (tx, rx) = std::sync::mpsc::channel::<(bool, Result<Vec<FeedGenerator>, String>)>();
+            let client = app.client();
+            let dids = ob.chosen_follows();
+            thread::spawn(move || {
+                let result = runtime::block_on(async {
+                    let followed = dids.is_empty() || client.follow_all(&dids).await.is_ok();
+                    let feeds = client
+                        .get_suggested_feeds(None)
+                        .await
+                        .map(|(feeds, _)| feeds);
+                    (followed, feeds)
+                });
+                let _ = tx.send((result.0, result.1.map_err(|e| e.to_string())));
+            });
+
+            let app = app.clone();
+            let ob = ob.clone();
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                match rx.try_recv() {
+                    Ok((followed, feeds)) => {
+                        if !followed {
+                            app.toast_unless_offline("Couldn't follow everyone you picked");
+                        }
+                        let feeds = feeds.unwrap_or_else(|e| {
+                            eprintln!("Failed to load suggested feeds: {}", e);
+                            app.report_session_expiry();
+                            Vec::new()
+                        });
+                        ob.set_busy(false);
+                        ob.show_feeds(&feeds);
+                        glib::ControlFlow::Break
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        ob.set_busy(false);
+                        glib::ControlFlow::Break
+                    }
                 }
+            });
+        });
+
+        let app = self.clone();
+        let ob = onboarding.clone();
+        onboarding.finish.connect_clicked(move |_| {
+            let feeds = ob.chosen_feeds();
+            if !feeds.is_empty() {
+                let mut list = vec![SavedFeed::home()];
+                list.extend(feeds.iter().map(|feed| feed.as_saved_feed(true)));
+                app.save_feed_list(list);
+            }
+            ob.close();
+            app.fetch_timeline();
+            if let Some(window) = app.imp().window.borrow().as_ref() {
+                window.show_toast("You're all set");
             }
         });
+
+        onboarding.present(&window);
+    }
+
+    /// Open the window on the account `session` just signed in to: its
+    /// cache, its profile, its preferences, and its timeline.
+    fn begin_session(&self, session: &Session) {
+        self.remember_account(session);
+
+        // Initialize cache for this user
+        match CacheDb::open(&session.did) {
+            Ok(cache) => {
+                // Clean up stale entries in background to not block UI
+                let cache_for_cleanup = cache.clone();
+                std::thread::spawn(move || {
+                    if let Err(e) = cache_for_cleanup.cleanup_stale() {
+                        eprintln!("Cache cleanup failed: {}", e);
+                    }
+                });
+                // Initialize image cache with database reference
+                avatar_cache::init(Arc::new(cache.clone()));
+                avatar_cache::cleanup_cache();
+                self.imp().cache.replace(Some(cache));
+            }
+            Err(e) => {
+                eprintln!("Failed to open cache: {}", e);
+            }
+        }
+
+        // Fetch user profile for sidebar avatar
+        self.fetch_user_profile(&session.did);
+
+        // Fetch saved feeds for feed selector
+        self.fetch_saved_feeds();
+        self.fetch_muted_words();
+        self.fetch_moderation_prefs();
+        self.fetch_notification_prefs();
+
+        // Fetch timeline
+        self.fetch_timeline();
+    }
+
+    fn fetch_user_profile(&self, did: &str) {
+        // Store the user DID for later use
+        self.imp().user_did.replace(Some(did.to_string()));
+        // Rows check post authorship against this before offering Delete
+        crate::ui::post_row::set_current_user_did(Some(did));
+        // People rows hide the follow button on your own row
+        crate::ui::actor_row::set_viewer_did(Some(did));
+
+        // Every path here follows a successful sign-in, so the polls a dead
+        // session parked may run again.
+        self.imp().session_dead.replace(false);
+
+        // Badges should not wait out the first 30-second tick.
+        self.check_unread_counts();
+
+        // Try cache first for instant display
+        let mut skip_fetch = false;
+        if let Some(cache) = self.imp().cache.borrow().as_ref() {
+            let profile_cache = ProfileCache::new(cache);
+            if let Ok(cached_profile) = profile_cache.get(did) {
+                if let Some(window) = self.imp().window.borrow().as_ref() {
+                    let display_name = cached_profile
+                        .display_name
+                        .as_deref()
+                        .unwrap_or(&cached_profile.handle);
+                    window.set_user_avatar(display_name, cached_profile.avatar.as_deref());
+                    window.set_current_user_did(did);
+                    window.update_profile_header(&cached_profile);
+                }
+                self.note_account_profile(&cached_profile);
+                // Skip network fetch if cache is fresh (< 5 minutes)
+                if profile_cache.has_fresh_full(did, 300).unwrap_or(false) {
+                    skip_fetch = true;
+                }
+            }
+        }
+
+        // Don't fetch if we have fresh data
+        if skip_fetch {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Profile, String>>();
+
+        let client = self.client();
+        let did = did.to_string();
+        let did_for_window = did.clone();
+        let account_token = self.imp().account_generation.token();
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_profile(&did).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(profile)) => {
+                    // The account was switched away from while this was out.
+                    if !app.imp().account_generation.is_current(account_token) {
+                        return glib::ControlFlow::Break;
+                    }
+                    // Store in cache
+                    if let Some(cache) = app.imp().cache.borrow().as_ref() {
+                        let profile_cache = ProfileCache::new(cache);
+                        let _ = profile_cache.store_full(&profile);
+                    }
+                    app.note_account_profile(&profile);
+
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        let display_name =
+                            profile.display_name.as_deref().unwrap_or(&profile.handle);
+                        window.set_user_avatar(display_name, profile.avatar.as_deref());
+                        // Set current user DID for filtering conversations
+                        window.set_current_user_did(&did_for_window);
+                        // Update the profile page header
+                        window.update_profile_header(&profile);
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch profile: {}", e);
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch profile: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    fn fetch_timeline(&self) {
+        // Cache first for instant display; fresh data follows
+        self.paint_cached_feed(FEED_HOME);
+
+        // With no cached page shown, the wait gets skeleton rows.
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_timeline_loading(true);
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+
+        let client = self.client();
+        let account_token = self.imp().account_generation.token();
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_timeline(None).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            // The last account's timeline must not reach the next one's
+            // cache.
+            if !app.imp().account_generation.is_current(account_token) {
+                return glib::ControlFlow::Break;
+            }
+            match rx.try_recv() {
+                Ok(Ok((posts, next_cursor))) => {
+                    app.merge_fresh_feed(FEED_HOME, posts, next_cursor, true);
+                    // Start background polling for new posts
+                    app.start_new_posts_polling();
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch timeline: {}", e);
+                    app.toast_unless_offline("Couldn't load the feed");
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_timeline_load_failed();
+                    }
+                    app.report_session_expiry();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch timeline: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Paint every cached post of a feed, back as far as the reader had
+    /// scrolled, and pick up its cursor and anchor. The first paint of a
+    /// feed in this window puts the reader back where they left it. Does
+    /// nothing when the feed has no cached posts.
+    fn paint_cached_feed(&self, feed_key: &str) {
+        let (posts, state, position) = {
+            let cache = self.imp().cache.borrow();
+            let Some(cache) = cache.as_ref() else {
+                return;
+            };
+            let feed_cache = FeedCache::new(cache);
+            (
+                feed_cache.get_all(feed_key).unwrap_or_default(),
+                feed_cache.get_state(feed_key).unwrap_or_default(),
+                feed_cache.reading_position(feed_key).ok().flatten(),
+            )
+        };
+        // Spent even on an empty cache: a refresh landing later must not
+        // yank the reader back to where they were yesterday.
+        let first_paint = self
+            .imp()
+            .restored_positions
+            .borrow_mut()
+            .insert(feed_key.to_string());
+        if posts.is_empty() {
+            return;
+        }
+        self.imp().timeline_cursor.replace(state.oldest_cursor);
+        self.imp().newest_post_uri.replace(state.newest_post_uri);
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_posts(posts);
+            for gap in &state.gaps {
+                window.insert_gap(&gap.boundary_uri);
+            }
+            if first_paint && let Some(position) = position {
+                window.restore_reading_position(&position);
+            }
+        }
+    }
+
+    /// Remember where the reader is in the feed on screen, for the next
+    /// launch or the next time they pick it. Nothing is saved while the
+    /// timeline is out of view.
+    fn save_reading_position(&self) {
+        let Some(position) = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .and_then(|window| window.reading_position())
+        else {
+            return;
+        };
+        if let Some(cache) = self.imp().cache.borrow().as_ref() {
+            let _ = FeedCache::new(cache).set_reading_position(&self.current_feed_key(), &position);
+        }
+    }
+
+    /// Fold a fresh first page into the feed's cache and the list on
+    /// screen. The page lands on top and leaves the older pages, the
+    /// reading position and the cursor past them be. A page that never
+    /// reached the cached head leaves a gap row between the two.
+    fn merge_fresh_feed(
+        &self,
+        feed_key: &str,
+        posts: Vec<Post>,
+        cursor: Option<String>,
+        chronological: bool,
+    ) {
+        if let Some(first) = posts.first() {
+            self.imp().newest_post_uri.replace(Some(first.uri.clone()));
+        }
+        let merged = match self.imp().cache.borrow().as_ref() {
+            Some(cache) => FeedCache::new(cache)
+                .merge_top(feed_key, &posts, cursor.clone(), chronological)
+                .unwrap_or(TopMerge::Fresh),
+            None => TopMerge::Fresh,
+        };
+        if merged == TopMerge::Fresh {
+            self.imp().timeline_cursor.replace(cursor);
+        }
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.hide_new_posts_banner();
+            match merged {
+                TopMerge::Joined => {
+                    window.merge_posts(posts);
+                }
+                TopMerge::Gapped(gap) => {
+                    window.merge_posts(posts);
+                    window.insert_gap(&gap.boundary_uri);
+                }
+                TopMerge::Fresh => window.set_posts(posts),
+            }
+        }
+    }
+
+    /// The cache key of the feed on screen
+    fn current_feed_key(&self) -> String {
+        self.imp()
+            .current_feed
+            .borrow()
+            .as_ref()
+            .filter(|f| !f.is_home())
+            .map(|f| f.uri.clone())
+            .unwrap_or_else(|| FEED_HOME.to_string())
+    }
+
+    /// Where the feed on screen gets its pages
+    fn current_feed_source(&self) -> FeedSource {
+        let current = self.imp().current_feed.borrow();
+        let Some(feed) = current.as_ref().filter(|f| !f.is_home()) else {
+            return FeedSource::Timeline;
+        };
+        match feed.search_query() {
+            Some(query) => FeedSource::Search(SearchQuery::parse(query)),
+            None if feed.is_list() => FeedSource::List(feed.uri.clone()),
+            None => FeedSource::Generator(feed.uri.clone()),
+        }
+    }
+
+    /// Load the posts missing behind a gap row, from the cursor the gap
+    /// kept. Filled from above, that is one page under the boundary. Filled
+    /// `from_below`, by a reader who scrolled up to it from the older posts,
+    /// it walks on until it meets them and keeps the page nearest, so what
+    /// opens up leads straight into what they were reading.
+    fn fill_timeline_gap(&self, boundary_uri: String, from_below: bool) {
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+        if *self.imp().filling_gap.borrow() {
+            window.reset_gap(&boundary_uri);
+            return;
+        }
+        let feed_key = self.current_feed_key();
+        let found = self.imp().cache.borrow().as_ref().and_then(|cache| {
+            let feed_cache = FeedCache::new(cache);
+            let gap = feed_cache
+                .get_state(&feed_key)
+                .ok()?
+                .gaps
+                .into_iter()
+                .find(|gap| gap.boundary_uri == boundary_uri)?;
+            let edge = feed_cache.gap_edge(&feed_key, &gap).ok()?;
+            Some((gap, edge))
+        });
+        // Cleared from the cache since, so there is no cursor to go on.
+        let Some((gap, edge)) = found else {
+            window.remove_gap(&boundary_uri);
+            return;
+        };
+        self.imp().filling_gap.replace(true);
+
+        let pages = if from_below { GAP_FILL_UP_PAGES } else { 1 };
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>, bool), String>>();
+        let client = self.client();
+        let semaphore = API_SEMAPHORE.clone();
+        let feed_source = self.current_feed_source();
+        let cursor = gap.cursor.clone();
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                let _permit = semaphore.acquire().await;
+                let mut collected = Vec::new();
+                let mut cursor = Some(cursor);
+                let mut met = false;
+                for _ in 0..pages {
+                    let Some(from) = cursor.take() else {
+                        break;
+                    };
+                    let (posts, next) = feed_source.page(&client, Some(&from)).await?;
+                    cursor = next;
+                    if let Some(at) = edge.meets(&posts) {
+                        collected.extend(posts.into_iter().take(at));
+                        met = true;
+                        break;
+                    }
+                    collected.extend(posts);
+                }
+                Ok::<_, ClientError>((collected, cursor, met))
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        let feed_token = self.imp().feed_generation.token();
+        let account_token = self.imp().account_generation.token();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            // Another feed or account is on screen; its list has no such gap.
+            if !app.imp().feed_generation.is_current(feed_token)
+                || !app.imp().account_generation.is_current(account_token)
+            {
+                app.imp().filling_gap.replace(false);
+                return glib::ControlFlow::Break;
+            }
+            match rx.try_recv() {
+                Ok(Ok((mut posts, cursor, met))) => {
+                    app.imp().filling_gap.replace(false);
+                    // The feed ending inside the gap closes it as surely.
+                    let closed = met || cursor.is_none();
+                    let rest = if from_below && closed && posts.len() > GAP_FILL_SIZE {
+                        // Only the posts nearest the reader; the gap stays
+                        // above them, at the same place in the feed.
+                        posts.drain(..posts.len() - GAP_FILL_SIZE);
+                        Some(gap.clone())
+                    } else if closed {
+                        None
+                    } else {
+                        Some(FeedGap {
+                            boundary_uri: posts
+                                .last()
+                                .map_or_else(|| gap.boundary_uri.clone(), |p| p.uri.clone()),
+                            cursor: cursor.unwrap_or_default(),
+                        })
+                    };
+                    if let Some(cache) = app.imp().cache.borrow().as_ref() {
+                        let _ =
+                            FeedCache::new(cache).fill_gap(&feed_key, &gap, &posts, rest.clone());
+                    }
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.fill_gap(
+                            &gap.boundary_uri,
+                            posts,
+                            rest.as_ref().map(|rest| rest.boundary_uri.as_str()),
+                            from_below,
+                        );
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    app.imp().filling_gap.replace(false);
+                    eprintln!("Failed to fill feed gap: {}", e);
+                    app.toast_unless_offline("Couldn't load the missing posts");
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.reset_gap(&gap.boundary_uri);
+                    }
+                    app.report_session_expiry();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    app.imp().filling_gap.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.reset_gap(&gap.boundary_uri);
+                    }
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Every cached post of a view, and the cursor past them
+    fn cached_view(&self, key: &str) -> (Vec<Post>, Option<String>) {
+        let cache = self.imp().cache.borrow();
+        let Some(cache) = cache.as_ref() else {
+            return (Vec::new(), None);
+        };
+        let feed_cache = FeedCache::new(cache);
+        let posts = feed_cache.get_all(key).unwrap_or_default();
+        let cursor = feed_cache
+            .get_state(key)
+            .ok()
+            .and_then(|state| state.oldest_cursor);
+        (posts, cursor)
+    }
+
+    /// Keep a view's fresh first page for the next visit, offline or not
+    fn cache_view(&self, key: &str, posts: &[Post], cursor: Option<String>) {
+        if let Some(cache) = self.imp().cache.borrow().as_ref() {
+            let _ = FeedCache::new(cache).replace_page(key, posts, cursor);
+        }
+    }
+
+    /// Keep an older page of a view below what is cached
+    fn extend_cached_view(&self, key: &str, posts: &[Post], cursor: Option<String>) {
+        if let Some(cache) = self.imp().cache.borrow().as_ref() {
+            let _ = FeedCache::new(cache).extend_page(key, posts, cursor);
+        }
     }
 
     /// Undo the optimistic like flip after the server refused it.
@@ -1825,1216 +3353,3364 @@ impl HangarApplication {
                         glib::ControlFlow::Break
                     }
                 }
-            });
+            });
+        });
+
+        // Thread callback for replies
+        let app2 = self.clone();
+        let dialog_weak2 = dialog.downgrade();
+        let reply_uri = parent_post.uri.clone();
+        let reply_cid = parent_post.cid.clone();
+        dialog.connect_thread(move |posts| {
+            if let Some(dialog) = dialog_weak2.upgrade() {
+                dialog.set_loading(true);
+                dialog.hide_error();
+            }
+
+            let app = app2.clone();
+            let dialog_weak = dialog_weak2.clone();
+            let reply_uri = reply_uri.clone();
+            let reply_cid = reply_cid.clone();
+
+            let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+            let client = app.client();
+
+            thread::spawn(move || {
+                let result = runtime::block_on(async {
+                    let reply_ref = crate::atproto::ReplyRef {
+                        root_uri: reply_uri.clone(),
+                        root_cid: reply_cid.clone(),
+                        parent_uri: reply_uri,
+                        parent_cid: reply_cid,
+                    };
+                    client.create_thread(&posts, Some(reply_ref)).await
+                });
+                let _ = tx.send(result.map(|_| ()).map_err(|e| e.to_string()));
+            });
+
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                match rx.try_recv() {
+                    Ok(Ok(())) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.close();
+                        }
+                        app.fetch_timeline();
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(e)) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.show_error(&e);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                }
+            });
+        });
+
+        self.setup_mention_search(&dialog);
+        self.setup_video_upload(&dialog);
+        self.setup_link_card_fetch(&dialog);
+        dialog.present(Some(&window));
+        dialog.focus_composer();
+    }
+
+    fn open_quote_dialog(&self, quoted_post: Post) {
+        let window = match self.imp().window.borrow().as_ref() {
+            Some(w) => w.clone(),
+            None => return,
+        };
+
+        let context = QuoteContext {
+            uri: quoted_post.uri.clone(),
+            cid: quoted_post.cid.clone(),
+            author_handle: quoted_post.author.handle.clone(),
+            text: quoted_post.text.clone(),
+        };
+
+        let dialog = ComposeDialog::new_quote(context);
+
+        let app = self.clone();
+        let dialog_weak = dialog.downgrade();
+
+        dialog.connect_quote(move |data, quoted_uri, quoted_cid| {
+            if let Some(dialog) = dialog_weak.upgrade() {
+                dialog.set_loading(true);
+                dialog.hide_error();
+            }
+
+            let app = app.clone();
+            let dialog_weak = dialog_weak.clone();
+
+            let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+            let client = app.client();
+
+            thread::spawn(move || {
+                let result = runtime::block_on(async {
+                    client
+                        .create_post_with_data(&data, None, Some((&quoted_uri, &quoted_cid)))
+                        .await
+                });
+                let _ = tx.send(result.map(|_| ()).map_err(|e| e.to_string()));
+            });
+
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                match rx.try_recv() {
+                    Ok(Ok(())) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.close();
+                        }
+                        app.fetch_timeline();
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(e)) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.show_error(&e);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                }
+            });
+        });
+
+        // Thread callback for quotes
+        let app2 = self.clone();
+        let dialog_weak2 = dialog.downgrade();
+        dialog.connect_thread(move |posts| {
+            if let Some(dialog) = dialog_weak2.upgrade() {
+                dialog.set_loading(true);
+                dialog.hide_error();
+            }
+
+            let app = app2.clone();
+            let dialog_weak = dialog_weak2.clone();
+
+            let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+            let client = app.client();
+
+            thread::spawn(move || {
+                let result = runtime::block_on(async { client.create_thread(&posts, None).await });
+                let _ = tx.send(result.map(|_| ()).map_err(|e| e.to_string()));
+            });
+
+            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+                match rx.try_recv() {
+                    Ok(Ok(())) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.close();
+                        }
+                        app.fetch_timeline();
+                        glib::ControlFlow::Break
+                    }
+                    Ok(Err(e)) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                            dialog.show_error(&e);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        if let Some(dialog) = dialog_weak.upgrade() {
+                            dialog.set_loading(false);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                }
+            });
+        });
+
+        self.setup_mention_search(&dialog);
+        self.setup_video_upload(&dialog);
+        self.setup_link_card_fetch(&dialog);
+        dialog.present(Some(&window));
+        dialog.focus_composer();
+    }
+
+    fn toggle_repost(&self, post: &Post, post_row_weak: glib::WeakRef<PostRow>) {
+        // Result type: Ok(Some(uri)) for repost success, Ok(None) for unrepost success, Err for failure
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Option<String>, String>>();
+        let client = self.client();
+
+        // What the row showed before it flipped itself, for the revert.
+        let was_reposted = post.viewer_repost.is_some();
+        let previous_uri = post.viewer_repost.clone();
+        let post_uri = post.uri.clone();
+
+        // If already reposted, this press deletes the repost.
+        if let Some(repost_uri) = &post.viewer_repost {
+            let repost_uri = repost_uri.clone();
+            thread::spawn(move || {
+                let result = runtime::block_on(async { client.delete_repost(&repost_uri).await });
+                let _ = tx.send(result.map(|_| None).map_err(|e| e.to_string()));
+            });
+        } else {
+            // Not reposted yet, create repost
+            let uri = post.uri.clone();
+            let cid = post.cid.clone();
+            thread::spawn(move || {
+                let result = runtime::block_on(async { client.repost(&uri, &cid).await });
+                let _ = tx.send(result.map(Some).map_err(|e| e.to_string()));
+            });
+        }
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(new_repost_uri)) => {
+                    // Guarded like the revert path. Landing a cleared URI on
+                    // a rebound row is the loud failure: the next press on
+                    // that post publishes a repost instead of undoing one.
+                    if let Some(post_row) = post_row_weak.upgrade()
+                        && post_row.post_uri().as_deref() == Some(post_uri.as_str())
+                    {
+                        post_row.set_viewer_repost_uri(new_repost_uri);
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Repost/unrepost failed: {}", e);
+                    if let Some(post_row) = post_row_weak.upgrade() {
+                        Self::revert_repost(&post_row, &post_uri, previous_uri.clone());
+                    }
+                    app.report_session_expiry();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast(if was_reposted {
+                            "Couldn't undo your repost"
+                        } else {
+                            "Couldn't repost"
+                        });
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    pub fn fetch_timeline_more(&self) {
+        if *self.imp().loading_more.borrow() {
+            return;
+        }
+        let cursor = match self.imp().timeline_cursor.borrow().as_ref() {
+            Some(c) => c.clone(),
+            None => return,
+        };
+        self.imp().loading_more.replace(true);
+
+        // Show loading spinner
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_loading_more(true);
+        }
+
+        let feed_key = self.current_feed_key();
+        let feed_source = self.current_feed_source();
+
+        let account_token = self.imp().account_generation.token();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+        let client = self.client();
+        let semaphore = API_SEMAPHORE.clone();
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                // Acquire permit to limit concurrent API requests
+                let _permit = semaphore.acquire().await;
+                feed_source.page(&client, Some(&cursor)).await
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    app.imp().loading_more.replace(false);
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((posts, next_cursor))) => {
+                    app.imp().loading_more.replace(false);
+                    app.imp().timeline_cursor.replace(next_cursor.clone());
+
+                    // Store in cache (append to existing feed)
+                    if let Some(cache) = app.imp().cache.borrow().as_ref() {
+                        // Below whatever is cached, which a merged
+                        // refresh may have grown upward past position 0.
+                        let _ = FeedCache::new(cache).extend_page(&feed_key, &posts, next_cursor);
+                    }
+
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_loading_more(false);
+                        if !posts.is_empty() {
+                            window.append_posts(posts);
+                        }
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    app.imp().loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_loading_more(false);
+                    }
+                    eprintln!("Failed to fetch more timeline: {}", e);
+                    app.toast_unless_offline("Couldn't load more posts");
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    app.imp().loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_loading_more(false);
+                    }
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    fn start_new_posts_polling(&self) {
+        // Only start polling once
+        if *self.imp().polling_started.borrow() {
+            return;
+        }
+        self.imp().polling_started.replace(true);
+
+        let app = self.clone();
+        // One timer drives every badge: new posts and unread counts alike
+        glib::timeout_add_seconds_local(30, move || {
+            // With the window closed the background poll takes over, at its
+            // own pace.
+            if app.imp().window.borrow().is_none() {
+                return glib::ControlFlow::Continue;
+            }
+            app.check_for_new_posts();
+            app.check_unread_counts();
+            app.check_other_accounts_unread();
+            glib::ControlFlow::Continue
+        });
+    }
+
+    fn check_for_new_posts(&self) {
+        // Don't check if we're already checking
+        if *self.imp().checking_new_posts.borrow() {
+            return;
+        }
+
+        // A dead session would fail here every 30 seconds; wait for the
+        // next sign-in instead.
+        if *self.imp().session_dead.borrow() {
+            return;
+        }
+
+        // Offline says why every request would fail; the banner covers it.
+        if *self.imp().offline.borrow() {
+            return;
+        }
+
+        // A backgrounded window would prepend into a feed nobody is
+        // reading, every 30 seconds, for as long as it sits there; the
+        // model and the image caches grow with every pass and the kernel
+        // eventually ends it. Catch up on focus instead.
+        let window_active = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .is_some_and(|w| w.is_active());
+        if !window_active {
+            return;
+        }
+
+        // Only check if we have an anchor
+        let anchor_uri = match self.imp().newest_post_uri.borrow().clone() {
+            Some(uri) => uri,
+            None => return,
+        };
+
+        self.imp().checking_new_posts.replace(true);
+
+        let feed_key = self.current_feed_key();
+        let feed_source = self.current_feed_source();
+        let chronological = feed_source.is_chronological();
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+        let client = self.client();
+        let account_token = self.imp().account_generation.token();
+        let feed_token = self.imp().feed_generation.token();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { feed_source.page(&client, None).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok((posts, cursor))) => {
+                    app.imp().checking_new_posts.replace(false);
+                    app.imp().new_posts_poll_failed.replace(false);
+                    // Posts from the account or feed switched away from.
+                    if !app.imp().account_generation.is_current(account_token)
+                        || !app.imp().feed_generation.is_current(feed_token)
+                    {
+                        return glib::ControlFlow::Break;
+                    }
+
+                    // A page that never reaches the anchor, after hours
+                    // away, leaves a gap above what was already loaded.
+                    let merged = app.imp().cache.borrow().as_ref().and_then(|cache| {
+                        FeedCache::new(cache)
+                            .merge_top(&feed_key, &posts, cursor, chronological)
+                            .ok()
+                    });
+
+                    // Find posts newer than our anchor
+                    let new_posts: Vec<Post> = posts
+                        .into_iter()
+                        .take_while(|p| p.uri != anchor_uri)
+                        .collect();
+
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        // Always refresh timestamps on poll
+                        window.refresh_timestamps();
+
+                        if !new_posts.is_empty() {
+                            // Update anchor to newest post
+                            if let Some(newest) = new_posts.first() {
+                                app.imp().newest_post_uri.replace(Some(newest.uri.clone()));
+                            }
+
+                            // Insert new posts at the top of the timeline
+                            // User can scroll up to see them
+                            let inserted = window.insert_posts_at_top(new_posts);
+                            if let Some(TopMerge::Gapped(gap)) = &merged {
+                                window.insert_gap(&gap.boundary_uri);
+                            }
+
+                            // Count what actually landed: a reordered feed
+                            // re-serves posts already on screen.
+                            if inserted > 0 {
+                                window.show_new_posts_banner(inserted);
+                            }
+                        }
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    app.imp().checking_new_posts.replace(false);
+                    // A flaky upstream fails poll after poll; say so once.
+                    if !*app.imp().new_posts_poll_failed.borrow() {
+                        app.imp().new_posts_poll_failed.replace(true);
+                        eprintln!("Failed to check for new posts: {}", e);
+                    }
+                    // The poll is the only thing talking to the server on an
+                    // idle window, so a session dying mid-use shows up here
+                    // first.
+                    app.report_session_expiry();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    app.imp().checking_new_posts.replace(false);
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    fn show_new_posts(&self) {
+        // The posts are already in the model. Scroll to top to reveal them.
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.hide_new_posts_banner();
+            window.scroll_to_top();
+        }
+    }
+
+    /// Refresh the Mentions, Activity, and Chat badges from the server.
+    ///
+    /// Rides the same 30-second timer as `check_for_new_posts`: one thread,
+    /// one round trip, three badges.
+    fn check_unread_counts(&self) {
+        let signed_in = self.imp().user_did.borrow().is_some();
+        let in_flight = *self.imp().checking_unread.borrow();
+        let expired = *self.imp().session_dead.borrow();
+        if !unread_poll_allowed(signed_in, in_flight, expired) {
+            return;
+        }
+        // Same offline guard as the new-posts poll.
+        if *self.imp().offline.borrow() {
+            return;
+        }
+        self.imp().checking_unread.replace(true);
+
+        // A badge clear while this fetch is out makes its counts stale.
+        let clear_token = self.imp().badge_clear_generation.token();
+        // So does a switch: these would be the last account's counts.
+        let account_token = self.imp().account_generation.token();
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<UnreadActivity, String>>();
+        let client = self.client();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_unread_activity().await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(activity)) => {
+                    app.imp().checking_unread.replace(false);
+                    app.imp().unread_poll_failed.replace(false);
+                    if !app.imp().account_generation.is_current(account_token) {
+                        return glib::ControlFlow::Break;
+                    }
+                    app.raise_desktop_notifications(&activity);
+                    let counts = activity.counts;
+                    if let Some(sidebar) = app.sidebar() {
+                        sidebar.set_badge(NavItem::Chat, counts.chat);
+                        // Opening a section may have cleared these two while
+                        // the fetch was out; stale counts would relight them.
+                        if app.imp().badge_clear_generation.is_current(clear_token) {
+                            sidebar.set_badge(NavItem::Mentions, counts.mentions);
+                            sidebar.set_badge(NavItem::Activity, counts.activity);
+                        }
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    app.imp().checking_unread.replace(false);
+                    // A flaky upstream fails poll after poll; say so once.
+                    if !*app.imp().unread_poll_failed.borrow() {
+                        app.imp().unread_poll_failed.replace(true);
+                        eprintln!("Failed to check unread counts: {}", e);
+                    }
+                    // On expiry this parks the poll until the next sign-in.
+                    app.report_session_expiry();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    app.imp().checking_unread.replace(false);
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Hand what the unread poll found to the tracker, and raise whatever
+    /// is new as desktop notifications.
+    ///
+    /// The tracker sees every poll, so what arrived while the window was in
+    /// front is not announced later when it loses focus. Only the raising
+    /// waits for the window to be in the background and outside quiet hours.
+    fn raise_desktop_notifications(&self, activity: &UnreadActivity) {
+        let Some(own_did) = self.imp().user_did.borrow().clone() else {
+            return;
+        };
+        let prefs = crate::state::AppSettings::load().notifications;
+        let alerts = self.imp().notify_tracker.borrow_mut().collect(
+            &activity.notifications,
+            &activity.conversations,
+            &own_did,
+            &prefs,
+            &self.imp().muted_words.borrow(),
+            &self.imp().moderation_prefs.borrow(),
+        );
+        if alerts.is_empty() {
+            return;
+        }
+
+        // The window in front already shows all of this.
+        let in_front = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .is_some_and(|w| w.is_active());
+        if in_front {
+            return;
+        }
+        if let Some(quiet) = prefs.quiet_hours {
+            use chrono::Timelike;
+            let now = chrono::Local::now();
+            if quiet.contains((now.hour() * 60 + now.minute()) as u16) {
+                return;
+            }
+        }
+
+        for alert in alerts {
+            let notification = gio::Notification::new(&alert.title);
+            if !alert.body.is_empty() {
+                notification.set_body(Some(&alert.body));
+            }
+            let target = alert.target.encode().to_variant();
+            notification
+                .set_default_action_and_target_value("app.open-notification", Some(&target));
+            notification.add_button_with_target_value(
+                alert.button,
+                "app.open-notification",
+                Some(&target),
+            );
+            self.send_notification(Some(&alert.id), &notification);
+        }
+    }
+
+    /// Bring the window forward and open what a desktop notification
+    /// pointed at.
+    fn open_notification_target(&self, target: crate::notify::Target) {
+        use crate::notify::Target;
+
+        // From the background there is no window; activating builds one on
+        // the session already signed in.
+        if self.imp().window.borrow().is_none() {
+            gio::prelude::ApplicationExt::activate(self.upcast_ref::<gio::Application>());
+        }
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+        window.present();
+        // Signed out since the notification went up: nothing to open.
+        if self.imp().user_did.borrow().is_none() {
+            return;
+        }
+
+        let section = |app: &Self, item: NavItem| {
+            window.select_nav(item);
+            app.handle_nav_change(item);
+        };
+        match target {
+            Target::Thread(uri) => self.open_thread_by_uri(uri),
+            Target::Profile(did) => self.open_profile_by_handle(did),
+            Target::Conversation(id) => {
+                section(self, NavItem::Chat);
+                self.open_conversation_by_id(id);
+            }
+            Target::Mentions => section(self, NavItem::Mentions),
+            Target::Activity => section(self, NavItem::Activity),
+            Target::Chat => section(self, NavItem::Chat),
+        }
+    }
+
+    /// Open the thread view for a post known only by URI, as a desktop
+    /// notification carries it.
+    fn open_thread_by_uri(&self, uri: String) {
+        // A thread read before carries the post itself; open it the usual
+        // way, cache first.
+        let (cached, _) = self.cached_view(&thread_key(&uri));
+        if let Some(post) = cached.into_iter().find(|p| p.uri == uri) {
+            self.open_thread_view(post);
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<Post>, String>>();
+        let client = self.client();
+        let post_uri = uri.clone();
+        let account_token = self.imp().account_generation.token();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_thread(&post_uri).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok(posts)) => {
+                    app.cache_view(&thread_key(&uri), &posts, None);
+                    let main_post = posts.iter().find(|p| p.uri == uri).cloned();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        match main_post {
+                            Some(post) => {
+                                window.push_thread_page(&post, posts);
+                            }
+                            None => window.show_toast("Couldn't load this post"),
+                        }
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch thread: {}", e);
+                    app.toast_unless_offline("Couldn't load the thread");
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch thread: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Open a conversation known only by ID, as a desktop notification
+    /// carries it.
+    fn open_conversation_by_id(&self, convo_id: String) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Conversation, String>>();
+        let client = self.client();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_conversation(&convo_id).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(conversation)) => {
+                    app.open_conversation_view(conversation);
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch conversation: {}", e);
+                    app.toast_unless_offline("Couldn't open the conversation");
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch conversation: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// The sidebar, while a window is up.
+    fn sidebar(&self) -> Option<crate::ui::sidebar::Sidebar> {
+        self.imp()
+            .window
+            .borrow()
+            .as_ref()
+            .and_then(|w| w.sidebar())
+    }
+
+    /// What `item`'s badge currently holds.
+    fn badge_count(&self, item: NavItem) -> u32 {
+        self.sidebar().map(|s| s.badge_count(item)).unwrap_or(0)
+    }
+
+    /// Clear both notification badges and tell the server.
+    ///
+    /// `seenAt` is account-wide, so opening either Mentions or Activity
+    /// marks everything seen. The badges clear together to say so honestly.
+    fn clear_notification_badges(&self) {
+        if self.badge_count(NavItem::Mentions) == 0 && self.badge_count(NavItem::Activity) == 0 {
+            return;
+        }
+        // Strand any unread poll already in flight; it counted before this
+        // clear and would relight the badges.
+        self.imp().badge_clear_generation.bump();
+        if let Some(sidebar) = self.sidebar() {
+            sidebar.set_badge(NavItem::Mentions, 0);
+            sidebar.set_badge(NavItem::Activity, 0);
+        }
+        // Fire and forget. If it fails, the next poll raises the badges again.
+        let client = self.client();
+        thread::spawn(move || {
+            if let Err(e) = runtime::block_on(async { client.update_notifications_seen().await }) {
+                eprintln!("Failed to mark notifications seen: {}", e);
+            }
+        });
+    }
+
+    /// Fetch the user's saved feeds for Settings and populate the feed
+    /// selector with the pinned ones
+    fn fetch_saved_feeds(&self) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<SavedFeed>, String>>();
+        let client = self.client();
+        let account_token = self.imp().account_generation.token();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_saved_feed_list().await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok(saved)) => {
+                    let mut feeds = SavedFeed::selector_feeds(&saved);
+                    feeds.extend(saved_search_feeds());
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_saved_feed_list(saved);
+                    }
+                    // Set default feed if not already set
+                    if app.imp().current_feed.borrow().is_none() {
+                        if let Some(first) = feeds.first() {
+                            app.imp().current_feed.replace(Some(first.clone()));
+                        }
+                    }
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        // Set the current feed name/uri first so checkmark shows correctly
+                        if let Some(current) = app.imp().current_feed.borrow().as_ref() {
+                            window.set_current_feed_name(&current.display_name, &current.uri);
+                        }
+                        window.set_saved_feeds(feeds);
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch saved feeds: {}", e);
+                    // Still set up a default home feed, and the searches
+                    // kept on this machine
+                    let mut home_feed = vec![SavedFeed::home()];
+                    home_feed.extend(saved_search_feeds());
+                    app.imp().current_feed.replace(Some(SavedFeed::home()));
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_saved_feeds(home_feed);
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch saved feeds: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Keep the search in the entry as a feed, or let it go if it already
+    /// is one.
+    fn toggle_saved_search(&self, input: String) {
+        let query = SearchQuery::parse(&input);
+        if query.is_empty() {
+            return;
+        }
+        let input = query.to_input();
+        let Some(saved) = self.toggle_search_feed(&input) else {
+            return;
+        };
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_search_saved(saved);
+            window.show_toast(if saved {
+                "Search saved to your feeds"
+            } else {
+                "Search removed from your feeds"
+            });
+        }
+    }
+
+    /// Pin a hashtag's page to the feed selector, or unpin it. A tag pinned
+    /// in another case is the same tag, and is the one unpinned.
+    fn toggle_pinned_tag(&self, tag: String) {
+        let wanted = SearchQuery {
+            tags: vec![tag.clone()],
+            ..Default::default()
+        }
+        .to_input();
+        let input = crate::state::AppSettings::load()
+            .saved_searches
+            .into_iter()
+            .find(|s| s.eq_ignore_ascii_case(&wanted))
+            .unwrap_or(wanted);
+        let Some(pinned) = self.toggle_search_feed(&input) else {
+            return;
+        };
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            // The Search tab's star, if it is showing this same search.
+            let on_search_tab = self
+                .imp()
+                .search_query
+                .borrow()
+                .as_ref()
+                .is_some_and(|q| q.to_input() == input);
+            if on_search_tab {
+                window.set_search_saved(pinned);
+            }
+            window.show_toast(&if pinned {
+                format!("Pinned #{tag} to your feeds")
+            } else {
+                format!("Unpinned #{tag} from your feeds")
+            });
+        }
+    }
+
+    /// Save the search `input` as a feed, or drop it if it is one, and say
+    /// which. The feed selector follows straight away; a saved search on
+    /// screen when it is dropped gives way to the home timeline. `None`
+    /// when the settings could not be written.
+    fn toggle_search_feed(&self, input: &str) -> Option<bool> {
+        let mut settings = crate::state::AppSettings::load();
+        let saved = match settings.saved_searches.iter().position(|s| s == input) {
+            Some(at) => {
+                settings.saved_searches.remove(at);
+                false
+            }
+            None => {
+                settings.saved_searches.push(input.to_string());
+                true
+            }
+        };
+        if let Err(e) = settings.save() {
+            eprintln!("Failed to save settings: {}", e);
+            if let Some(window) = self.imp().window.borrow().as_ref() {
+                window.show_toast("Couldn't save the search");
+            }
+            return None;
+        }
+
+        let window = self.imp().window.borrow().clone()?;
+        let mut feeds: Vec<SavedFeed> = window
+            .saved_feeds()
+            .into_iter()
+            .filter(|f| f.search_query().is_none())
+            .collect();
+        feeds.extend(saved_search_feeds());
+        window.set_saved_feeds(feeds);
+
+        let dropped_current = self
+            .imp()
+            .current_feed
+            .borrow()
+            .as_ref()
+            .is_some_and(|f| f.search_query() == Some(input));
+        if !saved && dropped_current {
+            self.switch_feed(SavedFeed::home());
+        }
+        Some(saved)
+    }
+
+    /// Switch to a different feed
+    fn switch_feed(&self, feed: SavedFeed) {
+        // Pressing the feed that is already loading is not a new request.
+        // Without this every press spawned its own thread and poll source.
+        let same_feed = self
+            .imp()
+            .current_feed
+            .borrow()
+            .as_ref()
+            .is_some_and(|current| current.uri == feed.uri);
+        if same_feed && *self.imp().feed_loading.borrow() {
+            return;
+        }
+
+        // The feed being left keeps its place for when it is picked again,
+        // and the one picked goes back to its own. It also hears what it
+        // was shown, before the next feed takes over the menus.
+        self.save_reading_position();
+        self.end_feed_feedback();
+
+        // Update current feed
+        let feed_name = feed.display_name.clone();
+        let feed_uri = feed.uri.clone();
+        self.imp().current_feed.replace(Some(feed.clone()));
+        self.imp()
+            .restored_positions
+            .borrow_mut()
+            .remove(&self.current_feed_key());
+
+        // Update UI
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_current_feed_name(&feed_name, &feed_uri);
+            window.hide_new_posts_banner();
+        }
+
+        // Clear state
+        self.imp().timeline_cursor.replace(None);
+        self.imp().newest_post_uri.replace(None);
+
+        // This switch owns the feed now. The bump strands whatever the
+        // last one has out, and releasing the guard lets the new fetch
+        // start without waiting for a response nobody wants any more.
+        self.imp().feed_generation.bump();
+        self.imp().feed_loading.replace(false);
+
+        // Fetch the new feed
+        self.fetch_current_feed();
+        if let FeedSource::Generator(uri) = self.current_feed_source() {
+            self.check_feed_feedback(uri);
+        }
+    }
+
+    /// Ask a custom feed whether it takes interactions, and start
+    /// collecting them if it does. The answer is dropped if the reader
+    /// has moved to another feed by then.
+    fn check_feed_feedback(&self, uri: String) {
+        let token = self.imp().feed_generation.token();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<FeedGenerator, String>>();
+        let client = self.client();
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_feed_generator(&uri).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(generator)) => {
+                    if generator.accepts_interactions && app.imp().feed_generation.is_current(token)
+                    {
+                        app.start_feed_feedback(generator.did, token);
+                    }
+                    glib::ControlFlow::Break
+                }
+                // Feedback is a courtesy to the feed; reading it goes on.
+                Ok(Err(e)) => {
+                    eprintln!("Failed to check feed for interactions: {}", e);
+                    app.report_session_expiry();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Offer Show More/Less on the home feed and send what piles up every
+    /// so often. The timer ends with the feed switch that owns it.
+    fn start_feed_feedback(&self, service_did: String, token: u64) {
+        self.imp().feed_feedback.replace(Some(FeedFeedback {
+            service_did,
+            queue: InteractionQueue::default(),
+        }));
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_feed_takes_interactions(true);
+        }
+
+        let app = self.clone();
+        glib::timeout_add_seconds_local(FEED_FEEDBACK_SECS, move || {
+            if !app.imp().feed_generation.is_current(token)
+                || app.imp().feed_feedback.borrow().is_none()
+            {
+                return glib::ControlFlow::Break;
+            }
+            app.send_feed_feedback(false);
+            glib::ControlFlow::Continue
+        });
+    }
+
+    /// Send what the feed being left has not heard yet, and stop offering
+    /// feedback to it.
+    fn end_feed_feedback(&self) {
+        self.send_feed_feedback(false);
+        self.imp().feed_feedback.replace(None);
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_feed_takes_interactions(false);
+        }
+    }
+
+    /// Queue what the reader did with a post in the home feed. Asking for
+    /// more or less goes out at once; sightings wait for a full batch or
+    /// the timer.
+    fn record_feed_interaction(&self, post: Post, event: InteractionEvent) {
+        let send_now = {
+            let mut feedback = self.imp().feed_feedback.borrow_mut();
+            let Some(feedback) = feedback.as_mut() else {
+                return;
+            };
+            feedback.queue.record(&post, event) && (event.is_explicit() || feedback.queue.is_full())
+        };
+        if send_now {
+            self.send_feed_feedback(event.is_explicit());
+        }
+    }
+
+    /// Send the queued interactions to the feed. `confirm` toasts the
+    /// outcome, for the reader who asked for more or less. A failed batch
+    /// of sightings is let go; the feed only uses them as hints.
+    fn send_feed_feedback(&self, confirm: bool) {
+        let (service_did, batch) = {
+            let mut feedback = self.imp().feed_feedback.borrow_mut();
+            let Some(feedback) = feedback.as_mut() else {
+                return;
+            };
+            (feedback.service_did.clone(), feedback.queue.take())
+        };
+        if batch.is_empty() {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+        let client = self.client();
+        thread::spawn(move || {
+            let result =
+                runtime::block_on(async { client.send_interactions(&service_did, &batch).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(())) => {
+                    if confirm && let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Feedback sent to the feed");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to send feed interactions: {}", e);
+                    app.report_session_expiry();
+                    if confirm {
+                        app.toast_unless_offline("Couldn't send feedback to the feed");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Fetch posts for the current feed (home timeline or custom feed).
+    ///
+    /// One fetch at a time, and it carries the switch it belongs to: a
+    /// response for a feed the user has already moved past is dropped
+    /// rather than installed over the one they are looking at.
+    fn fetch_current_feed(&self) {
+        if *self.imp().feed_loading.borrow() {
+            return;
+        }
+        self.imp().feed_loading.replace(true);
+        let generation = self.imp().feed_generation.token();
+
+        let feed_key = self.current_feed_key();
+        let feed_source = self.current_feed_source();
+        let chronological = feed_source.is_chronological();
+
+        // Cache first for instant display; fresh data follows
+        self.paint_cached_feed(&feed_key);
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+        let client = self.client();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { feed_source.page(&client, None).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        let feed_key_for_cache = feed_key.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            // Another feed was picked while this one was out. Stop here
+            // rather than poll on for a response that cannot be used; the
+            // switch already handed the guard to the new fetch.
+            if !app.imp().feed_generation.is_current(generation) {
+                return glib::ControlFlow::Break;
+            }
+            match rx.try_recv() {
+                Ok(Ok((posts, next_cursor))) => {
+                    app.imp().feed_loading.replace(false);
+                    app.merge_fresh_feed(&feed_key_for_cache, posts, next_cursor, chronological);
+                    app.start_new_posts_polling();
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    app.imp().feed_loading.replace(false);
+                    eprintln!("Failed to fetch feed: {}", e);
+                    app.toast_unless_offline("Couldn't load this feed");
+                    app.report_session_expiry();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    app.imp().feed_loading.replace(false);
+                    eprintln!("Failed to fetch feed: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Open the thread view for a post
+    fn open_thread_view(&self, post: Post) {
+        // A thread read before opens from the cache straight away, offline
+        // included, and the fetch below swaps in the live copy.
+        let cache_key = thread_key(&post.uri);
+        let (cached, _) = self.cached_view(&cache_key);
+        let painted = !cached.is_empty()
+            && self
+                .imp()
+                .window
+                .borrow()
+                .as_ref()
+                .is_some_and(|window| window.push_thread_page(&post, cached));
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<Post>, String>>();
+        let client = self.client();
+        let post_uri = post.uri.clone();
+        let main_post = post.clone();
+        let account_token = self.imp().account_generation.token();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_thread(&post_uri).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok(posts)) => {
+                    app.cache_view(&cache_key, &posts, None);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        if painted {
+                            window.refresh_thread_page(&main_post, posts);
+                        } else {
+                            window.push_thread_page(&main_post, posts);
+                        }
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch thread: {}", e);
+                    // The cached copy is on screen; it stands.
+                    if painted {
+                        return glib::ControlFlow::Break;
+                    }
+                    app.toast_unless_offline("Couldn't load the thread");
+                    // Clicking a quote of a deleted post lands here. Silence
+                    // read as a dead click.
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Couldn't load this post");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch thread: connection lost");
+                    if painted {
+                        return glib::ControlFlow::Break;
+                    }
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Couldn't load this post");
+                    }
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Open a hashtag's page (e.g., from a #hashtag click) and load its
+    /// opening tab.
+    fn open_hashtag_view(&self, tag: String) {
+        let ctx = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .and_then(|window| window.push_hashtag_page(&tag));
+        if let Some(ctx) = ctx {
+            self.fetch_hashtag_tab(ctx, true);
+        }
+    }
+
+    /// Open the profile view for a user by their handle or DID (e.g., from
+    /// an @mention click)
+    fn open_profile_by_handle(&self, handle: String) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Profile, String>>();
+        let client = self.client();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_profile(&handle).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(profile)) => {
+                    // Now open the profile view with the resolved profile
+                    app.open_profile_view(profile);
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch profile for handle: {}", e);
+                    app.toast_unless_offline("Couldn't open the profile");
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch profile: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Open the profile view for a user
+    fn open_profile_view(&self, profile: Profile) {
+        // A profile visited before opens from the cache, offline included,
+        // with its posts as they were last read; the fetch below swaps in
+        // the live page. The cached profile wins over the passed one only
+        // when it has the bio and counts an avatar click lacks.
+        let cache_key = author_key(&profile.did, "posts_and_author_threads");
+        let (cached_posts, cached_cursor) = self.cached_view(&cache_key);
+        let mut painted = false;
+        if !cached_posts.is_empty() {
+            let shown = self
+                .imp()
+                .cache
+                .borrow()
+                .as_ref()
+                .and_then(|cache| ProfileCache::new(cache).get(&profile.did).ok())
+                .filter(|cached| cached.followers_count.is_some())
+                .unwrap_or_else(|| profile.clone());
+            if let Some(window) = self.imp().window.borrow().as_ref() {
+                painted = window.push_profile_page(&shown, cached_posts, cached_cursor);
+            }
+        }
+
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Profile, Vec<Post>, Option<String>), String>>();
+        let client = self.client();
+        let actor = profile.did.clone();
+        let account_token = self.imp().account_generation.token();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                let feed = client
+                    .get_author_feed(&actor, None, Some("posts_and_author_threads"))
+                    .await;
+                // Avatar clicks arrive with the post author, a minimal
+                // profile missing its bio, and search results come without
+                // counts. Fetch the full one so the pushed page can show
+                // both; if that fails, push what we had.
+                let full_profile =
+                    if profile.description.is_none() || profile.followers_count.is_none() {
+                        client.get_profile(&actor).await.ok()
+                    } else {
+                        None
+                    };
+                feed.map(|(posts, cursor)| (full_profile.unwrap_or(profile), posts, cursor))
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((profile, posts, cursor))) => {
+                    app.cache_view(&cache_key, &posts, cursor.clone());
+                    if profile.followers_count.is_some()
+                        && let Some(cache) = app.imp().cache.borrow().as_ref()
+                    {
+                        let _ = ProfileCache::new(cache).store_full(&profile);
+                    }
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        if painted {
+                            window.refresh_profile_page(&profile, posts, cursor);
+                        } else {
+                            window.push_profile_page(&profile, posts, cursor);
+                        }
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch profile feed: {}", e);
+                    // The cached page is on screen; it stands.
+                    if !painted {
+                        app.toast_unless_offline("Couldn't open the profile");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch profile feed: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Open the followers or following list for a profile
+    fn open_follow_list(&self, profile: Profile, kind: FollowListKind) {
+        let pushed = {
+            let window = self.imp().window.borrow();
+            let Some(window) = window.as_ref() else {
+                return;
+            };
+            window.push_follow_list_page(&profile, kind)
+        };
+        let Some(pushed) = pushed else {
+            return;
+        };
+
+        let page = match pushed {
+            FollowListPush::Pushed(page) => {
+                let app = self.clone();
+                let did = profile.did.clone();
+                let page_weak = page.downgrade();
+                page.set_load_more_callback(move || {
+                    let Some(page) = page_weak.upgrade() else {
+                        return;
+                    };
+                    // No cursor means either the first page is still in
+                    // flight or the server said the list is done.
+                    let Some(cursor) = page.cursor() else {
+                        return;
+                    };
+                    app.fetch_follow_list(&page, did.clone(), kind, Some(cursor));
+                });
+
+                // Retry resumes from the stored cursor: a failed first page
+                // starts over, a failed later page picks up where it was.
+                let app = self.clone();
+                let did = profile.did.clone();
+                let page_weak = page.downgrade();
+                page.set_retry_callback(move || {
+                    let Some(page) = page_weak.upgrade() else {
+                        return;
+                    };
+                    app.fetch_follow_list(&page, did.clone(), kind, page.cursor());
+                });
+
+                page
+            }
+            FollowListPush::PoppedBack(page) => {
+                // A healthy page keeps what it has. One whose first load
+                // failed gets another try on reopen.
+                if !page.needs_reload() {
+                    return;
+                }
+                page
+            }
+        };
+
+        self.fetch_follow_list(&page, profile.did.clone(), kind, page.cursor());
+    }
+
+    /// Fetch one page of a follow list and hand it to the pushed page.
+    ///
+    /// The page owns its cursor and in-flight flag; stacked lists must not
+    /// share state, and a popped page takes its fetch bookkeeping with it.
+    fn fetch_follow_list(
+        &self,
+        page: &FollowListPage,
+        did: String,
+        kind: FollowListKind,
+        cursor: Option<String>,
+    ) {
+        if page.is_fetching() {
+            return;
+        }
+        page.set_fetching(true);
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Profile>, Option<String>), String>>();
+        let client = self.client();
+        let semaphore = API_SEMAPHORE.clone();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                let _permit = semaphore.acquire().await;
+                match kind {
+                    FollowListKind::Followers => {
+                        client.get_followers(&did, cursor.as_deref()).await
+                    }
+                    FollowListKind::Following => client.get_follows(&did, cursor.as_deref()).await,
+                }
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        let page_weak = page.downgrade();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok((profiles, next_cursor))) => {
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_cursor(next_cursor);
+                        page.append_profiles(profiles);
+                        page.set_fetching(false);
+                        // Filtered or short pages can leave the viewport
+                        // unfilled with a cursor still stored.
+                        page.backfill_if_short();
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch follow list: {}", e);
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_fetching(false);
+                        page.show_load_failed();
+                    }
+                    app.report_session_expiry();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch follow list: connection lost");
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_fetching(false);
+                        page.show_load_failed();
+                    }
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Open the Lists section, loading the user's lists on first visit
+    /// or after a failed one.
+    fn open_lists_view(&self) {
+        let page = {
+            let window = self.imp().window.borrow();
+            let Some(window) = window.as_ref() else {
+                return;
+            };
+            window.show_lists_page();
+            window.own_lists_page()
+        };
+        let Some(page) = page else {
+            return;
+        };
+        let Some(did) = self.imp().user_did.borrow().clone() else {
+            return;
+        };
+        self.wire_lists_paging(&page, did.clone());
+        if page.needs_reload() {
+            self.fetch_lists(&page, did, None);
+        }
+    }
+
+    /// Open the lists an account made, from their profile.
+    fn open_lists_for(&self, profile: Profile) {
+        let page = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .and_then(|window| window.push_lists_page(&profile));
+        let Some(page) = page else {
+            return;
+        };
+        self.wire_lists_paging(&page, profile.did.clone());
+        self.fetch_lists(&page, profile.did, None);
+    }
+
+    /// Load-more and retry for a page of lists, as on the follow lists.
+    fn wire_lists_paging(&self, page: &ListsPage, did: String) {
+        let app = self.clone();
+        let actor = did.clone();
+        let page_weak = page.downgrade();
+        page.set_load_more_callback(move || {
+            let Some(page) = page_weak.upgrade() else {
+                return;
+            };
+            let Some(cursor) = page.cursor() else {
+                return;
+            };
+            app.fetch_lists(&page, actor.clone(), Some(cursor));
+        });
+
+        let app = self.clone();
+        let page_weak = page.downgrade();
+        page.set_retry_callback(move || {
+            let Some(page) = page_weak.upgrade() else {
+                return;
+            };
+            app.fetch_lists(&page, did.clone(), page.cursor());
+        });
+    }
+
+    /// Fetch one page of an account's lists into `page`, which keeps its
+    /// own cursor and in-flight flag.
+    fn fetch_lists(&self, page: &ListsPage, did: String, cursor: Option<String>) {
+        if page.is_fetching() {
+            return;
+        }
+        page.set_fetching(true);
+
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<UserList>, Option<String>), String>>();
+        let client = self.client();
+        let account_token = self.imp().account_generation.token();
+        thread::spawn(move || {
+            let result =
+                runtime::block_on(async { client.get_lists(&did, cursor.as_deref()).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        let page_weak = page.downgrade();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((lists, next_cursor))) => {
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_cursor(next_cursor);
+                        page.append_lists(lists);
+                        page.set_fetching(false);
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch lists: {}", e);
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_fetching(false);
+                        page.show_load_failed();
+                    }
+                    app.report_session_expiry();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_fetching(false);
+                        page.show_load_failed();
+                    }
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Start the user's lists over, after one was made or deleted.
+    fn reload_own_lists(&self) {
+        let page = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .and_then(|window| window.own_lists_page());
+        let Some(page) = page else {
+            return;
+        };
+        let Some(did) = self.imp().user_did.borrow().clone() else {
+            return;
+        };
+        page.clear();
+        self.fetch_lists(&page, did, None);
+    }
+
+    /// Open a list's page and load its opening tab.
+    fn open_list(&self, list: UserList) {
+        let ctx = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .and_then(|window| window.push_list_page(&list));
+        if let Some(ctx) = ctx {
+            self.fetch_list_tab(ctx, true);
+        }
+    }
+
+    /// One page of a list's Posts or People tab. The People tab's answer
+    /// carries the list itself too, which refreshes the header.
+    fn fetch_list_tab(&self, ctx: std::rc::Rc<crate::ui::ListFeedCtx>, first_page: bool) {
+        if ctx.fetching.get() {
+            return;
+        }
+        ctx.fetching.set(true);
+        let generation = ctx.generation.get();
+        let tab = ctx.tab.get();
+        let uri = ctx.list().uri;
+        let cursor = if first_page {
+            None
+        } else {
+            ctx.cursor.borrow().clone()
+        };
+
+        enum Page {
+            Posts(Vec<Post>),
+            People(UserList, Vec<ListMember>),
+        }
+
+        let account_token = self.imp().account_generation.token();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Page, Option<String>), String>>();
+        let client = self.client();
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                match tab {
+                    ListTab::Posts => client
+                        .get_list_feed(&uri, cursor.as_deref())
+                        .await
+                        .map(|(posts, next)| (Page::Posts(posts), next)),
+                    ListTab::People => client
+                        .get_list(&uri, cursor.as_deref())
+                        .await
+                        .map(|(list, members, next)| (Page::People(list, members), next)),
+                }
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            if ctx.generation.get() != generation {
+                return glib::ControlFlow::Break;
+            }
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    ctx.fetching.set(false);
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((page, next_cursor))) => {
+                    ctx.fetching.set(false);
+                    match page {
+                        Page::Posts(posts) => ctx.append_posts(posts),
+                        Page::People(list, members) => {
+                            ctx.set_list(list);
+                            ctx.append_members(members);
+                        }
+                    }
+                    ctx.cursor.replace(next_cursor);
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    ctx.fetching.set(false);
+                    eprintln!("Failed to fetch list: {}", e);
+                    app.report_session_expiry();
+                    app.toast_unless_offline("Couldn't load this list");
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    ctx.fetching.set(false);
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Ask for a new list's name and kind, then make it.
+    fn new_list(&self) {
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+        let app = self.clone();
+        crate::ui::list_dialog::present_editor(&window, None, move |name, description, purpose| {
+            app.create_list(name, description, purpose);
+        });
+    }
+
+    fn create_list(&self, name: String, description: String, purpose: ListPurpose) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<String, String>>();
+        let client = self.client();
+        thread::spawn(move || {
+            let result =
+                runtime::block_on(async { client.create_list(&name, &description, purpose).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(_)) => {
+                    app.reload_own_lists();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("List created");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to create list: {}", e);
+                    app.report_session_expiry();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Couldn't create the list");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Rename a list or change its description from its page. The page
+    /// shows the edit once the server has it.
+    fn edit_list(&self, ctx: std::rc::Rc<crate::ui::ListFeedCtx>) {
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+        let app = self.clone();
+        let existing = ctx.list();
+        crate::ui::list_dialog::present_editor(
+            &window,
+            Some(&existing),
+            move |name, description, _| {
+                app.update_list(ctx.clone(), name, description);
+            },
+        );
+    }
+
+    fn update_list(
+        &self,
+        ctx: std::rc::Rc<crate::ui::ListFeedCtx>,
+        name: String,
+        description: String,
+    ) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+        let client = self.client();
+        let uri = ctx.list().uri;
+        let (new_name, new_description) = (name.clone(), description.clone());
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                client.update_list(&uri, &new_name, &new_description).await
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(())) => {
+                    let mut list = ctx.list();
+                    list.name = name.clone();
+                    list.description = Some(description.clone()).filter(|d| !d.is_empty());
+                    ctx.set_list(list);
+                    // Names in the Lists section are the old ones now.
+                    app.reload_own_lists();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("List updated");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to update list: {}", e);
+                    app.report_session_expiry();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Couldn't update the list");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Ask before deleting a list; its entries go with it, and there is
+    /// no undo.
+    fn confirm_delete_list(&self, list: UserList) {
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+
+        let dialog = adw::AlertDialog::new(
+            Some(&format!("Delete {}?", list.name)),
+            Some("The list and everyone on it go for good. This can't be undone."),
+        );
+        dialog.add_response("cancel", "Cancel");
+        dialog.add_response("delete", "Delete");
+        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_close_response("cancel");
+
+        let app = self.clone();
+        dialog.connect_response(Some("delete"), move |_, _| {
+            app.delete_list(list.clone());
+        });
+        dialog.present(Some(&window));
+    }
+
+    /// Delete a list, unpinning its feed first if it was pinned so the
+    /// feed selector keeps no dead entry.
+    fn delete_list(&self, list: UserList) {
+        let pinned = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .is_some_and(|window| window.list_pinned(&list.uri));
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+        let client = self.client();
+        let uri = list.uri.clone();
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                if pinned {
+                    client.set_list_pinned(&uri, false).await?;
+                }
+                client.delete_list(&uri).await
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(())) => {
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.close_list_pages(&list.uri);
+                        window.show_toast("List deleted");
+                    }
+                    if pinned {
+                        app.after_list_unpinned(&list.uri);
+                    }
+                    app.reload_own_lists();
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to delete list: {}", e);
+                    app.report_session_expiry();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Couldn't delete the list");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Pin a list's feed to the feed selector, or unpin it. The pin lives
+    /// in the account's preferences, so it follows the account to other
+    /// clients.
+    fn toggle_pinned_list(&self, list: UserList) {
+        let pinned = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .is_some_and(|window| window.list_pinned(&list.uri));
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+        let client = self.client();
+        let uri = list.uri.clone();
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.set_list_pinned(&uri, !pinned).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(())) => {
+                    if pinned {
+                        app.after_list_unpinned(&list.uri);
+                    }
+                    app.fetch_saved_feeds();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast(&if pinned {
+                            format!("Unpinned {} from your feeds", list.name)
+                        } else {
+                            format!("Pinned {} to your feeds", list.name)
+                        });
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to pin list: {}", e);
+                    app.report_session_expiry();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Couldn't change your feeds");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// A list's feed on screen when it is unpinned or deleted gives way to
+    /// the home timeline, as a dropped saved search does.
+    fn after_list_unpinned(&self, uri: &str) {
+        let showing = self
+            .imp()
+            .current_feed
+            .borrow()
+            .as_ref()
+            .is_some_and(|feed| feed.is_list() && feed.uri == uri);
+        if showing {
+            self.switch_feed(SavedFeed::home());
+        }
+    }
+
+    /// Show which of the user's lists an account is on, and let each
+    /// check put them on or take them off.
+    fn open_add_to_lists(&self, profile: Profile) {
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<Vec<(UserList, Option<String>)>, String>>();
+        let client = self.client();
+        let did = profile.did.clone();
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.list_memberships(&did).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(memberships)) => {
+                    let Some(window) = app.imp().window.borrow().clone() else {
+                        return glib::ControlFlow::Break;
+                    };
+                    let toggler = app.clone();
+                    let did = profile.did.clone();
+                    crate::ui::list_dialog::present_memberships(
+                        &window,
+                        &profile.handle,
+                        memberships,
+                        Rc::new(move |list, cell, check| {
+                            toggler.toggle_list_member(list, did.clone(), cell, check);
+                        }),
+                    );
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch list memberships: {}", e);
+                    app.report_session_expiry();
+                    app.toast_unless_offline("Couldn't load your lists");
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Put an account on a list or take them off, whichever the entry
+    /// cell says they are not, then settle the dialog's check.
+    fn toggle_list_member(
+        &self,
+        list: UserList,
+        did: String,
+        cell: Rc<RefCell<Option<String>>>,
+        check: glib::WeakRef<gtk4::CheckButton>,
+    ) {
+        let current = cell.borrow().clone();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Option<String>, String>>();
+        let client = self.client();
+        let uri = list.uri.clone();
+        let item = current.clone();
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                match item {
+                    Some(item_uri) => client.remove_list_member(&item_uri).await.map(|()| None),
+                    None => client.add_list_member(&uri, &did).await.map(Some),
+                }
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(entry)) => {
+                    crate::ui::list_dialog::settle_membership(&cell, &check, entry);
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to change list membership: {}", e);
+                    app.report_session_expiry();
+                    crate::ui::list_dialog::settle_membership(&cell, &check, current.clone());
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast(&format!("Couldn't update {}", list.name));
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    crate::ui::list_dialog::settle_membership(&cell, &check, current.clone());
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Handle navigation item changes from the sidebar
+    fn handle_nav_change(&self, item: NavItem) {
+        match item {
+            NavItem::Home => {
+                if let Some(window) = self.imp().window.borrow().as_ref() {
+                    window.show_home_page();
+                }
+            }
+            NavItem::Mentions => {
+                self.open_mentions_view();
+            }
+            NavItem::Activity => {
+                self.open_activity_view();
+            }
+            NavItem::Chat => {
+                self.open_chat_view();
+            }
+            NavItem::Profile => {
+                self.open_own_profile_view();
+            }
+            NavItem::Likes => {
+                self.open_likes_view();
+            }
+            NavItem::Bookmarks => {
+                self.open_bookmarks_view();
+            }
+            NavItem::Search => {
+                self.open_search_view();
+            }
+            NavItem::Lists => {
+                self.open_lists_view();
+            }
+        }
+    }
+
+    /// Open the mentions view
+    fn open_mentions_view(&self) {
+        // Switch to mentions page (instant, no animation)
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.show_mentions_page();
+        }
+
+        // A lit badge means the server has mentions this list does not.
+        let stale = self.badge_count(NavItem::Mentions) > 0;
+        self.clear_notification_badges();
+
+        // Fetch on first open, or when the badge said the list moved
+        if self.imp().mentions_cursor.borrow().is_none() || stale {
+            self.fetch_mentions();
+        }
+    }
+
+    /// Fetch mentions (notifications filtered to mentions/replies/quotes)
+    fn fetch_mentions(&self) {
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<Notification>, Option<String>), String>>();
+        let client = self.client();
+        let account_token = self.imp().account_generation.token();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                client.get_notifications(None, MENTION_REASONS, false).await
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((notifications, next_cursor))) => {
+                    app.imp().mentions_cursor.replace(next_cursor);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_mentions(notifications);
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch mentions: {}", e);
+                    app.toast_unless_offline("Couldn't load mentions");
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_mentions_load_failed();
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch mentions: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
         });
+    }
 
-        // Thread callback for replies
-        let app2 = self.clone();
-        let dialog_weak2 = dialog.downgrade();
-        let reply_uri = parent_post.uri.clone();
-        let reply_cid = parent_post.cid.clone();
-        dialog.connect_thread(move |posts| {
-            if let Some(dialog) = dialog_weak2.upgrade() {
-                dialog.set_loading(true);
-                dialog.hide_error();
-            }
+    /// Fetch more mentions for infinite scroll
+    fn fetch_mentions_more(&self) {
+        if *self.imp().mentions_loading_more.borrow() {
+            return;
+        }
+        let cursor = match self.imp().mentions_cursor.borrow().as_ref() {
+            Some(c) => c.clone(),
+            None => return,
+        };
+        self.imp().mentions_loading_more.replace(true);
 
-            let app = app2.clone();
-            let dialog_weak = dialog_weak2.clone();
-            let reply_uri = reply_uri.clone();
-            let reply_cid = reply_cid.clone();
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_mentions_loading(true);
+        }
 
-            let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
-            let client = app.client();
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<Notification>, Option<String>), String>>();
+        let client = self.client();
+        let semaphore = API_SEMAPHORE.clone();
+        let account_token = self.imp().account_generation.token();
 
-            thread::spawn(move || {
-                let result = runtime::block_on(async {
-                    let reply_ref = crate::atproto::ReplyRef {
-                        root_uri: reply_uri.clone(),
-                        root_cid: reply_cid.clone(),
-                        parent_uri: reply_uri,
-                        parent_cid: reply_cid,
-                    };
-                    client.create_thread(&posts, Some(reply_ref)).await
-                });
-                let _ = tx.send(result.map(|_| ()).map_err(|e| e.to_string()));
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                let _permit = semaphore.acquire().await;
+                client
+                    .get_notifications(Some(&cursor), MENTION_REASONS, false)
+                    .await
             });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
 
-            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-                match rx.try_recv() {
-                    Ok(Ok(())) => {
-                        if let Some(dialog) = dialog_weak.upgrade() {
-                            dialog.set_loading(false);
-                            dialog.close();
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    app.imp().mentions_loading_more.replace(false);
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((notifications, next_cursor))) => {
+                    app.imp().mentions_loading_more.replace(false);
+                    app.imp().mentions_cursor.replace(next_cursor);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_mentions_loading(false);
+                        if !notifications.is_empty() {
+                            window.append_mentions(notifications);
                         }
-                        app.fetch_timeline();
-                        glib::ControlFlow::Break
                     }
-                    Ok(Err(e)) => {
-                        if let Some(dialog) = dialog_weak.upgrade() {
-                            dialog.set_loading(false);
-                            dialog.show_error(&e);
-                        }
-                        glib::ControlFlow::Break
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    app.imp().mentions_loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_mentions_loading(false);
                     }
-                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                        if let Some(dialog) = dialog_weak.upgrade() {
-                            dialog.set_loading(false);
-                        }
-                        glib::ControlFlow::Break
+                    eprintln!("Failed to fetch more mentions: {}", e);
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    app.imp().mentions_loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_mentions_loading(false);
                     }
+                    glib::ControlFlow::Break
                 }
-            });
+            }
         });
-
-        self.setup_mention_search(&dialog);
-        self.setup_video_upload(&dialog);
-        self.setup_link_card_fetch(&dialog);
-        dialog.present(Some(&window));
-        dialog.focus_composer();
     }
 
-    fn open_quote_dialog(&self, quoted_post: Post) {
-        let window = match self.imp().window.borrow().as_ref() {
-            Some(w) => w.clone(),
-            None => return,
-        };
-
-        let context = QuoteContext {
-            uri: quoted_post.uri.clone(),
-            cid: quoted_post.cid.clone(),
-            author_handle: quoted_post.author.handle.clone(),
-            text: quoted_post.text.clone(),
-        };
-
-        let dialog = ComposeDialog::new_quote(context);
+    /// Open the activity view
+    fn open_activity_view(&self) {
+        // Switch to activity page (instant, no animation)
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.show_activity_page();
+        }
 
-        let app = self.clone();
-        let dialog_weak = dialog.downgrade();
+        // Activity lists every notification, so unread under either badge
+        // means this list is behind.
+        let stale =
+            self.badge_count(NavItem::Mentions) > 0 || self.badge_count(NavItem::Activity) > 0;
+        self.clear_notification_badges();
 
-        dialog.connect_quote(move |data, quoted_uri, quoted_cid| {
-            if let Some(dialog) = dialog_weak.upgrade() {
-                dialog.set_loading(true);
-                dialog.hide_error();
-            }
+        // Fetch on first open, or when the badge said the list moved
+        if self.imp().activity_cursor.borrow().is_none() || stale {
+            self.fetch_activity();
+        }
+    }
 
-            let app = app.clone();
-            let dialog_weak = dialog_weak.clone();
+    /// Fetch activity: every notification, or the reasons of the window's
+    /// filter chip, from everyone or only followed accounts
+    fn fetch_activity(&self) {
+        // A new filter or mode owns the list: pages still in flight, and
+        // the cursor they would continue, belong to the old one.
+        self.imp().activity_generation.bump();
+        let generation = self.imp().activity_generation.token();
+        let account_token = self.imp().account_generation.token();
+        self.imp().activity_loading_more.replace(false);
+        self.imp().activity_cursor.replace(None);
 
-            let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
-            let client = app.client();
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<Notification>, Option<String>), String>>();
+        let client = self.client();
+        let reasons = self.activity_reasons();
+        let priority = crate::state::AppSettings::load().activity_priority;
 
-            thread::spawn(move || {
-                let result = runtime::block_on(async {
-                    client
-                        .create_post_with_data(&data, None, Some((&quoted_uri, &quoted_cid)))
-                        .await
-                });
-                let _ = tx.send(result.map(|_| ()).map_err(|e| e.to_string()));
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                client.get_notifications(None, reasons, priority).await
             });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
 
-            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-                match rx.try_recv() {
-                    Ok(Ok(())) => {
-                        if let Some(dialog) = dialog_weak.upgrade() {
-                            dialog.set_loading(false);
-                            dialog.close();
-                        }
-                        app.fetch_timeline();
-                        glib::ControlFlow::Break
-                    }
-                    Ok(Err(e)) => {
-                        if let Some(dialog) = dialog_weak.upgrade() {
-                            dialog.set_loading(false);
-                            dialog.show_error(&e);
-                        }
-                        glib::ControlFlow::Break
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            if !app.imp().activity_generation.is_current(generation)
+                || !app.imp().account_generation.is_current(account_token)
+            {
+                return glib::ControlFlow::Break;
+            }
+            match rx.try_recv() {
+                Ok(Ok((notifications, next_cursor))) => {
+                    app.imp().activity_cursor.replace(next_cursor);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_activity(notifications);
                     }
-                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                        if let Some(dialog) = dialog_weak.upgrade() {
-                            dialog.set_loading(false);
-                        }
-                        glib::ControlFlow::Break
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch activity: {}", e);
+                    app.toast_unless_offline("Couldn't load activity");
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_activity_load_failed();
                     }
+                    glib::ControlFlow::Break
                 }
-            });
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch activity: connection lost");
+                    glib::ControlFlow::Break
+                }
+            }
         });
+    }
 
-        // Thread callback for quotes
-        let app2 = self.clone();
-        let dialog_weak2 = dialog.downgrade();
-        dialog.connect_thread(move |posts| {
-            if let Some(dialog) = dialog_weak2.upgrade() {
-                dialog.set_loading(true);
-                dialog.hide_error();
-            }
+    /// The `reasons` the window's Activity filter asks for.
+    fn activity_reasons(&self) -> &'static [&'static str] {
+        self.imp()
+            .window
+            .borrow()
+            .as_ref()
+            .map_or(&[], |window| window.activity_filter().reasons())
+    }
 
-            let app = app2.clone();
-            let dialog_weak = dialog_weak2.clone();
+    /// Fetch more activity for infinite scroll
+    fn fetch_activity_more(&self) {
+        if *self.imp().activity_loading_more.borrow() {
+            return;
+        }
+        let cursor = match self.imp().activity_cursor.borrow().as_ref() {
+            Some(c) => c.clone(),
+            None => return,
+        };
+        self.imp().activity_loading_more.replace(true);
 
-            let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
-            let client = app.client();
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_activity_loading(true);
+        }
 
-            thread::spawn(move || {
-                let result = runtime::block_on(async { client.create_thread(&posts, None).await });
-                let _ = tx.send(result.map(|_| ()).map_err(|e| e.to_string()));
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<Notification>, Option<String>), String>>();
+        let client = self.client();
+        let semaphore = API_SEMAPHORE.clone();
+        let generation = self.imp().activity_generation.token();
+        let account_token = self.imp().account_generation.token();
+        let reasons = self.activity_reasons();
+        let priority = crate::state::AppSettings::load().activity_priority;
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                let _permit = semaphore.acquire().await;
+                client
+                    .get_notifications(Some(&cursor), reasons, priority)
+                    .await
             });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
 
-            glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-                match rx.try_recv() {
-                    Ok(Ok(())) => {
-                        if let Some(dialog) = dialog_weak.upgrade() {
-                            dialog.set_loading(false);
-                            dialog.close();
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            if !app.imp().activity_generation.is_current(generation) {
+                // The reload reset the loading flags already.
+                return glib::ControlFlow::Break;
+            }
+            if !app.imp().account_generation.is_current(account_token) {
+                app.imp().activity_loading_more.replace(false);
+                return glib::ControlFlow::Break;
+            }
+            match rx.try_recv() {
+                Ok(Ok((notifications, next_cursor))) => {
+                    app.imp().activity_loading_more.replace(false);
+                    app.imp().activity_cursor.replace(next_cursor);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_activity_loading(false);
+                        if !notifications.is_empty() {
+                            window.append_activity(notifications);
                         }
-                        app.fetch_timeline();
-                        glib::ControlFlow::Break
                     }
-                    Ok(Err(e)) => {
-                        if let Some(dialog) = dialog_weak.upgrade() {
-                            dialog.set_loading(false);
-                            dialog.show_error(&e);
-                        }
-                        glib::ControlFlow::Break
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    app.imp().activity_loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_activity_loading(false);
                     }
-                    Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                        if let Some(dialog) = dialog_weak.upgrade() {
-                            dialog.set_loading(false);
-                        }
-                        glib::ControlFlow::Break
+                    eprintln!("Failed to fetch more activity: {}", e);
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    app.imp().activity_loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_activity_loading(false);
                     }
+                    glib::ControlFlow::Break
                 }
-            });
+            }
         });
+    }
 
-        self.setup_mention_search(&dialog);
-        self.setup_video_upload(&dialog);
-        self.setup_link_card_fetch(&dialog);
-        dialog.present(Some(&window));
-        dialog.focus_composer();
+    /// Open the chat view
+    fn open_chat_view(&self) {
+        // Switch to chat page (instant, no animation)
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.show_chat_page();
+        }
+
+        // The chat badge stays lit until a conversation is actually read;
+        // clearing it from the list would claim messages nobody has seen.
+        // The list itself still refreshes when the badge says it moved.
+        let stale = self.badge_count(NavItem::Chat) > 0;
+        if self.imp().chat_cursor.borrow().is_none() || stale {
+            self.fetch_conversations();
+        }
     }
 
-    fn toggle_repost(&self, post: &Post, post_row_weak: glib::WeakRef<PostRow>) {
-        // Result type: Ok(Some(uri)) for repost success, Ok(None) for unrepost success, Err for failure
-        let (tx, rx) = std::sync::mpsc::channel::<Result<Option<String>, String>>();
+    /// Fetch conversations
+    fn fetch_conversations(&self) {
+        let account_token = self.imp().account_generation.token();
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<Conversation>, Option<String>), String>>();
         let client = self.client();
 
-        // What the row showed before it flipped itself, for the revert.
-        let was_reposted = post.viewer_repost.is_some();
-        let previous_uri = post.viewer_repost.clone();
-        let post_uri = post.uri.clone();
-
-        // If already reposted, this press deletes the repost.
-        if let Some(repost_uri) = &post.viewer_repost {
-            let repost_uri = repost_uri.clone();
-            thread::spawn(move || {
-                let result = runtime::block_on(async { client.delete_repost(&repost_uri).await });
-                let _ = tx.send(result.map(|_| None).map_err(|e| e.to_string()));
-            });
-        } else {
-            // Not reposted yet, create repost
-            let uri = post.uri.clone();
-            let cid = post.cid.clone();
-            thread::spawn(move || {
-                let result = runtime::block_on(async { client.repost(&uri, &cid).await });
-                let _ = tx.send(result.map(Some).map_err(|e| e.to_string()));
-            });
-        }
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_conversations(None).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok(new_repost_uri)) => {
-                    // Guarded like the revert path. Landing a cleared URI on
-                    // a rebound row is the loud failure: the next press on
-                    // that post publishes a repost instead of undoing one.
-                    if let Some(post_row) = post_row_weak.upgrade()
-                        && post_row.post_uri().as_deref() == Some(post_uri.as_str())
-                    {
-                        post_row.set_viewer_repost_uri(new_repost_uri);
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((conversations, next_cursor))) => {
+                    app.imp().chat_cursor.replace(next_cursor);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_conversations(conversations);
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Repost/unrepost failed: {}", e);
-                    if let Some(post_row) = post_row_weak.upgrade() {
-                        Self::revert_repost(&post_row, &post_uri, previous_uri.clone());
-                    }
-                    app.report_session_expiry();
+                    eprintln!("Failed to fetch conversations: {}", e);
+                    app.toast_unless_offline("Couldn't load conversations");
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast(if was_reposted {
-                            "Couldn't undo your repost"
-                        } else {
-                            "Couldn't repost"
-                        });
+                        window.set_conversations_load_failed();
                     }
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    eprintln!("Failed to fetch conversations: connection lost");
+                    glib::ControlFlow::Break
+                }
             }
         });
     }
 
-    pub fn fetch_timeline_more(&self) {
-        if *self.imp().loading_more.borrow() {
+    /// Fetch more conversations for infinite scroll
+    fn fetch_chat_more(&self) {
+        if *self.imp().chat_loading_more.borrow() {
             return;
         }
-        let cursor = match self.imp().timeline_cursor.borrow().as_ref() {
+        let cursor = match self.imp().chat_cursor.borrow().as_ref() {
             Some(c) => c.clone(),
             None => return,
         };
-        self.imp().loading_more.replace(true);
+        self.imp().chat_loading_more.replace(true);
 
-        // Show loading spinner
         if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.set_loading_more(true);
+            window.set_chat_loading(true);
         }
 
-        // Get current feed URI if not home
-        let current_feed = self.imp().current_feed.borrow().clone();
-        let feed_uri = current_feed.as_ref().and_then(|f| {
-            if f.is_home() {
-                None
-            } else {
-                Some(f.uri.clone())
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<Conversation>, Option<String>), String>>();
+        let client = self.client();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_conversations(Some(&cursor)).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok((conversations, next_cursor))) => {
+                    app.imp().chat_loading_more.replace(false);
+                    app.imp().chat_cursor.replace(next_cursor);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_chat_loading(false);
+                        if !conversations.is_empty() {
+                            window.append_conversations(conversations);
+                        }
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    app.imp().chat_loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_chat_loading(false);
+                    }
+                    eprintln!("Failed to fetch more conversations: {}", e);
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    app.imp().chat_loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_chat_loading(false);
+                    }
+                    glib::ControlFlow::Break
+                }
+            }
+        });
+    }
+
+    /// Open a conversation in the message view, pushed onto the chat stack.
+    /// Message someone, from a profile button or the New Message picker.
+    /// The server decides whether messaging is allowed; DMs off, blocks,
+    /// and account restrictions all come back as a plain no, so the toast
+    /// says so without guessing the reason. The button, when there is one,
+    /// is already locked by the click.
+    fn open_direct_message(&self, profile: Profile, btn_weak: Option<glib::WeakRef<gtk4::Button>>) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Option<Conversation>, String>>();
+        let client = self.client();
+        let did = profile.did.clone();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.start_conversation(&did).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            let btn = btn_weak.as_ref().and_then(|weak| weak.upgrade());
+            match rx.try_recv() {
+                Ok(Ok(Some(conversation))) => {
+                    if let Some(btn) = btn {
+                        btn.set_sensitive(true);
+                    }
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        // The message page lives on the chat stack; go there
+                        // with the rail highlight in tow.
+                        window.select_nav(NavItem::Chat);
+                        window.switch_to_page("chat");
+                    }
+                    app.open_conversation_view(conversation);
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok(None)) => {
+                    // Stays locked: asking again will not change the answer.
+                    if let Some(btn) = btn {
+                        btn.set_tooltip_text(Some("This account can't receive your messages"));
+                    }
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("This account can't receive your messages");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to check messaging: {}", e);
+                    app.report_session_expiry();
+                    if let Some(btn) = btn {
+                        btn.set_sensitive(true);
+                    }
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Couldn't open messaging");
+                    }
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
+    }
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+    /// Typeahead for the New Message picker. The generation strands slow
+    /// answers so a fast typist never sees results for an older query.
+    fn search_message_targets(&self, query: String) {
+        self.imp().message_target_generation.bump();
+        let generation = self.imp().message_target_generation.token();
+
+        if query.trim().is_empty() {
+            if let Some(window) = self.imp().window.borrow().as_ref() {
+                window.set_new_message_results(vec![]);
+            }
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<Profile>, String>>();
         let client = self.client();
-        let semaphore = API_SEMAPHORE.clone();
         thread::spawn(move || {
-            let result = runtime::block_on(async {
-                // Acquire permit to limit concurrent API requests
-                let _permit = semaphore.acquire().await;
-                match feed_uri {
-                    Some(uri) => client.get_feed(&uri, Some(&cursor)).await,
-                    None => client.get_timeline(Some(&cursor)).await,
-                }
-            });
+            let result =
+                runtime::block_on(async { client.search_actors_typeahead(&query, 8).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            if !app.imp().message_target_generation.is_current(generation) {
+                return glib::ControlFlow::Break;
+            }
             match rx.try_recv() {
-                Ok(Ok((posts, next_cursor))) => {
-                    app.imp().loading_more.replace(false);
-                    app.imp().timeline_cursor.replace(next_cursor.clone());
-
-                    // Store in cache (append to existing feed)
-                    if let Some(cache) = app.imp().cache.borrow().as_ref() {
-                        let current_feed = app.imp().current_feed.borrow();
-                        let feed_key = current_feed
-                            .as_ref()
-                            .map(|f| {
-                                if f.is_home() {
-                                    "home".to_string()
-                                } else {
-                                    f.uri.clone()
-                                }
-                            })
-                            .unwrap_or_else(|| "home".to_string());
-                        drop(current_feed);
-
-                        let post_cache = PostCache::new(cache);
-                        let feed_cache = FeedCache::new(cache);
-                        let start_pos = feed_cache.count(&feed_key).unwrap_or(0) as i64;
-                        let _ = post_cache.store_batch(&posts);
-                        let _ = feed_cache.store_page(&feed_key, &posts, start_pos);
-                        // Update cursor in feed state
-                        if let Ok(mut state) = feed_cache.get_state(&feed_key) {
-                            state.oldest_cursor = next_cursor;
-                            state.has_more = state.oldest_cursor.is_some();
-                            let _ = feed_cache.set_state(&feed_key, &state);
-                        }
-                    }
-
+                Ok(Ok(profiles)) => {
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_loading_more(false);
-                        if !posts.is_empty() {
-                            window.append_posts(posts);
-                        }
+                        window.set_new_message_results(profiles);
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    app.imp().loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_loading_more(false);
-                    }
-                    eprintln!("Failed to fetch more timeline: {}", e);
-                    app.toast_unless_offline("Couldn't load more posts");
+                    eprintln!("Failed to search people: {}", e);
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    app.imp().loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_loading_more(false);
-                    }
-                    glib::ControlFlow::Break
-                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    fn start_new_posts_polling(&self) {
-        // Only start polling once
-        if *self.imp().polling_started.borrow() {
+    fn open_conversation_view(&self, conversation: Conversation) {
+        let pushed = {
+            let window = self.imp().window.borrow();
+            let Some(window) = window.as_ref() else {
+                return;
+            };
+            window.push_message_page(&conversation)
+        };
+        let Some(pushed) = pushed else {
             return;
-        }
-        self.imp().polling_started.replace(true);
+        };
 
-        let app = self.clone();
-        // One timer drives every badge: new posts and unread counts alike
-        glib::timeout_add_seconds_local(30, move || {
-            app.check_for_new_posts();
-            app.check_unread_counts();
-            glib::ControlFlow::Continue
-        });
-    }
+        let page = match pushed {
+            MessagePush::Pushed(page) => {
+                let app = self.clone();
+                let page_weak = page.downgrade();
+                page.set_load_older_callback(move || {
+                    let Some(page) = page_weak.upgrade() else {
+                        return;
+                    };
+                    app.fetch_older_messages(&page);
+                });
 
-    fn check_for_new_posts(&self) {
-        // Don't check if we're already checking
-        if *self.imp().checking_new_posts.borrow() {
-            return;
-        }
+                // Retry redoes the first load; only a page with nothing
+                // listed ever shows the button.
+                let app = self.clone();
+                let page_weak = page.downgrade();
+                page.set_retry_callback(move || {
+                    let Some(page) = page_weak.upgrade() else {
+                        return;
+                    };
+                    app.fetch_first_messages(&page);
+                });
 
-        // A dead session would fail here every 30 seconds; wait for the
-        // next sign-in instead.
-        if *self.imp().session_dead.borrow() {
-            return;
-        }
+                let app = self.clone();
+                let page_weak = page.downgrade();
+                page.set_send_callback(move |text| {
+                    let Some(page) = page_weak.upgrade() else {
+                        return;
+                    };
+                    app.send_conversation_message(&page, text);
+                });
 
-        // Offline says why every request would fail; the banner covers it.
-        if *self.imp().offline.borrow() {
-            return;
-        }
+                let app = self.clone();
+                let page_weak = page.downgrade();
+                page.set_react_callback(move |message_id, value, add| {
+                    let Some(page) = page_weak.upgrade() else {
+                        return;
+                    };
+                    app.toggle_message_reaction(&page, message_id, value, add);
+                });
 
-        // A backgrounded window would prepend into a feed nobody is
-        // reading, every 30 seconds, for as long as it sits there; the
-        // model and the image caches grow with every pass and the kernel
-        // eventually ends it. Catch up on focus instead.
-        let window_active = self
-            .imp()
-            .window
-            .borrow()
-            .as_ref()
-            .is_some_and(|w| w.is_active());
-        if !window_active {
-            return;
-        }
+                let app = self.clone();
+                let page_weak = page.downgrade();
+                page.set_delete_message_callback(move |message_id| {
+                    let Some(page) = page_weak.upgrade() else {
+                        return;
+                    };
+                    app.confirm_delete_message(&page, message_id);
+                });
 
-        // Only check if we have an anchor
-        let anchor_uri = match self.imp().newest_post_uri.borrow().clone() {
-            Some(uri) => uri,
-            None => return,
+                self.start_message_poll(&page);
+                page
+            }
+            MessagePush::PoppedBack(page) => {
+                // A healthy page keeps what it has, and its poll is still
+                // running. One whose first load failed gets another try.
+                if !page.needs_reload() {
+                    return;
+                }
+                page
+            }
         };
 
-        self.imp().checking_new_posts.replace(true);
+        self.fetch_first_messages(&page);
+    }
 
-        // Get current feed URI if not home
-        let current_feed = self.imp().current_feed.borrow().clone();
-        let feed_uri = current_feed.as_ref().and_then(|f| {
-            if f.is_home() {
-                None
-            } else {
-                Some(f.uri.clone())
-            }
-        });
+    /// Fetch the newest page of a conversation's history.
+    ///
+    /// The page is its own generation token: results apply through a weak
+    /// ref, so a popped page drops whatever was still in flight for it.
+    fn fetch_first_messages(&self, page: &MessagePage) {
+        if page.is_fetching() {
+            return;
+        }
+        page.set_fetching(true);
+        let convo_id = page.convo_id();
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<ChatMessage>, Option<String>), String>>();
         let client = self.client();
+        let semaphore = API_SEMAPHORE.clone();
 
         thread::spawn(move || {
             let result = runtime::block_on(async {
-                match feed_uri {
-                    Some(uri) => client.get_feed(&uri, None).await,
-                    None => client.get_timeline(None).await,
-                }
+                let _permit = semaphore.acquire().await;
+                client.get_messages(&convo_id, None).await
             });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
+        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((posts, _))) => {
-                    app.imp().checking_new_posts.replace(false);
-                    app.imp().new_posts_poll_failed.replace(false);
-
-                    // Find posts newer than our anchor
-                    let new_posts: Vec<Post> = posts
-                        .into_iter()
-                        .take_while(|p| p.uri != anchor_uri)
-                        .collect();
-
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        // Always refresh timestamps on poll
-                        window.refresh_timestamps();
-
-                        if !new_posts.is_empty() {
-                            // Update anchor to newest post
-                            if let Some(newest) = new_posts.first() {
-                                app.imp().newest_post_uri.replace(Some(newest.uri.clone()));
-                            }
-
-                            // Insert new posts at the top of the timeline
-                            // User can scroll up to see them
-                            let inserted = window.insert_posts_at_top(new_posts);
-
-                            // Count what actually landed: a reordered feed
-                            // re-serves posts already on screen.
-                            if inserted > 0 {
-                                window.show_new_posts_banner(inserted);
-                            }
-                        }
+                Ok(Ok((messages, cursor))) => {
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_cursor(cursor);
+                        page.set_initial_messages(messages);
+                        page.set_fetching(false);
+                        page.backfill_if_short();
+                        // The conversation is on screen now, so the server
+                        // can honestly call it read.
+                        app.mark_conversation_read(page.convo_id());
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    app.imp().checking_new_posts.replace(false);
-                    // A flaky upstream fails poll after poll; say so once.
-                    if !*app.imp().new_posts_poll_failed.borrow() {
-                        app.imp().new_posts_poll_failed.replace(true);
-                        eprintln!("Failed to check for new posts: {}", e);
+                    eprintln!("Failed to fetch messages: {}", e);
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_fetching(false);
+                        page.show_load_failed();
                     }
-                    // The poll is the only thing talking to the server on an
-                    // idle window, so a session dying mid-use shows up here
-                    // first.
                     app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    app.imp().checking_new_posts.replace(false);
+                    eprintln!("Failed to fetch messages: connection lost");
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_fetching(false);
+                        page.show_load_failed();
+                    }
                     glib::ControlFlow::Break
                 }
             }
-        });
-    }
-
-    fn show_new_posts(&self) {
-        // The posts are already in the model. Scroll to top to reveal them.
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.hide_new_posts_banner();
-            window.scroll_to_top();
-        }
+        });
     }
 
-    /// Refresh the Mentions, Activity, and Chat badges from the server.
-    ///
-    /// Rides the same 30-second timer as `check_for_new_posts`: one thread,
-    /// one round trip, three badges.
-    fn check_unread_counts(&self) {
-        let signed_in = self.imp().user_did.borrow().is_some();
-        let in_flight = *self.imp().checking_unread.borrow();
-        let expired = *self.imp().session_dead.borrow();
-        if !unread_poll_allowed(signed_in, in_flight, expired) {
+    /// Fetch the page of history older than what is shown.
+    fn fetch_older_messages(&self, page: &MessagePage) {
+        if page.is_fetching() {
             return;
         }
-        // Same offline guard as the new-posts poll.
-        if *self.imp().offline.borrow() {
+        let Some(cursor) = page.cursor() else {
             return;
-        }
-        self.imp().checking_unread.replace(true);
-
-        // A badge clear while this fetch is out makes its counts stale.
-        let clear_token = self.imp().badge_clear_generation.token();
+        };
+        page.set_fetching(true);
+        let convo_id = page.convo_id();
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<UnreadCounts, String>>();
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<ChatMessage>, Option<String>), String>>();
         let client = self.client();
+        let semaphore = API_SEMAPHORE.clone();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_unread_counts().await });
+            let result = runtime::block_on(async {
+                let _permit = semaphore.acquire().await;
+                client.get_messages(&convo_id, Some(&cursor)).await
+            });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
+        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok(counts)) => {
-                    app.imp().checking_unread.replace(false);
-                    app.imp().unread_poll_failed.replace(false);
-                    if let Some(sidebar) = app.sidebar() {
-                        sidebar.set_badge(NavItem::Chat, counts.chat);
-                        // Opening a section may have cleared these two while
-                        // the fetch was out; stale counts would relight them.
-                        if app.imp().badge_clear_generation.is_current(clear_token) {
-                            sidebar.set_badge(NavItem::Mentions, counts.mentions);
-                            sidebar.set_badge(NavItem::Activity, counts.activity);
-                        }
+                Ok(Ok((messages, cursor))) => {
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_cursor(cursor);
+                        page.prepend_older(messages);
+                        page.set_fetching(false);
+                        // Filtered pages can leave the viewport unfilled
+                        // with a cursor still stored.
+                        page.backfill_if_short();
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    app.imp().checking_unread.replace(false);
-                    // A flaky upstream fails poll after poll; say so once.
-                    if !*app.imp().unread_poll_failed.borrow() {
-                        app.imp().unread_poll_failed.replace(true);
-                        eprintln!("Failed to check unread counts: {}", e);
+                    eprintln!("Failed to fetch older messages: {}", e);
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_fetching(false);
+                    }
+                    // Rows are on screen, so the status page is out; say it
+                    // out loud instead.
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Couldn't load older messages");
                     }
-                    // On expiry this parks the poll until the next sign-in.
                     app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    app.imp().checking_unread.replace(false);
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_fetching(false);
+                    }
                     glib::ControlFlow::Break
                 }
             }
         });
     }
 
-    /// The sidebar, while a window is up.
-    fn sidebar(&self) -> Option<crate::ui::sidebar::Sidebar> {
-        self.imp()
-            .window
-            .borrow()
-            .as_ref()
-            .and_then(|w| w.sidebar())
-    }
-
-    /// What `item`'s badge currently holds.
-    fn badge_count(&self, item: NavItem) -> u32 {
-        self.sidebar().map(|s| s.badge_count(item)).unwrap_or(0)
-    }
-
-    /// Clear both notification badges and tell the server.
-    ///
-    /// `seenAt` is account-wide, so opening either Mentions or Activity
-    /// marks everything seen. The badges clear together to say so honestly.
-    fn clear_notification_badges(&self) {
-        if self.badge_count(NavItem::Mentions) == 0 && self.badge_count(NavItem::Activity) == 0 {
+    /// Send the composer's draft. On failure the draft stays in the entry
+    /// and the toast says to try again.
+    fn send_conversation_message(&self, page: &MessagePage, text: String) {
+        if page.is_sending() {
             return;
         }
-        // Strand any unread poll already in flight; it counted before this
-        // clear and would relight the badges.
-        self.imp().badge_clear_generation.bump();
-        if let Some(sidebar) = self.sidebar() {
-            sidebar.set_badge(NavItem::Mentions, 0);
-            sidebar.set_badge(NavItem::Activity, 0);
-        }
-        // Fire and forget. If it fails, the next poll raises the badges again.
-        let client = self.client();
-        thread::spawn(move || {
-            if let Err(e) = runtime::block_on(async { client.update_notifications_seen().await }) {
-                eprintln!("Failed to mark notifications seen: {}", e);
-            }
-        });
-    }
+        page.set_sending(true);
+        let convo_id = page.convo_id();
 
-    /// Fetch the user's saved feeds and populate the feed selector
-    fn fetch_saved_feeds(&self) {
-        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<SavedFeed>, String>>();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<ChatMessage, String>>();
         let client = self.client();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_saved_feeds().await });
+            let result =
+                runtime::block_on(async { client.send_chat_message(&convo_id, &text).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
+        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok(feeds)) => {
-                    // Set default feed if not already set
-                    if app.imp().current_feed.borrow().is_none() {
-                        if let Some(first) = feeds.first() {
-                            app.imp().current_feed.replace(Some(first.clone()));
-                        }
-                    }
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        // Set the current feed name/uri first so checkmark shows correctly
-                        if let Some(current) = app.imp().current_feed.borrow().as_ref() {
-                            window.set_current_feed_name(&current.display_name, &current.uri);
-                        }
-                        window.set_saved_feeds(feeds);
+                Ok(Ok(message)) => {
+                    if let Some(page) = page_weak.upgrade() {
+                        page.sent_ok(message);
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to fetch saved feeds: {}", e);
-                    // Still set up a default home feed
-                    let home_feed = vec![SavedFeed::home()];
-                    app.imp().current_feed.replace(Some(SavedFeed::home()));
+                    eprintln!("Failed to send message: {}", e);
+                    if let Some(page) = page_weak.upgrade() {
+                        page.send_failed();
+                    }
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_saved_feeds(home_feed);
+                        window.show_toast("The message didn't send. Try again.");
                     }
+                    app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch saved feeds: connection lost");
+                    if let Some(page) = page_weak.upgrade() {
+                        page.send_failed();
+                    }
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("The message didn't send. Try again.");
+                    }
                     glib::ControlFlow::Break
                 }
             }
         });
     }
 
-    /// Switch to a different feed
-    fn switch_feed(&self, feed: SavedFeed) {
-        // Pressing the feed that is already loading is not a new request.
-        // Without this every press spawned its own thread and poll source.
-        let same_feed = self
-            .imp()
-            .current_feed
-            .borrow()
-            .as_ref()
-            .is_some_and(|current| current.uri == feed.uri);
-        if same_feed && *self.imp().feed_loading.borrow() {
-            return;
-        }
-
-        // Update current feed
-        let feed_name = feed.display_name.clone();
-        let feed_uri = feed.uri.clone();
-        self.imp().current_feed.replace(Some(feed.clone()));
-
-        // Update UI
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.set_current_feed_name(&feed_name, &feed_uri);
-            window.hide_new_posts_banner();
-        }
-
-        // Clear state
-        self.imp().timeline_cursor.replace(None);
-        self.imp().newest_post_uri.replace(None);
-
-        // This switch owns the feed now. The bump strands whatever the
-        // last one has out, and releasing the guard lets the new fetch
-        // start without waiting for a response nobody wants any more.
-        self.imp().feed_generation.bump();
-        self.imp().feed_loading.replace(false);
-
-        // Fetch the new feed
-        self.fetch_current_feed();
-    }
-
-    /// Fetch posts for the current feed (home timeline or custom feed).
+    /// Poll for new messages while the conversation page is up.
     ///
-    /// One fetch at a time, and it carries the switch it belongs to: a
-    /// response for a feed the user has already moved past is dropped
-    /// rather than installed over the one they are looking at.
-    fn fetch_current_feed(&self) {
-        if *self.imp().feed_loading.borrow() {
-            return;
-        }
-        self.imp().feed_loading.replace(true);
-        let generation = self.imp().feed_generation.token();
-
-        let current_feed = self.imp().current_feed.borrow().clone();
-        let feed_key = current_feed
-            .as_ref()
-            .map(|f| {
-                if f.is_home() {
-                    "home".to_string()
-                } else {
-                    f.uri.clone()
-                }
-            })
-            .unwrap_or_else(|| "home".to_string());
-        let feed_uri = current_feed.as_ref().and_then(|f| {
-            if f.is_home() {
-                None
-            } else {
-                Some(f.uri.clone())
+    /// The timer holds a weak ref and dies with the page. Ticks skip while
+    /// the page is covered or off screen, while any fetch of its own is
+    /// already out, and for the rest of a dead session.
+    fn start_message_poll(&self, page: &MessagePage) {
+        let app = self.clone();
+        let page_weak = page.downgrade();
+        glib::timeout_add_seconds_local(MESSAGE_POLL_SECS, move || {
+            let Some(page) = page_weak.upgrade() else {
+                return glib::ControlFlow::Break;
+            };
+            let busy = page.is_fetching() || page.is_polling() || !page.loaded_once();
+            if message_poll_allowed(page.is_mapped(), busy, *app.imp().session_dead.borrow()) {
+                app.poll_conversation(&page);
             }
+            glib::ControlFlow::Continue
         });
+    }
 
-        // Try cache first for instant display
-        if let Some(cache) = self.imp().cache.borrow().as_ref() {
-            let feed_cache = FeedCache::new(cache);
-            if let Ok(cached_posts) = feed_cache.get_page(&feed_key, 0, 50) {
-                if !cached_posts.is_empty() {
-                    // Restore feed state from cache
-                    if let Ok(state) = feed_cache.get_state(&feed_key) {
-                        self.imp()
-                            .timeline_cursor
-                            .replace(state.oldest_cursor.clone());
-                        self.imp()
-                            .newest_post_uri
-                            .replace(state.newest_post_uri.clone());
-                    }
-                    if let Some(window) = self.imp().window.borrow().as_ref() {
-                        window.set_posts(cached_posts);
-                    }
-                }
-            }
-        }
+    /// One poll round: fetch the newest page and fold in whatever is new.
+    fn poll_conversation(&self, page: &MessagePage) {
+        page.set_polling(true);
+        let convo_id = page.convo_id();
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<ChatMessage>, Option<String>), String>>();
         let client = self.client();
-        let feed_uri_clone = feed_uri.clone();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async {
-                match feed_uri_clone {
-                    Some(uri) => client.get_feed(&uri, None).await,
-                    None => client.get_timeline(None).await,
-                }
-            });
+            let result = runtime::block_on(async { client.get_messages(&convo_id, None).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
-        });
-
-        let app = self.clone();
-        let feed_key_for_cache = feed_key.clone();
-        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-            // Another feed was picked while this one was out. Stop here
-            // rather than poll on for a response that cannot be used; the
-            // switch already handed the guard to the new fetch.
-            if !app.imp().feed_generation.is_current(generation) {
-                return glib::ControlFlow::Break;
-            }
-            match rx.try_recv() {
-                Ok(Ok((posts, next_cursor))) => {
-                    app.imp().feed_loading.replace(false);
-                    app.imp().timeline_cursor.replace(next_cursor.clone());
-                    if let Some(first) = posts.first() {
-                        app.imp().newest_post_uri.replace(Some(first.uri.clone()));
-                    }
-
-                    // Store in cache
-                    if let Some(cache) = app.imp().cache.borrow().as_ref() {
-                        let post_cache = PostCache::new(cache);
-                        let feed_cache = FeedCache::new(cache);
-                        let _ = feed_cache.clear_feed(&feed_key_for_cache);
-                        let _ = post_cache.store_batch(&posts);
-                        let _ = feed_cache.store_page(&feed_key_for_cache, &posts, 0);
-                        let state = FeedState {
-                            oldest_cursor: next_cursor,
-                            has_more: true,
-                            newest_post_uri: posts.first().map(|p| p.uri.clone()),
-                            newest_sort_timestamp: posts.first().map(|p| p.indexed_at.clone()),
-                            last_refresh_at: Some(CacheDb::now()),
-                        };
-                        let _ = feed_cache.set_state(&feed_key_for_cache, &state);
-                    }
+        });
 
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.hide_new_posts_banner();
-                        window.set_posts(posts);
+        let app = self.clone();
+        let page_weak = page.downgrade();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok((messages, _cursor))) => {
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_polling(false);
+                        let added = page.merge_new(messages);
+                        // Only a page still on screen can honestly claim
+                        // the user saw what just arrived.
+                        if added > 0 && page.is_mapped() {
+                            app.mark_conversation_read(page.convo_id());
+                        }
                     }
-                    app.start_new_posts_polling();
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    app.imp().feed_loading.replace(false);
-                    eprintln!("Failed to fetch feed: {}", e);
-                    app.toast_unless_offline("Couldn't load this feed");
+                    // Transient failures self-heal on the next tick; an
+                    // expiry parks the poll through the flag it raises.
+                    eprintln!("Failed to poll messages: {}", e);
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_polling(false);
+                    }
                     app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    app.imp().feed_loading.replace(false);
-                    eprintln!("Failed to fetch feed: connection lost");
+                    if let Some(page) = page_weak.upgrade() {
+                        page.set_polling(false);
+                    }
                     glib::ControlFlow::Break
                 }
             }
         });
     }
 
-    /// Open the thread view for a post
-    fn open_thread_view(&self, post: Post) {
-        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<Post>, String>>();
+    /// Add or remove one emoji reaction. The server answers with the
+    /// updated message and the page swaps it in; nothing is guessed.
+    fn toggle_message_reaction(
+        &self,
+        page: &MessagePage,
+        message_id: String,
+        value: String,
+        add: bool,
+    ) {
+        let convo_id = page.convo_id();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<ChatMessage, String>>();
         let client = self.client();
-        let post_uri = post.uri.clone();
-        let main_post = post.clone();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_thread(&post_uri).await });
+            let result = runtime::block_on(async {
+                if add {
+                    client
+                        .add_chat_reaction(&convo_id, &message_id, &value)
+                        .await
+                } else {
+                    client
+                        .remove_chat_reaction(&convo_id, &message_id, &value)
+                        .await
+                }
+            });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
+        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok(posts)) => {
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.push_thread_page(&main_post, posts);
+                Ok(Ok(message)) => {
+                    if let Some(page) = page_weak.upgrade() {
+                        page.update_message(message);
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to fetch thread: {}", e);
-                    app.toast_unless_offline("Couldn't load the thread");
-                    // Clicking a quote of a deleted post lands here. Silence
-                    // read as a dead click.
+                    eprintln!("Failed to update reaction: {}", e);
+                    app.report_session_expiry();
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("Couldn't load this post");
+                        window.show_toast("Couldn't update the reaction");
                     }
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch thread: connection lost");
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("Couldn't load this post");
-                    }
-                    glib::ControlFlow::Break
-                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Open the profile view for a user by their handle (e.g., from @mention click)
-    fn open_profile_by_handle(&self, handle: String) {
-        let (tx, rx) = std::sync::mpsc::channel::<Result<Profile, String>>();
+    /// Delete for Me is quiet but permanent for this account; ask first.
+    fn confirm_delete_message(&self, page: &MessagePage, message_id: String) {
+        let window = match self.imp().window.borrow().as_ref() {
+            Some(w) => w.clone(),
+            None => return,
+        };
+
+        let dialog = adw::AlertDialog::new(
+            Some("Delete for you?"),
+            Some("The message disappears from your view only. The other side keeps their copy."),
+        );
+        dialog.add_response("cancel", "Cancel");
+        dialog.add_response("delete", "Delete");
+        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_close_response("cancel");
+
+        let app = self.clone();
+        let page_weak = page.downgrade();
+        dialog.connect_response(Some("delete"), move |_, _| {
+            let Some(page) = page_weak.upgrade() else {
+                return;
+            };
+            app.delete_message_for_me(&page, message_id.clone());
+        });
+        dialog.present(Some(&window));
+    }
+
+    fn delete_message_for_me(&self, page: &MessagePage, message_id: String) {
+        let convo_id = page.convo_id();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
         let client = self.client();
+        let id_for_call = message_id.clone();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_profile(&handle).await });
+            let result = runtime::block_on(async {
+                client.delete_chat_message(&convo_id, &id_for_call).await
+            });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
+        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok(profile)) => {
-                    // Now open the profile view with the resolved profile
-                    app.open_profile_view(profile);
+                Ok(Ok(())) => {
+                    if let Some(page) = page_weak.upgrade() {
+                        page.remove_message(&message_id);
+                    }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to fetch profile for handle: {}", e);
-                    app.toast_unless_offline("Couldn't open the profile");
+                    eprintln!("Failed to delete message: {}", e);
+                    app.report_session_expiry();
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast("Couldn't delete the message");
+                    }
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch profile: connection lost");
-                    glib::ControlFlow::Break
-                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Open the profile view for a user
-    fn open_profile_view(&self, profile: Profile) {
-        let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Profile, Vec<Post>, Option<String>), String>>();
+    /// Tell the server the conversation is read, then let the UI follow:
+    /// the row in the chat list drops its badge and the sidebar re-tallies.
+    /// On failure both stay lit, which is the truth; the next successful
+    /// open clears them.
+    fn mark_conversation_read(&self, convo_id: String) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
         let client = self.client();
-        let actor = profile.did.clone();
+        let id = convo_id.clone();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async {
-                let feed = client
-                    .get_author_feed(&actor, None, Some("posts_and_author_threads"))
-                    .await;
-                // Avatar clicks arrive with the post author, a minimal
-                // profile missing its bio, and search results come without
-                // counts. Fetch the full one so the pushed page can show
-                // both; if that fails, push what we had.
-                let full_profile =
-                    if profile.description.is_none() || profile.followers_count.is_none() {
-                        client.get_profile(&actor).await.ok()
-                    } else {
-                        None
-                    };
-                feed.map(|(posts, cursor)| (full_profile.unwrap_or(profile), posts, cursor))
-            });
+            let result = runtime::block_on(async { client.mark_convo_read(&id).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((profile, posts, cursor))) => {
+                Ok(Ok(())) => {
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.push_profile_page(&profile, posts, cursor);
+                        window.set_conversation_read(&convo_id);
                     }
+                    app.check_unread_counts();
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to fetch profile feed: {}", e);
-                    app.toast_unless_offline("Couldn't open the profile");
+                    eprintln!("Failed to mark conversation read: {}", e);
+                    app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch profile feed: connection lost");
-                    glib::ControlFlow::Break
-                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Open the followers or following list for a profile
-    fn open_follow_list(&self, profile: Profile, kind: FollowListKind) {
-        let pushed = {
-            let window = self.imp().window.borrow();
-            let Some(window) = window.as_ref() else {
-                return;
-            };
-            window.push_follow_list_page(&profile, kind)
+    /// Open the own profile view
+    fn open_own_profile_view(&self) {
+        // Switch to profile page (instant, no animation)
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.show_profile_page();
+        }
+
+        // The first open loads; after that the page keeps what it has.
+        let loaded = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .and_then(|w| w.own_profile_feed_ctx())
+            .is_some_and(|ctx| !ctx.did.borrow().is_empty());
+        if !loaded {
+            self.fetch_profile_posts();
+        }
+    }
+
+    /// Fetch posts for the logged-in user's profile
+    fn fetch_profile_posts(&self) {
+        let Some(user_did) = self.imp().user_did.borrow().clone() else {
+            eprintln!("Cannot fetch profile posts: no user DID");
+            return;
         };
-        let Some(pushed) = pushed else {
+        let Some(window) = self.imp().window.borrow().clone() else {
             return;
         };
-
-        let page = match pushed {
-            FollowListPush::Pushed(page) => {
-                let app = self.clone();
-                let did = profile.did.clone();
-                let page_weak = page.downgrade();
-                page.set_load_more_callback(move || {
-                    let Some(page) = page_weak.upgrade() else {
-                        return;
-                    };
-                    // No cursor means either the first page is still in
-                    // flight or the server said the list is done.
-                    let Some(cursor) = page.cursor() else {
-                        return;
-                    };
-                    app.fetch_follow_list(&page, did.clone(), kind, Some(cursor));
-                });
-
-                // Retry resumes from the stored cursor: a failed first page
-                // starts over, a failed later page picks up where it was.
-                let app = self.clone();
-                let did = profile.did.clone();
-                let page_weak = page.downgrade();
-                page.set_retry_callback(move || {
-                    let Some(page) = page_weak.upgrade() else {
-                        return;
-                    };
-                    app.fetch_follow_list(&page, did.clone(), kind, page.cursor());
-                });
-
-                page
-            }
-            FollowListPush::PoppedBack(page) => {
-                // A healthy page keeps what it has. One whose first load
-                // failed gets another try on reopen.
-                if !page.needs_reload() {
-                    return;
-                }
-                page
-            }
+        let Some(ctx) = window.own_profile_feed_ctx() else {
+            return;
         };
+        // A reopen starts the active tab over from the top.
+        ctx.did.replace(user_did);
+        ctx.begin_refresh();
+        self.fetch_profile_tab(ctx, true);
+    }
 
-        self.fetch_follow_list(&page, profile.did.clone(), kind, page.cursor());
+    /// Open the likes view
+    fn open_likes_view(&self) {
+        // Switch to likes page (instant, no animation)
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.show_likes_page();
+        }
+
+        // Only fetch if we haven't loaded likes yet
+        if self.imp().likes_cursor.borrow().is_none() {
+            self.fetch_likes();
+        }
     }
 
-    /// Fetch one page of a follow list and hand it to the pushed page.
-    ///
-    /// The page owns its cursor and in-flight flag; stacked lists must not
-    /// share state, and a popped page takes its fetch bookkeeping with it.
-    fn fetch_follow_list(
-        &self,
-        page: &FollowListPage,
-        did: String,
-        kind: FollowListKind,
-        cursor: Option<String>,
-    ) {
-        if page.is_fetching() {
-            return;
+    /// Fetch liked posts for the logged-in user
+    fn fetch_likes(&self) {
+        let user_did = match self.imp().user_did.borrow().clone() {
+            Some(did) => did,
+            None => {
+                eprintln!("Cannot fetch likes: no user DID");
+                return;
+            }
+        };
+
+        // The likes as last seen, while the live list loads.
+        let (cached, _) = self.cached_view(FEED_LIKES);
+        let painted = !cached.is_empty();
+        if painted && let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_likes(cached);
         }
-        page.set_fetching(true);
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Profile>, Option<String>), String>>();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
         let client = self.client();
-        let semaphore = API_SEMAPHORE.clone();
+        let account_token = self.imp().account_generation.token();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async {
-                let _permit = semaphore.acquire().await;
-                match kind {
-                    FollowListKind::Followers => {
-                        client.get_followers(&did, cursor.as_deref()).await
-                    }
-                    FollowListKind::Following => client.get_follows(&did, cursor.as_deref()).await,
-                }
-            });
+            let result = runtime::block_on(async { client.get_actor_likes(&user_did, None).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            // The last account's likes must not reach the next one's cache.
+            if !app.imp().account_generation.is_current(account_token) {
+                return glib::ControlFlow::Break;
+            }
             match rx.try_recv() {
-                Ok(Ok((profiles, next_cursor))) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_cursor(next_cursor);
-                        page.append_profiles(profiles);
-                        page.set_fetching(false);
-                        // Filtered or short pages can leave the viewport
-                        // unfilled with a cursor still stored.
-                        page.backfill_if_short();
+                Ok(Ok((posts, next_cursor))) => {
+                    app.cache_view(FEED_LIKES, &posts, next_cursor.clone());
+                    app.imp().likes_cursor.replace(next_cursor);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_likes(posts);
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to fetch follow list: {}", e);
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_fetching(false);
-                        page.show_load_failed();
+                    eprintln!("Failed to fetch likes: {}", e);
+                    app.toast_unless_offline("Couldn't load likes");
+                    // The cached list is on screen; it stands.
+                    if !painted && let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_likes_load_failed();
                     }
-                    app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch follow list: connection lost");
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_fetching(false);
-                        page.show_load_failed();
-                    }
+                    eprintln!("Failed to fetch likes: connection lost");
                     glib::ControlFlow::Break
                 }
             }
         });
     }
 
-    /// Handle navigation item changes from the sidebar
-    fn handle_nav_change(&self, item: NavItem) {
-        match item {
-            NavItem::Home => {
-                if let Some(window) = self.imp().window.borrow().as_ref() {
-                    window.show_home_page();
-                }
-            }
-            NavItem::Mentions => {
-                self.open_mentions_view();
-            }
-            NavItem::Activity => {
-                self.open_activity_view();
-            }
-            NavItem::Chat => {
-                self.open_chat_view();
-            }
-            NavItem::Profile => {
-                self.open_own_profile_view();
-            }
-            NavItem::Likes => {
-                self.open_likes_view();
-            }
-            NavItem::Bookmarks => {
-                self.open_bookmarks_view();
-            }
-            NavItem::Search => {
-                self.open_search_view();
-            }
+    /// Fetch more liked posts for infinite scroll
+    fn fetch_likes_more(&self) {
+        if *self.imp().likes_loading_more.borrow() {
+            return;
         }
-    }
+        let cursor = match self.imp().likes_cursor.borrow().as_ref() {
+            Some(c) => c.clone(),
+            None => return,
+        };
+        let user_did = match self.imp().user_did.borrow().clone() {
+            Some(did) => did,
+            None => return,
+        };
+        self.imp().likes_loading_more.replace(true);
 
-    /// Open the mentions view
-    fn open_mentions_view(&self) {
-        // Switch to mentions page (instant, no animation)
         if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.show_mentions_page();
-        }
-
-        // A lit badge means the server has mentions this list does not.
-        let stale = self.badge_count(NavItem::Mentions) > 0;
-        self.clear_notification_badges();
-
-        // Fetch on first open, or when the badge said the list moved
-        if self.imp().mentions_cursor.borrow().is_none() || stale {
-            self.fetch_mentions();
+            window.set_likes_loading(true);
         }
-    }
 
-    /// Fetch mentions (notifications filtered to mentions/replies/quotes)
-    fn fetch_mentions(&self) {
-        let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Vec<Notification>, Option<String>), String>>();
+        let account_token = self.imp().account_generation.token();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
         let client = self.client();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_notifications(None, true).await });
+            let result =
+                runtime::block_on(async { client.get_actor_likes(&user_did, Some(&cursor)).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((notifications, next_cursor))) => {
-                    app.imp().mentions_cursor.replace(next_cursor);
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    app.imp().likes_loading_more.replace(false);
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((posts, next_cursor))) => {
+                    app.imp().likes_loading_more.replace(false);
+                    app.extend_cached_view(FEED_LIKES, &posts, next_cursor.clone());
+                    app.imp().likes_cursor.replace(next_cursor);
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_mentions(notifications);
+                        window.set_likes_loading(false);
+                        if !posts.is_empty() {
+                            window.append_likes(posts);
+                        }
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to fetch mentions: {}", e);
-                    app.toast_unless_offline("Couldn't load mentions");
+                    app.imp().likes_loading_more.replace(false);
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_mentions_load_failed();
+                        window.set_likes_loading(false);
                     }
+                    eprintln!("Failed to fetch more likes: {}", e);
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch mentions: connection lost");
+                    app.imp().likes_loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_likes_loading(false);
+                    }
                     glib::ControlFlow::Break
                 }
             }
         });
     }
 
-    /// Fetch more mentions for infinite scroll
-    fn fetch_mentions_more(&self) {
-        if *self.imp().mentions_loading_more.borrow() {
-            return;
+    /// Open the Saved view. Posts get saved and unsaved from every list, and
+    /// from other clients, so this refetches on every open rather than
+    /// trusting a list loaded earlier.
+    fn open_bookmarks_view(&self) {
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.show_bookmarks_page();
         }
-        let cursor = match self.imp().mentions_cursor.borrow().as_ref() {
-            Some(c) => c.clone(),
-            None => return,
-        };
-        self.imp().mentions_loading_more.replace(true);
+        self.fetch_bookmarks();
+    }
 
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.set_mentions_loading(true);
+    /// Fetch the first page of saved posts, replacing the list
+    fn fetch_bookmarks(&self) {
+        // Take ownership of the list: anything still in flight from an
+        // earlier visit belongs to the page this replaces.
+        self.imp().bookmarks_generation.bump();
+        let token = self.imp().bookmarks_generation.token();
+        let account_token = self.imp().account_generation.token();
+        self.imp().bookmarks_loading_more.replace(false);
+
+        // The saved posts as last seen, while the live list loads.
+        let (cached, _) = self.cached_view(FEED_BOOKMARKS);
+        let painted = !cached.is_empty();
+        if painted && let Some(window) = self.imp().window.borrow().as_ref() {
+            window.set_bookmarks(cached);
         }
 
-        let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Vec<Notification>, Option<String>), String>>();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
         let client = self.client();
-        let semaphore = API_SEMAPHORE.clone();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async {
-                let _permit = semaphore.acquire().await;
-                client.get_notifications(Some(&cursor), true).await
-            });
+            let result = runtime::block_on(async { client.get_bookmarks(None).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((notifications, next_cursor))) => {
-                    app.imp().mentions_loading_more.replace(false);
-                    app.imp().mentions_cursor.replace(next_cursor);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_mentions_loading(false);
-                        if !notifications.is_empty() {
-                            window.append_mentions(notifications);
-                        }
+                Ok(result) => {
+                    if !app.imp().bookmarks_generation.is_current(token)
+                        || !app.imp().account_generation.is_current(account_token)
+                    {
+                        return glib::ControlFlow::Break;
                     }
-                    glib::ControlFlow::Break
-                }
-                Ok(Err(e)) => {
-                    app.imp().mentions_loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_mentions_loading(false);
+                    match result {
+                        Ok((posts, next_cursor)) => {
+                            app.cache_view(FEED_BOOKMARKS, &posts, next_cursor.clone());
+                            app.imp().bookmarks_cursor.replace(next_cursor);
+                            if let Some(window) = app.imp().window.borrow().as_ref() {
+                                window.set_bookmarks(posts);
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("Failed to fetch saved posts: {}", e);
+                            app.report_session_expiry();
+                            // The cached list is on screen; it stands.
+                            if !painted && let Some(window) = app.imp().window.borrow().as_ref() {
+                                window.show_toast("Couldn't load saved posts");
+                                window.set_bookmarks_load_failed();
+                            }
+                        }
                     }
-                    eprintln!("Failed to fetch more mentions: {}", e);
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    app.imp().mentions_loading_more.replace(false);
+                    eprintln!("Failed to fetch saved posts: connection lost");
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_mentions_loading(false);
+                        window.show_toast("Couldn't load saved posts");
                     }
                     glib::ControlFlow::Break
                 }
@@ -3042,796 +6718,877 @@ impl HangarApplication {
         });
     }
 
-    /// Open the activity view
-    fn open_activity_view(&self) {
-        // Switch to activity page (instant, no animation)
+    /// Fetch more saved posts for infinite scroll
+    fn fetch_bookmarks_more(&self) {
+        if *self.imp().bookmarks_loading_more.borrow() {
+            return;
+        }
+        let cursor = match self.imp().bookmarks_cursor.borrow().as_ref() {
+            Some(c) => c.clone(),
+            None => return,
+        };
+        self.imp().bookmarks_loading_more.replace(true);
+        let token = self.imp().bookmarks_generation.token();
+
         if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.show_activity_page();
-        }
-
-        // Activity lists every notification, so unread under either badge
-        // means this list is behind.
-        let stale =
-            self.badge_count(NavItem::Mentions) > 0 || self.badge_count(NavItem::Activity) > 0;
-        self.clear_notification_badges();
-
-        // Fetch on first open, or when the badge said the list moved
-        if self.imp().activity_cursor.borrow().is_none() || stale {
-            self.fetch_activity();
+            window.set_bookmarks_loading(true);
         }
-    }
 
-    /// Fetch activity (all notifications)
-    fn fetch_activity(&self) {
-        let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Vec<Notification>, Option<String>), String>>();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
         let client = self.client();
 
         thread::spawn(move || {
-            // Pass false for mentions_only to get all notifications
-            let result = runtime::block_on(async { client.get_notifications(None, false).await });
+            let result = runtime::block_on(async { client.get_bookmarks(Some(&cursor)).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((notifications, next_cursor))) => {
-                    app.imp().activity_cursor.replace(next_cursor);
+                Ok(result) => {
+                    app.imp().bookmarks_loading_more.replace(false);
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_activity(notifications);
+                        window.set_bookmarks_loading(false);
                     }
-                    glib::ControlFlow::Break
-                }
-                Ok(Err(e)) => {
-                    eprintln!("Failed to fetch activity: {}", e);
-                    app.toast_unless_offline("Couldn't load activity");
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_activity_load_failed();
+                    if !app.imp().bookmarks_generation.is_current(token) {
+                        return glib::ControlFlow::Break;
+                    }
+                    match result {
+                        Ok((posts, next_cursor)) => {
+                            app.extend_cached_view(FEED_BOOKMARKS, &posts, next_cursor.clone());
+                            app.imp().bookmarks_cursor.replace(next_cursor);
+                            if !posts.is_empty() {
+                                if let Some(window) = app.imp().window.borrow().as_ref() {
+                                    window.append_bookmarks(posts);
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("Failed to fetch more saved posts: {}", e);
+                            app.report_session_expiry();
+                            if let Some(window) = app.imp().window.borrow().as_ref() {
+                                window.show_toast("Couldn't load more saved posts");
+                            }
+                        }
                     }
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch activity: connection lost");
+                    app.imp().bookmarks_loading_more.replace(false);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_bookmarks_loading(false);
+                    }
                     glib::ControlFlow::Break
                 }
             }
         });
     }
 
-    /// Fetch more activity for infinite scroll
-    fn fetch_activity_more(&self) {
-        if *self.imp().activity_loading_more.borrow() {
-            return;
-        }
-        let cursor = match self.imp().activity_cursor.borrow().as_ref() {
-            Some(c) => c.clone(),
-            None => return,
-        };
-        self.imp().activity_loading_more.replace(true);
-
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.set_activity_loading(true);
-        }
-
-        let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Vec<Notification>, Option<String>), String>>();
+    /// Save or unsave a post, whichever the menu offered
+    fn toggle_bookmark(&self, post: Post, row_weak: glib::WeakRef<PostRow>) {
+        let was_saved = post.viewer_bookmarked == Some(true);
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
         let client = self.client();
-        let semaphore = API_SEMAPHORE.clone();
+        let uri = post.uri.clone();
+        let cid = post.cid.clone();
 
         thread::spawn(move || {
-            // Pass false for mentions_only to get all notifications
             let result = runtime::block_on(async {
-                let _permit = semaphore.acquire().await;
-                client.get_notifications(Some(&cursor), false).await
+                if was_saved {
+                    client.delete_bookmark(&uri).await
+                } else {
+                    client.create_bookmark(&uri, &cid).await
+                }
             });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
+        let uri = post.uri;
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((notifications, next_cursor))) => {
-                    app.imp().activity_loading_more.replace(false);
-                    app.imp().activity_cursor.replace(next_cursor);
+                Ok(Ok(())) => {
+                    // Flip the row, but only if it is still showing this post;
+                    // a pooled row may have been rebound while we waited.
+                    if let Some(row) = row_weak.upgrade() {
+                        if row.post_uri().as_deref() == Some(uri.as_str()) {
+                            row.set_viewer_bookmarked(!was_saved);
+                        }
+                    }
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_activity_loading(false);
-                        if !notifications.is_empty() {
-                            window.append_activity(notifications);
+                        if was_saved {
+                            // Unsaving from the Saved page takes the row out
+                            // now; from any other list this is a no-op.
+                            window.remove_bookmark(&uri);
+                            window.show_toast("Removed from Saved");
+                        } else {
+                            window.show_toast("Post saved");
                         }
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    app.imp().activity_loading_more.replace(false);
+                    eprintln!("Failed to update bookmark: {}", e);
+                    app.report_session_expiry();
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_activity_loading(false);
+                        window.show_toast(if was_saved {
+                            "Couldn't remove from Saved"
+                        } else {
+                            "Couldn't save post"
+                        });
                     }
-                    eprintln!("Failed to fetch more activity: {}", e);
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    app.imp().activity_loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_activity_loading(false);
-                    }
-                    glib::ControlFlow::Break
-                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Open the chat view
-    fn open_chat_view(&self) {
-        // Switch to chat page (instant, no animation)
+    /// Track connectivity. Going offline raises the banner and parks the
+    /// polls; coming back lowers it and refreshes the feed, since whatever
+    /// is on screen predates the outage.
+    fn set_network_available(&self, available: bool) {
+        let was_offline = *self.imp().offline.borrow();
+        self.imp().offline.replace(!available);
         if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.show_chat_page();
+            window.set_offline(!available);
         }
+        if available && was_offline && self.imp().user_did.borrow().is_some() {
+            self.fetch_timeline();
+        }
+    }
 
-        // The chat badge stays lit until a conversation is actually read;
-        // clearing it from the list would claim messages nobody has seen.
-        // The list itself still refreshes when the badge says it moved.
-        let stale = self.badge_count(NavItem::Chat) > 0;
-        if self.imp().chat_cursor.borrow().is_none() || stale {
-            self.fetch_conversations();
+    /// A failure toast, unless the offline banner already explains it.
+    fn toast_unless_offline(&self, message: &str) {
+        if *self.imp().offline.borrow() {
+            return;
+        }
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.show_toast(message);
         }
     }
 
-    /// Fetch conversations
-    fn fetch_conversations(&self) {
-        let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Vec<Conversation>, Option<String>), String>>();
+    /// Flip a follow on the server and hand the outcome to `done` on the
+    /// main thread: Ok(Some(uri)) followed, Ok(None) unfollowed. A failure
+    /// toasts and reports session expiry before `done` runs.
+    fn follow_flip<F: Fn(Result<Option<String>, String>) + 'static>(
+        &self,
+        did: String,
+        current_uri: Option<String>,
+        done: F,
+    ) {
+        let unfollowing = current_uri.is_some();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Option<String>, String>>();
         let client = self.client();
 
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_conversations(None).await });
+            let result = runtime::block_on(async {
+                match current_uri {
+                    Some(uri) => client.unfollow(&uri).await.map(|_| None),
+                    None => client.follow(&did).await.map(Some),
+                }
+            });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((conversations, next_cursor))) => {
-                    app.imp().chat_cursor.replace(next_cursor);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_conversations(conversations);
-                    }
-                    glib::ControlFlow::Break
-                }
-                Ok(Err(e)) => {
-                    eprintln!("Failed to fetch conversations: {}", e);
-                    app.toast_unless_offline("Couldn't load conversations");
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_conversations_load_failed();
+                Ok(result) => {
+                    if let Err(e) = &result {
+                        eprintln!("Failed to update follow: {}", e);
+                        app.report_session_expiry();
+                        if let Some(window) = app.imp().window.borrow().as_ref() {
+                            window.show_toast(if unfollowing {
+                                "Couldn't unfollow"
+                            } else {
+                                "Couldn't follow"
+                            });
+                        }
                     }
+                    done(result);
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch conversations: connection lost");
-                    glib::ControlFlow::Break
-                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Follow or unfollow from a profile page, decided by whether the page
+    /// holds a record URI.
+    ///
+    /// The button already shows the hoped-for state and is insensitive; on
+    /// success the cell takes the new URI, on failure the button goes back.
+    fn toggle_follow(
+        &self,
+        did: String,
+        uri_cell: Rc<RefCell<Option<String>>>,
+        btn_weak: glib::WeakRef<gtk4::Button>,
+    ) {
+        let current_uri = uri_cell.borrow().clone();
+        self.follow_flip(did, current_uri, move |result| {
+            if let Ok(new_uri) = result {
+                uri_cell.replace(new_uri);
+            }
+            if let Some(btn) = btn_weak.upgrade() {
+                HangarWindow::sync_follow_button(&btn, uri_cell.borrow().is_some());
+            }
+        });
+    }
+
+    /// Follow or unfollow from a people row. The result only lands if the
+    /// recycled row still shows the same profile; the model object behind
+    /// it is updated either way through the row.
+    fn toggle_follow_row(
+        &self,
+        profile: Profile,
+        row_weak: glib::WeakRef<crate::ui::actor_row::ActorRow>,
+    ) {
+        let did = profile.did.clone();
+        let current_uri = profile.viewer_following.clone();
+        self.follow_flip(profile.did, current_uri.clone(), move |result| {
+            let Some(row) = row_weak.upgrade() else {
+                return;
+            };
+            if row.profile_did().as_deref() != Some(did.as_str()) {
+                return;
+            }
+            match result {
+                Ok(new_uri) => row.set_viewer_following(new_uri),
+                // The button showed the hoped-for state; put it back.
+                Err(_) => row.set_viewer_following(current_uri.clone()),
             }
         });
     }
 
-    /// Fetch more conversations for infinite scroll
-    fn fetch_chat_more(&self) {
-        if *self.imp().chat_loading_more.borrow() {
+    /// What a tab's page actually lists. The server's posts_with_replies
+    /// is a superset with standalone posts included; the Replies tab wants
+    /// only the replies, so the rest of the page stays behind.
+    fn tab_posts(filter: &str, mut posts: Vec<Post>) -> Vec<Post> {
+        if filter == "posts_with_replies" {
+            posts.retain(|post| post.reply_context.is_some());
+        }
+        posts
+    }
+
+    /// One page of a profile tab's feed. First pages were cleared by the
+    /// tab switch; later pages append. The context's generation strands a
+    /// fetch whose tab was switched away while it was out.
+    fn fetch_profile_tab(&self, ctx: std::rc::Rc<crate::ui::ProfileFeedCtx>, first_page: bool) {
+        if ctx.fetching.get() {
             return;
         }
-        let cursor = match self.imp().chat_cursor.borrow().as_ref() {
-            Some(c) => c.clone(),
-            None => return,
-        };
-        self.imp().chat_loading_more.replace(true);
-
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.set_chat_loading(true);
+        let did = ctx.did.borrow().clone();
+        // The own page's context exists before sign-in does.
+        if did.is_empty() {
+            return;
+        }
+        if ctx.filter.get() == crate::ui::ProfileFeedCtx::STARTER_PACKS {
+            self.fetch_profile_starter_packs(ctx, did, first_page);
+            return;
         }
+        ctx.fetching.set(true);
+        let generation = ctx.generation.get();
+        let account_token = self.imp().account_generation.token();
+        let filter = ctx.filter.get();
+        let cache_key = author_key(&did, filter);
+        let cursor = if first_page {
+            // The tab as last read, while the live page is out.
+            let (cached, cached_cursor) = self.cached_view(&cache_key);
+            ctx.cursor.replace(cached_cursor);
+            ctx.append_posts(cached);
+            None
+        } else {
+            ctx.cursor.borrow().clone()
+        };
 
-        let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Vec<Conversation>, Option<String>), String>>();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
         let client = self.client();
-
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_conversations(Some(&cursor)).await });
+            let result = runtime::block_on(async {
+                client
+                    .get_author_feed(&did, cursor.as_deref(), Some(filter))
+                    .await
+            });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            if ctx.generation.get() != generation
+                || !app.imp().account_generation.is_current(account_token)
+            {
+                return glib::ControlFlow::Break;
+            }
             match rx.try_recv() {
-                Ok(Ok((conversations, next_cursor))) => {
-                    app.imp().chat_loading_more.replace(false);
-                    app.imp().chat_cursor.replace(next_cursor);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_chat_loading(false);
-                        if !conversations.is_empty() {
-                            window.append_conversations(conversations);
-                        }
+                Ok(Ok((posts, next_cursor))) => {
+                    ctx.fetching.set(false);
+                    let posts = Self::tab_posts(filter, posts);
+                    if first_page {
+                        app.cache_view(&cache_key, &posts, next_cursor.clone());
+                        ctx.replace_posts(posts);
+                    } else {
+                        app.extend_cached_view(&cache_key, &posts, next_cursor.clone());
+                        ctx.append_posts(posts);
                     }
+                    ctx.cursor.replace(next_cursor);
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    app.imp().chat_loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_chat_loading(false);
-                    }
-                    eprintln!("Failed to fetch more conversations: {}", e);
+                    ctx.fetching.set(false);
+                    eprintln!("Failed to fetch profile tab: {}", e);
+                    app.toast_unless_offline("Couldn't load these posts");
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    app.imp().chat_loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_chat_loading(false);
-                    }
-                    glib::ControlFlow::Break
-                }
-            }
-        });
-    }
-
-    /// Open a conversation in the message view, pushed onto the chat stack.
-    /// Message someone, from a profile button or the New Message picker.
-    /// The server decides whether messaging is allowed; DMs off, blocks,
-    /// and account restrictions all come back as a plain no, so the toast
-    /// says so without guessing the reason. The button, when there is one,
-    /// is already locked by the click.
-    fn open_direct_message(&self, profile: Profile, btn_weak: Option<glib::WeakRef<gtk4::Button>>) {
-        let (tx, rx) = std::sync::mpsc::channel::<Result<Option<Conversation>, String>>();
-        let client = self.client();
-        let did = profile.did.clone();
-
-        thread::spawn(move || {
-            let result = runtime::block_on(async { client.start_conversation(&did).await });
-            let _ = tx.send(result.map_err(|e| e.to_string()));
-        });
-
-        let app = self.clone();
-        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-            let btn = btn_weak.as_ref().and_then(|weak| weak.upgrade());
-            match rx.try_recv() {
-                Ok(Ok(Some(conversation))) => {
-                    if let Some(btn) = btn {
-                        btn.set_sensitive(true);
-                    }
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        // The message page lives on the chat stack; go there
-                        // with the rail highlight in tow.
-                        window.select_nav(NavItem::Chat);
-                        window.switch_to_page("chat");
-                    }
-                    app.open_conversation_view(conversation);
-                    glib::ControlFlow::Break
-                }
-                Ok(Ok(None)) => {
-                    // Stays locked: asking again will not change the answer.
-                    if let Some(btn) = btn {
-                        btn.set_tooltip_text(Some("This account can't receive your messages"));
-                    }
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("This account can't receive your messages");
-                    }
-                    glib::ControlFlow::Break
-                }
-                Ok(Err(e)) => {
-                    eprintln!("Failed to check messaging: {}", e);
-                    app.report_session_expiry();
-                    if let Some(btn) = btn {
-                        btn.set_sensitive(true);
-                    }
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("Couldn't open messaging");
-                    }
+                    ctx.fetching.set(false);
                     glib::ControlFlow::Break
                 }
-                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Typeahead for the New Message picker. The generation strands slow
-    /// answers so a fast typist never sees results for an older query.
-    fn search_message_targets(&self, query: String) {
-        self.imp().message_target_generation.bump();
-        let generation = self.imp().message_target_generation.token();
-
-        if query.trim().is_empty() {
-            if let Some(window) = self.imp().window.borrow().as_ref() {
-                window.set_new_message_results(vec![]);
-            }
+    /// One page of a hashtag's Top or Latest tab, the same way
+    /// [`Self::fetch_profile_tab`] loads a profile's: the cached page
+    /// first, then the live one, stranded if the tab was switched.
+    fn fetch_hashtag_tab(&self, ctx: std::rc::Rc<crate::ui::HashtagFeedCtx>, first_page: bool) {
+        if ctx.fetching.get() {
             return;
         }
+        ctx.fetching.set(true);
+        let generation = ctx.generation.get();
+        let account_token = self.imp().account_generation.token();
+        let query = ctx.query();
+        let cache_key = search_key(&query.to_input());
+        let cursor = if first_page {
+            let (cached, cached_cursor) = self.cached_view(&cache_key);
+            ctx.cursor.replace(cached_cursor);
+            ctx.append_posts(cached);
+            None
+        } else {
+            ctx.cursor.borrow().clone()
+        };
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<Profile>, String>>();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
         let client = self.client();
         thread::spawn(move || {
             let result =
-                runtime::block_on(async { client.search_actors_typeahead(&query, 8).await });
+                runtime::block_on(async { client.search_posts(&query, cursor.as_deref()).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-            if !app.imp().message_target_generation.is_current(generation) {
+            if ctx.generation.get() != generation
+                || !app.imp().account_generation.is_current(account_token)
+            {
                 return glib::ControlFlow::Break;
             }
             match rx.try_recv() {
-                Ok(Ok(profiles)) => {
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_new_message_results(profiles);
+                Ok(Ok((posts, next_cursor))) => {
+                    ctx.fetching.set(false);
+                    if first_page {
+                        app.cache_view(&cache_key, &posts, next_cursor.clone());
+                        ctx.replace_posts(posts);
+                    } else {
+                        app.extend_cached_view(&cache_key, &posts, next_cursor.clone());
+                        ctx.append_posts(posts);
                     }
+                    ctx.cursor.replace(next_cursor);
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to search people: {}", e);
+                    ctx.fetching.set(false);
+                    eprintln!("Failed to fetch hashtag posts: {}", e);
+                    app.report_session_expiry();
+                    app.toast_unless_offline("Couldn't load these posts");
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    ctx.fetching.set(false);
+                    glib::ControlFlow::Break
+                }
             }
         });
     }
 
-    fn open_conversation_view(&self, conversation: Conversation) {
-        let pushed = {
-            let window = self.imp().window.borrow();
-            let Some(window) = window.as_ref() else {
-                return;
-            };
-            window.push_message_page(&conversation)
-        };
-        let Some(pushed) = pushed else {
-            return;
-        };
-
-        let page = match pushed {
-            MessagePush::Pushed(page) => {
-                let app = self.clone();
-                let page_weak = page.downgrade();
-                page.set_load_older_callback(move || {
-                    let Some(page) = page_weak.upgrade() else {
-                        return;
-                    };
-                    app.fetch_older_messages(&page);
-                });
-
-                // Retry redoes the first load; only a page with nothing
-                // listed ever shows the button.
-                let app = self.clone();
-                let page_weak = page.downgrade();
-                page.set_retry_callback(move || {
-                    let Some(page) = page_weak.upgrade() else {
-                        return;
-                    };
-                    app.fetch_first_messages(&page);
-                });
-
-                let app = self.clone();
-                let page_weak = page.downgrade();
-                page.set_send_callback(move |text| {
-                    let Some(page) = page_weak.upgrade() else {
-                        return;
-                    };
-                    app.send_conversation_message(&page, text);
-                });
-
-                let app = self.clone();
-                let page_weak = page.downgrade();
-                page.set_react_callback(move |message_id, value, add| {
-                    let Some(page) = page_weak.upgrade() else {
-                        return;
-                    };
-                    app.toggle_message_reaction(&page, message_id, value, add);
-                });
-
-                let app = self.clone();
-                let page_weak = page.downgrade();
-                page.set_delete_message_callback(move |message_id| {
-                    let Some(page) = page_weak.upgrade() else {
-                        return;
-                    };
-                    app.confirm_delete_message(&page, message_id);
-                });
+    /// Ask GitHub for the latest release and offer it in a toast when it
+    /// beats the running build. Quiet on every failure; an update nudge
+    /// that can error is worse than none.
+    fn check_for_updates(&self) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(String, String), String>>();
+        thread::spawn(move || {
+            let result = runtime::block_on(crate::update::fetch_latest());
+            let _ = tx.send(result);
+        });
 
-                self.start_message_poll(&page);
-                page
-            }
-            MessagePush::PoppedBack(page) => {
-                // A healthy page keeps what it has, and its poll is still
-                // running. One whose first load failed gets another try.
-                if !page.needs_reload() {
-                    return;
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(200), move || {
+            match rx.try_recv() {
+                Ok(Ok((tag, url))) => {
+                    if crate::update::newer_available(env!("HANGAR_VERSION"), &tag)
+                        && let Some(window) = app.imp().window.borrow().as_ref()
+                    {
+                        let version = tag.trim_start_matches('v').to_string();
+                        let window_weak = window.downgrade();
+                        window.show_toast_with_action(
+                            &format!("Hangar {version} is available"),
+                            "Get It",
+                            move || {
+                                if let Some(window) = window_weak.upgrade() {
+                                    crate::ui::external::open_url(
+                                        &window,
+                                        &url,
+                                        "the release page",
+                                    );
+                                }
+                            },
+                        );
+                    }
+                    glib::ControlFlow::Break
                 }
-                page
+                Ok(Err(e)) => {
+                    eprintln!("Update check failed: {}", e);
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
-        };
-
-        self.fetch_first_messages(&page);
+        });
     }
 
-    /// Fetch the newest page of a conversation's history.
-    ///
-    /// The page is its own generation token: results apply through a weak
-    /// ref, so a popped page drops whatever was still in flight for it.
-    fn fetch_first_messages(&self, page: &MessagePage) {
-        if page.is_fetching() {
-            return;
-        }
-        page.set_fetching(true);
-        let convo_id = page.convo_id();
-
-        let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Vec<ChatMessage>, Option<String>), String>>();
+    /// Mute or unmute, decided by the page's cell; the cell only flips
+    /// once the server agrees.
+    fn toggle_mute(&self, did: String, cell: std::rc::Rc<std::cell::Cell<bool>>) {
+        let muted = cell.get();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
         let client = self.client();
-        let semaphore = API_SEMAPHORE.clone();
+        let did_for_call = did.clone();
 
         thread::spawn(move || {
             let result = runtime::block_on(async {
-                let _permit = semaphore.acquire().await;
-                client.get_messages(&convo_id, None).await
+                if muted {
+                    client.unmute_actor(&did_for_call).await
+                } else {
+                    client.mute_actor(&did_for_call).await
+                }
             });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((messages, cursor))) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_cursor(cursor);
-                        page.set_initial_messages(messages);
-                        page.set_fetching(false);
-                        page.backfill_if_short();
-                        // The conversation is on screen now, so the server
-                        // can honestly call it read.
-                        app.mark_conversation_read(page.convo_id());
+                Ok(Ok(())) => {
+                    cell.set(!muted);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast(if muted {
+                            "Account unmuted"
+                        } else {
+                            "Account muted. Their posts drop from your feeds."
+                        });
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to fetch messages: {}", e);
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_fetching(false);
-                        page.show_load_failed();
-                    }
+                    eprintln!("Failed to update mute: {}", e);
                     app.report_session_expiry();
+                    app.toast_unless_offline("Couldn't update the mute");
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch messages: connection lost");
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_fetching(false);
-                        page.show_load_failed();
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Mute everyone on a moderation list, or stop. The list page and
+    /// Settings both take the new state once the server has it.
+    fn toggle_list_mute(&self, list: UserList, ctx: Option<Rc<crate::ui::ListFeedCtx>>) {
+        let muted = list.viewer_muted;
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+        let client = self.client();
+        let uri = list.uri.clone();
+        thread::spawn(move || {
+            let result = runtime::block_on(async {
+                if muted {
+                    client.unmute_list(&uri).await
+                } else {
+                    client.mute_list(&uri).await
+                }
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                Ok(Ok(())) => {
+                    let mut updated = list.clone();
+                    updated.viewer_muted = !muted;
+                    app.list_moderation_settled(updated, ctx.as_deref());
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast(if muted {
+                            "List unmuted"
+                        } else {
+                            "List muted. Posts from everyone on it drop from your feeds."
+                        });
                     }
                     glib::ControlFlow::Break
                 }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to update list mute: {}", e);
+                    app.report_session_expiry();
+                    app.list_moderation_settled(list.clone(), None);
+                    app.toast_unless_offline("Couldn't update the mute");
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Fetch the page of history older than what is shown.
-    fn fetch_older_messages(&self, page: &MessagePage) {
-        if page.is_fetching() {
+    /// Unblock a list straight away; blocking one asks first, as blocking
+    /// an account does.
+    fn toggle_list_block(&self, list: UserList, ctx: Option<Rc<crate::ui::ListFeedCtx>>) {
+        if list.viewer_blocked.is_some() {
+            self.set_list_blocked(list, ctx);
             return;
         }
-        let Some(cursor) = page.cursor() else {
+        let Some(window) = self.imp().window.borrow().clone() else {
             return;
         };
-        page.set_fetching(true);
-        let convo_id = page.convo_id();
+        let dialog = adw::AlertDialog::new(
+            Some(&format!("Block {}?", list.name)),
+            Some(
+                "Everyone on the list is blocked, including anyone added later. They won't be able to see your posts, follow you, or message you.",
+            ),
+        );
+        dialog.add_response("cancel", "Cancel");
+        dialog.add_response("block", "Block");
+        dialog.set_response_appearance("block", adw::ResponseAppearance::Destructive);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_close_response("cancel");
 
-        let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Vec<ChatMessage>, Option<String>), String>>();
-        let client = self.client();
-        let semaphore = API_SEMAPHORE.clone();
+        let app = self.clone();
+        dialog.connect_response(Some("block"), move |_, _| {
+            app.set_list_blocked(list.clone(), ctx.clone());
+        });
+        dialog.present(Some(&window));
+    }
 
+    fn set_list_blocked(&self, list: UserList, ctx: Option<Rc<crate::ui::ListFeedCtx>>) {
+        let block_uri = list.viewer_blocked.clone();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Option<String>, String>>();
+        let client = self.client();
+        let uri = list.uri.clone();
         thread::spawn(move || {
             let result = runtime::block_on(async {
-                let _permit = semaphore.acquire().await;
-                client.get_messages(&convo_id, Some(&cursor)).await
+                match block_uri {
+                    Some(block_uri) => client.unblock_list(&block_uri).await.map(|()| None),
+                    None => client.block_list(&uri).await.map(Some),
+                }
             });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((messages, cursor))) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_cursor(cursor);
-                        page.prepend_older(messages);
-                        page.set_fetching(false);
-                        // Filtered pages can leave the viewport unfilled
-                        // with a cursor still stored.
-                        page.backfill_if_short();
+                Ok(Ok(blocked)) => {
+                    let now_blocked = blocked.is_some();
+                    let mut updated = list.clone();
+                    updated.viewer_blocked = blocked;
+                    app.list_moderation_settled(updated, ctx.as_deref());
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast(if now_blocked {
+                            "List blocked"
+                        } else {
+                            "List unblocked"
+                        });
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to fetch older messages: {}", e);
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_fetching(false);
-                    }
-                    // Rows are on screen, so the status page is out; say it
-                    // out loud instead.
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("Couldn't load older messages");
-                    }
+                    eprintln!("Failed to update list block: {}", e);
                     app.report_session_expiry();
+                    app.list_moderation_settled(list.clone(), None);
+                    app.toast_unless_offline("Couldn't update the block");
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_fetching(false);
-                    }
-                    glib::ControlFlow::Break
-                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Send the composer's draft. On failure the draft stays in the entry
-    /// and the toast says to try again.
-    fn send_conversation_message(&self, page: &MessagePage, text: String) {
-        if page.is_sending() {
-            return;
+    /// Show a list's mute and block as the server now has them. A failure
+    /// passes the list as it was, which puts Settings' buttons back.
+    fn list_moderation_settled(&self, list: UserList, ctx: Option<&crate::ui::ListFeedCtx>) {
+        if let Some(ctx) = ctx {
+            ctx.set_list(list.clone());
         }
-        page.set_sending(true);
-        let convo_id = page.convo_id();
+        if let Some(window) = self.imp().window.borrow().as_ref() {
+            window.update_moderation_list(list);
+        }
+    }
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<ChatMessage, String>>();
+    /// Fetch the moderation lists the account mutes or blocks, for
+    /// Settings. A failure leaves the previous rows in place.
+    fn fetch_moderation_lists(&self) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<UserList>, String>>();
         let client = self.client();
+        let account_token = self.imp().account_generation.token();
 
         thread::spawn(move || {
-            let result =
-                runtime::block_on(async { client.send_chat_message(&convo_id, &text).await });
+            let result = runtime::block_on(async { client.moderation_lists().await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok(message)) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.sent_ok(message);
-                    }
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
                     glib::ControlFlow::Break
                 }
-                Ok(Err(e)) => {
-                    eprintln!("Failed to send message: {}", e);
-                    if let Some(page) = page_weak.upgrade() {
-                        page.send_failed();
-                    }
+                Ok(Ok(lists)) => {
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("The message didn't send. Try again.");
+                        window.set_moderation_lists(lists);
                     }
-                    app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
-                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.send_failed();
-                    }
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("The message didn't send. Try again.");
-                    }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch moderation lists: {}", e);
+                    app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Poll for new messages while the conversation page is up.
-    ///
-    /// The timer holds a weak ref and dies with the page. Ticks skip while
-    /// the page is covered or off screen, while any fetch of its own is
-    /// already out, and for the rest of a dead session.
-    fn start_message_poll(&self, page: &MessagePage) {
-        let app = self.clone();
-        let page_weak = page.downgrade();
-        glib::timeout_add_seconds_local(MESSAGE_POLL_SECS, move || {
-            let Some(page) = page_weak.upgrade() else {
-                return glib::ControlFlow::Break;
-            };
-            let busy = page.is_fetching() || page.is_polling() || !page.loaded_once();
-            if message_poll_allowed(page.is_mapped(), busy, *app.imp().session_dead.borrow()) {
-                app.poll_conversation(&page);
-            }
-            glib::ControlFlow::Continue
-        });
-    }
-
-    /// One poll round: fetch the newest page and fold in whatever is new.
-    fn poll_conversation(&self, page: &MessagePage) {
-        page.set_polling(true);
-        let convo_id = page.convo_id();
+    /// One page of a profile's Starter Packs tab, stranded by the
+    /// generation the way the post tabs are.
+    fn fetch_profile_starter_packs(
+        &self,
+        ctx: Rc<crate::ui::ProfileFeedCtx>,
+        did: String,
+        first_page: bool,
+    ) {
+        ctx.fetching.set(true);
+        let generation = ctx.generation.get();
+        let cursor = if first_page {
+            None
+        } else {
+            ctx.cursor.borrow().clone()
+        };
 
+        let account_token = self.imp().account_generation.token();
         let (tx, rx) =
-            std::sync::mpsc::channel::<Result<(Vec<ChatMessage>, Option<String>), String>>();
+            std::sync::mpsc::channel::<Result<(Vec<StarterPack>, Option<String>), String>>();
         let client = self.client();
-
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_messages(&convo_id, None).await });
+            let result = runtime::block_on(async {
+                client
+                    .get_actor_starter_packs(&did, cursor.as_deref())
+                    .await
+            });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            if ctx.generation.get() != generation {
+                return glib::ControlFlow::Break;
+            }
             match rx.try_recv() {
-                Ok(Ok((messages, _cursor))) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_polling(false);
-                        let added = page.merge_new(messages);
-                        // Only a page still on screen can honestly claim
-                        // the user saw what just arrived.
-                        if added > 0 && page.is_mapped() {
-                            app.mark_conversation_read(page.convo_id());
-                        }
-                    }
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    ctx.fetching.set(false);
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((packs, next_cursor))) => {
+                    ctx.fetching.set(false);
+                    ctx.cursor.replace(next_cursor);
+                    ctx.append_starter_packs(packs);
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    // Transient failures self-heal on the next tick; an
-                    // expiry parks the poll through the flag it raises.
-                    eprintln!("Failed to poll messages: {}", e);
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_polling(false);
-                    }
+                    ctx.fetching.set(false);
+                    eprintln!("Failed to fetch starter packs: {}", e);
                     app.report_session_expiry();
+                    app.toast_unless_offline("Couldn't load starter packs");
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.set_polling(false);
-                    }
+                    ctx.fetching.set(false);
                     glib::ControlFlow::Break
                 }
             }
         });
     }
 
-    /// Add or remove one emoji reaction. The server answers with the
-    /// updated message and the page swaps it in; nothing is guessed.
-    fn toggle_message_reaction(
-        &self,
-        page: &MessagePage,
-        message_id: String,
-        value: String,
-        add: bool,
-    ) {
-        let convo_id = page.convo_id();
-        let (tx, rx) = std::sync::mpsc::channel::<Result<ChatMessage, String>>();
-        let client = self.client();
+    fn open_starter_pack(&self, pack: StarterPack) {
+        let ctx = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .and_then(|window| window.push_starter_pack_page(&pack));
+        if let Some(ctx) = ctx {
+            self.fetch_starter_pack_members(ctx, true);
+        }
+    }
+
+    /// One page of a starter pack's people. The first also brings the
+    /// full pack, whose feeds and list a profile's listing leaves out.
+    fn fetch_starter_pack_members(&self, ctx: Rc<crate::ui::StarterPackCtx>, first_page: bool) {
+        if ctx.fetching.get() {
+            return;
+        }
+        ctx.fetching.set(true);
+        let generation = ctx.generation.get();
+        let pack = ctx.pack();
+        let cursor = if first_page {
+            None
+        } else {
+            ctx.cursor.borrow().clone()
+        };
 
+        type Page = (Option<StarterPack>, Vec<ListMember>, Option<String>);
+        let account_token = self.imp().account_generation.token();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Page, String>>();
+        let client = self.client();
         thread::spawn(move || {
             let result = runtime::block_on(async {
-                if add {
-                    client
-                        .add_chat_reaction(&convo_id, &message_id, &value)
-                        .await
+                let full = if first_page {
+                    Some(client.get_starter_pack(&pack.uri).await?)
                 } else {
-                    client
-                        .remove_chat_reaction(&convo_id, &message_id, &value)
-                        .await
-                }
+                    None
+                };
+                let list_uri = full
+                    .as_ref()
+                    .map_or(pack.list_uri.clone(), |full| full.list_uri.clone());
+                let Some(list_uri) = list_uri else {
+                    return Ok((full, Vec::new(), None));
+                };
+                let (_, members, next) = client.get_list(&list_uri, cursor.as_deref()).await?;
+                Ok::<Page, ClientError>((full, members, next))
             });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            if ctx.generation.get() != generation {
+                return glib::ControlFlow::Break;
+            }
             match rx.try_recv() {
-                Ok(Ok(message)) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.update_message(message);
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    ctx.fetching.set(false);
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((full, members, next_cursor))) => {
+                    ctx.fetching.set(false);
+                    if let Some(full) = full {
+                        ctx.set_pack(full);
                     }
+                    ctx.append_members(members);
+                    ctx.cursor.replace(next_cursor);
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to update reaction: {}", e);
+                    ctx.fetching.set(false);
+                    eprintln!("Failed to fetch starter pack: {}", e);
                     app.report_session_expiry();
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("Couldn't update the reaction");
-                    }
+                    app.toast_unless_offline("Couldn't load this starter pack");
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    ctx.fetching.set(false);
+                    glib::ControlFlow::Break
+                }
             }
         });
     }
 
-    /// Delete for Me is quiet but permanent for this account; ask first.
-    fn confirm_delete_message(&self, page: &MessagePage, message_id: String) {
-        let window = match self.imp().window.borrow().as_ref() {
-            Some(w) => w.clone(),
-            None => return,
+    /// Follow everyone in the pack the user does not follow yet, the
+    /// whole list rather than the rows loaded so far, in batched writes.
+    fn follow_starter_pack(&self, ctx: Rc<crate::ui::StarterPackCtx>) {
+        let Some(list_uri) = ctx.pack().list_uri else {
+            return;
         };
+        ctx.set_following_all(true);
 
-        let dialog = adw::AlertDialog::new(
-            Some("Delete for you?"),
-            Some("The message disappears from your view only. The other side keeps their copy."),
-        );
-        dialog.add_response("cancel", "Cancel");
-        dialog.add_response("delete", "Delete");
-        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
-        dialog.set_default_response(Some("cancel"));
-        dialog.set_close_response("cancel");
-
-        let app = self.clone();
-        let page_weak = page.downgrade();
-        dialog.connect_response(Some("delete"), move |_, _| {
-            let Some(page) = page_weak.upgrade() else {
-                return;
-            };
-            app.delete_message_for_me(&page, message_id.clone());
-        });
-        dialog.present(Some(&window));
-    }
-
-    fn delete_message_for_me(&self, page: &MessagePage, message_id: String) {
-        let convo_id = page.convo_id();
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<(String, String)>, String>>();
         let client = self.client();
-        let id_for_call = message_id.clone();
-
         thread::spawn(move || {
             let result = runtime::block_on(async {
-                client.delete_chat_message(&convo_id, &id_for_call).await
+                let dids: Vec<String> = client
+                    .all_list_members(&list_uri)
+                    .await?
+                    .into_iter()
+                    .filter(|member| member.profile.viewer_following.is_none())
+                    .map(|member| member.profile.did)
+                    .collect();
+                client.follow_all(&dids).await
             });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok(())) => {
-                    if let Some(page) = page_weak.upgrade() {
-                        page.remove_message(&message_id);
+                Ok(Ok(follows)) => {
+                    ctx.set_following_all(false);
+                    ctx.mark_followed(&follows);
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.show_toast(&match follows.len() {
+                            0 => "You already follow everyone here".to_string(),
+                            1 => "Followed 1 person".to_string(),
+                            n => format!("Followed {n} people"),
+                        });
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to delete message: {}", e);
+                    ctx.set_following_all(false);
+                    eprintln!("Failed to follow starter pack: {}", e);
                     app.report_session_expiry();
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("Couldn't delete the message");
+                        window.show_toast("Couldn't follow everyone in the pack");
                     }
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    ctx.set_following_all(false);
+                    glib::ControlFlow::Break
+                }
             }
         });
     }
 
-    /// Tell the server the conversation is read, then let the UI follow:
-    /// the row in the chat list drops its badge and the sidebar re-tallies.
-    /// On failure both stay lit, which is the truth; the next successful
-    /// open clears them.
-    fn mark_conversation_read(&self, convo_id: String) {
+    /// Write the account's saved feeds as Settings or a feed page left
+    /// them, then bring the feed selector in line. The window already
+    /// shows the edit; if the write fails the list is read back.
+    fn save_feed_list(&self, feeds: Vec<SavedFeed>) {
         let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
         let client = self.client();
-        let id = convo_id.clone();
-
+        let to_write = feeds.clone();
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.mark_convo_read(&id).await });
+            let result = runtime::block_on(async { client.set_saved_feed_list(&to_write).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
@@ -3840,361 +7597,289 @@ impl HangarApplication {
             match rx.try_recv() {
                 Ok(Ok(())) => {
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_conversation_read(&convo_id);
+                        let mut selector = SavedFeed::selector_feeds(&feeds);
+                        selector.extend(saved_search_feeds());
+                        window.set_saved_feeds(selector);
                     }
-                    app.check_unread_counts();
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to mark conversation read: {}", e);
+                    eprintln!("Failed to save feeds: {}", e);
                     app.report_session_expiry();
+                    app.toast_unless_offline("Couldn't save your feeds");
+                    app.fetch_saved_feeds();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    app.fetch_saved_feeds();
+                    glib::ControlFlow::Break
+                }
             }
         });
     }
 
-    /// Open the own profile view
-    fn open_own_profile_view(&self) {
-        // Switch to profile page (instant, no animation)
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.show_profile_page();
-        }
-
-        // The first open loads; after that the page keeps what it has.
-        let loaded = self
+    /// Push Discover Feeds and load the suggestions.
+    fn open_feed_browser(&self) {
+        let page = self
             .imp()
             .window
             .borrow()
             .as_ref()
-            .and_then(|w| w.own_profile_feed_ctx())
-            .is_some_and(|ctx| !ctx.did.borrow().is_empty());
-        if !loaded {
-            self.fetch_profile_posts();
-        }
-    }
-
-    /// Fetch posts for the logged-in user's profile
-    fn fetch_profile_posts(&self) {
-        let Some(user_did) = self.imp().user_did.borrow().clone() else {
-            eprintln!("Cannot fetch profile posts: no user DID");
-            return;
-        };
-        let Some(window) = self.imp().window.borrow().clone() else {
-            return;
-        };
-        let Some(ctx) = window.own_profile_feed_ctx() else {
+            .and_then(|window| window.push_feed_browser());
+        let Some(page) = page else {
             return;
         };
-        // A reopen starts the active tab over from the top.
-        ctx.did.replace(user_did);
-        ctx.begin_refresh();
-        self.fetch_profile_tab(ctx, true);
-    }
-
-    /// Open the likes view
-    fn open_likes_view(&self) {
-        // Switch to likes page (instant, no animation)
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.show_likes_page();
-        }
-
-        // Only fetch if we haven't loaded likes yet
-        if self.imp().likes_cursor.borrow().is_none() {
-            self.fetch_likes();
-        }
-    }
 
-    /// Fetch liked posts for the logged-in user
-    fn fetch_likes(&self) {
-        let user_did = match self.imp().user_did.borrow().clone() {
-            Some(did) => did,
-            None => {
-                eprintln!("Cannot fetch likes: no user DID");
-                return;
+        let app = self.clone();
+        let page_weak = page.downgrade();
+        page.set_search_callback(move || {
+            if let Some(page) = page_weak.upgrade() {
+                app.fetch_feed_browser(&page, None);
             }
-        };
-
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
-        let client = self.client();
-
-        thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_actor_likes(&user_did, None).await });
-            let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-            match rx.try_recv() {
-                Ok(Ok((posts, next_cursor))) => {
-                    app.imp().likes_cursor.replace(next_cursor);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_likes(posts);
-                    }
-                    glib::ControlFlow::Break
-                }
-                Ok(Err(e)) => {
-                    eprintln!("Failed to fetch likes: {}", e);
-                    app.toast_unless_offline("Couldn't load likes");
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_likes_load_failed();
-                    }
-                    glib::ControlFlow::Break
-                }
-                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch likes: connection lost");
-                    glib::ControlFlow::Break
-                }
-            }
+        let page_weak = page.downgrade();
+        page.set_load_more_callback(move || {
+            let Some(page) = page_weak.upgrade() else {
+                return;
+            };
+            let Some(cursor) = page.cursor() else {
+                return;
+            };
+            app.fetch_feed_browser(&page, Some(cursor));
         });
-    }
 
-    /// Fetch more liked posts for infinite scroll
-    fn fetch_likes_more(&self) {
-        if *self.imp().likes_loading_more.borrow() {
-            return;
-        }
-        let cursor = match self.imp().likes_cursor.borrow().as_ref() {
-            Some(c) => c.clone(),
-            None => return,
-        };
-        let user_did = match self.imp().user_did.borrow().clone() {
-            Some(did) => did,
-            None => return,
-        };
-        self.imp().likes_loading_more.replace(true);
+        let app = self.clone();
+        let page_weak = page.downgrade();
+        page.set_retry_callback(move || {
+            if let Some(page) = page_weak.upgrade() {
+                app.fetch_feed_browser(&page, page.cursor());
+            }
+        });
 
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.set_likes_loading(true);
+        self.fetch_feed_browser(&page, None);
+    }
+
+    /// Fetch one page of Discover Feeds: the suggestions with no search,
+    /// the popular feeds matching it otherwise. A page that lands after
+    /// the search changed is dropped.
+    fn fetch_feed_browser(&self, page: &FeedBrowser, cursor: Option<String>) {
+        if page.is_fetching() {
+            return;
         }
+        page.set_fetching(true);
+        let generation = page.generation();
+        let query = page.query();
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+        let account_token = self.imp().account_generation.token();
+        let (tx, rx) =
+            std::sync::mpsc::channel::<Result<(Vec<FeedGenerator>, Option<String>), String>>();
         let client = self.client();
-
         thread::spawn(move || {
-            let result =
-                runtime::block_on(async { client.get_actor_likes(&user_did, Some(&cursor)).await });
+            let result = runtime::block_on(async {
+                if query.is_empty() {
+                    client.get_suggested_feeds(cursor.as_deref()).await
+                } else {
+                    client
+                        .search_feed_generators(Some(&query), cursor.as_deref())
+                        .await
+                }
+            });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
+        let page_weak = page.downgrade();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            let Some(page) = page_weak.upgrade() else {
+                return glib::ControlFlow::Break;
+            };
+            if page.generation() != generation {
+                return glib::ControlFlow::Break;
+            }
             match rx.try_recv() {
-                Ok(Ok((posts, next_cursor))) => {
-                    app.imp().likes_loading_more.replace(false);
-                    app.imp().likes_cursor.replace(next_cursor);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_likes_loading(false);
-                        if !posts.is_empty() {
-                            window.append_likes(posts);
-                        }
-                    }
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    page.set_fetching(false);
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok((feeds, next_cursor))) => {
+                    page.set_cursor(next_cursor);
+                    page.append_feeds(feeds);
+                    page.set_fetching(false);
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    app.imp().likes_loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_likes_loading(false);
-                    }
-                    eprintln!("Failed to fetch more likes: {}", e);
+                    eprintln!("Failed to fetch feeds: {}", e);
+                    page.set_fetching(false);
+                    page.show_load_failed();
+                    app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    app.imp().likes_loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_likes_loading(false);
-                    }
+                    page.set_fetching(false);
+                    page.show_load_failed();
                     glib::ControlFlow::Break
                 }
             }
         });
     }
 
-    /// Open the Saved view. Posts get saved and unsaved from every list, and
-    /// from other clients, so this refetches on every open rather than
-    /// trusting a list loaded earlier.
-    fn open_bookmarks_view(&self) {
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.show_bookmarks_page();
-        }
-        self.fetch_bookmarks();
-    }
-
-    /// Fetch the first page of saved posts, replacing the list
-    fn fetch_bookmarks(&self) {
-        // Take ownership of the list: anything still in flight from an
-        // earlier visit belongs to the page this replaces.
-        self.imp().bookmarks_generation.bump();
-        let token = self.imp().bookmarks_generation.token();
-        self.imp().bookmarks_loading_more.replace(false);
+    /// Open a feed's page on what is known of it, then fill in the full
+    /// view: a starter pack's feeds come without the viewer's like.
+    fn open_feed(&self, feed: FeedGenerator) {
+        let ctx = self
+            .imp()
+            .window
+            .borrow()
+            .as_ref()
+            .and_then(|window| window.push_feed_page(&feed));
+        let Some(ctx) = ctx else {
+            return;
+        };
 
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<FeedGenerator, String>>();
         let client = self.client();
-
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_bookmarks(None).await });
+            let result = runtime::block_on(async { client.get_feed_generator(&feed.uri).await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(result) => {
-                    if !app.imp().bookmarks_generation.is_current(token) {
-                        return glib::ControlFlow::Break;
-                    }
-                    match result {
-                        Ok((posts, next_cursor)) => {
-                            app.imp().bookmarks_cursor.replace(next_cursor);
-                            if let Some(window) = app.imp().window.borrow().as_ref() {
-                                window.set_bookmarks(posts);
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to fetch saved posts: {}", e);
-                            app.report_session_expiry();
-                            if let Some(window) = app.imp().window.borrow().as_ref() {
-                                window.show_toast("Couldn't load saved posts");
-                                window.set_bookmarks_load_failed();
-                            }
-                        }
-                    }
+                Ok(Ok(full)) => {
+                    ctx.set_feed(full);
                     glib::ControlFlow::Break
                 }
-                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    eprintln!("Failed to fetch saved posts: connection lost");
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast("Couldn't load saved posts");
-                    }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch feed: {}", e);
+                    app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Fetch more saved posts for infinite scroll
-    fn fetch_bookmarks_more(&self) {
-        if *self.imp().bookmarks_loading_more.borrow() {
-            return;
-        }
-        let cursor = match self.imp().bookmarks_cursor.borrow().as_ref() {
-            Some(c) => c.clone(),
-            None => return,
-        };
-        self.imp().bookmarks_loading_more.replace(true);
-        let token = self.imp().bookmarks_generation.token();
-
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.set_bookmarks_loading(true);
-        }
-
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+    /// Like the feed on a feed page, or unlike it if the viewer already
+    /// did, and count it on the page.
+    fn toggle_feed_like(&self, ctx: Rc<crate::ui::FeedGeneratorCtx>) {
+        let feed = ctx.feed();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Option<String>, String>>();
         let client = self.client();
-
+        let (uri, cid, like) = (feed.uri.clone(), feed.cid.clone(), feed.viewer_like.clone());
         thread::spawn(move || {
-            let result = runtime::block_on(async { client.get_bookmarks(Some(&cursor)).await });
+            let result = runtime::block_on(async {
+                match like {
+                    Some(like_uri) => client.unlike(&like_uri).await.map(|()| None),
+                    None => client.like(&uri, &cid).await.map(Some),
+                }
+            });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(result) => {
-                    app.imp().bookmarks_loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_bookmarks_loading(false);
-                    }
-                    if !app.imp().bookmarks_generation.is_current(token) {
-                        return glib::ControlFlow::Break;
-                    }
-                    match result {
-                        Ok((posts, next_cursor)) => {
-                            app.imp().bookmarks_cursor.replace(next_cursor);
-                            if !posts.is_empty() {
-                                if let Some(window) = app.imp().window.borrow().as_ref() {
-                                    window.append_bookmarks(posts);
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to fetch more saved posts: {}", e);
-                            app.report_session_expiry();
-                            if let Some(window) = app.imp().window.borrow().as_ref() {
-                                window.show_toast("Couldn't load more saved posts");
-                            }
-                        }
-                    }
+                Ok(Ok(like)) => {
+                    let mut liked = feed.clone();
+                    liked.like_count = liked.like_count.map(|n| match like {
+                        Some(_) => n.saturating_add(1),
+                        None => n.saturating_sub(1),
+                    });
+                    liked.viewer_like = like;
+                    ctx.set_feed(liked);
+                    ctx.set_liking(false);
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    ctx.set_liking(false);
+                    eprintln!("Failed to like feed: {}", e);
+                    app.report_session_expiry();
+                    app.toast_unless_offline(if feed.viewer_like.is_some() {
+                        "Couldn't unlike the feed"
+                    } else {
+                        "Couldn't like the feed"
+                    });
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    app.imp().bookmarks_loading_more.replace(false);
-                    if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.set_bookmarks_loading(false);
-                    }
+                    ctx.set_liking(false);
                     glib::ControlFlow::Break
                 }
             }
         });
     }
 
-    /// Save or unsave a post, whichever the menu offered
-    fn toggle_bookmark(&self, post: Post, row_weak: glib::WeakRef<PostRow>) {
-        let was_saved = post.viewer_bookmarked == Some(true);
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
-        let client = self.client();
-        let uri = post.uri.clone();
-        let cid = post.cid.clone();
+    /// Ask for a starter pack's name and feeds, offering the custom feeds
+    /// pinned to the feed selector, then make it from `list`.
+    fn new_starter_pack(&self, list: UserList) {
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+        let feeds = window
+            .saved_feeds()
+            .into_iter()
+            .filter(|feed| feed.feed_type == "feed")
+            .collect();
+        let app = self.clone();
+        let source = list.clone();
+        crate::ui::list_dialog::present_starter_pack_editor(
+            &window,
+            &list,
+            feeds,
+            move |name, description, feed_uris| {
+                app.create_starter_pack(source.uri.clone(), name, description, feed_uris);
+            },
+        );
+    }
 
+    /// Copy everyone on the list into a new starter pack. It shows up on
+    /// the user's profile under Starter Packs.
+    fn create_starter_pack(
+        &self,
+        list_uri: String,
+        name: String,
+        description: String,
+        feed_uris: Vec<String>,
+    ) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<(String, String), String>>();
+        let client = self.client();
         thread::spawn(move || {
             let result = runtime::block_on(async {
-                if was_saved {
-                    client.delete_bookmark(&uri).await
-                } else {
-                    client.create_bookmark(&uri, &cid).await
-                }
+                let members: Vec<String> = client
+                    .all_list_members(&list_uri)
+                    .await?
+                    .into_iter()
+                    .map(|member| member.profile.did)
+                    .collect();
+                client
+                    .create_starter_pack(&name, &description, &members, &feed_uris)
+                    .await
             });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        let uri = post.uri;
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok(())) => {
-                    // Flip the row, but only if it is still showing this post;
-                    // a pooled row may have been rebound while we waited.
-                    if let Some(row) = row_weak.upgrade() {
-                        if row.post_uri().as_deref() == Some(uri.as_str()) {
-                            row.set_viewer_bookmarked(!was_saved);
-                        }
-                    }
+                Ok(Ok(_)) => {
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        if was_saved {
-                            // Unsaving from the Saved page takes the row out
-                            // now; from any other list this is a no-op.
-                            window.remove_bookmark(&uri);
-                            window.show_toast("Removed from Saved");
-                        } else {
-                            window.show_toast("Post saved");
-                        }
+                        window.show_toast("Starter pack created");
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to update bookmark: {}", e);
+                    eprintln!("Failed to create starter pack: {}", e);
                     app.report_session_expiry();
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast(if was_saved {
-                            "Couldn't remove from Saved"
-                        } else {
-                            "Couldn't save post"
-                        });
+                        window.show_toast("Couldn't create the starter pack");
                     }
                     glib::ControlFlow::Break
                 }
@@ -4204,69 +7889,35 @@ impl HangarApplication {
         });
     }
 
-    /// Track connectivity. Going offline raises the banner and parks the
-    /// polls; coming back lowers it and refreshes the feed, since whatever
-    /// is on screen predates the outage.
-    fn set_network_available(&self, available: bool) {
-        let was_offline = *self.imp().offline.borrow();
-        self.imp().offline.replace(!available);
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.set_offline(!available);
-        }
-        if available && was_offline && self.imp().user_did.borrow().is_some() {
-            self.fetch_timeline();
-        }
-    }
-
-    /// A failure toast, unless the offline banner already explains it.
-    fn toast_unless_offline(&self, message: &str) {
-        if *self.imp().offline.borrow() {
-            return;
-        }
-        if let Some(window) = self.imp().window.borrow().as_ref() {
-            window.show_toast(message);
-        }
-    }
-
-    /// Flip a follow on the server and hand the outcome to `done` on the
-    /// main thread: Ok(Some(uri)) followed, Ok(None) unfollowed. A failure
-    /// toasts and reports session expiry before `done` runs.
-    fn follow_flip<F: Fn(Result<Option<String>, String>) + 'static>(
-        &self,
-        did: String,
-        current_uri: Option<String>,
-        done: F,
-    ) {
-        let unfollowing = current_uri.is_some();
-        let (tx, rx) = std::sync::mpsc::channel::<Result<Option<String>, String>>();
+    /// Fetch the account's muted words so the lists can drop matching posts.
+    /// A failure leaves the previous list in place.
+    fn fetch_muted_words(&self) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<MutedWord>, String>>();
         let client = self.client();
-
-        thread::spawn(move || {
-            let result = runtime::block_on(async {
-                match current_uri {
-                    Some(uri) => client.unfollow(&uri).await.map(|_| None),
-                    None => client.follow(&did).await.map(Some),
-                }
-            });
+        let account_token = self.imp().account_generation.token();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_muted_words().await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(result) => {
-                    if let Err(e) = &result {
-                        eprintln!("Failed to update follow: {}", e);
-                        app.report_session_expiry();
-                        if let Some(window) = app.imp().window.borrow().as_ref() {
-                            window.show_toast(if unfollowing {
-                                "Couldn't unfollow"
-                            } else {
-                                "Couldn't follow"
-                            });
-                        }
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok(words)) => {
+                    app.imp().muted_words.replace(words.clone());
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_muted_words(words);
                     }
-                    done(result);
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch muted words: {}", e);
+                    app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
@@ -4275,159 +7926,109 @@ impl HangarApplication {
         });
     }
 
-    /// Follow or unfollow from a profile page, decided by whether the page
-    /// holds a record URI.
-    ///
-    /// The button already shows the hoped-for state and is insensitive; on
-    /// success the cell takes the new URI, on failure the button goes back.
-    fn toggle_follow(
-        &self,
-        did: String,
-        uri_cell: Rc<RefCell<Option<String>>>,
-        btn_weak: glib::WeakRef<gtk4::Button>,
-    ) {
-        let current_uri = uri_cell.borrow().clone();
-        self.follow_flip(did, current_uri, move |result| {
-            if let Ok(new_uri) = result {
-                uri_cell.replace(new_uri);
-            }
-            if let Some(btn) = btn_weak.upgrade() {
-                HangarWindow::sync_follow_button(&btn, uri_cell.borrow().is_some());
-            }
+    /// Fetch the account's label preferences so rows can cover labeled
+    /// posts. Until they arrive, adult labels hide, as for a new account.
+    fn fetch_moderation_prefs(&self) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<ModerationPrefs, String>>();
+        let client = self.client();
+        let account_token = self.imp().account_generation.token();
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.get_moderation_prefs().await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
         });
-    }
 
-    /// Follow or unfollow from a people row. The result only lands if the
-    /// recycled row still shows the same profile; the model object behind
-    /// it is updated either way through the row.
-    fn toggle_follow_row(
-        &self,
-        profile: Profile,
-        row_weak: glib::WeakRef<crate::ui::actor_row::ActorRow>,
-    ) {
-        let did = profile.did.clone();
-        let current_uri = profile.viewer_following.clone();
-        self.follow_flip(profile.did, current_uri.clone(), move |result| {
-            let Some(row) = row_weak.upgrade() else {
-                return;
-            };
-            if row.profile_did().as_deref() != Some(did.as_str()) {
-                return;
-            }
-            match result {
-                Ok(new_uri) => row.set_viewer_following(new_uri),
-                // The button showed the hoped-for state; put it back.
-                Err(_) => row.set_viewer_following(current_uri.clone()),
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok(prefs)) => {
+                    app.imp().moderation_prefs.replace(prefs.clone());
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_moderation_prefs(prefs);
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch moderation preferences: {}", e);
+                    app.report_session_expiry();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// What a tab's page actually lists. The server's posts_with_replies
-    /// is a superset with standalone posts included; the Replies tab wants
-    /// only the replies, so the rest of the page stays behind.
-    fn tab_posts(filter: &str, mut posts: Vec<Post>) -> Vec<Post> {
-        if filter == "posts_with_replies" {
-            posts.retain(|post| post.reply_context.is_some());
-        }
-        posts
-    }
-
-    /// One page of a profile tab's feed. First pages were cleared by the
-    /// tab switch; later pages append. The context's generation strands a
-    /// fetch whose tab was switched away while it was out.
-    fn fetch_profile_tab(&self, ctx: std::rc::Rc<crate::ui::ProfileFeedCtx>, first_page: bool) {
-        if ctx.fetching.get() {
-            return;
-        }
-        let did = ctx.did.borrow().clone();
-        // The own page's context exists before sign-in does.
-        if did.is_empty() {
-            return;
-        }
-        ctx.fetching.set(true);
-        let generation = ctx.generation.get();
-        let filter = ctx.filter.get();
-        let cursor = if first_page {
-            None
-        } else {
-            ctx.cursor.borrow().clone()
-        };
-
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
+    /// Fetch which notifications the server lists and pushes, for
+    /// Settings.
+    fn fetch_notification_prefs(&self) {
+        let (tx, rx) = std::sync::mpsc::channel::<Result<NotificationPrefs, String>>();
         let client = self.client();
+        let account_token = self.imp().account_generation.token();
+
         thread::spawn(move || {
-            let result = runtime::block_on(async {
-                client
-                    .get_author_feed(&did, cursor.as_deref(), Some(filter))
-                    .await
-            });
+            let result = runtime::block_on(async { client.get_notification_prefs().await });
             let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-            if ctx.generation.get() != generation {
-                return glib::ControlFlow::Break;
-            }
             match rx.try_recv() {
-                Ok(Ok((posts, next_cursor))) => {
-                    ctx.fetching.set(false);
-                    ctx.cursor.replace(next_cursor);
-                    ctx.append_posts(Self::tab_posts(filter, posts));
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
                     glib::ControlFlow::Break
                 }
-                Ok(Err(e)) => {
-                    ctx.fetching.set(false);
-                    eprintln!("Failed to fetch profile tab: {}", e);
-                    app.toast_unless_offline("Couldn't load these posts");
+                Ok(Ok(prefs)) => {
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_notification_prefs(prefs);
+                    }
                     glib::ControlFlow::Break
                 }
-                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                    ctx.fetching.set(false);
+                Ok(Err(e)) => {
+                    eprintln!("Failed to fetch notification preferences: {}", e);
+                    app.report_session_expiry();
                     glib::ControlFlow::Break
                 }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
             }
         });
     }
 
-    /// Ask GitHub for the latest release and offer it in a toast when it
-    /// beats the running build. Quiet on every failure; an update nudge
-    /// that can error is worse than none.
-    fn check_for_updates(&self) {
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(String, String), String>>();
+    /// Write one notification preference and show the set the server kept.
+    fn edit_notification_pref(&self, edit: NotificationPrefEdit) {
+        let account_token = self.imp().account_generation.token();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<NotificationPrefs, String>>();
+        let client = self.client();
+
         thread::spawn(move || {
-            let result = runtime::block_on(crate::update::fetch_latest());
-            let _ = tx.send(result);
+            let result = runtime::block_on(async { client.put_notification_pref(edit).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
         });
 
         let app = self.clone();
-        glib::timeout_add_local(std::time::Duration::from_millis(200), move || {
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok((tag, url))) => {
-                    if crate::update::newer_available(env!("HANGAR_VERSION"), &tag)
-                        && let Some(window) = app.imp().window.borrow().as_ref()
-                    {
-                        let version = tag.trim_start_matches('v').to_string();
-                        let window_weak = window.downgrade();
-                        window.show_toast_with_action(
-                            &format!("Hangar {version} is available"),
-                            "Get It",
-                            move || {
-                                if let Some(window) = window_weak.upgrade() {
-                                    crate::ui::external::open_url(
-                                        &window,
-                                        &url,
-                                        "the release page",
-                                    );
-                                }
-                            },
-                        );
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok(prefs)) => {
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_notification_prefs(prefs);
                     }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Update check failed: {}", e);
+                    eprintln!("Failed to update notification preferences: {}", e);
+                    app.report_session_expiry();
+                    app.toast_unless_offline("Couldn't update alerts");
+                    // Put the rows back the way the server has them.
+                    app.fetch_notification_prefs();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
@@ -4436,20 +8037,29 @@ impl HangarApplication {
         });
     }
 
-    /// Mute or unmute, decided by the page's cell; the cell only flips
-    /// once the server agrees.
-    fn toggle_mute(&self, did: String, cell: std::rc::Rc<std::cell::Cell<bool>>) {
-        let muted = cell.get();
-        let (tx, rx) = std::sync::mpsc::channel::<Result<(), String>>();
+    /// Add a muted word, or replace the entry with the same id.
+    fn save_muted_word(&self, word: MutedWord) {
+        self.edit_muted_words(word, false);
+    }
+
+    /// Drop a muted word, then reload the timeline so the posts it hid
+    /// come back.
+    fn remove_muted_word(&self, word: MutedWord) {
+        self.edit_muted_words(word, true);
+    }
+
+    /// Save or remove one muted word and show the list the server kept.
+    fn edit_muted_words(&self, word: MutedWord, removing: bool) {
+        let account_token = self.imp().account_generation.token();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<Vec<MutedWord>, String>>();
         let client = self.client();
-        let did_for_call = did.clone();
 
         thread::spawn(move || {
             let result = runtime::block_on(async {
-                if muted {
-                    client.unmute_actor(&did_for_call).await
+                if removing {
+                    client.remove_muted_word(&word).await
                 } else {
-                    client.mute_actor(&did_for_call).await
+                    client.save_muted_word(word).await
                 }
             });
             let _ = tx.send(result.map_err(|e| e.to_string()));
@@ -4458,21 +8068,91 @@ impl HangarApplication {
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
             match rx.try_recv() {
-                Ok(Ok(())) => {
-                    cell.set(!muted);
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok(words)) => {
+                    app.imp().muted_words.replace(words.clone());
                     if let Some(window) = app.imp().window.borrow().as_ref() {
-                        window.show_toast(if muted {
-                            "Account unmuted"
+                        window.set_muted_words(words);
+                        window.show_toast(if removing {
+                            "Muted word removed"
                         } else {
-                            "Account muted. Their posts drop from your feeds."
+                            "Muted word saved"
                         });
                     }
+                    if removing {
+                        app.fetch_timeline();
+                    }
                     glib::ControlFlow::Break
                 }
                 Ok(Err(e)) => {
-                    eprintln!("Failed to update mute: {}", e);
+                    eprintln!("Failed to update muted words: {}", e);
                     app.report_session_expiry();
-                    app.toast_unless_offline("Couldn't update the mute");
+                    app.toast_unless_offline("Couldn't update muted words");
+                    // Put the rows back the way the server has them.
+                    app.fetch_muted_words();
+                    glib::ControlFlow::Break
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Write one moderation settings change and show the settings the
+    /// server kept. A labeler coming or going changes which labels posts
+    /// carry, so the timeline reloads with it.
+    fn edit_moderation(&self, edit: ModerationEdit) {
+        let account_token = self.imp().account_generation.token();
+        let (tx, rx) = std::sync::mpsc::channel::<Result<ModerationPrefs, String>>();
+        let client = self.client();
+        let relabels = matches!(
+            edit,
+            ModerationEdit::Subscribe(_) | ModerationEdit::Unsubscribe(_)
+        );
+        let toast = match &edit {
+            ModerationEdit::Subscribe(_) => Some("Subscribed to labeler"),
+            ModerationEdit::Unsubscribe(_) => Some("Unsubscribed from labeler"),
+            _ => None,
+        };
+
+        thread::spawn(move || {
+            let result = runtime::block_on(async { client.edit_moderation(edit).await });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+
+        let app = self.clone();
+        glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
+            match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    glib::ControlFlow::Break
+                }
+                Ok(Ok(prefs)) => {
+                    app.imp().moderation_prefs.replace(prefs.clone());
+                    if let Some(window) = app.imp().window.borrow().as_ref() {
+                        window.set_moderation_prefs(prefs);
+                        if let Some(toast) = toast {
+                            window.show_toast(toast);
+                        }
+                    }
+                    if relabels {
+                        app.fetch_timeline();
+                    }
+                    glib::ControlFlow::Break
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to update moderation settings: {}", e);
+                    app.report_session_expiry();
+                    app.toast_unless_offline(if relabels {
+                        "Couldn't update labelers"
+                    } else {
+                        "Couldn't update content filters"
+                    });
+                    // Put the rows back the way the server has them.
+                    app.fetch_moderation_prefs();
                     glib::ControlFlow::Break
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
@@ -4748,8 +8428,12 @@ impl HangarApplication {
         }
     }
 
-    /// Execute a search with the given query
-    fn execute_search(&self, query: String) {
+    /// Execute a search with the given query, operators and all
+    fn execute_search(&self, input: String) {
+        let query = SearchQuery::parse(&input);
+        if query.is_empty() {
+            return;
+        }
         // Strand any fetch still out for the previous query
         self.imp().search_generation.bump();
 
@@ -4762,20 +8446,64 @@ impl HangarApplication {
 
         if let Some(window) = self.imp().window.borrow().as_ref() {
             window.clear_search_results();
+            window.set_search_filters(&query);
+            let input = query.to_input();
+            window.set_search_saved(
+                crate::state::AppSettings::load()
+                    .saved_searches
+                    .contains(&input),
+            );
             window.set_search_loading(true);
-            window.set_search_people_loading(true);
+            window.set_search_people_loading(!query.text.is_empty());
         }
 
+        self.search_history();
         self.fetch_search(&query);
-        self.fetch_search_people(&query);
+        // Filters mean nothing to an account search; with no words there
+        // are no people to look for.
+        if !query.text.is_empty() {
+            self.fetch_search_people(&query.text);
+        }
+    }
+
+    /// Search the posts already cached for the History page. Local and
+    /// indexed, so it runs here rather than on a worker, and needs no
+    /// network at all.
+    fn search_history(&self) {
+        let Some(window) = self.imp().window.borrow().clone() else {
+            return;
+        };
+        let query = window.history_query();
+        if query.is_empty() {
+            window.clear_history_results();
+            return;
+        }
+        let posts = self
+            .imp()
+            .cache
+            .borrow()
+            .as_ref()
+            .and_then(|cache| PostSearch::new(cache).search(&query).ok())
+            .unwrap_or_default();
+        window.set_history_results(posts);
     }
 
     /// Fetch search results
-    fn fetch_search(&self, query: &str) {
+    fn fetch_search(&self, query: &SearchQuery) {
+        // A search run before shows its last results while this one is out.
+        let cache_key = search_key(&query.to_input());
+        let (cached, _) = self.cached_view(&cache_key);
+        if !cached.is_empty()
+            && let Some(window) = self.imp().window.borrow().as_ref()
+        {
+            window.set_search_results(cached);
+        }
+
         let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
         let client = self.client();
-        let query = query.to_string();
+        let query = query.clone();
         let generation = self.imp().search_generation.token();
+        let account_token = self.imp().account_generation.token();
 
         thread::spawn(move || {
             let result = runtime::block_on(async { client.search_posts(&query, None).await });
@@ -4784,12 +8512,16 @@ impl HangarApplication {
 
         let app = self.clone();
         glib::timeout_add_local(std::time::Duration::from_millis(50), move || {
-            if !app.imp().search_generation.is_current(generation) {
-                // A newer search owns the list now; drop these results.
+            if !app.imp().search_generation.is_current(generation)
+                || !app.imp().account_generation.is_current(account_token)
+            {
+                // A newer search, or another account, owns the list now;
+                // drop these results.
                 return glib::ControlFlow::Break;
             }
             match rx.try_recv() {
                 Ok(Ok((posts, next_cursor))) => {
+                    app.cache_view(&cache_key, &posts, next_cursor.clone());
                     app.imp().search_cursor.replace(next_cursor);
                     if let Some(window) = app.imp().window.borrow().as_ref() {
                         window.set_search_loading(false);
@@ -4836,10 +8568,12 @@ impl HangarApplication {
             window.set_search_loading(true);
         }
 
+        let account_token = self.imp().account_generation.token();
         let (tx, rx) = std::sync::mpsc::channel::<Result<(Vec<Post>, Option<String>), String>>();
         let client = self.client();
         let semaphore = API_SEMAPHORE.clone();
         let generation = self.imp().search_generation.token();
+        let cache_key = search_key(&query.to_input());
 
         thread::spawn(move || {
             let result = runtime::block_on(async {
@@ -4856,8 +8590,14 @@ impl HangarApplication {
                 return glib::ControlFlow::Break;
             }
             match rx.try_recv() {
+                // The account was switched away from while this was out.
+                Ok(_) if !app.imp().account_generation.is_current(account_token) => {
+                    app.imp().search_loading_more.replace(false);
+                    glib::ControlFlow::Break
+                }
                 Ok(Ok((posts, next_cursor))) => {
                     app.imp().search_loading_more.replace(false);
+                    app.extend_cached_view(&cache_key, &posts, next_cursor.clone());
                     app.imp().search_cursor.replace(next_cursor);
                     if let Some(window) = app.imp().window.borrow().as_ref() {
                         window.set_search_loading(false);
@@ -4983,7 +8723,7 @@ impl HangarApplication {
             None => return,
         };
         let query = match self.imp().search_query.borrow().as_ref() {
-            Some(q) => q.clone(),
+            Some(q) => q.text.clone(),
             None => return,
         };
         self.imp().search_people_loading_more.replace(true);
@@ -5050,6 +8790,16 @@ impl Default for HangarApplication {
     }
 }
 
+/// The searches kept as feeds on this machine, in the order they were saved
+fn saved_search_feeds() -> Vec<SavedFeed> {
+    crate::state::AppSettings::load()
+        .saved_searches
+        .iter()
+        .map(String::as_str)
+        .map(SavedFeed::saved_search)
+        .collect()
+}
+
 /// Whether an unread poll may start: someone is signed in, the last one
 /// came back, and the session has not expired. The timer outlives sign-out
 /// and expiry alike, so this is what quiets it.
@@ -5092,6 +8842,9 @@ mod tests {
             viewer_bookmarked: None,
             repost_reason: None,
             reply_context: None,
+            labels: Vec::new(),
+            feed_context: None,
+            facets: Vec::new(),
         }
     }
 
@@ -5200,6 +8953,9 @@ mod tests {
                     parent_author: author.clone(),
                     root_author: author,
                 }),
+                labels: Vec::new(),
+                feed_context: None,
+                facets: Vec::new(),
             }
         };
 
@@ -2,12 +2,22 @@
 #![allow(clippy::collapsible_if)]
 
 use crate::atproto::facets;
+use crate::atproto::interactions::FeedInteraction;
+use crate::atproto::muted_words;
+use crate::atproto::notifications::{
+    CategoryPref, ChatNotify, IncludeFrom, MENTION_REASONS, NotificationCategory,
+    NotificationPrefEdit, NotificationPrefs,
+};
+use crate::atproto::search_query::{SearchQuery, SearchSort};
 use crate::atproto::types::{
-    AuthMethod, ChatMessage, ChatReaction, ComposeData, Conversation, Embed, ExternalEmbed,
-    ImageEmbed, LinkCardData, Notification, Post, PostgateConfig, Profile, QuoteEmbed,
-    ReplyContext, RepostReason, SavedFeed, Session, ThreadgateConfig, ThreadgateRule, VideoEmbed,
+    AuthMethod, ChatMessage, ChatReaction, ComposeData, Conversation, Embed, ExternalEmbed, Facet,
+    FeedGenerator, ImageEmbed, Label, LabelDefinition, LabelStrings, LabelVisibility,
+    LabelerService, LinkCardData, ListMember, ListPurpose, ListRef, ModerationPrefs, MutedWord,
+    MutedWordTarget, NewAccount, Notification, Post, PostgateConfig, Profile, QuoteEmbed,
+    ReplyContext, RepostReason, SavedFeed, ServerDescription, Session, StarterPack,
+    ThreadgateConfig, ThreadgateRule, UserList, VideoEmbed,
 };
-use crate::config::DEFAULT_PDS;
+use crate::config::{DEFAULT_PDS, PLC_DIRECTORY, PUBLIC_APPVIEW};
 use std::time::Duration;
 
 /// How long an XRPC call may take before it is a failure rather than a
@@ -25,6 +35,14 @@ const XRPC_TIMEOUT: Duration = Duration::from_secs(30);
 /// Bluesky's video processing service; uploads land here, not on the PDS.
 const VIDEO_SERVICE: &str = "https://video.bsky.app";
 const VIDEO_SERVICE_DID: &str = "did:web:video.bsky.app";
+/// Most pages one membership lookup reads, of lists and of list items
+/// alike. A hundred lists or ten thousand list entries is past anyone
+/// picking from a dialog.
+const MEMBERSHIP_PAGE_CAP: usize = 100;
+/// The most writes a PDS takes in one `applyWrites` call.
+const WRITE_BATCH: usize = 200;
+/// Feeds a starter pack may recommend, per the lexicon.
+pub const STARTER_PACK_FEED_CAP: usize = 3;
 use atrium_api::agent::atp_agent::AtpAgent;
 use atrium_api::agent::atp_agent::store::MemorySessionStore;
 use atrium_api::com::atproto::repo::{create_record, delete_record};
@@ -44,6 +62,10 @@ pub enum ClientError {
     Network(String),
     #[error("invalid response: {0}")]
     InvalidResponse(String),
+    /// Something this client was asked to write is not valid as written,
+    /// caught before it goes to the server.
+    #[error("invalid value: {0}")]
+    InvalidInput(String),
     #[error("not authenticated")]
     NotAuthenticated,
     /// The stored session cannot be revived and only a fresh sign-in recovers.
@@ -51,6 +73,13 @@ pub enum ClientError {
     /// login dialog with no explanation.
     #[error("session expired, sign in again")]
     ReauthRequired,
+    /// The account has email two-factor sign-in on, and the server has just
+    /// mailed it a code. Sign in again with the code as `authFactorToken`.
+    #[error("a sign-in code was sent to your email")]
+    AuthFactorTokenRequired,
+    /// The emailed code was too old. Signing in without it sends a new one.
+    #[error("that sign-in code has expired")]
+    AuthFactorTokenExpired,
 }
 
 use crate::state::oauth::HangarOAuthSession;
@@ -98,7 +127,7 @@ impl UnreadCounts {
     /// Tally one page of notifications and one of conversations.
     ///
     /// Notifications come as `(reason, is_read)`; the mentions/activity split
-    /// mirrors the filter in `get_notifications`. Conversations come as
+    /// is the Mentions tab's [`MENTION_REASONS`]. Conversations come as
     /// `(unread_count, muted)`, and muted ones stay off the badge.
     pub(crate) fn tally<'a>(
         notifications: impl IntoIterator<Item = (&'a str, bool)>,
@@ -109,7 +138,7 @@ impl UnreadCounts {
             if is_read {
                 continue;
             }
-            if matches!(reason, "mention" | "reply" | "quote") {
+            if MENTION_REASONS.contains(&reason) {
                 counts.mentions += 1;
             } else {
                 counts.activity += 1;
@@ -124,6 +153,17 @@ impl UnreadCounts {
     }
 }
 
+/// What the unread poll found: the badge tallies, plus the unread items
+/// themselves for the desktop notifications.
+#[derive(Debug, Clone, Default)]
+pub struct UnreadActivity {
+    pub counts: UnreadCounts,
+    /// Unread notifications from the first page, newest first.
+    pub notifications: Vec<Notification>,
+    /// Conversations with unread messages, muted ones left out.
+    pub conversations: Vec<Conversation>,
+}
+
 /// Wraps atrium so the rest of the app only sees our own types.
 /// Supports both credential-based (app password) and OAuth authentication.
 /// Only one of `credential_agent` or `oauth_agent` is set at a time.
@@ -136,11 +176,17 @@ pub struct HangarClient {
     // main thread for the length of a network round trip.
     credential_agent: RwLock<Option<Arc<CredentialAgent>>>,
     oauth_agent: RwLock<Option<Arc<OAuthAgentType>>>,
-    service_url: String,
+    /// The PDS app-password sessions talk to. Set by each sign-in, since a
+    /// client outlives the account it first signed in.
+    service_url: RwLock<String>,
     /// Set once the server rejects our credentials after atrium has already
     /// tried to refresh them. Latched, because a dead session fails every
     /// in-flight request at once.
     session_expired: AtomicBool,
+    /// Labelers the account subscribes to beyond Bluesky's own. Every
+    /// request names them in `atproto-accept-labelers`, or the AppView
+    /// leaves their labels off.
+    accepted_labelers: RwLock<Vec<String>>,
 }
 
 /// Dispatch an expression through whichever agent is active.
@@ -210,8 +256,9 @@ impl HangarClient {
         Self {
             credential_agent: RwLock::new(None),
             oauth_agent: RwLock::new(None),
-            service_url: DEFAULT_PDS.to_string(),
+            service_url: RwLock::new(DEFAULT_PDS.to_string()),
             session_expired: AtomicBool::new(false),
+            accepted_labelers: RwLock::new(Vec::new()),
         }
     }
 
@@ -220,8 +267,9 @@ impl HangarClient {
         Self {
             credential_agent: RwLock::new(None),
             oauth_agent: RwLock::new(None),
-            service_url: service_url.to_string(),
+            service_url: RwLock::new(service_url.to_string()),
             session_expired: AtomicBool::new(false),
+            accepted_labelers: RwLock::new(Vec::new()),
         }
     }
 
@@ -260,17 +308,278 @@ impl HangarClient {
         ClientError::Network(e.to_string())
     }
 
+    /// Send the labeler header on every request from the active agent:
+    /// Bluesky's moderation service, allowed to redact, then the account's
+    /// own subscriptions.
+    fn apply_labelers_header(&self) {
+        use atrium_api::types::string::Did;
+
+        let mut labelers: Vec<(Did, bool)> = Vec::new();
+        if let Ok(did) = crate::atproto::moderation::BLUESKY_MODERATION_DID.parse() {
+            labelers.push((did, true));
+        }
+        labelers.extend(
+            self.accepted_labelers
+                .read()
+                .unwrap()
+                .iter()
+                .filter_map(|did| did.parse().ok())
+                .map(|did| (did, false)),
+        );
+        if let Some(agent) = self.credential_agent.read().unwrap().as_ref() {
+            agent.configure_labelers_header(Some(labelers.clone()));
+        }
+        if let Some(agent) = self.oauth_agent.read().unwrap().as_ref() {
+            agent.configure_labelers_header(Some(labelers));
+        }
+    }
+
     /// Consume the expired-session flag. True at most once per dead session.
     pub fn take_session_expired(&self) -> bool {
         self.session_expired.swap(false, Ordering::Relaxed)
     }
 
-    pub async fn login(&self, handle: &str, password: &str) -> Result<Session, ClientError> {
-        let client = Self::xrpc_client(&self.service_url);
+    /// A server address as typed, made into the origin XRPC calls go to:
+    /// `example.com` reads as `https://example.com`. `None` for anything
+    /// that is not a web address.
+    pub fn normalize_server(input: &str) -> Option<String> {
+        let input = input.trim();
+        let url = if input.contains("://") {
+            url::Url::parse(input).ok()?
+        } else {
+            url::Url::parse(&format!("https://{input}")).ok()?
+        };
+        if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+            return None;
+        }
+        Some(url.origin().ascii_serialization())
+    }
+
+    /// An agent with no session, for what is asked before there is one.
+    fn anonymous_agent(service_url: &str) -> CredentialAgent {
+        AtpAgent::new(
+            Self::xrpc_client(service_url),
+            MemorySessionStore::default(),
+        )
+    }
+
+    /// The error name and message a server refused a call with, whether the
+    /// lexicon declares that error or not.
+    fn xrpc_error_code<E: serde::Serialize>(
+        e: &atrium_api::xrpc::Error<E>,
+    ) -> (Option<String>, Option<String>) {
+        use atrium_api::xrpc::error::{Error, XrpcError, XrpcErrorKind};
+
+        match e {
+            // Declared errors serialize as the `{error, message}` body they
+            // were read from.
+            Error::XrpcResponse(XrpcError {
+                error: Some(XrpcErrorKind::Custom(custom)),
+                ..
+            }) => {
+                let body = serde_json::to_value(custom).unwrap_or_default();
+                let field = |name: &str| body.get(name).and_then(|v| v.as_str()).map(String::from);
+                (field("error"), field("message"))
+            }
+            Error::XrpcResponse(XrpcError {
+                error: Some(XrpcErrorKind::Undefined(body)),
+                ..
+            }) => (body.error.clone(), body.message.clone()),
+            _ => (None, None),
+        }
+    }
+
+    /// Where to fetch a DID's document: the PLC directory for `did:plc`,
+    /// the host's well-known path for `did:web`.
+    ///
+    /// A `did:web` with path segments (`did:web:host:user:x`) is not one
+    /// atproto accepts, and has no well-known document to fetch.
+    fn did_doc_url(did: &str) -> Option<String> {
+        if did.starts_with("did:plc:") {
+            Some(format!("{PLC_DIRECTORY}/{did}"))
+        } else {
+            let host = did.strip_prefix("did:web:")?;
+            if host.is_empty() || host.contains([':', '/']) {
+                return None;
+            }
+            // A port rides a did:web percent-encoded.
+            let host = host.replace("%3A", ":");
+            Some(format!("https://{host}/.well-known/did.json"))
+        }
+    }
+
+    /// The PDS hosting `identifier`'s account, for an app-password sign-in.
+    ///
+    /// A handle resolves to its DID through the public AppView, and the DID
+    /// document comes from wherever its method publishes it. `describeRepo`
+    /// hands back the same document, but only once the PDS being looked for
+    /// is known, so it cannot start the search. `None` when `identifier` is
+    /// neither a handle nor a DID: an email address, which the default
+    /// entryway signs in itself.
+    async fn resolve_pds(identifier: &str) -> Result<Option<String>, ClientError> {
+        let identifier = identifier.trim().trim_start_matches('@');
+        let did = if identifier.starts_with("did:") {
+            identifier.to_string()
+        } else {
+            let Ok(handle) = identifier.parse::<atrium_api::types::string::Handle>() else {
+                return Ok(None);
+            };
+            let agent = Self::anonymous_agent(PUBLIC_APPVIEW);
+            let params =
+                atrium_api::com::atproto::identity::resolve_handle::ParametersData { handle };
+            agent
+                .api
+                .com
+                .atproto
+                .identity
+                .resolve_handle(params.into())
+                .await
+                .map_err(|e| ClientError::Auth(format!("couldn't find {identifier}: {e}")))?
+                .data
+                .did
+                .to_string()
+        };
+
+        let url = Self::did_doc_url(&did)
+            .ok_or_else(|| ClientError::Auth(format!("unsupported DID method: {did}")))?;
+        let net = |e: reqwest::Error| ClientError::Network(e.to_string());
+        let http = reqwest::Client::builder()
+            .connect_timeout(XRPC_CONNECT_TIMEOUT)
+            .timeout(XRPC_TIMEOUT)
+            .build()
+            .map_err(net)?;
+        let body = http
+            .get(url)
+            .send()
+            .await
+            .map_err(net)?
+            .error_for_status()
+            .map_err(net)?
+            .bytes()
+            .await
+            .map_err(net)?;
+        let doc: serde_json::Value = serde_json::from_slice(&body)
+            .map_err(|e| ClientError::InvalidResponse(format!("DID document: {e}")))?;
+        Self::pds_endpoint_from_doc(&doc)
+            .and_then(|endpoint| Self::normalize_server(&endpoint))
+            .map(Some)
+            .ok_or_else(|| ClientError::InvalidResponse(format!("no PDS in {did}'s DID document")))
+    }
+
+    /// What a refused `createSession` means for the person signing in.
+    ///
+    /// Kept apart from [`Self::xrpc_error`], which reads `ExpiredToken` and
+    /// `InvalidToken` as a dead session; here they are about the emailed
+    /// code.
+    fn login_failure(code: Option<&str>, message: Option<&str>) -> ClientError {
+        match code {
+            Some("AuthFactorTokenRequired") => ClientError::AuthFactorTokenRequired,
+            Some("ExpiredToken") => ClientError::AuthFactorTokenExpired,
+            Some("InvalidToken") => ClientError::Auth(
+                "That code isn't right. Check the latest email and try again.".into(),
+            ),
+            Some("AccountTakedown") => ClientError::Auth(
+                "This account has been taken down by its hosting provider.".into(),
+            ),
+            Some("AccountDeactivated") => ClientError::Auth(Self::inactive_message("deactivated")),
+            _ => ClientError::Auth(
+                message
+                    .or(code)
+                    .unwrap_or("the server refused the sign-in")
+                    .to_string(),
+            ),
+        }
+    }
+
+    /// Why an account that signed in cannot be used, from its `status`.
+    fn inactive_message(status: &str) -> String {
+        match status {
+            "takendown" => "This account has been taken down by its hosting provider.".into(),
+            "suspended" => "This account is suspended for now.".into(),
+            "deactivated" => {
+                "This account is deactivated. Reactivate it on bsky.app, then sign in again.".into()
+            }
+            other => format!("This account can't be used right now ({other})."),
+        }
+    }
+
+    /// Sign in with an app password.
+    ///
+    /// `server` is the custom server from the sign-in dialog. Without one,
+    /// the account's own PDS is looked up from its DID document, so an
+    /// account hosted anywhere signs in where it lives. A lookup that fails
+    /// (the directory down, a handle not indexed yet) falls back to the
+    /// default entryway, which signs in every account it hosts.
+    ///
+    /// An account with email two-factor sign-in fails the first try with
+    /// [`ClientError::AuthFactorTokenRequired`]; the second passes the
+    /// emailed code as `auth_factor_token`.
+    pub async fn login(
+        &self,
+        handle: &str,
+        password: &str,
+        server: Option<&str>,
+        auth_factor_token: Option<&str>,
+    ) -> Result<Session, ClientError> {
+        let service_url = match server {
+            Some(server) => Self::normalize_server(server)
+                .ok_or_else(|| ClientError::Auth(format!("{server} is not a server address")))?,
+            None => match Self::resolve_pds(handle).await {
+                Ok(pds) => pds.unwrap_or_else(|| DEFAULT_PDS.to_string()),
+                Err(e) => {
+                    eprintln!("Couldn't find {handle}'s server, trying {DEFAULT_PDS}: {e}");
+                    DEFAULT_PDS.to_string()
+                }
+            },
+        };
+        let client = Self::xrpc_client(&service_url);
         let agent = AtpAgent::new(client, MemorySessionStore::default());
 
+        // `AtpAgent::login` has no way to pass the code, so the session is
+        // created here and handed to the agent the way a stored one is.
+        let input: atrium_api::com::atproto::server::create_session::Input =
+            serde_json::from_value(serde_json::json!({
+                "identifier": handle,
+                "password": password,
+                "authFactorToken": auth_factor_token,
+            }))
+            .map_err(|e| ClientError::InvalidResponse(e.to_string()))?;
         let result = agent
-            .login(handle, password)
+            .api
+            .com
+            .atproto
+            .server
+            .create_session(input)
+            .await
+            .map_err(|e| {
+                use atrium_api::com::atproto::server::create_session::Error as CreateSession;
+                use atrium_api::xrpc::error::{Error, XrpcError, XrpcErrorKind};
+
+                match &e {
+                    Error::XrpcResponse(XrpcError {
+                        error: Some(XrpcErrorKind::Custom(custom)),
+                        ..
+                    }) => match custom {
+                        CreateSession::AuthFactorTokenRequired(message) => {
+                            Self::login_failure(Some("AuthFactorTokenRequired"), message.as_deref())
+                        }
+                        CreateSession::AccountTakedown(message) => {
+                            Self::login_failure(Some("AccountTakedown"), message.as_deref())
+                        }
+                    },
+                    Error::XrpcResponse(XrpcError {
+                        error: Some(XrpcErrorKind::Undefined(body)),
+                        ..
+                    }) => Self::login_failure(body.error.as_deref(), body.message.as_deref()),
+                    _ => ClientError::Auth(e.to_string()),
+                }
+            })?;
+        if result.data.active == Some(false) {
+            let status = result.data.status.as_deref().unwrap_or("deactivated");
+            return Err(ClientError::Auth(Self::inactive_message(status)));
+        }
+        agent
+            .resume_session(result.clone())
             .await
             .map_err(|e| ClientError::Auth(e.to_string()))?;
 
@@ -281,16 +590,199 @@ impl HangarClient {
                 access_jwt: result.data.access_jwt.clone(),
                 refresh_jwt: result.data.refresh_jwt.clone(),
             },
+            service_url: Some(service_url.clone()),
         };
 
         // Clear any existing OAuth agent
         *self.oauth_agent.write().unwrap() = None;
+        *self.service_url.write().unwrap() = service_url;
+        *self.credential_agent.write().unwrap() = Some(Arc::new(agent));
+        self.session_expired.store(false, Ordering::Relaxed);
+        self.apply_labelers_header();
+
+        Ok(session)
+    }
+
+    /// What `service_url` asks of a new account: the handle domains it
+    /// hands out, and whether it wants an invite code or a phone number.
+    pub async fn describe_server(service_url: &str) -> Result<ServerDescription, ClientError> {
+        let agent = Self::anonymous_agent(service_url);
+        let output = agent
+            .api
+            .com
+            .atproto
+            .server
+            .describe_server()
+            .await
+            .map_err(|e| ClientError::Network(e.to_string()))?;
+        let links = output.data.links.as_ref();
+        Ok(ServerDescription {
+            user_domains: output.data.available_user_domains.clone(),
+            invite_code_required: output.data.invite_code_required.unwrap_or(false),
+            phone_verification_required: output.data.phone_verification_required.unwrap_or(false),
+            terms_of_service: links.and_then(|l| l.terms_of_service.clone()),
+            privacy_policy: links.and_then(|l| l.privacy_policy.clone()),
+        })
+    }
+
+    /// Have `service_url` text a sign-up code to `phone`, for a server
+    /// that asks for one.
+    pub async fn request_phone_verification(
+        service_url: &str,
+        phone: &str,
+    ) -> Result<(), ClientError> {
+        let agent = Self::anonymous_agent(service_url);
+        let input: atrium_api::com::atproto::temp::request_phone_verification::Input =
+            serde_json::from_value(serde_json::json!({ "phoneNumber": phone.trim() }))
+                .map_err(|e| ClientError::InvalidResponse(e.to_string()))?;
+        agent
+            .api
+            .com
+            .atproto
+            .temp
+            .request_phone_verification(input)
+            .await
+            .map_err(|e| {
+                let (code, message) = Self::xrpc_error_code(&e);
+                ClientError::Auth(message.or(code).unwrap_or_else(|| e.to_string()))
+            })?;
+        Ok(())
+    }
+
+    /// What a refused `createAccount` means for the person signing up.
+    fn signup_failure(code: Option<&str>, message: Option<&str>) -> ClientError {
+        let text = match code {
+            Some("HandleNotAvailable") => "That handle is taken. Try another.",
+            Some("InvalidHandle" | "UnsupportedDomain") => {
+                "That handle can't be used on this server."
+            }
+            Some("InvalidInviteCode") => "That invite code isn't valid, or has been used.",
+            Some("InvalidPassword") => "That password isn't allowed. Try a longer one.",
+            _ => message.or(code).unwrap_or("the server refused the sign-up"),
+        };
+        ClientError::Auth(text.to_string())
+    }
+
+    /// Make an account on `service_url` and sign in to it, as
+    /// [`Self::login`] would have.
+    pub async fn create_account(
+        &self,
+        service_url: &str,
+        account: &NewAccount,
+    ) -> Result<Session, ClientError> {
+        let service_url = Self::normalize_server(service_url)
+            .ok_or_else(|| ClientError::Auth(format!("{service_url} is not a server address")))?;
+        let agent = Self::anonymous_agent(&service_url);
+
+        let input: atrium_api::com::atproto::server::create_account::Input =
+            serde_json::from_value(serde_json::json!({
+                "handle": account.handle,
+                "email": account.email,
+                "password": account.password,
+                "inviteCode": account.invite_code,
+                "verificationPhone": account.verification_phone,
+                "verificationCode": account.verification_code,
+            }))
+            .map_err(|_| Self::signup_failure(Some("InvalidHandle"), None))?;
+        let created = agent
+            .api
+            .com
+            .atproto
+            .server
+            .create_account(input)
+            .await
+            .map_err(|e| match Self::xrpc_error_code(&e) {
+                (None, None) => ClientError::Network(e.to_string()),
+                (code, message) => Self::signup_failure(code.as_deref(), message.as_deref()),
+            })?;
+
+        // Handed to the agent the way a stored session is.
+        let atrium_session = atrium_api::com::atproto::server::create_session::Output::from(
+            atrium_api::com::atproto::server::create_session::OutputData {
+                access_jwt: created.data.access_jwt.clone(),
+                active: None,
+                did: created.data.did.clone(),
+                did_doc: None,
+                email: Some(account.email.clone()),
+                email_auth_factor: None,
+                email_confirmed: Some(false),
+                handle: created.data.handle.clone(),
+                refresh_jwt: created.data.refresh_jwt.clone(),
+                status: None,
+            },
+        );
+        agent
+            .resume_session(atrium_session)
+            .await
+            .map_err(|e| ClientError::Auth(e.to_string()))?;
+
+        let session = Session {
+            did: created.data.did.to_string(),
+            handle: created.data.handle.to_string(),
+            auth: AuthMethod::AppPassword {
+                access_jwt: created.data.access_jwt.clone(),
+                refresh_jwt: created.data.refresh_jwt.clone(),
+            },
+            service_url: Some(service_url.clone()),
+        };
+
+        *self.oauth_agent.write().unwrap() = None;
+        *self.service_url.write().unwrap() = service_url;
         *self.credential_agent.write().unwrap() = Some(Arc::new(agent));
         self.session_expired.store(false, Ordering::Relaxed);
+        self.apply_labelers_header();
 
         Ok(session)
     }
 
+    /// Have the server email the signed-in account a confirmation code.
+    pub async fn request_email_confirmation(&self) -> Result<(), ClientError> {
+        with_agent!(self, agent => {
+        agent
+            .api
+            .com
+            .atproto
+            .server
+            .request_email_confirmation()
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+        Ok(())
+        })
+    }
+
+    /// Confirm the signed-in account's email with the code sent to it.
+    ///
+    /// A bad code comes back as `InvalidToken` or `ExpiredToken`, which
+    /// [`Self::xrpc_error`] would take for a dead session.
+    pub async fn confirm_email(&self, email: &str, token: &str) -> Result<(), ClientError> {
+        with_agent!(self, agent => {
+        let input = atrium_api::com::atproto::server::confirm_email::InputData {
+            email: email.to_string(),
+            token: token.trim().to_string(),
+        };
+        agent
+            .api
+            .com
+            .atproto
+            .server
+            .confirm_email(input.into())
+            .await
+            .map_err(|e| match Self::xrpc_error_code(&e).0.as_deref() {
+                Some("ExpiredToken") => {
+                    ClientError::Auth("That code has expired. Send a new one.".into())
+                }
+                Some("InvalidToken") => ClientError::Auth(
+                    "That code isn't right. Check the latest email and try again.".into(),
+                ),
+                Some("InvalidEmail") => {
+                    ClientError::Auth("That isn't the email this account signed up with.".into())
+                }
+                _ => self.xrpc_error(e),
+            })?;
+        Ok(())
+        })
+    }
+
     /// Forget whichever session is active.
     ///
     /// The client outlives the window, and so does the 30-second poll, so
@@ -302,6 +794,8 @@ impl HangarClient {
         *self.oauth_agent.write().unwrap() = None;
         // Nothing is signed in any more, so there is no expiry left to report.
         self.session_expired.store(false, Ordering::Relaxed);
+        // Subscriptions belong to the account, not the client.
+        self.accepted_labelers.write().unwrap().clear();
     }
 
     /// Set an OAuth session as the active agent.
@@ -318,11 +812,13 @@ impl HangarClient {
         // Clear any existing credential agent
         *self.credential_agent.write().unwrap() = None;
         *self.oauth_agent.write().unwrap() = Some(Arc::new(agent));
+        self.apply_labelers_header();
 
         Session {
             did: did_str,
             handle: String::new(), // Will be populated by profile fetch
             auth: AuthMethod::OAuth,
+            service_url: None,
         }
     }
 
@@ -342,7 +838,11 @@ impl HangarClient {
             AuthMethod::OAuth => unreachable!("handled above"),
         };
 
-        let client = Self::xrpc_client(&self.service_url);
+        let service_url = session
+            .service_url
+            .clone()
+            .unwrap_or_else(|| DEFAULT_PDS.to_string());
+        let client = Self::xrpc_client(&service_url);
         let agent = AtpAgent::new(client, MemorySessionStore::default());
 
         let atrium_session = atrium_api::com::atproto::server::create_session::Output::from(
@@ -373,7 +873,9 @@ impl HangarClient {
 
         // Clear any existing OAuth agent
         *self.oauth_agent.write().unwrap() = None;
+        *self.service_url.write().unwrap() = service_url;
         *self.credential_agent.write().unwrap() = Some(Arc::new(agent));
+        self.apply_labelers_header();
 
         Ok(())
     }
@@ -442,7 +944,7 @@ impl HangarClient {
         self.oauth_agent.read().unwrap().is_some()
     }
 
-    #[allow(dead_code, clippy::await_holding_lock)]
+    #[allow(clippy::await_holding_lock)]
     pub async fn session(&self) -> Option<Session> {
         {
             let cred = self.credential_agent.read().unwrap();
@@ -455,6 +957,7 @@ impl HangarClient {
                         access_jwt: atrium_session.data.access_jwt.clone(),
                         refresh_jwt: atrium_session.data.refresh_jwt.clone(),
                     },
+                    service_url: Some(self.service_url.read().unwrap().clone()),
                 });
             }
         }
@@ -466,6 +969,7 @@ impl HangarClient {
                 did: did.to_string(),
                 handle: String::new(),
                 auth: AuthMethod::OAuth,
+                service_url: None,
             })
         }
     }
@@ -516,6 +1020,7 @@ impl HangarClient {
         let author = post_view.data.author;
 
         let (text, created_at) = self.extract_post_record(&post_view.data.record);
+        let facets = Self::extract_facets(&post_view.data.record);
 
         // Extract rich embed (images, external links, videos, quotes)
         let embed = self.extract_embed(&post_view.data.embed);
@@ -525,6 +1030,7 @@ impl HangarClient {
 
         // Extract reply context (who this is replying to)
         let reply_context = self.extract_reply_context(&feed_view.data.reply);
+        let feed_context = feed_view.data.feed_context;
 
         // Extract viewer state (like/repost URIs, bookmark flag)
         let (viewer_like, viewer_repost, viewer_bookmarked) = post_view
@@ -543,12 +1049,7 @@ impl HangarClient {
         Post {
             uri: post_view.data.uri,
             cid: post_view.data.cid.as_ref().to_string(),
-            author: Profile::minimal(
-                author.data.did.to_string(),
-                author.data.handle.to_string(),
-                author.data.display_name.clone(),
-                author.data.avatar.clone(),
-            ),
+            author: Self::author_from_view(&author),
             text,
             created_at,
             reply_count: post_view.data.reply_count.map(|c| c as u32),
@@ -561,6 +1062,9 @@ impl HangarClient {
             viewer_bookmarked,
             repost_reason,
             reply_context,
+            labels: Self::labels_from_view(&post_view.data.labels),
+            feed_context,
+            facets,
         }
     }
 
@@ -739,6 +1243,7 @@ impl HangarClient {
             Union::Refs(ViewRecordRefs::ViewRecord(view_record)) => {
                 let data = &view_record.data;
                 let (text, _) = self.extract_post_record(&data.value);
+                let facets = Self::extract_facets(&data.value);
 
                 // Extract nested embeds if present
                 let nested_embed = data
@@ -750,15 +1255,12 @@ impl HangarClient {
                 Some(QuoteEmbed {
                     uri: data.uri.clone(),
                     cid: data.cid.as_ref().to_string(),
-                    author: Profile::minimal(
-                        data.author.data.did.to_string(),
-                        data.author.data.handle.to_string(),
-                        data.author.data.display_name.clone(),
-                        data.author.data.avatar.clone(),
-                    ),
+                    author: Self::author_from_view(&data.author),
                     text,
                     indexed_at: data.indexed_at.as_str().to_string(),
                     embed: nested_embed.map(Box::new),
+                    labels: Self::labels_from_view(&data.labels),
+                    facets,
                 })
             }
             // ViewNotFound, ViewBlocked, ViewDetached all map to None
@@ -999,6 +1501,8 @@ impl HangarClient {
             .as_ref()
             .and_then(|v| v.data.blocked_by)
             .unwrap_or(false);
+        let (muted_by_list, blocking_by_list) =
+            Self::lists_behind(output.data.viewer.as_ref());
 
         Ok(Profile {
             did: output.data.did.to_string(),
@@ -1015,6 +1519,9 @@ impl HangarClient {
             viewer_muted,
             viewer_blocking,
             viewer_blocked_by,
+            labels: Self::labels_from_view(&output.data.labels),
+            muted_by_list,
+            blocking_by_list,
         })
         })
     }
@@ -1055,6 +1562,7 @@ impl HangarClient {
             .map(|p| {
                 let viewer_following = p.viewer.as_ref().and_then(|v| v.data.following.clone());
                 let viewer_followed_by = p.viewer.as_ref().and_then(|v| v.data.followed_by.clone());
+                let (muted_by_list, blocking_by_list) = Self::lists_behind(p.viewer.as_ref());
 
                 Profile {
                     did: p.did.to_string(),
@@ -1079,6 +1587,9 @@ impl HangarClient {
                         .unwrap_or(false),
                     viewer_following,
                     viewer_followed_by,
+                    labels: Self::labels_from_view(&p.labels),
+                    muted_by_list,
+                    blocking_by_list,
                 }
             })
             .collect();
@@ -1181,6 +1692,42 @@ impl HangarClient {
         self.delete_record(post_uri, "app.bsky.feed.post").await
     }
 
+    /// Write a new record to the signed-in user's repo. Returns its AT-URI.
+    async fn create_record(
+        &self,
+        collection: &str,
+        record_json: serde_json::Value,
+    ) -> Result<String, ClientError> {
+        with_agent_and_did!(self, agent, did => {
+
+        let record: Unknown = serde_json::from_value(record_json)
+            .map_err(|e| ClientError::InvalidResponse(e.to_string()))?;
+
+        let collection = atrium_api::types::string::Nsid::new(collection.to_string())
+            .map_err(|_| ClientError::InvalidResponse("invalid collection".into()))?;
+
+        let input = create_record::InputData {
+            collection,
+            record,
+            repo: did.clone().into(),
+            rkey: None,
+            swap_commit: None,
+            validate: None,
+        };
+
+        let output = agent
+            .api
+            .com
+            .atproto
+            .repo
+            .create_record(input.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        Ok(output.data.uri.to_string())
+        })
+    }
+
     /// Generic delete record helper
     async fn delete_record(&self, record_uri: &str, collection: &str) -> Result<(), ClientError> {
         with_agent!(self, agent => {
@@ -1377,15 +1924,20 @@ impl HangarClient {
         })
     }
 
-    /// The PDS hosting this DID document, named as a `did:web`. That is the
-    /// audience the video service needs its upload token bound to.
-    fn pds_did_from_doc(doc: &serde_json::Value) -> Option<String> {
+    /// The `#atproto_pds` endpoint in a DID document, as written there.
+    fn pds_endpoint_from_doc(doc: &serde_json::Value) -> Option<String> {
         let services = doc.get("service")?.as_array()?;
         let pds = services.iter().find(|s| {
             s.get("id").and_then(|i| i.as_str()) == Some("#atproto_pds")
                 || s.get("type").and_then(|t| t.as_str()) == Some("AtprotoPersonalDataServer")
         })?;
-        let endpoint = pds.get("serviceEndpoint")?.as_str()?;
+        Some(pds.get("serviceEndpoint")?.as_str()?.to_string())
+    }
+
+    /// The PDS hosting this DID document, named as a `did:web`. That is the
+    /// audience the video service needs its upload token bound to.
+    fn pds_did_from_doc(doc: &serde_json::Value) -> Option<String> {
+        let endpoint = Self::pds_endpoint_from_doc(doc)?;
         let host = endpoint
             .strip_prefix("https://")
             .or_else(|| endpoint.strip_prefix("http://"))?
@@ -1986,60 +2538,74 @@ impl HangarClient {
         }
     }
 
-    /// Get the user's saved/pinned feeds from preferences
-    pub async fn get_saved_feeds(&self) -> Result<Vec<SavedFeed>, ClientError> {
-        with_agent!(self, agent => {
-
-        let output = agent
-            .api
-            .app
-            .bsky
-            .actor
-            .get_preferences(
-                atrium_api::app::bsky::actor::get_preferences::ParametersData {}.into(),
-            )
-            .await
-            .map_err(|e| self.xrpc_error(e))?;
-
-        let mut feeds = vec![SavedFeed::home()];
+    /// The record's rich text facets, empty when it has none.
+    fn extract_facets(record: &atrium_api::types::Unknown) -> Vec<Facet> {
+        use atrium_api::types::Unknown;
 
-        // Parse preferences to find saved feeds
-        for pref in output.data.preferences.iter() {
-            use atrium_api::app::bsky::actor::defs::PreferencesItem;
-            use atrium_api::types::Union;
+        match record {
+            Unknown::Object(map) => map
+                .get("facets")
+                .and_then(|dm| serde_json::to_value(dm).ok())
+                .map(|v| facets::from_record(&v))
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
 
-            if let Union::Refs(PreferencesItem::SavedFeedsPrefV2(saved_feeds_pref)) = pref {
-                for item in &saved_feeds_pref.data.items {
-                    // Only include pinned feeds (shown in feed selector)
-                    if item.data.pinned {
-                        let feed_type = item.data.r#type.clone();
-                        let uri = item.data.value.clone();
+    /// Every feed in `savedFeedsPrefV2`, pinned or not, in the account's
+    /// order. The timeline item comes back as [`SavedFeed::home`];
+    /// [`SavedFeed::selector_feeds`] picks out what the feed selector shows.
+    pub async fn get_saved_feed_list(&self) -> Result<Vec<SavedFeed>, ClientError> {
+        let preferences = self.get_preferences_raw().await?;
+        let mut feeds = Self::saved_feeds_from_prefs(&preferences);
+        self.name_saved_feeds(&mut feeds).await;
+        Ok(feeds)
+    }
 
-                        // Skip timeline type as we already have "Following"
-                        if feed_type == "timeline" {
-                            continue;
-                        }
+    /// The `savedFeedsPrefV2` items, named by their rkey until
+    /// [`Self::name_saved_feeds`] looks them up.
+    fn saved_feeds_from_prefs(
+        preferences: &atrium_api::app::bsky::actor::defs::Preferences,
+    ) -> Vec<SavedFeed> {
+        use atrium_api::app::bsky::actor::defs::PreferencesItem;
+        use atrium_api::types::Union;
 
-                        // We'll need to fetch the display name separately
-                        // For now, use the rkey from URI as a fallback name
-                        let display_name = uri.split('/').next_back().unwrap_or("Feed").to_string();
-
-                        feeds.push(SavedFeed {
-                            feed_type,
-                            uri,
-                            display_name,
-                            description: None,
-                            pinned: true,
-                        });
-                    }
+        let Some(saved) = preferences.iter().find_map(|pref| match pref {
+            Union::Refs(PreferencesItem::SavedFeedsPrefV2(saved)) => Some(saved),
+            _ => None,
+        }) else {
+            return Vec::new();
+        };
+        saved
+            .data
+            .items
+            .iter()
+            .map(|item| {
+                if item.data.r#type == "timeline" {
+                    return SavedFeed {
+                        pinned: item.data.pinned,
+                        ..SavedFeed::home()
+                    };
                 }
-            }
-        }
+                let uri = item.data.value.clone();
+                SavedFeed {
+                    feed_type: item.data.r#type.clone(),
+                    display_name: uri.split('/').next_back().unwrap_or("Feed").to_string(),
+                    uri,
+                    description: None,
+                    pinned: item.data.pinned,
+                }
+            })
+            .collect()
+    }
 
-        // Now fetch display names for the feed generators
+    /// Give saved feeds their real names. A list URI in the generator
+    /// batch fails the whole call, so lists are named one by one; a feed
+    /// that cannot be looked up keeps its rkey.
+    async fn name_saved_feeds(&self, feeds: &mut [SavedFeed]) {
         let feed_uris: Vec<String> = feeds
             .iter()
-            .filter(|f| !f.is_home())
+            .filter(|f| !f.is_home() && !f.is_list())
             .map(|f| f.uri.clone())
             .collect();
 
@@ -2054,19 +2620,99 @@ impl HangarClient {
             }
         }
 
-        Ok(feeds)
-        })
+        for feed in feeds.iter_mut().filter(|f| f.is_list()) {
+            if let Ok(list) = self.get_list_info(&feed.uri).await {
+                feed.display_name = list.name;
+                feed.description = list.description;
+            }
+        }
     }
 
-    /// Internal helper to get feed generator metadata (uri, display_name, description)
-    #[allow(clippy::await_holding_lock, dead_code)]
-    async fn get_feed_generators_internal(
-        &self,
-        uris: &[String],
-    ) -> Result<Vec<(String, String, Option<String>)>, ClientError> {
-        with_agent!(self, agent => {
-        let params = atrium_api::app::bsky::feed::get_feed_generators::ParametersData {
-            feeds: uris.iter().map(|s| s.parse().unwrap()).collect(),
+    /// Replace the account's saved feeds with `feeds`, in that order.
+    /// Only the `savedFeedsPrefV2` entry changes; see
+    /// [`Self::write_saved_feeds`].
+    pub async fn set_saved_feed_list(&self, feeds: &[SavedFeed]) -> Result<(), ClientError> {
+        let mut preferences = self.get_preferences_raw().await?;
+        Self::write_saved_feeds(&mut preferences, feeds);
+        self.put_preferences_raw(preferences).await
+    }
+
+    /// Rewrite the `savedFeedsPrefV2` items from `feeds`. An item already
+    /// saved keeps its id, so other clients see it moved rather than
+    /// replaced. Saved searches live in this app's settings, not here, and
+    /// are left out.
+    fn write_saved_feeds(
+        preferences: &mut atrium_api::app::bsky::actor::defs::Preferences,
+        feeds: &[SavedFeed],
+    ) {
+        use atrium_api::app::bsky::actor::defs::{
+            PreferencesItem, SavedFeedData, SavedFeedsPrefV2Data,
+        };
+        use atrium_api::types::Union;
+
+        let existing: Vec<SavedFeedData> = preferences
+            .iter()
+            .find_map(|pref| match pref {
+                Union::Refs(PreferencesItem::SavedFeedsPrefV2(saved)) => Some(
+                    saved
+                        .data
+                        .items
+                        .iter()
+                        .map(|item| item.data.clone())
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        // As in `write_list_pin`, the clock and a counter make new ids.
+        let stamp = chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default();
+        let items = feeds
+            .iter()
+            .filter(|feed| feed.search_query().is_none())
+            .enumerate()
+            .map(|(n, feed)| {
+                let (kind, value) = if feed.is_home() {
+                    ("timeline", "following")
+                } else {
+                    (feed.feed_type.as_str(), feed.uri.as_str())
+                };
+                let saved = existing.iter().find(|item| {
+                    item.r#type == kind && (kind == "timeline" || item.value == value)
+                });
+                atrium_api::app::bsky::actor::defs::SavedFeed::from(SavedFeedData {
+                    id: saved.map_or_else(|| format!("{stamp:x}{n}"), |item| item.id.clone()),
+                    pinned: feed.pinned,
+                    r#type: kind.to_string(),
+                    value: saved
+                        .filter(|_| kind == "timeline")
+                        .map_or_else(|| value.to_string(), |item| item.value.clone()),
+                })
+            })
+            .collect();
+
+        let pref = Union::Refs(PreferencesItem::SavedFeedsPrefV2(Box::new(
+            SavedFeedsPrefV2Data { items }.into(),
+        )));
+        match preferences
+            .iter_mut()
+            .find(|pref| matches!(pref, Union::Refs(PreferencesItem::SavedFeedsPrefV2(_))))
+        {
+            Some(slot) => *slot = pref,
+            None => preferences.push(pref),
+        }
+    }
+
+    /// One page of feeds the server suggests
+    pub async fn get_suggested_feeds(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<FeedGenerator>, Option<String>), ClientError> {
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::feed::get_suggested_feeds::ParametersData {
+            cursor: cursor.map(String::from),
+            limit: None,
         };
 
         let output = agent
@@ -2074,65 +2720,53 @@ impl HangarClient {
             .app
             .bsky
             .feed
-            .get_feed_generators(params.into())
+            .get_suggested_feeds(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        Ok(output
-            .data
-            .feeds
-            .into_iter()
-            .map(|f| (f.data.uri, f.data.display_name, f.data.description))
-            .collect())
+        let feeds = output.data.feeds.iter().map(Self::feed_generator_from_view).collect();
+        Ok((feeds, output.data.cursor))
         })
     }
 
-    /// Fetch a custom feed by its AT-URI
-    pub async fn get_feed(
+    /// One page of popular feeds, narrowed to those matching `query` when
+    /// there is one. The lexicon is unspecced but it is what the official
+    /// app searches feeds with.
+    pub async fn search_feed_generators(
         &self,
-        feed_uri: &str,
+        query: Option<&str>,
         cursor: Option<&str>,
-    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
+    ) -> Result<(Vec<FeedGenerator>, Option<String>), ClientError> {
         with_agent!(self, agent => {
 
-        let params = atrium_api::app::bsky::feed::get_feed::ParametersData {
-            feed: feed_uri
-                .parse()
-                .map_err(|e| ClientError::InvalidResponse(format!("invalid feed URI: {e}")))?,
+        let params = atrium_api::app::bsky::unspecced::get_popular_feed_generators::ParametersData {
             cursor: cursor.map(String::from),
             limit: None,
+            query: query.map(String::from),
         };
 
         let output = agent
             .api
             .app
             .bsky
-            .feed
-            .get_feed(params.into())
+            .unspecced
+            .get_popular_feed_generators(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        let posts: Vec<Post> = output
-            .data
-            .feed
-            .into_iter()
-            .map(|feed_view| self.convert_feed_view_post(feed_view))
-            .collect();
-
-        Ok((posts, output.data.cursor))
+        let feeds = output.data.feeds.iter().map(Self::feed_generator_from_view).collect();
+        Ok((feeds, output.data.cursor))
         })
     }
 
-    /// Get a post thread (the main post and its replies)
-    pub async fn get_thread(&self, post_uri: &str) -> Result<Vec<Post>, ClientError> {
+    /// One feed generator, for its detail page
+    pub async fn get_feed_generator(&self, uri: &str) -> Result<FeedGenerator, ClientError> {
         with_agent!(self, agent => {
 
-        let params = atrium_api::app::bsky::feed::get_post_thread::ParametersData {
-            uri: post_uri
+        let params = atrium_api::app::bsky::feed::get_feed_generator::ParametersData {
+            feed: uri
                 .parse()
-                .map_err(|e| ClientError::InvalidResponse(format!("invalid URI: {e}")))?,
-            depth: Some(atrium_api::types::LimitedU16::try_from(6_u16).unwrap()),
-            parent_height: Some(atrium_api::types::LimitedU16::try_from(80_u16).unwrap()),
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid feed URI: {e}")))?,
         };
 
         let output = agent
@@ -2140,171 +2774,104 @@ impl HangarClient {
             .app
             .bsky
             .feed
-            .get_post_thread(params.into())
+            .get_feed_generator(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        // Extract posts from thread view
-        let mut posts = Vec::new();
-        self.extract_thread_posts(&output.data.thread, &mut posts);
-        Ok(posts)
+        Ok(Self::feed_generator_from_view(&output.data.view))
         })
     }
 
-    /// Recursively extract posts from a thread view
-    fn extract_thread_posts(
-        &self,
-        thread: &atrium_api::types::Union<
-            atrium_api::app::bsky::feed::get_post_thread::OutputThreadRefs,
-        >,
-        posts: &mut Vec<Post>,
-    ) {
-        use atrium_api::app::bsky::feed::get_post_thread::OutputThreadRefs;
-        use atrium_api::types::Union;
-
-        match thread {
-            Union::Refs(OutputThreadRefs::AppBskyFeedDefsThreadViewPost(thread_view)) => {
-                // Add parent posts first (recursively)
-                if let Some(parent) = &thread_view.data.parent {
-                    self.extract_parent_posts(parent, posts);
-                }
-
-                // Add the main post
-                let post = self.convert_post_view(&thread_view.data.post);
-                posts.push(post);
-
-                // Add replies
-                if let Some(replies) = &thread_view.data.replies {
-                    for reply in replies {
-                        self.extract_reply_posts(reply, posts);
-                    }
-                }
-            }
-            Union::Refs(OutputThreadRefs::AppBskyFeedDefsNotFoundPost(_)) => {}
-            Union::Refs(OutputThreadRefs::AppBskyFeedDefsBlockedPost(_)) => {}
-            _ => {}
+    fn feed_generator_from_view(
+        view: &atrium_api::app::bsky::feed::defs::GeneratorView,
+    ) -> FeedGenerator {
+        FeedGenerator {
+            uri: view.data.uri.to_string(),
+            cid: view.data.cid.as_ref().to_string(),
+            did: view.data.did.to_string(),
+            display_name: view.data.display_name.clone(),
+            description: view.data.description.clone(),
+            avatar: view.data.avatar.clone(),
+            creator: Self::profile_from_view(&view.data.creator),
+            like_count: view.data.like_count.and_then(|n| u32::try_from(n).ok()),
+            viewer_like: view.data.viewer.as_ref().and_then(|v| v.data.like.clone()),
+            accepts_interactions: view.data.accepts_interactions.unwrap_or(false),
         }
     }
 
-    /// Extract parent posts from thread (going up the chain)
-    fn extract_parent_posts(
-        &self,
-        parent: &atrium_api::types::Union<
-            atrium_api::app::bsky::feed::defs::ThreadViewPostParentRefs,
-        >,
-        posts: &mut Vec<Post>,
-    ) {
-        use atrium_api::app::bsky::feed::defs::ThreadViewPostParentRefs;
-        use atrium_api::types::Union;
-
-        match parent {
-            Union::Refs(ThreadViewPostParentRefs::ThreadViewPost(thread_view)) => {
-                // Recurse to get older parents first
-                if let Some(grandparent) = &thread_view.data.parent {
-                    self.extract_parent_posts(grandparent, posts);
-                }
-                // Then add this parent
-                let post = self.convert_post_view(&thread_view.data.post);
-                posts.push(post);
-            }
-            Union::Refs(ThreadViewPostParentRefs::NotFoundPost(_)) => {}
-            Union::Refs(ThreadViewPostParentRefs::BlockedPost(_)) => {}
-            _ => {}
+    /// Pin a list's feed to the feed selector, or take it off.
+    ///
+    /// The `savedFeedsPrefV2` entry is edited in place and every other
+    /// entry is written back as it was read. An account that never saved
+    /// a feed gets the Following timeline first, as the official app
+    /// writes it, so pinning a list does not drop the home feed.
+    pub async fn set_list_pinned(&self, list_uri: &str, pinned: bool) -> Result<(), ClientError> {
+        let mut preferences = self.get_preferences_raw().await?;
+        if Self::write_list_pin(&mut preferences, list_uri, pinned) {
+            self.put_preferences_raw(preferences).await?;
         }
+        Ok(())
     }
 
-    /// Extract reply posts from thread
-    fn extract_reply_posts(
-        &self,
-        reply: &atrium_api::types::Union<
-            atrium_api::app::bsky::feed::defs::ThreadViewPostRepliesItem,
-        >,
-        posts: &mut Vec<Post>,
-    ) {
-        use atrium_api::app::bsky::feed::defs::ThreadViewPostRepliesItem;
+    /// Add or drop the list's `savedFeedsPrefV2` item. False when there
+    /// was nothing to change: unpinning with no saved feeds at all.
+    fn write_list_pin(
+        preferences: &mut atrium_api::app::bsky::actor::defs::Preferences,
+        list_uri: &str,
+        pinned: bool,
+    ) -> bool {
+        use atrium_api::app::bsky::actor::defs::{
+            PreferencesItem, SavedFeedData, SavedFeedsPrefV2Data,
+        };
         use atrium_api::types::Union;
 
-        match reply {
-            Union::Refs(ThreadViewPostRepliesItem::ThreadViewPost(thread_view)) => {
-                let post = self.convert_post_view(&thread_view.data.post);
-                posts.push(post);
+        // Entries only need an id unique within the preference; the clock
+        // plus a counter gives one without pulling in a TID generator.
+        let stamp = chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default();
+        let saved_feed = |n: u8, kind: &str, value: &str| {
+            atrium_api::app::bsky::actor::defs::SavedFeed::from(SavedFeedData {
+                id: format!("{stamp:x}{n}"),
+                pinned: true,
+                r#type: kind.to_string(),
+                value: value.to_string(),
+            })
+        };
 
-                // Recursively add nested replies
-                if let Some(replies) = &thread_view.data.replies {
-                    for nested_reply in replies {
-                        self.extract_reply_posts(nested_reply, posts);
-                    }
+        let existing = preferences.iter_mut().find_map(|pref| match pref {
+            Union::Refs(PreferencesItem::SavedFeedsPrefV2(saved)) => Some(saved),
+            _ => None,
+        });
+        match existing {
+            Some(saved) => {
+                let items = &mut saved.data.items;
+                items.retain(|item| item.data.value != list_uri);
+                if pinned {
+                    items.push(saved_feed(0, "list", list_uri));
                 }
             }
-            Union::Refs(ThreadViewPostRepliesItem::NotFoundPost(_)) => {}
-            Union::Refs(ThreadViewPostRepliesItem::BlockedPost(_)) => {}
-            _ => {}
-        }
-    }
-
-    /// Convert a PostView to our Post type (used for thread extraction)
-    fn convert_post_view(&self, post_view: &atrium_api::app::bsky::feed::defs::PostView) -> Post {
-        let author = &post_view.data.author;
-        let (text, created_at) = self.extract_post_record(&post_view.data.record);
-        let embed = self.extract_embed(&post_view.data.embed);
-
-        let (viewer_like, viewer_repost, viewer_bookmarked) = post_view
-            .data
-            .viewer
-            .as_ref()
-            .map(|v| {
-                (
-                    v.data.like.clone(),
-                    v.data.repost.clone(),
-                    v.data.bookmarked,
-                )
-            })
-            .unwrap_or((None, None, None));
-
-        Post {
-            uri: post_view.data.uri.clone(),
-            cid: post_view.data.cid.as_ref().to_string(),
-            author: Profile::minimal(
-                author.data.did.to_string(),
-                author.data.handle.to_string(),
-                author.data.display_name.clone(),
-                author.data.avatar.clone(),
-            ),
-            text,
-            created_at,
-            reply_count: post_view.data.reply_count.map(|c| c as u32),
-            repost_count: post_view.data.repost_count.map(|c| c as u32),
-            like_count: post_view.data.like_count.map(|c| c as u32),
-            indexed_at: post_view.data.indexed_at.as_str().to_string(),
-            embed,
-            viewer_like,
-            viewer_repost,
-            viewer_bookmarked,
-            repost_reason: None,
-            reply_context: None,
+            None if pinned => {
+                let items = vec![
+                    saved_feed(0, "timeline", "following"),
+                    saved_feed(1, "list", list_uri),
+                ];
+                preferences.push(Union::Refs(PreferencesItem::SavedFeedsPrefV2(Box::new(
+                    SavedFeedsPrefV2Data { items }.into(),
+                ))));
+            }
+            None => return false,
         }
+        true
     }
 
-    /// Get an author's feed (posts by a specific user)
-    /// `filter` is a lexicon value: posts_and_author_threads,
-    /// posts_with_replies, posts_with_media, posts_with_video. None leaves
-    /// the server's default, which includes replies.
-    pub async fn get_author_feed(
+    /// Internal helper to get feed generator metadata (uri, display_name, description)
+    #[allow(clippy::await_holding_lock, dead_code)]
+    async fn get_feed_generators_internal(
         &self,
-        actor: &str,
-        cursor: Option<&str>,
-        filter: Option<&str>,
-    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
+        uris: &[String],
+    ) -> Result<Vec<(String, String, Option<String>)>, ClientError> {
         with_agent!(self, agent => {
-
-        let params = atrium_api::app::bsky::feed::get_author_feed::ParametersData {
-            actor: actor
-                .parse()
-                .map_err(|e| ClientError::InvalidResponse(format!("invalid actor: {e}")))?,
-            cursor: cursor.map(String::from),
-            filter: filter.map(String::from),
-            include_pins: None,
-            limit: None,
+        let params = atrium_api::app::bsky::feed::get_feed_generators::ParametersData {
+            feeds: uris.iter().map(|s| s.parse().unwrap()).collect(),
         };
 
         let output = agent
@@ -2312,43 +2879,626 @@ impl HangarClient {
             .app
             .bsky
             .feed
-            .get_author_feed(params.into())
+            .get_feed_generators(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        let posts: Vec<Post> = output
+        Ok(output
             .data
-            .feed
+            .feeds
             .into_iter()
-            .map(|feed_view| self.convert_feed_view_post(feed_view))
-            .collect();
-
-        Ok((posts, output.data.cursor))
+            .map(|f| (f.data.uri, f.data.display_name, f.data.description))
+            .collect())
         })
     }
 
-    /// Get posts liked by a specific user
-    pub async fn get_actor_likes(
+    /// The account's preferences, exactly as the server holds them.
+    async fn get_preferences_raw(
         &self,
-        actor: &str,
-        cursor: Option<&str>,
-    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
+    ) -> Result<atrium_api::app::bsky::actor::defs::Preferences, ClientError> {
         with_agent!(self, agent => {
-
-        let params = atrium_api::app::bsky::feed::get_actor_likes::ParametersData {
-            actor: actor
-                .parse()
-                .map_err(|e| ClientError::InvalidResponse(format!("invalid actor: {e}")))?,
-            cursor: cursor.map(String::from),
-            limit: None,
-        };
-
         let output = agent
             .api
             .app
             .bsky
-            .feed
-            .get_actor_likes(params.into())
+            .actor
+            .get_preferences(
+                atrium_api::app::bsky::actor::get_preferences::ParametersData {}.into(),
+            )
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+        Ok(output.data.preferences)
+        })
+    }
+
+    /// Write the whole preference set back. `putPreferences` replaces every
+    /// entry, so callers start from `get_preferences_raw` and change only
+    /// their own; entries this app does not understand ride along as read.
+    async fn put_preferences_raw(
+        &self,
+        preferences: atrium_api::app::bsky::actor::defs::Preferences,
+    ) -> Result<(), ClientError> {
+        with_agent!(self, agent => {
+        agent
+            .api
+            .app
+            .bsky
+            .actor
+            .put_preferences(
+                atrium_api::app::bsky::actor::put_preferences::InputData { preferences }
+                .into(),
+            )
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+        Ok(())
+        })
+    }
+
+    /// Set the topics the account picked when it signed up, as
+    /// `interestsPref`. The server reads them to shape suggestions.
+    pub async fn set_interests(&self, tags: &[String]) -> Result<(), ClientError> {
+        use atrium_api::app::bsky::actor::defs::{InterestsPrefData, PreferencesItem};
+        use atrium_api::types::Union;
+
+        let mut preferences = self.get_preferences_raw().await?;
+        preferences.retain(|pref| !matches!(pref, Union::Refs(PreferencesItem::InterestsPref(_))));
+        preferences.push(Union::Refs(PreferencesItem::InterestsPref(Box::new(
+            InterestsPrefData {
+                tags: tags.to_vec(),
+            }
+            .into(),
+        ))));
+        self.put_preferences_raw(preferences).await
+    }
+
+    /// Every `mutedWordsPref` item as stored, fields this client does not
+    /// know included.
+    fn muted_word_items(
+        preferences: &atrium_api::app::bsky::actor::defs::Preferences,
+    ) -> Vec<atrium_api::app::bsky::actor::defs::MutedWord> {
+        use atrium_api::app::bsky::actor::defs::PreferencesItem;
+        use atrium_api::types::Union;
+
+        preferences
+            .iter()
+            .filter_map(|pref| match pref {
+                Union::Refs(PreferencesItem::MutedWordsPref(muted)) => Some(muted),
+                _ => None,
+            })
+            .flat_map(|muted| muted.data.items.iter().cloned())
+            .collect()
+    }
+
+    fn muted_word_from_item(item: &atrium_api::app::bsky::actor::defs::MutedWord) -> MutedWord {
+        MutedWord {
+            id: item.data.id.clone(),
+            value: item.data.value.clone(),
+            targets: item
+                .data
+                .targets
+                .iter()
+                .filter_map(|t| MutedWordTarget::from_wire(t))
+                .collect(),
+            exclude_following: item.data.actor_target.as_deref() == Some("exclude-following"),
+            expires_at: item
+                .data
+                .expires_at
+                .as_ref()
+                .map(|at| at.as_str().to_string()),
+        }
+    }
+
+    /// `word` as a `mutedWordsPref` item. Built on `original`, the item it
+    /// edits, so targets and fields this client does not know survive.
+    fn muted_word_item(
+        word: &MutedWord,
+        original: Option<&atrium_api::app::bsky::actor::defs::MutedWord>,
+    ) -> atrium_api::app::bsky::actor::defs::MutedWord {
+        use atrium_api::app::bsky::actor::defs::MutedWordData;
+
+        let mut item = original.cloned().unwrap_or_else(|| {
+            MutedWordData {
+                actor_target: None,
+                expires_at: None,
+                id: None,
+                targets: Vec::new(),
+                value: String::new(),
+            }
+            .into()
+        });
+        let unknown_targets: Vec<String> = item
+            .data
+            .targets
+            .iter()
+            .filter(|t| MutedWordTarget::from_wire(t).is_none())
+            .cloned()
+            .collect();
+        item.data.targets = word
+            .targets
+            .iter()
+            .map(|t| t.as_str().to_string())
+            .chain(unknown_targets)
+            .collect();
+        if word.exclude_following {
+            item.data.actor_target = Some("exclude-following".to_string());
+        } else if original.is_none()
+            || item.data.actor_target.as_deref() == Some("exclude-following")
+        {
+            item.data.actor_target = Some("all".to_string());
+        }
+        item.data.expires_at = word.expires_at.as_deref().and_then(|at| at.parse().ok());
+        item.data.id = word.id.clone();
+        item.data.value = word.value.clone();
+        item
+    }
+
+    fn muted_words_from_prefs(
+        preferences: &atrium_api::app::bsky::actor::defs::Preferences,
+    ) -> Vec<MutedWord> {
+        Self::muted_word_items(preferences)
+            .iter()
+            .map(Self::muted_word_from_item)
+            .collect()
+    }
+
+    /// The account's muted words and tags, expired ones included so the
+    /// editor can show and renew them.
+    pub async fn get_muted_words(&self) -> Result<Vec<MutedWord>, ClientError> {
+        let preferences = self.get_preferences_raw().await?;
+        Ok(Self::muted_words_from_prefs(&preferences))
+    }
+
+    fn moderation_prefs_from_prefs(
+        preferences: &atrium_api::app::bsky::actor::defs::Preferences,
+    ) -> ModerationPrefs {
+        use atrium_api::app::bsky::actor::defs::PreferencesItem;
+        use atrium_api::types::Union;
+
+        let mut prefs = ModerationPrefs::default();
+        for pref in preferences {
+            match pref {
+                Union::Refs(PreferencesItem::AdultContentPref(adult)) => {
+                    prefs.adult_content_enabled = adult.data.enabled;
+                }
+                // Entries naming a labeler are for the labels it defines.
+                Union::Refs(PreferencesItem::ContentLabelPref(label)) => {
+                    let Some(visibility) = LabelVisibility::from_wire(&label.data.visibility)
+                    else {
+                        continue;
+                    };
+                    match &label.data.labeler_did {
+                        Some(labeler) => {
+                            prefs.labeler_label_visibility.insert(
+                                (labeler.to_string(), label.data.label.clone()),
+                                visibility,
+                            );
+                        }
+                        None => {
+                            prefs
+                                .label_visibility
+                                .insert(label.data.label.clone(), visibility);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        prefs
+    }
+
+    /// The subscribed labelers, in the order the account added them.
+    /// Bluesky's own is implied and left out, as bsky.app writes it.
+    fn labelers_from_prefs(
+        preferences: &atrium_api::app::bsky::actor::defs::Preferences,
+    ) -> Vec<String> {
+        use atrium_api::app::bsky::actor::defs::PreferencesItem;
+        use atrium_api::types::Union;
+
+        let mut dids: Vec<String> = Vec::new();
+        for pref in preferences {
+            if let Union::Refs(PreferencesItem::LabelersPref(labelers)) = pref {
+                for item in &labelers.data.labelers {
+                    let did = item.data.did.to_string();
+                    if did != crate::atproto::moderation::BLUESKY_MODERATION_DID
+                        && !dids.contains(&did)
+                    {
+                        dids.push(did);
+                    }
+                }
+            }
+        }
+        dids
+    }
+
+    /// How the account wants labeled content shown. Also points the labeler
+    /// header at the account's subscriptions, so labels from them come back
+    /// from here on.
+    pub async fn get_moderation_prefs(&self) -> Result<ModerationPrefs, ClientError> {
+        let preferences = self.get_preferences_raw().await?;
+        self.moderation_prefs_with_labelers(&preferences).await
+    }
+
+    async fn moderation_prefs_with_labelers(
+        &self,
+        preferences: &atrium_api::app::bsky::actor::defs::Preferences,
+    ) -> Result<ModerationPrefs, ClientError> {
+        let mut prefs = Self::moderation_prefs_from_prefs(preferences);
+        let dids = Self::labelers_from_prefs(preferences);
+        *self.accepted_labelers.write().unwrap() = dids.clone();
+        self.apply_labelers_header();
+        if !dids.is_empty() {
+            // One labeler the AppView chokes on must not cost the account
+            // its label preferences: keep the subscriptions, minus what
+            // their labels are called.
+            let mut labelers = match self.get_labeler_services(&dids).await {
+                Ok(labelers) => labelers,
+                Err(e) => {
+                    eprintln!("Failed to fetch labeler definitions: {}", e);
+                    dids.iter()
+                        .map(|did| LabelerService {
+                            did: did.clone(),
+                            creator: Profile::minimal(did.clone(), did.clone(), None, None),
+                            definitions: Vec::new(),
+                        })
+                        .collect()
+                }
+            };
+            labelers.sort_by_key(|l| dids.iter().position(|d| *d == l.did));
+            prefs.labelers = labelers;
+        }
+        Ok(prefs)
+    }
+
+    /// Labelers and the labels each defines. DIDs that are not labelers
+    /// are left out of the answer.
+    pub async fn get_labeler_services(
+        &self,
+        dids: &[String],
+    ) -> Result<Vec<LabelerService>, ClientError> {
+        use atrium_api::app::bsky::labeler::get_services::OutputViewsItem;
+        use atrium_api::types::Union;
+
+        with_agent!(self, agent => {
+        let params = atrium_api::app::bsky::labeler::get_services::ParametersData {
+            detailed: Some(true),
+            dids: dids.iter().filter_map(|did| did.parse().ok()).collect(),
+        };
+
+        let output = agent
+            .api
+            .app
+            .bsky
+            .labeler
+            .get_services(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        Ok(output
+            .data
+            .views
+            .iter()
+            .filter_map(|view| match view {
+                Union::Refs(OutputViewsItem::AppBskyLabelerDefsLabelerViewDetailed(view)) => {
+                    Some(Self::labeler_from_view(view))
+                }
+                _ => None,
+            })
+            .collect())
+        })
+    }
+
+    fn labeler_from_view(
+        view: &atrium_api::app::bsky::labeler::defs::LabelerViewDetailed,
+    ) -> LabelerService {
+        let definitions = view
+            .data
+            .policies
+            .data
+            .label_value_definitions
+            .iter()
+            .flatten()
+            .map(|def| LabelDefinition {
+                identifier: def.data.identifier.clone(),
+                severity: def.data.severity.clone(),
+                blurs: def.data.blurs.clone(),
+                // The lexicon's own default when a labeler names none.
+                default_setting: def
+                    .data
+                    .default_setting
+                    .as_deref()
+                    .and_then(LabelVisibility::from_wire)
+                    .unwrap_or(LabelVisibility::Warn),
+                adult_only: def.data.adult_only.unwrap_or(false),
+                locales: def
+                    .data
+                    .locales
+                    .iter()
+                    .map(|strings| LabelStrings {
+                        lang: AsRef::<str>::as_ref(&strings.data.lang).to_string(),
+                        name: strings.data.name.clone(),
+                        description: strings.data.description.clone(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        LabelerService {
+            did: view.data.creator.data.did.to_string(),
+            creator: Self::profile_from_view(&view.data.creator),
+            definitions,
+        }
+    }
+
+    /// Apply `edit` to the server's current preferences, write them back,
+    /// and read the moderation settings out of what was written.
+    async fn edit_moderation_prefs(
+        &self,
+        edit: impl FnOnce(&mut atrium_api::app::bsky::actor::defs::Preferences),
+    ) -> Result<ModerationPrefs, ClientError> {
+        let mut preferences = self.get_preferences_raw().await?;
+        edit(&mut preferences);
+        self.put_preferences_raw(preferences.clone()).await?;
+        self.moderation_prefs_with_labelers(&preferences).await
+    }
+
+    /// Replace the `labelersPref` entry with `dids`.
+    fn write_labelers(
+        preferences: &mut atrium_api::app::bsky::actor::defs::Preferences,
+        dids: &[String],
+    ) {
+        use atrium_api::app::bsky::actor::defs::{
+            LabelerPrefItemData, LabelersPrefData, PreferencesItem,
+        };
+        use atrium_api::types::Union;
+
+        let labelers = dids
+            .iter()
+            .filter_map(|did| did.parse().ok())
+            .map(|did| LabelerPrefItemData { did }.into())
+            .collect();
+        preferences.retain(|pref| !matches!(pref, Union::Refs(PreferencesItem::LabelersPref(_))));
+        preferences.push(Union::Refs(PreferencesItem::LabelersPref(Box::new(
+            LabelersPrefData { labelers }.into(),
+        ))));
+    }
+
+    /// Subscribe to a labeler. Refused when the account is not one, since
+    /// subscribing to it would do nothing.
+    pub async fn subscribe_labeler(&self, did: &str) -> Result<ModerationPrefs, ClientError> {
+        if self
+            .get_labeler_services(&[did.to_string()])
+            .await?
+            .is_empty()
+        {
+            return Err(ClientError::InvalidResponse(format!(
+                "{did} is not a labeler"
+            )));
+        }
+        let did = did.to_string();
+        self.edit_moderation_prefs(|preferences| {
+            let mut dids = Self::labelers_from_prefs(preferences);
+            if !dids.contains(&did) {
+                dids.push(did);
+            }
+            Self::write_labelers(preferences, &dids);
+        })
+        .await
+    }
+
+    /// Unsubscribe from a labeler, dropping the choices made for its
+    /// labels along with it.
+    pub async fn unsubscribe_labeler(&self, did: &str) -> Result<ModerationPrefs, ClientError> {
+        use atrium_api::app::bsky::actor::defs::PreferencesItem;
+        use atrium_api::types::Union;
+
+        self.edit_moderation_prefs(|preferences| {
+            let mut dids = Self::labelers_from_prefs(preferences);
+            dids.retain(|d| d != did);
+            Self::write_labelers(preferences, &dids);
+            preferences.retain(|pref| {
+                !matches!(
+                    pref,
+                    Union::Refs(PreferencesItem::ContentLabelPref(label))
+                        if label.data.labeler_did.as_ref().is_some_and(|l| l.as_str() == did)
+                )
+            });
+        })
+        .await
+    }
+
+    /// Choose how one label shows: a global one with `labeler` unset, or
+    /// one that labeler defines.
+    pub async fn set_label_visibility(
+        &self,
+        labeler: Option<&str>,
+        label: &str,
+        visibility: LabelVisibility,
+    ) -> Result<ModerationPrefs, ClientError> {
+        use atrium_api::app::bsky::actor::defs::{ContentLabelPrefData, PreferencesItem};
+        use atrium_api::types::Union;
+
+        let labeler_did: Option<atrium_api::types::string::Did> = match labeler {
+            Some(did) => Some(
+                did.parse()
+                    .map_err(|_| ClientError::InvalidResponse("invalid labeler DID".into()))?,
+            ),
+            None => None,
+        };
+        self.edit_moderation_prefs(|preferences| {
+            preferences.retain(|pref| {
+                !matches!(
+                    pref,
+                    Union::Refs(PreferencesItem::ContentLabelPref(existing))
+                        if existing.data.label == label
+                            && existing.data.labeler_did.as_ref().map(|d| d.as_str())
+                                == labeler
+                )
+            });
+            preferences.push(Union::Refs(PreferencesItem::ContentLabelPref(Box::new(
+                ContentLabelPrefData {
+                    label: label.to_string(),
+                    labeler_did,
+                    visibility: visibility.as_wire().to_string(),
+                }
+                .into(),
+            ))));
+        })
+        .await
+    }
+
+    /// Make one change from the moderation settings and return the
+    /// settings as written.
+    pub async fn edit_moderation(
+        &self,
+        edit: crate::atproto::moderation::ModerationEdit,
+    ) -> Result<ModerationPrefs, ClientError> {
+        use crate::atproto::moderation::ModerationEdit;
+
+        match edit {
+            ModerationEdit::AdultContent(enabled) => self.set_adult_content_enabled(enabled).await,
+            ModerationEdit::LabelVisibility {
+                labeler,
+                label,
+                visibility,
+            } => {
+                self.set_label_visibility(labeler.as_deref(), &label, visibility)
+                    .await
+            }
+            ModerationEdit::Subscribe(actor) => {
+                let actor = actor.trim().trim_start_matches('@');
+                let did = if actor.starts_with("did:") {
+                    actor.to_string()
+                } else {
+                    self.resolve_handle(actor).await?
+                };
+                self.subscribe_labeler(&did).await
+            }
+            ModerationEdit::Unsubscribe(did) => self.unsubscribe_labeler(&did).await,
+        }
+    }
+
+    /// Turn adult content on or off for the account.
+    pub async fn set_adult_content_enabled(
+        &self,
+        enabled: bool,
+    ) -> Result<ModerationPrefs, ClientError> {
+        use atrium_api::app::bsky::actor::defs::{AdultContentPrefData, PreferencesItem};
+        use atrium_api::types::Union;
+
+        self.edit_moderation_prefs(|preferences| {
+            preferences
+                .retain(|pref| !matches!(pref, Union::Refs(PreferencesItem::AdultContentPref(_))));
+            preferences.push(Union::Refs(PreferencesItem::AdultContentPref(Box::new(
+                AdultContentPrefData { enabled }.into(),
+            ))));
+        })
+        .await
+    }
+
+    /// Apply `edit` to the server's current list and write it back, leaving
+    /// every other preference alone. Starting from a fresh read keeps an
+    /// edit made on the web since the last fetch, and an entry the edit
+    /// leaves alone is written back exactly as it was read.
+    ///
+    /// An expiry that is not a valid datetime is never written as no expiry
+    /// at all: an entry already on the server keeps the one it had, and a
+    /// new entry is refused.
+    async fn edit_muted_words(
+        &self,
+        edit: impl FnOnce(&mut Vec<MutedWord>),
+    ) -> Result<Vec<MutedWord>, ClientError> {
+        use atrium_api::app::bsky::actor::defs::{MutedWordsPrefData, PreferencesItem};
+        use atrium_api::types::Union;
+
+        let mut preferences = self.get_preferences_raw().await?;
+        let items = Self::muted_word_items(&preferences);
+        let original: Vec<MutedWord> = items.iter().map(Self::muted_word_from_item).collect();
+        let mut words = original.clone();
+        edit(&mut words);
+        for word in &mut words {
+            let Some(at) = word.expires_at.as_deref() else {
+                continue;
+            };
+            if at.parse::<atrium_api::types::string::Datetime>().is_ok() {
+                continue;
+            }
+            let kept = original
+                .iter()
+                .find(|w| w.same_entry(word))
+                .ok_or_else(|| ClientError::InvalidInput(format!("muted word expiry {at}")))?;
+            eprintln!(
+                "Keeping the previous expiry of muted word {:?}: {at} does not parse",
+                word.value
+            );
+            word.expires_at = kept.expires_at.clone();
+        }
+
+        let new_items = words
+            .iter()
+            .map(|word| {
+                match items
+                    .iter()
+                    .zip(&original)
+                    .find(|(_, was)| was.same_entry(word))
+                {
+                    Some((item, was)) if was == word => item.clone(),
+                    Some((item, _)) => Self::muted_word_item(word, Some(item)),
+                    None => Self::muted_word_item(word, None),
+                }
+            })
+            .collect();
+
+        // The pref object itself may carry fields of its own; keep the
+        // first one and only swap its items.
+        let mut pref = preferences
+            .iter()
+            .find_map(|pref| match pref {
+                Union::Refs(PreferencesItem::MutedWordsPref(muted)) => Some(muted.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| Box::new(MutedWordsPrefData { items: Vec::new() }.into()));
+        pref.data.items = new_items;
+        preferences.retain(|pref| !matches!(pref, Union::Refs(PreferencesItem::MutedWordsPref(_))));
+        preferences.push(Union::Refs(PreferencesItem::MutedWordsPref(pref)));
+        self.put_preferences_raw(preferences).await?;
+        Ok(words)
+    }
+
+    /// Add a muted word, or replace the entry it edits. Returns the list
+    /// as written.
+    pub async fn save_muted_word(&self, word: MutedWord) -> Result<Vec<MutedWord>, ClientError> {
+        self.edit_muted_words(|words| muted_words::upsert(words, word))
+            .await
+    }
+
+    /// Delete a muted word. Returns the list as written.
+    pub async fn remove_muted_word(&self, word: &MutedWord) -> Result<Vec<MutedWord>, ClientError> {
+        self.edit_muted_words(|words| muted_words::remove(words, word))
+            .await
+    }
+
+    /// Fetch a custom feed by its AT-URI
+    pub async fn get_feed(
+        &self,
+        feed_uri: &str,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::feed::get_feed::ParametersData {
+            feed: feed_uri
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid feed URI: {e}")))?,
+            cursor: cursor.map(String::from),
+            limit: None,
+        };
+
+        let output = agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_feed(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
@@ -2363,259 +3513,1124 @@ impl HangarClient {
         })
     }
 
-    /// Get notifications (mentions, replies, quotes, likes, reposts, follows)
-    /// If `mentions_only` is true, filters to just mentions, replies, and quotes
-    pub async fn get_notifications(
+    /// Report what the reader did with a custom feed's posts.
+    ///
+    /// The call is proxied to the generator's own service, which is how
+    /// the AppView knows where to send it. `service_did` is the
+    /// generator's `did`, not its creator's.
+    pub async fn send_interactions(
+        &self,
+        service_did: &str,
+        interactions: &[FeedInteraction],
+    ) -> Result<(), ClientError> {
+        with_agent!(self, agent => {
+
+        let did = service_did
+            .parse()
+            .map_err(|e| ClientError::InvalidResponse(format!("invalid feed service DID: {e}")))?;
+        let feed_api = agent.api_with_proxy(did, "bsky_fg");
+
+        // Built from JSON: the interaction view grew fields over lexicon
+        // revisions and only these three are ours to fill.
+        let items: Vec<serde_json::Value> = interactions
+            .iter()
+            .map(|i| {
+                serde_json::json!({
+                    "item": i.item,
+                    "event": i.event.as_str(),
+                    "feedContext": i.feed_context,
+                })
+            })
+            .collect();
+        let input: atrium_api::app::bsky::feed::send_interactions::Input =
+            serde_json::from_value(serde_json::json!({ "interactions": items }))
+                .map_err(|e| ClientError::InvalidResponse(format!("interactions: {e}")))?;
+
+        feed_api
+            .app
+            .bsky
+            .feed
+            .send_interactions(input)
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        Ok(())
+        })
+    }
+
+    /// One page of a list's feed: posts by its members, newest first.
+    pub async fn get_list_feed(
         &self,
+        list_uri: &str,
         cursor: Option<&str>,
-        mentions_only: bool,
-    ) -> Result<(Vec<Notification>, Option<String>), ClientError> {
+    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
         with_agent!(self, agent => {
 
-        let params = atrium_api::app::bsky::notification::list_notifications::ParametersData {
+        let params = atrium_api::app::bsky::graph::get_list_feed::ParametersData {
+            list: list_uri
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid list URI: {e}")))?,
+            cursor: cursor.map(String::from),
+            limit: None,
+        };
+
+        let output = agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_list_feed(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let posts: Vec<Post> = output
+            .data
+            .feed
+            .into_iter()
+            .map(|feed_view| self.convert_feed_view_post(feed_view))
+            .collect();
+
+        Ok((posts, output.data.cursor))
+        })
+    }
+
+    /// Get a post thread (the main post and its replies)
+    pub async fn get_thread(&self, post_uri: &str) -> Result<Vec<Post>, ClientError> {
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::feed::get_post_thread::ParametersData {
+            uri: post_uri
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid URI: {e}")))?,
+            depth: Some(atrium_api::types::LimitedU16::try_from(6_u16).unwrap()),
+            parent_height: Some(atrium_api::types::LimitedU16::try_from(80_u16).unwrap()),
+        };
+
+        let output = agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_post_thread(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        // Extract posts from thread view
+        let mut posts = Vec::new();
+        self.extract_thread_posts(&output.data.thread, &mut posts);
+        Ok(posts)
+        })
+    }
+
+    /// Recursively extract posts from a thread view
+    fn extract_thread_posts(
+        &self,
+        thread: &atrium_api::types::Union<
+            atrium_api::app::bsky::feed::get_post_thread::OutputThreadRefs,
+        >,
+        posts: &mut Vec<Post>,
+    ) {
+        use atrium_api::app::bsky::feed::get_post_thread::OutputThreadRefs;
+        use atrium_api::types::Union;
+
+        match thread {
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsThreadViewPost(thread_view)) => {
+                // Add parent posts first (recursively)
+                if let Some(parent) = &thread_view.data.parent {
+                    self.extract_parent_posts(parent, posts);
+                }
+
+                // Add the main post
+                let post = self.convert_post_view(&thread_view.data.post);
+                posts.push(post);
+
+                // Add replies
+                if let Some(replies) = &thread_view.data.replies {
+                    for reply in replies {
+                        self.extract_reply_posts(reply, posts);
+                    }
+                }
+            }
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsNotFoundPost(_)) => {}
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsBlockedPost(_)) => {}
+            _ => {}
+        }
+    }
+
+    /// Extract parent posts from thread (going up the chain)
+    fn extract_parent_posts(
+        &self,
+        parent: &atrium_api::types::Union<
+            atrium_api::app::bsky::feed::defs::ThreadViewPostParentRefs,
+        >,
+        posts: &mut Vec<Post>,
+    ) {
+        use atrium_api::app::bsky::feed::defs::ThreadViewPostParentRefs;
+        use atrium_api::types::Union;
+
+        match parent {
+            Union::Refs(ThreadViewPostParentRefs::ThreadViewPost(thread_view)) => {
+                // Recurse to get older parents first
+                if let Some(grandparent) = &thread_view.data.parent {
+                    self.extract_parent_posts(grandparent, posts);
+                }
+                // Then add this parent
+                let post = self.convert_post_view(&thread_view.data.post);
+                posts.push(post);
+            }
+            Union::Refs(ThreadViewPostParentRefs::NotFoundPost(_)) => {}
+            Union::Refs(ThreadViewPostParentRefs::BlockedPost(_)) => {}
+            _ => {}
+        }
+    }
+
+    /// Extract reply posts from thread
+    fn extract_reply_posts(
+        &self,
+        reply: &atrium_api::types::Union<
+            atrium_api::app::bsky::feed::defs::ThreadViewPostRepliesItem,
+        >,
+        posts: &mut Vec<Post>,
+    ) {
+        use atrium_api::app::bsky::feed::defs::ThreadViewPostRepliesItem;
+        use atrium_api::types::Union;
+
+        match reply {
+            Union::Refs(ThreadViewPostRepliesItem::ThreadViewPost(thread_view)) => {
+                let post = self.convert_post_view(&thread_view.data.post);
+                posts.push(post);
+
+                // Recursively add nested replies
+                if let Some(replies) = &thread_view.data.replies {
+                    for nested_reply in replies {
+                        self.extract_reply_posts(nested_reply, posts);
+                    }
+                }
+            }
+            Union::Refs(ThreadViewPostRepliesItem::NotFoundPost(_)) => {}
+            Union::Refs(ThreadViewPostRepliesItem::BlockedPost(_)) => {}
+            _ => {}
+        }
+    }
+
+    /// Convert a PostView to our Post type (used for thread extraction)
+    fn convert_post_view(&self, post_view: &atrium_api::app::bsky::feed::defs::PostView) -> Post {
+        let author = &post_view.data.author;
+        let (text, created_at) = self.extract_post_record(&post_view.data.record);
+        let embed = self.extract_embed(&post_view.data.embed);
+
+        let (viewer_like, viewer_repost, viewer_bookmarked) = post_view
+            .data
+            .viewer
+            .as_ref()
+            .map(|v| {
+                (
+                    v.data.like.clone(),
+                    v.data.repost.clone(),
+                    v.data.bookmarked,
+                )
+            })
+            .unwrap_or((None, None, None));
+
+        Post {
+            uri: post_view.data.uri.clone(),
+            cid: post_view.data.cid.as_ref().to_string(),
+            author: Self::author_from_view(author),
+            text,
+            created_at,
+            reply_count: post_view.data.reply_count.map(|c| c as u32),
+            repost_count: post_view.data.repost_count.map(|c| c as u32),
+            like_count: post_view.data.like_count.map(|c| c as u32),
+            indexed_at: post_view.data.indexed_at.as_str().to_string(),
+            embed,
+            viewer_like,
+            viewer_repost,
+            viewer_bookmarked,
+            repost_reason: None,
+            reply_context: None,
+            labels: Self::labels_from_view(&post_view.data.labels),
+            feed_context: None,
+            facets: Self::extract_facets(&post_view.data.record),
+        }
+    }
+
+    /// Get an author's feed (posts by a specific user)
+    /// `filter` is a lexicon value: posts_and_author_threads,
+    /// posts_with_replies, posts_with_media, posts_with_video. None leaves
+    /// the server's default, which includes replies.
+    pub async fn get_author_feed(
+        &self,
+        actor: &str,
+        cursor: Option<&str>,
+        filter: Option<&str>,
+    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::feed::get_author_feed::ParametersData {
+            actor: actor
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid actor: {e}")))?,
+            cursor: cursor.map(String::from),
+            filter: filter.map(String::from),
+            include_pins: None,
+            limit: None,
+        };
+
+        let output = agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_author_feed(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let posts: Vec<Post> = output
+            .data
+            .feed
+            .into_iter()
+            .map(|feed_view| self.convert_feed_view_post(feed_view))
+            .collect();
+
+        Ok((posts, output.data.cursor))
+        })
+    }
+
+    /// Get posts liked by a specific user
+    pub async fn get_actor_likes(
+        &self,
+        actor: &str,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::feed::get_actor_likes::ParametersData {
+            actor: actor
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid actor: {e}")))?,
+            cursor: cursor.map(String::from),
+            limit: None,
+        };
+
+        let output = agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_actor_likes(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let posts: Vec<Post> = output
+            .data
+            .feed
+            .into_iter()
+            .map(|feed_view| self.convert_feed_view_post(feed_view))
+            .collect();
+
+        Ok((posts, output.data.cursor))
+        })
+    }
+
+    /// One page of notifications for `reasons`, or for every reason when
+    /// it is empty. `priority` keeps to accounts the user follows.
+    pub async fn get_notifications(
+        &self,
+        cursor: Option<&str>,
+        reasons: &[&str],
+        priority: bool,
+    ) -> Result<(Vec<Notification>, Option<String>), ClientError> {
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::notification::list_notifications::ParametersData {
+            cursor: cursor.map(String::from),
+            limit: None,
+            priority: priority.then_some(true),
+            reasons: (!reasons.is_empty())
+                .then(|| reasons.iter().map(|r| r.to_string()).collect()),
+            seen_at: None,
+        };
+
+        let output = agent
+            .api
+            .app
+            .bsky
+            .notification
+            .list_notifications(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let notifications: Vec<Notification> = output
+            .data
+            .notifications
+            .into_iter()
+            .filter_map(|notif| {
+                // The server filters; this keeps one that ignores the
+                // parameter from mixing reasons into a filtered list.
+                if !reasons.is_empty() && !reasons.contains(&notif.data.reason.as_str()) {
+                    return None;
+                }
+
+                Some(self.convert_notification(&notif))
+            })
+            .collect();
+
+        Ok((notifications, output.data.cursor))
+        })
+    }
+
+    /// Convert one `listNotifications` entry to our type.
+    fn convert_notification(
+        &self,
+        notif: &atrium_api::app::bsky::notification::list_notifications::Notification,
+    ) -> Notification {
+        let author = Profile::minimal(
+            notif.data.author.data.did.to_string(),
+            notif.data.author.data.handle.to_string(),
+            notif.data.author.data.display_name.clone(),
+            notif.data.author.data.avatar.clone(),
+        );
+
+        // Extract post data if this is a post-based notification
+        let post = self.extract_notification_post(notif);
+
+        Notification {
+            uri: notif.data.uri.clone(),
+            cid: notif.data.cid.as_ref().to_string(),
+            author,
+            reason: notif.data.reason.clone(),
+            reason_subject: notif.data.reason_subject.clone(),
+            indexed_at: notif.data.indexed_at.as_str().to_string(),
+            is_read: notif.data.is_read,
+            post,
+        }
+    }
+
+    /// Extract post data from a notification record
+    fn extract_notification_post(
+        &self,
+        notif: &atrium_api::app::bsky::notification::list_notifications::Notification,
+    ) -> Option<Post> {
+        use atrium_api::types::Unknown;
+
+        // The record contains the post data for mentions/replies/quotes
+        let reason = &notif.data.reason;
+        if reason != "mention" && reason != "reply" && reason != "quote" {
+            return None;
+        }
+
+        // Extract text and created_at from record
+        let (text, created_at) = match &notif.data.record {
+            Unknown::Object(map) => {
+                let text = map
+                    .get("text")
+                    .and_then(|dm| serde_json::to_value(dm).ok())
+                    .and_then(|v| v.as_str().map(String::from))
+                    .unwrap_or_default();
+
+                let created_at = map
+                    .get("createdAt")
+                    .and_then(|dm| serde_json::to_value(dm).ok())
+                    .and_then(|v| v.as_str().map(String::from))
+                    .unwrap_or_default();
+
+                (text, created_at)
+            }
+            _ => (String::new(), String::new()),
+        };
+
+        let author = Profile::minimal(
+            notif.data.author.data.did.to_string(),
+            notif.data.author.data.handle.to_string(),
+            notif.data.author.data.display_name.clone(),
+            notif.data.author.data.avatar.clone(),
+        );
+
+        Some(Post {
+            uri: notif.data.uri.clone(),
+            cid: notif.data.cid.as_ref().to_string(),
+            author,
+            text,
+            created_at,
+            indexed_at: notif.data.indexed_at.as_str().to_string(),
+            like_count: None,
+            repost_count: None,
+            reply_count: None,
+            embed: None,
+            viewer_like: None,
+            viewer_repost: None,
+            viewer_bookmarked: None,
+            repost_reason: None,
+            reply_context: None,
+            labels: Self::labels_from_view(&notif.data.labels),
+            feed_context: None,
+            facets: Self::extract_facets(&notif.data.record),
+        })
+    }
+
+    /// The account's notification preferences: which categories the
+    /// server lists and pushes, and for whom.
+    pub async fn get_notification_prefs(&self) -> Result<NotificationPrefs, ClientError> {
+        with_agent!(self, agent => {
+        let output = agent
+            .api
+            .app
+            .bsky
+            .notification
+            .get_preferences(
+                atrium_api::app::bsky::notification::get_preferences::ParametersData {}.into(),
+            )
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+        Ok(Self::notification_prefs_from(&output.data.preferences))
+        })
+    }
+
+    /// Write one notification preference and return them all as the
+    /// server kept them. `putPreferencesV2` leaves every field not sent
+    /// alone, so there is no read-modify-write to race.
+    pub async fn put_notification_pref(
+        &self,
+        edit: NotificationPrefEdit,
+    ) -> Result<NotificationPrefs, ClientError> {
+        use atrium_api::app::bsky::notification::defs::{
+            ChatPreferenceData, FilterablePreferenceData, PreferenceData,
+        };
+
+        let mut input = atrium_api::app::bsky::notification::put_preferences_v2::InputData {
+            chat: None,
+            follow: None,
+            like: None,
+            like_via_repost: None,
+            mention: None,
+            quote: None,
+            reply: None,
+            repost: None,
+            repost_via_repost: None,
+            starterpack_joined: None,
+            subscribed_post: None,
+            unverified: None,
+            verified: None,
+        };
+        match edit {
+            NotificationPrefEdit::Chat(chat) => {
+                let (include, push) = chat.to_server();
+                input.chat = Some(
+                    ChatPreferenceData {
+                        include: include.to_string(),
+                        push,
+                    }
+                    .into(),
+                );
+            }
+            NotificationPrefEdit::Category(category, pref) if category.filterable() => {
+                let value = Some(
+                    FilterablePreferenceData {
+                        include: pref.include.unwrap_or_default().as_str().to_string(),
+                        list: pref.in_app,
+                        push: pref.push,
+                    }
+                    .into(),
+                );
+                match category {
+                    NotificationCategory::Mention => input.mention = value,
+                    NotificationCategory::Reply => input.reply = value,
+                    NotificationCategory::Quote => input.quote = value,
+                    NotificationCategory::Like => input.like = value,
+                    NotificationCategory::Repost => input.repost = value,
+                    NotificationCategory::Follow => input.follow = value,
+                    NotificationCategory::LikeViaRepost => input.like_via_repost = value,
+                    NotificationCategory::RepostViaRepost => input.repost_via_repost = value,
+                    NotificationCategory::StarterpackJoined
+                    | NotificationCategory::SubscribedPost
+                    | NotificationCategory::Verified
+                    | NotificationCategory::Unverified => {}
+                }
+            }
+            NotificationPrefEdit::Category(category, pref) => {
+                let value = Some(
+                    PreferenceData {
+                        list: pref.in_app,
+                        push: pref.push,
+                    }
+                    .into(),
+                );
+                match category {
+                    NotificationCategory::StarterpackJoined => input.starterpack_joined = value,
+                    NotificationCategory::SubscribedPost => input.subscribed_post = value,
+                    NotificationCategory::Verified => input.verified = value,
+                    NotificationCategory::Unverified => input.unverified = value,
+                    _ => {}
+                }
+            }
+        }
+
+        with_agent!(self, agent => {
+        let output = agent
+            .api
+            .app
+            .bsky
+            .notification
+            .put_preferences_v2(input.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+        Ok(Self::notification_prefs_from(&output.data.preferences))
+        })
+    }
+
+    fn notification_prefs_from(
+        prefs: &atrium_api::app::bsky::notification::defs::Preferences,
+    ) -> NotificationPrefs {
+        use atrium_api::app::bsky::notification::defs::{FilterablePreference, Preference};
+
+        let filterable = |pref: &FilterablePreference| CategoryPref {
+            include: Some(IncludeFrom::parse(&pref.include)),
+            in_app: pref.list,
+            push: pref.push,
+        };
+        let plain = |pref: &Preference| CategoryPref {
+            include: None,
+            in_app: pref.list,
+            push: pref.push,
+        };
+        let categories = NotificationCategory::ALL
+            .into_iter()
+            .map(|category| {
+                let pref = match category {
+                    NotificationCategory::Mention => filterable(&prefs.mention),
+                    NotificationCategory::Reply => filterable(&prefs.reply),
+                    NotificationCategory::Quote => filterable(&prefs.quote),
+                    NotificationCategory::Like => filterable(&prefs.like),
+                    NotificationCategory::Repost => filterable(&prefs.repost),
+                    NotificationCategory::Follow => filterable(&prefs.follow),
+                    NotificationCategory::LikeViaRepost => filterable(&prefs.like_via_repost),
+                    NotificationCategory::RepostViaRepost => filterable(&prefs.repost_via_repost),
+                    NotificationCategory::StarterpackJoined => plain(&prefs.starterpack_joined),
+                    NotificationCategory::SubscribedPost => plain(&prefs.subscribed_post),
+                    NotificationCategory::Verified => plain(&prefs.verified),
+                    NotificationCategory::Unverified => plain(&prefs.unverified),
+                };
+                (category, pref)
+            })
+            .collect();
+        NotificationPrefs {
+            chat: ChatNotify::from_server(&prefs.chat.include, prefs.chat.push),
+            categories,
+        }
+    }
+
+    /// Get list of direct message conversations
+    pub async fn get_conversations(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Conversation>, Option<String>), ClientError> {
+        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+
+        with_agent!(self, agent => {
+
+        // Chat API requires proxying through the chat service
+        let chat_did = BSKY_CHAT_DID
+            .parse()
+            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
+        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
+
+        let params = atrium_api::chat::bsky::convo::list_convos::ParametersData {
+            cursor: cursor.map(String::from),
+            limit: None,
+            read_state: None,
+            status: None,
+        };
+
+        let output = chat_api
+            .chat
+            .bsky
+            .convo
+            .list_convos(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let conversations: Vec<Conversation> = output
+            .data
+            .convos
+            .into_iter()
+            .map(|convo| self.convert_convo_view(convo))
+            .collect();
+
+        Ok((conversations, output.data.cursor))
+        })
+    }
+
+    /// Get one conversation by ID
+    pub async fn get_conversation(&self, convo_id: &str) -> Result<Conversation, ClientError> {
+        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+
+        with_agent!(self, agent => {
+
+        let chat_did = BSKY_CHAT_DID
+            .parse()
+            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
+        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
+
+        let params = atrium_api::chat::bsky::convo::get_convo::ParametersData {
+            convo_id: convo_id.to_string(),
+        };
+
+        let output = chat_api
+            .chat
+            .bsky
+            .convo
+            .get_convo(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        Ok(self.convert_convo_view(output.data.convo))
+        })
+    }
+
+    /// Tally unread notifications and chat messages for the sidebar badges.
+    pub async fn get_unread_counts(&self) -> Result<UnreadCounts, ClientError> {
+        self.get_unread_activity()
+            .await
+            .map(|activity| activity.counts)
+    }
+
+    /// Tally unread notifications and chat messages, and keep the unread
+    /// items for the desktop notifications.
+    ///
+    /// One page of each, notifications capped at 100. A very busy account can
+    /// undercount, but the badge display caps at 99+ anyway.
+    pub async fn get_unread_activity(&self) -> Result<UnreadActivity, ClientError> {
+        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::notification::list_notifications::ParametersData {
+            cursor: None,
+            limit: 100u8.try_into().ok(),
+            priority: None,
+            reasons: None,
+            seen_at: None,
+        };
+
+        let notif_output = agent
+            .api
+            .app
+            .bsky
+            .notification
+            .list_notifications(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let chat_did = BSKY_CHAT_DID
+            .parse()
+            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
+        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
+
+        let convo_params = atrium_api::chat::bsky::convo::list_convos::ParametersData {
+            cursor: None,
+            limit: None,
+            read_state: None,
+            status: None,
+        };
+
+        let convo_output = chat_api
+            .chat
+            .bsky
+            .convo
+            .list_convos(convo_params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let counts = UnreadCounts::tally(
+            notif_output
+                .data
+                .notifications
+                .iter()
+                .map(|n| (n.data.reason.as_str(), n.data.is_read)),
+            convo_output
+                .data
+                .convos
+                .iter()
+                .map(|c| (c.data.unread_count, c.data.muted)),
+        );
+        let notifications = notif_output
+            .data
+            .notifications
+            .iter()
+            .filter(|n| !n.data.is_read)
+            .map(|n| self.convert_notification(n))
+            .collect();
+        let conversations = convo_output
+            .data
+            .convos
+            .into_iter()
+            .filter(|c| c.data.unread_count > 0 && !c.data.muted)
+            .map(|c| self.convert_convo_view(c))
+            .collect();
+
+        Ok(UnreadActivity {
+            counts,
+            notifications,
+            conversations,
+        })
+        })
+    }
+
+    /// Tell the server every notification has been seen.
+    ///
+    /// `seenAt` is account-wide. The server cannot mark only mentions seen,
+    /// so the Mentions and Activity badges clear together.
+    pub async fn update_notifications_seen(&self) -> Result<(), ClientError> {
+        with_agent!(self, agent => {
+
+        let input = atrium_api::app::bsky::notification::update_seen::InputData {
+            seen_at: atrium_api::types::string::Datetime::now(),
+        };
+
+        agent
+            .api
+            .app
+            .bsky
+            .notification
+            .update_seen(input.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        Ok(())
+        })
+    }
+
+    /// Mark a conversation read up to its latest message.
+    ///
+    /// The chat badge follows the server's word on the next poll.
+    pub async fn mark_convo_read(&self, convo_id: &str) -> Result<(), ClientError> {
+        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+
+        with_agent!(self, agent => {
+
+        let chat_did = BSKY_CHAT_DID
+            .parse()
+            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
+        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
+
+        let input = atrium_api::chat::bsky::convo::update_read::InputData {
+            convo_id: convo_id.to_string(),
+            message_id: None,
+        };
+
+        chat_api
+            .chat
+            .bsky
+            .convo
+            .update_read(input.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        Ok(())
+        })
+    }
+
+    /// Get messages for a specific conversation
+    pub async fn get_messages(
+        &self,
+        convo_id: &str,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<ChatMessage>, Option<String>), ClientError> {
+        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+
+        with_agent!(self, agent => {
+
+        // Chat API requires proxying through the chat service
+        let chat_did = BSKY_CHAT_DID
+            .parse()
+            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
+        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
+
+        let params = atrium_api::chat::bsky::convo::get_messages::ParametersData {
+            convo_id: convo_id.to_string(),
             cursor: cursor.map(String::from),
             limit: None,
-            priority: None,
-            reasons: None,
-            seen_at: None,
         };
 
-        let output = agent
-            .api
-            .app
+        let output = chat_api
+            .chat
             .bsky
-            .notification
-            .list_notifications(params.into())
+            .convo
+            .get_messages(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        let notifications: Vec<Notification> = output
+        use atrium_api::chat::bsky::convo::get_messages::OutputMessagesItem;
+        use atrium_api::types::Union;
+
+        let messages: Vec<ChatMessage> = output
             .data
-            .notifications
+            .messages
             .into_iter()
-            .filter_map(|notif| {
-                let reason = notif.data.reason.clone();
-
-                // If mentions_only, filter to just mentions/replies/quotes
-                if mentions_only && reason != "mention" && reason != "reply" && reason != "quote" {
-                    return None;
+            .filter_map(|msg| match msg {
+                Union::Refs(OutputMessagesItem::ChatBskyConvoDefsMessageView(view)) => {
+                    Some(self.chat_message_from_view(&view))
                 }
-
-                let author = Profile::minimal(
-                    notif.data.author.data.did.to_string(),
-                    notif.data.author.data.handle.to_string(),
-                    notif.data.author.data.display_name.clone(),
-                    notif.data.author.data.avatar.clone(),
-                );
-
-                // Extract post data if this is a post-based notification
-                let post = self.extract_notification_post(&notif);
-
-                Some(Notification {
-                    uri: notif.data.uri.clone(),
-                    cid: notif.data.cid.as_ref().to_string(),
-                    author,
-                    reason,
-                    indexed_at: notif.data.indexed_at.as_str().to_string(),
-                    is_read: notif.data.is_read,
-                    post,
-                })
+                // Skip deleted messages
+                Union::Refs(OutputMessagesItem::ChatBskyConvoDefsDeletedMessageView(_)) => None,
+                _ => None,
             })
             .collect();
 
-        Ok((notifications, output.data.cursor))
+        Ok((messages, output.data.cursor))
         })
     }
 
-    /// Extract post data from a notification record
-    fn extract_notification_post(
+    /// Convert atrium ConvoView to our Conversation type
+    fn convert_convo_view(
         &self,
-        notif: &atrium_api::app::bsky::notification::list_notifications::Notification,
-    ) -> Option<Post> {
-        use atrium_api::types::Unknown;
+        convo: atrium_api::chat::bsky::convo::defs::ConvoView,
+    ) -> Conversation {
+        use atrium_api::chat::bsky::convo::defs::ConvoViewLastMessageRefs;
+        use atrium_api::types::Union;
 
-        // The record contains the post data for mentions/replies/quotes
-        let reason = &notif.data.reason;
-        if reason != "mention" && reason != "reply" && reason != "quote" {
-            return None;
-        }
+        let members: Vec<Profile> = convo
+            .data
+            .members
+            .iter()
+            .map(|m| {
+                Profile::minimal(
+                    m.data.did.to_string(),
+                    m.data.handle.to_string(),
+                    m.data.display_name.clone(),
+                    m.data.avatar.clone(),
+                )
+            })
+            .collect();
 
-        // Extract text and created_at from record
-        let (text, created_at) = match &notif.data.record {
-            Unknown::Object(map) => {
-                let text = map
-                    .get("text")
-                    .and_then(|dm| serde_json::to_value(dm).ok())
-                    .and_then(|v| v.as_str().map(String::from))
-                    .unwrap_or_default();
+        let last_message = convo.data.last_message.as_ref().and_then(|msg| match msg {
+            Union::Refs(ConvoViewLastMessageRefs::MessageView(view)) => {
+                Some(self.chat_message_from_view(view))
+            }
+            _ => None,
+        });
 
-                let created_at = map
-                    .get("createdAt")
-                    .and_then(|dm| serde_json::to_value(dm).ok())
-                    .and_then(|v| v.as_str().map(String::from))
-                    .unwrap_or_default();
+        Conversation {
+            id: convo.data.id,
+            members,
+            last_message,
+            unread_count: convo.data.unread_count,
+            muted: convo.data.muted,
+        }
+    }
 
-                (text, created_at)
+    /// Convert an atrium MessageView to our ChatMessage type
+    fn chat_message_from_view(
+        &self,
+        view: &atrium_api::chat::bsky::convo::defs::MessageView,
+    ) -> ChatMessage {
+        use atrium_api::chat::bsky::convo::defs::MessageViewEmbedRefs;
+        use atrium_api::types::Union;
+
+        // The chat lexicon's only embed is a record view, a post shared
+        // into the conversation.
+        let embed = view.data.embed.as_ref().and_then(|e| match e {
+            Union::Refs(MessageViewEmbedRefs::AppBskyEmbedRecordView(record_view)) => {
+                self.extract_quote_embed(&record_view.data.record)
             }
-            _ => (String::new(), String::new()),
-        };
+            Union::Unknown(_) => None,
+        });
 
-        let author = Profile::minimal(
-            notif.data.author.data.did.to_string(),
-            notif.data.author.data.handle.to_string(),
-            notif.data.author.data.display_name.clone(),
-            notif.data.author.data.avatar.clone(),
-        );
+        let reactions = view
+            .data
+            .reactions
+            .as_ref()
+            .map(|reactions| {
+                reactions
+                    .iter()
+                    .map(|r| ChatReaction {
+                        value: r.data.value.clone(),
+                        sender_did: r.data.sender.data.did.to_string(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
 
-        Some(Post {
-            uri: notif.data.uri.clone(),
-            cid: notif.data.cid.as_ref().to_string(),
-            author,
-            text,
-            created_at,
-            indexed_at: notif.data.indexed_at.as_str().to_string(),
-            like_count: None,
-            repost_count: None,
-            reply_count: None,
-            embed: None,
-            viewer_like: None,
-            viewer_repost: None,
-            viewer_bookmarked: None,
-            repost_reason: None,
-            reply_context: None,
-        })
+        ChatMessage {
+            id: view.data.id.clone(),
+            text: view.data.text.clone(),
+            sender_did: view.data.sender.data.did.to_string(),
+            sent_at: view.data.sent_at.as_str().to_string(),
+            embed,
+            reactions,
+        }
     }
 
-    /// Get list of direct message conversations
-    pub async fn get_conversations(
+    /// Send a plain text message in a conversation.
+    ///
+    /// The server answers with the message it stored, so the open view can
+    /// append the real thing rather than a local copy.
+    pub async fn send_chat_message(
         &self,
-        cursor: Option<&str>,
-    ) -> Result<(Vec<Conversation>, Option<String>), ClientError> {
+        convo_id: &str,
+        text: &str,
+    ) -> Result<ChatMessage, ClientError> {
         use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
 
         with_agent!(self, agent => {
 
-        // Chat API requires proxying through the chat service
         let chat_did = BSKY_CHAT_DID
             .parse()
             .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
         let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
 
-        let params = atrium_api::chat::bsky::convo::list_convos::ParametersData {
-            cursor: cursor.map(String::from),
-            limit: None,
-            read_state: None,
-            status: None,
+        let message = atrium_api::chat::bsky::convo::defs::MessageInputData {
+            embed: None,
+            facets: None,
+            text: text.to_string(),
+        };
+
+        let input = atrium_api::chat::bsky::convo::send_message::InputData {
+            convo_id: convo_id.to_string(),
+            message: message.into(),
         };
 
         let output = chat_api
             .chat
             .bsky
             .convo
-            .list_convos(params.into())
+            .send_message(input.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        let conversations: Vec<Conversation> = output
-            .data
-            .convos
-            .into_iter()
-            .map(|convo| self.convert_convo_view(convo))
-            .collect();
-
-        Ok((conversations, output.data.cursor))
+        Ok(self.chat_message_from_view(&output))
         })
     }
 
-    /// Tally unread notifications and chat messages for the sidebar badges.
+    /// The conversation with `did`, if the server allows one.
     ///
-    /// One page of each, notifications capped at 100. A very busy account can
-    /// undercount, but the badge display caps at 99+ anyway.
-    pub async fn get_unread_counts(&self) -> Result<UnreadCounts, ClientError> {
+    /// Ok(None) means messaging is unavailable: DMs off, a block, or an
+    /// account restriction. The server does not say which. When chat is
+    /// allowed but no conversation exists yet, get_convo_for_members
+    /// mints an empty one.
+    pub async fn start_conversation(&self, did: &str) -> Result<Option<Conversation>, ClientError> {
         use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
 
         with_agent!(self, agent => {
 
-        let params = atrium_api::app::bsky::notification::list_notifications::ParametersData {
-            cursor: None,
-            limit: 100u8.try_into().ok(),
-            priority: None,
-            reasons: None,
-            seen_at: None,
+        let chat_did = BSKY_CHAT_DID
+            .parse()
+            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
+        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
+
+        let member: atrium_api::types::string::Did = did
+            .parse()
+            .map_err(|e| ClientError::InvalidResponse(format!("invalid did: {e}")))?;
+
+        let params = atrium_api::chat::bsky::convo::get_convo_availability::ParametersData {
+            members: vec![member.clone()],
+        };
+        let availability = chat_api
+            .chat
+            .bsky
+            .convo
+            .get_convo_availability(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        if !availability.data.can_chat {
+            return Ok(None);
+        }
+        if let Some(convo) = availability.data.convo.clone() {
+            return Ok(Some(self.convert_convo_view(convo)));
+        }
+
+        let params = atrium_api::chat::bsky::convo::get_convo_for_members::ParametersData {
+            members: vec![member],
         };
+        let output = chat_api
+            .chat
+            .bsky
+            .convo
+            .get_convo_for_members(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        Ok(Some(self.convert_convo_view(output.data.convo.clone())))
+        })
+    }
+
+    /// Add an emoji reaction to a message. The server answers with the
+    /// updated message, so the open view can refresh the row from truth.
+    pub async fn add_chat_reaction(
+        &self,
+        convo_id: &str,
+        message_id: &str,
+        value: &str,
+    ) -> Result<ChatMessage, ClientError> {
+        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
 
-        let notif_output = agent
-            .api
-            .app
-            .bsky
-            .notification
-            .list_notifications(params.into())
-            .await
-            .map_err(|e| self.xrpc_error(e))?;
+        with_agent!(self, agent => {
 
         let chat_did = BSKY_CHAT_DID
             .parse()
             .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
         let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
 
-        let convo_params = atrium_api::chat::bsky::convo::list_convos::ParametersData {
-            cursor: None,
-            limit: None,
-            read_state: None,
-            status: None,
+        let input = atrium_api::chat::bsky::convo::add_reaction::InputData {
+            convo_id: convo_id.to_string(),
+            message_id: message_id.to_string(),
+            value: value.to_string(),
         };
 
-        let convo_output = chat_api
+        let output = chat_api
             .chat
             .bsky
             .convo
-            .list_convos(convo_params.into())
+            .add_reaction(input.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        Ok(UnreadCounts::tally(
-            notif_output
-                .data
-                .notifications
-                .iter()
-                .map(|n| (n.data.reason.as_str(), n.data.is_read)),
-            convo_output
-                .data
-                .convos
-                .iter()
-                .map(|c| (c.data.unread_count, c.data.muted)),
-        ))
+        Ok(self.chat_message_from_view(&output.data.message))
         })
     }
 
-    /// Tell the server every notification has been seen.
-    ///
-    /// `seenAt` is account-wide. The server cannot mark only mentions seen,
-    /// so the Mentions and Activity badges clear together.
-    pub async fn update_notifications_seen(&self) -> Result<(), ClientError> {
+    /// Take a reaction back off a message. Same answer shape as adding.
+    pub async fn remove_chat_reaction(
+        &self,
+        convo_id: &str,
+        message_id: &str,
+        value: &str,
+    ) -> Result<ChatMessage, ClientError> {
+        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+
         with_agent!(self, agent => {
 
-        let input = atrium_api::app::bsky::notification::update_seen::InputData {
-            seen_at: atrium_api::types::string::Datetime::now(),
+        let chat_did = BSKY_CHAT_DID
+            .parse()
+            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
+        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
+
+        let input = atrium_api::chat::bsky::convo::remove_reaction::InputData {
+            convo_id: convo_id.to_string(),
+            message_id: message_id.to_string(),
+            value: value.to_string(),
         };
 
-        agent
-            .api
-            .app
+        let output = chat_api
+            .chat
             .bsky
-            .notification
-            .update_seen(input.into())
+            .convo
+            .remove_reaction(input.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        Ok(())
+        Ok(self.chat_message_from_view(&output.data.message))
         })
     }
 
-    /// Mark a conversation read up to its latest message.
-    ///
-    /// The chat badge follows the server's word on the next poll.
-    pub async fn mark_convo_read(&self, convo_id: &str) -> Result<(), ClientError> {
+    /// Delete a message from this account's view of the conversation. The
+    /// other side keeps their copy; there is no delete-for-everyone.
+    pub async fn delete_chat_message(
+        &self,
+        convo_id: &str,
+        message_id: &str,
+    ) -> Result<(), ClientError> {
         use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
 
         with_agent!(self, agent => {
@@ -2625,16 +4640,16 @@ impl HangarClient {
             .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
         let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
 
-        let input = atrium_api::chat::bsky::convo::update_read::InputData {
+        let input = atrium_api::chat::bsky::convo::delete_message_for_self::InputData {
             convo_id: convo_id.to_string(),
-            message_id: None,
+            message_id: message_id.to_string(),
         };
 
         chat_api
             .chat
             .bsky
             .convo
-            .update_read(input.into())
+            .delete_message_for_self(input.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
@@ -2642,326 +4657,501 @@ impl HangarClient {
         })
     }
 
-    /// Get messages for a specific conversation
-    pub async fn get_messages(
+    /// Search posts, with whatever filters the query carries.
+    ///
+    /// `SearchQuery::parse` keeps handles and languages the API would
+    /// refuse in the text, so the parameters here always parse.
+    pub async fn search_posts(
         &self,
-        convo_id: &str,
+        query: &SearchQuery,
         cursor: Option<&str>,
-    ) -> Result<(Vec<ChatMessage>, Option<String>), ClientError> {
-        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
-
+    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
         with_agent!(self, agent => {
 
-        // Chat API requires proxying through the chat service
-        let chat_did = BSKY_CHAT_DID
-            .parse()
-            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
-        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
-
-        let params = atrium_api::chat::bsky::convo::get_messages::ParametersData {
-            convo_id: convo_id.to_string(),
+        let day = |date: chrono::NaiveDate| date.format("%Y-%m-%d").to_string();
+        let params = atrium_api::app::bsky::feed::search_posts::ParametersData {
+            q: query.q(),
+            author: query.author.as_deref().and_then(|a| a.parse().ok()),
             cursor: cursor.map(String::from),
+            domain: query.domain.clone(),
+            lang: query.lang.as_deref().and_then(|l| l.parse().ok()),
             limit: None,
+            mentions: query.mentions.as_deref().and_then(|m| m.parse().ok()),
+            since: query.since.map(day),
+            // The API's until is exclusive; the query's last day is not.
+            until: query.until.and_then(|d| d.succ_opt()).map(day),
+            sort: (query.sort != SearchSort::Latest).then(|| query.sort.as_str().to_string()),
+            tag: (!query.tags.is_empty()).then(|| query.tags.clone()),
+            url: query.url.clone(),
         };
 
-        let output = chat_api
-            .chat
+        let output = agent
+            .api
+            .app
             .bsky
-            .convo
-            .get_messages(params.into())
+            .feed
+            .search_posts(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        use atrium_api::chat::bsky::convo::get_messages::OutputMessagesItem;
-        use atrium_api::types::Union;
+        let posts: Vec<Post> = output
+            .data
+            .posts
+            .into_iter()
+            .map(|post_view| self.convert_post_view(&post_view))
+            .collect();
 
-        let messages: Vec<ChatMessage> = output
+        Ok((posts, output.data.cursor))
+        })
+    }
+
+    /// Fast typeahead search for actors (used by mention autocomplete).
+    /// Returns a lightweight list of matching profiles.
+    pub async fn search_actors_typeahead(
+        &self,
+        query: &str,
+        limit: u8,
+    ) -> Result<Vec<Profile>, ClientError> {
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::actor::search_actors_typeahead::ParametersData {
+            q: Some(query.to_string()),
+            limit: atrium_api::types::LimitedNonZeroU8::try_from(limit).ok(),
+            term: None,
+        };
+
+        let output = agent
+            .api
+            .app
+            .bsky
+            .actor
+            .search_actors_typeahead(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let actors: Vec<Profile> = output
             .data
-            .messages
+            .actors
             .into_iter()
-            .filter_map(|msg| match msg {
-                Union::Refs(OutputMessagesItem::ChatBskyConvoDefsMessageView(view)) => {
-                    Some(self.chat_message_from_view(&view))
-                }
-                // Skip deleted messages
-                Union::Refs(OutputMessagesItem::ChatBskyConvoDefsDeletedMessageView(_)) => None,
-                _ => None,
+            .map(|actor| Profile {
+                did: actor.data.did.to_string(),
+                handle: actor.data.handle.to_string(),
+                display_name: actor.data.display_name.clone(),
+                avatar: actor.data.avatar.clone(),
+                banner: None,
+                description: None,
+                followers_count: None,
+                following_count: None,
+                posts_count: None,
+                viewer_following: actor
+                    .data
+                    .viewer
+                    .as_ref()
+                    .and_then(|v| v.data.following.clone()),
+                viewer_followed_by: actor
+                    .data
+                    .viewer
+                    .as_ref()
+                    .and_then(|v| v.data.followed_by.clone()),
+                viewer_muted: false,
+                viewer_blocking: None,
+                viewer_blocked_by: false,
+                labels: Self::labels_from_view(&actor.data.labels),
+                muted_by_list: None,
+                blocking_by_list: None,
             })
             .collect();
 
-        Ok((messages, output.data.cursor))
+        Ok(actors)
         })
     }
 
-    /// Convert atrium ConvoView to our Conversation type
-    fn convert_convo_view(
+    /// Search actors (users) by query string, with cursor pagination
+    pub async fn search_actors(
         &self,
-        convo: atrium_api::chat::bsky::convo::defs::ConvoView,
-    ) -> Conversation {
-        use atrium_api::chat::bsky::convo::defs::ConvoViewLastMessageRefs;
-        use atrium_api::types::Union;
+        query: &str,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Profile>, Option<String>), ClientError> {
+        with_agent!(self, agent => {
 
-        let members: Vec<Profile> = convo
+        let params = atrium_api::app::bsky::actor::search_actors::ParametersData {
+            q: Some(query.to_string()),
+            cursor: cursor.map(String::from),
+            limit: None,
+            term: None,
+        };
+
+        let output = agent
+            .api
+            .app
+            .bsky
+            .actor
+            .search_actors(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let actors: Vec<Profile> = output
             .data
-            .members
+            .actors
             .iter()
-            .map(|m| {
-                Profile::minimal(
-                    m.data.did.to_string(),
-                    m.data.handle.to_string(),
-                    m.data.display_name.clone(),
-                    m.data.avatar.clone(),
-                )
-            })
+            .map(Self::profile_from_view)
             .collect();
 
-        let last_message = convo.data.last_message.as_ref().and_then(|msg| match msg {
-            Union::Refs(ConvoViewLastMessageRefs::MessageView(view)) => {
-                Some(self.chat_message_from_view(view))
-            }
-            _ => None,
-        });
+        Ok((actors, output.data.cursor))
+        })
+    }
 
-        Conversation {
-            id: convo.data.id,
-            members,
-            last_message,
-            unread_count: convo.data.unread_count,
-            muted: convo.data.muted,
+    /// Convert a wire ProfileView into our Profile. The view carries no
+    /// counts; pages that need them fetch the full profile.
+    fn profile_from_view(view: &atrium_api::app::bsky::actor::defs::ProfileView) -> Profile {
+        let viewer = view.data.viewer.as_ref();
+        let (muted_by_list, blocking_by_list) = Self::lists_behind(viewer);
+        Profile {
+            did: view.data.did.to_string(),
+            handle: view.data.handle.to_string(),
+            display_name: view.data.display_name.clone(),
+            avatar: view.data.avatar.clone(),
+            banner: None,
+            description: view.data.description.clone(),
+            followers_count: None,
+            following_count: None,
+            posts_count: None,
+            viewer_following: viewer.and_then(|v| v.data.following.clone()),
+            viewer_followed_by: viewer.and_then(|v| v.data.followed_by.clone()),
+            viewer_muted: viewer.and_then(|v| v.data.muted).unwrap_or(false),
+            viewer_blocking: viewer.and_then(|v| v.data.blocking.clone()),
+            viewer_blocked_by: viewer.and_then(|v| v.data.blocked_by).unwrap_or(false),
+            labels: Self::labels_from_view(&view.data.labels),
+            muted_by_list,
+            blocking_by_list,
         }
     }
 
-    /// Convert an atrium MessageView to our ChatMessage type
-    fn chat_message_from_view(
-        &self,
-        view: &atrium_api::chat::bsky::convo::defs::MessageView,
-    ) -> ChatMessage {
-        use atrium_api::chat::bsky::convo::defs::MessageViewEmbedRefs;
-        use atrium_api::types::Union;
-
-        // The chat lexicon's only embed is a record view, a post shared
-        // into the conversation.
-        let embed = view.data.embed.as_ref().and_then(|e| match e {
-            Union::Refs(MessageViewEmbedRefs::AppBskyEmbedRecordView(record_view)) => {
-                self.extract_quote_embed(&record_view.data.record)
-            }
-            Union::Unknown(_) => None,
-        });
+    /// The lists a viewer state says the account is muted and blocked
+    /// through, in that order.
+    fn lists_behind(
+        viewer: Option<&atrium_api::app::bsky::actor::defs::ViewerState>,
+    ) -> (Option<ListRef>, Option<ListRef>) {
+        let list_ref = |list: &atrium_api::app::bsky::graph::defs::ListViewBasic| ListRef {
+            uri: list.data.uri.to_string(),
+            name: list.data.name.clone(),
+        };
+        let Some(viewer) = viewer else {
+            return (None, None);
+        };
+        (
+            viewer.data.muted_by_list.as_ref().map(list_ref),
+            viewer.data.blocking_by_list.as_ref().map(list_ref),
+        )
+    }
 
-        let reactions = view
+    /// A post or quote author. Only the follow is kept from the viewer
+    /// state: muted words that spare followed accounts need it.
+    fn author_from_view(view: &atrium_api::app::bsky::actor::defs::ProfileViewBasic) -> Profile {
+        let mut profile = Profile::minimal(
+            view.data.did.to_string(),
+            view.data.handle.to_string(),
+            view.data.display_name.clone(),
+            view.data.avatar.clone(),
+        );
+        profile.viewer_following = view
             .data
-            .reactions
+            .viewer
             .as_ref()
-            .map(|reactions| {
-                reactions
-                    .iter()
-                    .map(|r| ChatReaction {
-                        value: r.data.value.clone(),
-                        sender_did: r.data.sender.data.did.to_string(),
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+            .and_then(|v| v.data.following.clone());
+        profile.labels = Self::labels_from_view(&view.data.labels);
+        profile
+    }
 
-        ChatMessage {
-            id: view.data.id.clone(),
-            text: view.data.text.clone(),
-            sender_did: view.data.sender.data.did.to_string(),
-            sent_at: view.data.sent_at.as_str().to_string(),
-            embed,
-            reactions,
-        }
+    /// Wire labels as the app models them. Lapsed ones are dropped here, so
+    /// nothing downstream needs to know labels can expire.
+    fn labels_from_view(
+        labels: &Option<Vec<atrium_api::com::atproto::label::defs::Label>>,
+    ) -> Vec<Label> {
+        let now = chrono::Utc::now();
+        labels
+            .iter()
+            .flatten()
+            .filter(|label| {
+                label
+                    .data
+                    .exp
+                    .as_ref()
+                    .and_then(|exp| chrono::DateTime::parse_from_rfc3339(exp.as_str()).ok())
+                    .is_none_or(|exp| exp > now)
+            })
+            .map(|label| Label {
+                src: label.data.src.to_string(),
+                val: label.data.val.clone(),
+                neg: label.data.neg.unwrap_or(false),
+            })
+            .collect()
     }
 
-    /// Send a plain text message in a conversation.
-    ///
-    /// The server answers with the message it stored, so the open view can
-    /// append the real thing rather than a local copy.
-    pub async fn send_chat_message(
+    /// Fetch one page of the accounts following `actor`
+    pub async fn get_followers(
         &self,
-        convo_id: &str,
-        text: &str,
-    ) -> Result<ChatMessage, ClientError> {
-        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
-
+        actor: &str,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Profile>, Option<String>), ClientError> {
         with_agent!(self, agent => {
 
-        let chat_did = BSKY_CHAT_DID
-            .parse()
-            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
-        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
-
-        let message = atrium_api::chat::bsky::convo::defs::MessageInputData {
-            embed: None,
-            facets: None,
-            text: text.to_string(),
-        };
-
-        let input = atrium_api::chat::bsky::convo::send_message::InputData {
-            convo_id: convo_id.to_string(),
-            message: message.into(),
+        let params = atrium_api::app::bsky::graph::get_followers::ParametersData {
+            actor: actor
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid actor: {e}")))?,
+            cursor: cursor.map(String::from),
+            limit: None,
         };
 
-        let output = chat_api
-            .chat
+        let output = agent
+            .api
+            .app
             .bsky
-            .convo
-            .send_message(input.into())
+            .graph
+            .get_followers(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        Ok(self.chat_message_from_view(&output))
+        let profiles = output.data.followers.iter().map(Self::profile_from_view).collect();
+
+        Ok((profiles, output.data.cursor))
         })
     }
 
-    /// The conversation with `did`, if the server allows one.
-    ///
-    /// Ok(None) means messaging is unavailable: DMs off, a block, or an
-    /// account restriction. The server does not say which. When chat is
-    /// allowed but no conversation exists yet, get_convo_for_members
-    /// mints an empty one.
-    pub async fn start_conversation(&self, did: &str) -> Result<Option<Conversation>, ClientError> {
-        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
-
+    /// Fetch one page of the accounts `actor` follows
+    pub async fn get_follows(
+        &self,
+        actor: &str,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Profile>, Option<String>), ClientError> {
         with_agent!(self, agent => {
 
-        let chat_did = BSKY_CHAT_DID
-            .parse()
-            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
-        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
-
-        let member: atrium_api::types::string::Did = did
-            .parse()
-            .map_err(|e| ClientError::InvalidResponse(format!("invalid did: {e}")))?;
-
-        let params = atrium_api::chat::bsky::convo::get_convo_availability::ParametersData {
-            members: vec![member.clone()],
+        let params = atrium_api::app::bsky::graph::get_follows::ParametersData {
+            actor: actor
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid actor: {e}")))?,
+            cursor: cursor.map(String::from),
+            limit: None,
         };
-        let availability = chat_api
-            .chat
-            .bsky
-            .convo
-            .get_convo_availability(params.into())
-            .await
-            .map_err(|e| self.xrpc_error(e))?;
-
-        if !availability.data.can_chat {
-            return Ok(None);
-        }
-        if let Some(convo) = availability.data.convo.clone() {
-            return Ok(Some(self.convert_convo_view(convo)));
-        }
 
-        let params = atrium_api::chat::bsky::convo::get_convo_for_members::ParametersData {
-            members: vec![member],
-        };
-        let output = chat_api
-            .chat
+        let output = agent
+            .api
+            .app
             .bsky
-            .convo
-            .get_convo_for_members(params.into())
+            .graph
+            .get_follows(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        Ok(Some(self.convert_convo_view(output.data.convo.clone())))
+        let profiles = output.data.follows.iter().map(Self::profile_from_view).collect();
+
+        Ok((profiles, output.data.cursor))
         })
     }
 
-    /// Add an emoji reaction to a message. The server answers with the
-    /// updated message, so the open view can refresh the row from truth.
-    pub async fn add_chat_reaction(
-        &self,
-        convo_id: &str,
-        message_id: &str,
-        value: &str,
-    ) -> Result<ChatMessage, ClientError> {
-        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+    fn list_from_view(view: &atrium_api::app::bsky::graph::defs::ListView) -> UserList {
+        let viewer = view.data.viewer.as_ref();
+        UserList {
+            uri: view.data.uri.to_string(),
+            name: view.data.name.clone(),
+            purpose: ListPurpose::parse(&view.data.purpose),
+            description: view.data.description.clone(),
+            avatar: view.data.avatar.clone(),
+            creator: Self::profile_from_view(&view.data.creator),
+            item_count: view
+                .data
+                .list_item_count
+                .and_then(|n| u32::try_from(n).ok()),
+            viewer_muted: viewer.and_then(|v| v.data.muted).unwrap_or(false),
+            viewer_blocked: viewer.and_then(|v| v.data.blocked.clone()),
+        }
+    }
 
+    /// Fetch one page of the lists `actor` made
+    pub async fn get_lists(
+        &self,
+        actor: &str,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<UserList>, Option<String>), ClientError> {
         with_agent!(self, agent => {
 
-        let chat_did = BSKY_CHAT_DID
-            .parse()
-            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
-        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
-
-        let input = atrium_api::chat::bsky::convo::add_reaction::InputData {
-            convo_id: convo_id.to_string(),
-            message_id: message_id.to_string(),
-            value: value.to_string(),
+        let params = atrium_api::app::bsky::graph::get_lists::ParametersData {
+            actor: actor
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid actor: {e}")))?,
+            cursor: cursor.map(String::from),
+            limit: None,
+            purposes: None,
         };
 
-        let output = chat_api
-            .chat
+        let output = agent
+            .api
+            .app
             .bsky
-            .convo
-            .add_reaction(input.into())
+            .graph
+            .get_lists(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        Ok(self.chat_message_from_view(&output.data.message))
+        let lists = output.data.lists.iter().map(Self::list_from_view).collect();
+
+        Ok((lists, output.data.cursor))
         })
     }
 
-    /// Take a reaction back off a message. Same answer shape as adding.
-    pub async fn remove_chat_reaction(
+    /// A list and one page of its members
+    pub async fn get_list(
         &self,
-        convo_id: &str,
-        message_id: &str,
-        value: &str,
-    ) -> Result<ChatMessage, ClientError> {
-        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+        list_uri: &str,
+        cursor: Option<&str>,
+    ) -> Result<(UserList, Vec<ListMember>, Option<String>), ClientError> {
+        self.get_list_page(list_uri, cursor, None).await
+    }
 
-        with_agent!(self, agent => {
+    /// A list's own description, without paging through who is on it.
+    async fn get_list_info(&self, list_uri: &str) -> Result<UserList, ClientError> {
+        let (list, _, _) = self.get_list_page(list_uri, None, Some(1)).await?;
+        Ok(list)
+    }
 
-        let chat_did = BSKY_CHAT_DID
-            .parse()
-            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
-        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
+    async fn get_list_page(
+        &self,
+        list_uri: &str,
+        cursor: Option<&str>,
+        limit: Option<u8>,
+    ) -> Result<(UserList, Vec<ListMember>, Option<String>), ClientError> {
+        with_agent!(self, agent => {
 
-        let input = atrium_api::chat::bsky::convo::remove_reaction::InputData {
-            convo_id: convo_id.to_string(),
-            message_id: message_id.to_string(),
-            value: value.to_string(),
+        let params = atrium_api::app::bsky::graph::get_list::ParametersData {
+            list: list_uri
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid list URI: {e}")))?,
+            cursor: cursor.map(String::from),
+            limit: limit.and_then(|l| atrium_api::types::LimitedNonZeroU8::try_from(l).ok()),
         };
 
-        let output = chat_api
-            .chat
+        let output = agent
+            .api
+            .app
             .bsky
-            .convo
-            .remove_reaction(input.into())
+            .graph
+            .get_list(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        Ok(self.chat_message_from_view(&output.data.message))
-        })
+        let members = output
+            .data
+            .items
+            .iter()
+            .map(|item| ListMember {
+                item_uri: item.data.uri.to_string(),
+                profile: Self::profile_from_view(&item.data.subject),
+            })
+            .collect();
+
+        Ok((Self::list_from_view(&output.data.list), members, output.data.cursor))
+        })
+    }
+
+    /// Make a new list. Returns its AT-URI.
+    pub async fn create_list(
+        &self,
+        name: &str,
+        description: &str,
+        purpose: ListPurpose,
+    ) -> Result<String, ClientError> {
+        let mut record = serde_json::json!({
+            "$type": "app.bsky.graph.list",
+            "purpose": purpose.as_str(),
+            "name": name,
+            "createdAt": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+        });
+        if !description.is_empty() {
+            record["description"] = description.into();
+        }
+        self.create_record("app.bsky.graph.list", record).await
+    }
+
+    /// The edited name and description land on the existing record, the
+    /// way [`Self::merge_profile_record`] keeps a profile's other fields.
+    fn merge_list_record(
+        mut record: serde_json::Value,
+        name: &str,
+        description: &str,
+    ) -> serde_json::Value {
+        let Some(map) = record.as_object_mut() else {
+            return record;
+        };
+        map.insert("name".into(), name.into());
+        if description.is_empty() {
+            map.remove("description");
+            // Facets index into the text; with no text they point nowhere.
+            map.remove("descriptionFacets");
+        } else {
+            map.insert("description".into(), description.into());
+        }
+        record
     }
 
-    /// Delete a message from this account's view of the conversation. The
-    /// other side keeps their copy; there is no delete-for-everyone.
-    pub async fn delete_chat_message(
+    /// Rename a list or change its description. Swaps against the CID that
+    /// was read, as [`Self::update_profile`] does.
+    pub async fn update_list(
         &self,
-        convo_id: &str,
-        message_id: &str,
+        list_uri: &str,
+        name: &str,
+        description: &str,
     ) -> Result<(), ClientError> {
-        use atrium_api::agent::bluesky::{AtprotoServiceType, BSKY_CHAT_DID};
+        let (repo, rkey) = parse_record_uri(list_uri, "app.bsky.graph.list")?;
 
         with_agent!(self, agent => {
 
-        let chat_did = BSKY_CHAT_DID
+        let collection = atrium_api::types::string::Nsid::new("app.bsky.graph.list".to_string())
+            .map_err(|_| ClientError::InvalidResponse("invalid collection".into()))?;
+        let repo: atrium_api::types::string::AtIdentifier = repo
             .parse()
-            .map_err(|e| ClientError::Network(format!("invalid chat DID: {e}")))?;
-        let chat_api = agent.api_with_proxy(chat_did, AtprotoServiceType::BskyChat);
+            .map_err(|_| ClientError::InvalidResponse("invalid repo DID".into()))?;
+        let rkey: RecordKey = rkey
+            .parse()
+            .map_err(|_| ClientError::InvalidResponse("invalid record key".into()))?;
 
-        let input = atrium_api::chat::bsky::convo::delete_message_for_self::InputData {
-            convo_id: convo_id.to_string(),
-            message_id: message_id.to_string(),
+        let params = atrium_api::com::atproto::repo::get_record::ParametersData {
+            cid: None,
+            collection: collection.clone(),
+            repo: repo.clone(),
+            rkey: rkey.clone(),
         };
+        let output = agent
+            .api
+            .com
+            .atproto
+            .repo
+            .get_record(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+        let existing = serde_json::to_value(&output.data.value)
+            .map_err(|e| ClientError::InvalidResponse(e.to_string()))?;
 
-        chat_api
-            .chat
-            .bsky
-            .convo
-            .delete_message_for_self(input.into())
+        let record: Unknown =
+            serde_json::from_value(Self::merge_list_record(existing, name, description))
+                .map_err(|e| ClientError::InvalidResponse(e.to_string()))?;
+
+        let input = atrium_api::com::atproto::repo::put_record::InputData {
+            collection,
+            record,
+            repo,
+            rkey,
+            swap_commit: None,
+            swap_record: output.data.cid.clone(),
+            validate: None,
+        };
+
+        agent
+            .api
+            .com
+            .atproto
+            .repo
+            .put_record(input.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
@@ -2969,178 +5159,385 @@ impl HangarClient {
         })
     }
 
-    /// Search posts by query string
-    pub async fn search_posts(
-        &self,
-        query: &str,
-        cursor: Option<&str>,
-    ) -> Result<(Vec<Post>, Option<String>), ClientError> {
-        with_agent!(self, agent => {
+    /// Delete a list, its members' entries first. A list record deleted
+    /// first would leave the entries behind in the repo with nothing to
+    /// point at; entries deleted first leave, at worst, a shorter list.
+    ///
+    /// The list itself goes only once every entry has: a list too long to
+    /// read through [`MEMBERSHIP_PAGE_CAP`] pages, or a batch of deletes
+    /// that fails, leaves it in place to try again.
+    pub async fn delete_list(&self, list_uri: &str) -> Result<(), ClientError> {
+        let mut cursor: Option<String> = None;
+        let mut item_uris = Vec::new();
+        for _ in 0..MEMBERSHIP_PAGE_CAP {
+            let (_, members, next) = self
+                .get_list_page(list_uri, cursor.as_deref(), Some(100))
+                .await?;
+            item_uris.extend(members.into_iter().map(|m| m.item_uri));
+            cursor = next;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        if cursor.is_some() {
+            return Err(ClientError::InvalidResponse(
+                "list has too many members to delete at once".into(),
+            ));
+        }
+        self.delete_records("app.bsky.graph.listitem", &item_uris)
+            .await?;
+        self.delete_record(list_uri, "app.bsky.graph.list").await
+    }
 
-        let params = atrium_api::app::bsky::feed::search_posts::ParametersData {
-            q: query.to_string(),
-            author: None,
-            cursor: cursor.map(String::from),
-            domain: None,
-            lang: None,
-            limit: None,
-            mentions: None,
-            since: None,
-            sort: None,
-            tag: None,
-            until: None,
-            url: None,
-        };
+    /// Put an account on a list. Returns the `listitem` record's AT-URI,
+    /// which is what takes them off again.
+    pub async fn add_list_member(
+        &self,
+        list_uri: &str,
+        subject_did: &str,
+    ) -> Result<String, ClientError> {
+        let record = serde_json::json!({
+            "$type": "app.bsky.graph.listitem",
+            "subject": subject_did,
+            "list": list_uri,
+            "createdAt": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+        });
+        self.create_record("app.bsky.graph.listitem", record).await
+    }
 
-        let output = agent
-            .api
-            .app
-            .bsky
-            .feed
-            .search_posts(params.into())
+    /// Take an account off a list by deleting its `listitem` record
+    pub async fn remove_list_member(&self, item_uri: &str) -> Result<(), ClientError> {
+        self.delete_record(item_uri, "app.bsky.graph.listitem")
             .await
-            .map_err(|e| self.xrpc_error(e))?;
+    }
 
-        let posts: Vec<Post> = output
-            .data
-            .posts
+    /// The signed-in user's lists, each with the entry that puts
+    /// `subject_did` on it, if one does. Backs "Add to Lists".
+    ///
+    /// The entries come from the user's own repo rather than from each
+    /// list in turn: one paged read instead of one per list.
+    pub async fn list_memberships(
+        &self,
+        subject_did: &str,
+    ) -> Result<Vec<(UserList, Option<String>)>, ClientError> {
+        let did = self.current_did().await?;
+
+        let mut lists = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..MEMBERSHIP_PAGE_CAP {
+            let (page, next) = self.get_lists(&did, cursor.as_deref()).await?;
+            lists.extend(page);
+            cursor = next;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..MEMBERSHIP_PAGE_CAP {
+            let (page, next) = self
+                .list_own_records("app.bsky.graph.listitem", cursor)
+                .await?;
+            items.extend(page);
+            cursor = next;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        let entries = Self::list_entries_for(&items, subject_did);
+
+        Ok(lists
             .into_iter()
-            .map(|post_view| self.convert_post_view(&post_view))
-            .collect();
+            .map(|list| {
+                let entry = entries.get(&list.uri).cloned();
+                (list, entry)
+            })
+            .collect())
+    }
 
-        Ok((posts, output.data.cursor))
-        })
+    /// Which lists `subject_did` is on, by list URI, given `listitem`
+    /// records as `(record URI, value)`. Entries naming anyone else, or
+    /// missing a field, are passed over.
+    fn list_entries_for(
+        items: &[(String, serde_json::Value)],
+        subject_did: &str,
+    ) -> HashMap<String, String> {
+        items
+            .iter()
+            .filter(|(_, value)| value["subject"].as_str() == Some(subject_did))
+            .filter_map(|(uri, value)| Some((value["list"].as_str()?.to_string(), uri.clone())))
+            .collect()
     }
 
-    /// Fast typeahead search for actors (used by mention autocomplete).
-    /// Returns a lightweight list of matching profiles.
-    pub async fn search_actors_typeahead(
+    /// One page of the signed-in user's records in `collection`, as
+    /// `(record URI, value)`.
+    async fn list_own_records(
         &self,
-        query: &str,
-        limit: u8,
-    ) -> Result<Vec<Profile>, ClientError> {
-        with_agent!(self, agent => {
+        collection: &str,
+        cursor: Option<String>,
+    ) -> Result<(Vec<(String, serde_json::Value)>, Option<String>), ClientError> {
+        with_agent_and_did!(self, agent, did => {
 
-        let params = atrium_api::app::bsky::actor::search_actors_typeahead::ParametersData {
-            q: Some(query.to_string()),
-            limit: atrium_api::types::LimitedNonZeroU8::try_from(limit).ok(),
-            term: None,
+        let params = atrium_api::com::atproto::repo::list_records::ParametersData {
+            collection: atrium_api::types::string::Nsid::new(collection.to_string())
+                .map_err(|_| ClientError::InvalidResponse("invalid collection".into()))?,
+            cursor,
+            limit: atrium_api::types::LimitedNonZeroU8::try_from(100).ok(),
+            repo: did.clone().into(),
+            reverse: None,
         };
 
         let output = agent
             .api
-            .app
-            .bsky
-            .actor
-            .search_actors_typeahead(params.into())
+            .com
+            .atproto
+            .repo
+            .list_records(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        let actors: Vec<Profile> = output
+        let records = output
             .data
-            .actors
-            .into_iter()
-            .map(|actor| Profile {
-                did: actor.data.did.to_string(),
-                handle: actor.data.handle.to_string(),
-                display_name: actor.data.display_name.clone(),
-                avatar: actor.data.avatar.clone(),
-                banner: None,
-                description: None,
-                followers_count: None,
-                following_count: None,
-                posts_count: None,
-                viewer_following: actor
-                    .data
-                    .viewer
-                    .as_ref()
-                    .and_then(|v| v.data.following.clone()),
-                viewer_followed_by: actor
-                    .data
-                    .viewer
-                    .as_ref()
-                    .and_then(|v| v.data.followed_by.clone()),
-                viewer_muted: false,
-                viewer_blocking: None,
-                viewer_blocked_by: false,
+            .records
+            .iter()
+            .filter_map(|record| {
+                let value = serde_json::to_value(&record.data.value).ok()?;
+                Some((record.data.uri.to_string(), value))
             })
             .collect();
 
-        Ok(actors)
+        Ok((records, output.data.cursor))
         })
     }
 
-    /// Search actors (users) by query string, with cursor pagination
-    pub async fn search_actors(
+    /// Everyone on a list, paged through up to [`MEMBERSHIP_PAGE_CAP`].
+    /// Backs the actions that take a list whole: following a starter
+    /// pack, or making one from a list.
+    pub async fn all_list_members(&self, list_uri: &str) -> Result<Vec<ListMember>, ClientError> {
+        let mut members = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..MEMBERSHIP_PAGE_CAP {
+            let (_, page, next) = self
+                .get_list_page(list_uri, cursor.as_deref(), Some(100))
+                .await?;
+            members.extend(page);
+            cursor = next;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        Ok(members)
+    }
+
+    /// Create `records` in `collection` through `applyWrites`, a batch at
+    /// a time rather than a request each. Returns the new records'
+    /// AT-URIs in order. A failed batch stops the run; the batches before
+    /// it stay written.
+    async fn create_records(
         &self,
-        query: &str,
-        cursor: Option<&str>,
-    ) -> Result<(Vec<Profile>, Option<String>), ClientError> {
-        with_agent!(self, agent => {
+        collection: &str,
+        records: Vec<serde_json::Value>,
+    ) -> Result<Vec<String>, ClientError> {
+        let mut uris = Vec::with_capacity(records.len());
+        for batch in records.chunks(WRITE_BATCH) {
+            uris.extend(self.apply_creates(collection, batch).await?);
+        }
+        Ok(uris)
+    }
 
-        let params = atrium_api::app::bsky::actor::search_actors::ParametersData {
-            q: Some(query.to_string()),
-            cursor: cursor.map(String::from),
-            limit: None,
-            term: None,
+    async fn apply_creates(
+        &self,
+        collection: &str,
+        batch: &[serde_json::Value],
+    ) -> Result<Vec<String>, ClientError> {
+        use atrium_api::com::atproto::repo::apply_writes;
+
+        with_agent_and_did!(self, agent, did => {
+
+        let collection = atrium_api::types::string::Nsid::new(collection.to_string())
+            .map_err(|_| ClientError::InvalidResponse("invalid collection".into()))?;
+        let writes = batch
+            .iter()
+            .map(|json| {
+                let value: Unknown = serde_json::from_value(json.clone())
+                    .map_err(|e| ClientError::InvalidResponse(e.to_string()))?;
+                Ok(apply_writes::InputWritesItem::Create(Box::new(
+                    apply_writes::CreateData {
+                        collection: collection.clone(),
+                        rkey: None,
+                        value,
+                    }
+                    .into(),
+                )))
+            })
+            .collect::<Result<Vec<_>, ClientError>>()?;
+
+        let input = apply_writes::InputData {
+            repo: did.clone().into(),
+            swap_commit: None,
+            validate: None,
+            writes,
         };
 
         let output = agent
             .api
-            .app
-            .bsky
-            .actor
-            .search_actors(params.into())
+            .com
+            .atproto
+            .repo
+            .apply_writes(input.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        let actors: Vec<Profile> = output
+        Ok(output
             .data
-            .actors
-            .iter()
-            .map(Self::profile_from_view)
-            .collect();
-
-        Ok((actors, output.data.cursor))
+            .results
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|result| match result {
+                apply_writes::OutputResultsItem::CreateResult(created) => {
+                    Some(created.data.uri.clone())
+                }
+                _ => None,
+            })
+            .collect())
         })
     }
 
-    /// Convert a wire ProfileView into our Profile. The view carries no
-    /// counts; pages that need them fetch the full profile.
-    fn profile_from_view(view: &atrium_api::app::bsky::actor::defs::ProfileView) -> Profile {
-        let viewer = view.data.viewer.as_ref();
-        Profile {
-            did: view.data.did.to_string(),
-            handle: view.data.handle.to_string(),
-            display_name: view.data.display_name.clone(),
-            avatar: view.data.avatar.clone(),
-            banner: None,
-            description: view.data.description.clone(),
-            followers_count: None,
-            following_count: None,
-            posts_count: None,
-            viewer_following: viewer.and_then(|v| v.data.following.clone()),
-            viewer_followed_by: viewer.and_then(|v| v.data.followed_by.clone()),
-            viewer_muted: viewer.and_then(|v| v.data.muted).unwrap_or(false),
-            viewer_blocking: viewer.and_then(|v| v.data.blocking.clone()),
-            viewer_blocked_by: viewer.and_then(|v| v.data.blocked_by).unwrap_or(false),
+    /// Delete the records at `uris`, all in `collection`, through
+    /// `applyWrites` a batch at a time. A failed batch stops the run; the
+    /// batches before it stay deleted.
+    async fn delete_records(&self, collection: &str, uris: &[String]) -> Result<(), ClientError> {
+        for batch in uris.chunks(WRITE_BATCH) {
+            self.apply_deletes(collection, batch).await?;
+        }
+        Ok(())
+    }
+
+    async fn apply_deletes(&self, collection: &str, batch: &[String]) -> Result<(), ClientError> {
+        use atrium_api::com::atproto::repo::apply_writes;
+
+        with_agent_and_did!(self, agent, did => {
+
+        let nsid = atrium_api::types::string::Nsid::new(collection.to_string())
+            .map_err(|_| ClientError::InvalidResponse("invalid collection".into()))?;
+        let writes = batch
+            .iter()
+            .map(|uri| {
+                let (_, rkey) = parse_record_uri(uri, collection)?;
+                let rkey = rkey
+                    .parse::<RecordKey>()
+                    .map_err(|_| ClientError::InvalidResponse("invalid record key".into()))?;
+                Ok(apply_writes::InputWritesItem::Delete(Box::new(
+                    apply_writes::DeleteData {
+                        collection: nsid.clone(),
+                        rkey,
+                    }
+                    .into(),
+                )))
+            })
+            .collect::<Result<Vec<_>, ClientError>>()?;
+
+        let input = apply_writes::InputData {
+            repo: did.clone().into(),
+            swap_commit: None,
+            validate: None,
+            writes,
+        };
+
+        agent
+            .api
+            .com
+            .atproto
+            .repo
+            .apply_writes(input.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+        Ok(())
+        })
+    }
+
+    /// Follow everyone in `dids` at once, the signed-in user aside.
+    /// Returns `(did, follow record URI)` for each follow made.
+    pub async fn follow_all(&self, dids: &[String]) -> Result<Vec<(String, String)>, ClientError> {
+        let own = self.current_did().await?;
+        let subjects: Vec<String> = dids.iter().filter(|did| **did != own).cloned().collect();
+        let created_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        let records = subjects
+            .iter()
+            .map(|did| {
+                serde_json::json!({
+                    "$type": "app.bsky.graph.follow",
+                    "subject": did,
+                    "createdAt": created_at
+                })
+            })
+            .collect();
+        let uris = self
+            .create_records("app.bsky.graph.follow", records)
+            .await?;
+        Ok(subjects.into_iter().zip(uris).collect())
+    }
+
+    /// A starter pack's own words and list, read off its record. Counts
+    /// and feeds come from the view around it.
+    fn starter_pack_from_record(
+        uri: String,
+        record: &serde_json::Value,
+        creator: Profile,
+    ) -> StarterPack {
+        StarterPack {
+            uri,
+            name: record["name"].as_str().unwrap_or_default().to_string(),
+            description: record["description"]
+                .as_str()
+                .filter(|d| !d.is_empty())
+                .map(String::from),
+            creator,
+            list_uri: record["list"].as_str().map(String::from),
+            item_count: None,
+            joined_count: None,
+            feeds: Vec::new(),
         }
     }
 
-    /// Fetch one page of the accounts following `actor`
-    pub async fn get_followers(
-        &self,
-        actor: &str,
-        cursor: Option<&str>,
-    ) -> Result<(Vec<Profile>, Option<String>), ClientError> {
+    /// An `app.bsky.graph.starterpack` record over `list_uri`, naming at
+    /// most [`STARTER_PACK_FEED_CAP`] feeds.
+    fn starter_pack_record(
+        name: &str,
+        description: &str,
+        list_uri: &str,
+        feed_uris: &[String],
+    ) -> serde_json::Value {
+        let mut record = serde_json::json!({
+            "$type": "app.bsky.graph.starterpack",
+            "name": name,
+            "list": list_uri,
+            "createdAt": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+        });
+        if !description.is_empty() {
+            record["description"] = description.into();
+        }
+        let feeds: Vec<serde_json::Value> = feed_uris
+            .iter()
+            .take(STARTER_PACK_FEED_CAP)
+            .map(|uri| serde_json::json!({ "uri": uri }))
+            .collect();
+        if !feeds.is_empty() {
+            record["feeds"] = feeds.into();
+        }
+        record
+    }
+
+    /// A starter pack with its feeds. Its people are paged from its list
+    /// with [`Self::get_list`].
+    pub async fn get_starter_pack(&self, uri: &str) -> Result<StarterPack, ClientError> {
         with_agent!(self, agent => {
 
-        let params = atrium_api::app::bsky::graph::get_followers::ParametersData {
-            actor: actor
-                .parse()
-                .map_err(|e| ClientError::InvalidResponse(format!("invalid actor: {e}")))?,
-            cursor: cursor.map(String::from),
-            limit: None,
+        let params = atrium_api::app::bsky::graph::get_starter_pack::ParametersData {
+            starter_pack: uri.parse().map_err(|e| {
+                ClientError::InvalidResponse(format!("invalid starter pack URI: {e}"))
+            })?,
         };
 
         let output = agent
@@ -3148,25 +5545,46 @@ impl HangarClient {
             .app
             .bsky
             .graph
-            .get_followers(params.into())
+            .get_starter_pack(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        let profiles = output.data.followers.iter().map(Self::profile_from_view).collect();
-
-        Ok((profiles, output.data.cursor))
+        let view = &output.data.starter_pack.data;
+        let record = serde_json::to_value(&view.record).unwrap_or_default();
+        let mut pack = Self::starter_pack_from_record(
+            view.uri.to_string(),
+            &record,
+            Self::author_from_view(&view.creator),
+        );
+        if let Some(list) = &view.list {
+            pack.list_uri = Some(list.data.uri.to_string());
+            pack.item_count = list
+                .data
+                .list_item_count
+                .and_then(|n| u32::try_from(n).ok());
+        }
+        pack.joined_count = view
+            .joined_all_time_count
+            .and_then(|n| u32::try_from(n).ok());
+        pack.feeds = view
+            .feeds
+            .iter()
+            .flatten()
+            .map(Self::feed_generator_from_view)
+            .collect();
+        Ok(pack)
         })
     }
 
-    /// Fetch one page of the accounts `actor` follows
-    pub async fn get_follows(
+    /// Fetch one page of the starter packs `actor` made
+    pub async fn get_actor_starter_packs(
         &self,
         actor: &str,
         cursor: Option<&str>,
-    ) -> Result<(Vec<Profile>, Option<String>), ClientError> {
+    ) -> Result<(Vec<StarterPack>, Option<String>), ClientError> {
         with_agent!(self, agent => {
 
-        let params = atrium_api::app::bsky::graph::get_follows::ParametersData {
+        let params = atrium_api::app::bsky::graph::get_actor_starter_packs::ParametersData {
             actor: actor
                 .parse()
                 .map_err(|e| ClientError::InvalidResponse(format!("invalid actor: {e}")))?,
@@ -3179,16 +5597,71 @@ impl HangarClient {
             .app
             .bsky
             .graph
-            .get_follows(params.into())
+            .get_actor_starter_packs(params.into())
             .await
             .map_err(|e| self.xrpc_error(e))?;
 
-        let profiles = output.data.follows.iter().map(Self::profile_from_view).collect();
+        let packs = output
+            .data
+            .starter_packs
+            .iter()
+            .map(|view| {
+                let record = serde_json::to_value(&view.data.record).unwrap_or_default();
+                let mut pack = Self::starter_pack_from_record(
+                    view.data.uri.to_string(),
+                    &record,
+                    Self::author_from_view(&view.data.creator),
+                );
+                pack.item_count = view
+                    .data
+                    .list_item_count
+                    .and_then(|n| u32::try_from(n).ok());
+                pack.joined_count = view
+                    .data
+                    .joined_all_time_count
+                    .and_then(|n| u32::try_from(n).ok());
+                pack
+            })
+            .collect();
 
-        Ok((profiles, output.data.cursor))
+        Ok((packs, output.data.cursor))
         })
     }
 
+    /// Make a starter pack of `members` and up to
+    /// [`STARTER_PACK_FEED_CAP`] feeds. The people go on a new reference
+    /// list, as the lexicon has it, so the pack does not change when the
+    /// list it was made from does. Returns the pack's AT-URI and its
+    /// list's.
+    pub async fn create_starter_pack(
+        &self,
+        name: &str,
+        description: &str,
+        members: &[String],
+        feed_uris: &[String],
+    ) -> Result<(String, String), ClientError> {
+        let list_uri = self.create_list(name, "", ListPurpose::Reference).await?;
+        let created_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        let items = members
+            .iter()
+            .map(|did| {
+                serde_json::json!({
+                    "$type": "app.bsky.graph.listitem",
+                    "subject": did,
+                    "list": list_uri,
+                    "createdAt": created_at
+                })
+            })
+            .collect();
+        self.create_records("app.bsky.graph.listitem", items)
+            .await?;
+        let record = Self::starter_pack_record(name, description, &list_uri, feed_uris);
+        let uri = self
+            .create_record("app.bsky.graph.starterpack", record)
+            .await?;
+        Ok((uri, list_uri))
+    }
+
     /// The edited fields land on top of the existing record, so anything
     /// this client does not know about (pinned post, labels, whatever the
     /// lexicon grows next) survives the edit untouched.
@@ -3480,6 +5953,146 @@ impl HangarClient {
         })
     }
 
+    /// Mute everyone on a moderation list, for as long as the list has
+    /// them. The mute is the viewer's private state, as an account mute is.
+    pub async fn mute_list(&self, list_uri: &str) -> Result<(), ClientError> {
+        with_agent!(self, agent => {
+
+        let input = atrium_api::app::bsky::graph::mute_actor_list::InputData {
+            list: list_uri
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid list URI: {e}")))?,
+        };
+        agent
+            .api
+            .app
+            .bsky
+            .graph
+            .mute_actor_list(input.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+        Ok(())
+        })
+    }
+
+    /// Stop muting a moderation list
+    pub async fn unmute_list(&self, list_uri: &str) -> Result<(), ClientError> {
+        with_agent!(self, agent => {
+
+        let input = atrium_api::app::bsky::graph::unmute_actor_list::InputData {
+            list: list_uri
+                .parse()
+                .map_err(|e| ClientError::InvalidResponse(format!("invalid list URI: {e}")))?,
+        };
+        agent
+            .api
+            .app
+            .bsky
+            .graph
+            .unmute_actor_list(input.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+        Ok(())
+        })
+    }
+
+    /// Block everyone on a moderation list with one `listblock` record.
+    /// Returns the record's AT-URI, which is what unblocks the list.
+    pub async fn block_list(&self, list_uri: &str) -> Result<String, ClientError> {
+        let record = serde_json::json!({
+            "$type": "app.bsky.graph.listblock",
+            "subject": list_uri,
+            "createdAt": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+        });
+        self.create_record("app.bsky.graph.listblock", record).await
+    }
+
+    /// Stop blocking a list by deleting its `listblock` record
+    pub async fn unblock_list(&self, listblock_uri: &str) -> Result<(), ClientError> {
+        self.delete_record(listblock_uri, "app.bsky.graph.listblock")
+            .await
+    }
+
+    /// One page of the lists the viewer mutes
+    pub async fn get_list_mutes(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<UserList>, Option<String>), ClientError> {
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::graph::get_list_mutes::ParametersData {
+            cursor: cursor.map(String::from),
+            limit: None,
+        };
+        let output = agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_list_mutes(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let lists = output.data.lists.iter().map(Self::list_from_view).collect();
+        Ok((lists, output.data.cursor))
+        })
+    }
+
+    /// One page of the lists the viewer blocks
+    pub async fn get_list_blocks(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<UserList>, Option<String>), ClientError> {
+        with_agent!(self, agent => {
+
+        let params = atrium_api::app::bsky::graph::get_list_blocks::ParametersData {
+            cursor: cursor.map(String::from),
+            limit: None,
+        };
+        let output = agent
+            .api
+            .app
+            .bsky
+            .graph
+            .get_list_blocks(params.into())
+            .await
+            .map_err(|e| self.xrpc_error(e))?;
+
+        let lists = output.data.lists.iter().map(Self::list_from_view).collect();
+        Ok((lists, output.data.cursor))
+        })
+    }
+
+    /// Every list the viewer mutes or blocks, each once, for Settings.
+    /// Paged to the end like [`Self::list_memberships`], with the same cap.
+    pub async fn moderation_lists(&self) -> Result<Vec<UserList>, ClientError> {
+        let mut lists: Vec<UserList> = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..MEMBERSHIP_PAGE_CAP {
+            let (page, next) = self.get_list_blocks(cursor.as_deref()).await?;
+            lists.extend(page);
+            cursor = next;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        cursor = None;
+        for _ in 0..MEMBERSHIP_PAGE_CAP {
+            let (page, next) = self.get_list_mutes(cursor.as_deref()).await?;
+            for list in page {
+                match lists.iter_mut().find(|known| known.uri == list.uri) {
+                    Some(known) => known.viewer_muted = true,
+                    None => lists.push(list),
+                }
+            }
+            cursor = next;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        Ok(lists)
+    }
+
     /// File a moderation report on an account or a post.
     pub async fn create_report(
         &self,
@@ -3845,6 +6458,110 @@ mod tests {
         assert_eq!(HangarClient::pds_did_from_doc(&serde_json::json!({})), None);
     }
 
+    /// Each way the server refuses a sign-in reads as what to do next; the
+    /// code errors in particular must not come out as an expired session,
+    /// which is what the same error names mean anywhere else.
+    #[test]
+    fn refused_sign_ins_say_what_went_wrong() {
+        assert!(matches!(
+            HangarClient::login_failure(Some("AuthFactorTokenRequired"), None),
+            ClientError::AuthFactorTokenRequired
+        ));
+        assert!(matches!(
+            HangarClient::login_failure(Some("ExpiredToken"), Some("Token is expired")),
+            ClientError::AuthFactorTokenExpired
+        ));
+        let ClientError::Auth(wrong) =
+            HangarClient::login_failure(Some("InvalidToken"), Some("Token is invalid"))
+        else {
+            panic!("a wrong code is a failed sign-in");
+        };
+        assert!(wrong.contains("code isn't right"), "{wrong}");
+        let ClientError::Auth(takedown) =
+            HangarClient::login_failure(Some("AccountTakedown"), None)
+        else {
+            panic!("a takedown is a failed sign-in");
+        };
+        assert!(takedown.contains("taken down"), "{takedown}");
+        assert!(HangarClient::inactive_message("deactivated").contains("deactivated"));
+
+        // Anything else passes the server's own words through.
+        let ClientError::Auth(other) = HangarClient::login_failure(
+            Some("AuthenticationRequired"),
+            Some("Invalid identifier or password"),
+        ) else {
+            panic!("a refused password is a failed sign-in");
+        };
+        assert_eq!(other, "Invalid identifier or password");
+    }
+
+    /// A refused sign-up says what to change, and a server's own reason
+    /// comes through when there is no better one.
+    #[test]
+    fn refused_sign_ups_say_what_to_change() {
+        for (code, expected) in [
+            ("HandleNotAvailable", "taken"),
+            ("UnsupportedDomain", "can't be used"),
+            ("InvalidInviteCode", "invite code"),
+            ("InvalidPassword", "password"),
+        ] {
+            let ClientError::Auth(text) = HangarClient::signup_failure(Some(code), None) else {
+                panic!("{code} is a refused sign-up");
+            };
+            assert!(text.contains(expected), "{code}: {text}");
+        }
+        let ClientError::Auth(text) =
+            HangarClient::signup_failure(Some("RateLimitExceeded"), Some("Slow down"))
+        else {
+            panic!("anything else is a refused sign-up too");
+        };
+        assert_eq!(text, "Slow down");
+    }
+
+    /// An app-password sign-in goes to the server the DID document names,
+    /// found where each DID method publishes its documents, and a custom
+    /// server reads the way people type one.
+    #[test]
+    fn sign_in_finds_the_server_the_account_lives_on() {
+        let doc = serde_json::json!({
+            "service": [{ "id": "#atproto_pds", "type": "AtprotoPersonalDataServer",
+                          "serviceEndpoint": "https://pds.example.org/" }]
+        });
+        let endpoint = HangarClient::pds_endpoint_from_doc(&doc).unwrap();
+        assert_eq!(
+            HangarClient::normalize_server(&endpoint).as_deref(),
+            Some("https://pds.example.org")
+        );
+
+        assert_eq!(
+            HangarClient::did_doc_url("did:plc:abc123").as_deref(),
+            Some("https://plc.directory/did:plc:abc123")
+        );
+        assert_eq!(
+            HangarClient::did_doc_url("did:web:localhost%3A3000").as_deref(),
+            Some("https://localhost:3000/.well-known/did.json")
+        );
+        assert_eq!(HangarClient::did_doc_url("did:key:z6Mk"), None);
+        assert_eq!(
+            HangarClient::did_doc_url("did:web:example.com:user:x"),
+            None
+        );
+
+        assert_eq!(
+            HangarClient::normalize_server(" pds.example.org ").as_deref(),
+            Some("https://pds.example.org")
+        );
+        assert_eq!(
+            HangarClient::normalize_server("http://localhost:2583/xrpc").as_deref(),
+            Some("http://localhost:2583")
+        );
+        assert_eq!(
+            HangarClient::normalize_server("ftp://pds.example.org"),
+            None
+        );
+        assert_eq!(HangarClient::normalize_server(""), None);
+    }
+
     /// Upload names carry the extension the service keys formats off,
     /// GIFs included since those ride the video pipeline.
     #[test]
@@ -3868,6 +6585,8 @@ mod tests {
             text: "the quoted text".into(),
             indexed_at: "2026-01-01T00:00:00Z".into(),
             embed: None,
+            labels: Vec::new(),
+            facets: Vec::new(),
         }
     }
 
@@ -4222,4 +6941,276 @@ mod tests {
         assert_eq!(quote.text, "the shared post");
         assert_eq!(quote.author.handle, "author.bsky.social");
     }
+
+    /// Global and per-labeler label choices land in their own maps, and
+    /// the subscription list leaves out Bluesky's implied service.
+    #[test]
+    fn label_preferences_split_global_from_per_labeler() {
+        let preferences: atrium_api::app::bsky::actor::defs::Preferences =
+            serde_json::from_value(serde_json::json!([
+                { "$type": "app.bsky.actor.defs#adultContentPref", "enabled": true },
+                {
+                    "$type": "app.bsky.actor.defs#contentLabelPref",
+                    "label": "graphic-media",
+                    "visibility": "hide"
+                },
+                {
+                    "$type": "app.bsky.actor.defs#contentLabelPref",
+                    "label": "spoiler",
+                    "labelerDid": "did:plc:labeler",
+                    "visibility": "ignore"
+                },
+                {
+                    "$type": "app.bsky.actor.defs#labelersPref",
+                    "labelers": [
+                        { "did": "did:plc:ar7c4by46qjdydhdevvrndac" },
+                        { "did": "did:plc:labeler" }
+                    ]
+                }
+            ]))
+            .expect("valid preferences");
+
+        let prefs = HangarClient::moderation_prefs_from_prefs(&preferences);
+        assert!(prefs.adult_content_enabled);
+        assert_eq!(
+            prefs.label_visibility.get("graphic-media"),
+            Some(&LabelVisibility::Hide)
+        );
+        assert_eq!(
+            prefs
+                .labeler_label_visibility
+                .get(&("did:plc:labeler".to_string(), "spoiler".to_string())),
+            Some(&LabelVisibility::Ignore)
+        );
+        assert!(!prefs.label_visibility.contains_key("spoiler"));
+        assert_eq!(
+            HangarClient::labelers_from_prefs(&preferences),
+            vec!["did:plc:labeler".to_string()]
+        );
+    }
+
+    /// Pinning a list adds its saved-feed item beside the others and
+    /// unpinning takes only that item away; preferences this has nothing
+    /// to do with are written back untouched.
+    #[test]
+    fn pinning_a_list_edits_only_its_saved_feed_item() {
+        use atrium_api::app::bsky::actor::defs::PreferencesItem;
+        use atrium_api::types::Union;
+
+        let list = "at://did:plc:me/app.bsky.graph.list/abc";
+        let mut preferences: atrium_api::app::bsky::actor::defs::Preferences =
+            serde_json::from_value(serde_json::json!([
+                { "$type": "app.bsky.actor.defs#adultContentPref", "enabled": true },
+                {
+                    "$type": "app.bsky.actor.defs#savedFeedsPrefV2",
+                    "items": [
+                        { "id": "a", "type": "timeline", "value": "following", "pinned": true },
+                        {
+                            "id": "b",
+                            "type": "feed",
+                            "value": "at://did:plc:gen/app.bsky.feed.generator/art",
+                            "pinned": true
+                        }
+                    ]
+                }
+            ]))
+            .expect("valid preferences");
+        let items = |preferences: &atrium_api::app::bsky::actor::defs::Preferences| {
+            preferences
+                .iter()
+                .find_map(|pref| match pref {
+                    Union::Refs(PreferencesItem::SavedFeedsPrefV2(saved)) => Some(
+                        saved
+                            .data
+                            .items
+                            .iter()
+                            .map(|item| item.data.r#type.clone())
+                            .collect::<Vec<_>>(),
+                    ),
+                    _ => None,
+                })
+                .unwrap_or_default()
+        };
+
+        assert!(HangarClient::write_list_pin(&mut preferences, list, true));
+        assert_eq!(items(&preferences), ["timeline", "feed", "list"]);
+        assert_eq!(preferences.len(), 2, "the other preference rides along");
+
+        // Pinning twice does not save the list twice.
+        HangarClient::write_list_pin(&mut preferences, list, true);
+        assert_eq!(items(&preferences), ["timeline", "feed", "list"]);
+
+        HangarClient::write_list_pin(&mut preferences, list, false);
+        assert_eq!(items(&preferences), ["timeline", "feed"]);
+
+        // An account that never saved a feed keeps its Following timeline.
+        let mut fresh = Vec::new();
+        assert!(!HangarClient::write_list_pin(&mut fresh, list, false));
+        assert!(HangarClient::write_list_pin(&mut fresh, list, true));
+        assert_eq!(items(&fresh), ["timeline", "list"]);
+    }
+
+    /// Reordering, unpinning and removing saved feeds rewrites only the
+    /// saved-feeds entry, and feeds that stay keep the ids they had.
+    #[test]
+    fn saved_feeds_write_back_in_order_keeping_their_ids() {
+        use atrium_api::app::bsky::actor::defs::PreferencesItem;
+        use atrium_api::types::Union;
+
+        let art = "at://did:plc:gen/app.bsky.feed.generator/art";
+        let mut preferences: atrium_api::app::bsky::actor::defs::Preferences =
+            serde_json::from_value(serde_json::json!([
+                { "$type": "app.bsky.actor.defs#adultContentPref", "enabled": true },
+                {
+                    "$type": "app.bsky.actor.defs#savedFeedsPrefV2",
+                    "items": [
+                        { "id": "a", "type": "timeline", "value": "following", "pinned": true },
+                        { "id": "b", "type": "feed", "value": art, "pinned": true },
+                        {
+                            "id": "c",
+                            "type": "list",
+                            "value": "at://did:plc:me/app.bsky.graph.list/abc",
+                            "pinned": false
+                        }
+                    ]
+                }
+            ]))
+            .expect("valid preferences");
+
+        let mut feeds = HangarClient::saved_feeds_from_prefs(&preferences);
+        assert_eq!(feeds.len(), 3);
+        assert!(feeds[0].is_home());
+        assert!(!feeds[2].pinned);
+
+        // Art moves first and is unpinned, the list goes, a new feed and a
+        // saved search are added.
+        let mut art_feed = feeds.remove(1);
+        art_feed.pinned = false;
+        feeds.pop();
+        feeds.insert(0, art_feed);
+        feeds.push(SavedFeed {
+            feed_type: "feed".to_string(),
+            uri: "at://did:plc:gen/app.bsky.feed.generator/new".to_string(),
+            display_name: "New".to_string(),
+            description: None,
+            pinned: true,
+        });
+        feeds.push(SavedFeed::saved_search("rust"));
+        HangarClient::write_saved_feeds(&mut preferences, &feeds);
+
+        assert_eq!(preferences.len(), 2, "the other preference rides along");
+        let items: Vec<(String, String, bool)> = preferences
+            .iter()
+            .find_map(|pref| match pref {
+                Union::Refs(PreferencesItem::SavedFeedsPrefV2(saved)) => Some(
+                    saved
+                        .data
+                        .items
+                        .iter()
+                        .map(|item| {
+                            (
+                                item.data.id.clone(),
+                                item.data.r#type.clone(),
+                                item.data.pinned,
+                            )
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .unwrap_or_default();
+        assert_eq!(items.len(), 3, "the saved search is not written");
+        assert_eq!(items[0], ("b".to_string(), "feed".to_string(), false));
+        assert_eq!(items[1], ("a".to_string(), "timeline".to_string(), true));
+        assert_eq!(items[2].1, "feed");
+        assert!(!["a", "b", "c"].contains(&items[2].0.as_str()));
+    }
+
+    /// Only entries naming the account count, and each is found by the
+    /// list it puts them on.
+    #[test]
+    fn list_entries_are_matched_by_subject() {
+        let item = |rkey: &str, subject: &str, list: &str| {
+            (
+                format!("at://did:plc:me/app.bsky.graph.listitem/{rkey}"),
+                serde_json::json!({
+                    "$type": "app.bsky.graph.listitem",
+                    "subject": subject,
+                    "list": format!("at://did:plc:me/app.bsky.graph.list/{list}"),
+                }),
+            )
+        };
+        let items = vec![
+            item("1", "did:plc:alice", "friends"),
+            item("2", "did:plc:bob", "friends"),
+            item("3", "did:plc:alice", "artists"),
+            (
+                "at://broken".to_string(),
+                serde_json::json!({ "subject": "did:plc:alice" }),
+            ),
+        ];
+
+        let entries = HangarClient::list_entries_for(&items, "did:plc:alice");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries
+                .get("at://did:plc:me/app.bsky.graph.list/artists")
+                .map(String::as_str),
+            Some("at://did:plc:me/app.bsky.graph.listitem/3")
+        );
+    }
+
+    /// An edit changes the name and description and nothing else; an
+    /// emptied description takes its facets with it.
+    #[test]
+    fn a_list_edit_keeps_the_rest_of_the_record() {
+        let record = serde_json::json!({
+            "$type": "app.bsky.graph.list",
+            "purpose": "app.bsky.graph.defs#curatelist",
+            "name": "Old",
+            "description": "with a @mention",
+            "descriptionFacets": [{ "index": { "byteStart": 7, "byteEnd": 15 } }],
+            "createdAt": "2026-01-01T00:00:00.000Z"
+        });
+
+        let merged = HangarClient::merge_list_record(record, "New", "");
+        assert_eq!(merged["name"], "New");
+        assert_eq!(merged["purpose"], "app.bsky.graph.defs#curatelist");
+        assert_eq!(merged["createdAt"], "2026-01-01T00:00:00.000Z");
+        assert!(merged.get("description").is_none());
+        assert!(merged.get("descriptionFacets").is_none());
+    }
+
+    /// A starter pack record names its list and at most three feeds, and
+    /// reads back into the pack it was written from.
+    #[test]
+    fn a_starter_pack_record_caps_its_feeds_and_reads_back() {
+        let feeds: Vec<String> = (0..5)
+            .map(|n| format!("at://did:plc:f/app.bsky.feed.generator/{n}"))
+            .collect();
+        let record = HangarClient::starter_pack_record(
+            "Rustaceans",
+            "",
+            "at://did:plc:me/app.bsky.graph.list/ref",
+            &feeds,
+        );
+        assert_eq!(record["$type"], "app.bsky.graph.starterpack");
+        assert_eq!(record["feeds"].as_array().map(Vec::len), Some(3));
+        assert_eq!(record["feeds"][0]["uri"], feeds[0].as_str());
+        assert!(record.get("description").is_none());
+
+        let creator = Profile::minimal("did:plc:me".into(), "me.test".into(), None, None);
+        let pack = HangarClient::starter_pack_from_record(
+            "at://did:plc:me/app.bsky.graph.starterpack/p".into(),
+            &record,
+            creator,
+        );
+        assert_eq!(pack.name, "Rustaceans");
+        assert_eq!(pack.description, None);
+        assert_eq!(
+            pack.list_uri.as_deref(),
+            Some("at://did:plc:me/app.bsky.graph.list/ref")
+        );
+        assert_eq!(pack.summary(), "by @me.test");
+    }
 }
@@ -4,8 +4,10 @@
 //!
 //! Detects mentions (@handle), links (URLs), and hashtags (#tag) in post text,
 //! computes UTF-8 byte offsets, and builds the JSON facets array for inclusion
-//! in post records.
+//! in post records. Reading goes the other way: [`from_record`] takes the
+//! facets a post was written with, for display.
 
+use crate::atproto::types::{Facet, FacetFeature};
 use regex::Regex;
 use std::collections::HashMap;
 use std::sync::LazyLock;
@@ -192,6 +194,50 @@ pub fn build_facets_json(
     serde_json::Value::Array(facets)
 }
 
+/// The `facets` of a post record, as JSON, in text order.
+///
+/// Features Hangar does not know are skipped, and so are spans that are
+/// empty or run backwards. Whether a span fits the text is the renderer's
+/// call; the record may have been written against different text.
+pub fn from_record(facets: &serde_json::Value) -> Vec<Facet> {
+    let Some(items) = facets.as_array() else {
+        return Vec::new();
+    };
+    let mut out: Vec<Facet> = items
+        .iter()
+        .filter_map(|item| {
+            let index = item.get("index")?;
+            let byte_start = usize::try_from(index.get("byteStart")?.as_u64()?).ok()?;
+            let byte_end = usize::try_from(index.get("byteEnd")?.as_u64()?).ok()?;
+            if byte_start >= byte_end {
+                return None;
+            }
+            let feature = item
+                .get("features")?
+                .as_array()?
+                .iter()
+                .find_map(feature_from_record)?;
+            Some(Facet {
+                byte_start,
+                byte_end,
+                feature,
+            })
+        })
+        .collect();
+    out.sort_by_key(|f| f.byte_start);
+    out
+}
+
+fn feature_from_record(feature: &serde_json::Value) -> Option<FacetFeature> {
+    let field = |name: &str| feature.get(name)?.as_str().map(str::to_string);
+    match feature.get("$type")?.as_str()? {
+        "app.bsky.richtext.facet#link" => field("uri").map(FacetFeature::Link),
+        "app.bsky.richtext.facet#mention" => field("did").map(FacetFeature::Mention),
+        "app.bsky.richtext.facet#tag" => field("tag").map(FacetFeature::Tag),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -340,6 +386,48 @@ mod tests {
         assert!(arr.is_empty());
     }
 
+    /// What a record says wins over what the text looks like: the link
+    /// goes to its full URI and the mention to a DID. A feature from a
+    /// lexicon we do not know drops only that facet.
+    #[test]
+    fn record_facets_read_back_in_text_order() {
+        let json = serde_json::json!([
+            {
+                "index": { "byteStart": 20, "byteEnd": 26 },
+                "features": [{ "$type": "app.bsky.richtext.facet#tag", "tag": "café" }]
+            },
+            {
+                "index": { "byteStart": 0, "byteEnd": 14 },
+                "features": [{
+                    "$type": "app.bsky.richtext.facet#link",
+                    "uri": "https://example.com/very-long-path"
+                }]
+            },
+            {
+                "index": { "byteStart": 15, "byteEnd": 19 },
+                "features": [{ "$type": "app.bsky.richtext.facet#mention", "did": "did:plc:x" }]
+            },
+            {
+                "index": { "byteStart": 27, "byteEnd": 30 },
+                "features": [{ "$type": "com.example.facet#sparkle" }]
+            },
+            {
+                "index": { "byteStart": 9, "byteEnd": 9 },
+                "features": [{ "$type": "app.bsky.richtext.facet#tag", "tag": "empty" }]
+            }
+        ]);
+        let facets = from_record(&json);
+        assert_eq!(
+            facets.iter().map(|f| f.feature.clone()).collect::<Vec<_>>(),
+            vec![
+                FacetFeature::Link("https://example.com/very-long-path".into()),
+                FacetFeature::Mention("did:plc:x".into()),
+                FacetFeature::Tag("café".into()),
+            ]
+        );
+        assert!(from_record(&serde_json::Value::Null).is_empty());
+    }
+
     #[test]
     fn test_unicode_byte_offsets() {
         // Emoji are multi-byte in UTF-8: each basic emoji is 4 bytes
@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Feedback for custom feeds that ask for it.
+//!
+//! A feed generator that sets `acceptsInteractions` takes
+//! `app.bsky.feed.sendInteractions`: which of its posts the reader saw,
+//! which they opened, and which they asked for more or less of. Each item
+//! goes back with the `feedContext` the generator attached to it, so the
+//! generator can tell which of its rankings produced it.
+//!
+//! Interactions are queued and sent in batches, as bsky.app does. A post
+//! counts as seen once per feed however often it scrolls back into view;
+//! opening it or asking for more or less is recorded every time.
+
+use crate::atproto::types::Post;
+use std::collections::HashSet;
+
+/// How many interactions may wait before the queue asks to be sent early.
+const BATCH_SIZE: usize = 50;
+
+/// What the reader did with a feed item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteractionEvent {
+    /// It was shown on screen.
+    Seen,
+    /// The reader opened it.
+    Clickthrough,
+    /// "Show More Like This"
+    RequestMore,
+    /// "Show Less Like This"
+    RequestLess,
+}
+
+impl InteractionEvent {
+    /// The lexicon token for the event.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Seen => "app.bsky.feed.defs#interactionSeen",
+            Self::Clickthrough => "app.bsky.feed.defs#clickthroughItem",
+            Self::RequestMore => "app.bsky.feed.defs#requestMore",
+            Self::RequestLess => "app.bsky.feed.defs#requestLess",
+        }
+    }
+
+    /// Whether the reader asked for this outright, and should hear that
+    /// the feed was told.
+    pub fn is_explicit(self) -> bool {
+        matches!(self, Self::RequestMore | Self::RequestLess)
+    }
+}
+
+/// One interaction, as `sendInteractions` takes it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedInteraction {
+    /// The post's URI
+    pub item: String,
+    pub event: InteractionEvent,
+    /// Handed back as the generator gave it
+    pub feed_context: Option<String>,
+}
+
+/// Interactions waiting to go to one feed generator.
+#[derive(Debug, Default)]
+pub struct InteractionQueue {
+    pending: Vec<FeedInteraction>,
+    /// Posts already reported seen to this feed
+    seen: HashSet<String>,
+}
+
+impl InteractionQueue {
+    /// Queue what the reader did with `post`. A repeat sighting is
+    /// dropped; returns whether anything was queued.
+    pub fn record(&mut self, post: &Post, event: InteractionEvent) -> bool {
+        if event == InteractionEvent::Seen && !self.seen.insert(post.uri.clone()) {
+            return false;
+        }
+        self.pending.push(FeedInteraction {
+            item: post.uri.clone(),
+            event,
+            feed_context: post.feed_context.clone(),
+        });
+        true
+    }
+
+    /// Whether enough has piled up to send without waiting for the timer.
+    pub fn is_full(&self) -> bool {
+        self.pending.len() >= BATCH_SIZE
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Everything queued, oldest first, leaving the queue empty. What was
+    /// seen stays remembered.
+    pub fn take(&mut self) -> Vec<FeedInteraction> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atproto::Profile;
+
+    fn post(uri: &str, context: Option<&str>) -> Post {
+        Post {
+            uri: uri.into(),
+            cid: "cid".into(),
+            author: Profile::minimal("did:plc:a".into(), "a.bsky.social".into(), None, None),
+            text: String::new(),
+            created_at: String::new(),
+            indexed_at: String::new(),
+            like_count: None,
+            repost_count: None,
+            reply_count: None,
+            embed: None,
+            viewer_like: None,
+            viewer_repost: None,
+            viewer_bookmarked: None,
+            repost_reason: None,
+            reply_context: None,
+            labels: Vec::new(),
+            feed_context: context.map(str::to_string),
+            facets: Vec::new(),
+        }
+    }
+
+    /// A post scrolled past twice is one sighting; the generator would
+    /// otherwise count every recycle of the row as a fresh impression.
+    /// Opening it still counts each time.
+    #[test]
+    fn a_post_is_seen_once_but_opened_every_time() {
+        let mut queue = InteractionQueue::default();
+        let a = post("at://a/app.bsky.feed.post/1", Some("ctx-1"));
+        assert!(queue.record(&a, InteractionEvent::Seen));
+        assert!(!queue.record(&a, InteractionEvent::Seen));
+        assert!(queue.record(&a, InteractionEvent::Clickthrough));
+        assert!(queue.record(&a, InteractionEvent::Clickthrough));
+
+        let sent = queue.take();
+        assert_eq!(sent.len(), 3);
+        assert_eq!(sent[0].feed_context.as_deref(), Some("ctx-1"));
+        assert!(queue.is_empty());
+
+        // Sent is not forgotten: the next batch does not repeat it.
+        assert!(!queue.record(&a, InteractionEvent::Seen));
+        assert!(queue.is_empty());
+    }
+
+    /// The queue asks to be sent once a batch fills, so a long scroll does
+    /// not wait on the timer with hundreds of sightings.
+    #[test]
+    fn a_full_batch_asks_to_be_sent() {
+        let mut queue = InteractionQueue::default();
+        for n in 0..BATCH_SIZE {
+            assert!(!queue.is_full());
+            queue.record(
+                &post(&format!("at://a/app.bsky.feed.post/{n}"), None),
+                InteractionEvent::Seen,
+            );
+        }
+        assert!(queue.is_full());
+        queue.take();
+        assert!(!queue.is_full());
+    }
+
+    /// The events read as the lexicon spells them; a typo here is a
+    /// request the generator silently ignores.
+    #[test]
+    fn events_use_the_lexicon_tokens() {
+        assert_eq!(
+            InteractionEvent::RequestLess.as_str(),
+            "app.bsky.feed.defs#requestLess"
+        );
+        assert_eq!(
+            InteractionEvent::Clickthrough.as_str(),
+            "app.bsky.feed.defs#clickthroughItem"
+        );
+        assert!(InteractionEvent::RequestMore.is_explicit());
+        assert!(!InteractionEvent::Seen.is_explicit());
+    }
+}
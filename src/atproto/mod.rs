@@ -3,16 +3,23 @@
 pub mod client;
 mod facets;
 pub mod gif;
+pub mod interactions;
+pub mod moderation;
+pub mod muted_words;
+pub mod notifications;
+pub mod search_query;
 mod types;
 
 pub use client::{HangarClient, ReplyRef};
 pub use gif::GifEmbed;
 pub use types::{
-    ChatMessage, ComposeData, Conversation, Embed, ExternalEmbed, ImageAttachment, ImageEmbed,
-    LinkCardData, Notification, Post, PostgateConfig, Profile, QuoteEmbed, ReplyContext,
-    RepostReason, SavedFeed, Session, ThreadgateConfig, ThreadgateRule, VideoAttachment,
-    VideoEmbed,
+    ChatMessage, ComposeData, Conversation, Embed, ExternalEmbed, Facet, FacetFeature,
+    FeedGenerator, ImageAttachment, ImageEmbed, Label, LabelDefinition, LabelStrings,
+    LabelVisibility, LabelerService, LinkCardData, ListMember, ListPurpose, ModerationPrefs,
+    MutedWord, MutedWordTarget, NewAccount, Notification, Post, PostgateConfig, Profile,
+    QuoteEmbed, ReplyContext, RepostReason, SavedFeed, ServerDescription, Session, StarterPack,
+    ThreadgateConfig, ThreadgateRule, UserList, VideoAttachment, VideoEmbed,
 };
-// Only test fixtures build reactions by hand so far.
+// Only test fixtures build reactions or list references by hand so far.
 #[cfg(test)]
-pub use types::ChatReaction;
+pub use types::{ChatReaction, ListRef};
@@ -0,0 +1,444 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Label moderation: what the labels on a post or account mean for showing
+//! it.
+//!
+//! The rules follow bsky.app's for the global labels, the ones the Bluesky
+//! moderation service and authors themselves apply. Adult labels hide with
+//! no way through until the account turns adult content on; past that, each
+//! label's visibility is the account's `contentLabelPref`, or the label's
+//! default where there is none.
+//!
+//! Labels a subscribed labeler defines for itself follow its published
+//! definition instead, with the account's per-labeler preference on top.
+
+use crate::atproto::types::{
+    Label, LabelDefinition, LabelVisibility, ModerationPrefs, Post, Profile, QuoteEmbed,
+};
+
+/// Bluesky's own moderation service. Every account subscribes to it and
+/// cannot leave.
+pub const BLUESKY_MODERATION_DID: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+
+/// What a label covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Blur {
+    /// Images, video and link cards; the text stays readable.
+    Media,
+    /// The whole post.
+    Content,
+}
+
+/// What to do with one post, quote or profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decision {
+    /// `Warn` or `Hide`; content to be shown as is gets no decision at all.
+    pub visibility: LabelVisibility,
+    pub blur: Blur,
+    /// The label as a person would name it, for the cover.
+    pub name: String,
+    /// False for moderator hides and for adult content while it is turned
+    /// off: the cover has no Show button.
+    pub can_reveal: bool,
+}
+
+impl Decision {
+    /// Orders decisions so the strictest wins when several labels apply.
+    fn severity(&self) -> (bool, Blur, bool) {
+        (
+            self.visibility == LabelVisibility::Hide,
+            self.blur,
+            !self.can_reveal,
+        )
+    }
+}
+
+struct KnownLabel {
+    value: &'static str,
+    name: &'static str,
+    blur: Blur,
+    default: LabelVisibility,
+    /// Covered by `adultContentPref`.
+    adult: bool,
+    /// Whether `contentLabelPref` may change it. The `!` labels are for
+    /// moderators only and ignored when an author applies them.
+    configurable: bool,
+}
+
+const GLOBAL_LABELS: &[KnownLabel] = &[
+    KnownLabel {
+        value: "!hide",
+        name: "Hidden by moderators",
+        blur: Blur::Content,
+        default: LabelVisibility::Hide,
+        adult: false,
+        configurable: false,
+    },
+    KnownLabel {
+        value: "!warn",
+        name: "Content warning",
+        blur: Blur::Content,
+        default: LabelVisibility::Warn,
+        adult: false,
+        configurable: false,
+    },
+    KnownLabel {
+        value: "porn",
+        name: "Adult content",
+        blur: Blur::Media,
+        default: LabelVisibility::Hide,
+        adult: true,
+        configurable: true,
+    },
+    KnownLabel {
+        value: "sexual",
+        name: "Sexually suggestive",
+        blur: Blur::Media,
+        default: LabelVisibility::Warn,
+        adult: true,
+        configurable: true,
+    },
+    KnownLabel {
+        value: "nudity",
+        name: "Non-sexual nudity",
+        blur: Blur::Media,
+        default: LabelVisibility::Ignore,
+        adult: true,
+        configurable: true,
+    },
+    KnownLabel {
+        value: "graphic-media",
+        name: "Graphic media",
+        blur: Blur::Media,
+        default: LabelVisibility::Warn,
+        adult: true,
+        configurable: true,
+    },
+];
+
+/// The global labels an account may choose a visibility for, as
+/// (value, name, default), in the order Settings lists them. All are adult
+/// labels.
+pub fn configurable_global_labels()
+-> impl Iterator<Item = (&'static str, &'static str, LabelVisibility)> {
+    GLOBAL_LABELS
+        .iter()
+        .filter(|label| label.configurable)
+        .map(|label| (label.value, label.name, label.default))
+}
+
+/// A change to the account's moderation settings, as Settings asks for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModerationEdit {
+    AdultContent(bool),
+    /// `labeler` is unset for a global label.
+    LabelVisibility {
+        labeler: Option<String>,
+        label: String,
+        visibility: LabelVisibility,
+    },
+    /// A labeler by handle or DID.
+    Subscribe(String),
+    /// A labeler by DID.
+    Unsubscribe(String),
+}
+
+fn known(value: &str) -> Option<&'static KnownLabel> {
+    // "gore" is the old name for graphic media and still turns up on
+    // older content.
+    let value = if value == "gore" {
+        "graphic-media"
+    } else {
+        value
+    };
+    GLOBAL_LABELS.iter().find(|label| label.value == value)
+}
+
+/// Labels still in force: negations withdraw the earlier label with the
+/// same source and value, and are not labels themselves.
+fn live(labels: &[Label]) -> impl Iterator<Item = &Label> {
+    labels.iter().filter(|label| {
+        !label.neg
+            && !labels
+                .iter()
+                .any(|other| other.neg && other.src == label.src && other.val == label.val)
+    })
+}
+
+/// The definition `label` points at, if its labeler is subscribed to and
+/// publishes one by that name.
+fn custom<'a>(label: &Label, prefs: &'a ModerationPrefs) -> Option<&'a LabelDefinition> {
+    prefs
+        .labelers
+        .iter()
+        .find(|labeler| labeler.did == label.src)?
+        .definitions
+        .iter()
+        .find(|def| def.identifier == label.val)
+}
+
+/// A custom label's decision. One that blurs nothing still hides when the
+/// account says so; a warning about it has nothing to cover.
+fn decide_custom(
+    label: &Label,
+    def: &LabelDefinition,
+    prefs: &ModerationPrefs,
+) -> Option<Decision> {
+    let (visibility, can_reveal) = if def.adult_only && !prefs.adult_content_enabled {
+        (LabelVisibility::Hide, false)
+    } else {
+        let chosen = prefs
+            .labeler_label_visibility
+            .get(&(label.src.clone(), label.val.clone()))
+            .copied()
+            .unwrap_or(def.default_setting);
+        (chosen, true)
+    };
+    let blur = match def.blurs.as_str() {
+        "content" => Blur::Content,
+        "media" => Blur::Media,
+        _ if visibility == LabelVisibility::Hide => Blur::Content,
+        _ => return None,
+    };
+    let languages: Vec<&str> = prefs.languages.iter().map(String::as_str).collect();
+    (visibility != LabelVisibility::Ignore).then(|| Decision {
+        visibility,
+        blur,
+        name: def.name(&languages),
+        can_reveal,
+    })
+}
+
+/// What `labels`, on something `author_did` made, call for. `None` means
+/// show it as is.
+pub fn decide(labels: &[Label], author_did: &str, prefs: &ModerationPrefs) -> Option<Decision> {
+    live(labels)
+        .filter_map(|label| {
+            if let Some(def) = custom(label, prefs) {
+                return decide_custom(label, def, prefs);
+            }
+            let known = known(&label.val)?;
+            if !known.configurable && label.src == author_did {
+                return None;
+            }
+            let (visibility, can_reveal) = if known.adult && !prefs.adult_content_enabled {
+                (LabelVisibility::Hide, false)
+            } else if known.configurable {
+                let chosen = prefs
+                    .label_visibility
+                    .get(known.value)
+                    .copied()
+                    .unwrap_or(known.default);
+                (chosen, true)
+            } else {
+                (known.default, known.default != LabelVisibility::Hide)
+            };
+            (visibility != LabelVisibility::Ignore).then(|| Decision {
+                visibility,
+                blur: known.blur,
+                name: known.name.to_string(),
+                can_reveal,
+            })
+        })
+        .max_by_key(Decision::severity)
+}
+
+/// The post's own labels, plus account labels that cover everything the
+/// account posts. An account label on the avatar or banner alone leaves
+/// the post be.
+pub fn decide_post(post: &Post, prefs: &ModerationPrefs) -> Option<Decision> {
+    decide_content(&post.labels, &post.author, prefs)
+}
+
+/// The same for a quoted post, judged on its own labels and author.
+pub fn decide_quote(quote: &QuoteEmbed, prefs: &ModerationPrefs) -> Option<Decision> {
+    decide_content(&quote.labels, &quote.author, prefs)
+}
+
+/// An account's own labels, for its profile page.
+pub fn decide_profile(profile: &Profile, prefs: &ModerationPrefs) -> Option<Decision> {
+    decide(&profile.labels, &profile.did, prefs)
+}
+
+/// Whether feeds should drop the post rather than cover it.
+pub fn hidden_from_feeds(post: &Post, prefs: &ModerationPrefs) -> bool {
+    decide_post(post, prefs).is_some_and(|d| d.visibility == LabelVisibility::Hide)
+}
+
+fn decide_content(labels: &[Label], author: &Profile, prefs: &ModerationPrefs) -> Option<Decision> {
+    let own = decide(labels, &author.did, prefs);
+    let account = decide(&author.labels, &author.did, prefs).filter(|d| d.blur == Blur::Content);
+    [own, account]
+        .into_iter()
+        .flatten()
+        .max_by_key(Decision::severity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AUTHOR: &str = "did:plc:author";
+    const MODERATION: &str = BLUESKY_MODERATION_DID;
+
+    fn label(src: &str, val: &str) -> Label {
+        Label {
+            src: src.to_string(),
+            val: val.to_string(),
+            neg: false,
+        }
+    }
+
+    fn adult_on() -> ModerationPrefs {
+        ModerationPrefs {
+            adult_content_enabled: true,
+            ..ModerationPrefs::default()
+        }
+    }
+
+    /// Adult labels are a hard hide until the account opts in; after that
+    /// each one follows its default.
+    #[test]
+    fn adult_labels_hide_until_adult_content_is_on() {
+        let labels = [label(AUTHOR, "sexual")];
+
+        let off = decide(&labels, AUTHOR, &ModerationPrefs::default()).expect("covered");
+        assert_eq!(off.visibility, LabelVisibility::Hide);
+        assert!(!off.can_reveal);
+
+        let on = decide(&labels, AUTHOR, &adult_on()).expect("covered");
+        assert_eq!(on.visibility, LabelVisibility::Warn);
+        assert_eq!(on.blur, Blur::Media);
+        assert!(on.can_reveal);
+
+        // Nudity defaults to showing once adult content is on.
+        assert_eq!(
+            decide(&[label(AUTHOR, "nudity")], AUTHOR, &adult_on()),
+            None
+        );
+    }
+
+    /// The account's own choice beats the label's default, both ways.
+    #[test]
+    fn content_label_prefs_override_the_defaults() {
+        let mut prefs = adult_on();
+        prefs
+            .label_visibility
+            .insert("graphic-media".into(), LabelVisibility::Ignore);
+        prefs
+            .label_visibility
+            .insert("nudity".into(), LabelVisibility::Hide);
+
+        assert_eq!(decide(&[label(AUTHOR, "gore")], AUTHOR, &prefs), None);
+        let nudity = decide(&[label(AUTHOR, "nudity")], AUTHOR, &prefs).expect("covered");
+        assert_eq!(nudity.visibility, LabelVisibility::Hide);
+        assert!(nudity.can_reveal);
+    }
+
+    /// Moderator labels cover the whole post and `!hide` has no way
+    /// through; an author cannot apply either to their own post.
+    #[test]
+    fn moderator_labels_cover_everything_and_are_not_self_applied() {
+        let hide = decide(&[label(MODERATION, "!hide")], AUTHOR, &adult_on()).expect("hidden");
+        assert_eq!(hide.blur, Blur::Content);
+        assert!(!hide.can_reveal);
+
+        let warn = decide(&[label(MODERATION, "!warn")], AUTHOR, &adult_on()).expect("warned");
+        assert_eq!(warn.visibility, LabelVisibility::Warn);
+        assert!(warn.can_reveal);
+
+        assert_eq!(decide(&[label(AUTHOR, "!hide")], AUTHOR, &adult_on()), None);
+    }
+
+    /// A negation withdraws the label it names and nothing else; unknown
+    /// labels are left alone.
+    #[test]
+    fn negated_and_unknown_labels_do_nothing() {
+        let labels = [
+            label(MODERATION, "!warn"),
+            Label {
+                neg: true,
+                ..label(MODERATION, "!warn")
+            },
+            label(MODERATION, "spam-ish"),
+        ];
+        assert_eq!(decide(&labels, AUTHOR, &adult_on()), None);
+    }
+
+    /// With several labels the strictest wins, and only an account's
+    /// whole-content labels carry over to its posts.
+    #[test]
+    fn the_strictest_label_wins_and_account_media_labels_stay_on_the_profile() {
+        let labels = [label(AUTHOR, "graphic-media"), label(MODERATION, "!warn")];
+        let decision = decide(&labels, AUTHOR, &adult_on()).expect("covered");
+        assert_eq!(decision.blur, Blur::Content);
+
+        let mut author = Profile::minimal(AUTHOR.into(), "author.test".into(), None, None);
+        author.labels = vec![label(MODERATION, "porn")];
+        let quote = QuoteEmbed {
+            uri: "at://did:plc:author/app.bsky.feed.post/1".into(),
+            cid: "bafy".into(),
+            author: author.clone(),
+            text: "hello".into(),
+            indexed_at: "2026-01-01T00:00:00Z".into(),
+            embed: None,
+            labels: Vec::new(),
+            facets: Vec::new(),
+        };
+        assert_eq!(decide_quote(&quote, &adult_on()), None);
+        assert!(decide_profile(&author, &adult_on()).is_some());
+
+        author.labels.push(label(MODERATION, "!hide"));
+        let quote = QuoteEmbed { author, ..quote };
+        let hidden = decide_quote(&quote, &adult_on()).expect("hidden");
+        assert_eq!(hidden.visibility, LabelVisibility::Hide);
+    }
+
+    /// A subscribed labeler's own labels follow its definition and the
+    /// account's choice for that labeler; the same value from anyone else
+    /// means nothing.
+    #[test]
+    fn custom_labels_follow_their_labelers_definition() {
+        use crate::atproto::types::{LabelStrings, LabelerService};
+
+        const LABELER: &str = "did:plc:labeler";
+        let mut prefs = adult_on();
+        prefs.languages = vec!["de_DE.UTF-8".into(), "en".into()];
+        prefs.labelers = vec![LabelerService {
+            did: LABELER.into(),
+            creator: Profile::minimal(LABELER.into(), "labeler.test".into(), None, None),
+            definitions: vec![LabelDefinition {
+                identifier: "spoiler".into(),
+                severity: "alert".into(),
+                blurs: "content".into(),
+                default_setting: LabelVisibility::Warn,
+                adult_only: false,
+                locales: vec![
+                    LabelStrings {
+                        lang: "en".into(),
+                        name: "Spoiler".into(),
+                        description: String::new(),
+                    },
+                    LabelStrings {
+                        lang: "de".into(),
+                        name: "Spoiler-Warnung".into(),
+                        description: String::new(),
+                    },
+                ],
+            }],
+        }];
+
+        let warned = decide(&[label(LABELER, "spoiler")], AUTHOR, &prefs).expect("covered");
+        assert_eq!(warned.blur, Blur::Content);
+        assert_eq!(warned.name, "Spoiler-Warnung");
+        assert_eq!(
+            decide(&[label("did:plc:other", "spoiler")], AUTHOR, &prefs),
+            None
+        );
+
+        prefs
+            .labeler_label_visibility
+            .insert((LABELER.into(), "spoiler".into()), LabelVisibility::Ignore);
+        assert_eq!(decide(&[label(LABELER, "spoiler")], AUTHOR, &prefs), None);
+    }
+}
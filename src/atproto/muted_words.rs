@@ -0,0 +1,467 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Muted words: deciding whether a post trips one, and editing the list.
+//!
+//! The list itself lives in the account's preferences and is shared with
+//! every other client, so the rules here follow bsky.app's: a content word
+//! matches whole words (or a phrase anywhere), a tag word matches hashtags,
+//! and a quoted post's text counts against the post quoting it.
+
+use crate::atproto::facets::{self, RawFacet};
+use crate::atproto::types::{
+    Embed, Facet, FacetFeature, MutedWord, MutedWordTarget, Post, Profile, QuoteEmbed,
+};
+use chrono::{DateTime, Utc};
+
+impl MutedWord {
+    /// A word that has run out no longer mutes anything. An expiry that
+    /// does not parse is treated as never expiring; a muted word quietly
+    /// coming back is worse than one staying.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at
+            .as_deref()
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            .is_some_and(|at| at.with_timezone(&Utc) <= now)
+    }
+
+    /// Whether two entries are the same one. Ids win; entries from before
+    /// clients assigned ids fall back to the value.
+    pub fn same_entry(&self, other: &MutedWord) -> bool {
+        match (&self.id, &other.id) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.value.eq_ignore_ascii_case(&other.value),
+        }
+    }
+
+    fn applies_to(&self, author: &Profile, now: DateTime<Utc>) -> bool {
+        !self.is_expired(now) && (!self.exclude_following || author.viewer_following.is_none())
+    }
+}
+
+/// Whether any live word in `words` matches the post, its media text, or
+/// the post it quotes.
+pub fn post_is_muted(words: &[MutedWord], post: &Post, now: DateTime<Utc>) -> bool {
+    if words.is_empty() {
+        return false;
+    }
+    let mut media = Vec::new();
+    if let Some(embed) = &post.embed {
+        embed_texts(embed, &mut media);
+    }
+    let tags = tags_of(&post.text, &post.facets, &media);
+    let texts: Vec<&str> = std::iter::once(post.text.as_str()).chain(media).collect();
+    if matches_any(words, &post.author, &tags, &texts, now) {
+        return true;
+    }
+    quote_of(post).is_some_and(|quote| quote_is_muted(words, quote, now))
+}
+
+/// The quote on its own, judged against its own author.
+pub fn quote_is_muted(words: &[MutedWord], quote: &QuoteEmbed, now: DateTime<Utc>) -> bool {
+    let mut media = Vec::new();
+    if let Some(embed) = &quote.embed {
+        embed_texts(embed, &mut media);
+    }
+    let tags = tags_of(&quote.text, &quote.facets, &media);
+    let texts: Vec<&str> = std::iter::once(quote.text.as_str()).chain(media).collect();
+    matches_any(words, &quote.author, &tags, &texts, now)
+}
+
+fn quote_of(post: &Post) -> Option<&QuoteEmbed> {
+    match post.embed.as_ref()? {
+        Embed::Quote(quote) | Embed::QuoteWithMedia { quote, .. } => Some(quote),
+        _ => None,
+    }
+}
+
+/// Alt text and link cards, but not the quote: that has its own author,
+/// and "exclude people I follow" has to be asked of them.
+fn embed_texts<'a>(embed: &'a Embed, out: &mut Vec<&'a str>) {
+    match embed {
+        Embed::Images(images) => out.extend(images.iter().map(|i| i.alt.as_str())),
+        Embed::External(external) => {
+            out.push(external.title.as_str());
+            out.push(external.description.as_str());
+        }
+        Embed::Video(video) => out.extend(video.alt.as_deref()),
+        Embed::Quote(_) => {}
+        Embed::QuoteWithMedia { media, .. } => embed_texts(media, out),
+    }
+}
+
+fn matches_any(
+    words: &[MutedWord],
+    author: &Profile,
+    tags: &[String],
+    texts: &[&str],
+    now: DateTime<Utc>,
+) -> bool {
+    let live: Vec<&MutedWord> = words.iter().filter(|w| w.applies_to(author, now)).collect();
+    if live.is_empty() {
+        return false;
+    }
+    let lowered: Vec<String> = texts.iter().map(|t| t.to_lowercase()).collect();
+
+    live.iter().any(|word| {
+        let value = normalized(&word.value);
+        if value.is_empty() {
+            return false;
+        }
+        if tags.iter().any(|tag| *tag == value) {
+            return true;
+        }
+        word.targets.contains(&MutedWordTarget::Content)
+            && lowered.iter().any(|text| content_matches(text, &value))
+    })
+}
+
+/// A word's value as it is compared: no `#`, no case.
+fn normalized(value: &str) -> String {
+    value.trim().trim_start_matches('#').to_lowercase()
+}
+
+/// A post's hashtags, lowercased and without the `#`. The record's tag
+/// facets are what its author's client made tags, so they win; the text
+/// is searched only for a record written with no facets at all. Alt text
+/// and link cards never carry facets and are always searched.
+fn tags_of(text: &str, facets: &[Facet], media: &[&str]) -> Vec<String> {
+    let mut tags: Vec<String> = if facets.is_empty() {
+        hashtags(text)
+    } else {
+        facets
+            .iter()
+            .filter_map(|facet| match &facet.feature {
+                FacetFeature::Tag(tag) => Some(tag.to_lowercase()),
+                _ => None,
+            })
+            .collect()
+    };
+    tags.extend(media.iter().flat_map(|t| hashtags(t)));
+    tags
+}
+
+/// Hashtags in `text`, lowercased and without the `#`.
+fn hashtags(text: &str) -> Vec<String> {
+    facets::parse_facets(text)
+        .into_iter()
+        .filter_map(|facet| match facet {
+            RawFacet::Tag { tag, .. } => Some(tag.to_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A phrase, or anything with punctuation in it, matches as a substring.
+/// A single word matches whole words only, so muting "cat" leaves
+/// "concatenate" alone but still catches "cat," and "cat's".
+fn content_matches(text: &str, value: &str) -> bool {
+    if value.chars().any(|c| !c.is_alphanumeric()) {
+        return text.contains(value);
+    }
+    text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .flat_map(|word| {
+            let whole = word.trim_matches('\'');
+            // "cat's" counts as "cat"; the possessive is not a new word.
+            let stem = whole.split('\'').next().unwrap_or(whole);
+            [whole, stem]
+        })
+        .any(|word| word == value)
+}
+
+/// Put `word` in the list, replacing the entry it edits if there is one.
+pub fn upsert(words: &mut Vec<MutedWord>, word: MutedWord) {
+    match words.iter_mut().find(|w| w.same_entry(&word)) {
+        Some(existing) => *existing = word,
+        None => words.push(word),
+    }
+}
+
+/// Take `word` out of the list. Missing entries are fine; another client
+/// may have got there first.
+pub fn remove(words: &mut Vec<MutedWord>, word: &MutedWord) {
+    words.retain(|w| !w.same_entry(word));
+}
+
+/// A new entry muting the hashtag `tag` and nothing else, forever.
+pub fn tag_word(tag: &str, now: DateTime<Utc>) -> MutedWord {
+    MutedWord {
+        id: Some(new_id(now)),
+        value: tag.trim_start_matches('#').to_string(),
+        targets: vec![MutedWordTarget::Tag],
+        exclude_following: false,
+        expires_at: None,
+    }
+}
+
+/// The live entry already muting the hashtag `tag`, if there is one. A
+/// content word catches hashtags too, so it counts.
+pub fn word_for_tag<'a>(
+    words: &'a [MutedWord],
+    tag: &str,
+    now: DateTime<Utc>,
+) -> Option<&'a MutedWord> {
+    let tag = normalized(tag);
+    words
+        .iter()
+        .find(|w| !w.is_expired(now) && normalized(&w.value) == tag)
+}
+
+/// A fresh entry id in the TID shape the other clients use: microseconds
+/// since the epoch and a clock id, in sortable base32.
+pub fn new_id(now: DateTime<Utc>) -> String {
+    const ALPHABET: &[u8] = b"234567abcdefghijklmnopqrstuvwxyz";
+    let micros = now.timestamp_micros().max(0) as u64;
+    let clock_id = u64::from(now.timestamp_subsec_nanos() % 1024);
+    let mut value = ((micros & ((1 << 53) - 1)) << 10) | clock_id;
+    let mut out = [b'2'; 13];
+    for slot in out.iter_mut().rev() {
+        *slot = ALPHABET[(value & 31) as usize];
+        value >>= 5;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atproto::types::{ExternalEmbed, ImageEmbed};
+
+    fn word(value: &str, targets: &[MutedWordTarget]) -> MutedWord {
+        MutedWord {
+            id: None,
+            value: value.to_string(),
+            targets: targets.to_vec(),
+            exclude_following: false,
+            expires_at: None,
+        }
+    }
+
+    fn author() -> Profile {
+        Profile::minimal(
+            "did:plc:author".to_string(),
+            "author.bsky.social".to_string(),
+            None,
+            None,
+        )
+    }
+
+    fn post(text: &str) -> Post {
+        Post {
+            uri: "at://did:plc:author/app.bsky.feed.post/1".to_string(),
+            cid: "cid".to_string(),
+            author: author(),
+            text: text.to_string(),
+            created_at: String::new(),
+            indexed_at: String::new(),
+            like_count: None,
+            repost_count: None,
+            reply_count: None,
+            embed: None,
+            viewer_like: None,
+            viewer_repost: None,
+            viewer_bookmarked: None,
+            repost_reason: None,
+            reply_context: None,
+            labels: Vec::new(),
+            feed_context: None,
+            facets: Vec::new(),
+        }
+    }
+
+    fn quote(text: &str) -> QuoteEmbed {
+        QuoteEmbed {
+            uri: "at://did:plc:other/app.bsky.feed.post/2".to_string(),
+            cid: "qcid".to_string(),
+            author: Profile::minimal(
+                "did:plc:other".to_string(),
+                "other.bsky.social".to_string(),
+                None,
+                None,
+            ),
+            text: text.to_string(),
+            indexed_at: String::new(),
+            embed: None,
+            labels: Vec::new(),
+            facets: Vec::new(),
+        }
+    }
+
+    const CONTENT: &[MutedWordTarget] = &[MutedWordTarget::Content, MutedWordTarget::Tag];
+    const TAG: &[MutedWordTarget] = &[MutedWordTarget::Tag];
+
+    /// Single words match whole words in any case; phrases match anywhere.
+    #[test]
+    fn content_words_match_whole_words_and_phrases_match_anywhere() {
+        let now = Utc::now();
+        let cat = [word("Cat", CONTENT)];
+        assert!(post_is_muted(&cat, &post("my cat, asleep"), now));
+        assert!(post_is_muted(&cat, &post("the CAT's toy"), now));
+        assert!(!post_is_muted(&cat, &post("concatenate strings"), now));
+
+        let phrase = [word("hot take", CONTENT)];
+        assert!(post_is_muted(&phrase, &post("a very hot take here"), now));
+        assert!(!post_is_muted(&phrase, &post("hot coffee, take two"), now));
+    }
+
+    /// A tag-only word ignores plain text but catches the hashtag, with or
+    /// without the `#` typed into the entry.
+    #[test]
+    fn tag_words_only_match_hashtags() {
+        let now = Utc::now();
+        for value in ["spoilers", "#Spoilers"] {
+            let words = [word(value, TAG)];
+            assert!(!post_is_muted(&words, &post("no spoilers please"), now));
+            assert!(post_is_muted(
+                &words,
+                &post("finale tonight #spoilers"),
+                now
+            ));
+        }
+    }
+
+    /// A post's own tag facets decide its tags: a tag the record marks
+    /// up counts even where the text spells it otherwise, and `#` text the
+    /// record left unmarked does not.
+    #[test]
+    fn tag_facets_win_over_the_text() {
+        let now = Utc::now();
+        let mut tagged = post("see #spoilers and a link");
+        tagged.facets = vec![
+            Facet {
+                byte_start: 4,
+                byte_end: 13,
+                feature: FacetFeature::Tag("Finale".to_string()),
+            },
+            Facet {
+                byte_start: 20,
+                byte_end: 24,
+                feature: FacetFeature::Link("https://example.com".to_string()),
+            },
+        ];
+        assert!(post_is_muted(&[word("finale", TAG)], &tagged, now));
+        assert!(!post_is_muted(&[word("spoilers", TAG)], &tagged, now));
+
+        let mut linked_only = post("see #spoilers");
+        linked_only.facets = vec![tagged.facets[1].clone()];
+        assert!(!post_is_muted(&[word("spoilers", TAG)], &linked_only, now));
+    }
+
+    /// Quoted text, alt text and link cards all count.
+    #[test]
+    fn quotes_alt_text_and_link_cards_are_checked() {
+        let now = Utc::now();
+        let words = [word("election", CONTENT)];
+
+        let mut quoting = post("look at this");
+        quoting.embed = Some(Embed::Quote(quote("election results are in")));
+        assert!(post_is_muted(&words, &quoting, now));
+
+        let mut with_media = post("look at this");
+        with_media.embed = Some(Embed::QuoteWithMedia {
+            quote: quote("nothing to see"),
+            media: Box::new(Embed::Images(vec![ImageEmbed {
+                thumb: String::new(),
+                fullsize: String::new(),
+                alt: "election night map".to_string(),
+                aspect_ratio: None,
+            }])),
+        });
+        assert!(post_is_muted(&words, &with_media, now));
+
+        let mut card = post("read this");
+        card.embed = Some(Embed::External(ExternalEmbed {
+            uri: "https://example.com".to_string(),
+            title: "Election coverage".to_string(),
+            description: String::new(),
+            thumb: None,
+        }));
+        assert!(post_is_muted(&words, &card, now));
+    }
+
+    /// Expired words stop muting; "exclude people I follow" spares followed
+    /// authors, and a followed author's quote of a stranger is still caught.
+    #[test]
+    fn expiry_and_exclude_following_are_honored() {
+        let now = Utc::now();
+        let mut expired = word("cat", CONTENT);
+        expired.expires_at = Some((now - chrono::Duration::hours(1)).to_rfc3339());
+        assert!(!post_is_muted(&[expired.clone()], &post("cat"), now));
+        expired.expires_at = Some((now + chrono::Duration::hours(1)).to_rfc3339());
+        assert!(post_is_muted(&[expired], &post("cat"), now));
+
+        let mut spare_friends = word("cat", CONTENT);
+        spare_friends.exclude_following = true;
+        let mut followed = post("cat");
+        followed.author.viewer_following = Some("at://follow".to_string());
+        assert!(!post_is_muted(&[spare_friends.clone()], &followed, now));
+        assert!(post_is_muted(&[spare_friends.clone()], &post("cat"), now));
+
+        let mut quoting = followed.clone();
+        quoting.text = "ha".to_string();
+        quoting.embed = Some(Embed::Quote(quote("cat")));
+        assert!(post_is_muted(&[spare_friends], &quoting, now));
+    }
+
+    /// Edits replace by id, id-less entries by value, and removal of a word
+    /// that is already gone is a no-op.
+    #[test]
+    fn list_edits_find_their_entry() {
+        let mut words = vec![word("cat", CONTENT)];
+        let mut edited = word("CAT", TAG);
+        upsert(&mut words, edited.clone());
+        assert_eq!(words, vec![edited.clone()]);
+
+        edited.id = Some("abc".to_string());
+        words = vec![edited.clone()];
+        let mut renamed = word("dog", CONTENT);
+        renamed.id = Some("abc".to_string());
+        upsert(&mut words, renamed.clone());
+        assert_eq!(words, vec![renamed.clone()]);
+
+        remove(&mut words, &word("bird", CONTENT));
+        assert_eq!(words.len(), 1);
+        remove(&mut words, &renamed);
+        assert!(words.is_empty());
+    }
+
+    /// Muting from a hashtag page writes a tag-only word that the page
+    /// then finds again, whatever case or `#` either side was spelled with.
+    #[test]
+    fn a_muted_tag_is_found_again() {
+        let now = Utc::now();
+        let muted = tag_word("#Rust", now);
+        assert_eq!(muted.value, "Rust");
+        assert_eq!(muted.targets, TAG);
+        assert!(post_is_muted(&[muted.clone()], &post("hello #rust"), now));
+        assert!(!post_is_muted(
+            &[muted.clone()],
+            &post("rust never sleeps"),
+            now
+        ));
+
+        let words = vec![word("gtk", CONTENT), muted.clone()];
+        assert_eq!(word_for_tag(&words, "rust", now), Some(&muted));
+        assert!(word_for_tag(&words, "GTK", now).is_some());
+        assert!(word_for_tag(&words, "rustlang", now).is_none());
+
+        let mut expired = word("rust", TAG);
+        expired.expires_at = Some((now - chrono::Duration::hours(1)).to_rfc3339());
+        assert!(word_for_tag(&[expired], "rust", now).is_none());
+    }
+
+    /// Ids come out as 13 sortable base32 characters, later ones sorting
+    /// after earlier ones.
+    #[test]
+    fn new_ids_are_sortable_tids() {
+        let earlier = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let later = DateTime::from_timestamp(1_700_000_001, 0).unwrap();
+        let a = new_id(earlier);
+        let b = new_id(later);
+        assert_eq!(a.len(), 13);
+        assert!(a < b, "{a} should sort before {b}");
+        assert!(
+            a.chars()
+                .all(|c| "234567abcdefghijklmnopqrstuvwxyz".contains(c))
+        );
+    }
+}
@@ -0,0 +1,544 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Notification reasons: how each reads, which the Activity tab's filters
+//! ask the server for, folding likes, reposts and follows into one row, and
+//! the account's preferences for which of them the server sends at all.
+//!
+//! Grouping follows bsky.app's: notifications for the same reason and the
+//! same subject fold into the newest of them when they arrived within two
+//! days of it, so a popular post reads "Alice and 12 others liked your
+//! post" instead of thirteen rows. Mentions, replies and quotes each carry
+//! a post of their own and never fold.
+
+use crate::atproto::types::{Notification, Profile};
+use chrono::DateTime;
+
+/// The reasons the Mentions tab lists.
+pub const MENTION_REASONS: &[&str] = &["mention", "reply", "quote"];
+
+/// How far behind the newest notification of a group another may be and
+/// still join it.
+const GROUP_WINDOW_HOURS: i64 = 48;
+
+/// The Activity tab's filter chips. Each asks the server for its own
+/// reasons, so a page of Follows is a full page of follows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ActivityFilter {
+    #[default]
+    All,
+    Likes,
+    Reposts,
+    Follows,
+    Mentions,
+    Replies,
+    Quotes,
+}
+
+impl ActivityFilter {
+    /// Every filter, in chip order
+    pub const ALL: [Self; 7] = [
+        Self::All,
+        Self::Likes,
+        Self::Reposts,
+        Self::Follows,
+        Self::Mentions,
+        Self::Replies,
+        Self::Quotes,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "All",
+            Self::Likes => "Likes",
+            Self::Reposts => "Reposts",
+            Self::Follows => "Follows",
+            Self::Mentions => "Mentions",
+            Self::Replies => "Replies",
+            Self::Quotes => "Quotes",
+        }
+    }
+
+    /// The `reasons` parameter; empty asks for every reason. A like or
+    /// repost of your repost counts with the likes and reposts.
+    pub fn reasons(self) -> &'static [&'static str] {
+        match self {
+            Self::All => &[],
+            Self::Likes => &["like", "like-via-repost"],
+            Self::Reposts => &["repost", "repost-via-repost"],
+            Self::Follows => &["follow", "starterpack-joined"],
+            Self::Mentions => &["mention"],
+            Self::Replies => &["reply"],
+            Self::Quotes => &["quote"],
+        }
+    }
+}
+
+/// What a reason reads as after the name of whoever caused it.
+pub fn reason_phrase(reason: &str) -> &str {
+    match reason {
+        "like" => "liked your post",
+        "like-via-repost" => "liked your repost",
+        "repost" => "reposted your post",
+        "repost-via-repost" => "reposted your repost",
+        "follow" => "followed you",
+        "mention" => "mentioned you",
+        "reply" => "replied to your post",
+        "quote" => "quoted your post",
+        "starterpack-joined" => "signed up with your starter pack",
+        "verified" => "verified your account",
+        "unverified" => "removed your verification",
+        "subscribed-post" => "posted",
+        other => other,
+    }
+}
+
+/// Reasons that fold into one row per subject.
+fn folds(reason: &str) -> bool {
+    matches!(
+        reason,
+        "like"
+            | "like-via-repost"
+            | "repost"
+            | "repost-via-repost"
+            | "follow"
+            | "starterpack-joined"
+    )
+}
+
+/// One Activity row: the newest notification, and everyone else who did
+/// the same to the same subject.
+#[derive(Debug, Clone)]
+pub struct NotificationGroup {
+    pub lead: Notification,
+    /// Other accounts, newest first, each once
+    pub others: Vec<Profile>,
+}
+
+impl NotificationGroup {
+    pub fn single(notification: Notification) -> Self {
+        Self {
+            lead: notification,
+            others: Vec::new(),
+        }
+    }
+
+    /// Fold `notification` in if it belongs here; hand it back if not.
+    fn absorb(&mut self, notification: Notification) -> Result<(), Notification> {
+        let lead = &self.lead;
+        let belongs = folds(&lead.reason)
+            && notification.reason == lead.reason
+            && notification.reason_subject == lead.reason_subject
+            && within_window(&lead.indexed_at, &notification.indexed_at);
+        if !belongs {
+            return Err(notification);
+        }
+        // An unread straggler keeps the row unread.
+        self.lead.is_read &= notification.is_read;
+        // Liking again after an unlike is still one person.
+        let did = &notification.author.did;
+        if *did != self.lead.author.did && !self.others.iter().any(|p| p.did == *did) {
+            self.others.push(notification.author);
+        }
+        Ok(())
+    }
+
+    /// Everyone in the row, lead included.
+    pub fn count(&self) -> usize {
+        1 + self.others.len()
+    }
+}
+
+/// Whether `older` arrived within [`GROUP_WINDOW_HOURS`] of `newest`.
+/// Stamps that do not parse never group; a wrong fold is worse than none.
+fn within_window(newest: &str, older: &str) -> bool {
+    let (Ok(newest), Ok(older)) = (
+        DateTime::parse_from_rfc3339(newest),
+        DateTime::parse_from_rfc3339(older),
+    ) else {
+        return false;
+    };
+    (newest - older).num_hours().abs() < GROUP_WINDOW_HOURS
+}
+
+/// Fold a newest-first page onto `groups`, which may already hold the rows
+/// above it. Returns how many of `groups` were there before and changed,
+/// counted from the end, so a caller appending a page knows which rows to
+/// redraw.
+pub fn fold_into(groups: &mut Vec<NotificationGroup>, page: Vec<Notification>) -> usize {
+    let existing = groups.len();
+    let mut first_changed = existing;
+    'next: for mut notification in page {
+        for (i, group) in groups.iter_mut().enumerate().rev() {
+            match group.absorb(notification) {
+                Ok(()) => {
+                    first_changed = first_changed.min(i);
+                    continue 'next;
+                }
+                Err(back) => notification = back,
+            }
+        }
+        groups.push(NotificationGroup::single(notification));
+    }
+    existing - first_changed
+}
+
+/// A page's rows, grouped.
+pub fn group(page: Vec<Notification>) -> Vec<NotificationGroup> {
+    let mut groups = Vec::new();
+    fold_into(&mut groups, page);
+    groups
+}
+
+/// Whose actions a category notifies about, a preference's `include`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IncludeFrom {
+    #[default]
+    Everyone,
+    Follows,
+}
+
+impl IncludeFrom {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Everyone => "all",
+            Self::Follows => "follows",
+        }
+    }
+
+    /// Anything newer than this app reads as everyone, the server default.
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "follows" => Self::Follows,
+            _ => Self::Everyone,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Everyone => "Everyone",
+            Self::Follows => "People you follow",
+        }
+    }
+}
+
+/// What the server does with one category of notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryPref {
+    /// `None` for the categories with no audience to pick: verification,
+    /// starter pack sign-ups and subscribed posts.
+    pub include: Option<IncludeFrom>,
+    /// Listed in the app's Activity and Mentions
+    pub in_app: bool,
+    /// Pushed to the account's phones
+    pub push: bool,
+}
+
+impl CategoryPref {
+    /// One line for a collapsed row: "Everyone · In app · Push", or "Off".
+    pub fn summary(&self) -> String {
+        if !self.in_app && !self.push {
+            return "Off".to_string();
+        }
+        let mut parts = Vec::new();
+        if let Some(include) = self.include {
+            parts.push(include.label());
+        }
+        if self.in_app {
+            parts.push("In app");
+        }
+        if self.push {
+            parts.push("Push");
+        }
+        parts.join(" · ")
+    }
+}
+
+/// The categories `putPreferencesV2` sets, in Settings order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationCategory {
+    Mention,
+    Reply,
+    Quote,
+    Like,
+    Repost,
+    Follow,
+    LikeViaRepost,
+    RepostViaRepost,
+    StarterpackJoined,
+    SubscribedPost,
+    Verified,
+    Unverified,
+}
+
+impl NotificationCategory {
+    pub const ALL: [Self; 12] = [
+        Self::Mention,
+        Self::Reply,
+        Self::Quote,
+        Self::Like,
+        Self::Repost,
+        Self::Follow,
+        Self::LikeViaRepost,
+        Self::RepostViaRepost,
+        Self::StarterpackJoined,
+        Self::SubscribedPost,
+        Self::Verified,
+        Self::Unverified,
+    ];
+
+    /// The lexicon's field name
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Mention => "mention",
+            Self::Reply => "reply",
+            Self::Quote => "quote",
+            Self::Like => "like",
+            Self::Repost => "repost",
+            Self::Follow => "follow",
+            Self::LikeViaRepost => "likeViaRepost",
+            Self::RepostViaRepost => "repostViaRepost",
+            Self::StarterpackJoined => "starterpackJoined",
+            Self::SubscribedPost => "subscribedPost",
+            Self::Verified => "verified",
+            Self::Unverified => "unverified",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Mention => "Mentions",
+            Self::Reply => "Replies",
+            Self::Quote => "Quotes",
+            Self::Like => "Likes",
+            Self::Repost => "Reposts",
+            Self::Follow => "New Followers",
+            Self::LikeViaRepost => "Likes of Your Reposts",
+            Self::RepostViaRepost => "Reposts of Your Reposts",
+            Self::StarterpackJoined => "Starter Pack Sign-ups",
+            Self::SubscribedPost => "Subscribed Accounts",
+            Self::Verified => "Verified",
+            Self::Unverified => "Verification Removed",
+        }
+    }
+
+    /// Whether the server lets this category keep to followed accounts.
+    pub fn filterable(self) -> bool {
+        !matches!(
+            self,
+            Self::StarterpackJoined | Self::SubscribedPost | Self::Verified | Self::Unverified
+        )
+    }
+}
+
+/// Chat notifications. The server keeps an audience and a push switch;
+/// no push is the "no one" choice, since chat has no in-app list to keep.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChatNotify {
+    #[default]
+    Everyone,
+    /// Only conversations already accepted
+    Accepted,
+    Nobody,
+}
+
+impl ChatNotify {
+    pub const ALL: [Self; 3] = [Self::Everyone, Self::Accepted, Self::Nobody];
+
+    /// Read the server's `include` and `push`.
+    pub fn from_server(include: &str, push: bool) -> Self {
+        match (include, push) {
+            (_, false) => Self::Nobody,
+            ("accepted", true) => Self::Accepted,
+            _ => Self::Everyone,
+        }
+    }
+
+    /// The `include` and `push` to write. "No one" keeps everyone as the
+    /// audience, so turning push back on starts from the default.
+    pub fn to_server(self) -> (&'static str, bool) {
+        match self {
+            Self::Everyone => ("all", true),
+            Self::Accepted => ("accepted", true),
+            Self::Nobody => ("all", false),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Everyone => "Everyone",
+            Self::Accepted => "Accepted chats",
+            Self::Nobody => "No one",
+        }
+    }
+}
+
+/// The account's notification preferences, as `getPreferences` has them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationPrefs {
+    pub chat: ChatNotify,
+    /// Every category the server sent, in [`NotificationCategory::ALL`] order
+    pub categories: Vec<(NotificationCategory, CategoryPref)>,
+}
+
+impl NotificationPrefs {
+    pub fn category(&self, category: NotificationCategory) -> Option<CategoryPref> {
+        self.categories
+            .iter()
+            .find(|(c, _)| *c == category)
+            .map(|(_, pref)| *pref)
+    }
+}
+
+/// One change to the notification preferences, as Settings asks for it.
+/// `putPreferencesV2` takes each category on its own, so nothing else is
+/// sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationPrefEdit {
+    Category(NotificationCategory, CategoryPref),
+    Chat(ChatNotify),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(author: &str, reason: &str, subject: Option<&str>, at: &str) -> Notification {
+        Notification {
+            uri: format!("at://did:plc:{author}/{reason}/{at}"),
+            cid: String::new(),
+            author: Profile::minimal(
+                format!("did:plc:{author}"),
+                format!("{author}.bsky.social"),
+                None,
+                None,
+            ),
+            reason: reason.to_string(),
+            reason_subject: subject.map(String::from),
+            indexed_at: format!("2026-05-{at}Z"),
+            is_read: true,
+            post: None,
+        }
+    }
+
+    /// Likes of one post fold into its newest, each liker once; another
+    /// post, another reason, or a reply each get a row of their own.
+    #[test]
+    fn likes_of_one_post_fold_into_one_row() {
+        let post = Some("at://me/app.bsky.feed.post/1");
+        let other = Some("at://me/app.bsky.feed.post/2");
+        let mut bob = notification("bob", "like", post, "10T11:00:00");
+        bob.is_read = false;
+        let groups = group(vec![
+            notification("alice", "like", post, "10T12:00:00"),
+            bob,
+            notification("carol", "like", other, "10T10:00:00"),
+            notification("dave", "repost", post, "10T09:00:00"),
+            notification("erin", "reply", post, "10T08:30:00"),
+            notification("frank", "reply", post, "10T08:00:00"),
+            notification("alice", "like", post, "10T07:00:00"),
+            notification("gina", "like", post, "10T06:00:00"),
+        ]);
+
+        let rows: Vec<(&str, usize)> = groups
+            .iter()
+            .map(|g| (g.lead.author.handle.as_str(), g.count()))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("alice.bsky.social", 3),
+                ("carol.bsky.social", 1),
+                ("dave.bsky.social", 1),
+                ("erin.bsky.social", 1),
+                ("frank.bsky.social", 1),
+            ]
+        );
+        assert!(
+            !groups[0].lead.is_read,
+            "bob's unread like keeps the row unread"
+        );
+        assert_eq!(groups[0].others[1].handle, "gina.bsky.social");
+    }
+
+    /// Follows fold across the window but not past it, and a next page
+    /// can still join the last row it lands under.
+    #[test]
+    fn a_next_page_joins_the_row_above_it() {
+        let mut groups = group(vec![
+            notification("alice", "follow", None, "10T12:00:00"),
+            notification("bob", "follow", None, "09T13:00:00"),
+        ]);
+        assert_eq!(groups.len(), 1);
+
+        let changed = fold_into(
+            &mut groups,
+            vec![
+                notification("carol", "follow", None, "08T13:00:00"),
+                notification("dave", "follow", None, "08T11:00:00"),
+            ],
+        );
+        assert_eq!(changed, 1, "carol joined the row already on screen");
+        assert_eq!(groups.len(), 2, "dave is past alice's two days");
+        assert_eq!(groups[0].count(), 3);
+        assert_eq!(groups[1].lead.author.handle, "dave.bsky.social");
+    }
+
+    /// The newer reasons read as sentences, and filters ask for their
+    /// variants too.
+    #[test]
+    fn newer_reasons_read_and_filter() {
+        assert_eq!(reason_phrase("like-via-repost"), "liked your repost");
+        assert_eq!(reason_phrase("verified"), "verified your account");
+        assert_eq!(reason_phrase("something-new"), "something-new");
+        assert!(
+            ActivityFilter::Reposts
+                .reasons()
+                .contains(&"repost-via-repost")
+        );
+        assert!(ActivityFilter::All.reasons().is_empty());
+    }
+
+    /// "No one" is push off whatever the audience, and writing it back
+    /// reads as "No one" again.
+    #[test]
+    fn chat_preferences_round_trip() {
+        assert_eq!(
+            ChatNotify::from_server("accepted", false),
+            ChatNotify::Nobody
+        );
+        assert_eq!(
+            ChatNotify::from_server("accepted", true),
+            ChatNotify::Accepted
+        );
+        assert_eq!(
+            ChatNotify::from_server("something", true),
+            ChatNotify::Everyone
+        );
+        for chat in ChatNotify::ALL {
+            let (include, push) = chat.to_server();
+            assert_eq!(ChatNotify::from_server(include, push), chat);
+        }
+    }
+
+    /// A collapsed row says who and where, or that it is off.
+    #[test]
+    fn category_summaries_read_as_set() {
+        let pref = CategoryPref {
+            include: Some(IncludeFrom::Follows),
+            in_app: true,
+            push: false,
+        };
+        assert_eq!(pref.summary(), "People you follow · In app");
+        let verified = CategoryPref {
+            include: None,
+            in_app: false,
+            push: true,
+        };
+        assert_eq!(verified.summary(), "Push");
+        let off = CategoryPref {
+            push: false,
+            ..verified
+        };
+        assert_eq!(off.summary(), "Off");
+    }
+}
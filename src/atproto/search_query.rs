@@ -0,0 +1,401 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Post search queries: the words typed, plus the filters `searchPosts`
+//! takes as parameters.
+//!
+//! The search entry is the one place a query is written down. Filters can be
+//! typed there as operators (`from:alice since:2026-01-01 sort:top`) or set
+//! from the filter popover, which writes them back into the entry the same
+//! way, so a query round-trips through [`SearchQuery::parse`] and
+//! [`SearchQuery::to_input`] whichever way it was built.
+
+use chrono::NaiveDate;
+
+/// Result order, the `sort` parameter
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchSort {
+    /// Newest first, the server's default
+    #[default]
+    Latest,
+    /// Most engaged first
+    Top,
+}
+
+impl SearchSort {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SearchSort::Latest => "latest",
+            SearchSort::Top => "top",
+        }
+    }
+}
+
+/// A post search: free text and every filter `searchPosts` knows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub text: String,
+    /// Posts by this handle or DID, without its `@`
+    pub author: Option<String>,
+    /// Posts mentioning this handle or DID
+    pub mentions: Option<String>,
+    /// First day included
+    pub since: Option<NaiveDate>,
+    /// Last day included. The API's `until` is exclusive; the client adds
+    /// the day.
+    pub until: Option<NaiveDate>,
+    /// Language code, lowercased
+    pub lang: Option<String>,
+    /// Posts linking anywhere on this domain
+    pub domain: Option<String>,
+    /// Posts linking to this exact URL
+    pub url: Option<String>,
+    /// Hashtags, without their `#`; a post needs all of them
+    pub tags: Vec<String>,
+    pub sort: SearchSort,
+}
+
+/// One active filter, as shown on a chip and removed by it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchFilter {
+    Author,
+    Mentions,
+    Since,
+    Until,
+    Lang,
+    Domain,
+    Url,
+    Tag(String),
+    Sort,
+}
+
+impl SearchQuery {
+    /// Read a query out of what was typed. Words that look like operators
+    /// but carry nothing usable, `since:soon` or `from:alice` with no
+    /// domain say, stay part of the text, so nothing typed is silently
+    /// dropped: the server reads them there, where the API parameter would
+    /// have refused them.
+    pub fn parse(input: &str) -> Self {
+        let mut query = SearchQuery::default();
+        let mut words = Vec::new();
+        for word in input.split_whitespace() {
+            if !query.take_operator(word) {
+                words.push(word);
+            }
+        }
+        query.text = words.join(" ");
+        query
+    }
+
+    /// Apply one `key:value` word; false if it is not one.
+    fn take_operator(&mut self, word: &str) -> bool {
+        let Some((key, value)) = word.split_once(':') else {
+            return false;
+        };
+        if value.is_empty() {
+            return false;
+        }
+        match key.to_ascii_lowercase().as_str() {
+            "from" => match account(value) {
+                Some(account) => self.author = Some(account),
+                None => return false,
+            },
+            "to" | "mentions" => match account(value) {
+                Some(account) => self.mentions = Some(account),
+                None => return false,
+            },
+            "since" => match parse_day(value) {
+                Some(day) => self.since = Some(day),
+                None => return false,
+            },
+            "until" => match parse_day(value) {
+                Some(day) => self.until = Some(day),
+                None => return false,
+            },
+            "lang" => match language(value) {
+                Some(lang) => self.lang = Some(lang),
+                None => return false,
+            },
+            "domain" => self.domain = Some(domain(value)),
+            // Links carry their own colons; only the first split counts.
+            "url" => self.url = Some(value.to_string()),
+            "tag" => {
+                let tag = value.trim_start_matches('#');
+                if tag.is_empty() {
+                    return false;
+                }
+                if !self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                    self.tags.push(tag.to_string());
+                }
+            }
+            "sort" => match value.to_ascii_lowercase().as_str() {
+                "top" => self.sort = SearchSort::Top,
+                "latest" => self.sort = SearchSort::Latest,
+                _ => return false,
+            },
+            _ => return false,
+        }
+        true
+    }
+
+    /// The query written back as it would be typed: the words, then each
+    /// filter as an operator.
+    pub fn to_input(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.text.is_empty() {
+            parts.push(self.text.clone());
+        }
+        if let Some(author) = &self.author {
+            parts.push(format!("from:{author}"));
+        }
+        if let Some(mentions) = &self.mentions {
+            parts.push(format!("mentions:{mentions}"));
+        }
+        if let Some(since) = self.since {
+            parts.push(format!("since:{}", since.format("%Y-%m-%d")));
+        }
+        if let Some(until) = self.until {
+            parts.push(format!("until:{}", until.format("%Y-%m-%d")));
+        }
+        if let Some(lang) = &self.lang {
+            parts.push(format!("lang:{lang}"));
+        }
+        if let Some(domain) = &self.domain {
+            parts.push(format!("domain:{domain}"));
+        }
+        if let Some(url) = &self.url {
+            parts.push(format!("url:{url}"));
+        }
+        for tag in &self.tags {
+            parts.push(format!("tag:{tag}"));
+        }
+        if self.sort != SearchSort::Latest {
+            parts.push(format!("sort:{}", self.sort.as_str()));
+        }
+        parts.join(" ")
+    }
+
+    /// The `q` parameter. The API insists on one, so a search that is all
+    /// filters sends them as operators, which the server also reads.
+    pub fn q(&self) -> String {
+        if self.text.is_empty() {
+            self.to_input()
+        } else {
+            self.text.clone()
+        }
+    }
+
+    /// Whether there is nothing to search for at all.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.filters().is_empty()
+    }
+
+    /// The active filters with their chip labels, in operator order.
+    pub fn filters(&self) -> Vec<(SearchFilter, String)> {
+        let mut filters = Vec::new();
+        if let Some(author) = &self.author {
+            filters.push((SearchFilter::Author, format!("From @{author}")));
+        }
+        if let Some(mentions) = &self.mentions {
+            filters.push((SearchFilter::Mentions, format!("Mentions @{mentions}")));
+        }
+        if let Some(since) = self.since {
+            filters.push((
+                SearchFilter::Since,
+                format!("Since {}", since.format("%b %-d, %Y")),
+            ));
+        }
+        if let Some(until) = self.until {
+            filters.push((
+                SearchFilter::Until,
+                format!("Until {}", until.format("%b %-d, %Y")),
+            ));
+        }
+        if let Some(lang) = &self.lang {
+            filters.push((SearchFilter::Lang, format!("Language: {lang}")));
+        }
+        if let Some(domain) = &self.domain {
+            filters.push((SearchFilter::Domain, format!("Links to {domain}")));
+        }
+        if let Some(url) = &self.url {
+            filters.push((SearchFilter::Url, format!("Links to {url}")));
+        }
+        for tag in &self.tags {
+            filters.push((SearchFilter::Tag(tag.clone()), format!("#{tag}")));
+        }
+        if self.sort == SearchSort::Top {
+            filters.push((SearchFilter::Sort, "Top posts".to_string()));
+        }
+        filters
+    }
+
+    /// The same query with one filter taken off.
+    pub fn without(&self, filter: &SearchFilter) -> Self {
+        let mut query = self.clone();
+        match filter {
+            SearchFilter::Author => query.author = None,
+            SearchFilter::Mentions => query.mentions = None,
+            SearchFilter::Since => query.since = None,
+            SearchFilter::Until => query.until = None,
+            SearchFilter::Lang => query.lang = None,
+            SearchFilter::Domain => query.domain = None,
+            SearchFilter::Url => query.url = None,
+            SearchFilter::Tag(tag) => query.tags.retain(|t| t != tag),
+            SearchFilter::Sort => query.sort = SearchSort::Latest,
+        }
+        query
+    }
+}
+
+/// A handle or DID, without its `@`, if it is one the API takes.
+fn account(value: &str) -> Option<String> {
+    let value = value.trim_start_matches('@');
+    let valid = match value.strip_prefix("did:") {
+        Some(rest) => rest.split_once(':').is_some_and(|(method, id)| {
+            !method.is_empty()
+                && method.bytes().all(|b| b.is_ascii_lowercase())
+                && !id.is_empty()
+                && !id.ends_with([':', '%'])
+                && id
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b".:%-_".contains(&b))
+        }),
+        None => {
+            let labels: Vec<&str> = value.split('.').collect();
+            value.len() <= 253
+                && labels.len() >= 2
+                && labels.iter().all(|label| {
+                    (1..=63).contains(&label.len())
+                        && !label.starts_with('-')
+                        && !label.ends_with('-')
+                        && label
+                            .bytes()
+                            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+                })
+                && labels
+                    .last()
+                    .is_some_and(|tld| tld.starts_with(|c: char| c.is_ascii_alphabetic()))
+        }
+    };
+    valid.then(|| value.to_string())
+}
+
+/// A language tag, lowercased: a two- or three-letter code and any
+/// subtags, `en` or `pt-br`. A name written out, `english`, is not one.
+fn language(value: &str) -> Option<String> {
+    let mut subtags = value.split('-');
+    let primary = subtags.next()?;
+    let valid = (2..=3).contains(&primary.len())
+        && primary.bytes().all(|b| b.is_ascii_alphabetic())
+        && subtags.all(|sub| {
+            (1..=8).contains(&sub.len()) && sub.bytes().all(|b| b.is_ascii_alphanumeric())
+        });
+    valid.then(|| value.to_ascii_lowercase())
+}
+
+/// A bare host: `https://www.Example.com/path` is `example.com`.
+fn domain(value: &str) -> String {
+    let host = value
+        .split_once("://")
+        .map_or(value, |(_, rest)| rest)
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    host.trim_start_matches("www.").to_ascii_lowercase()
+}
+
+/// A filter day, and the one spelling the entry writes back.
+pub fn parse_day(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Operators come out of the text wherever they sit, and the query
+    /// writes back the way it would be typed.
+    #[test]
+    fn operators_parse_out_and_round_trip() {
+        let query = SearchQuery::parse(
+            "sort:top rust from:@alice.bsky.social since:2026-01-01 gtk tag:#Linux \
+             domain:https://www.Example.com/blog lang:EN",
+        );
+        assert_eq!(query.text, "rust gtk");
+        assert_eq!(query.author.as_deref(), Some("alice.bsky.social"));
+        assert_eq!(query.since, NaiveDate::from_ymd_opt(2026, 1, 1));
+        assert_eq!(query.tags, ["Linux"]);
+        assert_eq!(query.domain.as_deref(), Some("example.com"));
+        assert_eq!(query.lang.as_deref(), Some("en"));
+        assert_eq!(query.sort, SearchSort::Top);
+
+        let input = query.to_input();
+        assert_eq!(
+            input,
+            "rust gtk from:alice.bsky.social since:2026-01-01 lang:en domain:example.com \
+             tag:Linux sort:top"
+        );
+        assert_eq!(SearchQuery::parse(&input), query);
+    }
+
+    /// A word that only looks like an operator is still searched for: a
+    /// time, a bad date, an unknown key.
+    #[test]
+    fn unusable_operators_stay_in_the_text() {
+        let query = SearchQuery::parse("meet at 10:30 since:soon sort:random note: re:thing");
+        assert_eq!(
+            query.text,
+            "meet at 10:30 since:soon sort:random note: re:thing"
+        );
+        assert!(query.filters().is_empty());
+    }
+
+    /// An account or language the API would refuse is not a filter. Taken
+    /// as one, it was left off the request while its chip still claimed
+    /// it; as text the server reads it itself.
+    #[test]
+    fn accounts_and_languages_the_api_refuses_stay_in_the_text() {
+        let query = SearchQuery::parse("from:alice mentions:@-bad-.com lang:english cats");
+        assert_eq!(
+            query.text,
+            "from:alice mentions:@-bad-.com lang:english cats"
+        );
+        assert!(query.filters().is_empty());
+
+        let query = SearchQuery::parse("from:did:plc:abc123 to:bob.test lang:pt-BR");
+        assert_eq!(query.author.as_deref(), Some("did:plc:abc123"));
+        assert_eq!(query.mentions.as_deref(), Some("bob.test"));
+        assert_eq!(query.lang.as_deref(), Some("pt-br"));
+        assert!(query.text.is_empty());
+    }
+
+    /// Each chip takes off exactly its own filter, and a query of filters
+    /// alone still has a `q` to send.
+    #[test]
+    fn a_chip_removes_its_filter() {
+        let query = SearchQuery::parse(
+            "from:bob.test url:https://a.example/x?y=1 tag:rust tag:gtk sort:top",
+        );
+        let labels: Vec<String> = query.filters().into_iter().map(|(_, l)| l).collect();
+        assert_eq!(
+            labels,
+            [
+                "From @bob.test",
+                "Links to https://a.example/x?y=1",
+                "#rust",
+                "#gtk",
+                "Top posts"
+            ]
+        );
+        assert_eq!(query.q(), query.to_input());
+
+        let fewer = query
+            .without(&SearchFilter::Tag("rust".into()))
+            .without(&SearchFilter::Sort);
+        assert_eq!(
+            fewer.to_input(),
+            "from:bob.test url:https://a.example/x?y=1 tag:gtk"
+        );
+        assert!(!fewer.is_empty());
+        assert!(SearchQuery::parse("  ").is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MPL-2.0
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// How this session was authenticated.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +28,10 @@ pub struct Session {
     pub did: String,
     pub handle: String,
     pub auth: AuthMethod,
+    /// The PDS an app-password session signed in against. `None` for OAuth,
+    /// whose session store keeps its own, and for sessions saved before
+    /// Hangar signed in anywhere but `DEFAULT_PDS`.
+    pub service_url: Option<String>,
 }
 
 impl Session {
@@ -59,6 +64,8 @@ enum SessionRaw {
         did: String,
         handle: String,
         auth: AuthMethod,
+        #[serde(default)]
+        service_url: Option<String>,
     },
     /// Legacy format with flat access_jwt/refresh_jwt fields
     Legacy {
@@ -72,7 +79,17 @@ enum SessionRaw {
 impl From<SessionRaw> for Session {
     fn from(raw: SessionRaw) -> Self {
         match raw {
-            SessionRaw::Tagged { did, handle, auth } => Session { did, handle, auth },
+            SessionRaw::Tagged {
+                did,
+                handle,
+                auth,
+                service_url,
+            } => Session {
+                did,
+                handle,
+                auth,
+                service_url,
+            },
             SessionRaw::Legacy {
                 did,
                 handle,
@@ -85,11 +102,36 @@ impl From<SessionRaw> for Session {
                     access_jwt,
                     refresh_jwt,
                 },
+                service_url: None,
             },
         }
     }
 }
 
+/// What a PDS asks of a new account, from `describeServer`.
+#[derive(Debug, Clone, Default)]
+pub struct ServerDescription {
+    /// Handle suffixes it hands out, each with its leading dot: ".bsky.social"
+    pub user_domains: Vec<String>,
+    pub invite_code_required: bool,
+    /// Whether sign-up needs a code texted to a phone first
+    pub phone_verification_required: bool,
+    pub terms_of_service: Option<String>,
+    pub privacy_policy: Option<String>,
+}
+
+/// The sign-up form, as `createAccount` takes it.
+#[derive(Debug, Clone, Default)]
+pub struct NewAccount {
+    /// The whole handle, domain included
+    pub handle: String,
+    pub email: String,
+    pub password: String,
+    pub invite_code: Option<String>,
+    pub verification_phone: Option<String>,
+    pub verification_code: Option<String>,
+}
+
 /// External link card embed (URLs with previews)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExternalEmbed {
@@ -118,6 +160,35 @@ pub struct QuoteEmbed {
     pub indexed_at: String,
     /// Nested embed within the quoted post
     pub embed: Option<Box<Embed>>,
+    /// Labels on the quoted post itself. Defaulted so cached quotes written
+    /// before the field existed still deserialize.
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    /// The quoted record's rich text spans. Defaulted like `labels`.
+    #[serde(default)]
+    pub facets: Vec<Facet>,
+}
+
+/// A span of post text the record marks up, as UTF-8 byte offsets into
+/// `text`. Taken from the record as written, so a link shows where it
+/// really goes even when its visible text is shortened.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Facet {
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub feature: FacetFeature,
+}
+
+/// What a facet span is. A facet may list several features; the first
+/// one Hangar knows is kept.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FacetFeature {
+    /// A link to this URI
+    Link(String),
+    /// A mention of the account with this DID
+    Mention(String),
+    /// A hashtag, without its `#`
+    Tag(String),
 }
 
 /// All possible embed types for a post
@@ -182,6 +253,20 @@ pub struct Post {
     pub repost_reason: Option<RepostReason>,
     /// Reply context if this post is a reply
     pub reply_context: Option<ReplyContext>,
+    /// Moderation labels, self-applied ones included. Defaulted so cached
+    /// posts written before the field existed still deserialize.
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    /// The opaque context a custom feed attached to this item, handed back
+    /// with any interaction reported on it. Only set on feed pages: it
+    /// means something only to the generator that served the page, so a
+    /// cached post has none to report back against.
+    #[serde(default)]
+    pub feed_context: Option<String>,
+    /// Links, mentions and tags in `text`, from the record. Empty for a
+    /// post that has none and for one cached before they were kept.
+    #[serde(default)]
+    pub facets: Vec<Facet>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -214,6 +299,25 @@ pub struct Profile {
     /// Whether this account blocks the viewer
     #[serde(default)]
     pub viewer_blocked_by: bool,
+    /// Moderation labels on the account or its profile record
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    /// The moderation list the viewer mutes this account through, if any.
+    /// `viewer_muted` is set too when it is.
+    #[serde(default)]
+    pub muted_by_list: Option<ListRef>,
+    /// The moderation list the viewer blocks this account through, if
+    /// any. `viewer_blocking` then holds the list's block, not one of the
+    /// account's own.
+    #[serde(default)]
+    pub blocking_by_list: Option<ListRef>,
+}
+
+/// A list named in someone's viewer state: enough to say which list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListRef {
+    pub uri: String,
+    pub name: String,
 }
 
 impl Profile {
@@ -239,6 +343,378 @@ impl Profile {
             viewer_muted: false,
             viewer_blocking: None,
             viewer_blocked_by: false,
+            labels: Vec::new(),
+            muted_by_list: None,
+            blocking_by_list: None,
+        }
+    }
+
+    /// Why a hidden account is hidden, when a list is the reason: the
+    /// profile header shows it, since no Unmute or Unblock of the
+    /// account's own would undo it.
+    pub fn list_moderation_note(&self) -> Option<String> {
+        let mut notes = Vec::new();
+        if let Some(list) = &self.blocking_by_list {
+            notes.push(format!("Blocked by the list {}", list.name));
+        }
+        if let Some(list) = &self.muted_by_list {
+            notes.push(format!("Muted by the list {}", list.name));
+        }
+        (!notes.is_empty()).then(|| notes.join(" · "))
+    }
+}
+
+/// A moderation label, applied by a labeler or by the author to their own
+/// content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Label {
+    /// DID of whoever applied it. The author's own DID makes it a
+    /// self-label.
+    pub src: String,
+    /// The label value, e.g. "porn" or "!warn".
+    pub val: String,
+    /// A negation withdraws an earlier label with the same source and value.
+    #[serde(default)]
+    pub neg: bool,
+}
+
+/// What the viewer asked to happen to content carrying a label. Mirrors the
+/// lexicon's `contentLabelPref.visibility`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LabelVisibility {
+    /// Show as if unlabeled.
+    Ignore,
+    /// Cover behind a warning that can be clicked through.
+    Warn,
+    /// Drop from feeds; cover elsewhere.
+    Hide,
+}
+
+impl LabelVisibility {
+    /// "show" is the legacy spelling of "ignore". Unknown values are
+    /// dropped so the label's default applies.
+    pub fn from_wire(value: &str) -> Option<Self> {
+        match value {
+            "ignore" | "show" => Some(Self::Ignore),
+            "warn" => Some(Self::Warn),
+            "hide" => Some(Self::Hide),
+            _ => None,
+        }
+    }
+
+    /// The lexicon's spelling, for writing a `contentLabelPref` back.
+    pub fn as_wire(self) -> &'static str {
+        match self {
+            Self::Ignore => "ignore",
+            Self::Warn => "warn",
+            Self::Hide => "hide",
+        }
+    }
+}
+
+/// The account's label preferences, read from `adultContentPref`,
+/// `contentLabelPref` and `labelersPref`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModerationPrefs {
+    /// Off until the account turns it on, as on bsky.app. While off, adult
+    /// labels hide with no way through.
+    pub adult_content_enabled: bool,
+    /// Visibility per global label value. A label with no entry uses its
+    /// default.
+    pub label_visibility: HashMap<String, LabelVisibility>,
+    /// Visibility per (labeler DID, label value), for the labels a
+    /// subscribed labeler defines itself.
+    pub labeler_label_visibility: HashMap<(String, String), LabelVisibility>,
+    /// Labelers subscribed to beyond Bluesky's own moderation service,
+    /// which every account gets.
+    pub labelers: Vec<LabelerService>,
+    /// The reader's languages in preference order, for naming custom
+    /// labels. Not an account preference: the window fills it in.
+    pub languages: Vec<String>,
+}
+
+/// A labeler and the labels it defines, from `app.bsky.labeler.getServices`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelerService {
+    pub did: String,
+    /// The labeler's account, for its name and avatar.
+    pub creator: Profile,
+    pub definitions: Vec<LabelDefinition>,
+}
+
+/// One custom label a labeler publishes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelDefinition {
+    pub identifier: String,
+    /// "alert", "inform" or "none".
+    pub severity: String,
+    /// "content", "media" or "none".
+    pub blurs: String,
+    pub default_setting: LabelVisibility,
+    /// Hidden with no way through while adult content is off.
+    pub adult_only: bool,
+    /// The labeler's translations, in its own order.
+    pub locales: Vec<LabelStrings>,
+}
+
+/// A label's name and description in one language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelStrings {
+    pub lang: String,
+    pub name: String,
+    pub description: String,
+}
+
+impl LabelDefinition {
+    /// The strings in the first of `languages` the labeler translated
+    /// into, matching on the language alone when no region matches; else
+    /// its first set. `languages` is in preference order, as
+    /// `g_get_language_names` gives it.
+    pub fn strings(&self, languages: &[&str]) -> Option<&LabelStrings> {
+        let base = |lang: &str| {
+            lang.split(['-', '_', '.', '@'])
+                .next()
+                .unwrap_or("")
+                .to_ascii_lowercase()
+        };
+        languages
+            .iter()
+            .find_map(|want| {
+                self.locales
+                    .iter()
+                    .find(|l| l.lang.eq_ignore_ascii_case(&want.replace('_', "-")))
+                    .or_else(|| self.locales.iter().find(|l| base(&l.lang) == base(want)))
+            })
+            .or_else(|| self.locales.first())
+    }
+
+    /// What to call the label, falling back to its identifier.
+    pub fn name(&self, languages: &[&str]) -> String {
+        self.strings(languages)
+            .map_or_else(|| self.identifier.clone(), |s| s.name.clone())
+    }
+}
+
+/// What a muted word is checked against. Mirrors the lexicon's
+/// `mutedWordTarget` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MutedWordTarget {
+    /// Post text, alt text and link cards; hashtags count too.
+    Content,
+    /// Hashtags only.
+    Tag,
+}
+
+impl MutedWordTarget {
+    /// The wire value.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Content => "content",
+            Self::Tag => "tag",
+        }
+    }
+
+    /// Unknown values are dropped rather than guessed at.
+    pub fn from_wire(value: &str) -> Option<Self> {
+        match value {
+            "content" => Some(Self::Content),
+            "tag" => Some(Self::Tag),
+            _ => None,
+        }
+    }
+}
+
+/// One entry of the account's `mutedWordsPref`, shared with every other
+/// client the account signs in to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MutedWord {
+    /// Set by whichever client created the entry. Older entries have none
+    /// and are told apart by value.
+    pub id: Option<String>,
+    pub value: String,
+    pub targets: Vec<MutedWordTarget>,
+    /// The lexicon's `actorTarget: "exclude-following"`: people the viewer
+    /// follows are not muted by this word.
+    pub exclude_following: bool,
+    /// RFC 3339; none means forever.
+    pub expires_at: Option<String>,
+}
+
+/// What a list is for, the lexicon's `purpose`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListPurpose {
+    /// A list of people, with a feed of their posts
+    Curate,
+    /// A list to mute or block as a whole
+    Moderation,
+    /// A list with no behaviour of its own, as starter packs use
+    Reference,
+}
+
+impl ListPurpose {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ListPurpose::Curate => "app.bsky.graph.defs#curatelist",
+            ListPurpose::Moderation => "app.bsky.graph.defs#modlist",
+            ListPurpose::Reference => "app.bsky.graph.defs#referencelist",
+        }
+    }
+
+    /// A purpose this client does not know is read as a plain curated
+    /// list, which is all it can do with one anyway.
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "app.bsky.graph.defs#modlist" => ListPurpose::Moderation,
+            "app.bsky.graph.defs#referencelist" => ListPurpose::Reference,
+            _ => ListPurpose::Curate,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ListPurpose::Curate => "User list",
+            ListPurpose::Moderation => "Moderation list",
+            ListPurpose::Reference => "Reference list",
+        }
+    }
+
+    /// Whether the list has a feed of posts worth reading or pinning.
+    pub fn has_feed(self) -> bool {
+        self == ListPurpose::Curate
+    }
+}
+
+/// An `app.bsky.graph.list`, as the AppView describes it.
+#[derive(Debug, Clone)]
+pub struct UserList {
+    pub uri: String,
+    pub name: String,
+    pub purpose: ListPurpose,
+    pub description: Option<String>,
+    pub avatar: Option<String>,
+    pub creator: Profile,
+    /// Members, when the server counted them
+    pub item_count: Option<u32>,
+    /// Whether the viewer mutes everyone on this list
+    pub viewer_muted: bool,
+    /// The viewer's `listblock` record on this list, if they block it
+    pub viewer_blocked: Option<String>,
+}
+
+impl UserList {
+    /// "User list · 12 people · by @alice.bsky.social", for list rows,
+    /// then "Blocked" or "Muted" when the viewer subscribes to it.
+    pub fn summary(&self) -> String {
+        let mut parts = vec![self.purpose.label().to_string()];
+        if let Some(count) = self.item_count {
+            parts.push(match count {
+                1 => "1 person".to_string(),
+                n => format!("{n} people"),
+            });
+        }
+        parts.push(format!("by @{}", self.creator.handle));
+        if self.viewer_blocked.is_some() {
+            parts.push("Blocked".to_string());
+        } else if self.viewer_muted {
+            parts.push("Muted".to_string());
+        }
+        parts.join(" · ")
+    }
+}
+
+/// One account on a list, with the `listitem` record that put it there.
+#[derive(Debug, Clone)]
+pub struct ListMember {
+    /// The `app.bsky.graph.listitem` record; deleting it takes them off
+    pub item_uri: String,
+    pub profile: Profile,
+}
+
+/// An `app.bsky.graph.starterpack`: a reference list of people and a few
+/// feeds, handed to newcomers to follow in one go.
+#[derive(Debug, Clone)]
+pub struct StarterPack {
+    pub uri: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub creator: Profile,
+    /// The reference list holding its people
+    pub list_uri: Option<String>,
+    /// People on it, when the server counted them
+    pub item_count: Option<u32>,
+    /// Accounts that joined through it
+    pub joined_count: Option<u32>,
+    /// The feeds it recommends. Only the full view carries them; a pack
+    /// from a profile's listing has none until it is opened.
+    pub feeds: Vec<FeedGenerator>,
+}
+
+impl StarterPack {
+    /// "12 people · 3 feeds · 40 joined · by @alice.bsky.social", with
+    /// whatever the server did not count left out.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(count) = self.item_count {
+            parts.push(match count {
+                1 => "1 person".to_string(),
+                n => format!("{n} people"),
+            });
+        }
+        match self.feeds.len() {
+            0 => {}
+            1 => parts.push("1 feed".to_string()),
+            n => parts.push(format!("{n} feeds")),
+        }
+        if let Some(joined) = self.joined_count.filter(|n| *n > 0) {
+            parts.push(format!("{joined} joined"));
+        }
+        parts.push(format!("by @{}", self.creator.handle));
+        parts.join(" · ")
+    }
+}
+
+/// An `app.bsky.feed.generator`: a custom feed someone runs, as the feed
+/// browser and its detail page show it.
+#[derive(Debug, Clone)]
+pub struct FeedGenerator {
+    pub uri: String,
+    pub cid: String,
+    /// The DID of the service that runs it, which interactions go to
+    pub did: String,
+    pub display_name: String,
+    pub description: Option<String>,
+    pub avatar: Option<String>,
+    pub creator: Profile,
+    pub like_count: Option<u32>,
+    /// The URI of the viewer's like, if they liked it
+    pub viewer_like: Option<String>,
+    /// Whether it takes `app.bsky.feed.sendInteractions` feedback
+    pub accepts_interactions: bool,
+}
+
+impl FeedGenerator {
+    /// "40 likes · by @alice.bsky.social", the count left out when the
+    /// server did not give one.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(count) = self.like_count {
+            parts.push(match count {
+                1 => "1 like".to_string(),
+                n => format!("{n} likes"),
+            });
+        }
+        parts.push(format!("by @{}", self.creator.handle));
+        parts.join(" · ")
+    }
+
+    /// This feed as an entry in the account's saved feeds.
+    pub fn as_saved_feed(&self, pinned: bool) -> SavedFeed {
+        SavedFeed {
+            feed_type: "feed".to_string(),
+            uri: self.uri.clone(),
+            display_name: self.display_name.clone(),
+            description: self.description.clone(),
+            pinned,
         }
     }
 }
@@ -246,9 +722,10 @@ impl Profile {
 /// Represents a feed that the user can switch to
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SavedFeed {
-    /// The type of feed: "timeline" for home, "feed" for custom generators
+    /// The type of feed: "timeline" for home, "feed" for custom generators,
+    /// "list" for a list's feed
     pub feed_type: String,
-    /// The AT-URI of the feed generator (empty for home timeline)
+    /// The AT-URI of the feed generator or list (empty for home timeline)
     pub uri: String,
     /// Display name shown in the feed selector
     pub display_name: String,
@@ -266,6 +743,9 @@ pub struct Notification {
     pub author: Profile,
     /// The reason for the notification: "mention", "reply", "quote", "like", "repost", "follow"
     pub reason: String,
+    /// What a like or repost is of; none for follows
+    #[serde(default)]
+    pub reason_subject: Option<String>,
     /// When the notification was indexed
     pub indexed_at: String,
     /// Whether this notification has been seen
@@ -274,6 +754,9 @@ pub struct Notification {
     pub post: Option<Post>,
 }
 
+/// What a saved search's `uri` starts with; the rest is the query as typed.
+const SAVED_SEARCH_PREFIX: &str = "saved-search:";
+
 impl SavedFeed {
     /// Create the default "Following" (home timeline) feed
     pub fn home() -> Self {
@@ -290,6 +773,65 @@ impl SavedFeed {
     pub fn is_home(&self) -> bool {
         self.feed_type == "timeline" || self.uri.is_empty()
     }
+
+    /// The feed selector's share of the saved feeds: Following first,
+    /// since it is the app's home whatever the account says, then the
+    /// pinned feeds in the account's order.
+    pub fn selector_feeds(saved: &[SavedFeed]) -> Vec<SavedFeed> {
+        let mut feeds = vec![SavedFeed::home()];
+        feeds.extend(saved.iter().filter(|f| f.pinned && !f.is_home()).cloned());
+        feeds
+    }
+
+    /// A search kept from the Search tab, listed and polled like a feed.
+    /// Its `uri` is also its cache key, apart from the Search tab's own.
+    /// A hashtag pinned from its page is a search for the tag alone, and
+    /// is named the way it is written in posts.
+    pub fn saved_search(query: &str) -> Self {
+        let (display_name, description) = match query
+            .strip_prefix("tag:")
+            .filter(|tag| !tag.contains(char::is_whitespace))
+        {
+            Some(tag) => (format!("#{tag}"), "Hashtag"),
+            None => (query.to_string(), "Saved search"),
+        };
+        Self {
+            feed_type: "search".to_string(),
+            uri: format!("{SAVED_SEARCH_PREFIX}{query}"),
+            display_name,
+            description: Some(description.to_string()),
+            pinned: true,
+        }
+    }
+
+    /// A list's feed, as it sits in the feed selector once pinned.
+    pub fn list(list: &UserList) -> Self {
+        Self {
+            feed_type: "list".to_string(),
+            uri: list.uri.clone(),
+            display_name: list.name.clone(),
+            description: list.description.clone(),
+            pinned: true,
+        }
+    }
+
+    /// Whether this is a list's feed rather than a generator's
+    pub fn is_list(&self) -> bool {
+        self.feed_type == "list"
+    }
+
+    /// The saved search behind a pinned hashtag.
+    pub fn hashtag(tag: &str) -> Self {
+        Self::saved_search(&format!("tag:{tag}"))
+    }
+
+    /// The query behind a saved search, or `None` for any other feed
+    pub fn search_query(&self) -> Option<&str> {
+        if self.feed_type != "search" {
+            return None;
+        }
+        self.uri.strip_prefix(SAVED_SEARCH_PREFIX)
+    }
 }
 
 // ─── Compose data types (passed from UI → client when creating posts) ───
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Running with the window closed, and starting that way at login.
+//!
+//! With "Run in Background" on, closing the window holds the application
+//! instead of ending it, and a slower poll keeps the desktop notifications
+//! coming on the session already signed in. "Start at Login" writes an XDG
+//! autostart entry that launches with [`FLAG`], which skips the window
+//! entirely until the app is activated again.
+
+use crate::config::APP_ID;
+use std::path::{Path, PathBuf};
+
+/// The command-line switch for a launch with no window.
+pub const FLAG: &str = "--background";
+
+/// Seconds between unread polls while no window is open. The window's own
+/// timer runs every 30; nobody is looking at a badge now, so the only cost
+/// of waiting longer is a notification arriving a little later.
+pub const POLL_SECS: u32 = 120;
+
+/// Pull [`FLAG`] out of the arguments.
+///
+/// `FLAGS_NONE` makes GApplication reject options it was not told about,
+/// so the flag is taken out here and the rest handed to `run_with_args`.
+pub fn split_args(args: impl IntoIterator<Item = String>) -> (bool, Vec<String>) {
+    let mut background = false;
+    let rest = args
+        .into_iter()
+        .filter(|arg| {
+            let ours = arg == FLAG;
+            background |= ours;
+            !ours
+        })
+        .collect();
+    (background, rest)
+}
+
+/// Whether this is a Flatpak sandbox.
+fn in_flatpak() -> bool {
+    Path::new("/.flatpak-info").exists()
+}
+
+/// ~/.config/autostart/<APP_ID>.desktop on the host.
+///
+/// Inside Flatpak `dirs::config_dir` is the app's private config, which
+/// the session never reads at login. The manifest grants
+/// `xdg-config/autostart`, which appears at its host path.
+fn autostart_path() -> Option<PathBuf> {
+    let config = if in_flatpak() {
+        dirs::home_dir().map(|home| home.join(".config"))
+    } else {
+        dirs::config_dir()
+    };
+    config.map(|dir| dir.join("autostart").join(format!("{APP_ID}.desktop")))
+}
+
+/// Whether the autostart entry is in place.
+pub fn autostart_enabled() -> bool {
+    autostart_path().is_some_and(|path| path.exists())
+}
+
+/// Write or remove the autostart entry.
+pub fn set_autostart(enabled: bool) -> Result<(), String> {
+    let path = autostart_path().ok_or("Could not determine config directory")?;
+    if enabled {
+        write_entry(&path, &desktop_entry(&exec_command()))
+    } else {
+        remove_entry(&path)
+    }
+}
+
+fn write_entry(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create autostart directory: {e}"))?;
+    }
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write autostart entry: {e}"))
+}
+
+fn remove_entry(path: &Path) -> Result<(), String> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to remove autostart entry: {e}")),
+    }
+}
+
+/// The `Exec` line that relaunches this build, whichever way it was
+/// installed.
+fn exec_command() -> String {
+    // The sandbox path means nothing to the host session.
+    if in_flatpak() {
+        return format!("flatpak run {APP_ID} {FLAG}");
+    }
+    let program = if let Some(appimage) = std::env::var_os("APPIMAGE") {
+        // The mounted binary moves on every run; the image does not.
+        PathBuf::from(appimage)
+    } else {
+        std::env::current_exe().unwrap_or_else(|_| PathBuf::from("hangar"))
+    };
+    format!("{} {FLAG}", quote_exec_arg(&program.to_string_lossy()))
+}
+
+/// Quote one `Exec` argument the way the desktop entry spec asks: double
+/// quotes around anything with a reserved character, and a backslash
+/// before `"`, `` ` ``, `$` and `\` inside them. `%` starts a field code
+/// anywhere on the line, quoted or not, so it is doubled either way.
+fn quote_exec_arg(arg: &str) -> String {
+    const RESERVED: &[char] = &[
+        ' ', '\t', '\n', '"', '\'', '\\', '>', '<', '~', '|', '&', ';', '$', '*', '?', '#', '(',
+        ')', '`',
+    ];
+    let arg = arg.replace('%', "%%");
+    if !arg.is_empty() && !arg.contains(RESERVED) {
+        return arg;
+    }
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+    for c in arg.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn desktop_entry(exec: &str) -> String {
+    format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name={name}\n\
+         Comment=Desktop notifications for Bluesky\n\
+         Exec={exec}\n\
+         Icon={APP_ID}\n\
+         Terminal=false\n\
+         NoDisplay=true\n\
+         X-GNOME-Autostart-enabled=true\n",
+        name = crate::config::APP_NAME,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The flag is taken out wherever it sits, and everything else reaches
+    /// GApplication in order.
+    #[test]
+    fn the_background_flag_is_taken_out_of_the_arguments() {
+        let args = ["hangar", "--background", "--gapplication-service"].map(String::from);
+        let (background, rest) = split_args(args);
+        assert!(background);
+        assert_eq!(rest, vec!["hangar", "--gapplication-service"]);
+
+        let (background, rest) = split_args(["hangar".to_string()]);
+        assert!(!background);
+        assert_eq!(rest, vec!["hangar"]);
+    }
+
+    /// A path with a space or a dollar sign survives the session's
+    /// unquoting; a plain one is left alone.
+    #[test]
+    fn exec_arguments_are_quoted_per_the_desktop_entry_spec() {
+        assert_eq!(quote_exec_arg("/usr/bin/hangar"), "/usr/bin/hangar");
+        assert_eq!(
+            quote_exec_arg("/home/me/My Apps/Hangar.AppImage"),
+            "\"/home/me/My Apps/Hangar.AppImage\""
+        );
+        assert_eq!(quote_exec_arg("/opt/$x/hangar"), "\"/opt/\\$x/hangar\"");
+        assert_eq!(quote_exec_arg("/opt/100%/hangar"), "/opt/100%%/hangar");
+        assert_eq!(quote_exec_arg("/opt/100% x/h"), "\"/opt/100%% x/h\"");
+    }
+
+    /// The entry lands with its Exec line, and removing it twice is not an
+    /// error: the switch may be turned off after the file was deleted by hand.
+    #[test]
+    fn the_autostart_entry_is_written_and_removed() {
+        let dir = std::env::temp_dir().join(format!("hangar-autostart-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("autostart").join("hangar.desktop");
+
+        write_entry(&path, &desktop_entry("/usr/bin/hangar --background"))
+            .expect("writes, creating the directory");
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.starts_with("[Desktop Entry]\n"));
+        assert!(written.contains("\nExec=/usr/bin/hangar --background\n"));
+
+        remove_entry(&path).expect("removes");
+        assert!(!path.exists());
+        remove_entry(&path).expect("a missing entry is already removed");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
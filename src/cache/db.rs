@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: MPL-2.0
 
 use crate::cache::CacheError;
-use crate::cache::schema::{MIGRATION_3, SCHEMA, SCHEMA_VERSION};
+use crate::cache::schema::{
+    MIGRATION_3, MIGRATION_4, MIGRATION_5, MIGRATION_6, MIGRATION_7, MIGRATION_8, SCHEMA,
+    SCHEMA_VERSION,
+};
 use rusqlite::Connection;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
@@ -56,6 +59,18 @@ impl CacheDb {
         }
     }
 
+    /// A throwaway cache with no file behind it, for the other cache modules'
+    /// tests.
+    #[cfg(test)]
+    pub(crate) fn in_memory() -> Self {
+        let conn = Connection::open_in_memory().expect("in-memory cache");
+        Self::migrate(&conn).expect("schema");
+        Self {
+            conn: Arc::new(Mutex::new(conn)),
+            user_did: String::new(),
+        }
+    }
+
     /// One connection, pragmas applied, schema current.
     fn prepare(path: &Path) -> Result<Connection, CacheError> {
         let conn = Connection::open(path)?;
@@ -121,6 +136,21 @@ impl CacheDb {
         if version < 3 {
             tx.execute_batch(MIGRATION_3)?;
         }
+        if version < 4 {
+            tx.execute_batch(MIGRATION_4)?;
+        }
+        if version < 5 {
+            tx.execute_batch(MIGRATION_5)?;
+        }
+        if version < 6 {
+            tx.execute_batch(MIGRATION_6)?;
+        }
+        if version < 7 {
+            tx.execute_batch(MIGRATION_7)?;
+        }
+        if version < 8 {
+            tx.execute_batch(MIGRATION_8)?;
+        }
         tx.execute_batch(&format!("PRAGMA user_version = {}", SCHEMA_VERSION))?;
         tx.commit()?;
         Ok(())
@@ -153,18 +183,22 @@ impl CacheDb {
     }
 
     /// Cleanup old entries with sensible defaults:
-    /// - Feed items: 24 hours (feed order changes constantly)
+    /// - Feed items: 7 days (refreshes merge on top, and a thread or profile
+    ///   read last week should still open offline)
     /// - Orphan posts: 7 days (posts not in any feed)
     /// - Profiles: kept if any post references them
+    /// - Reading positions: 30 days (a feed not opened in a month)
     pub fn cleanup_stale(&self) -> Result<(), CacheError> {
         let conn = self.conn();
         let now = Self::now();
 
-        // Feed items expire after 24 hours; the ordering is stale by then
-        let feed_cutoff = now - (24 * 60 * 60);
+        // Feed items expire after a week. Refreshes merge into what is
+        // cached rather than replacing it, so staleness is not the concern
+        // it was; a view nobody has opened in that long is.
+        let feed_cutoff = now - (7 * 24 * 60 * 60);
         conn.execute("DELETE FROM feed_items WHERE fetched_at < ?", [feed_cutoff])?;
 
-        // Feed state older than 24 hours
+        // Feed state for the same views
         conn.execute(
             "DELETE FROM feed_state WHERE last_refresh_at < ?",
             [feed_cutoff],
@@ -181,6 +215,14 @@ impl CacheDb {
             [post_cutoff],
         )?;
 
+        // A position outlives its feed's cached pages, since the post may
+        // come back with the next refresh, but not by a month.
+        let position_cutoff = now - (30 * 24 * 60 * 60);
+        conn.execute(
+            "DELETE FROM reading_positions WHERE saved_at < ?",
+            [position_cutoff],
+        )?;
+
         // Orphan profiles (no posts reference them) older than 7 days
         conn.execute(
             r#"
@@ -324,6 +366,164 @@ mod tests {
         assert_eq!(rows, 1);
     }
 
+    /// Posts cached before labels were kept stay readable, with no labels
+    /// until the next fetch brings them.
+    #[test]
+    fn version_three_cache_gains_label_columns() {
+        let db = TempDb::new();
+        {
+            let conn = Connection::open(db.path()).expect("open");
+            conn.execute_batch(SCHEMA).expect("schema");
+            conn.execute_batch("PRAGMA user_version = 3")
+                .expect("stamp");
+            conn.execute(
+                "INSERT INTO posts (uri, cid, author_did, text, created_at, indexed_at, fetched_at)
+                 VALUES ('at://did:plc:a/app.bsky.feed.post/1', 'bafy', 'did:plc:a', 'hi',
+                         '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z', 0)",
+                [],
+            )
+            .expect("insert");
+        }
+
+        let conn = CacheDb::open_resilient(db.path()).expect("open");
+        assert_eq!(version(&conn), SCHEMA_VERSION);
+        let (labels, author_labels): (Option<String>, Option<String>) = conn
+            .query_row(
+                "SELECT labels_json, author_labels_json FROM posts",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .expect("the post survived with the new columns");
+        assert_eq!((labels, author_labels), (None, None));
+    }
+
+    /// A feed cached before gaps were recorded keeps its state and reads
+    /// back with none.
+    #[test]
+    fn version_four_cache_gains_feed_gaps() {
+        let db = TempDb::new();
+        {
+            let conn = Connection::open(db.path()).expect("open");
+            conn.execute_batch(SCHEMA).expect("schema");
+            conn.execute_batch(MIGRATION_4).expect("labels");
+            conn.execute_batch("PRAGMA user_version = 4")
+                .expect("stamp");
+            conn.execute(
+                "INSERT INTO feed_state (feed_key, oldest_cursor, has_more) VALUES ('home', 'c', 1)",
+                [],
+            )
+            .expect("insert");
+        }
+
+        let conn = CacheDb::open_resilient(db.path()).expect("open");
+        assert_eq!(version(&conn), SCHEMA_VERSION);
+        let (cursor, gaps): (Option<String>, Option<String>) = conn
+            .query_row(
+                "SELECT oldest_cursor, gaps_json FROM feed_state",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .expect("the state survived with the new column");
+        assert_eq!((cursor.as_deref(), gaps), (Some("c"), None));
+    }
+
+    /// A version five cache keeps its feeds and gains somewhere to keep
+    /// the reading positions.
+    #[test]
+    fn version_five_cache_gains_reading_positions() {
+        let db = TempDb::new();
+        {
+            let conn = Connection::open(db.path()).expect("open");
+            conn.execute_batch(SCHEMA).expect("schema");
+            conn.execute_batch(MIGRATION_4).expect("labels");
+            conn.execute_batch(MIGRATION_5).expect("gaps");
+            conn.execute_batch("PRAGMA user_version = 5")
+                .expect("stamp");
+            conn.execute(
+                "INSERT INTO feed_state (feed_key, has_more) VALUES ('home', 1)",
+                [],
+            )
+            .expect("insert");
+        }
+
+        let conn = CacheDb::open_resilient(db.path()).expect("open");
+        assert_eq!(version(&conn), SCHEMA_VERSION);
+        assert!(table_exists(&conn, "reading_positions"));
+        let rows: i64 = conn
+            .query_row("SELECT count(*) FROM feed_state", [], |row| row.get(0))
+            .expect("count");
+        assert_eq!(rows, 1);
+    }
+
+    /// Posts cached before the History search existed are searchable the
+    /// moment the cache is opened, alt text included.
+    #[test]
+    fn version_six_cache_indexes_the_posts_it_has() {
+        let db = TempDb::new();
+        {
+            let conn = Connection::open(db.path()).expect("open");
+            conn.execute_batch(SCHEMA).expect("schema");
+            for step in [MIGRATION_4, MIGRATION_5, MIGRATION_6] {
+                conn.execute_batch(step).expect("earlier steps");
+            }
+            conn.execute_batch("PRAGMA user_version = 6")
+                .expect("stamp");
+            conn.execute(
+                r#"INSERT INTO posts (uri, cid, author_did, text, created_at, indexed_at,
+                                      embed_json, fetched_at)
+                   VALUES ('at://did:plc:a/app.bsky.feed.post/1', 'bafy', 'did:plc:a', 'hello',
+                           '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z',
+                           '{"Images":[{"thumb":"t","fullsize":"f","alt":"a heron",
+                             "aspect_ratio":null}]}', 0)"#,
+                [],
+            )
+            .expect("insert");
+        }
+
+        let conn = CacheDb::open_resilient(db.path()).expect("open");
+        assert_eq!(version(&conn), SCHEMA_VERSION);
+        let hits: i64 = conn
+            .query_row(
+                "SELECT count(*) FROM posts_fts WHERE posts_fts MATCH 'heron'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("the index answers");
+        assert_eq!(hits, 1);
+    }
+
+    /// Posts cached before facets were kept stay readable, with none until
+    /// the next fetch; the renderer finds their links itself meanwhile.
+    #[test]
+    fn version_seven_cache_gains_post_facets() {
+        let db = TempDb::new();
+        {
+            let conn = Connection::open(db.path()).expect("open");
+            conn.execute_batch(SCHEMA).expect("schema");
+            for step in [MIGRATION_4, MIGRATION_5, MIGRATION_6, MIGRATION_7] {
+                conn.execute_batch(step).expect("earlier steps");
+            }
+            conn.execute_batch("PRAGMA user_version = 7")
+                .expect("stamp");
+            conn.execute(
+                "INSERT INTO posts (uri, cid, author_did, text, created_at, indexed_at, fetched_at)
+                 VALUES ('at://did:plc:a/app.bsky.feed.post/1', 'bafy', 'did:plc:a', 'hi',
+                         '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z', 0)",
+                [],
+            )
+            .expect("insert");
+        }
+
+        let conn = CacheDb::open_resilient(db.path()).expect("open");
+        assert_eq!(version(&conn), SCHEMA_VERSION);
+        let (text, facets): (String, Option<String>) = conn
+            .query_row("SELECT text, facets_json FROM posts", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .expect("the post survived with the new column");
+        assert_eq!((text.as_str(), facets), ("hi", None));
+    }
+
     #[test]
     fn corrupt_file_is_rebuilt() {
         let db = TempDb::new();
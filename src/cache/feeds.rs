@@ -1,13 +1,39 @@
 // SPDX-License-Identifier: MPL-2.0
 
-use crate::atproto::{Embed, Post, Profile, ReplyContext, RepostReason};
+use crate::atproto::{Embed, Facet, Label, Post, Profile, ReplyContext, RepostReason};
 use crate::cache::{CacheDb, CacheError, PostCache};
 use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Feed key for the home timeline
-#[allow(dead_code)]
 pub const FEED_HOME: &str = "home";
 
+/// Feed key for the signed-in account's likes
+pub const FEED_LIKES: &str = "likes";
+
+/// Feed key for the signed-in account's saved posts
+pub const FEED_BOOKMARKS: &str = "bookmarks";
+
+/// Posts one press of a gap row loads: the page the timeline and feed
+/// endpoints serve when no limit is asked for
+pub const GAP_FILL_SIZE: usize = 50;
+
+/// Feed key for a thread, the focused post and everything around it
+pub fn thread_key(uri: &str) -> String {
+    format!("thread:{uri}")
+}
+
+/// Feed key for one filter of an author's feed, which is one profile tab
+pub fn author_key(did: &str, filter: &str) -> String {
+    format!("author:{did}:{filter}")
+}
+
+/// Feed key for the post results of a search
+pub fn search_key(query: &str) -> String {
+    format!("search:{}", query.trim())
+}
+
 /// State of a feed's pagination and anchor
 #[derive(Debug, Clone, Default)]
 pub struct FeedState {
@@ -21,6 +47,82 @@ pub struct FeedState {
     pub newest_sort_timestamp: Option<String>,
     /// When we last refreshed this feed
     pub last_refresh_at: Option<i64>,
+    /// Holes left by refreshes that never reached the cached head, top to
+    /// bottom
+    pub gaps: Vec<FeedGap>,
+}
+
+/// Posts missing from a cached feed: everything between `boundary_uri` and
+/// the next cached post below it. `cursor` carries on from the boundary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedGap {
+    pub boundary_uri: String,
+    pub cursor: String,
+}
+
+/// Where the reader left a feed: the post at the top of the view, and how
+/// far its top edge sat from the view's, in pixels
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadingPosition {
+    pub post_uri: String,
+    pub offset: f64,
+}
+
+/// The cached side below a gap, which a fill is trying to reach
+#[derive(Debug, Clone, Default)]
+pub struct GapEdge {
+    /// Every post cached below the gap
+    uris: HashSet<String>,
+    /// Sort timestamp of the first of them
+    timestamp: Option<String>,
+}
+
+impl GapEdge {
+    /// Where a page fetched into the gap meets the cached posts below it:
+    /// the first post already cached there, or no newer than the first of
+    /// them. `None` means the whole page belongs in the gap.
+    pub fn meets(&self, posts: &[Post]) -> Option<usize> {
+        posts.iter().position(|post| {
+            self.uris.contains(&post.uri)
+                || self
+                    .timestamp
+                    .as_deref()
+                    .is_some_and(|edge| at_or_before(&sort_timestamp(post), edge))
+        })
+    }
+}
+
+/// How a freshly fetched first page landed on what was cached
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TopMerge {
+    /// Nothing was cached; the page is the whole feed now
+    Fresh,
+    /// The page reached back to the cached head. Its new posts went on top
+    /// and every cached page below stays, along with the older cursor.
+    Joined,
+    /// The page never met the cached head. It went on top all the same,
+    /// with this gap recorded between it and the posts cached before.
+    Gapped(FeedGap),
+}
+
+/// The timestamp a feed item sorts by: the repost time for a repost
+fn sort_timestamp(post: &Post) -> String {
+    post.repost_reason
+        .as_ref()
+        .map(|r| r.indexed_at.clone())
+        .unwrap_or_else(|| post.indexed_at.clone())
+}
+
+/// Whether `timestamp` is no newer than `anchor`. Both are RFC 3339, but the
+/// precision varies from record to record, so they are compared as times.
+fn at_or_before(timestamp: &str, anchor: &str) -> bool {
+    match (
+        chrono::DateTime::parse_from_rfc3339(timestamp),
+        chrono::DateTime::parse_from_rfc3339(anchor),
+    ) {
+        (Ok(timestamp), Ok(anchor)) => timestamp <= anchor,
+        _ => false,
+    }
 }
 
 /// Cache operations for feeds
@@ -52,12 +154,7 @@ impl<'a> FeedCache<'a> {
 
         for (i, post) in posts.iter().enumerate() {
             let position = start_position + i as i64;
-            // Use indexed_at as sort timestamp, or repost time if this is a repost
-            let sort_timestamp = post
-                .repost_reason
-                .as_ref()
-                .map(|r| r.indexed_at.clone())
-                .unwrap_or_else(|| post.indexed_at.clone());
+            let sort_timestamp = sort_timestamp(post);
 
             tx.execute(
                 r#"
@@ -92,7 +189,8 @@ impl<'a> FeedCache<'a> {
                 p.like_count, p.repost_count, p.reply_count,
                 p.embed_json, p.repost_reason_json, p.reply_context_json,
                 p.viewer_like, p.viewer_repost,
-                pr.handle, pr.display_name, pr.avatar
+                pr.handle, pr.display_name, pr.avatar,
+                p.labels_json, p.author_labels_json, p.facets_json
             FROM feed_items fi
             JOIN posts p ON fi.post_uri = p.uri
             LEFT JOIN profiles pr ON p.author_did = pr.did
@@ -112,13 +210,303 @@ impl<'a> FeedCache<'a> {
         Ok(posts)
     }
 
+    /// Every cached post of a feed, top to bottom
+    pub fn get_all(&self, feed_key: &str) -> Result<Vec<Post>, CacheError> {
+        // No cap: a feed only ever holds as much as the reader scrolled
+        // through. The shift keeps the limit within SQLite's i64.
+        self.get_page(feed_key, 0, usize::MAX >> 1)
+    }
+
+    /// Replace a feed with a single first page and its cursor
+    pub fn replace_page(
+        &self,
+        feed_key: &str,
+        posts: &[Post],
+        cursor: Option<String>,
+    ) -> Result<(), CacheError> {
+        self.clear_feed(feed_key)?;
+        self.store_page(feed_key, posts, 0)?;
+        self.set_state(
+            feed_key,
+            &FeedState {
+                has_more: cursor.is_some(),
+                oldest_cursor: cursor,
+                newest_post_uri: posts.first().map(|p| p.uri.clone()),
+                newest_sort_timestamp: posts.first().map(sort_timestamp),
+                last_refresh_at: Some(CacheDb::now()),
+                gaps: Vec::new(),
+            },
+        )
+    }
+
+    /// Add an older page below what is cached, moving the cursor past it
+    pub fn extend_page(
+        &self,
+        feed_key: &str,
+        posts: &[Post],
+        cursor: Option<String>,
+    ) -> Result<(), CacheError> {
+        let start = self.next_position(feed_key)?;
+        self.store_page(feed_key, posts, start)?;
+        let mut state = self.get_state(feed_key)?;
+        state.has_more = cursor.is_some();
+        state.oldest_cursor = cursor;
+        self.set_state(feed_key, &state)
+    }
+
+    /// Fold a freshly fetched first page into the cached feed.
+    ///
+    /// The page meets the cache at the first post already cached, or at the
+    /// first post no newer than the cached anchor when that one was
+    /// deleted. Everything above goes on top; everything cached stays where
+    /// it is, so older pages and the cursor past them survive a refresh.
+    /// Posts below the meeting point not cached yet are slotted in among
+    /// the cached ones by time rather than left out. A
+    /// page that never meets the cache goes on top too, and `cursor`, its
+    /// own, is kept with the gap left below it.
+    ///
+    /// A feed that is not `chronological` (a custom feed's ranking, a
+    /// search for top posts) has no place to meet by time, and a post
+    /// below one already cached can still be new. Every post of its page
+    /// not cached yet goes on top, as the window puts them, and a page
+    /// sharing nothing with the cache replaces it.
+    pub fn merge_top(
+        &self,
+        feed_key: &str,
+        posts: &[Post],
+        cursor: Option<String>,
+        chronological: bool,
+    ) -> Result<TopMerge, CacheError> {
+        let cached = self.uris(feed_key)?;
+        if cached.is_empty() {
+            self.replace_page(feed_key, posts, cursor)?;
+            return Ok(TopMerge::Fresh);
+        }
+        if posts.is_empty() {
+            return Ok(TopMerge::Joined);
+        }
+
+        let mut state = self.get_state(feed_key)?;
+        let anchor = state.newest_sort_timestamp.clone();
+        let meets = posts.iter().position(|post| {
+            cached.contains(&post.uri)
+                || (chronological
+                    && anchor
+                        .as_deref()
+                        .is_some_and(|anchor| at_or_before(&sort_timestamp(post), anchor)))
+        });
+        let (fresh_end, gap) = match (meets, cursor, posts.last()) {
+            (Some(_), _, _) if !chronological => (posts.len(), None),
+            (Some(meets), _, _) => (meets, None),
+            (None, cursor, _) if !chronological => {
+                self.replace_page(feed_key, posts, cursor)?;
+                return Ok(TopMerge::Fresh);
+            }
+            (None, Some(cursor), Some(last)) => (
+                posts.len(),
+                Some(FeedGap {
+                    boundary_uri: last.uri.clone(),
+                    cursor,
+                }),
+            ),
+            // The feed ended inside this page, so the page is all of it.
+            (None, cursor, _) => {
+                self.replace_page(feed_key, posts, cursor)?;
+                return Ok(TopMerge::Fresh);
+            }
+        };
+
+        // Known posts are stored again for their fresh counts and viewer
+        // state; only the ones above the meeting point get new rows.
+        PostCache::new(self.db).store_batch(posts)?;
+        let fresh: Vec<Post> = posts[..fresh_end]
+            .iter()
+            .filter(|post| !cached.contains(&post.uri))
+            .cloned()
+            .collect();
+        let top = self.first_position(feed_key)?;
+        self.store_page(feed_key, &fresh, top - fresh.len() as i64)?;
+        for post in posts[fresh_end..]
+            .iter()
+            .filter(|post| !cached.contains(&post.uri))
+        {
+            self.insert_by_time(feed_key, post)?;
+        }
+
+        // The whole feed was just confirmed, so none of it is stale yet.
+        self.db.conn().execute(
+            "UPDATE feed_items SET fetched_at = ? WHERE feed_key = ?",
+            params![CacheDb::now(), feed_key],
+        )?;
+
+        if let Some(first) = posts.first() {
+            state.newest_post_uri = Some(first.uri.clone());
+            state.newest_sort_timestamp = Some(sort_timestamp(first));
+        }
+        state.last_refresh_at = Some(CacheDb::now());
+        if let Some(gap) = &gap {
+            state.gaps.insert(0, gap.clone());
+        }
+        self.set_state(feed_key, &state)?;
+        Ok(match gap {
+            Some(gap) => TopMerge::Gapped(gap),
+            None => TopMerge::Joined,
+        })
+    }
+
+    /// Put `post` above the first cached post no newer than it, or at the
+    /// bottom when every cached post is newer.
+    fn insert_by_time(&self, feed_key: &str, post: &Post) -> Result<(), CacheError> {
+        let timestamp = sort_timestamp(post);
+        let below = {
+            let conn = self.db.conn();
+            let mut stmt = conn.prepare(
+                r#"
+                SELECT position, sort_timestamp FROM feed_items
+                WHERE feed_key = ?
+                ORDER BY position ASC
+                "#,
+            )?;
+            let rows = stmt
+                .query_map([feed_key], |row| {
+                    Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            rows.into_iter()
+                .find(|(_, cached)| at_or_before(cached, &timestamp))
+                .map(|(position, _)| position)
+        };
+        let position = match below {
+            Some(position) => {
+                self.db.conn().execute(
+                    "UPDATE feed_items SET position = position + 1 WHERE feed_key = ? AND position >= ?",
+                    params![feed_key, position],
+                )?;
+                position
+            }
+            None => self.next_position(feed_key)?,
+        };
+        self.store_page(feed_key, std::slice::from_ref(post), position)
+    }
+
+    /// What a fill of `gap` has to reach: the posts cached below it
+    pub fn gap_edge(&self, feed_key: &str, gap: &FeedGap) -> Result<GapEdge, CacheError> {
+        let boundary = self.position_of(feed_key, &gap.boundary_uri)?;
+        let conn = self.db.conn();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT post_uri, sort_timestamp FROM feed_items
+            WHERE feed_key = ? AND position > ?
+            ORDER BY position ASC
+            "#,
+        )?;
+        let mut rows = stmt.query(params![feed_key, boundary])?;
+        let mut edge = GapEdge::default();
+        while let Some(row) = rows.next()? {
+            edge.uris.insert(row.get(0)?);
+            if edge.timestamp.is_none() {
+                edge.timestamp = Some(row.get(1)?);
+            }
+        }
+        Ok(edge)
+    }
+
+    /// Put `posts` into `gap`, directly below its boundary, and leave `rest`
+    /// in its place: the part still missing, or `None` once it is closed.
+    pub fn fill_gap(
+        &self,
+        feed_key: &str,
+        gap: &FeedGap,
+        posts: &[Post],
+        rest: Option<FeedGap>,
+    ) -> Result<(), CacheError> {
+        let boundary = self.position_of(feed_key, &gap.boundary_uri)?;
+        let cached = self.uris(feed_key)?;
+        let fresh: Vec<Post> = posts
+            .iter()
+            .filter(|post| !cached.contains(&post.uri))
+            .cloned()
+            .collect();
+
+        // Positions need not be unique, but order has to hold: make room
+        // under the boundary rather than renumber the whole feed.
+        self.db.conn().execute(
+            "UPDATE feed_items SET position = position + ? WHERE feed_key = ? AND position > ?",
+            params![fresh.len() as i64, feed_key, boundary],
+        )?;
+        self.store_page(feed_key, &fresh, boundary + 1)?;
+
+        let mut state = self.get_state(feed_key)?;
+        match state
+            .gaps
+            .iter()
+            .position(|g| g.boundary_uri == gap.boundary_uri)
+        {
+            Some(i) => match rest {
+                Some(rest) => state.gaps[i] = rest,
+                None => {
+                    state.gaps.remove(i);
+                }
+            },
+            None => state.gaps.extend(rest),
+        }
+        self.set_state(feed_key, &state)
+    }
+
+    /// Where a post sits in a feed
+    fn position_of(&self, feed_key: &str, uri: &str) -> Result<i64, CacheError> {
+        let conn = self.db.conn();
+        conn.query_row(
+            "SELECT position FROM feed_items WHERE feed_key = ? AND post_uri = ?",
+            params![feed_key, uri],
+            |row| row.get(0),
+        )
+        .map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => CacheError::NotFound,
+            other => CacheError::Database(other),
+        })
+    }
+
+    /// The position just below the last cached post
+    pub fn next_position(&self, feed_key: &str) -> Result<i64, CacheError> {
+        let conn = self.db.conn();
+        let next = conn.query_row(
+            "SELECT COALESCE(MAX(position) + 1, 0) FROM feed_items WHERE feed_key = ?",
+            [feed_key],
+            |row| row.get(0),
+        )?;
+        Ok(next)
+    }
+
+    /// The position of the top cached post
+    fn first_position(&self, feed_key: &str) -> Result<i64, CacheError> {
+        let conn = self.db.conn();
+        let first = conn.query_row(
+            "SELECT COALESCE(MIN(position), 0) FROM feed_items WHERE feed_key = ?",
+            [feed_key],
+            |row| row.get(0),
+        )?;
+        Ok(first)
+    }
+
+    /// The URIs a feed holds, in no particular order
+    fn uris(&self, feed_key: &str) -> Result<HashSet<String>, CacheError> {
+        let conn = self.db.conn();
+        let mut stmt = conn.prepare("SELECT post_uri FROM feed_items WHERE feed_key = ?")?;
+        let uris = stmt
+            .query_map([feed_key], |row| row.get(0))?
+            .collect::<Result<HashSet<String>, _>>()?;
+        Ok(uris)
+    }
+
     /// Get feed state (cursor, anchor)
     pub fn get_state(&self, feed_key: &str) -> Result<FeedState, CacheError> {
         let conn = self.db.conn();
 
         let mut stmt = conn.prepare(
             r#"
-            SELECT oldest_cursor, has_more, newest_post_uri, newest_sort_timestamp, last_refresh_at
+            SELECT oldest_cursor, has_more, newest_post_uri, newest_sort_timestamp, last_refresh_at,
+                gaps_json
             FROM feed_state
             WHERE feed_key = ?
             "#,
@@ -132,6 +520,10 @@ impl<'a> FeedCache<'a> {
                     newest_post_uri: row.get(2)?,
                     newest_sort_timestamp: row.get(3)?,
                     last_refresh_at: row.get(4)?,
+                    gaps: row
+                        .get::<_, Option<String>>(5)?
+                        .and_then(|j| serde_json::from_str(&j).ok())
+                        .unwrap_or_default(),
                 })
             })
             .unwrap_or_default();
@@ -146,14 +538,16 @@ impl<'a> FeedCache<'a> {
         conn.execute(
             r#"
             INSERT INTO feed_state (
-                feed_key, oldest_cursor, has_more, newest_post_uri, newest_sort_timestamp, last_refresh_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                feed_key, oldest_cursor, has_more, newest_post_uri, newest_sort_timestamp,
+                last_refresh_at, gaps_json
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
             ON CONFLICT(feed_key) DO UPDATE SET
                 oldest_cursor = excluded.oldest_cursor,
                 has_more = excluded.has_more,
                 newest_post_uri = excluded.newest_post_uri,
                 newest_sort_timestamp = excluded.newest_sort_timestamp,
-                last_refresh_at = excluded.last_refresh_at
+                last_refresh_at = excluded.last_refresh_at,
+                gaps_json = excluded.gaps_json
             "#,
             params![
                 feed_key,
@@ -162,33 +556,61 @@ impl<'a> FeedCache<'a> {
                 state.newest_post_uri,
                 state.newest_sort_timestamp,
                 state.last_refresh_at,
+                serde_json::to_string(&state.gaps)?,
             ],
         )?;
 
         Ok(())
     }
 
-    /// Clear a feed (on switch or full refresh)
-    pub fn clear_feed(&self, feed_key: &str) -> Result<(), CacheError> {
+    /// Where the reader left a feed, if anywhere
+    pub fn reading_position(&self, feed_key: &str) -> Result<Option<ReadingPosition>, CacheError> {
         let conn = self.db.conn();
+        let position = conn
+            .query_row(
+                "SELECT post_uri, pixel_offset FROM reading_positions WHERE feed_key = ?",
+                [feed_key],
+                |row| {
+                    Ok(ReadingPosition {
+                        post_uri: row.get(0)?,
+                        offset: row.get(1)?,
+                    })
+                },
+            )
+            .ok();
+        Ok(position)
+    }
 
-        conn.execute("DELETE FROM feed_items WHERE feed_key = ?", [feed_key])?;
-        conn.execute("DELETE FROM feed_state WHERE feed_key = ?", [feed_key])?;
-
+    /// Remember where the reader is in a feed. Clearing the feed leaves it
+    /// be: the post may well come back with the next page.
+    pub fn set_reading_position(
+        &self,
+        feed_key: &str,
+        position: &ReadingPosition,
+    ) -> Result<(), CacheError> {
+        let conn = self.db.conn();
+        conn.execute(
+            r#"
+            INSERT INTO reading_positions (feed_key, post_uri, pixel_offset, saved_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(feed_key) DO UPDATE SET
+                post_uri = excluded.post_uri,
+                pixel_offset = excluded.pixel_offset,
+                saved_at = excluded.saved_at
+            "#,
+            params![feed_key, position.post_uri, position.offset, CacheDb::now()],
+        )?;
         Ok(())
     }
 
-    /// Get count of cached items for feed
-    pub fn count(&self, feed_key: &str) -> Result<usize, CacheError> {
+    /// Clear a feed (on switch or full refresh)
+    pub fn clear_feed(&self, feed_key: &str) -> Result<(), CacheError> {
         let conn = self.db.conn();
 
-        let count: i64 = conn.query_row(
-            "SELECT COUNT(*) FROM feed_items WHERE feed_key = ?",
-            [feed_key],
-            |row| row.get(0),
-        )?;
+        conn.execute("DELETE FROM feed_items WHERE feed_key = ?", [feed_key])?;
+        conn.execute("DELETE FROM feed_state WHERE feed_key = ?", [feed_key])?;
 
-        Ok(count as usize)
+        Ok(())
     }
 
     /// Check if the feed was refreshed recently
@@ -208,6 +630,20 @@ impl<'a> FeedCache<'a> {
         let embed_json: Option<String> = row.get(9)?;
         let repost_reason_json: Option<String> = row.get(10)?;
         let reply_context_json: Option<String> = row.get(11)?;
+        // Columns 17 and 18 came with schema 4; older rows hold NULL.
+        let labels: Vec<Label> = row
+            .get::<_, Option<String>>(17)?
+            .and_then(|j| serde_json::from_str(j.as_str()).ok())
+            .unwrap_or_default();
+        let author_labels: Vec<Label> = row
+            .get::<_, Option<String>>(18)?
+            .and_then(|j| serde_json::from_str(j.as_str()).ok())
+            .unwrap_or_default();
+        // Column 19 came with schema 8.
+        let facets: Vec<Facet> = row
+            .get::<_, Option<String>>(19)?
+            .and_then(|j| serde_json::from_str(j.as_str()).ok())
+            .unwrap_or_default();
 
         let embed: Option<Embed> = embed_json
             .as_ref()
@@ -238,6 +674,9 @@ impl<'a> FeedCache<'a> {
                 viewer_muted: false,
                 viewer_blocking: None,
                 viewer_blocked_by: false,
+                labels: author_labels,
+                muted_by_list: None,
+                blocking_by_list: None,
             },
             text: row.get(3)?,
             created_at: row.get(4)?,
@@ -252,6 +691,278 @@ impl<'a> FeedCache<'a> {
             viewer_bookmarked: None,
             repost_reason,
             reply_context,
+            labels,
+            feed_context: None,
+            facets,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Post `n`, indexed `n` minutes past midnight so higher is newer.
+    fn post(n: u32) -> Post {
+        Post {
+            uri: format!("at://did:plc:a/app.bsky.feed.post/{n}"),
+            cid: "cid".into(),
+            author: Profile::minimal("did:plc:a".into(), "a.bsky.social".into(), None, None),
+            text: format!("post {n}"),
+            created_at: format!("2026-01-01T00:{n:02}:00Z"),
+            indexed_at: format!("2026-01-01T00:{n:02}:00.000Z"),
+            like_count: None,
+            repost_count: None,
+            reply_count: None,
+            embed: None,
+            viewer_like: None,
+            viewer_repost: None,
+            viewer_bookmarked: None,
+            repost_reason: None,
+            reply_context: None,
+            labels: Vec::new(),
+            feed_context: None,
+            facets: Vec::new(),
+        }
+    }
+
+    /// Newest first, the way every feed endpoint answers.
+    fn page(numbers: &[u32]) -> Vec<Post> {
+        numbers.iter().map(|&n| post(n)).collect()
+    }
+
+    fn numbers(posts: &[Post]) -> Vec<u32> {
+        posts
+            .iter()
+            .map(|p| p.uri.rsplit('/').next().unwrap().parse().unwrap())
+            .collect()
+    }
+
+    /// A refresh that overlaps the cache puts only its new posts on top.
+    /// The older page scrolled into and the cursor past it both survive,
+    /// which the old clear-and-replace threw away.
+    #[test]
+    fn a_refresh_joins_the_cached_feed_on_top() {
+        let db = CacheDb::in_memory();
+        let feeds = FeedCache::new(&db);
+        feeds
+            .replace_page(FEED_HOME, &page(&[30, 29, 28]), Some("c1".into()))
+            .unwrap();
+        feeds
+            .extend_page(FEED_HOME, &page(&[27, 26]), Some("c2".into()))
+            .unwrap();
+
+        let merged = feeds
+            .merge_top(
+                FEED_HOME,
+                &page(&[32, 31, 30, 29]),
+                Some("fresh".into()),
+                true,
+            )
+            .unwrap();
+        assert_eq!(merged, TopMerge::Joined);
+        assert_eq!(
+            numbers(&feeds.get_all(FEED_HOME).unwrap()),
+            [32, 31, 30, 29, 28, 27, 26]
+        );
+
+        let state = feeds.get_state(FEED_HOME).unwrap();
+        assert_eq!(state.oldest_cursor.as_deref(), Some("c2"));
+        assert_eq!(state.newest_post_uri, Some(post(32).uri));
+    }
+
+    /// A cached feed links what the record linked. Before facets were
+    /// kept, a shortened link came back from the cache pointing at its
+    /// visible text.
+    #[test]
+    fn cached_posts_keep_their_facets() {
+        let db = CacheDb::in_memory();
+        let feeds = FeedCache::new(&db);
+        let mut linked = post(1);
+        linked.text = "see example.com/very-lo…".into();
+        linked.facets = vec![Facet {
+            byte_start: 4,
+            byte_end: linked.text.len(),
+            feature: crate::atproto::FacetFeature::Link(
+                "https://example.com/very-long-path".into(),
+            ),
+        }];
+        feeds
+            .replace_page(FEED_HOME, &[linked.clone(), post(0)], None)
+            .unwrap();
+
+        let cached = feeds.get_all(FEED_HOME).unwrap();
+        assert_eq!(cached[0].facets, linked.facets);
+        assert!(cached[1].facets.is_empty());
+    }
+
+    /// A deleted anchor post must not read as a gap: the page still meets
+    /// the cache at the first post no newer than the anchor's time, and the
+    /// uncached post below that point is slotted in by time, not dropped.
+    #[test]
+    fn a_refresh_meets_the_cache_by_time_when_the_anchor_is_gone() {
+        let db = CacheDb::in_memory();
+        let feeds = FeedCache::new(&db);
+        feeds
+            .replace_page(FEED_HOME, &page(&[30, 28]), Some("c1".into()))
+            .unwrap();
+
+        let merged = feeds
+            .merge_top(FEED_HOME, &page(&[31, 29]), None, true)
+            .unwrap();
+        assert_eq!(merged, TopMerge::Joined);
+        assert_eq!(
+            numbers(&feeds.get_all(FEED_HOME).unwrap()),
+            [31, 30, 29, 28]
+        );
+    }
+
+    /// A ranked feed serves older posts above newer ones. Meeting it by
+    /// time cut the page at the first older post and left everything under
+    /// it out of the cached feed, though the window showed it.
+    #[test]
+    fn a_ranked_refresh_keeps_every_new_post() {
+        let db = CacheDb::in_memory();
+        let feeds = FeedCache::new(&db);
+        let key = "at://did:plc:f/app.bsky.feed.generator/hot";
+        feeds
+            .replace_page(key, &page(&[30, 20]), Some("c1".into()))
+            .unwrap();
+
+        let merged = feeds
+            .merge_top(key, &page(&[25, 10, 30, 40]), Some("c2".into()), false)
+            .unwrap();
+        assert_eq!(merged, TopMerge::Joined);
+        assert_eq!(numbers(&feeds.get_all(key).unwrap()), [25, 10, 40, 30, 20]);
+
+        // Nothing in common is a new ranking, not a gap.
+        let merged = feeds
+            .merge_top(key, &page(&[5, 6]), Some("c3".into()), false)
+            .unwrap();
+        assert_eq!(merged, TopMerge::Fresh);
+        assert_eq!(numbers(&feeds.get_all(key).unwrap()), [5, 6]);
+    }
+
+    /// A page that never reaches the cache goes on top of it with a gap
+    /// below, keeping the older cursor; an empty cache simply takes the
+    /// page.
+    #[test]
+    fn a_refresh_that_misses_the_cache_leaves_a_gap() {
+        let db = CacheDb::in_memory();
+        let feeds = FeedCache::new(&db);
+        assert_eq!(
+            feeds
+                .merge_top(FEED_HOME, &page(&[10, 9]), Some("old".into()), true)
+                .unwrap(),
+            TopMerge::Fresh
+        );
+
+        let merged = feeds
+            .merge_top(FEED_HOME, &page(&[50, 49]), Some("gap".into()), true)
+            .unwrap();
+        let gap = FeedGap {
+            boundary_uri: post(49).uri,
+            cursor: "gap".into(),
+        };
+        assert_eq!(merged, TopMerge::Gapped(gap.clone()));
+        assert_eq!(numbers(&feeds.get_all(FEED_HOME).unwrap()), [50, 49, 10, 9]);
+
+        let state = feeds.get_state(FEED_HOME).unwrap();
+        assert_eq!(state.oldest_cursor.as_deref(), Some("old"));
+        assert_eq!(state.gaps, [gap]);
+    }
+
+    /// Filling from the top moves the gap down under what came in; the page
+    /// that reaches the posts below closes it.
+    #[test]
+    fn a_gap_fills_down_until_it_meets_the_posts_below() {
+        let db = CacheDb::in_memory();
+        let feeds = FeedCache::new(&db);
+        feeds
+            .replace_page(FEED_HOME, &page(&[10, 9]), None)
+            .unwrap();
+        let TopMerge::Gapped(gap) = feeds
+            .merge_top(FEED_HOME, &page(&[50, 49]), Some("c49".into()), true)
+            .unwrap()
+        else {
+            panic!("no gap");
+        };
+
+        let edge = feeds.gap_edge(FEED_HOME, &gap).unwrap();
+        let first = page(&[48, 47]);
+        assert_eq!(edge.meets(&first), None);
+        let rest = FeedGap {
+            boundary_uri: post(47).uri,
+            cursor: "c47".into(),
+        };
+        feeds
+            .fill_gap(FEED_HOME, &gap, &first, Some(rest.clone()))
+            .unwrap();
+        assert_eq!(
+            numbers(&feeds.get_all(FEED_HOME).unwrap()),
+            [50, 49, 48, 47, 10, 9]
+        );
+        assert_eq!(feeds.get_state(FEED_HOME).unwrap().gaps, [rest.clone()]);
+
+        let second = page(&[46, 11, 10, 9]);
+        let meets = feeds.gap_edge(FEED_HOME, &rest).unwrap().meets(&second);
+        assert_eq!(meets, Some(2), "post 10 is already cached below");
+        feeds
+            .fill_gap(FEED_HOME, &rest, &second[..2], None)
+            .unwrap();
+        assert_eq!(
+            numbers(&feeds.get_all(FEED_HOME).unwrap()),
+            [50, 49, 48, 47, 46, 11, 10, 9]
+        );
+        assert!(feeds.get_state(FEED_HOME).unwrap().gaps.is_empty());
+    }
+
+    /// A reading position is per feed and outlives the feed starting over.
+    #[test]
+    fn a_reading_position_survives_the_feed_starting_over() {
+        let db = CacheDb::in_memory();
+        let feeds = FeedCache::new(&db);
+        assert_eq!(feeds.reading_position(FEED_HOME).unwrap(), None);
+
+        let position = ReadingPosition {
+            post_uri: post(7).uri,
+            offset: -120.0,
+        };
+        feeds.set_reading_position(FEED_HOME, &position).unwrap();
+        feeds
+            .replace_page(FEED_HOME, &page(&[9, 8, 7]), None)
+            .unwrap();
+        assert_eq!(
+            feeds.reading_position(FEED_HOME).unwrap(),
+            Some(position.clone())
+        );
+        assert_eq!(feeds.reading_position("at://feed/other").unwrap(), None);
+
+        let moved = ReadingPosition {
+            post_uri: post(9).uri,
+            offset: 0.0,
+        };
+        feeds.set_reading_position(FEED_HOME, &moved).unwrap();
+        assert_eq!(feeds.reading_position(FEED_HOME).unwrap(), Some(moved));
+    }
+
+    /// Views keep to their own keys: a thread cached on the side does not
+    /// leak into the timeline it was opened from.
+    #[test]
+    fn views_are_cached_apart() {
+        let db = CacheDb::in_memory();
+        let feeds = FeedCache::new(&db);
+        feeds.replace_page(FEED_HOME, &page(&[3]), None).unwrap();
+        let thread = thread_key(&post(3).uri);
+        feeds
+            .replace_page(&thread, &page(&[3, 2, 1]), None)
+            .unwrap();
+
+        assert_eq!(numbers(&feeds.get_all(FEED_HOME).unwrap()), [3]);
+        assert_eq!(numbers(&feeds.get_all(&thread).unwrap()), [3, 2, 1]);
+        assert_ne!(
+            author_key("did:plc:a", "posts_no_replies"),
+            author_key("did:plc:a", "posts_with_media")
+        );
+    }
+}
@@ -5,11 +5,16 @@ mod feeds;
 mod posts;
 mod profiles;
 mod schema;
+mod search;
 
 pub use db::CacheDb;
-pub use feeds::{FeedCache, FeedState};
+pub use feeds::{
+    FEED_BOOKMARKS, FEED_HOME, FEED_LIKES, FeedCache, FeedGap, FeedState, GAP_FILL_SIZE, GapEdge,
+    ReadingPosition, TopMerge, author_key, search_key, thread_key,
+};
 pub use posts::PostCache;
 pub use profiles::ProfileCache;
+pub use search::{HistoryQuery, PostSearch};
 
 use thiserror::Error;
 
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MPL-2.0
 
-use crate::atproto::{Embed, Post, Profile, ReplyContext, RepostReason};
+use crate::atproto::{Embed, Facet, Label, Post, Profile, ReplyContext, RepostReason};
 use crate::cache::{CacheDb, CacheError};
 use rusqlite::params;
 
@@ -32,6 +32,9 @@ impl<'a> PostCache<'a> {
             .as_ref()
             .map(serde_json::to_string)
             .transpose()?;
+        let labels_json = serde_json::to_string(&post.labels)?;
+        let author_labels_json = serde_json::to_string(&post.author.labels)?;
+        let facets_json = serde_json::to_string(&post.facets)?;
 
         conn.execute(
             r#"
@@ -39,8 +42,9 @@ impl<'a> PostCache<'a> {
                 uri, cid, author_did, text, created_at, indexed_at,
                 like_count, repost_count, reply_count,
                 embed_json, repost_reason_json, reply_context_json,
-                viewer_like, viewer_repost, fetched_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+                viewer_like, viewer_repost, fetched_at,
+                labels_json, author_labels_json, facets_json
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)
             ON CONFLICT(uri) DO UPDATE SET
                 cid = excluded.cid,
                 text = excluded.text,
@@ -52,7 +56,10 @@ impl<'a> PostCache<'a> {
                 reply_context_json = excluded.reply_context_json,
                 viewer_like = excluded.viewer_like,
                 viewer_repost = excluded.viewer_repost,
-                fetched_at = excluded.fetched_at
+                fetched_at = excluded.fetched_at,
+                labels_json = excluded.labels_json,
+                author_labels_json = excluded.author_labels_json,
+                facets_json = excluded.facets_json
             "#,
             params![
                 post.uri,
@@ -70,6 +77,9 @@ impl<'a> PostCache<'a> {
                 post.viewer_like,
                 post.viewer_repost,
                 now,
+                labels_json,
+                author_labels_json,
+                facets_json,
             ],
         )?;
 
@@ -97,6 +107,9 @@ impl<'a> PostCache<'a> {
                 .as_ref()
                 .map(serde_json::to_string)
                 .transpose()?;
+            let labels_json = serde_json::to_string(&post.labels)?;
+            let author_labels_json = serde_json::to_string(&post.author.labels)?;
+            let facets_json = serde_json::to_string(&post.facets)?;
 
             tx.execute(
                 r#"
@@ -104,8 +117,9 @@ impl<'a> PostCache<'a> {
                     uri, cid, author_did, text, created_at, indexed_at,
                     like_count, repost_count, reply_count,
                     embed_json, repost_reason_json, reply_context_json,
-                    viewer_like, viewer_repost, fetched_at
-                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+                    viewer_like, viewer_repost, fetched_at,
+                    labels_json, author_labels_json, facets_json
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)
                 ON CONFLICT(uri) DO UPDATE SET
                     cid = excluded.cid,
                     text = excluded.text,
@@ -117,7 +131,10 @@ impl<'a> PostCache<'a> {
                     reply_context_json = excluded.reply_context_json,
                     viewer_like = excluded.viewer_like,
                     viewer_repost = excluded.viewer_repost,
-                    fetched_at = excluded.fetched_at
+                    fetched_at = excluded.fetched_at,
+                    labels_json = excluded.labels_json,
+                    author_labels_json = excluded.author_labels_json,
+                    facets_json = excluded.facets_json
                 "#,
                 params![
                     post.uri,
@@ -135,6 +152,9 @@ impl<'a> PostCache<'a> {
                     post.viewer_like,
                     post.viewer_repost,
                     now,
+                    labels_json,
+                    author_labels_json,
+                    facets_json,
                 ],
             )?;
 
@@ -169,7 +189,8 @@ impl<'a> PostCache<'a> {
                 p.like_count, p.repost_count, p.reply_count,
                 p.embed_json, p.repost_reason_json, p.reply_context_json,
                 p.viewer_like, p.viewer_repost,
-                pr.handle, pr.display_name, pr.avatar
+                pr.handle, pr.display_name, pr.avatar,
+                p.labels_json, p.author_labels_json, p.facets_json
             FROM posts p
             LEFT JOIN profiles pr ON p.author_did = pr.did
             WHERE p.uri = ?
@@ -204,7 +225,8 @@ impl<'a> PostCache<'a> {
                 p.like_count, p.repost_count, p.reply_count,
                 p.embed_json, p.repost_reason_json, p.reply_context_json,
                 p.viewer_like, p.viewer_repost,
-                pr.handle, pr.display_name, pr.avatar
+                pr.handle, pr.display_name, pr.avatar,
+                p.labels_json, p.author_labels_json, p.facets_json
             FROM posts p
             LEFT JOIN profiles pr ON p.author_did = pr.did
             WHERE p.uri IN ({})
@@ -256,10 +278,24 @@ impl<'a> PostCache<'a> {
     }
 
     /// Convert a database row to a Post
-    fn row_to_post(row: &rusqlite::Row) -> Result<Post, rusqlite::Error> {
+    pub(super) fn row_to_post(row: &rusqlite::Row) -> Result<Post, rusqlite::Error> {
         let embed_json: Option<String> = row.get(9)?;
         let repost_reason_json: Option<String> = row.get(10)?;
         let reply_context_json: Option<String> = row.get(11)?;
+        // Columns 17 and 18 came with schema 4; older rows hold NULL.
+        let labels: Vec<Label> = row
+            .get::<_, Option<String>>(17)?
+            .and_then(|j| serde_json::from_str(j.as_str()).ok())
+            .unwrap_or_default();
+        let author_labels: Vec<Label> = row
+            .get::<_, Option<String>>(18)?
+            .and_then(|j| serde_json::from_str(j.as_str()).ok())
+            .unwrap_or_default();
+        // Column 19 came with schema 8.
+        let facets: Vec<Facet> = row
+            .get::<_, Option<String>>(19)?
+            .and_then(|j| serde_json::from_str(j.as_str()).ok())
+            .unwrap_or_default();
 
         let embed: Option<Embed> = embed_json
             .as_ref()
@@ -290,6 +326,9 @@ impl<'a> PostCache<'a> {
                 viewer_muted: false,
                 viewer_blocking: None,
                 viewer_blocked_by: false,
+                labels: author_labels,
+                muted_by_list: None,
+                blocking_by_list: None,
             },
             text: row.get(3)?,
             created_at: row.get(4)?,
@@ -304,6 +343,9 @@ impl<'a> PostCache<'a> {
             viewer_bookmarked: None,
             repost_reason,
             reply_context,
+            labels,
+            feed_context: None,
+            facets,
         })
     }
 
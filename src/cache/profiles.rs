@@ -122,6 +122,9 @@ impl<'a> ProfileCache<'a> {
                     viewer_muted: false,
                     viewer_blocking: None,
                     viewer_blocked_by: false,
+                    labels: Vec::new(),
+                    muted_by_list: None,
+                    blocking_by_list: None,
                 })
             })
             .map_err(|e| match e {
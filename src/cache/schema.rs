@@ -2,7 +2,7 @@
 
 /// Schema version this build understands. Every change to `SCHEMA` needs a
 /// matching step in `CacheDb::migrate` and a bump here.
-pub const SCHEMA_VERSION: i64 = 3;
+pub const SCHEMA_VERSION: i64 = 8;
 
 /// SQL schema for the cache database, applied to a fresh file. Existing files
 /// are brought forward by the ladder in `CacheDb::migrate` instead.
@@ -81,3 +81,88 @@ pub const MIGRATION_3: &str = r#"
 DROP TABLE IF EXISTS notifications;
 DROP TABLE IF EXISTS images;
 "#;
+
+/// Version 4 keeps moderation labels with each post: its own, and its
+/// author's as they stood when the post was fetched, so a cached feed
+/// covers what the live one would. Fresh files take it after `SCHEMA` too.
+pub const MIGRATION_4: &str = r#"
+ALTER TABLE posts ADD COLUMN labels_json TEXT;
+ALTER TABLE posts ADD COLUMN author_labels_json TEXT;
+"#;
+
+/// Version 5 remembers the holes a refresh leaves in a feed, as JSON on the
+/// feed's state row. Feeds cached before it have none.
+pub const MIGRATION_5: &str = r#"
+ALTER TABLE feed_state ADD COLUMN gaps_json TEXT;
+"#;
+
+/// Version 6 keeps where the reader left each feed. It is a table of its own
+/// rather than more of `feed_state`, which goes whenever a feed starts over.
+pub const MIGRATION_6: &str = r#"
+CREATE TABLE IF NOT EXISTS reading_positions (
+    feed_key TEXT PRIMARY KEY,
+    post_uri TEXT NOT NULL,
+    pixel_offset REAL NOT NULL DEFAULT 0,
+    saved_at INTEGER NOT NULL
+);
+"#;
+
+/// Version 7 indexes every cached post for the History search: its text, its
+/// images' and video's alt text, and its link card's title. Triggers keep the
+/// index in step with `posts`, keyed by rowid, which an upsert keeps, and the
+/// posts already cached are indexed on the way in.
+///
+/// The alt text and titles are read out of `embed_json` wherever they sit, a
+/// quoted post's media included.
+pub const MIGRATION_7: &str = r#"
+CREATE VIRTUAL TABLE IF NOT EXISTS posts_fts USING fts5(
+    text,
+    alt_text,
+    link_title,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS posts_fts_insert AFTER INSERT ON posts BEGIN
+    INSERT INTO posts_fts (rowid, text, alt_text, link_title) VALUES (
+        new.rowid,
+        new.text,
+        (SELECT group_concat(value, ' ') FROM json_tree(new.embed_json)
+            WHERE key = 'alt' AND type = 'text'),
+        (SELECT group_concat(value, ' ') FROM json_tree(new.embed_json)
+            WHERE key = 'title' AND type = 'text')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS posts_fts_update AFTER UPDATE OF text, embed_json ON posts BEGIN
+    DELETE FROM posts_fts WHERE rowid = old.rowid;
+    INSERT INTO posts_fts (rowid, text, alt_text, link_title) VALUES (
+        new.rowid,
+        new.text,
+        (SELECT group_concat(value, ' ') FROM json_tree(new.embed_json)
+            WHERE key = 'alt' AND type = 'text'),
+        (SELECT group_concat(value, ' ') FROM json_tree(new.embed_json)
+            WHERE key = 'title' AND type = 'text')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS posts_fts_delete AFTER DELETE ON posts BEGIN
+    DELETE FROM posts_fts WHERE rowid = old.rowid;
+END;
+
+INSERT INTO posts_fts (rowid, text, alt_text, link_title)
+SELECT
+    rowid,
+    text,
+    (SELECT group_concat(value, ' ') FROM json_tree(posts.embed_json)
+        WHERE key = 'alt' AND type = 'text'),
+    (SELECT group_concat(value, ' ') FROM json_tree(posts.embed_json)
+        WHERE key = 'title' AND type = 'text')
+FROM posts;
+"#;
+
+/// Version 8 keeps each post's rich text facets, so a cached post links
+/// what its record links. Posts cached before it hold NULL and fall back
+/// to finding links in their text until they are fetched again.
+pub const MIGRATION_8: &str = r#"
+ALTER TABLE posts ADD COLUMN facets_json TEXT;
+"#;
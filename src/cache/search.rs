@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: MPL-2.0
+
+use crate::atproto::Post;
+use crate::cache::{CacheDb, CacheError, FEED_BOOKMARKS, FEED_LIKES, PostCache};
+use chrono::NaiveDate;
+
+/// Most posts one History search returns, newest first
+pub const HISTORY_LIMIT: usize = 200;
+
+/// A search of the posts already in the cache.
+///
+/// Every part is optional; the words are matched against the post text, alt
+/// text and link card titles, and the rest narrow what they match.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryQuery {
+    pub text: String,
+    /// A handle, with or without its `@`, or a DID
+    pub author: Option<String>,
+    /// First day included, by the post's own date
+    pub since: Option<NaiveDate>,
+    /// Last day included
+    pub until: Option<NaiveDate>,
+    pub liked: bool,
+    pub bookmarked: bool,
+}
+
+impl HistoryQuery {
+    /// Whether there is nothing to search by, which would list every post
+    /// the cache holds.
+    pub fn is_empty(&self) -> bool {
+        match_expression(&self.text).is_none()
+            && self.author().is_none()
+            && self.since.is_none()
+            && self.until.is_none()
+            && !self.liked
+            && !self.bookmarked
+    }
+
+    fn author(&self) -> Option<&str> {
+        self.author
+            .as_deref()
+            .map(|a| a.trim().trim_start_matches('@'))
+            .filter(|a| !a.is_empty())
+    }
+}
+
+/// Full-text search over cached posts
+pub struct PostSearch<'a> {
+    db: &'a CacheDb,
+}
+
+impl<'a> PostSearch<'a> {
+    pub fn new(db: &'a CacheDb) -> Self {
+        Self { db }
+    }
+
+    /// Cached posts matching `query`, newest first.
+    pub fn search(&self, query: &HistoryQuery) -> Result<Vec<Post>, CacheError> {
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conditions = Vec::new();
+        let mut params: Vec<String> = Vec::new();
+
+        if let Some(expression) = match_expression(&query.text) {
+            conditions.push(
+                "p.rowid IN (SELECT rowid FROM posts_fts WHERE posts_fts MATCH ?)".to_string(),
+            );
+            params.push(expression);
+        }
+        if let Some(author) = query.author() {
+            conditions.push("(p.author_did = ? OR pr.handle = ? COLLATE NOCASE)".to_string());
+            params.push(author.to_string());
+            params.push(author.to_string());
+        }
+        // created_at is the post's own ISO 8601 stamp, so its first ten
+        // characters are the day it was written.
+        if let Some(since) = query.since {
+            conditions.push("substr(p.created_at, 1, 10) >= ?".to_string());
+            params.push(since.format("%Y-%m-%d").to_string());
+        }
+        if let Some(until) = query.until {
+            conditions.push("substr(p.created_at, 1, 10) <= ?".to_string());
+            params.push(until.format("%Y-%m-%d").to_string());
+        }
+        // A like shows on the post itself wherever it was seen; the Likes
+        // tab also holds posts liked before they reached any other view.
+        if query.liked {
+            conditions.push(
+                "(p.viewer_like IS NOT NULL OR p.uri IN \
+                 (SELECT post_uri FROM feed_items WHERE feed_key = ?))"
+                    .to_string(),
+            );
+            params.push(FEED_LIKES.to_string());
+        }
+        // Bookmarks are not a cached column; the Saved tab is the record.
+        if query.bookmarked {
+            conditions
+                .push("p.uri IN (SELECT post_uri FROM feed_items WHERE feed_key = ?)".to_string());
+            params.push(FEED_BOOKMARKS.to_string());
+        }
+
+        let sql = format!(
+            r#"
+            SELECT
+                p.uri, p.cid, p.author_did, p.text, p.created_at, p.indexed_at,
+                p.like_count, p.repost_count, p.reply_count,
+                p.embed_json, p.repost_reason_json, p.reply_context_json,
+                p.viewer_like, p.viewer_repost,
+                pr.handle, pr.display_name, pr.avatar,
+                p.labels_json, p.author_labels_json, p.facets_json
+            FROM posts p
+            LEFT JOIN profiles pr ON p.author_did = pr.did
+            WHERE {}
+            ORDER BY p.created_at DESC
+            LIMIT {}
+            "#,
+            conditions.join(" AND "),
+            HISTORY_LIMIT
+        );
+
+        let conn = self.db.conn();
+        let mut stmt = conn.prepare(&sql)?;
+        let params: Vec<&dyn rusqlite::ToSql> =
+            params.iter().map(|s| s as &dyn rusqlite::ToSql).collect();
+        let posts = stmt
+            .query_map(params.as_slice(), PostCache::row_to_post)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(posts)
+    }
+}
+
+/// An FTS5 query that finds every word typed, each as a prefix.
+///
+/// Each word is quoted so punctuation, `-` and words like `OR` are searched
+/// for instead of read as query syntax.
+fn match_expression(text: &str) -> Option<String> {
+    let terms: Vec<String> = text
+        .split_whitespace()
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atproto::{Embed, ExternalEmbed, ImageEmbed, Profile};
+    use crate::cache::FeedCache;
+
+    fn post(n: u32, author: &str, text: &str) -> Post {
+        Post {
+            uri: format!("at://did:plc:{author}/app.bsky.feed.post/{n}"),
+            cid: "cid".into(),
+            author: Profile::minimal(
+                format!("did:plc:{author}"),
+                format!("{author}.bsky.social"),
+                None,
+                None,
+            ),
+            text: text.into(),
+            created_at: format!("2026-03-{n:02}T12:00:00Z"),
+            indexed_at: format!("2026-03-{n:02}T12:00:00.000Z"),
+            like_count: None,
+            repost_count: None,
+            reply_count: None,
+            embed: None,
+            viewer_like: None,
+            viewer_repost: None,
+            viewer_bookmarked: None,
+            repost_reason: None,
+            reply_context: None,
+            labels: Vec::new(),
+            feed_context: None,
+            facets: Vec::new(),
+        }
+    }
+
+    fn search(db: &CacheDb, query: HistoryQuery) -> Vec<String> {
+        PostSearch::new(db)
+            .search(&query)
+            .unwrap()
+            .into_iter()
+            .map(|p| p.text)
+            .collect()
+    }
+
+    fn words(text: &str) -> HistoryQuery {
+        HistoryQuery {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Alt text and link card titles are searched along with the post
+    /// itself, and a word matches by its start, accents aside.
+    #[test]
+    fn words_match_text_alt_text_and_link_titles() {
+        let db = CacheDb::in_memory();
+        let mut photo = post(1, "a", "look at this");
+        photo.embed = Some(Embed::Images(vec![ImageEmbed {
+            thumb: "t".into(),
+            fullsize: "f".into(),
+            alt: "A grey heron on a jetty".into(),
+            aspect_ratio: None,
+        }]));
+        let mut link = post(2, "a", "worth a read");
+        link.embed = Some(Embed::External(ExternalEmbed {
+            uri: "https://example.com".into(),
+            title: "Herons of the northern coast".into(),
+            description: String::new(),
+            thumb: None,
+        }));
+        let cafe = post(3, "b", "Café opening tomorrow");
+        PostCache::new(&db)
+            .store_batch(&[photo, link, cafe])
+            .unwrap();
+
+        assert_eq!(
+            search(&db, words("heron")),
+            ["worth a read", "look at this"]
+        );
+        assert_eq!(search(&db, words("cafe open")), ["Café opening tomorrow"]);
+        assert!(search(&db, words("heron cafe")).is_empty());
+    }
+
+    /// Author, dates and the Liked and Saved filters narrow the words, and
+    /// work with no words at all.
+    #[test]
+    fn filters_narrow_the_search() {
+        let db = CacheDb::in_memory();
+        let mut liked = post(5, "a", "rain again");
+        liked.viewer_like = Some("at://like".into());
+        let saved = post(10, "b", "rain all week");
+        let plain = post(20, "a", "no rain today");
+        PostCache::new(&db)
+            .store_batch(&[liked, saved.clone(), plain])
+            .unwrap();
+        FeedCache::new(&db)
+            .replace_page(FEED_BOOKMARKS, &[saved], None)
+            .unwrap();
+
+        let by_author = HistoryQuery {
+            author: Some("@a.bsky.social".into()),
+            ..words("rain")
+        };
+        assert_eq!(search(&db, by_author), ["no rain today", "rain again"]);
+
+        let dated = HistoryQuery {
+            since: NaiveDate::from_ymd_opt(2026, 3, 6),
+            until: NaiveDate::from_ymd_opt(2026, 3, 20),
+            ..words("rain")
+        };
+        assert_eq!(search(&db, dated), ["no rain today", "rain all week"]);
+
+        let liked = HistoryQuery {
+            liked: true,
+            ..Default::default()
+        };
+        assert_eq!(search(&db, liked), ["rain again"]);
+        let saved = HistoryQuery {
+            bookmarked: true,
+            ..Default::default()
+        };
+        assert_eq!(search(&db, saved), ["rain all week"]);
+
+        assert!(search(&db, HistoryQuery::default()).is_empty());
+    }
+
+    /// An edited embed or text is searched as it now reads, not as it was
+    /// first cached.
+    #[test]
+    fn a_restored_post_is_reindexed() {
+        let db = CacheDb::in_memory();
+        let posts = PostCache::new(&db);
+        posts.store_batch(&[post(1, "a", "first draft")]).unwrap();
+        posts.store_batch(&[post(1, "a", "final copy")]).unwrap();
+
+        assert!(search(&db, words("draft")).is_empty());
+        assert_eq!(search(&db, words("final")), ["final copy"]);
+    }
+
+    /// Quotes, dashes and FTS5 keywords are searched for, not parsed.
+    #[test]
+    fn query_syntax_is_searched_literally() {
+        let db = CacheDb::in_memory();
+        PostCache::new(&db)
+            .store_batch(&[post(1, "a", "this OR that, \"quoted\" - done")])
+            .unwrap();
+
+        assert_eq!(search(&db, words("OR")).len(), 1);
+        assert_eq!(search(&db, words("\"quoted")).len(), 1);
+        assert!(search(&db, words("-")).is_empty());
+        assert!(search(&db, words("NEAR(")).is_empty());
+    }
+}
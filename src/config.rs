@@ -22,3 +22,9 @@ pub const IS_DEVEL: bool = true;
 pub const IS_DEVEL: bool = false;
 
 pub const DEFAULT_PDS: &str = "https://bsky.social";
+
+/// Answers `resolveHandle` for any handle, without signing in.
+pub const PUBLIC_APPVIEW: &str = "https://public.api.bsky.app";
+
+/// Where `did:plc` documents are published.
+pub const PLC_DIRECTORY: &str = "https://plc.directory";
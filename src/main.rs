@@ -2,9 +2,11 @@
 
 mod app;
 mod atproto;
+mod background;
 mod cache;
 mod config;
 mod media;
+mod notify;
 mod runtime;
 mod state;
 mod ui;
@@ -13,6 +15,8 @@ mod update;
 use gtk4::prelude::*;
 
 fn main() {
+    let (background, args) = background::split_args(std::env::args());
     let app = app::HangarApplication::new();
-    app.run();
+    app.set_start_in_background(background);
+    app.run_with_args(&args);
 }
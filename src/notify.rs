@@ -0,0 +1,633 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Desktop notifications for what arrives while the window is not in front.
+//!
+//! The unread poll already fetches the unread notifications and
+//! conversations. This decides which of them are new since the last poll,
+//! which the user asked to hear about, and how they read in the tray. The
+//! application turns the result into `gio::Notification`s; nothing here
+//! touches GTK, so the rules are testable on their own.
+//!
+//! A post the timeline would drop, for a muted word or a hiding label,
+//! raises nothing; one it would cover raises without its text.
+
+use crate::atproto::moderation::{self, Blur};
+use crate::atproto::muted_words;
+use crate::atproto::{
+    ChatMessage, Conversation, ModerationPrefs, MutedWord, Notification, Profile,
+};
+use crate::state::DesktopNotifications;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+/// More than this many of one kind in a single poll go out as one summary.
+const GROUP_AFTER: usize = 3;
+
+/// The reasons a notification can be raised for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Mention,
+    Reply,
+    Quote,
+    Follow,
+    Message,
+}
+
+impl Kind {
+    /// Likes and reposts stay on the Activity badge; they are too frequent
+    /// to interrupt for.
+    fn from_reason(reason: &str) -> Option<Self> {
+        match reason {
+            "mention" => Some(Self::Mention),
+            "reply" => Some(Self::Reply),
+            "quote" => Some(Self::Quote),
+            "follow" => Some(Self::Follow),
+            _ => None,
+        }
+    }
+
+    fn enabled(self, prefs: &DesktopNotifications) -> bool {
+        match self {
+            Self::Mention => prefs.mentions,
+            Self::Reply => prefs.replies,
+            Self::Quote => prefs.quotes,
+            Self::Follow => prefs.follows,
+            Self::Message => prefs.messages,
+        }
+    }
+
+    /// The tray id of the summary. Raising it again replaces the last one
+    /// rather than stacking a second.
+    fn group_id(self) -> &'static str {
+        match self {
+            Self::Mention => "group-mentions",
+            Self::Reply => "group-replies",
+            Self::Quote => "group-quotes",
+            Self::Follow => "group-follows",
+            Self::Message => "group-messages",
+        }
+    }
+
+    fn summary(self, count: usize) -> String {
+        match self {
+            Self::Mention => format!("{count} new mentions"),
+            Self::Reply => format!("{count} new replies"),
+            Self::Quote => format!("{count} new quotes"),
+            Self::Follow => format!("{count} new followers"),
+            Self::Message => format!("{count} new messages"),
+        }
+    }
+
+    /// The section a summary opens.
+    fn section(self) -> Target {
+        match self {
+            Self::Mention | Self::Reply | Self::Quote => Target::Mentions,
+            Self::Follow => Target::Activity,
+            Self::Message => Target::Chat,
+        }
+    }
+}
+
+/// Where activating a notification goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// A post, by URI
+    Thread(String),
+    /// A profile, by DID
+    Profile(String),
+    /// A conversation, by ID
+    Conversation(String),
+    Mentions,
+    Activity,
+    Chat,
+}
+
+impl Target {
+    /// The string carried as the `app.open-notification` target.
+    pub fn encode(&self) -> String {
+        match self {
+            Self::Thread(uri) => format!("thread:{uri}"),
+            Self::Profile(did) => format!("profile:{did}"),
+            Self::Conversation(id) => format!("conversation:{id}"),
+            Self::Mentions => "mentions".to_string(),
+            Self::Activity => "activity".to_string(),
+            Self::Chat => "chat".to_string(),
+        }
+    }
+
+    /// The inverse of [`Self::encode`]. Splits on the first colon only:
+    /// URIs and DIDs carry their own.
+    pub fn decode(target: &str) -> Option<Self> {
+        match target {
+            "mentions" => return Some(Self::Mentions),
+            "activity" => return Some(Self::Activity),
+            "chat" => return Some(Self::Chat),
+            _ => {}
+        }
+        let (kind, value) = target.split_once(':')?;
+        if value.is_empty() {
+            return None;
+        }
+        match kind {
+            "thread" => Some(Self::Thread(value.to_string())),
+            "profile" => Some(Self::Profile(value.to_string())),
+            "conversation" => Some(Self::Conversation(value.to_string())),
+            _ => None,
+        }
+    }
+}
+
+/// One notification ready for the tray.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alert {
+    /// The tray id. The same id replaces the notification already there.
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub target: Target,
+    /// The label of the one button, which goes to the same place as a
+    /// click on the notification itself.
+    pub button: &'static str,
+}
+
+/// A new item before grouping: its kind, who it is from, and how it reads
+/// on its own.
+struct Fresh {
+    kind: Kind,
+    name: String,
+    alert: Alert,
+}
+
+/// What has already been announced, so each poll raises only what is new.
+#[derive(Debug, Default)]
+pub struct Tracker {
+    seen: HashSet<String>,
+    primed: bool,
+}
+
+impl Tracker {
+    /// Forget everything, for a different account. Its next poll primes
+    /// again instead of announcing its whole backlog.
+    pub fn reset(&mut self) {
+        self.seen.clear();
+        self.primed = false;
+    }
+
+    /// The alerts for everything in this poll that the last one did not
+    /// have, filtered by `prefs`, `muted` and `moderation`, and grouped per
+    /// kind.
+    ///
+    /// The first poll only records. What was unread at launch is the
+    /// badges' business, not a burst of notifications. Every item counts as
+    /// seen whether or not its kind is switched on, so turning a toggle on
+    /// later does not replay old activity.
+    pub fn collect(
+        &mut self,
+        notifications: &[Notification],
+        conversations: &[Conversation],
+        own_did: &str,
+        prefs: &DesktopNotifications,
+        muted: &[MutedWord],
+        moderation: &ModerationPrefs,
+    ) -> Vec<Alert> {
+        let now = Utc::now();
+        let mut fresh: Vec<Fresh> = Vec::new();
+        let mut current = HashSet::new();
+
+        for notification in notifications {
+            let key = format!("notification:{}", notification.uri);
+            let new = !self.seen.contains(&key);
+            current.insert(key);
+            if !new {
+                continue;
+            }
+            let Some(kind) = Kind::from_reason(&notification.reason) else {
+                continue;
+            };
+            let Some(alert) = notification_alert(kind, notification, muted, moderation, now) else {
+                continue;
+            };
+            fresh.push(Fresh {
+                kind,
+                name: display_name(&notification.author).to_string(),
+                alert,
+            });
+        }
+
+        for conversation in conversations {
+            let Some(message) = &conversation.last_message else {
+                continue;
+            };
+            let key = format!("message:{}", message.id);
+            let new = !self.seen.contains(&key);
+            current.insert(key);
+            // Your own reply from another device is not news.
+            if !new || message.sender_did == own_did {
+                continue;
+            }
+            let alert = message_alert(conversation, message, own_did);
+            fresh.push(Fresh {
+                kind: Kind::Message,
+                name: alert.title.clone(),
+                alert,
+            });
+        }
+
+        // Only what is still unread is kept: once read, an item leaves the
+        // poll and does not come back.
+        self.seen = current;
+        if !self.primed {
+            self.primed = true;
+            return Vec::new();
+        }
+
+        fresh.retain(|item| item.kind.enabled(prefs));
+        group(fresh)
+    }
+}
+
+/// Collapse each kind with more than [`GROUP_AFTER`] alerts into one
+/// summary, keeping the order kinds first appeared in.
+fn group(fresh: Vec<Fresh>) -> Vec<Alert> {
+    let mut kinds: Vec<Kind> = Vec::new();
+    for item in &fresh {
+        if !kinds.contains(&item.kind) {
+            kinds.push(item.kind);
+        }
+    }
+
+    let mut alerts = Vec::new();
+    for kind in kinds {
+        let of_kind: Vec<&Fresh> = fresh.iter().filter(|item| item.kind == kind).collect();
+        if of_kind.len() <= GROUP_AFTER {
+            alerts.extend(of_kind.into_iter().map(|item| item.alert.clone()));
+            continue;
+        }
+        alerts.push(Alert {
+            id: kind.group_id().to_string(),
+            title: kind.summary(of_kind.len()),
+            body: names(of_kind.iter().map(|item| item.name.as_str())),
+            target: kind.section(),
+            button: "Open",
+        });
+    }
+    alerts
+}
+
+/// "Alice, Bob and 3 others", each name once.
+fn names<'a>(all: impl Iterator<Item = &'a str>) -> String {
+    let mut unique: Vec<&str> = Vec::new();
+    for name in all {
+        if !unique.contains(&name) {
+            unique.push(name);
+        }
+    }
+    match unique.len() {
+        0 => String::new(),
+        1 => unique[0].to_string(),
+        2 => format!("{} and {}", unique[0], unique[1]),
+        n => format!("{}, {} and {} others", unique[0], unique[1], n - 2),
+    }
+}
+
+fn display_name(profile: &Profile) -> &str {
+    profile
+        .display_name
+        .as_deref()
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or(&profile.handle)
+}
+
+/// How a notification reads in the tray, or `None` for one that should
+/// not be raised: its post is muted or hidden, or it is not a
+/// notification's kind at all.
+fn notification_alert(
+    kind: Kind,
+    notification: &Notification,
+    muted: &[MutedWord],
+    prefs: &ModerationPrefs,
+    now: DateTime<Utc>,
+) -> Option<Alert> {
+    let name = display_name(&notification.author);
+    let text = match &notification.post {
+        Some(post) => {
+            if muted_words::post_is_muted(muted, post, now)
+                || moderation::hidden_from_feeds(post, prefs)
+            {
+                return None;
+            }
+            match moderation::decide_post(post, prefs) {
+                Some(decision) if decision.blur == Blur::Content => {
+                    format!("Covered: {}", decision.name)
+                }
+                _ => post.text.clone(),
+            }
+        }
+        None => String::new(),
+    };
+    let (title, body, target, button) = match kind {
+        Kind::Follow => (
+            format!("{name} followed you"),
+            format!("@{}", notification.author.handle),
+            Target::Profile(notification.author.did.clone()),
+            "View Profile",
+        ),
+        Kind::Mention => (
+            format!("{name} mentioned you"),
+            text,
+            Target::Thread(notification.uri.clone()),
+            "Open Thread",
+        ),
+        Kind::Reply => (
+            format!("{name} replied to you"),
+            text,
+            Target::Thread(notification.uri.clone()),
+            "Open Thread",
+        ),
+        Kind::Quote => (
+            format!("{name} quoted your post"),
+            text,
+            Target::Thread(notification.uri.clone()),
+            "Open Thread",
+        ),
+        // Messages come from conversations.
+        Kind::Message => return None,
+    };
+    Some(Alert {
+        id: format!("notification-{}", notification.uri),
+        title,
+        body,
+        target,
+        button,
+    })
+}
+
+fn message_alert(conversation: &Conversation, message: &ChatMessage, own_did: &str) -> Alert {
+    let sender = conversation
+        .members
+        .iter()
+        .find(|m| m.did == message.sender_did)
+        .or_else(|| conversation.members.iter().find(|m| m.did != own_did));
+    let title = sender
+        .map(display_name)
+        .unwrap_or("New message")
+        .to_string();
+    let body = if message.text.trim().is_empty() && message.embed.is_some() {
+        "Shared a post".to_string()
+    } else {
+        message.text.clone()
+    };
+    Alert {
+        // One per conversation: a second message replaces the first.
+        id: format!("conversation-{}", conversation.id),
+        title,
+        body,
+        target: Target::Conversation(conversation.id.clone()),
+        button: "Open Conversation",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atproto::{Label, MutedWordTarget, Post};
+
+    const ME: &str = "did:plc:me";
+
+    fn none() -> ModerationPrefs {
+        ModerationPrefs::default()
+    }
+
+    fn profile(did: &str, name: &str) -> Profile {
+        Profile::minimal(
+            did.to_string(),
+            format!("{name}.test"),
+            Some(name.to_string()),
+            None,
+        )
+    }
+
+    fn notification(uri: &str, reason: &str, author: &str) -> Notification {
+        Notification {
+            uri: uri.to_string(),
+            cid: String::new(),
+            author: profile(&format!("did:plc:{author}"), author),
+            reason: reason.to_string(),
+            reason_subject: None,
+            indexed_at: String::new(),
+            is_read: false,
+            post: None,
+        }
+    }
+
+    fn conversation(id: &str, message_id: &str, sender: &str) -> Conversation {
+        Conversation {
+            id: id.to_string(),
+            members: vec![profile(ME, "me"), profile("did:plc:bob", "bob")],
+            last_message: Some(ChatMessage {
+                id: message_id.to_string(),
+                text: "hi".to_string(),
+                sender_did: sender.to_string(),
+                sent_at: String::new(),
+                embed: None,
+                reactions: Vec::new(),
+            }),
+            unread_count: 1,
+            muted: false,
+        }
+    }
+
+    fn with_post(mut notification: Notification, text: &str, labels: &[&str]) -> Notification {
+        notification.post = Some(Post {
+            uri: notification.uri.clone(),
+            cid: "cid".into(),
+            author: notification.author.clone(),
+            text: text.to_string(),
+            created_at: String::new(),
+            indexed_at: String::new(),
+            like_count: None,
+            repost_count: None,
+            reply_count: None,
+            embed: None,
+            viewer_like: None,
+            viewer_repost: None,
+            viewer_bookmarked: None,
+            repost_reason: None,
+            reply_context: None,
+            labels: labels
+                .iter()
+                .map(|val| Label {
+                    src: "did:plc:moderator".into(),
+                    val: val.to_string(),
+                    neg: false,
+                })
+                .collect(),
+            feed_context: None,
+            facets: Vec::new(),
+        });
+        notification
+    }
+
+    fn primed() -> Tracker {
+        let mut tracker = Tracker::default();
+        tracker.collect(&[], &[], ME, &DesktopNotifications::default(), &[], &none());
+        tracker
+    }
+
+    /// A launch with a backlog of unread items raises nothing; the badges
+    /// already say so.
+    #[test]
+    fn the_first_poll_only_records() {
+        let mut tracker = Tracker::default();
+        let prefs = DesktopNotifications::default();
+        let backlog = [notification("at://a/1", "mention", "alice")];
+
+        assert!(
+            tracker
+                .collect(&backlog, &[], ME, &prefs, &[], &none())
+                .is_empty()
+        );
+        assert!(
+            tracker
+                .collect(&backlog, &[], ME, &prefs, &[], &none())
+                .is_empty(),
+            "the backlog was announced on the second poll"
+        );
+
+        let alerts = tracker.collect(
+            &[notification("at://a/2", "reply", "bob"), backlog[0].clone()],
+            &[],
+            ME,
+            &prefs,
+            &[],
+            &none(),
+        );
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].title, "bob replied to you");
+        assert_eq!(alerts[0].target, Target::Thread("at://a/2".to_string()));
+    }
+
+    /// Likes, reposts, switched-off kinds and your own messages stay out of
+    /// the tray.
+    #[test]
+    fn only_enabled_kinds_from_other_people_are_raised() {
+        let mut tracker = primed();
+        let prefs = DesktopNotifications {
+            follows: false,
+            ..Default::default()
+        };
+
+        let alerts = tracker.collect(
+            &[
+                notification("at://a/1", "like", "alice"),
+                notification("at://a/2", "follow", "alice"),
+                notification("at://a/3", "quote", "carol"),
+            ],
+            &[
+                conversation("c1", "m1", ME),
+                conversation("c2", "m2", "did:plc:bob"),
+            ],
+            ME,
+            &prefs,
+            &[],
+            &none(),
+        );
+
+        let targets: Vec<Target> = alerts.into_iter().map(|a| a.target).collect();
+        assert_eq!(
+            targets,
+            vec![
+                Target::Thread("at://a/3".to_string()),
+                Target::Conversation("c2".to_string()),
+            ]
+        );
+    }
+
+    /// What the timeline drops for a muted word or a hiding label stays
+    /// out of the tray, and what it covers goes up without its text.
+    #[test]
+    fn muted_and_labeled_posts_keep_their_text_off_the_desktop() {
+        let mut tracker = primed();
+        let muted = [MutedWord {
+            id: None,
+            value: "spoilers".into(),
+            targets: vec![MutedWordTarget::Content],
+            exclude_following: false,
+            expires_at: None,
+        }];
+
+        let alerts = tracker.collect(
+            &[
+                with_post(
+                    notification("at://a/1", "mention", "alice"),
+                    "big spoilers",
+                    &[],
+                ),
+                with_post(notification("at://a/2", "reply", "bob"), "look", &["porn"]),
+                with_post(
+                    notification("at://a/3", "quote", "carol"),
+                    "ouch",
+                    &["!warn"],
+                ),
+                with_post(notification("at://a/4", "mention", "dan"), "hello", &[]),
+            ],
+            &[],
+            ME,
+            &DesktopNotifications::default(),
+            &muted,
+            &none(),
+        );
+
+        let bodies: Vec<&str> = alerts.iter().map(|a| a.body.as_str()).collect();
+        assert_eq!(bodies, ["Covered: Content warning", "hello"]);
+    }
+
+    /// A burst of one kind becomes a single summary that opens the section;
+    /// a few stay individual.
+    #[test]
+    fn a_burst_of_one_kind_is_grouped() {
+        let mut tracker = primed();
+        let follows: Vec<Notification> = ["alice", "bob", "carol", "dan", "erin"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| notification(&format!("at://f/{i}"), "follow", name))
+            .collect();
+        let mut batch = follows.clone();
+        batch.push(notification("at://m/1", "mention", "alice"));
+
+        let alerts = tracker.collect(
+            &batch,
+            &[],
+            ME,
+            &DesktopNotifications::default(),
+            &[],
+            &none(),
+        );
+
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].id, "group-follows");
+        assert_eq!(alerts[0].title, "5 new followers");
+        assert_eq!(alerts[0].body, "alice, bob and 3 others");
+        assert_eq!(alerts[0].target, Target::Activity);
+        assert_eq!(alerts[1].title, "alice mentioned you");
+    }
+
+    /// The action target survives its trip through a GVariant string,
+    /// colons in URIs and DIDs included.
+    #[test]
+    fn targets_round_trip_through_their_action_string() {
+        let targets = [
+            Target::Thread("at://did:plc:x/app.bsky.feed.post/3k".to_string()),
+            Target::Profile("did:plc:alice".to_string()),
+            Target::Conversation("3abc".to_string()),
+            Target::Mentions,
+            Target::Activity,
+            Target::Chat,
+        ];
+        for target in targets {
+            assert_eq!(Target::decode(&target.encode()), Some(target.clone()));
+        }
+        assert_eq!(Target::decode("thread:"), None);
+        assert_eq!(Target::decode("nonsense"), None);
+    }
+}
@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! The signed-in accounts and which one is on screen.
+//!
+//! Credentials stay in the keyring and `oauth-sessions.json`, keyed by DID.
+//! This file only orders them for the account menu, remembers the last one in
+//! use, and keeps enough of each profile to draw its row before any network
+//! request has come back.
+
+use crate::config::APP_ID;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// One signed-in account, as the account menu shows it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Account {
+    pub did: String,
+    /// Empty until the first profile fetch for an OAuth sign-in, which
+    /// does not report a handle.
+    #[serde(default)]
+    pub handle: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub avatar: Option<String>,
+}
+
+impl Account {
+    /// What the menu row and the avatar fallback read.
+    pub fn label(&self) -> &str {
+        self.display_name
+            .as_deref()
+            .filter(|name| !name.is_empty())
+            .or(Some(self.handle.as_str()).filter(|handle| !handle.is_empty()))
+            .unwrap_or(&self.did)
+    }
+}
+
+/// Every account with a stored session, in the order they were added.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountList {
+    /// The account to restore on launch.
+    #[serde(default)]
+    pub active: Option<String>,
+    #[serde(default)]
+    pub accounts: Vec<Account>,
+}
+
+impl AccountList {
+    /// ~/.config/<APP_ID>/accounts.json
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|mut p| {
+            p.push(APP_ID);
+            p.push("accounts.json");
+            p
+        })
+    }
+
+    /// Read the list. Missing or unreadable is an empty list: the
+    /// credentials are elsewhere, so the worst case is the menu forgetting
+    /// its order until the next sign-in fills it back in.
+    pub fn load() -> Self {
+        Self::path()
+            .map(|path| Self::load_from(&path))
+            .unwrap_or_default()
+    }
+
+    fn load_from(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                eprintln!("hangar: {} did not parse: {e}", path.display());
+                Self::default()
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => {
+                eprintln!("hangar: could not read {}: {e}", path.display());
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path().ok_or("Could not determine config directory")?;
+        self.save_to(&path)
+    }
+
+    /// Temp file and rename, the way `AppSettings` writes its own.
+    fn save_to(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {e}"))?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize accounts: {e}"))?;
+        let tmp = path.with_extension(format!("json.tmp.{}", std::process::id()));
+        let written = (|| -> std::io::Result<()> {
+            let mut file = std::fs::File::create(&tmp)?;
+            file.write_all(json.as_bytes())?;
+            file.sync_all()
+        })();
+        if let Err(e) = written {
+            let _ = std::fs::remove_file(&tmp);
+            return Err(format!("Failed to write accounts: {e}"));
+        }
+        std::fs::rename(&tmp, path).map_err(|e| {
+            let _ = std::fs::remove_file(&tmp);
+            format!("Failed to replace accounts: {e}")
+        })
+    }
+
+    pub fn get(&self, did: &str) -> Option<&Account> {
+        self.accounts.iter().find(|a| a.did == did)
+    }
+
+    pub fn contains(&self, did: &str) -> bool {
+        self.get(did).is_some()
+    }
+
+    /// Add `account`, or refresh the row already there in place.
+    ///
+    /// Blank fields do not overwrite known ones: an OAuth sign-in reports
+    /// no handle, and the row should keep the one the last profile fetch
+    /// found.
+    pub fn upsert(&mut self, account: Account) {
+        match self.accounts.iter_mut().find(|a| a.did == account.did) {
+            Some(existing) => {
+                if !account.handle.is_empty() {
+                    existing.handle = account.handle;
+                }
+                if account.display_name.is_some() {
+                    existing.display_name = account.display_name;
+                }
+                if account.avatar.is_some() {
+                    existing.avatar = account.avatar;
+                }
+            }
+            None => self.accounts.push(account),
+        }
+    }
+
+    /// Drop `did`. When it was the active account, the first one left
+    /// takes over, or nobody does.
+    pub fn remove(&mut self, did: &str) {
+        self.accounts.retain(|a| a.did != did);
+        if self.active.as_deref() == Some(did) {
+            self.active = self.accounts.first().map(|a| a.did.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(did: &str, handle: &str) -> Account {
+        Account {
+            did: did.to_string(),
+            handle: handle.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// An OAuth sign-in knows only the DID. Re-adding an account that way
+    /// must not blank the handle and name the menu already shows.
+    #[test]
+    fn a_blank_sign_in_keeps_what_the_profile_fetch_found() {
+        let mut list = AccountList::default();
+        list.upsert(Account {
+            display_name: Some("Alice".to_string()),
+            avatar: Some("https://cdn.example/a.jpg".to_string()),
+            ..account("did:plc:alice", "alice.test")
+        });
+        list.upsert(account("did:plc:alice", ""));
+
+        assert_eq!(list.accounts.len(), 1, "the same DID was added twice");
+        let alice = list.get("did:plc:alice").unwrap();
+        assert_eq!(alice.handle, "alice.test");
+        assert_eq!(alice.label(), "Alice");
+        assert!(alice.avatar.is_some());
+    }
+
+    /// Removing the account on screen hands over to the next one; removing
+    /// any other leaves the active one where it is.
+    #[test]
+    fn removing_the_active_account_hands_over_to_the_next() {
+        let mut list = AccountList {
+            active: Some("did:plc:alice".to_string()),
+            accounts: vec![
+                account("did:plc:alice", "alice.test"),
+                account("did:plc:bob", "bob.test"),
+                account("did:plc:carol", "carol.test"),
+            ],
+        };
+
+        list.remove("did:plc:carol");
+        assert_eq!(list.active.as_deref(), Some("did:plc:alice"));
+
+        list.remove("did:plc:alice");
+        assert_eq!(list.active.as_deref(), Some("did:plc:bob"));
+
+        list.remove("did:plc:bob");
+        assert_eq!(list.active, None, "nobody left to hand over to");
+        assert!(list.accounts.is_empty());
+    }
+
+    /// The file round-trips, and a missing one reads as no accounts.
+    #[test]
+    fn the_account_list_round_trips_through_disk() {
+        let dir = std::env::temp_dir().join(format!("hangar-accounts-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("accounts.json");
+
+        assert_eq!(AccountList::load_from(&path), AccountList::default());
+
+        let list = AccountList {
+            active: Some("did:plc:bob".to_string()),
+            accounts: vec![
+                account("did:plc:alice", "alice.test"),
+                account("did:plc:bob", "bob.test"),
+            ],
+        };
+        list.save_to(&path).expect("saves, creating the directory");
+        assert_eq!(AccountList::load_from(&path), list);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
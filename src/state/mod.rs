@@ -1,9 +1,14 @@
 // SPDX-License-Identifier: MPL-2.0
 
+mod accounts;
 pub mod oauth;
 mod session;
 pub mod session_store;
 pub mod settings;
 
+pub use accounts::{Account, AccountList};
 pub use session::SessionManager;
-pub use settings::{AppSettings, ColorScheme, FontSize, VideoAutoplay, VideoVolume};
+pub use settings::{
+    AppSettings, ColorScheme, DesktopNotifications, FontSize, QuietHours, VideoAutoplay,
+    VideoVolume,
+};
@@ -73,72 +73,95 @@ impl SessionManager {
         Ok(())
     }
 
+    /// The first stored session, whichever account it belongs to.
+    ///
+    /// Only the fallback for an install with no active account recorded yet;
+    /// everything else knows which DID it wants and uses [`Self::load_for`].
     pub async fn load() -> Result<Session, SessionError> {
-        let ss = SecretService::connect(EncryptionType::Dh)
-            .await
-            .map_err(no_keyring)?;
+        let secrets = secrets_matching(vec![("application", APP_ID)]).await?;
+        let secret = secrets.first().ok_or(SessionError::NotFound)?;
+        parse_session(secret)
+    }
 
-        let collection = ss
-            .get_default_collection()
-            .await
-            .map_err(|e| SessionError::SecretService(e.to_string()))?;
+    /// One account's stored session.
+    pub async fn load_for(did: &str) -> Result<Session, SessionError> {
+        let secrets = secrets_matching(vec![("application", APP_ID), ("did", did)]).await?;
+        let secret = secrets.first().ok_or(SessionError::NotFound)?;
+        parse_session(secret)
+    }
 
-        if collection.is_locked().await.unwrap_or(true) {
-            collection
-                .unlock()
-                .await
-                .map_err(|e| SessionError::SecretService(e.to_string()))?;
-        }
+    /// Forget one account's stored session and leave the others signed in.
+    pub async fn clear_for(did: &str) -> Result<(), SessionError> {
+        delete_matching(vec![("application", APP_ID), ("did", did)]).await
+    }
+}
 
-        let attributes = vec![("application", APP_ID)];
-        let items = collection
-            .search_items(attributes.into_iter().collect())
-            .await
-            .map_err(|e| SessionError::SecretService(e.to_string()))?;
+fn parse_session(secret: &[u8]) -> Result<Session, SessionError> {
+    serde_json::from_slice(secret).map_err(|e| SessionError::InvalidData(e.to_string()))
+}
+
+/// The secret of every keyring item carrying `attributes`.
+async fn secrets_matching(attributes: Vec<(&str, &str)>) -> Result<Vec<Vec<u8>>, SessionError> {
+    let ss = SecretService::connect(EncryptionType::Dh)
+        .await
+        .map_err(no_keyring)?;
 
-        let item = items.first().ok_or(SessionError::NotFound)?;
+    let collection = ss
+        .get_default_collection()
+        .await
+        .map_err(|e| SessionError::SecretService(e.to_string()))?;
 
-        let secret = item
-            .get_secret()
+    if collection.is_locked().await.unwrap_or(true) {
+        collection
+            .unlock()
             .await
             .map_err(|e| SessionError::SecretService(e.to_string()))?;
+    }
 
-        let session: Session = serde_json::from_slice(&secret)
-            .map_err(|e| SessionError::InvalidData(e.to_string()))?;
+    let items = collection
+        .search_items(attributes.into_iter().collect())
+        .await
+        .map_err(|e| SessionError::SecretService(e.to_string()))?;
 
-        Ok(session)
+    let mut secrets = Vec::with_capacity(items.len());
+    for item in items {
+        secrets.push(
+            item.get_secret()
+                .await
+                .map_err(|e| SessionError::SecretService(e.to_string()))?,
+        );
     }
+    Ok(secrets)
+}
 
-    #[allow(dead_code)]
-    pub async fn clear() -> Result<(), SessionError> {
-        let ss = SecretService::connect(EncryptionType::Dh)
-            .await
-            .map_err(no_keyring)?;
+/// Delete every keyring item carrying `attributes`.
+async fn delete_matching(attributes: Vec<(&str, &str)>) -> Result<(), SessionError> {
+    let ss = SecretService::connect(EncryptionType::Dh)
+        .await
+        .map_err(no_keyring)?;
 
-        let collection = ss
-            .get_default_collection()
+    let collection = ss
+        .get_default_collection()
+        .await
+        .map_err(|e| SessionError::SecretService(e.to_string()))?;
+
+    if collection.is_locked().await.unwrap_or(true) {
+        collection
+            .unlock()
             .await
             .map_err(|e| SessionError::SecretService(e.to_string()))?;
+    }
 
-        if collection.is_locked().await.unwrap_or(true) {
-            collection
-                .unlock()
-                .await
-                .map_err(|e| SessionError::SecretService(e.to_string()))?;
-        }
+    let items = collection
+        .search_items(attributes.into_iter().collect())
+        .await
+        .map_err(|e| SessionError::SecretService(e.to_string()))?;
 
-        let attributes = vec![("application", APP_ID)];
-        let items = collection
-            .search_items(attributes.into_iter().collect())
+    for item in items {
+        item.delete()
             .await
             .map_err(|e| SessionError::SecretService(e.to_string()))?;
-
-        for item in items {
-            item.delete()
-                .await
-                .map_err(|e| SessionError::SecretService(e.to_string()))?;
-        }
-
-        Ok(())
     }
+
+    Ok(())
 }
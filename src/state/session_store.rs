@@ -135,9 +135,9 @@ impl FileSessionStore {
 
     /// Every DID this file still holds a credential for.
     ///
-    /// Hangar signs into one account at a time, so anything here that is
-    /// not the current account is a leftover from a sign-out that
-    /// predates the app deleting them, and it is still live.
+    /// Anything here that is not one of the signed-in accounts is a
+    /// leftover from a sign-out that predates the app deleting them, and
+    /// it is still live.
     pub fn stored_dids(&self) -> Result<Vec<Did>, StoreError> {
         let guard = self.inner.lock().map_err(|_| StoreError::Lock)?;
         Ok(guard.keys().cloned().collect())
@@ -151,6 +151,7 @@ impl FileSessionStore {
     /// on disk after the one action a user takes to end their session.
     pub fn forget(&self, did: &Did) -> Result<(), StoreError> {
         let mut guard = self.inner.lock().map_err(|_| StoreError::Lock)?;
+        self.merge_from_disk(&mut guard, did);
         guard.remove(did);
         self.save_to_disk(&guard)
     }
@@ -164,6 +165,7 @@ impl FileSessionStore {
     /// unrefreshed.
     pub fn invalidate_cached_expiry(&self, did: &Did) -> Result<(), StoreError> {
         let mut guard = self.inner.lock().map_err(|_| StoreError::Lock)?;
+        self.merge_from_disk(&mut guard, did);
         let Some(row) = guard.get_mut(did) else {
             return Ok(());
         };
@@ -193,6 +195,34 @@ impl FileSessionStore {
         }
     }
 
+    /// Take every row but `key`'s from the file before writing it back.
+    ///
+    /// Each signed-in account holds its own handle, loaded once, and each
+    /// refreshes on its own schedule. Writing a handle's whole map back
+    /// would put its stale copy of every other account over the tokens
+    /// that account just rotated, and a rotated-out refresh token is a
+    /// forced sign-in. So a handle owns only the row it is writing.
+    ///
+    /// No file yet means nothing was written elsewhere; keep what is here.
+    fn merge_from_disk(&self, sessions: &mut HashMap<Did, StoredSession>, key: &Did) {
+        if !self.path.exists() {
+            return;
+        }
+        let on_disk = match Self::load_from_disk(&self.path) {
+            Ok(on_disk) => on_disk,
+            Err(e) => {
+                eprintln!("hangar: could not re-read the session file before writing: {e}");
+                return;
+            }
+        };
+        let own = sessions.remove(key);
+        *sessions = on_disk;
+        sessions.remove(key);
+        if let Some(row) = own {
+            sessions.insert(key.clone(), row);
+        }
+    }
+
     fn save_to_disk(&self, sessions: &HashMap<Did, StoredSession>) -> Result<(), StoreError> {
         // Convert Did keys to String for JSON serialization
         let string_map: HashMap<String, &StoredSession> =
@@ -239,6 +269,7 @@ impl Store<Did, Session> for FileSessionStore {
 
     async fn set(&self, key: Did, value: Session) -> Result<(), Self::Error> {
         let mut guard = self.inner.lock().map_err(|_| StoreError::Lock)?;
+        self.merge_from_disk(&mut guard, &key);
         let row = self.bind(value, guard.get(&key));
         guard.insert(key, row);
         self.save_to_disk(&guard)?;
@@ -247,6 +278,7 @@ impl Store<Did, Session> for FileSessionStore {
 
     async fn del(&self, key: &Did) -> Result<(), Self::Error> {
         let mut guard = self.inner.lock().map_err(|_| StoreError::Lock)?;
+        self.merge_from_disk(&mut guard, key);
         guard.remove(key);
         self.save_to_disk(&guard)?;
         Ok(())
@@ -463,6 +495,67 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    /// Two accounts, two handles on the same file. One refreshing must not
+    /// write its stale copy of the other back over the other's new tokens.
+    #[test]
+    fn a_write_keeps_the_rows_other_handles_wrote() {
+        let dir = std::env::temp_dir().join(format!("hangar-merge-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let handle = || FileSessionStore {
+            inner: std::sync::Arc::new(Mutex::new(HashMap::new())),
+            path: dir.join("oauth-sessions.json"),
+            pending_redirect_uri: None,
+            pending_scopes: None,
+        };
+
+        let row: StoredSession = serde_json::from_str(LEGACY_ROW).unwrap();
+        let alice: Did = "did:plc:aaaaaaaaaaaaaaaaaaaaaaaa".parse().unwrap();
+        let bob: Did = "did:plc:bbbbbbbbbbbbbbbbbbbbbbbb".parse().unwrap();
+
+        // Both handles load the same starting file.
+        let first = handle();
+        {
+            let mut guard = first.inner.lock().unwrap();
+            guard.insert(alice.clone(), row.clone());
+            guard.insert(bob.clone(), row.clone());
+            first.save_to_disk(&guard).unwrap();
+        }
+        let second = FileSessionStore {
+            inner: std::sync::Arc::new(Mutex::new(
+                FileSessionStore::load_from_disk(&dir.join("oauth-sessions.json")).unwrap(),
+            )),
+            ..handle()
+        };
+
+        // Bob's handle rotates Bob's tokens.
+        let mut rotated = row.clone();
+        rotated.session.token_set.access_token = "rotated".to_string();
+        {
+            let mut guard = second.inner.lock().unwrap();
+            second.merge_from_disk(&mut guard, &bob);
+            guard.insert(bob.clone(), rotated);
+            second.save_to_disk(&guard).unwrap();
+        }
+
+        // Then Alice's handle, still holding Bob's old row, touches Alice.
+        first.invalidate_cached_expiry(&alice).unwrap();
+
+        let on_disk = FileSessionStore::load_from_disk(&first.path).unwrap();
+        assert_eq!(
+            on_disk[&bob].session.token_set.access_token, "rotated",
+            "Alice's write put Bob's old tokens back"
+        );
+        assert!(on_disk.contains_key(&alice));
+
+        // Forgetting Alice from Bob's handle leaves Bob's row alone.
+        second.forget(&alice).unwrap();
+        let on_disk = FileSessionStore::load_from_disk(&first.path).unwrap();
+        assert_eq!(on_disk.keys().collect::<Vec<_>>(), vec![&bob]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     /// atrium calls `set` after a refresh with no pending binding; it must not
     /// drop the stored one.
     #[test]
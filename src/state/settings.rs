@@ -4,6 +4,11 @@ use crate::config::APP_ID;
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The account whose settings `load` and `save` read and write. None before
+/// anyone has signed in, which uses the shared file.
+static ACCOUNT: Mutex<Option<String>> = Mutex::new(None);
 
 /// Font size as a scale factor (1.0 = default)
 /// Extended range for low-vision accessibility (WCAG 1.4.4)
@@ -136,6 +141,62 @@ impl VideoAutoplay {
     }
 }
 
+/// Which desktop notifications Hangar raises, and when it stays quiet.
+///
+/// Every toggle defaults to on. `#[serde(default)]` on the struct fills a
+/// toggle missing from an older file from the hand-written `Default`, not
+/// from `bool`'s false.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DesktopNotifications {
+    pub mentions: bool,
+    pub replies: bool,
+    pub quotes: bool,
+    pub follows: bool,
+    pub messages: bool,
+    /// Nothing is raised inside this window. None = never quiet.
+    pub quiet_hours: Option<QuietHours>,
+}
+
+impl Default for DesktopNotifications {
+    fn default() -> Self {
+        Self {
+            mentions: true,
+            replies: true,
+            quotes: true,
+            follows: true,
+            messages: true,
+            quiet_hours: None,
+        }
+    }
+}
+
+/// A daily window in local time, as minutes after midnight.
+///
+/// `start` after `end` runs past midnight: 22:00 to 07:00 is the usual
+/// night. Equal ends are an empty window, not a whole day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl QuietHours {
+    /// What the switch turns on before the times are touched.
+    pub const DEFAULT: Self = Self {
+        start: 22 * 60,
+        end: 7 * 60,
+    };
+
+    pub fn contains(self, minute: u16) -> bool {
+        match self.start.cmp(&self.end) {
+            std::cmp::Ordering::Equal => false,
+            std::cmp::Ordering::Less => minute >= self.start && minute < self.end,
+            std::cmp::Ordering::Greater => minute >= self.start || minute < self.end,
+        }
+    }
+}
+
 /// User-preferred color scheme
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ColorScheme {
@@ -200,11 +261,47 @@ pub struct AppSettings {
     /// Requires `#[serde(default)]`; [`Self::font_size`] explains why.
     #[serde(default)]
     pub video_autoplay: VideoAutoplay,
+    /// Desktop notification toggles and quiet hours.
+    #[serde(default)]
+    pub notifications: DesktopNotifications,
+    /// Keep running with the window closed, so notifications and messages
+    /// still arrive. Off by default: closing the window quits.
+    ///
+    /// App-wide, like the autostart entry it pairs with: only the shared
+    /// file's value counts, read and written through
+    /// [`Self::shared_run_in_background`] whichever account is on screen.
+    #[serde(default)]
+    pub run_in_background: bool,
+    /// Searches kept as feeds, each as it would be typed into the search
+    /// entry, in the order they were saved. Local: the account preferences
+    /// have no place for them.
+    #[serde(default)]
+    pub saved_searches: Vec<String>,
+    /// Activity lists only accounts this one follows: the server's
+    /// priority notifications.
+    #[serde(default)]
+    pub activity_priority: bool,
+    /// Domains whose links open without the warning about text that names
+    /// another site, lowercased and without `www.`.
+    #[serde(default)]
+    pub trusted_link_domains: Vec<String>,
 }
 
 impl AppSettings {
-    /// Get the settings file path (~/.config/<APP_ID>/settings.json)
-    fn settings_path() -> Option<PathBuf> {
+    /// Point `load` and `save` at `did`'s own settings, or back at the shared
+    /// file with `None`.
+    ///
+    /// Process-wide, like the file it picks: every call site is a disk
+    /// read-modify-write, so the switch has to happen before the window that
+    /// reads them is built.
+    pub fn use_account(did: Option<&str>) {
+        if let Ok(mut account) = ACCOUNT.lock() {
+            *account = did.map(str::to_string);
+        }
+    }
+
+    /// The shared settings file (~/.config/<APP_ID>/settings.json)
+    fn shared_path() -> Option<PathBuf> {
         dirs::config_dir().map(|mut p| {
             p.push(APP_ID);
             p.push("settings.json");
@@ -212,12 +309,61 @@ impl AppSettings {
         })
     }
 
+    /// The file in use: the account's own
+    /// (~/.config/<APP_ID>/accounts/<did>/settings.json) once one is signed
+    /// in, the shared one before.
+    fn settings_path() -> Option<PathBuf> {
+        let account = ACCOUNT.lock().ok().and_then(|a| a.clone());
+        match account {
+            Some(did) => dirs::config_dir().map(|mut p| {
+                p.push(APP_ID);
+                p.push("accounts");
+                // Same sanitizing as the cache directory.
+                p.push(did.replace(':', "_"));
+                p.push("settings.json");
+                p
+            }),
+            None => Self::shared_path(),
+        }
+    }
+
+    /// Whether closing the window leaves the app running, from the shared
+    /// file.
+    pub fn shared_run_in_background() -> bool {
+        Self::shared_path().is_some_and(|path| Self::load_from(&path).run_in_background)
+    }
+
+    /// Turn running in the background on or off in the shared file,
+    /// leaving every account's own settings alone.
+    pub fn set_shared_run_in_background(on: bool) -> Result<(), String> {
+        let path = Self::shared_path().ok_or("Could not determine config directory")?;
+        Self::set_run_in_background_at(&path, on)
+    }
+
+    fn set_run_in_background_at(path: &Path, on: bool) -> Result<(), String> {
+        let mut settings = Self::load_from(path);
+        settings.run_in_background = on;
+        settings.save_to(path)
+    }
+
     /// Load settings from disk, or return defaults if not found
     pub fn load() -> Self {
         let Some(path) = Self::settings_path() else {
             return Self::default();
         };
-        Self::load_from(&path)
+        Self::load_with_fallback(&path, Self::shared_path().as_deref())
+    }
+
+    /// Read `path`, or `shared` while `path` does not exist yet.
+    ///
+    /// An account's first launch starts from the shared file rather than
+    /// from defaults: that is what every account used before settings were
+    /// kept per account, and what the sign-in screen was drawn with.
+    fn load_with_fallback(path: &Path, shared: Option<&Path>) -> Self {
+        match shared {
+            Some(shared) if shared != path && !path.exists() => Self::load_from(shared),
+            _ => Self::load_from(path),
+        }
     }
 
     /// Read the file, falling back to defaults for this session.
@@ -373,6 +519,71 @@ mod tests {
 
     use super::*;
 
+    /// A second account starts from the shared settings, then keeps its
+    /// own: changing it leaves the shared file and the other account alone.
+    #[test]
+    fn an_account_starts_from_the_shared_settings_then_keeps_its_own() {
+        let dir = std::env::temp_dir().join(format!("hangar-per-account-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let shared = dir.join("settings.json");
+        let own = dir
+            .join("accounts")
+            .join("did_plc_bob")
+            .join("settings.json");
+
+        let before = AppSettings {
+            font_size: FontSize(1.25),
+            hide_replies_in_feed: true,
+            ..Default::default()
+        };
+        before.save_to(&shared).unwrap();
+
+        let mut bob = AppSettings::load_with_fallback(&own, Some(&shared));
+        assert_eq!(bob.font_size, FontSize(1.25), "did not start from shared");
+        assert!(bob.hide_replies_in_feed);
+
+        bob.hide_replies_in_feed = false;
+        bob.save_to(&own).unwrap();
+
+        let bob = AppSettings::load_with_fallback(&own, Some(&shared));
+        assert!(
+            !bob.hide_replies_in_feed,
+            "the account's own change was lost"
+        );
+        assert!(
+            AppSettings::load_with_fallback(&shared, Some(&shared)).hide_replies_in_feed,
+            "the account's change leaked into the shared file"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    /// Running in the background is one switch for the whole app: turning
+    /// it on changes the shared file and no account's own, and an account
+    /// file saying otherwise is not what gets read.
+    #[test]
+    fn running_in_the_background_is_kept_app_wide() {
+        let dir = std::env::temp_dir().join(format!("hangar-background-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let shared = dir.join("settings.json");
+        let own = dir
+            .join("accounts")
+            .join("did_plc_bob")
+            .join("settings.json");
+        AppSettings::default().save_to(&own).unwrap();
+
+        AppSettings::set_run_in_background_at(&shared, true).unwrap();
+        assert!(AppSettings::load_from(&shared).run_in_background);
+        assert!(
+            !AppSettings::load_from(&own).run_in_background,
+            "the switch leaked into an account's own file"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     /// Both paths into `video_autoplay` agree on "off".
     #[test]
     fn autoplay_defaults_to_never_from_both_paths() {
@@ -423,6 +634,15 @@ mod tests {
             hide_replies_in_feed: true,
             video_volume: VideoVolume(0.25),
             video_autoplay: VideoAutoplay::WithSound,
+            notifications: DesktopNotifications {
+                follows: false,
+                quiet_hours: Some(QuietHours::DEFAULT),
+                ..Default::default()
+            },
+            run_in_background: true,
+            saved_searches: vec!["hangar lang:en".to_string()],
+            activity_priority: true,
+            trusted_link_domains: vec!["example.com".to_string()],
         };
 
         let full = serde_json::to_value(&populated).expect("settings serialize");
@@ -430,7 +650,7 @@ mod tests {
         let keys: Vec<String> = full.keys().cloned().collect();
         assert_eq!(
             keys.len(),
-            10,
+            15,
             "field count changed; add the new field to `populated` above so it is \
              exercised with a non-default value: {keys:?}"
         );
@@ -557,4 +777,40 @@ mod tests {
         assert!(VideoAutoplay::Muted.starts_muted());
         assert!(!VideoAutoplay::WithSound.starts_muted());
     }
+
+    /// A toggle added after a file was written reads as on, the way a fresh
+    /// install has it, not as `bool`'s false.
+    #[test]
+    fn a_notification_toggle_missing_from_the_file_reads_as_on() {
+        let loaded: AppSettings =
+            serde_json::from_str(r#"{"notifications":{"mentions":false}}"#).expect("loads");
+        assert!(!loaded.notifications.mentions);
+        assert!(loaded.notifications.replies);
+        assert!(loaded.notifications.messages);
+        assert_eq!(loaded.notifications.quiet_hours, None);
+    }
+
+    /// Quiet hours that cross midnight cover both ends of the day.
+    #[test]
+    fn quiet_hours_wrap_past_midnight() {
+        let night = QuietHours::DEFAULT;
+        assert!(night.contains(23 * 60));
+        assert!(night.contains(0));
+        assert!(night.contains(6 * 60 + 59));
+        assert!(!night.contains(7 * 60), "the end is exclusive");
+        assert!(!night.contains(12 * 60));
+
+        let lunch = QuietHours {
+            start: 12 * 60,
+            end: 13 * 60,
+        };
+        assert!(lunch.contains(12 * 60 + 30));
+        assert!(!lunch.contains(11 * 60));
+
+        let empty = QuietHours {
+            start: 600,
+            end: 600,
+        };
+        assert!(!empty.contains(600), "equal ends are no window at all");
+    }
 }
@@ -19,6 +19,10 @@ thread_local! {
     static FOLLOW_HANDLER: std::cell::RefCell<
         Option<Box<dyn Fn(Profile, glib::WeakRef<ActorRow>)>>,
     > = const { std::cell::RefCell::new(None) };
+    /// What the Add to Lists button does; the row shows it only once one
+    /// is installed.
+    static ADD_TO_LISTS_HANDLER: std::cell::RefCell<Option<Box<dyn Fn(Profile)>>> =
+        const { std::cell::RefCell::new(None) };
 }
 
 /// Record whose rows hide the follow button. `None` on sign-out.
@@ -36,6 +40,14 @@ pub fn set_follow_handler<F: Fn(Profile, glib::WeakRef<ActorRow>) + 'static>(han
     });
 }
 
+/// Install the app-level Add to Lists flow, which asks the server which
+/// of the user's lists the person is on.
+pub fn set_add_to_lists_handler<F: Fn(Profile) + 'static>(handler: F) {
+    ADD_TO_LISTS_HANDLER.with(|cell| {
+        cell.replace(Some(Box::new(handler)));
+    });
+}
+
 mod actor_object {
     use super::*;
     use std::cell::RefCell;
@@ -98,6 +110,7 @@ mod imp {
         pub follow_label: RefCell<Option<gtk4::Label>>,
         pub bio_label: RefCell<Option<gtk4::Label>>,
         pub follow_btn: RefCell<Option<gtk4::Button>>,
+        pub lists_btn: RefCell<Option<gtk4::Button>>,
         /// The model object behind the current bind, so a follow that
         /// settles after a scroll still lands in the list's state.
         pub bound_object: RefCell<Option<glib::WeakRef<super::ActorObject>>>,
@@ -234,6 +247,29 @@ impl ActorRow {
         });
         main_box.append(&follow_btn);
 
+        let lists_btn = gtk4::Button::from_icon_name("list-add-symbolic");
+        lists_btn.add_css_class("flat");
+        lists_btn.add_css_class("circular");
+        lists_btn.set_valign(gtk4::Align::Center);
+        lists_btn.set_tooltip_text(Some("Add to Lists"));
+        lists_btn.update_property(&[gtk4::accessible::Property::Label("Add to Lists")]);
+        lists_btn.set_visible(false);
+        let row_weak = self.downgrade();
+        lists_btn.connect_clicked(move |_| {
+            let Some(row) = row_weak.upgrade() else {
+                return;
+            };
+            let Some(profile) = row.imp().profile.borrow().clone() else {
+                return;
+            };
+            ADD_TO_LISTS_HANDLER.with(|cell| {
+                if let Some(handler) = cell.borrow().as_ref() {
+                    handler(profile);
+                }
+            });
+        });
+        main_box.append(&lists_btn);
+
         self.append(&main_box);
 
         let sep = gtk4::Separator::new(gtk4::Orientation::Horizontal);
@@ -258,6 +294,7 @@ impl ActorRow {
         imp.follow_label.replace(Some(follow_label));
         imp.bio_label.replace(Some(bio_label));
         imp.follow_btn.replace(Some(follow_btn));
+        imp.lists_btn.replace(Some(lists_btn));
     }
 
     /// Show `profile`. Rebinding a recycled row overwrites everything the
@@ -315,10 +352,10 @@ impl ActorRow {
             }
         }
 
+        let own_row = VIEWER_DID
+            .with(|cell| cell.borrow().clone())
+            .is_none_or(|did| did == profile.did);
         if let Some(btn) = imp.follow_btn.borrow().as_ref() {
-            let own_row = VIEWER_DID
-                .with(|cell| cell.borrow().clone())
-                .is_none_or(|did| did == profile.did);
             btn.set_visible(!own_row);
             // sync_follow_button re-enables a row recycled mid-flight.
             super::window::HangarWindow::sync_follow_button(
@@ -327,6 +364,11 @@ impl ActorRow {
             );
         }
 
+        if let Some(btn) = imp.lists_btn.borrow().as_ref() {
+            let installed = ADD_TO_LISTS_HANDLER.with(|cell| cell.borrow().is_some());
+            btn.set_visible(installed && !own_row);
+        }
+
         let a11y_label = format!("{}, @{}", display_name, profile.handle);
         self.update_property(&[gtk4::accessible::Property::Label(&a11y_label)]);
     }
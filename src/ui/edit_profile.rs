@@ -252,11 +252,12 @@ pub(crate) fn build(profile: &Profile) -> EditProfileParts {
 }
 
 /// Show the dialog over `parent`; Save hands the edits over and closes.
+/// Returns the dialog, for callers that need to know when it goes away.
 pub fn present(
     parent: &impl IsA<gtk4::Widget>,
     profile: &Profile,
     on_save: impl Fn(ProfileEdits) + 'static,
-) {
+) -> adw::Dialog {
     let parts = build(profile);
     let dialog = parts.dialog.clone();
     let name_entry = parts.name_entry.clone();
@@ -281,6 +282,7 @@ pub fn present(
     });
 
     parts.dialog.present(Some(parent));
+    parts.dialog
 }
 
 #[cfg(test)]
@@ -1,6 +1,10 @@
 // SPDX-License-Identifier: MPL-2.0
 
 //! Browser and clipboard hand-offs, each with a toast.
+//!
+//! A link whose text names one site and whose target is another is held
+//! for confirmation first: a post can show `bsky.app` and send the click
+//! somewhere else entirely, through a facet or a card title.
 
 use crate::ui::HangarWindow;
 use gtk4::prelude::*;
@@ -73,6 +77,154 @@ fn is_web_uri(uri: &str) -> bool {
     Url::parse(uri).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
 }
 
+/// A host as links are compared: lowercased, without a leading `www.`.
+fn normalize_host(host: &str) -> String {
+    let host = host.to_ascii_lowercase();
+    match host.strip_prefix("www.") {
+        Some(rest) => rest.to_string(),
+        None => host,
+    }
+}
+
+/// The host `visible` claims to be, if it reads as a domain or a web address
+/// at all. Plain words, sentences and numbers claim nothing.
+fn claimed_host(visible: &str) -> Option<String> {
+    let visible = visible.trim();
+    // Bluesky shortens long link text with an ellipsis.
+    let visible = visible
+        .strip_suffix('…')
+        .or_else(|| visible.strip_suffix("..."))
+        .unwrap_or(visible);
+    if visible.is_empty() || visible.contains(char::is_whitespace) || !visible.contains('.') {
+        return None;
+    }
+    let url = if visible.contains("://") {
+        Url::parse(visible).ok()?
+    } else {
+        Url::parse(&format!("https://{visible}")).ok()?
+    };
+    let host = url.host_str()?;
+    // "v1.2" and "e.g." have dots but no top-level domain.
+    let tld = host.rsplit('.').next()?;
+    if tld.len() < 2 || !tld.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some(normalize_host(host))
+}
+
+/// The host a link card's title claims. A title is the page's own
+/// headline, and "Example.com" there names a site in passing; only a
+/// title written as an address, with a scheme or a path, is a claim.
+fn card_claimed_host(title: &str) -> Option<String> {
+    let title = title.trim();
+    if !title.contains("://") && !title.contains('/') {
+        return None;
+    }
+    claimed_host(title)
+}
+
+/// Where `uri` really goes, when `visible` names somewhere else.
+///
+/// `None` when the text names no site, or the same one the link goes to.
+fn deceptive_destination(visible: &str, uri: &str) -> Option<String> {
+    elsewhere(claimed_host(visible)?, uri)
+}
+
+/// [`deceptive_destination`] for a link card's title.
+fn deceptive_card_destination(title: &str, uri: &str) -> Option<String> {
+    elsewhere(card_claimed_host(title)?, uri)
+}
+
+/// `uri`'s host, unless it is `claimed` or a subdomain of it: a link
+/// reading "example.com" may well go to blog.example.com.
+fn elsewhere(claimed: String, uri: &str) -> Option<String> {
+    let real = normalize_host(Url::parse(uri).ok()?.host_str()?);
+    let same_site = real == claimed || real.ends_with(&format!(".{claimed}"));
+    (!same_site).then_some(real)
+}
+
+/// Open a link the person clicked on, after checking that `visible`, the
+/// text it was shown as, does not name another site.
+///
+/// When it does, and the real destination is not one they already said to
+/// always allow, a dialog names where the link goes before anything opens.
+/// Otherwise this is [`open_url`].
+pub fn open_link(widget: &impl IsA<gtk4::Widget>, url: &str, visible: &str, what: &str) {
+    let real = is_web_uri(url)
+        .then(|| deceptive_destination(visible, url))
+        .flatten();
+    confirm_and_open(widget.as_ref(), url, visible, real, what);
+}
+
+/// [`open_link`] for a link card, checked against its title.
+pub fn open_card_link(widget: &impl IsA<gtk4::Widget>, url: &str, title: &str) {
+    let real = is_web_uri(url)
+        .then(|| deceptive_card_destination(title, url))
+        .flatten();
+    confirm_and_open(widget.as_ref(), url, title, real, "link");
+}
+
+/// Open `url`, asking first when `real` says it goes somewhere other than
+/// `visible` reads.
+fn confirm_and_open(
+    widget: &gtk4::Widget,
+    url: &str,
+    visible: &str,
+    real: Option<String>,
+    what: &str,
+) {
+    let Some(host) = real else {
+        open_url(widget, url, what);
+        return;
+    };
+    if crate::state::AppSettings::load()
+        .trusted_link_domains
+        .contains(&host)
+    {
+        open_url(widget, url, what);
+        return;
+    }
+
+    let dialog = adw::AlertDialog::new(
+        Some("Open a different site?"),
+        Some(&format!(
+            "The link reads \"{}\" but goes to {host}.",
+            visible.trim()
+        )),
+    );
+    dialog.add_response("cancel", "Cancel");
+    dialog.add_response("trust", &format!("Always Allow {host}"));
+    dialog.add_response("open", "Open");
+    dialog.set_response_appearance("open", adw::ResponseAppearance::Suggested);
+    dialog.set_default_response(Some("cancel"));
+    dialog.set_close_response("cancel");
+
+    let weak = widget.downgrade();
+    let url = url.to_string();
+    let what = what.to_string();
+    dialog.connect_response(None, move |_, response| {
+        let Some(widget) = weak.upgrade() else {
+            return;
+        };
+        match response {
+            "trust" => {
+                let mut settings = crate::state::AppSettings::load();
+                if !settings.trusted_link_domains.contains(&host) {
+                    settings.trusted_link_domains.push(host.clone());
+                }
+                if let Err(e) = settings.save() {
+                    // Still open it: the person asked for this link either way.
+                    eprintln!("Failed to save settings: {}", e);
+                }
+                open_url(&widget, &url, &what);
+            }
+            "open" => open_url(&widget, &url, &what),
+            _ => {}
+        }
+    });
+    dialog.present(Some(widget));
+}
+
 /// Open `url` in the user's browser and report which way it went.
 ///
 /// Anything that is not http or https is not dispatched at all: the uri is
@@ -167,6 +319,74 @@ mod tests {
         }
     }
 
+    /// Text that names the site it links to, however it is written or
+    /// shortened, opens without asking; otherwise every link would warn.
+    #[test]
+    fn link_text_naming_the_real_site_is_not_deceptive() {
+        for (visible, uri) in [
+            (
+                "example.com/some/long/pa…",
+                "https://example.com/some/long/path",
+            ),
+            ("https://www.Example.com", "https://example.com/"),
+            ("example.com", "https://www.example.com/a"),
+            ("read this", "https://example.com/"),
+            ("v1.2", "https://example.com/"),
+            ("e.g.", "https://example.com/"),
+            ("", "https://example.com/"),
+            ("example.com", "https://blog.example.com/post"),
+        ] {
+            assert_eq!(
+                deceptive_destination(visible, uri),
+                None,
+                "{visible} -> {uri}"
+            );
+        }
+    }
+
+    /// Text that names another site is held, and the dialog names where
+    /// the link actually goes, not what it claims.
+    #[test]
+    fn link_text_naming_another_site_names_the_real_one() {
+        assert_eq!(
+            deceptive_destination("bsky.app/profile/alice", "https://evil.example/login"),
+            Some("evil.example".to_string())
+        );
+        assert_eq!(
+            deceptive_destination("https://paypal.com", "http://www.paypal.com.evil.example/"),
+            Some("paypal.com.evil.example".to_string())
+        );
+        assert_eq!(
+            deceptive_destination("example.com", "https://notexample.com/"),
+            Some("notexample.com".to_string())
+        );
+    }
+
+    /// A card title is a headline, so one that merely mentions a site is
+    /// no claim; one written as an address is held like link text.
+    #[test]
+    fn card_titles_claim_a_site_only_as_an_address() {
+        for title in ["Example.com", "Why example.com matters", "v1.2 released"] {
+            assert_eq!(
+                deceptive_card_destination(title, "https://news.example/"),
+                None,
+                "{title}"
+            );
+        }
+        assert_eq!(
+            deceptive_card_destination("paypal.com/login", "https://evil.example/"),
+            Some("evil.example".to_string())
+        );
+        assert_eq!(
+            deceptive_card_destination("https://paypal.com", "https://evil.example/"),
+            Some("evil.example".to_string())
+        );
+        assert_eq!(
+            deceptive_card_destination("example.com/blog", "https://blog.example.com/"),
+            None
+        );
+    }
+
     #[test]
     fn ordinary_links_still_open() {
         for ok in [
@@ -0,0 +1,417 @@
+// SPDX-License-Identifier: MPL-2.0
+#![allow(clippy::type_complexity)]
+
+//! Discover Feeds: the feeds the server suggests, or the popular ones
+//! matching a search.
+//!
+//! Built like [`super::ListsPage`], with a search entry over the rows.
+//! Typing starts the page over; each search bumps a generation so a page
+//! still in flight for the old words is dropped when it lands.
+
+use crate::atproto::FeedGenerator;
+use crate::ui::avatar_cache;
+use gtk4::glib;
+use gtk4::prelude::*;
+use gtk4::subclass::prelude::*;
+use libadwaita as adw;
+use libadwaita::prelude::*;
+
+mod imp {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    #[derive(Default)]
+    pub struct FeedBrowser {
+        pub list_box: RefCell<Option<gtk4::ListBox>>,
+        pub scrolled: RefCell<Option<gtk4::ScrolledWindow>>,
+        pub spinner: RefCell<Option<gtk4::Spinner>>,
+        pub empty_state: RefCell<Option<adw::StatusPage>>,
+        pub error_state: RefCell<Option<adw::StatusPage>>,
+        /// The feeds behind the rows, in row order.
+        pub feeds: RefCell<Vec<FeedGenerator>>,
+        /// The search the rows are for; empty for the suggestions.
+        pub query: RefCell<String>,
+        pub generation: Cell<u64>,
+        pub cursor: RefCell<Option<String>>,
+        pub fetching: Cell<bool>,
+        pub loaded_once: Cell<bool>,
+        pub feed_activated_callback: RefCell<Option<Box<dyn Fn(FeedGenerator) + 'static>>>,
+        pub load_more_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        pub retry_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        pub search_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for FeedBrowser {
+        const NAME: &'static str = "HangarFeedBrowser";
+        type Type = super::FeedBrowser;
+        type ParentType = gtk4::Box;
+    }
+
+    impl ObjectImpl for FeedBrowser {}
+    impl WidgetImpl for FeedBrowser {}
+    impl BoxImpl for FeedBrowser {}
+}
+
+glib::wrapper! {
+    pub struct FeedBrowser(ObjectSubclass<imp::FeedBrowser>)
+        @extends gtk4::Box, gtk4::Widget,
+        @implements gtk4::Accessible, gtk4::Buildable, gtk4::ConstraintTarget, gtk4::Orientable;
+}
+
+impl Default for FeedBrowser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeedBrowser {
+    pub fn new() -> Self {
+        let page: Self = glib::Object::builder()
+            .property("orientation", gtk4::Orientation::Vertical)
+            .property("spacing", 0)
+            .build();
+        page.setup_ui();
+        page
+    }
+
+    fn setup_ui(&self) {
+        self.set_vexpand(true);
+
+        let search_entry = gtk4::SearchEntry::new();
+        search_entry.set_placeholder_text(Some("Search feeds"));
+        search_entry.update_property(&[gtk4::accessible::Property::Label("Search feeds")]);
+        let page_weak = self.downgrade();
+        search_entry.connect_search_changed(move |entry| {
+            if let Some(page) = page_weak.upgrade() {
+                page.begin_search(entry.text().trim());
+            }
+        });
+
+        let search_clamp = adw::Clamp::new();
+        search_clamp.set_maximum_size(800);
+        search_clamp.set_tightening_threshold(600);
+        search_clamp.set_margin_top(12);
+        search_clamp.set_margin_start(12);
+        search_clamp.set_margin_end(12);
+        search_clamp.set_child(Some(&search_entry));
+        self.append(&search_clamp);
+
+        let list_box = gtk4::ListBox::new();
+        list_box.add_css_class("boxed-list");
+        list_box.set_selection_mode(gtk4::SelectionMode::None);
+        list_box.set_valign(gtk4::Align::Start);
+        let page_weak = self.downgrade();
+        list_box.connect_row_activated(move |_, row| {
+            let Some(page) = page_weak.upgrade() else {
+                return;
+            };
+            let feed = usize::try_from(row.index())
+                .ok()
+                .and_then(|i| page.imp().feeds.borrow().get(i).cloned());
+            if let Some(feed) = feed
+                && let Some(cb) = page.imp().feed_activated_callback.borrow().as_ref()
+            {
+                cb(feed);
+            }
+        });
+
+        let column = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        column.set_margin_top(12);
+        column.set_margin_bottom(24);
+        column.set_margin_start(12);
+        column.set_margin_end(12);
+        column.append(&list_box);
+
+        let spinner = gtk4::Spinner::new();
+        spinner.update_property(&[gtk4::accessible::Property::Label("Loading")]);
+        spinner.set_visible(false);
+        spinner.set_halign(gtk4::Align::Center);
+        spinner.set_margin_top(16);
+        column.append(&spinner);
+
+        let clamp = adw::Clamp::new();
+        clamp.set_maximum_size(800);
+        clamp.set_tightening_threshold(600);
+        clamp.set_child(Some(&column));
+
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_vexpand(true);
+        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled.set_child(Some(&clamp));
+
+        let empty_state = adw::StatusPage::new();
+        empty_state.set_icon_name(Some("system-search-symbolic"));
+        empty_state.set_title("No Feeds Found");
+        empty_state.set_description(Some(
+            "Try other words, or clear the search for suggestions.",
+        ));
+        empty_state.set_vexpand(true);
+        empty_state.set_visible(false);
+
+        let error_state = adw::StatusPage::new();
+        error_state.set_icon_name(Some("dialog-warning-symbolic"));
+        error_state.set_title("Couldn't Load Feeds");
+        error_state.set_description(Some("Check your connection and try again."));
+        error_state.set_vexpand(true);
+        error_state.set_visible(false);
+
+        let retry_button = gtk4::Button::with_label("Try Again");
+        retry_button.add_css_class("pill");
+        retry_button.add_css_class("suggested-action");
+        retry_button.set_halign(gtk4::Align::Center);
+        let page_weak = self.downgrade();
+        retry_button.connect_clicked(move |_| {
+            if let Some(page) = page_weak.upgrade()
+                && let Some(cb) = page.imp().retry_callback.borrow().as_ref()
+            {
+                cb();
+            }
+        });
+        error_state.set_child(Some(&retry_button));
+
+        self.append(&scrolled);
+        self.append(&empty_state);
+        self.append(&error_state);
+
+        let page_weak = self.downgrade();
+        scrolled.vadjustment().connect_value_changed(move |adj| {
+            let Some(page) = page_weak.upgrade() else {
+                return;
+            };
+            if adj.value() >= adj.upper() - adj.page_size() - 200.0
+                && let Some(cb) = page.imp().load_more_callback.borrow().as_ref()
+            {
+                cb();
+            }
+        });
+
+        let imp = self.imp();
+        imp.list_box.replace(Some(list_box));
+        imp.scrolled.replace(Some(scrolled));
+        imp.spinner.replace(Some(spinner));
+        imp.empty_state.replace(Some(empty_state));
+        imp.error_state.replace(Some(error_state));
+    }
+
+    fn build_row(feed: &FeedGenerator) -> adw::ActionRow {
+        let row = adw::ActionRow::new();
+        // Names and descriptions are whatever their authors typed.
+        row.set_use_markup(false);
+        row.set_title(&feed.display_name);
+        match feed.description.as_deref().filter(|d| !d.is_empty()) {
+            Some(description) => {
+                row.set_subtitle(&format!("{}\n{description}", feed.summary()));
+                row.set_subtitle_lines(3);
+            }
+            None => {
+                row.set_subtitle(&feed.summary());
+                row.set_subtitle_lines(1);
+            }
+        }
+        row.set_activatable(true);
+
+        let avatar = adw::Avatar::new(40, Some(&feed.display_name), false);
+        if let Some(url) = &feed.avatar {
+            avatar_cache::load_avatar(avatar.clone(), url.clone());
+        }
+        row.add_prefix(&avatar);
+        row.add_suffix(&gtk4::Image::from_icon_name("go-next-symbolic"));
+        row
+    }
+
+    /// Start over for `query`, and ask the app for its first page.
+    fn begin_search(&self, query: &str) {
+        let imp = self.imp();
+        if *imp.query.borrow() == query && imp.loaded_once.get() {
+            return;
+        }
+        imp.query.replace(query.to_string());
+        self.clear();
+        imp.fetching.set(false);
+        if let Some(cb) = imp.search_callback.borrow().as_ref() {
+            cb();
+        }
+    }
+
+    /// Add a fetched page of feeds. As [`super::ListsPage::append_lists`],
+    /// the cursor is stored first.
+    pub fn append_feeds(&self, feeds: Vec<FeedGenerator>) {
+        let imp = self.imp();
+        if let Some(list_box) = imp.list_box.borrow().as_ref() {
+            for feed in &feeds {
+                list_box.append(&Self::build_row(feed));
+            }
+        }
+        imp.feeds.borrow_mut().extend(feeds);
+        imp.loaded_once.set(true);
+
+        let empty = imp.feeds.borrow().is_empty() && imp.cursor.borrow().is_none();
+        if let Some(scrolled) = imp.scrolled.borrow().as_ref() {
+            scrolled.set_visible(!empty);
+        }
+        if let Some(empty_state) = imp.empty_state.borrow().as_ref() {
+            empty_state.set_visible(empty);
+        }
+        if let Some(error_state) = imp.error_state.borrow().as_ref() {
+            error_state.set_visible(false);
+        }
+    }
+
+    /// Drop every row and strand any page still in flight.
+    fn clear(&self) {
+        let imp = self.imp();
+        imp.generation.set(imp.generation.get() + 1);
+        if let Some(list_box) = imp.list_box.borrow().as_ref() {
+            list_box.remove_all();
+        }
+        imp.feeds.borrow_mut().clear();
+        imp.cursor.replace(None);
+        imp.loaded_once.set(false);
+    }
+
+    /// The search the rows are for; empty means the suggestions.
+    pub fn query(&self) -> String {
+        self.imp().query.borrow().clone()
+    }
+
+    /// Bumped by every new search; a fetch that started under another
+    /// generation has nothing to add.
+    pub fn generation(&self) -> u64 {
+        self.imp().generation.get()
+    }
+
+    /// As [`super::ListsPage::set_fetching`].
+    pub fn set_fetching(&self, fetching: bool) {
+        let imp = self.imp();
+        imp.fetching.set(fetching);
+        if fetching
+            && let Some(error_state) = imp.error_state.borrow().as_ref()
+            && error_state.is_visible()
+        {
+            error_state.set_visible(false);
+            if let Some(scrolled) = imp.scrolled.borrow().as_ref() {
+                scrolled.set_visible(true);
+            }
+        }
+        if let Some(spinner) = imp.spinner.borrow().as_ref() {
+            spinner.set_visible(fetching);
+            spinner.set_spinning(fetching);
+        }
+    }
+
+    pub fn is_fetching(&self) -> bool {
+        self.imp().fetching.get()
+    }
+
+    pub fn set_cursor(&self, cursor: Option<String>) {
+        self.imp().cursor.replace(cursor);
+    }
+
+    /// The cursor for the next page, if the server said there is one.
+    pub fn cursor(&self) -> Option<String> {
+        self.imp().cursor.borrow().clone()
+    }
+
+    /// Swap in the error state after a failed fetch, unless rows are shown.
+    pub fn show_load_failed(&self) {
+        let imp = self.imp();
+        if !imp.feeds.borrow().is_empty() {
+            return;
+        }
+        if let Some(scrolled) = imp.scrolled.borrow().as_ref() {
+            scrolled.set_visible(false);
+        }
+        if let Some(empty_state) = imp.empty_state.borrow().as_ref() {
+            empty_state.set_visible(false);
+        }
+        if let Some(error_state) = imp.error_state.borrow().as_ref() {
+            error_state.set_visible(true);
+        }
+    }
+
+    /// Replace the handler run when a row is activated.
+    pub fn set_feed_activated_callback<F: Fn(FeedGenerator) + 'static>(&self, callback: F) {
+        self.imp()
+            .feed_activated_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Replace the handler run when the rows near their bottom.
+    pub fn set_load_more_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp()
+            .load_more_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Replace the handler run by the error state's retry button.
+    pub fn set_retry_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp().retry_callback.replace(Some(Box::new(callback)));
+    }
+
+    /// Replace the handler run when the search changes; the page is
+    /// already cleared, and [`Self::query`] holds the new words.
+    pub fn set_search_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp().search_callback.replace(Some(Box::new(callback)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atproto::Profile;
+
+    fn a_feed(name: &str) -> FeedGenerator {
+        FeedGenerator {
+            uri: format!("at://did:plc:gen/app.bsky.feed.generator/{name}"),
+            cid: "bafy".into(),
+            did: "did:web:gen.example".into(),
+            display_name: name.into(),
+            description: Some("Posts about things".into()),
+            avatar: None,
+            creator: Profile::minimal("did:plc:gen".into(), "gen.bsky.social".into(), None, None),
+            like_count: Some(12),
+            viewer_like: None,
+            accepts_interactions: false,
+        }
+    }
+
+    /// A new search clears the rows and moves the generation on, so a
+    /// page fetched for the old words can tell it is stale; rows open the
+    /// feed they show.
+    #[test]
+    fn a_new_search_starts_over_and_strands_old_pages() {
+        crate::ui::with_gtk(a_new_search_starts_over_and_strands_old_pages_body);
+    }
+
+    fn a_new_search_starts_over_and_strands_old_pages_body() {
+        use std::cell::{Cell, RefCell};
+        use std::rc::Rc;
+
+        let page = FeedBrowser::new();
+        let imp = page.imp();
+        let searches = Rc::new(Cell::new(0));
+        let sink = searches.clone();
+        page.set_search_callback(move || sink.set(sink.get() + 1));
+        let opened: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(vec![]));
+        let sink = opened.clone();
+        page.set_feed_activated_callback(move |feed| sink.borrow_mut().push(feed.display_name));
+
+        page.set_cursor(Some("next".into()));
+        page.append_feeds(vec![a_feed("art"), a_feed("news")]);
+        let list_box = imp.list_box.borrow().clone().unwrap();
+        list_box.row_at_index(1).unwrap().emit_activate();
+        assert_eq!(opened.borrow().as_slice(), ["news"]);
+
+        let before = page.generation();
+        page.begin_search("cats");
+        assert_eq!(searches.get(), 1);
+        assert_eq!(page.query(), "cats");
+        assert_ne!(page.generation(), before, "the old page is stranded");
+        assert!(page.cursor().is_none());
+        assert!(list_box.row_at_index(0).is_none());
+
+        page.append_feeds(vec![]);
+        assert!(imp.empty_state.borrow().as_ref().unwrap().is_visible());
+    }
+}
@@ -1101,6 +1101,9 @@ mod tests {
                 viewer_muted: false,
                 viewer_blocking: None,
                 viewer_blocked_by: false,
+                labels: Vec::new(),
+                muted_by_list: None,
+                blocking_by_list: None,
             },
             text: format!("post {name}"),
             created_at: "2026-01-01T00:00:00Z".into(),
@@ -1121,6 +1124,9 @@ mod tests {
             viewer_bookmarked: None,
             repost_reason: None,
             reply_context: None,
+            labels: Vec::new(),
+            feed_context: None,
+            facets: Vec::new(),
         }
     }
 
@@ -0,0 +1,634 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Dialogs for lists: making or editing one, choosing which of your
+//! lists an account is on, and making a starter pack from one.
+//!
+//! The editor only asks for a purpose when a list is made; the lexicon
+//! lets it change later, but a moderation list turned into a feed would
+//! surprise everyone subscribed to it.
+
+use crate::atproto::client::STARTER_PACK_FEED_CAP;
+use crate::atproto::{ListPurpose, SavedFeed, UserList};
+use gtk4::glib;
+use gtk4::prelude::*;
+use libadwaita as adw;
+use libadwaita::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The lexicon's limit on a list name, in graphemes; characters are close
+/// enough for a Save button.
+const NAME_MAX: usize = 64;
+/// The lexicon's limit on a starter pack name.
+const PACK_NAME_MAX: usize = 50;
+
+/// The pieces a test needs to drive the editor without a pointer.
+pub(crate) struct ListEditorParts {
+    pub dialog: adw::Dialog,
+    pub name: adw::EntryRow,
+    pub description: gtk4::TextView,
+    /// Empty when editing: the purpose stays what it was.
+    pub purposes: Vec<(gtk4::CheckButton, ListPurpose)>,
+    pub save: gtk4::Button,
+}
+
+impl ListEditorParts {
+    /// The purpose picked, or a curated list when editing.
+    pub fn selected_purpose(&self) -> ListPurpose {
+        self.purposes
+            .iter()
+            .find(|(check, _)| check.is_active())
+            .map_or(ListPurpose::Curate, |(_, purpose)| *purpose)
+    }
+
+    fn description_text(&self) -> String {
+        let buffer = self.description.buffer();
+        buffer
+            .text(&buffer.start_iter(), &buffer.end_iter(), false)
+            .trim()
+            .to_string()
+    }
+}
+
+/// Whether `name` can be saved as a list name.
+fn name_ok(name: &str) -> bool {
+    name_fits(name, NAME_MAX)
+}
+
+fn name_fits(name: &str, max: usize) -> bool {
+    let name = name.trim();
+    !name.is_empty() && name.chars().count() <= max
+}
+
+pub(crate) fn build_editor(existing: Option<&UserList>) -> ListEditorParts {
+    let title_text = if existing.is_some() {
+        "Edit List"
+    } else {
+        "New List"
+    };
+    let content = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+
+    let header = adw::HeaderBar::new();
+    let title = gtk4::Label::new(Some(title_text));
+    title.add_css_class("title");
+    header.set_title_widget(Some(&title));
+    let save = gtk4::Button::with_label(if existing.is_some() { "Save" } else { "Create" });
+    save.add_css_class("suggested-action");
+    header.pack_end(&save);
+    content.append(&header);
+
+    let body = gtk4::Box::new(gtk4::Orientation::Vertical, 12);
+    body.set_margin_start(16);
+    body.set_margin_end(16);
+    body.set_margin_top(12);
+    body.set_margin_bottom(16);
+
+    let fields = gtk4::ListBox::new();
+    fields.add_css_class("boxed-list");
+    fields.set_selection_mode(gtk4::SelectionMode::None);
+    let name = adw::EntryRow::new();
+    name.set_title("Name");
+    if let Some(list) = existing {
+        name.set_text(&list.name);
+    }
+    fields.append(&name);
+    body.append(&fields);
+
+    let mut purposes = Vec::new();
+    if existing.is_none() {
+        let mut first: Option<gtk4::CheckButton> = None;
+        for (purpose, description) in [
+            (ListPurpose::Curate, "A feed of posts from the people on it"),
+            (ListPurpose::Moderation, "People to mute or block together"),
+        ] {
+            let check = gtk4::CheckButton::new();
+            let text = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+            let label = gtk4::Label::new(Some(purpose.label()));
+            label.set_halign(gtk4::Align::Start);
+            text.append(&label);
+            let desc = gtk4::Label::new(Some(description));
+            desc.add_css_class("dim-label");
+            desc.add_css_class("caption");
+            desc.set_halign(gtk4::Align::Start);
+            text.append(&desc);
+            check.set_child(Some(&text));
+            match &first {
+                Some(f) => check.set_group(Some(f)),
+                None => {
+                    check.set_active(true);
+                    first = Some(check.clone());
+                }
+            }
+            body.append(&check);
+            purposes.push((check, purpose));
+        }
+    }
+
+    let description_label = gtk4::Label::new(Some("Description (optional)"));
+    description_label.add_css_class("heading");
+    description_label.set_halign(gtk4::Align::Start);
+    body.append(&description_label);
+
+    let description_frame = gtk4::Frame::new(None);
+    let description = gtk4::TextView::new();
+    description.set_wrap_mode(gtk4::WrapMode::WordChar);
+    description.set_top_margin(8);
+    description.set_bottom_margin(8);
+    description.set_left_margin(8);
+    description.set_right_margin(8);
+    description.set_size_request(-1, 72);
+    if let Some(text) = existing.and_then(|list| list.description.as_deref()) {
+        description.buffer().set_text(text);
+    }
+    description_frame.set_child(Some(&description));
+    body.append(&description_frame);
+    content.append(&body);
+
+    save.set_sensitive(name_ok(&name.text()));
+    let save_ref = save.clone();
+    name.connect_changed(move |entry| {
+        save_ref.set_sensitive(name_ok(&entry.text()));
+    });
+
+    let dialog = adw::Dialog::builder()
+        .title(title_text)
+        .content_width(400)
+        .child(&content)
+        .build();
+
+    ListEditorParts {
+        dialog,
+        name,
+        description,
+        purposes,
+        save,
+    }
+}
+
+/// Show the editor over `parent`, empty for a new list or filled in from
+/// `existing`. Save hands over the trimmed name, description and purpose,
+/// then closes.
+pub fn present_editor(
+    parent: &impl IsA<gtk4::Widget>,
+    existing: Option<&UserList>,
+    on_save: impl Fn(String, String, ListPurpose) + 'static,
+) {
+    let parts = Rc::new(build_editor(existing));
+    let dialog = parts.dialog.clone();
+    let parts_for_click = parts.clone();
+    parts.save.connect_clicked(move |_| {
+        let name = parts_for_click.name.text().trim().to_string();
+        if !name_ok(&name) {
+            return;
+        }
+        on_save(
+            name,
+            parts_for_click.description_text(),
+            parts_for_click.selected_purpose(),
+        );
+        dialog.close();
+    });
+
+    parts.dialog.present(Some(parent));
+}
+
+/// What a membership toggle hands the app: the list, the entry cell that
+/// says whether the account is on it, and the check to settle once the
+/// server answers.
+pub type MembershipToggle =
+    dyn Fn(UserList, Rc<RefCell<Option<String>>>, glib::WeakRef<gtk4::CheckButton>);
+
+/// The checks of the membership dialog, with each list's entry cell.
+pub(crate) struct MembershipParts {
+    pub dialog: adw::Dialog,
+    pub rows: Vec<(gtk4::CheckButton, Rc<RefCell<Option<String>>>)>,
+}
+
+pub(crate) fn build_memberships(
+    handle: &str,
+    memberships: Vec<(UserList, Option<String>)>,
+    on_toggle: Rc<MembershipToggle>,
+) -> MembershipParts {
+    let content = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+
+    let header = adw::HeaderBar::new();
+    let title = gtk4::Label::new(Some("Add to Lists"));
+    title.add_css_class("title");
+    header.set_title_widget(Some(&title));
+    content.append(&header);
+
+    let subject = gtk4::Label::new(Some(&format!("@{handle}")));
+    subject.add_css_class("dim-label");
+    subject.set_halign(gtk4::Align::Start);
+    subject.set_margin_start(16);
+    subject.set_margin_top(8);
+    subject.set_ellipsize(gtk4::pango::EllipsizeMode::End);
+    content.append(&subject);
+
+    let mut rows = Vec::new();
+    if memberships.is_empty() {
+        let none = adw::StatusPage::new();
+        none.set_icon_name(Some("view-list-symbolic"));
+        none.set_title("No lists yet");
+        none.set_description(Some("Make a list from the Lists page first."));
+        content.append(&none);
+    } else {
+        let list_box = gtk4::ListBox::new();
+        list_box.add_css_class("boxed-list");
+        list_box.set_selection_mode(gtk4::SelectionMode::None);
+        list_box.set_margin_start(16);
+        list_box.set_margin_end(16);
+        list_box.set_margin_top(8);
+        list_box.set_margin_bottom(16);
+
+        for (list, entry) in memberships {
+            let row = adw::ActionRow::new();
+            row.set_use_markup(false);
+            row.set_title(&list.name);
+            row.set_subtitle(list.purpose.label());
+            let check = gtk4::CheckButton::new();
+            check.set_active(entry.is_some());
+            check.set_valign(gtk4::Align::Center);
+            row.add_suffix(&check);
+            row.set_activatable_widget(Some(&check));
+
+            let entry = Rc::new(RefCell::new(entry));
+            let cell = entry.clone();
+            let toggle = on_toggle.clone();
+            check.connect_toggled(move |check| {
+                // Settling the check from the entry, as the app does after
+                // a failure, is no request of its own.
+                if check.is_active() == cell.borrow().is_some() {
+                    return;
+                }
+                check.set_sensitive(false);
+                toggle(list.clone(), cell.clone(), check.downgrade());
+            });
+            list_box.append(&row);
+            rows.push((check, entry));
+        }
+
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled.set_propagate_natural_height(true);
+        scrolled.set_max_content_height(420);
+        scrolled.set_child(Some(&list_box));
+        content.append(&scrolled);
+    }
+
+    let dialog = adw::Dialog::builder()
+        .title("Add to Lists")
+        .content_width(360)
+        .child(&content)
+        .build();
+
+    MembershipParts { dialog, rows }
+}
+
+/// Show which of your lists `handle` is on, one check per list. Each
+/// toggle goes straight to `on_toggle`; the check stays locked until the
+/// app settles it with [`settle_membership`].
+pub fn present_memberships(
+    parent: &impl IsA<gtk4::Widget>,
+    handle: &str,
+    memberships: Vec<(UserList, Option<String>)>,
+    on_toggle: Rc<MembershipToggle>,
+) {
+    build_memberships(handle, memberships, on_toggle)
+        .dialog
+        .present(Some(parent));
+}
+
+/// Record what the server did with a toggle: the entry it now holds,
+/// unchanged on a failure, and the check put back to match.
+pub fn settle_membership(
+    cell: &Rc<RefCell<Option<String>>>,
+    check: &glib::WeakRef<gtk4::CheckButton>,
+    entry: Option<String>,
+) {
+    let on_list = entry.is_some();
+    cell.replace(entry);
+    if let Some(check) = check.upgrade() {
+        check.set_active(on_list);
+        check.set_sensitive(true);
+    }
+}
+
+/// The pieces of the starter pack editor a test drives.
+pub(crate) struct StarterPackEditorParts {
+    pub dialog: adw::Dialog,
+    pub name: adw::EntryRow,
+    pub description: gtk4::TextView,
+    /// One check per feed on offer
+    pub feeds: Vec<(gtk4::CheckButton, SavedFeed)>,
+    pub save: gtk4::Button,
+}
+
+impl StarterPackEditorParts {
+    /// The URIs of the feeds checked, in the order offered.
+    pub fn chosen_feeds(&self) -> Vec<String> {
+        self.feeds
+            .iter()
+            .filter(|(check, _)| check.is_active())
+            .map(|(_, feed)| feed.uri.clone())
+            .collect()
+    }
+
+    fn description_text(&self) -> String {
+        let buffer = self.description.buffer();
+        buffer
+            .text(&buffer.start_iter(), &buffer.end_iter(), false)
+            .trim()
+            .to_string()
+    }
+}
+
+/// A starter pack editor seeded from `list`: its name, its description,
+/// and a check for each of `feeds`, of which up to
+/// [`STARTER_PACK_FEED_CAP`] can be picked.
+pub(crate) fn build_starter_pack_editor(
+    list: &UserList,
+    feeds: Vec<SavedFeed>,
+) -> StarterPackEditorParts {
+    let content = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+
+    let header = adw::HeaderBar::new();
+    let title = gtk4::Label::new(Some("New Starter Pack"));
+    title.add_css_class("title");
+    header.set_title_widget(Some(&title));
+    let save = gtk4::Button::with_label("Create");
+    save.add_css_class("suggested-action");
+    header.pack_end(&save);
+    content.append(&header);
+
+    let body = gtk4::Box::new(gtk4::Orientation::Vertical, 12);
+    body.set_margin_start(16);
+    body.set_margin_end(16);
+    body.set_margin_top(12);
+    body.set_margin_bottom(16);
+
+    let people = gtk4::Label::new(Some(&match list.item_count {
+        Some(1) => format!("The 1 person on {} goes in the pack.", list.name),
+        Some(n) => format!("The {n} people on {} go in the pack.", list.name),
+        None => format!("Everyone on {} goes in the pack.", list.name),
+    }));
+    people.add_css_class("dim-label");
+    people.set_wrap(true);
+    people.set_xalign(0.0);
+    body.append(&people);
+
+    let fields = gtk4::ListBox::new();
+    fields.add_css_class("boxed-list");
+    fields.set_selection_mode(gtk4::SelectionMode::None);
+    let name = adw::EntryRow::new();
+    name.set_title("Name");
+    name.set_text(&list.name);
+    fields.append(&name);
+    body.append(&fields);
+
+    let description_label = gtk4::Label::new(Some("Description (optional)"));
+    description_label.add_css_class("heading");
+    description_label.set_halign(gtk4::Align::Start);
+    body.append(&description_label);
+
+    let description_frame = gtk4::Frame::new(None);
+    let description = gtk4::TextView::new();
+    description.set_wrap_mode(gtk4::WrapMode::WordChar);
+    description.set_top_margin(8);
+    description.set_bottom_margin(8);
+    description.set_left_margin(8);
+    description.set_right_margin(8);
+    description.set_size_request(-1, 72);
+    if let Some(text) = list.description.as_deref() {
+        description.buffer().set_text(text);
+    }
+    description_frame.set_child(Some(&description));
+    body.append(&description_frame);
+
+    let mut feed_checks = Vec::new();
+    if !feeds.is_empty() {
+        let feeds_label = gtk4::Label::new(Some(&format!("Feeds (up to {STARTER_PACK_FEED_CAP})")));
+        feeds_label.add_css_class("heading");
+        feeds_label.set_halign(gtk4::Align::Start);
+        body.append(&feeds_label);
+
+        let feed_box = gtk4::ListBox::new();
+        feed_box.add_css_class("boxed-list");
+        feed_box.set_selection_mode(gtk4::SelectionMode::None);
+        for feed in feeds {
+            let row = adw::ActionRow::new();
+            row.set_use_markup(false);
+            row.set_title(&feed.display_name);
+            let check = gtk4::CheckButton::new();
+            check.set_valign(gtk4::Align::Center);
+            row.add_prefix(&check);
+            row.set_activatable_widget(Some(&check));
+            feed_box.append(&row);
+            feed_checks.push((check, feed));
+        }
+        body.append(&feed_box);
+
+        // Once the cap is reached the rest lock, so the record never
+        // carries more than the lexicon allows.
+        let checks: Vec<gtk4::CheckButton> =
+            feed_checks.iter().map(|(check, _)| check.clone()).collect();
+        for (check, _) in &feed_checks {
+            let checks = checks.clone();
+            check.connect_toggled(move |_| {
+                let full = checks.iter().filter(|c| c.is_active()).count() >= STARTER_PACK_FEED_CAP;
+                for check in &checks {
+                    check.set_sensitive(check.is_active() || !full);
+                }
+            });
+        }
+    }
+    content.append(&body);
+
+    save.set_sensitive(name_fits(&name.text(), PACK_NAME_MAX));
+    let save_ref = save.clone();
+    name.connect_changed(move |entry| {
+        save_ref.set_sensitive(name_fits(&entry.text(), PACK_NAME_MAX));
+    });
+
+    let dialog = adw::Dialog::builder()
+        .title("New Starter Pack")
+        .content_width(400)
+        .child(&content)
+        .build();
+
+    StarterPackEditorParts {
+        dialog,
+        name,
+        description,
+        feeds: feed_checks,
+        save,
+    }
+}
+
+/// Show the starter pack editor for `list` over `parent`. Create hands
+/// over the trimmed name, the description and the chosen feed URIs, then
+/// closes.
+pub fn present_starter_pack_editor(
+    parent: &impl IsA<gtk4::Widget>,
+    list: &UserList,
+    feeds: Vec<SavedFeed>,
+    on_save: impl Fn(String, String, Vec<String>) + 'static,
+) {
+    let parts = Rc::new(build_starter_pack_editor(list, feeds));
+    let dialog = parts.dialog.clone();
+    let parts_for_click = parts.clone();
+    parts.save.connect_clicked(move |_| {
+        let name = parts_for_click.name.text().trim().to_string();
+        if !name_fits(&name, PACK_NAME_MAX) {
+            return;
+        }
+        on_save(
+            name,
+            parts_for_click.description_text(),
+            parts_for_click.chosen_feeds(),
+        );
+        dialog.close();
+    });
+
+    parts.dialog.present(Some(parent));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atproto::Profile;
+
+    fn a_list(name: &str) -> UserList {
+        UserList {
+            uri: format!("at://did:plc:me/app.bsky.graph.list/{name}"),
+            name: name.into(),
+            purpose: ListPurpose::Curate,
+            description: Some("People worth reading".into()),
+            avatar: None,
+            creator: Profile::minimal("did:plc:me".into(), "me.bsky.social".into(), None, None),
+            item_count: None,
+            viewer_muted: false,
+            viewer_blocked: None,
+        }
+    }
+
+    /// A new list needs a name and offers both purposes; an edit starts
+    /// from what the list says and leaves its purpose alone.
+    #[test]
+    fn the_editor_needs_a_name_and_asks_a_purpose_only_when_new() {
+        crate::ui::with_gtk(the_editor_needs_a_name_and_asks_a_purpose_only_when_new_body);
+    }
+
+    fn the_editor_needs_a_name_and_asks_a_purpose_only_when_new_body() {
+        let parts = build_editor(None);
+        assert!(!parts.save.is_sensitive(), "no name, no list");
+        assert_eq!(parts.purposes.len(), 2);
+        assert_eq!(parts.selected_purpose(), ListPurpose::Curate);
+
+        parts.name.set_text("   ");
+        assert!(!parts.save.is_sensitive(), "blanks are not a name");
+        parts.name.set_text("Birders");
+        assert!(parts.save.is_sensitive());
+        parts.name.set_text(&"x".repeat(NAME_MAX + 1));
+        assert!(!parts.save.is_sensitive(), "past the lexicon's limit");
+
+        parts.purposes[1].0.set_active(true);
+        assert_eq!(parts.selected_purpose(), ListPurpose::Moderation);
+
+        let list = a_list("friends");
+        let parts = build_editor(Some(&list));
+        assert!(parts.purposes.is_empty());
+        assert_eq!(parts.name.text(), "friends");
+        assert_eq!(parts.description_text(), "People worth reading");
+        assert!(parts.save.is_sensitive());
+    }
+
+    /// A toggle asks once and locks its check; settling puts the check
+    /// where the server left it without asking again.
+    #[test]
+    fn a_membership_toggle_asks_once_and_settles_quietly() {
+        crate::ui::with_gtk(a_membership_toggle_asks_once_and_settles_quietly_body);
+    }
+
+    fn a_membership_toggle_asks_once_and_settles_quietly_body() {
+        let asked: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(vec![]));
+        let sink = asked.clone();
+        let on_toggle: Rc<MembershipToggle> = Rc::new(move |list, _, _| {
+            sink.borrow_mut().push(list.name);
+        });
+        let parts = build_memberships(
+            "bob.bsky.social",
+            vec![
+                (a_list("friends"), None),
+                (
+                    a_list("artists"),
+                    Some("at://did:plc:me/app.bsky.graph.listitem/1".into()),
+                ),
+            ],
+            on_toggle,
+        );
+        let (friends, friends_entry) = &parts.rows[0];
+        let (artists, artists_entry) = &parts.rows[1];
+        assert!(!friends.is_active());
+        assert!(artists.is_active(), "already on this one");
+
+        friends.set_active(true);
+        assert_eq!(asked.borrow().as_slice(), ["friends"]);
+        assert!(!friends.is_sensitive(), "locked until the server answers");
+        settle_membership(
+            friends_entry,
+            &friends.downgrade(),
+            Some("at://did:plc:me/app.bsky.graph.listitem/2".into()),
+        );
+        assert!(friends.is_active() && friends.is_sensitive());
+
+        // A failed removal puts the check back without a second request.
+        artists.set_active(false);
+        let kept = artists_entry.borrow().clone();
+        settle_membership(artists_entry, &artists.downgrade(), kept);
+        assert!(artists.is_active());
+        assert_eq!(asked.borrow().as_slice(), ["friends", "artists"]);
+    }
+
+    /// The pack starts from the list's name and words, and once three
+    /// feeds are picked the rest lock until one is let go.
+    #[test]
+    fn the_starter_pack_editor_caps_its_feeds() {
+        crate::ui::with_gtk(the_starter_pack_editor_caps_its_feeds_body);
+    }
+
+    fn the_starter_pack_editor_caps_its_feeds_body() {
+        let feeds: Vec<SavedFeed> = (0..4)
+            .map(|n| SavedFeed {
+                feed_type: "feed".into(),
+                uri: format!("at://did:plc:f/app.bsky.feed.generator/{n}"),
+                display_name: format!("Feed {n}"),
+                description: None,
+                pinned: true,
+            })
+            .collect();
+        let parts = build_starter_pack_editor(&a_list("friends"), feeds);
+        assert_eq!(parts.name.text(), "friends");
+        assert_eq!(parts.description_text(), "People worth reading");
+        assert!(parts.save.is_sensitive());
+        parts.name.set_text(&"x".repeat(PACK_NAME_MAX + 1));
+        assert!(!parts.save.is_sensitive(), "past the pack name limit");
+
+        for (check, _) in &parts.feeds[..3] {
+            check.set_active(true);
+        }
+        let last = &parts.feeds[3].0;
+        assert!(!last.is_sensitive(), "a fourth feed is past the cap");
+        assert_eq!(parts.chosen_feeds().len(), 3);
+
+        parts.feeds[0].0.set_active(false);
+        assert!(last.is_sensitive());
+        assert_eq!(
+            parts.chosen_feeds(),
+            [
+                "at://did:plc:f/app.bsky.feed.generator/1",
+                "at://did:plc:f/app.bsky.feed.generator/2"
+            ]
+        );
+    }
+}
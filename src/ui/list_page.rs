@@ -0,0 +1,382 @@
+// SPDX-License-Identifier: MPL-2.0
+#![allow(clippy::type_complexity)]
+
+//! The lists one account made: the Lists section's own page, and the page
+//! a profile's "lists" stat pushes.
+//!
+//! Like [`super::FollowListPage`], each page carries its own cursor and
+//! in-flight flag, so stacked pages for different accounts never share
+//! state. An account rarely has more than a screenful of lists, so the
+//! rows sit in a plain list box rather than a virtualized list view.
+
+use crate::atproto::UserList;
+use crate::ui::avatar_cache;
+use gtk4::glib;
+use gtk4::prelude::*;
+use gtk4::subclass::prelude::*;
+use libadwaita as adw;
+use libadwaita::prelude::*;
+
+mod imp {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    #[derive(Default)]
+    pub struct ListsPage {
+        pub list_box: RefCell<Option<gtk4::ListBox>>,
+        pub scrolled: RefCell<Option<gtk4::ScrolledWindow>>,
+        pub spinner: RefCell<Option<gtk4::Spinner>>,
+        pub empty_state: RefCell<Option<adw::StatusPage>>,
+        pub error_state: RefCell<Option<adw::StatusPage>>,
+        /// The lists behind the rows, in row order.
+        pub lists: RefCell<Vec<UserList>>,
+        pub cursor: RefCell<Option<String>>,
+        pub fetching: Cell<bool>,
+        pub loaded_once: Cell<bool>,
+        pub list_activated_callback: RefCell<Option<Box<dyn Fn(UserList) + 'static>>>,
+        pub load_more_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        pub retry_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        pub create_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ListsPage {
+        const NAME: &'static str = "HangarListsPage";
+        type Type = super::ListsPage;
+        type ParentType = gtk4::Box;
+    }
+
+    impl ObjectImpl for ListsPage {}
+    impl WidgetImpl for ListsPage {}
+    impl BoxImpl for ListsPage {}
+}
+
+glib::wrapper! {
+    pub struct ListsPage(ObjectSubclass<imp::ListsPage>)
+        @extends gtk4::Box, gtk4::Widget,
+        @implements gtk4::Accessible, gtk4::Buildable, gtk4::ConstraintTarget, gtk4::Orientable;
+}
+
+impl ListsPage {
+    /// `own` is for the signed-in user's page, whose empty state offers
+    /// to make a first list.
+    pub fn new(own: bool) -> Self {
+        let page: Self = glib::Object::builder()
+            .property("orientation", gtk4::Orientation::Vertical)
+            .property("spacing", 0)
+            .build();
+        page.setup_ui(own);
+        page
+    }
+
+    fn setup_ui(&self, own: bool) {
+        self.set_vexpand(true);
+
+        let list_box = gtk4::ListBox::new();
+        list_box.add_css_class("boxed-list");
+        list_box.set_selection_mode(gtk4::SelectionMode::None);
+        list_box.set_valign(gtk4::Align::Start);
+        let page_weak = self.downgrade();
+        list_box.connect_row_activated(move |_, row| {
+            let Some(page) = page_weak.upgrade() else {
+                return;
+            };
+            let list = usize::try_from(row.index())
+                .ok()
+                .and_then(|i| page.imp().lists.borrow().get(i).cloned());
+            if let Some(list) = list
+                && let Some(cb) = page.imp().list_activated_callback.borrow().as_ref()
+            {
+                cb(list);
+            }
+        });
+
+        let column = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        column.set_margin_top(12);
+        column.set_margin_bottom(24);
+        column.set_margin_start(12);
+        column.set_margin_end(12);
+        column.append(&list_box);
+
+        let spinner = gtk4::Spinner::new();
+        spinner.update_property(&[gtk4::accessible::Property::Label("Loading")]);
+        spinner.set_visible(false);
+        spinner.set_halign(gtk4::Align::Center);
+        spinner.set_margin_top(16);
+        column.append(&spinner);
+
+        let clamp = adw::Clamp::new();
+        clamp.set_maximum_size(800);
+        clamp.set_tightening_threshold(600);
+        clamp.set_child(Some(&column));
+
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_vexpand(true);
+        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled.set_child(Some(&clamp));
+
+        // Shown in place of the rows once a first load comes back empty.
+        let empty_state = adw::StatusPage::new();
+        empty_state.set_icon_name(Some("view-list-symbolic"));
+        empty_state.set_vexpand(true);
+        empty_state.set_visible(false);
+        if own {
+            empty_state.set_title("No lists yet");
+            empty_state.set_description(Some(
+                "Lists gather people into a feed of their own, or into one place to mute or block.",
+            ));
+            let create_button = gtk4::Button::with_label("New List");
+            create_button.add_css_class("pill");
+            create_button.add_css_class("suggested-action");
+            create_button.set_halign(gtk4::Align::Center);
+            let page_weak = self.downgrade();
+            create_button.connect_clicked(move |_| {
+                if let Some(page) = page_weak.upgrade()
+                    && let Some(cb) = page.imp().create_callback.borrow().as_ref()
+                {
+                    cb();
+                }
+            });
+            empty_state.set_child(Some(&create_button));
+        } else {
+            empty_state.set_title("No lists");
+            empty_state.set_description(Some("Lists this account makes will show up here."));
+        }
+
+        // Shown when a fetch fails with nothing listed yet.
+        let error_state = adw::StatusPage::new();
+        error_state.set_icon_name(Some("dialog-warning-symbolic"));
+        error_state.set_title("Couldn't Load Lists");
+        error_state.set_description(Some("Check your connection and try again."));
+        error_state.set_vexpand(true);
+        error_state.set_visible(false);
+
+        let retry_button = gtk4::Button::with_label("Try Again");
+        retry_button.add_css_class("pill");
+        retry_button.add_css_class("suggested-action");
+        retry_button.set_halign(gtk4::Align::Center);
+        let page_weak = self.downgrade();
+        retry_button.connect_clicked(move |_| {
+            if let Some(page) = page_weak.upgrade()
+                && let Some(cb) = page.imp().retry_callback.borrow().as_ref()
+            {
+                cb();
+            }
+        });
+        error_state.set_child(Some(&retry_button));
+
+        self.append(&scrolled);
+        self.append(&empty_state);
+        self.append(&error_state);
+
+        // Wired once here; whether a fetch is due is the callback's call.
+        let page_weak = self.downgrade();
+        scrolled.vadjustment().connect_value_changed(move |adj| {
+            let Some(page) = page_weak.upgrade() else {
+                return;
+            };
+            if adj.value() >= adj.upper() - adj.page_size() - 200.0
+                && let Some(cb) = page.imp().load_more_callback.borrow().as_ref()
+            {
+                cb();
+            }
+        });
+
+        let imp = self.imp();
+        imp.list_box.replace(Some(list_box));
+        imp.scrolled.replace(Some(scrolled));
+        imp.spinner.replace(Some(spinner));
+        imp.empty_state.replace(Some(empty_state));
+        imp.error_state.replace(Some(error_state));
+    }
+
+    fn build_row(list: &UserList) -> adw::ActionRow {
+        let row = adw::ActionRow::new();
+        // Names and descriptions are whatever their authors typed.
+        row.set_use_markup(false);
+        row.set_title(&list.name);
+        row.set_subtitle(&list.summary());
+        row.set_subtitle_lines(1);
+        row.set_activatable(true);
+
+        let avatar = adw::Avatar::new(40, Some(&list.name), false);
+        if let Some(url) = &list.avatar {
+            avatar_cache::load_avatar(avatar.clone(), url.clone());
+        }
+        row.add_prefix(&avatar);
+        row.add_suffix(&gtk4::Image::from_icon_name("go-next-symbolic"));
+        row
+    }
+
+    /// Add a fetched page of lists.
+    ///
+    /// As [`super::FollowListPage::append_profiles`]: store the cursor
+    /// first, since only a spent cursor proves the page is empty.
+    pub fn append_lists(&self, lists: Vec<UserList>) {
+        let imp = self.imp();
+        if let Some(list_box) = imp.list_box.borrow().as_ref() {
+            for list in &lists {
+                list_box.append(&Self::build_row(list));
+            }
+        }
+        imp.lists.borrow_mut().extend(lists);
+        imp.loaded_once.set(true);
+
+        let empty = imp.lists.borrow().is_empty() && imp.cursor.borrow().is_none();
+        if let Some(scrolled) = imp.scrolled.borrow().as_ref() {
+            scrolled.set_visible(!empty);
+        }
+        if let Some(empty_state) = imp.empty_state.borrow().as_ref() {
+            empty_state.set_visible(empty);
+        }
+        if let Some(error_state) = imp.error_state.borrow().as_ref() {
+            error_state.set_visible(false);
+        }
+    }
+
+    /// Drop every row, for a reload after a list was made or deleted.
+    pub fn clear(&self) {
+        let imp = self.imp();
+        if let Some(list_box) = imp.list_box.borrow().as_ref() {
+            list_box.remove_all();
+        }
+        imp.lists.borrow_mut().clear();
+        imp.cursor.replace(None);
+        imp.loaded_once.set(false);
+    }
+
+    /// Mark a fetch in flight and show or hide the spinner with it. A
+    /// fetch starting stands the error state down, as on the follow lists.
+    pub fn set_fetching(&self, fetching: bool) {
+        let imp = self.imp();
+        imp.fetching.set(fetching);
+        if fetching
+            && let Some(error_state) = imp.error_state.borrow().as_ref()
+            && error_state.is_visible()
+        {
+            error_state.set_visible(false);
+            if let Some(scrolled) = imp.scrolled.borrow().as_ref() {
+                scrolled.set_visible(true);
+            }
+        }
+        if let Some(spinner) = imp.spinner.borrow().as_ref() {
+            spinner.set_visible(fetching);
+            spinner.set_spinning(fetching);
+        }
+    }
+
+    pub fn is_fetching(&self) -> bool {
+        self.imp().fetching.get()
+    }
+
+    pub fn set_cursor(&self, cursor: Option<String>) {
+        self.imp().cursor.replace(cursor);
+    }
+
+    /// The cursor for the next page, if the server said there is one.
+    pub fn cursor(&self) -> Option<String> {
+        self.imp().cursor.borrow().clone()
+    }
+
+    /// Swap in the error state after a failed fetch, unless rows are shown.
+    pub fn show_load_failed(&self) {
+        let imp = self.imp();
+        if !imp.lists.borrow().is_empty() {
+            return;
+        }
+        if let Some(scrolled) = imp.scrolled.borrow().as_ref() {
+            scrolled.set_visible(false);
+        }
+        if let Some(empty_state) = imp.empty_state.borrow().as_ref() {
+            empty_state.set_visible(false);
+        }
+        if let Some(error_state) = imp.error_state.borrow().as_ref() {
+            error_state.set_visible(true);
+        }
+    }
+
+    /// True when no fetch ever completed and none is under way.
+    pub fn needs_reload(&self) -> bool {
+        !self.imp().loaded_once.get() && !self.imp().fetching.get()
+    }
+
+    /// Replace the handler run when a row is activated.
+    pub fn set_list_activated_callback<F: Fn(UserList) + 'static>(&self, callback: F) {
+        self.imp()
+            .list_activated_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Replace the handler run when the rows near their bottom.
+    pub fn set_load_more_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp()
+            .load_more_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Replace the handler run by the error state's retry button.
+    pub fn set_retry_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp().retry_callback.replace(Some(Box::new(callback)));
+    }
+
+    /// Replace the handler run by the empty state's New List button.
+    pub fn set_create_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp().create_callback.replace(Some(Box::new(callback)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::atproto::{ListPurpose, Profile};
+
+    fn a_list(name: &str) -> UserList {
+        UserList {
+            uri: format!("at://did:plc:me/app.bsky.graph.list/{name}"),
+            name: name.into(),
+            purpose: ListPurpose::Curate,
+            description: None,
+            avatar: None,
+            creator: Profile::minimal("did:plc:me".into(), "me.bsky.social".into(), None, None),
+            item_count: Some(3),
+            viewer_muted: false,
+            viewer_blocked: None,
+        }
+    }
+
+    /// Rows open the list they show, a clear starts the page over, and
+    /// only an empty first load with no cursor reads as empty.
+    #[test]
+    fn rows_open_their_list_and_a_clear_starts_over() {
+        crate::ui::with_gtk(rows_open_their_list_and_a_clear_starts_over_body);
+    }
+
+    fn rows_open_their_list_and_a_clear_starts_over_body() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let page = ListsPage::new(true);
+        let imp = page.imp();
+        let opened: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(vec![]));
+        let sink = opened.clone();
+        page.set_list_activated_callback(move |list| sink.borrow_mut().push(list.name));
+
+        assert!(page.needs_reload());
+        page.append_lists(vec![a_list("friends"), a_list("artists")]);
+        assert!(!page.needs_reload());
+        assert!(!imp.empty_state.borrow().as_ref().unwrap().is_visible());
+
+        let list_box = imp.list_box.borrow().clone().unwrap();
+        let row = list_box.row_at_index(1).unwrap();
+        row.emit_activate();
+        assert_eq!(opened.borrow().as_slice(), ["artists"]);
+
+        page.clear();
+        assert!(page.needs_reload(), "a cleared page loads again");
+        assert!(list_box.row_at_index(0).is_none());
+
+        page.append_lists(vec![]);
+        assert!(imp.empty_state.borrow().as_ref().unwrap().is_visible());
+        assert!(!imp.scrolled.borrow().as_ref().unwrap().is_visible());
+    }
+}
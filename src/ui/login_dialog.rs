@@ -16,8 +16,12 @@ mod imp {
     pub struct LoginDialog {
         pub handle_row: RefCell<Option<adw::EntryRow>>,
         pub password_row: RefCell<Option<adw::PasswordEntryRow>>,
+        pub server_row: RefCell<Option<adw::EntryRow>>,
+        pub code_group: RefCell<Option<adw::PreferencesGroup>>,
+        pub code_row: RefCell<Option<adw::EntryRow>>,
         pub oauth_button: RefCell<Option<gtk4::Button>>,
         pub login_button: RefCell<Option<gtk4::Button>>,
+        pub create_account_button: RefCell<Option<gtk4::Button>>,
         pub spinner: RefCell<Option<gtk4::Spinner>>,
         pub error_label: RefCell<Option<gtk4::Label>>,
         pub oauth_status: RefCell<Option<gtk4::Box>>,
@@ -169,8 +173,31 @@ impl LoginDialog {
         let password_row = adw::PasswordEntryRow::new();
         password_row.set_title("App Password");
         password_group.add(&password_row);
+
+        // Most accounts are found from the handle alone; a server that has
+        // not published one yet, or signs in by email, is named here.
+        let server_expander = adw::ExpanderRow::new();
+        server_expander.set_title("Custom Server");
+        server_expander.set_subtitle("Only if your hosting provider gave you one");
+        let server_row = adw::EntryRow::new();
+        server_row.set_title("Server Address");
+        server_row.set_input_purpose(gtk4::InputPurpose::Url);
+        server_row.set_show_apply_button(false);
+        server_expander.add_row(&server_row);
+        password_group.add(&server_expander);
         password_box.append(&password_group);
 
+        // The second step for accounts with email two-factor sign-in, shown
+        // once the server has sent the code.
+        let code_group = adw::PreferencesGroup::new();
+        code_group.set_description(Some("A sign-in code was sent to your email."));
+        code_group.set_visible(false);
+        let code_row = adw::EntryRow::new();
+        code_row.set_title("Code from Email");
+        code_row.set_show_apply_button(false);
+        code_group.add(&code_row);
+        password_box.append(&code_group);
+
         let login_button = gtk4::Button::with_label("Sign In");
         login_button.add_css_class("suggested-action");
         login_button.set_sensitive(false);
@@ -200,6 +227,12 @@ impl LoginDialog {
         expander.set_child(Some(&password_box));
         content.append(&expander);
 
+        // No account yet: sign up without leaving the app
+        let create_account_button = gtk4::Button::with_label("Create an Account");
+        create_account_button.add_css_class("flat");
+        create_account_button.set_halign(gtk4::Align::Center);
+        content.append(&create_account_button);
+
         // Privacy link at bottom
         let privacy_link = gtk4::Button::with_label("Privacy & Security →");
         privacy_link.add_css_class("flat");
@@ -257,22 +290,30 @@ impl LoginDialog {
             }
         });
 
-        // Enter key on password activates Sign In button
+        // Enter key on password or code activates Sign In button
         let login_btn_weak2 = login_button.downgrade();
-        password_row.connect_entry_activated(move |_| {
+        let activate_login = move || {
             if let Some(btn) = login_btn_weak2.upgrade() {
                 if btn.is_sensitive() {
                     btn.emit_clicked();
                 }
             }
-        });
+        };
+        let activate = activate_login.clone();
+        password_row.connect_entry_activated(move |_| activate());
+        code_row.connect_entry_activated(move |_| activate_login());
 
         // Store references
         let imp = self.imp();
         imp.handle_row.replace(Some(handle_row));
         imp.password_row.replace(Some(password_row));
+        imp.server_row.replace(Some(server_row));
+        imp.code_group.replace(Some(code_group));
+        imp.code_row.replace(Some(code_row));
         imp.oauth_button.replace(Some(oauth_button));
         imp.login_button.replace(Some(login_button));
+        imp.create_account_button
+            .replace(Some(create_account_button));
         imp.spinner.replace(Some(spinner));
         imp.error_label.replace(Some(error_label));
         imp.oauth_status.replace(Some(oauth_status));
@@ -304,6 +345,59 @@ impl LoginDialog {
             .unwrap_or_default()
     }
 
+    /// The custom server, if one was typed. Otherwise sign-in looks the
+    /// account's server up from its handle.
+    pub fn server(&self) -> Option<String> {
+        self.imp()
+            .server_row
+            .borrow()
+            .as_ref()
+            .map(|e| e.text().trim().to_string())
+            .filter(|server| !server.is_empty())
+    }
+
+    /// The emailed sign-in code, once the dialog has asked for one.
+    pub fn auth_factor_token(&self) -> Option<String> {
+        let imp = self.imp();
+        if !imp
+            .code_group
+            .borrow()
+            .as_ref()
+            .is_some_and(|g| g.is_visible())
+        {
+            return None;
+        }
+        imp.code_row
+            .borrow()
+            .as_ref()
+            .map(|e| e.text().trim().to_string())
+            .filter(|code| !code.is_empty())
+    }
+
+    /// Move to the second step: the server has emailed a code. Any code
+    /// already typed is cleared, since it is not the one just sent.
+    pub fn ask_for_code(&self) {
+        let imp = self.imp();
+        if let Some(expander) = imp.app_password_expander.borrow().as_ref() {
+            expander.set_expanded(true);
+        }
+        if let Some(group) = imp.code_group.borrow().as_ref() {
+            group.set_visible(true);
+        }
+        if let Some(code) = imp.code_row.borrow().as_ref() {
+            code.set_text("");
+            code.grab_focus();
+        }
+    }
+
+    /// Clear a code the server says has expired, so the next Sign In asks
+    /// it for a fresh one.
+    pub fn clear_code(&self) {
+        if let Some(code) = self.imp().code_row.borrow().as_ref() {
+            code.set_text("");
+        }
+    }
+
     pub fn show_error(&self, message: &str) {
         if let Some(label) = self.imp().error_label.borrow().as_ref() {
             label.set_text(message);
@@ -362,6 +456,14 @@ impl LoginDialog {
         if let Some(password) = imp.password_row.borrow().as_ref() {
             password.set_sensitive(!loading);
         }
+
+        if let Some(server) = imp.server_row.borrow().as_ref() {
+            server.set_sensitive(!loading);
+        }
+
+        if let Some(code) = imp.code_row.borrow().as_ref() {
+            code.set_sensitive(!loading);
+        }
     }
 
     /// Connect callback for OAuth login (handle only).
@@ -405,6 +507,20 @@ impl LoginDialog {
             });
         }
     }
+
+    /// Connect callback for starting a new account instead of signing in.
+    pub fn connect_create_account<F: Fn(&Self) + 'static>(&self, f: F) {
+        if let Some(button) = self.imp().create_account_button.borrow().as_ref() {
+            let dialog = self.downgrade();
+            button.connect_clicked(move |_| {
+                // Weak: the button is this dialog's own child, so a strong
+                // capture would be a cycle GObject cannot collect.
+                if let Some(dialog) = dialog.upgrade() {
+                    f(&dialog);
+                }
+            });
+        }
+    }
 }
 
 impl Default for LoginDialog {
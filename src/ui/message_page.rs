@@ -659,6 +659,9 @@ mod message_row {
                 viewer_bookmarked: None,
                 repost_reason: None,
                 reply_context: None,
+                labels: Vec::new(),
+                feed_context: None,
+                facets: Vec::new(),
             };
             let row_weak = self.downgrade();
             card.connect_clicked(move |_| {
@@ -1615,6 +1618,8 @@ mod tests {
             text: text.into(),
             indexed_at: "2026-01-01T00:00:00Z".into(),
             embed: None,
+            labels: Vec::new(),
+            facets: Vec::new(),
         })
     }
 
@@ -5,11 +5,16 @@ pub mod avatar_cache;
 mod compose_dialog;
 pub mod edit_profile;
 pub mod external;
+mod feed_browser;
 mod follow_list_page;
 pub mod inline_video;
+pub mod list_dialog;
+mod list_page;
 mod login_dialog;
 pub mod media_viewer;
 mod message_page;
+pub mod muted_word_dialog;
+pub mod onboarding;
 pub mod post_row;
 pub mod progress_icon;
 #[cfg(test)]
@@ -17,15 +22,22 @@ mod rebind_audit;
 pub mod report_dialog;
 pub(crate) mod rich_text;
 pub mod sidebar;
+mod signup_dialog;
 pub mod video_player;
 mod window;
 
 pub use compose_dialog::{ComposeDialog, QuoteContext, ReplyContext};
+pub use feed_browser::FeedBrowser;
 pub use follow_list_page::{FollowListKind, FollowListPage};
+pub use list_page::ListsPage;
 pub use login_dialog::LoginDialog;
 pub use message_page::{MessagePage, MessagePush};
 pub use sidebar::NavItem;
-pub use window::{CacheClearOutcome, FollowListPush, HangarWindow, ProfileFeedCtx};
+pub use signup_dialog::SignupDialog;
+pub use window::{
+    CacheClearOutcome, FeedGeneratorCtx, FollowListPush, HangarWindow, HashtagFeedCtx, ListFeedCtx,
+    ListTab, ProfileFeedCtx, StarterPackCtx,
+};
 
 /// Run a test body on the one GTK thread, or skip if there is no display.
 ///
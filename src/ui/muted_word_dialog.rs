@@ -0,0 +1,314 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! The muted word editor: one dialog for adding and for editing.
+//!
+//! An edit keeps the entry's id, so the server-side list swaps the entry in
+//! place instead of growing a second copy with the new value.
+
+use crate::atproto::muted_words;
+use crate::atproto::{MutedWord, MutedWordTarget};
+use chrono::{DateTime, Utc};
+use gtk4::prelude::*;
+use libadwaita as adw;
+use libadwaita::prelude::*;
+
+/// What the Mute In row offers, in order.
+const SCOPES: &[(&str, &[MutedWordTarget])] = &[
+    (
+        "Text and Tags",
+        &[MutedWordTarget::Content, MutedWordTarget::Tag],
+    ),
+    ("Tags Only", &[MutedWordTarget::Tag]),
+];
+
+/// What the Duration row offers, in hours. `None` is forever.
+const DURATIONS: &[(&str, Option<i64>)] = &[
+    ("Forever", None),
+    ("24 Hours", Some(24)),
+    ("7 Days", Some(24 * 7)),
+    ("30 Days", Some(24 * 30)),
+];
+
+/// The pieces a test needs to drive the dialog without a pointer.
+pub(crate) struct MutedWordDialogParts {
+    pub dialog: adw::Dialog,
+    pub value: adw::EntryRow,
+    pub scope: adw::ComboRow,
+    pub duration: adw::ComboRow,
+    pub exclude_following: adw::SwitchRow,
+    pub save: gtk4::Button,
+    /// The entry being edited, if any.
+    existing: Option<MutedWord>,
+    /// Whether the Duration row leads with "keep the current expiry".
+    keeps_expiry: bool,
+}
+
+impl MutedWordDialogParts {
+    /// The word as the form describes it, or nothing while the entry is
+    /// blank. Durations count from `now`.
+    pub fn word(&self, now: DateTime<Utc>) -> Option<MutedWord> {
+        let value = self.value.text().trim().to_string();
+        if value.is_empty() {
+            return None;
+        }
+        let targets = SCOPES
+            .get(self.scope.selected() as usize)
+            .map_or(SCOPES[0].1, |(_, targets)| *targets)
+            .to_vec();
+
+        let mut selected = self.duration.selected() as usize;
+        let expires_at = if self.keeps_expiry && selected == 0 {
+            self.existing.as_ref().and_then(|w| w.expires_at.clone())
+        } else {
+            if self.keeps_expiry {
+                selected -= 1;
+            }
+            DURATIONS
+                .get(selected)
+                .and_then(|(_, hours)| *hours)
+                // Written the way record timestamps are, so it always
+                // parses as the lexicon's datetime on the way out.
+                .map(|hours| {
+                    (now + chrono::Duration::hours(hours))
+                        .to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+                })
+        };
+
+        let id = self
+            .existing
+            .as_ref()
+            .and_then(|w| w.id.clone())
+            .unwrap_or_else(|| muted_words::new_id(now));
+
+        Some(MutedWord {
+            id: Some(id),
+            value,
+            targets,
+            exclude_following: self.exclude_following.is_active(),
+            expires_at,
+        })
+    }
+}
+
+/// One line under a word in Settings: where it applies, for how long, and
+/// whether followed accounts are spared.
+pub(crate) fn summary(word: &MutedWord, now: DateTime<Utc>) -> String {
+    let mut parts = vec![
+        if word.targets.contains(&MutedWordTarget::Content) {
+            "Text and tags"
+        } else {
+            "Tags only"
+        }
+        .to_string(),
+    ];
+
+    if word.is_expired(now) {
+        parts.push("Expired".to_string());
+    } else if let Some(at) = word
+        .expires_at
+        .as_deref()
+        .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+    {
+        let left = at.with_timezone(&Utc) - now;
+        parts.push(if left.num_days() >= 1 {
+            let days = left.num_days();
+            format!("{days} day{} left", if days == 1 { "" } else { "s" })
+        } else {
+            let hours = left.num_hours().max(1);
+            format!("{hours} hour{} left", if hours == 1 { "" } else { "s" })
+        });
+    }
+
+    if word.exclude_following {
+        parts.push("Not for people you follow".to_string());
+    }
+    parts.join(" · ")
+}
+
+pub(crate) fn build(existing: Option<&MutedWord>) -> MutedWordDialogParts {
+    let now = Utc::now();
+    let title_text = if existing.is_some() {
+        "Edit Muted Word"
+    } else {
+        "Add Muted Word"
+    };
+
+    let content = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+
+    let header = adw::HeaderBar::new();
+    let title = gtk4::Label::new(Some(title_text));
+    title.add_css_class("title");
+    header.set_title_widget(Some(&title));
+    let save = gtk4::Button::with_label("Save");
+    save.add_css_class("suggested-action");
+    save.set_sensitive(existing.is_some());
+    header.pack_end(&save);
+    content.append(&header);
+
+    let group = adw::PreferencesGroup::new();
+    group.set_margin_start(16);
+    group.set_margin_end(16);
+    group.set_margin_top(12);
+    group.set_margin_bottom(16);
+
+    let value = adw::EntryRow::builder()
+        .title("Word, phrase or #tag")
+        .build();
+    let save_ref = save.clone();
+    value.connect_changed(move |row| {
+        save_ref.set_sensitive(!row.text().trim().is_empty());
+    });
+    group.add(&value);
+
+    let scope_labels: Vec<&str> = SCOPES.iter().map(|(label, _)| *label).collect();
+    let scope = adw::ComboRow::builder()
+        .title("Mute In")
+        .model(&gtk4::StringList::new(&scope_labels))
+        .build();
+    group.add(&scope);
+
+    // An edit can keep a still-running expiry instead of restarting it.
+    let keeps_expiry = existing.is_some_and(|w| w.expires_at.is_some() && !w.is_expired(now));
+    let mut duration_labels: Vec<&str> = Vec::new();
+    if keeps_expiry {
+        duration_labels.push("Keep Current");
+    }
+    duration_labels.extend(DURATIONS.iter().map(|(label, _)| *label));
+    let duration = adw::ComboRow::builder()
+        .title("Duration")
+        .model(&gtk4::StringList::new(&duration_labels))
+        .build();
+    group.add(&duration);
+
+    let exclude_following = adw::SwitchRow::builder()
+        .title("Exclude People You Follow")
+        .subtitle("Their posts show even when they use this word")
+        .build();
+    group.add(&exclude_following);
+
+    if let Some(word) = existing {
+        value.set_text(&word.value);
+        let tags_only = !word.targets.contains(&MutedWordTarget::Content);
+        scope.set_selected(u32::from(tags_only));
+        exclude_following.set_active(word.exclude_following);
+    }
+
+    content.append(&group);
+
+    let dialog = adw::Dialog::builder()
+        .title(title_text)
+        .content_width(400)
+        .child(&content)
+        .build();
+
+    MutedWordDialogParts {
+        dialog,
+        value,
+        scope,
+        duration,
+        exclude_following,
+        save,
+        existing: existing.cloned(),
+        keeps_expiry,
+    }
+}
+
+/// Show the dialog over `parent`; Save hands over the finished word, then
+/// closes.
+pub fn present(
+    parent: &impl IsA<gtk4::Widget>,
+    existing: Option<&MutedWord>,
+    on_save: impl Fn(MutedWord) + 'static,
+) {
+    let parts = std::rc::Rc::new(build(existing));
+    let dialog = parts.dialog.clone();
+    let parts_for_click = parts.clone();
+    parts.save.connect_clicked(move |_| {
+        let Some(word) = parts_for_click.word(Utc::now()) else {
+            return;
+        };
+        on_save(word);
+        dialog.close();
+    });
+
+    parts.dialog.present(Some(parent));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(expires_at: Option<String>) -> MutedWord {
+        MutedWord {
+            id: Some("3kabc".to_string()),
+            value: "spoilers".to_string(),
+            targets: vec![MutedWordTarget::Tag],
+            exclude_following: true,
+            expires_at,
+        }
+    }
+
+    /// The summary names the scope, the time left and the follow carve-out.
+    #[test]
+    fn the_summary_says_where_for_how_long_and_for_whom() {
+        let now = Utc::now();
+        assert_eq!(
+            summary(&word(None), now),
+            "Tags only · Not for people you follow"
+        );
+        let in_three_days =
+            (now + chrono::Duration::hours(72) + chrono::Duration::minutes(5)).to_rfc3339();
+        assert_eq!(
+            summary(&word(Some(in_three_days)), now),
+            "Tags only · 3 days left · Not for people you follow"
+        );
+        let gone = (now - chrono::Duration::hours(1)).to_rfc3339();
+        assert_eq!(
+            summary(&word(Some(gone)), now),
+            "Tags only · Expired · Not for people you follow"
+        );
+    }
+
+    /// A blank entry saves nothing; a new word gets an id and the picked
+    /// duration; an edit keeps its id and, by default, its expiry.
+    #[test]
+    fn the_form_reads_back_as_a_muted_word() {
+        crate::ui::with_gtk(the_form_reads_back_as_a_muted_word_body);
+    }
+
+    fn the_form_reads_back_as_a_muted_word_body() {
+        let now = Utc::now();
+
+        let parts = build(None);
+        assert!(!parts.save.is_sensitive());
+        assert!(parts.word(now).is_none());
+        parts.value.set_text("  hot take ");
+        parts.duration.set_selected(1);
+        assert!(parts.save.is_sensitive());
+        let added = parts.word(now).expect("a word");
+        assert_eq!(added.value, "hot take");
+        assert_eq!(
+            added.targets,
+            vec![MutedWordTarget::Content, MutedWordTarget::Tag]
+        );
+        assert!(added.id.is_some());
+        assert_eq!(
+            added.expires_at,
+            Some(
+                (now + chrono::Duration::hours(24))
+                    .to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+            )
+        );
+
+        let expiry = (now + chrono::Duration::days(2)).to_rfc3339();
+        let existing = word(Some(expiry.clone()));
+        let parts = build(Some(&existing));
+        assert_eq!(parts.word(now), Some(existing.clone()));
+
+        // "Forever" sits one below "Keep Current" when there is an expiry.
+        parts.duration.set_selected(1);
+        let forever = parts.word(now).expect("a word");
+        assert_eq!(forever.id, existing.id);
+        assert_eq!(forever.expires_at, None);
+    }
+}
@@ -0,0 +1,328 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! The steps after a new account's profile: interests, people to follow,
+//! and feeds to pin.
+//!
+//! Each step is a page on one navigation view, and the next page's
+//! contents come from the server once the step before is done, so the
+//! pages are filled and pushed by whoever drives the dialog. Nothing here
+//! talks to the network.
+
+use crate::atproto::{FeedGenerator, Profile};
+use gtk4::prelude::*;
+use libadwaita as adw;
+use libadwaita::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Bluesky's interest tags, as `interestsPref` stores them, with their
+/// labels.
+const INTERESTS: &[(&str, &str)] = &[
+    ("animals", "Animals"),
+    ("art", "Art"),
+    ("books", "Books"),
+    ("comedy", "Comedy"),
+    ("comics", "Comics"),
+    ("culture", "Culture"),
+    ("dev", "Software Dev"),
+    ("education", "Education"),
+    ("food", "Food"),
+    ("gaming", "Video Games"),
+    ("journalism", "Journalism"),
+    ("movies", "Movies"),
+    ("music", "Music"),
+    ("nature", "Nature"),
+    ("news", "News"),
+    ("pets", "Pets"),
+    ("photography", "Photography"),
+    ("politics", "Politics"),
+    ("science", "Science"),
+    ("sports", "Sports"),
+    ("tech", "Tech"),
+    ("tv", "TV"),
+    ("writers", "Writers"),
+];
+
+/// The onboarding dialog and what was picked on it.
+#[derive(Clone)]
+pub struct Onboarding {
+    pub dialog: adw::Dialog,
+    nav_view: adw::NavigationView,
+    interests: Vec<(&'static str, gtk4::ToggleButton)>,
+    pub interests_next: gtk4::Button,
+    follows_page: adw::NavigationPage,
+    follows_list: gtk4::ListBox,
+    /// Each suggested account's DID and its checkbox
+    follows: Rc<RefCell<Vec<(String, gtk4::CheckButton)>>>,
+    pub follows_next: gtk4::Button,
+    feeds_page: adw::NavigationPage,
+    feeds_list: gtk4::ListBox,
+    feeds: Rc<RefCell<Vec<(FeedGenerator, gtk4::Switch)>>>,
+    pub finish: gtk4::Button,
+}
+
+/// A page: a header, a dimmed explanation, `body`, and a button at the
+/// bottom that moves on.
+fn page(
+    title: &str,
+    intro: &str,
+    body: &impl IsA<gtk4::Widget>,
+    next: &gtk4::Button,
+) -> adw::NavigationPage {
+    let content = gtk4::Box::new(gtk4::Orientation::Vertical, 16);
+    content.set_margin_start(24);
+    content.set_margin_end(24);
+    content.set_margin_top(12);
+    content.set_margin_bottom(24);
+
+    let label = gtk4::Label::new(Some(intro));
+    label.set_wrap(true);
+    label.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
+    label.set_justify(gtk4::Justification::Center);
+    label.add_css_class("dim-label");
+    content.append(&label);
+    content.append(body);
+
+    let scrolled = gtk4::ScrolledWindow::new();
+    scrolled.set_hscrollbar_policy(gtk4::PolicyType::Never);
+    scrolled.set_vexpand(true);
+    scrolled.set_child(Some(&content));
+
+    next.add_css_class("suggested-action");
+    next.add_css_class("pill");
+    next.set_halign(gtk4::Align::Center);
+    next.set_margin_top(12);
+    next.set_margin_bottom(12);
+
+    let toolbar = adw::ToolbarView::new();
+    toolbar.add_top_bar(&adw::HeaderBar::new());
+    toolbar.set_content(Some(&scrolled));
+    toolbar.add_bottom_bar(next);
+    adw::NavigationPage::new(&toolbar, title)
+}
+
+/// A boxed list with `empty` shown in place of rows while it has none.
+fn list(empty: &str) -> gtk4::ListBox {
+    let list = gtk4::ListBox::new();
+    list.set_selection_mode(gtk4::SelectionMode::None);
+    list.add_css_class("boxed-list");
+    let placeholder = gtk4::Label::new(Some(empty));
+    placeholder.add_css_class("dim-label");
+    placeholder.set_margin_top(24);
+    placeholder.set_margin_bottom(24);
+    list.set_placeholder(Some(&placeholder));
+    list
+}
+
+impl Onboarding {
+    pub fn new() -> Self {
+        let nav_view = adw::NavigationView::new();
+
+        let flow = gtk4::FlowBox::new();
+        flow.set_selection_mode(gtk4::SelectionMode::None);
+        flow.set_homogeneous(true);
+        flow.set_max_children_per_line(3);
+        flow.set_row_spacing(8);
+        flow.set_column_spacing(8);
+        let interests: Vec<_> = INTERESTS
+            .iter()
+            .map(|&(tag, label)| {
+                let toggle = gtk4::ToggleButton::with_label(label);
+                toggle.add_css_class("pill");
+                flow.insert(&toggle, -1);
+                (tag, toggle)
+            })
+            .collect();
+        let interests_next = gtk4::Button::with_label("Next");
+        let interests_page = page(
+            "Your Interests",
+            "Pick what you'd like to see. This shapes who and what gets suggested next.",
+            &flow,
+            &interests_next,
+        );
+        nav_view.add(&interests_page);
+
+        let follows_list = list("No suggestions right now");
+        let follows_next = gtk4::Button::with_label("Next");
+        let follows_page = page(
+            "People to Follow",
+            "Follow a few accounts to fill your Following feed.",
+            &follows_list,
+            &follows_next,
+        );
+
+        let feeds_list = list("No feeds to suggest right now");
+        let finish = gtk4::Button::with_label("Done");
+        let feeds_page = page(
+            "Your Feeds",
+            "Pin feeds to keep them next to Following at the top of the timeline.",
+            &feeds_list,
+            &finish,
+        );
+
+        let dialog = adw::Dialog::builder()
+            .title("Get Started")
+            .content_width(440)
+            .content_height(560)
+            .child(&nav_view)
+            .build();
+
+        Self {
+            dialog,
+            nav_view,
+            interests,
+            interests_next,
+            follows_page,
+            follows_list,
+            follows: Rc::new(RefCell::new(Vec::new())),
+            follows_next,
+            feeds_page,
+            feeds_list,
+            feeds: Rc::new(RefCell::new(Vec::new())),
+            finish,
+        }
+    }
+
+    /// The interest tags toggled on.
+    pub fn chosen_interests(&self) -> Vec<String> {
+        self.interests
+            .iter()
+            .filter(|(_, toggle)| toggle.is_active())
+            .map(|(tag, _)| tag.to_string())
+            .collect()
+    }
+
+    /// Fill the follows page with `profiles`, none ticked, and move to it.
+    pub fn show_follows(&self, profiles: &[Profile]) {
+        self.follows_list.remove_all();
+        let mut follows = self.follows.borrow_mut();
+        follows.clear();
+        for profile in profiles {
+            let row = adw::ActionRow::new();
+            row.set_title(&glib_escape(
+                profile.display_name.as_deref().unwrap_or(&profile.handle),
+            ));
+            row.set_subtitle(&glib_escape(&format!("@{}", profile.handle)));
+            let avatar = adw::Avatar::new(36, profile.display_name.as_deref(), true);
+            if let Some(url) = &profile.avatar {
+                crate::ui::avatar_cache::load_avatar(avatar.clone(), url.clone());
+            }
+            row.add_prefix(&avatar);
+            let check = gtk4::CheckButton::new();
+            check.set_valign(gtk4::Align::Center);
+            row.add_suffix(&check);
+            row.set_activatable_widget(Some(&check));
+            self.follows_list.append(&row);
+            follows.push((profile.did.clone(), check));
+        }
+        self.nav_view.push(&self.follows_page);
+    }
+
+    /// The DIDs of the accounts ticked.
+    pub fn chosen_follows(&self) -> Vec<String> {
+        self.follows
+            .borrow()
+            .iter()
+            .filter(|(_, check)| check.is_active())
+            .map(|(did, _)| did.clone())
+            .collect()
+    }
+
+    /// Fill the feeds page with `feeds`, none pinned, and move to it.
+    pub fn show_feeds(&self, feeds: &[FeedGenerator]) {
+        self.feeds_list.remove_all();
+        let mut rows = self.feeds.borrow_mut();
+        rows.clear();
+        for feed in feeds {
+            let row = adw::ActionRow::new();
+            row.set_title(&glib_escape(&feed.display_name));
+            row.set_subtitle(&glib_escape(&feed.summary()));
+            let avatar = adw::Avatar::new(36, Some(&feed.display_name), false);
+            if let Some(url) = &feed.avatar {
+                crate::ui::avatar_cache::load_avatar(avatar.clone(), url.clone());
+            }
+            row.add_prefix(&avatar);
+            let switch = gtk4::Switch::new();
+            switch.set_valign(gtk4::Align::Center);
+            row.add_suffix(&switch);
+            row.set_activatable_widget(Some(&switch));
+            self.feeds_list.append(&row);
+            rows.push((feed.clone(), switch));
+        }
+        self.nav_view.push(&self.feeds_page);
+    }
+
+    /// The feeds switched on, in the order shown.
+    pub fn chosen_feeds(&self) -> Vec<FeedGenerator> {
+        self.feeds
+            .borrow()
+            .iter()
+            .filter(|(_, switch)| switch.is_active())
+            .map(|(feed, _)| feed.clone())
+            .collect()
+    }
+
+    /// Hold the page on screen still while its next step loads.
+    pub fn set_busy(&self, busy: bool) {
+        if let Some(page) = self.nav_view.visible_page() {
+            page.set_sensitive(!busy);
+        }
+    }
+
+    pub fn present(&self, parent: &impl IsA<gtk4::Widget>) {
+        self.dialog.present(Some(parent));
+    }
+
+    pub fn close(&self) {
+        self.dialog.close();
+    }
+}
+
+impl Default for Onboarding {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Row titles are markup; names and handles are not.
+fn glib_escape(text: &str) -> String {
+    gtk4::glib::markup_escape_text(text).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What was picked on each page is what comes back out, and nothing
+    /// is picked for the person ahead of time.
+    #[test]
+    fn picks_come_back_out_and_start_empty() {
+        crate::ui::with_gtk(picks_come_back_out_and_start_empty_body);
+    }
+
+    fn picks_come_back_out_and_start_empty_body() {
+        let onboarding = Onboarding::new();
+        assert!(onboarding.chosen_interests().is_empty());
+        onboarding.interests[0].1.set_active(true);
+        assert_eq!(onboarding.chosen_interests(), vec!["animals".to_string()]);
+
+        let people = [
+            Profile::minimal("did:plc:a".into(), "a.bsky.social".into(), None, None),
+            Profile::minimal(
+                "did:plc:b".into(),
+                "b.bsky.social".into(),
+                Some("B".into()),
+                None,
+            ),
+        ];
+        onboarding.show_follows(&people);
+        assert!(onboarding.chosen_follows().is_empty());
+        onboarding.follows.borrow()[1].1.set_active(true);
+        assert_eq!(onboarding.chosen_follows(), vec!["did:plc:b".to_string()]);
+
+        // A second fill replaces the first rather than adding to it.
+        onboarding.show_follows(&people[..1]);
+        assert_eq!(onboarding.follows.borrow().len(), 1);
+        assert!(onboarding.chosen_follows().is_empty());
+    }
+}
@@ -2,7 +2,9 @@
 #![allow(clippy::type_complexity)]
 #![allow(clippy::collapsible_if)]
 
-use crate::atproto::{Embed, ImageEmbed, Post};
+use crate::atproto::interactions::InteractionEvent;
+use crate::atproto::moderation;
+use crate::atproto::{Embed, Facet, FacetFeature, ImageEmbed, ModerationPrefs, Post};
 use crate::ui::avatar_cache;
 use gtk4::gdk;
 use gtk4::glib;
@@ -65,6 +67,14 @@ thread_local! {
     static BLOCK_ACCOUNT_HANDLER: std::cell::RefCell<
         Option<Box<dyn Fn(crate::atproto::Profile)>>,
     > = const { std::cell::RefCell::new(None) };
+    /// What Show More/Less Like This does, for feeds that take feedback.
+    static FEED_FEEDBACK_HANDLER: std::cell::RefCell<
+        Option<Box<dyn Fn(Post, InteractionEvent)>>,
+    > = const { std::cell::RefCell::new(None) };
+    /// The account's label preferences. `bind` decides each post's cover
+    /// from them, and quote cards theirs.
+    static MODERATION_PREFS: std::cell::RefCell<ModerationPrefs> =
+        std::cell::RefCell::new(ModerationPrefs::default());
 }
 
 /// Record whose posts are deletable. `None` on sign-out.
@@ -111,6 +121,28 @@ pub fn set_block_account_handler<F: Fn(crate::atproto::Profile) + 'static>(handl
     });
 }
 
+/// Install the app-level flow for Show More/Less Like This. The event is
+/// always [`InteractionEvent::RequestMore`] or
+/// [`InteractionEvent::RequestLess`].
+pub fn set_feed_feedback_handler<F: Fn(Post, InteractionEvent) + 'static>(handler: F) {
+    FEED_FEEDBACK_HANDLER.with(|cell| {
+        cell.replace(Some(Box::new(handler)));
+    });
+}
+
+/// Record the account's label preferences. Rows bound afterwards use them;
+/// the window rebinds the ones already on screen.
+pub fn set_moderation_prefs(prefs: ModerationPrefs) {
+    MODERATION_PREFS.with(|cell| {
+        cell.replace(prefs);
+    });
+}
+
+/// What the labels on `post` call for under the current preferences.
+fn moderation_for(post: &Post) -> Option<moderation::Decision> {
+    MODERATION_PREFS.with(|cell| moderation::decide_post(post, &cell.borrow()))
+}
+
 mod imp {
     use super::*;
     use std::cell::{Cell, RefCell};
@@ -160,6 +192,19 @@ mod imp {
         pub reply_handle_label: RefCell<Option<gtk4::Label>>,
         pub reply_indicator_box: RefCell<Option<gtk4::Box>>,
         pub embed_container: RefCell<Option<gtk4::Box>>,
+        /// The text and embeds, which the moderation cover sits among.
+        pub content_area: RefCell<Option<gtk4::Box>>,
+        pub moderation_cover: RefCell<Option<gtk4::Box>>,
+        pub moderation_label: RefCell<Option<gtk4::Label>>,
+        pub moderation_show_btn: RefCell<Option<gtk4::Button>>,
+        /// What the bound post's labels call for; `None` shows it as is.
+        pub moderation: RefCell<Option<moderation::Decision>>,
+        /// Whether the reader clicked through the bound post's cover.
+        pub revealed: Cell<bool>,
+        /// Whether the bound post's embed is built. A covered embed waits
+        /// for Show, so its images are never fetched and its video never
+        /// plays unasked.
+        pub embed_rendered: Cell<bool>,
         pub verified_badge: RefCell<Option<gtk4::Image>>,
         pub post_menu_btn: RefCell<Option<gtk4::MenuButton>>,
         pub view_post_item: RefCell<Option<gtk4::Button>>,
@@ -175,6 +220,9 @@ mod imp {
         pub block_item: RefCell<Option<gtk4::Button>>,
         pub delete_section: RefCell<Option<gtk4::Box>>,
         pub moderation_section: RefCell<Option<gtk4::Box>>,
+        /// Show More/Less Like This, offered only where the home feed
+        /// takes feedback. Bind hides it; the timeline turns it back on.
+        pub feedback_section: RefCell<Option<gtk4::Box>>,
         // Track current like/repost state (may differ from original post after user actions)
         pub is_liked: RefCell<bool>,
         pub is_reposted: RefCell<bool>,
@@ -186,6 +234,7 @@ mod imp {
         pub post_clicked_callback: RefCell<Option<Box<dyn Fn(Post) + 'static>>>,
         pub profile_clicked_callback: RefCell<Option<Box<dyn Fn(Profile) + 'static>>>,
         pub mention_clicked_callback: RefCell<Option<Box<dyn Fn(String) + 'static>>>,
+        pub tag_clicked_callback: RefCell<Option<Box<dyn Fn(String) + 'static>>>,
         // Main box for cursor control
         pub main_box: RefCell<Option<gtk4::Box>>,
         /// The row's video embed. Only strong ref in the process; the director
@@ -437,6 +486,34 @@ impl PostRow {
         // Content area
         let content_area = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
 
+        // Cover for labeled content. Above the text when the whole post is
+        // covered, between the text and the embeds when only the media is;
+        // `apply_moderation` moves it.
+        let moderation_cover = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
+        moderation_cover.add_css_class("moderation-cover");
+        moderation_cover.set_visible(false);
+        let cover_icon = gtk4::Image::from_icon_name("dialog-warning-symbolic");
+        cover_icon.set_valign(gtk4::Align::Center);
+        moderation_cover.append(&cover_icon);
+        let moderation_label = gtk4::Label::new(None);
+        moderation_label.set_hexpand(true);
+        moderation_label.set_xalign(0.0);
+        moderation_label.set_wrap(true);
+        moderation_cover.append(&moderation_label);
+        let moderation_show_btn = gtk4::Button::with_label("Show");
+        moderation_show_btn.add_css_class("flat");
+        moderation_show_btn.set_valign(gtk4::Align::Center);
+        // Wired once. It acts on whatever post the row holds when clicked.
+        let row_weak = self.downgrade();
+        moderation_show_btn.connect_clicked(move |_| {
+            if let Some(row) = row_weak.upgrade() {
+                row.imp().revealed.set(true);
+                row.apply_moderation();
+            }
+        });
+        moderation_cover.append(&moderation_show_btn);
+        content_area.append(&moderation_cover);
+
         // Post content
         let content = gtk4::Label::new(None);
         content.set_halign(gtk4::Align::Fill);
@@ -455,7 +532,7 @@ impl PostRow {
                 return glib::Propagation::Proceed;
             };
             if uri.starts_with("bsky-mention://") {
-                // An @mention click navigates to the profile
+                // An @mention click navigates to the profile, by DID or handle
                 let handle = uri.strip_prefix("bsky-mention://").unwrap_or("");
                 let imp = post_row_for_links.imp();
                 if let Some(cb) = imp.mention_clicked_callback.borrow().as_ref() {
@@ -463,13 +540,22 @@ impl PostRow {
                 }
                 glib::Propagation::Stop // We handled it
             } else if uri.starts_with("bsky-tag://") {
-                // Hashtag click. No navigation yet.
-                let _tag = uri.strip_prefix("bsky-tag://").unwrap_or("");
-                // TODO: Implement hashtag search navigation
+                // A #hashtag click opens the tag's page
+                let tag = uri.strip_prefix("bsky-tag://").unwrap_or("");
+                let imp = post_row_for_links.imp();
+                if let Some(cb) = imp.tag_clicked_callback.borrow().as_ref() {
+                    cb(tag.to_string());
+                }
                 glib::Propagation::Stop
             } else {
-                // A regular URL opens in the browser
-                crate::ui::external::open_url(label, uri, "link");
+                // A regular URL opens in the browser, once its text is
+                // checked against where it goes
+                let post = post_row_for_links.imp().post.borrow().clone();
+                let visible = post
+                    .as_ref()
+                    .and_then(|post| Self::link_text(post, uri))
+                    .unwrap_or(uri);
+                crate::ui::external::open_link(label, uri, visible, "link");
                 glib::Propagation::Stop
             }
         });
@@ -482,7 +568,7 @@ impl PostRow {
         embed_container.set_visible(false);
         content_area.append(&embed_container);
 
-        content_column.append(&content_area);
+        content_column.append(&content_area.clone());
 
         // Action bar
         let actions = gtk4::Box::new(gtk4::Orientation::Horizontal, 16);
@@ -532,6 +618,9 @@ impl PostRow {
             delete_item,
             delete_section,
             moderation_section,
+            feedback_section,
+            more_item,
+            less_item,
         ) = Self::create_post_menu_button();
         menu_btn.set_tooltip_text(Some("More options"));
         menu_btn.update_property(&[gtk4::accessible::Property::Label("More options")]);
@@ -596,6 +685,31 @@ impl PostRow {
             }
         });
 
+        // Feed feedback, wired once for the same reason as Delete below.
+        for (item, event) in [
+            (&more_item, InteractionEvent::RequestMore),
+            (&less_item, InteractionEvent::RequestLess),
+        ] {
+            let row_weak = self.downgrade();
+            let feedback_popover = menu_btn.popover();
+            item.connect_clicked(move |_| {
+                if let Some(p) = &feedback_popover {
+                    p.popdown();
+                }
+                let Some(row) = row_weak.upgrade() else {
+                    return;
+                };
+                let post = row.imp().post.borrow().clone();
+                if let Some(post) = post {
+                    FEED_FEEDBACK_HANDLER.with(|cell| {
+                        if let Some(handler) = cell.borrow().as_ref() {
+                            handler(post, event);
+                        }
+                    });
+                }
+            });
+        }
+
         // Report, wired once for the same reason as Delete below.
         let row_weak = self.downgrade();
         let report_popover = menu_btn.popover();
@@ -709,6 +823,10 @@ impl PostRow {
         imp.reply_handle_label.replace(Some(reply_handle_label));
         imp.reply_indicator_box.replace(Some(reply_indicator_box));
         imp.embed_container.replace(Some(embed_container));
+        imp.content_area.replace(Some(content_area));
+        imp.moderation_cover.replace(Some(moderation_cover));
+        imp.moderation_label.replace(Some(moderation_label));
+        imp.moderation_show_btn.replace(Some(moderation_show_btn));
         imp.verified_badge.replace(Some(verified_badge));
         imp.post_menu_btn.replace(Some(menu_btn));
         imp.view_post_item.replace(Some(view_post_item));
@@ -721,6 +839,7 @@ impl PostRow {
         imp.delete_item.replace(Some(delete_item));
         imp.delete_section.replace(Some(delete_section));
         imp.moderation_section.replace(Some(moderation_section));
+        imp.feedback_section.replace(Some(feedback_section));
         imp.main_box.replace(Some(main_box));
     }
 
@@ -897,9 +1016,19 @@ impl PostRow {
         self.imp().list_position.set(position);
     }
 
+    /// Offer Show More/Less Like This on the bound post. The home timeline
+    /// calls this after [`Self::bind`] while its feed takes feedback.
+    pub fn set_feed_feedback(&self, offered: bool) {
+        if let Some(section) = self.imp().feedback_section.borrow().as_ref() {
+            section.set_visible(offered);
+        }
+    }
+
     /// Create a post overflow menu button with View Post, Save, Report, etc.
     /// Returns: (menu_btn, view_item, copy_link_item, open_link_item,
-    /// bookmark_item, bookmark_item_label, delete_item, delete_section)
+    /// bookmark_item, bookmark_item_label, copy_text_item, mute_item,
+    /// block_item, report_item, delete_item, delete_section,
+    /// moderation_section, feedback_section, more_item, less_item)
     fn create_post_menu_button() -> (
         gtk4::MenuButton,
         gtk4::Button,
@@ -914,6 +1043,9 @@ impl PostRow {
         gtk4::Button,
         gtk4::Box,
         gtk4::Box,
+        gtk4::Box,
+        gtk4::Button,
+        gtk4::Button,
     ) {
         let popover_box = gtk4::Box::new(gtk4::Orientation::Vertical, 2);
         popover_box.set_margin_top(6);
@@ -980,6 +1112,32 @@ impl PostRow {
         copy_text_item.add_css_class("flat");
         popover_box.append(&copy_text_item);
 
+        // Feedback to the feed that served the post, in a block of its
+        // own. Hidden until the timeline says its feed wants it.
+        let feedback_section = gtk4::Box::new(gtk4::Orientation::Vertical, 2);
+        let sep_feedback = gtk4::Separator::new(gtk4::Orientation::Horizontal);
+        sep_feedback.set_margin_top(4);
+        sep_feedback.set_margin_bottom(4);
+        feedback_section.append(&sep_feedback);
+
+        let more_item = gtk4::Button::new();
+        let more_content = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
+        more_content.append(&gtk4::Image::from_icon_name("face-smile-symbolic"));
+        more_content.append(&gtk4::Label::new(Some("Show More Like This")));
+        more_item.set_child(Some(&more_content));
+        more_item.add_css_class("flat");
+        feedback_section.append(&more_item);
+
+        let less_item = gtk4::Button::new();
+        let less_content = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
+        less_content.append(&gtk4::Image::from_icon_name("face-sad-symbolic"));
+        less_content.append(&gtk4::Label::new(Some("Show Less Like This")));
+        less_item.set_child(Some(&less_content));
+        less_item.add_css_class("flat");
+        feedback_section.append(&less_item);
+        feedback_section.set_visible(false);
+        popover_box.append(&feedback_section);
+
         // Moderation, in a block of its own. Bind hides it on the
         // signed-in user's posts; you cannot mute yourself.
         let moderation_section = gtk4::Box::new(gtk4::Orientation::Vertical, 2);
@@ -1069,6 +1227,9 @@ impl PostRow {
             delete_item,
             delete_section,
             moderation_section,
+            feedback_section,
+            more_item,
+            less_item,
         )
     }
 
@@ -1278,13 +1439,19 @@ impl PostRow {
             .replace(Some(Box::new(f)));
     }
 
-    /// Set callback for when an @mention in post text is clicked (handle without @)
+    /// Set callback for when an @mention in post text is clicked: the DID
+    /// from its facet, or the handle without @ where the post had none
     pub fn set_mention_clicked_callback<F: Fn(String) + 'static>(&self, f: F) {
         self.imp()
             .mention_clicked_callback
             .replace(Some(Box::new(f)));
     }
 
+    /// Set callback for when a #hashtag in post text is clicked (tag without #)
+    pub fn set_tag_clicked_callback<F: Fn(String) + 'static>(&self, f: F) {
+        self.imp().tag_clicked_callback.replace(Some(Box::new(f)));
+    }
+
     /// Stop the row body from opening its own thread.
     ///
     /// Used for the main post in a thread view. This previously only cleared
@@ -1433,27 +1600,29 @@ impl PostRow {
 
         // Set content with rich text formatting (links, mentions, hashtags)
         if let Some(label) = imp.content_label.borrow().as_ref() {
-            Self::set_post_markup(label, &post.text);
+            Self::set_post_markup(label, &post.text, &post.facets);
         }
 
-        // Render embed content
+        // Clear the previous post's embeds. `apply_moderation` builds this
+        // post's, unless its labels call for a cover first.
         if let Some(container) = imp.embed_container.borrow().as_ref() {
             // Before the widgets go: a recycled row's pipeline belongs to the
             // old post.
             self.release_video();
 
-            // Clear previous embeds
             while let Some(child) = container.first_child() {
                 container.remove(&child);
             }
-
-            if let Some(embed) = &post.embed {
-                self.render_embed(container, embed, true);
-                container.set_visible(true);
-            } else {
-                container.set_visible(false);
-            }
         }
+        imp.embed_rendered.set(false);
+        imp.revealed.set(false);
+        // Whether the signed-in user wrote this post. Their own posts are
+        // never covered from them.
+        let own = CURRENT_USER_DID
+            .with(|cell| cell.borrow().as_deref() == Some(post.author.did.as_str()));
+        imp.moderation
+            .replace(if own { None } else { moderation_for(post) });
+        self.apply_moderation();
 
         if let Some(label) = imp.reply_count_label.borrow().as_ref() {
             label.set_text(&Self::format_count(post.reply_count));
@@ -1514,8 +1683,17 @@ impl PostRow {
             }
         }
 
-        // Set composite accessible label on the PostRow for screen readers
-        let text_preview = if post.text.len() > 200 {
+        // Set composite accessible label on the PostRow for screen readers.
+        // A covered post reads out its cover, not the text under it.
+        let covered = imp
+            .moderation
+            .borrow()
+            .as_ref()
+            .filter(|d| d.blur == moderation::Blur::Content)
+            .map(|d| format!("Covered: {}", d.name));
+        let text_preview = if let Some(covered) = covered.as_deref() {
+            covered
+        } else if post.text.len() > 200 {
             &post.text[..super::floor_char_boundary(&post.text, 200)]
         } else {
             &post.text
@@ -1553,8 +1731,6 @@ impl PostRow {
 
         // Delete is offered only on the signed-in user's own posts, decided
         // fresh on every bind so a recycled row cannot keep the offer.
-        let own = CURRENT_USER_DID
-            .with(|cell| cell.borrow().as_deref() == Some(post.author.did.as_str()));
         if let Some(section) = imp.delete_section.borrow().as_ref() {
             section.set_visible(own);
         }
@@ -1562,6 +1738,8 @@ impl PostRow {
         if let Some(section) = imp.moderation_section.borrow().as_ref() {
             section.set_visible(!own);
         }
+        // Feed feedback is the timeline's to offer, after bind.
+        self.set_feed_feedback(false);
 
         // The Save/Remove label follows the post, fresh on every bind so a
         // recycled row cannot offer to save a post the user already saved.
@@ -1678,6 +1856,59 @@ impl PostRow {
         }
     }
 
+    /// Show the bound post as its moderation decision allows: a cover over
+    /// the embeds or over everything, with Show unless the label forbids it.
+    /// Embeds are built the first time they are shown.
+    fn apply_moderation(&self) {
+        let imp = self.imp();
+        let decision = imp.moderation.borrow().clone();
+        let revealed = imp.revealed.get();
+        let covered = decision.as_ref().filter(|_| !revealed);
+        let text_shown = covered.is_none_or(|d| d.blur == moderation::Blur::Media);
+        let media_shown = covered.is_none();
+
+        if let Some(cover) = imp.moderation_cover.borrow().as_ref() {
+            if let Some(decision) = covered {
+                if let Some(label) = imp.moderation_label.borrow().as_ref() {
+                    label.set_text(&decision.name);
+                }
+                if let Some(btn) = imp.moderation_show_btn.borrow().as_ref() {
+                    btn.set_visible(decision.can_reveal);
+                }
+                // Above the text for a whole-post cover, under it for media.
+                if let Some(area) = imp.content_area.borrow().as_ref() {
+                    let after = if decision.blur == moderation::Blur::Media {
+                        imp.content_label
+                            .borrow()
+                            .as_ref()
+                            .map(|l| l.clone().upcast::<gtk4::Widget>())
+                    } else {
+                        None
+                    };
+                    area.reorder_child_after(cover, after.as_ref());
+                }
+            }
+            cover.set_visible(covered.is_some());
+        }
+
+        if let Some(label) = imp.content_label.borrow().as_ref() {
+            label.set_visible(text_shown);
+        }
+
+        let embed = imp.post.borrow().as_ref().and_then(|p| p.embed.clone());
+        if let Some(container) = imp.embed_container.borrow().as_ref() {
+            if let Some(embed) = embed.as_ref().filter(|_| media_shown) {
+                if !imp.embed_rendered.get() {
+                    self.render_embed(container, embed, true);
+                    imp.embed_rendered.set(true);
+                }
+                container.set_visible(true);
+            } else {
+                container.set_visible(false);
+            }
+        }
+    }
+
     /// Give up any video this row is playing. Idempotent.
     ///
     /// Called from every list factory's `unbind`, and from `bind` before the
@@ -1991,10 +2222,13 @@ impl PostRow {
         card.append(&text_box);
         card_btn.set_child(Some(&card));
 
-        // Open link in browser when clicked
+        // Open link in browser when clicked. The title is the card's
+        // visible text, and may be written as an address the link does
+        // not go to.
         let url = ext.uri.clone();
+        let title = ext.title.clone();
         card_btn.connect_clicked(move |btn| {
-            crate::ui::external::open_url(btn, &url, "link");
+            crate::ui::external::open_card_link(btn, &url, &title);
         });
 
         container.append(&card_btn);
@@ -2247,22 +2481,80 @@ impl PostRow {
 
         card.append(&author_row);
 
+        // The quoted post is judged on its own labels, not the quoting one's.
+        let decision =
+            MODERATION_PREFS.with(|cell| moderation::decide_quote(quote, &cell.borrow()));
+        let text_covered = decision
+            .as_ref()
+            .is_some_and(|d| d.blur == moderation::Blur::Content);
+
         // Quote text
-        if !quote.text.is_empty() {
+        let text_label = (!quote.text.is_empty()).then(|| {
             let text_label = gtk4::Label::new(Some(&quote.text));
             text_label.set_halign(gtk4::Align::Start);
             text_label.set_wrap(true);
             text_label.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
             text_label.set_xalign(0.0);
             text_label.add_css_class("caption");
+            text_label.set_visible(!text_covered);
             card.append(&text_label);
-        }
+            text_label
+        });
 
-        // Nested embed (if any)
-        if let Some(nested_embed) = &quote.embed {
+        // Nested embed (if any). Under a cover it is built on Show.
+        let nested_container = quote.embed.as_ref().map(|_| {
             let nested_container = gtk4::Box::new(gtk4::Orientation::Vertical, 8);
-            self.render_embed(&nested_container, nested_embed, false);
             card.append(&nested_container);
+            nested_container
+        });
+
+        if let Some(decision) = decision {
+            let cover = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
+            cover.add_css_class("moderation-cover");
+            let icon = gtk4::Image::from_icon_name("dialog-warning-symbolic");
+            icon.set_valign(gtk4::Align::Center);
+            cover.append(&icon);
+            let label = gtk4::Label::new(Some(&decision.name));
+            label.add_css_class("caption");
+            label.set_hexpand(true);
+            label.set_xalign(0.0);
+            label.set_wrap(true);
+            cover.append(&label);
+            if decision.can_reveal {
+                // A Button claims its own click, so Show does not also open
+                // the quoted post.
+                let show = gtk4::Button::with_label("Show");
+                show.add_css_class("flat");
+                show.set_valign(gtk4::Align::Center);
+                let row = self.downgrade();
+                let nested_embed = quote.embed.clone();
+                let text_label = text_label.clone();
+                let nested_container = nested_container.clone();
+                show.connect_clicked(glib::clone!(
+                    #[weak]
+                    cover,
+                    move |_| {
+                        cover.set_visible(false);
+                        if let Some(text_label) = &text_label {
+                            text_label.set_visible(true);
+                        }
+                        if let (Some(row), Some(container), Some(embed)) =
+                            (row.upgrade(), &nested_container, &nested_embed)
+                        {
+                            row.render_embed(container, embed, false);
+                        }
+                    }
+                ));
+                cover.append(&show);
+            }
+            // Above the text for a whole-post cover, under it for media.
+            let after: gtk4::Widget = match &text_label {
+                Some(text_label) if !text_covered => text_label.clone().upcast(),
+                _ => author_row.clone().upcast(),
+            };
+            card.insert_child_after(&cover, Some(&after));
+        } else if let (Some(container), Some(nested_embed)) = (&nested_container, &quote.embed) {
+            self.render_embed(container, nested_embed, false);
         }
 
         card_btn.append(&card);
@@ -2287,6 +2579,9 @@ impl PostRow {
             viewer_bookmarked: None,
             repost_reason: None,
             reply_context: None,
+            labels: quote.labels.clone(),
+            feed_context: None,
+            facets: quote.facets.clone(),
         };
         let imp = self.imp();
 
@@ -2371,6 +2666,16 @@ impl PostRow {
         ((CONTENT_WIDTH * f64::from(h) / f64::from(w)).round() as i32).clamp(min, max)
     }
 
+    /// The text a link facet for `uri` covers in `post`, as the reader saw it.
+    fn link_text<'a>(post: &'a Post, uri: &str) -> Option<&'a str> {
+        post.facets.iter().find_map(|facet| match &facet.feature {
+            FacetFeature::Link(link) if link == uri => {
+                post.text.get(facet.byte_start..facet.byte_end)
+            }
+            _ => None,
+        })
+    }
+
     /// Set post text on `label`, linkified, with a plain-text fallback.
     ///
     /// `gtk_label_set_markup` on markup it cannot parse emits a `Gtk-WARNING`
@@ -2379,8 +2684,8 @@ impl PostRow {
     ///
     /// Checked through the label itself. GtkLabel runs its own parser first
     /// and strips the `<a>` tags `pango::parse_markup` would reject.
-    fn set_post_markup(label: &gtk4::Label, text: &str) {
-        let markup = Self::format_post_text(text);
+    fn set_post_markup(label: &gtk4::Label, text: &str, facets: &[Facet]) {
+        let markup = Self::format_post_text(text, facets);
         label.set_text("");
         label.set_markup(&markup);
         if label.text().is_empty() && !text.is_empty() {
@@ -2393,11 +2698,12 @@ impl PostRow {
     }
 
     /// Format post text with clickable links, mentions and hashtags as Pango
-    /// markup. The rules live in [`crate::ui::rich_text`], shared with
+    /// markup: the record's facets where it has them, detection where it
+    /// does not. The rules live in [`crate::ui::rich_text`], shared with
     /// profile bios; the `bsky-mention://` and `bsky-tag://` schemes are
     /// handled in `connect_activate_link`.
-    fn format_post_text(text: &str) -> String {
-        crate::ui::rich_text::linkify(text)
+    fn format_post_text(text: &str, facets: &[Facet]) -> String {
+        crate::ui::rich_text::linkify_facets(text, facets)
     }
 
     fn format_timestamp(indexed_at: &str) -> String {
@@ -2544,19 +2850,19 @@ mod tests {
         ];
 
         for text in cases {
-            PostRow::set_post_markup(&label, text);
+            PostRow::set_post_markup(&label, text, &[]);
             assert_eq!(
                 label.text().as_str(),
                 text,
                 "the label must show the post, not an empty string\n  markup: {}",
-                PostRow::format_post_text(text)
+                PostRow::format_post_text(text, &[])
             );
             // `use-markup` still on means the markup was accepted rather than
             // having gone down the unformatted fallback.
             assert!(
                 label.uses_markup(),
                 "{text:?} fell back to plain text; the markup was rejected\n  markup: {}",
-                PostRow::format_post_text(text)
+                PostRow::format_post_text(text, &[])
             );
         }
 
@@ -2577,6 +2883,7 @@ mod tests {
         // text: these are what makes the label worth marking up at all.
         let markup = PostRow::format_post_text(
             "hi @user.bsky.social see https://example.com and example.org too #rust",
+            &[],
         );
         assert!(
             markup.contains(r#"<a href="bsky-mention://user.bsky.social">@user.bsky.social</a>"#),
@@ -2597,7 +2904,7 @@ mod tests {
 
         // A handle must come out as a mention even though `.social` is in
         // the bare-domain TLD list.
-        let markup = PostRow::format_post_text("@someone.bsky.social");
+        let markup = PostRow::format_post_text("@someone.bsky.social", &[]);
         assert!(
             markup.starts_with(r#"<a href="bsky-mention://"#),
             "{markup}"
@@ -2605,7 +2912,7 @@ mod tests {
 
         // An `&` inside a URL is escaped in the href as well as in the body,
         // and the two agree.
-        let markup = PostRow::format_post_text("https://example.com/?a=1&b=2");
+        let markup = PostRow::format_post_text("https://example.com/?a=1&b=2", &[]);
         assert_eq!(
             markup,
             r#"<a href="https://example.com/?a=1&amp;b=2">https://example.com/?a=1&amp;b=2</a>"#
@@ -2628,6 +2935,9 @@ mod tests {
             viewer_muted: false,
             viewer_blocking: None,
             viewer_blocked_by: false,
+            labels: Vec::new(),
+            muted_by_list: None,
+            blocking_by_list: None,
         }
     }
 
@@ -2645,6 +2955,8 @@ mod tests {
             text: "the quoted text".into(),
             indexed_at: "2026-01-01T00:00:00Z".into(),
             embed: embed.map(Box::new),
+            labels: Vec::new(),
+            facets: Vec::new(),
         }
     }
 
@@ -2836,6 +3148,9 @@ mod tests {
             viewer_bookmarked: None,
             repost_reason: None,
             reply_context: None,
+            labels: Vec::new(),
+            feed_context: None,
+            facets: Vec::new(),
         }
     }
 
@@ -3843,6 +4158,61 @@ mod tests {
         super::set_current_user_did(None);
     }
 
+    /// Show More/Less is the home timeline's to offer: every bind takes it
+    /// away, so a row recycled into a thread or a profile cannot send
+    /// feedback to a feed it is no longer part of.
+    #[test]
+    fn feed_feedback_is_offered_only_after_bind_and_reports_the_post() {
+        crate::ui::with_gtk(feed_feedback_is_offered_only_after_bind_and_reports_the_post_body);
+    }
+
+    fn feed_feedback_is_offered_only_after_bind_and_reports_the_post_body() {
+        use std::cell::RefCell as StdRefCell;
+        use std::rc::Rc;
+
+        let asked: Rc<StdRefCell<Vec<(String, InteractionEvent)>>> = Rc::default();
+        let sink = Rc::clone(&asked);
+        super::set_feed_feedback_handler(move |post, event| {
+            sink.borrow_mut().push((post.uri, event));
+        });
+
+        let row = PostRow::new();
+        let section = row
+            .imp()
+            .feedback_section
+            .borrow()
+            .clone()
+            .expect("built in setup_ui");
+        let uri = "at://did:plc:test/app.bsky.feed.post/fed";
+
+        row.bind(&post_with(None, uri));
+        assert!(!section.get_visible(), "a plain bind offers no feedback");
+        row.set_feed_feedback(true);
+        assert!(section.get_visible());
+        row.bind(&post_with(None, uri));
+        assert!(!section.get_visible(), "rebinding takes the offer away");
+
+        row.set_feed_feedback(true);
+        let more = section
+            .first_child()
+            .and_then(|separator| separator.next_sibling())
+            .and_downcast::<gtk4::Button>()
+            .expect("Show More follows the separator");
+        let less = more
+            .next_sibling()
+            .and_downcast::<gtk4::Button>()
+            .expect("Show Less follows Show More");
+        more.emit_clicked();
+        less.emit_clicked();
+        assert_eq!(
+            *asked.borrow(),
+            vec![
+                (uri.to_string(), InteractionEvent::RequestMore),
+                (uri.to_string(), InteractionEvent::RequestLess),
+            ]
+        );
+    }
+
     /// A row must die when its owner lets go.
     ///
     /// Every closure on a descendant widget holds the row weakly. A strong
@@ -4202,4 +4572,89 @@ mod tests {
         assert_eq!(like_count.text(), "999", "unliking has to undo the like");
         assert_eq!(accessible_label(&like_btn), "Like. 999 likes");
     }
+
+    /// A labeled post is covered until Show, its media is not even built
+    /// while covered, and a recycled row covers its next post afresh.
+    #[test]
+    fn a_labeled_post_stays_covered_until_shown() {
+        crate::ui::with_gtk(a_labeled_post_stays_covered_until_shown_body);
+    }
+
+    fn a_labeled_post_stays_covered_until_shown_body() {
+        use crate::atproto::{Label, ModerationPrefs};
+
+        super::set_moderation_prefs(ModerationPrefs {
+            adult_content_enabled: true,
+            ..ModerationPrefs::default()
+        });
+        let mut labeled = post_with(
+            Some(Embed::Images(vec![ImageEmbed {
+                thumb: dead_url(),
+                fullsize: dead_url(),
+                alt: String::new(),
+                aspect_ratio: Some((1, 1)),
+            }])),
+            "at://did:plc:test/app.bsky.feed.post/labeled",
+        );
+        labeled.labels = vec![Label {
+            src: "did:plc:ar7c4by46qjdydhdevvrndac".into(),
+            val: "graphic-media".into(),
+            neg: false,
+        }];
+
+        let row = PostRow::new();
+        let imp = row.imp();
+        let cover = imp
+            .moderation_cover
+            .borrow()
+            .clone()
+            .expect("built in setup_ui");
+        let container = embed_container_of(&row);
+        let text = imp
+            .content_label
+            .borrow()
+            .clone()
+            .expect("built in setup_ui");
+
+        row.bind(&labeled);
+        assert!(cover.get_visible(), "a graphic-media label covers the post");
+        assert!(text.get_visible(), "a media label leaves the text readable");
+        assert!(!container.get_visible());
+        assert!(
+            container.first_child().is_none(),
+            "covered media must not be built, or its images load anyway"
+        );
+
+        imp.moderation_show_btn
+            .borrow()
+            .as_ref()
+            .expect("built in setup_ui")
+            .emit_clicked();
+        assert!(!cover.get_visible());
+        assert!(container.get_visible());
+        assert!(container.first_child().is_some(), "Show builds the media");
+
+        // Recycled onto the same post, the reader has to click through again.
+        row.bind(&labeled);
+        assert!(
+            cover.get_visible(),
+            "a rebind must not keep the last reveal"
+        );
+
+        // A moderator's whole-post warning covers the text as well.
+        labeled.labels[0].val = "!warn".into();
+        row.bind(&labeled);
+        assert!(cover.get_visible());
+        assert!(!text.get_visible());
+
+        // And an unlabeled post gets no cover at all.
+        row.bind(&post_with(
+            None,
+            "at://did:plc:test/app.bsky.feed.post/plain",
+        ));
+        assert!(!cover.get_visible());
+        assert!(text.get_visible());
+
+        super::set_moderation_prefs(ModerationPrefs::default());
+    }
 }
@@ -79,6 +79,8 @@ fn quoted(nested: Option<Embed>) -> QuoteEmbed {
         text: "the quoted post body".into(),
         indexed_at: "2026-01-01T00:00:00Z".into(),
         embed: nested.map(Box::new),
+        labels: Vec::new(),
+        facets: Vec::new(),
     }
 }
 
@@ -158,6 +160,9 @@ fn audit_post(i: u32) -> Post {
             ),
             root_author: who(),
         }),
+        labels: Vec::new(),
+        feed_context: None,
+        facets: Vec::new(),
     }
 }
 
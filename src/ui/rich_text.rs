@@ -2,11 +2,14 @@
 
 //! Linkified label text for posts and profile bios.
 //!
-//! The lexicon has no facets for bios; `getProfile` returns a bare
-//! description string and every client finds the links itself. The span
-//! detection and escaping rules live here once; `PostRow::format_post_text`
-//! delegates to [`linkify`].
+//! A post's record says where its links, mentions and tags are, and
+//! [`linkify_facets`] marks up exactly those spans. The lexicon has no
+//! facets for bios; `getProfile` returns a bare description string and
+//! every client finds the links itself, as [`linkify`] does. Posts without
+//! facets fall back to it too. The escaping rules live here once;
+//! `PostRow::format_post_text` delegates.
 
+use crate::atproto::{Facet, FacetFeature};
 use gtk4::glib;
 
 /// One linkified span, as byte offsets into the raw text.
@@ -82,11 +85,61 @@ fn text_links(text: &str) -> Vec<(std::ops::Range<usize>, String)> {
 /// just produced, Pango then rejects the string, and a bio with `&` or `<`
 /// comes up blank. Post text had exactly that bug.
 pub(crate) fn linkify(text: &str) -> String {
-    let links = text_links(text);
+    markup_links(text, &text_links(text))
+}
+
+/// The spans a post's facets mark, as byte ranges with their hrefs.
+///
+/// A facet that does not fit the text (past its end, or splitting a
+/// character) is skipped, and so is one overlapping an earlier span.
+/// Links keep to http and https: anything else, our own mention and tag
+/// schemes included, would be followed blind from a click.
+fn facet_links(text: &str, facets: &[Facet]) -> Vec<(std::ops::Range<usize>, String)> {
+    let mut links: Vec<(std::ops::Range<usize>, String)> = Vec::new();
+    for facet in facets {
+        let range = facet.byte_start..facet.byte_end;
+        if range.is_empty()
+            || range.end > text.len()
+            || !text.is_char_boundary(range.start)
+            || !text.is_char_boundary(range.end)
+            || links.iter().any(|(taken, _)| range.start < taken.end)
+        {
+            continue;
+        }
+        let href = match &facet.feature {
+            FacetFeature::Link(uri)
+                if uri.starts_with("https://") || uri.starts_with("http://") =>
+            {
+                uri.clone()
+            }
+            FacetFeature::Link(_) => continue,
+            FacetFeature::Mention(did) => format!("bsky-mention://{did}"),
+            FacetFeature::Tag(tag) => format!("bsky-tag://{tag}"),
+        };
+        links.push((range, href));
+    }
+    links
+}
+
+/// Post text as Pango markup with its facets as clickable spans: a link
+/// goes where the record says, whatever its visible text, and a mention
+/// carries the DID, which still resolves after a handle change. Facets
+/// come sorted from [`crate::atproto::client`]. A post without any is
+/// linkified by detection instead.
+pub(crate) fn linkify_facets(text: &str, facets: &[Facet]) -> String {
+    if facets.is_empty() {
+        return linkify(text);
+    }
+    markup_links(text, &facet_links(text, facets))
+}
+
+/// `text` with each of `links` wrapped in an `<a>`. The links are sorted
+/// and do not overlap.
+fn markup_links(text: &str, links: &[(std::ops::Range<usize>, String)]) -> String {
     let mut out = String::with_capacity(text.len() + links.len() * 48);
     let mut cursor = 0;
 
-    for (range, href) in &links {
+    for (range, href) in links {
         out.push_str(&glib::markup_escape_text(&text[cursor..range.start]));
         out.push_str("<a href=\"");
         // The href is wire text too.
@@ -195,6 +248,69 @@ mod tests {
         );
     }
 
+    fn facet(range: std::ops::Range<usize>, feature: FacetFeature) -> Facet {
+        Facet {
+            byte_start: range.start,
+            byte_end: range.end,
+            feature,
+        }
+    }
+
+    /// Facets are taken at their word: the shortened link opens the full
+    /// URL, the mention carries the DID and the tag keeps its accent. No
+    /// detection runs alongside, so a domain-looking word stays text.
+    #[test]
+    fn facets_mark_up_exactly_their_spans() {
+        let text = "read example.com/very-lo… by @old.handle #café, also fake.com";
+        let link_end = "read example.com/very-lo…".len();
+        let mention = text.find("@old").unwrap();
+        let tag = text.find("#caf").unwrap();
+        let markup = linkify_facets(
+            text,
+            &[
+                facet(
+                    5..link_end,
+                    FacetFeature::Link("https://example.com/very-long-path".into()),
+                ),
+                facet(
+                    mention..mention + "@old.handle".len(),
+                    FacetFeature::Mention("did:plc:abc".into()),
+                ),
+                facet(tag..tag + "#café".len(), FacetFeature::Tag("café".into())),
+            ],
+        );
+        assert_eq!(
+            markup,
+            "read <a href=\"https://example.com/very-long-path\">example.com/very-lo…</a> \
+             by <a href=\"bsky-mention://did:plc:abc\">@old.handle</a> \
+             <a href=\"bsky-tag://café\">#café</a>, also fake.com"
+        );
+
+        // No facets at all: the text is linkified the old way.
+        assert_eq!(linkify_facets("see fake.com", &[]), linkify("see fake.com"));
+    }
+
+    /// A facet written against other text must not panic or split a
+    /// character, and a link facet outside the web schemes stays text.
+    #[test]
+    fn facets_that_do_not_fit_are_skipped() {
+        let text = "héllo world";
+        let markup = linkify_facets(
+            text,
+            &[
+                // Splits the é.
+                facet(0..2, FacetFeature::Tag("h".into())),
+                // Past the end.
+                facet(6..40, FacetFeature::Tag("world".into())),
+                facet(0..6, FacetFeature::Link("bsky-tag://sneaky".into())),
+                facet(7..12, FacetFeature::Link("https://example.com".into())),
+                // Overlaps the span before it.
+                facet(8..10, FacetFeature::Tag("or".into())),
+            ],
+        );
+        assert_eq!(markup, "héllo <a href=\"https://example.com\">world</a>");
+    }
+
     /// The label-level guarantee: hostile bios render as text, linked bios
     /// keep their markup.
     #[test]
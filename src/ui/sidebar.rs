@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MPL-2.0
 #![allow(clippy::collapsible_if)]
 
+use crate::state::Account;
 use crate::ui::avatar_cache;
 use gtk4::glib;
 use gtk4::prelude::*;
@@ -18,6 +19,8 @@ pub enum NavItem {
     Likes,
     Bookmarks,
     Search,
+    /// Last, so every earlier section keeps its Alt+number
+    Lists,
 }
 
 impl NavItem {
@@ -31,6 +34,7 @@ impl NavItem {
             Self::Likes => "emote-love-symbolic",
             Self::Bookmarks => "user-bookmarks-symbolic",
             Self::Search => "system-search-symbolic",
+            Self::Lists => "view-list-symbolic",
         }
     }
 
@@ -44,6 +48,7 @@ impl NavItem {
             Self::Likes => "Likes",
             Self::Bookmarks => "Saved",
             Self::Search => "Search",
+            Self::Lists => "Lists",
         }
     }
 
@@ -57,13 +62,23 @@ impl NavItem {
             Self::Likes,
             Self::Bookmarks,
             Self::Search,
+            Self::Lists,
         ]
     }
 }
 
+/// An account menu row that can carry an unread badge.
+pub struct AccountRow {
+    did: String,
+    label: String,
+    badge: gtk4::Label,
+    button: gtk4::Button,
+}
+
 mod imp {
     use super::*;
     use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
 
     #[derive(Default)]
     pub struct Sidebar {
@@ -76,6 +91,20 @@ mod imp {
         pub settings_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
         pub about_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
         pub sign_out_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        pub switch_account_callback: RefCell<Option<Box<dyn Fn(String) + 'static>>>,
+        pub add_account_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        pub remove_account_callback: RefCell<Option<Box<dyn Fn(String) + 'static>>>,
+        pub account_popover: RefCell<Option<gtk4::Popover>>,
+        /// One row per signed-in account, at the top of the avatar menu.
+        pub accounts_box: RefCell<Option<gtk4::Box>>,
+        /// The account on screen. Its unread lives on the nav badges.
+        pub active_account: RefCell<Option<String>>,
+        /// The menu row of each account that is not on screen.
+        pub account_rows: RefCell<Vec<AccountRow>>,
+        /// Unread per other account, kept across menu rebuilds.
+        pub account_unread: RefCell<HashMap<String, u32>>,
+        /// On the avatar while any other account has unread.
+        pub account_unread_dot: RefCell<Option<gtk4::Box>>,
         /// Unread badge per nav row, in `NavItem::all()` order.
         pub badge_labels: RefCell<Vec<gtk4::Label>>,
         /// The caption under each nav icon, hidden in compact mode.
@@ -129,7 +158,8 @@ impl Sidebar {
         // Accessible label for the navigation landmark
         self.update_property(&[gtk4::accessible::Property::Label("Main navigation")]);
 
-        // Avatar at top: a MenuButton whose popover holds Settings and Sign Out
+        // Avatar at top: a MenuButton whose popover holds the accounts,
+        // Settings, and Sign Out
         let avatar_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
         avatar_box.set_margin_top(12);
         avatar_box.set_margin_bottom(8);
@@ -144,6 +174,24 @@ impl Sidebar {
         popover_box.set_margin_start(8);
         popover_box.set_margin_end(8);
 
+        // Signed-in accounts, filled by `set_accounts`.
+        let accounts_box = gtk4::Box::new(gtk4::Orientation::Vertical, 2);
+        popover_box.append(&accounts_box);
+
+        // Add Account item
+        let add_account_item = gtk4::Button::new();
+        let add_account_content = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
+        add_account_content.append(&gtk4::Image::from_icon_name("list-add-symbolic"));
+        add_account_content.append(&gtk4::Label::new(Some("Add Account")));
+        add_account_item.set_child(Some(&add_account_content));
+        add_account_item.add_css_class("flat");
+        popover_box.append(&add_account_item);
+
+        let accounts_sep = gtk4::Separator::new(gtk4::Orientation::Horizontal);
+        accounts_sep.set_margin_top(4);
+        accounts_sep.set_margin_bottom(4);
+        popover_box.append(&accounts_sep);
+
         // My Profile item. The avatar reads as "me"; give it a way there.
         let my_profile_item = gtk4::Button::new();
         let my_profile_content = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
@@ -191,15 +239,43 @@ impl Sidebar {
         popover.add_css_class("menu");
         popover.set_has_arrow(false);
 
+        // Says another account has something unread without saying how
+        // much; the menu rows carry the counts. The button's accessible
+        // label says it in words.
+        let account_unread_dot = gtk4::Box::builder()
+            .visible(false)
+            .can_target(false)
+            .halign(gtk4::Align::End)
+            .valign(gtk4::Align::Start)
+            .accessible_role(gtk4::AccessibleRole::Presentation)
+            .build();
+        account_unread_dot.add_css_class("account-unread-dot");
+
+        let avatar_overlay = gtk4::Overlay::new();
+        avatar_overlay.set_child(Some(&avatar));
+        avatar_overlay.add_overlay(&account_unread_dot);
+
         // MenuButton wrapping the avatar
         let avatar_menu_btn = gtk4::MenuButton::new();
-        avatar_menu_btn.set_child(Some(&avatar));
+        avatar_menu_btn.set_child(Some(&avatar_overlay));
         avatar_menu_btn.set_popover(Some(&popover));
         avatar_menu_btn.add_css_class("flat");
         avatar_menu_btn.add_css_class("circular");
         avatar_menu_btn.set_tooltip_text(Some("Account"));
         avatar_menu_btn.update_property(&[gtk4::accessible::Property::Label("Account menu")]);
 
+        // Wire up add account click
+        let sidebar_weak = self.downgrade();
+        let popover_ref = popover.clone();
+        add_account_item.connect_clicked(move |_| {
+            popover_ref.popdown();
+            if let Some(sidebar) = sidebar_weak.upgrade() {
+                if let Some(cb) = sidebar.imp().add_account_callback.borrow().as_ref() {
+                    cb();
+                }
+            }
+        });
+
         // Wire up my profile click
         let sidebar_weak = self.downgrade();
         let popover_ref = popover.clone();
@@ -254,6 +330,9 @@ impl Sidebar {
         let imp_ref = self.imp();
         imp_ref.avatar.replace(Some(avatar));
         imp_ref.avatar_menu_btn.replace(Some(avatar_menu_btn));
+        imp_ref.account_popover.replace(Some(popover));
+        imp_ref.accounts_box.replace(Some(accounts_box));
+        imp_ref.account_unread_dot.replace(Some(account_unread_dot));
 
         // Navigation list
         let nav_list = gtk4::ListBox::new();
@@ -416,6 +495,197 @@ impl Sidebar {
             .replace(Some(Box::new(callback)));
     }
 
+    pub fn connect_switch_account_clicked<F: Fn(String) + 'static>(&self, callback: F) {
+        self.imp()
+            .switch_account_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    pub fn connect_add_account_clicked<F: Fn() + 'static>(&self, callback: F) {
+        self.imp()
+            .add_account_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    pub fn connect_remove_account_clicked<F: Fn(String) + 'static>(&self, callback: F) {
+        self.imp()
+            .remove_account_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Rebuild the account rows at the top of the avatar menu.
+    ///
+    /// Every account gets a row to switch to and a button to sign out of it
+    /// alone. The active one is marked rather than hidden, so the list reads
+    /// the same whichever account is on screen.
+    pub fn set_accounts(&self, accounts: &[Account], active: Option<&str>) {
+        let imp = self.imp();
+        imp.active_account.replace(active.map(str::to_string));
+        imp.account_rows.borrow_mut().clear();
+
+        let Some(accounts_box) = imp.accounts_box.borrow().clone() else {
+            return;
+        };
+        while let Some(child) = accounts_box.first_child() {
+            accounts_box.remove(&child);
+        }
+
+        for account in accounts {
+            let is_active = active == Some(account.did.as_str());
+            accounts_box.append(&self.create_account_row(account, is_active));
+        }
+
+        self.update_account_unread();
+    }
+
+    fn create_account_row(&self, account: &Account, is_active: bool) -> gtk4::Box {
+        let row = gtk4::Box::new(gtk4::Orientation::Horizontal, 2);
+        row.add_css_class("account-row");
+
+        let content = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
+
+        let avatar = adw::Avatar::new(32, Some(account.label()), true);
+        if let Some(url) = &account.avatar {
+            avatar_cache::load_avatar(avatar.clone(), url.clone());
+        }
+        content.append(&avatar);
+
+        let names = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        names.set_hexpand(true);
+        names.set_valign(gtk4::Align::Center);
+        let name = gtk4::Label::new(Some(account.label()));
+        name.set_halign(gtk4::Align::Start);
+        name.set_ellipsize(gtk4::pango::EllipsizeMode::End);
+        names.append(&name);
+        if !account.handle.is_empty() && account.label() != account.handle {
+            let handle = gtk4::Label::new(Some(&format!("@{}", account.handle)));
+            handle.set_halign(gtk4::Align::Start);
+            handle.set_ellipsize(gtk4::pango::EllipsizeMode::End);
+            handle.add_css_class("caption");
+            handle.add_css_class("dim-label");
+            names.append(&handle);
+        }
+        content.append(&names);
+
+        let switch_btn = gtk4::Button::new();
+        switch_btn.set_child(Some(&content));
+        switch_btn.add_css_class("flat");
+        switch_btn.set_hexpand(true);
+
+        if is_active {
+            content.append(&gtk4::Image::from_icon_name("object-select-symbolic"));
+            switch_btn.update_property(&[gtk4::accessible::Property::Label(&format!(
+                "{}, current account",
+                account.label()
+            ))]);
+        } else {
+            // Same presentation rule as the nav badges: the button's
+            // accessible label carries the count.
+            let badge = gtk4::Label::builder()
+                .visible(false)
+                .valign(gtk4::Align::Center)
+                .accessible_role(gtk4::AccessibleRole::Presentation)
+                .build();
+            badge.add_css_class("unread-badge");
+            content.append(&badge);
+            self.imp().account_rows.borrow_mut().push(AccountRow {
+                did: account.did.clone(),
+                label: account.label().to_string(),
+                badge,
+                button: switch_btn.clone(),
+            });
+        }
+        let sidebar_weak = self.downgrade();
+        let did = account.did.clone();
+        switch_btn.connect_clicked(move |_| {
+            let Some(sidebar) = sidebar_weak.upgrade() else {
+                return;
+            };
+            if let Some(popover) = sidebar.imp().account_popover.borrow().as_ref() {
+                popover.popdown();
+            }
+            if let Some(cb) = sidebar.imp().switch_account_callback.borrow().as_ref() {
+                cb(did.clone());
+            }
+        });
+        row.append(&switch_btn);
+
+        let remove_btn = gtk4::Button::from_icon_name("window-close-symbolic");
+        remove_btn.add_css_class("flat");
+        remove_btn.add_css_class("circular");
+        remove_btn.set_valign(gtk4::Align::Center);
+        let remove_label = format!("Sign Out of {}", account.label());
+        remove_btn.set_tooltip_text(Some(&remove_label));
+        remove_btn.update_property(&[gtk4::accessible::Property::Label(&remove_label)]);
+        let sidebar_weak = self.downgrade();
+        let did = account.did.clone();
+        remove_btn.connect_clicked(move |_| {
+            let Some(sidebar) = sidebar_weak.upgrade() else {
+                return;
+            };
+            if let Some(popover) = sidebar.imp().account_popover.borrow().as_ref() {
+                popover.popdown();
+            }
+            if let Some(cb) = sidebar.imp().remove_account_callback.borrow().as_ref() {
+                cb(did.clone());
+            }
+        });
+        row.append(&remove_btn);
+
+        row
+    }
+
+    /// Show `count` unread on another account's menu row.
+    pub fn set_account_unread(&self, did: &str, count: u32) {
+        self.imp()
+            .account_unread
+            .borrow_mut()
+            .insert(did.to_string(), count);
+        self.update_account_unread();
+    }
+
+    /// The count behind an account row's badge.
+    pub fn account_unread(&self, did: &str) -> u32 {
+        self.imp()
+            .account_unread
+            .borrow()
+            .get(did)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Push the stored counts onto the rows and the avatar dot.
+    fn update_account_unread(&self) {
+        let imp = self.imp();
+        let unread = imp.account_unread.borrow();
+        let active = imp.active_account.borrow();
+
+        for row in imp.account_rows.borrow().iter() {
+            let count = unread.get(&row.did).copied().unwrap_or(0);
+            row.badge.set_label(&badge_text(count));
+            row.badge.set_visible(count > 0);
+            row.button
+                .update_property(&[gtk4::accessible::Property::Label(&account_a11y_label(
+                    &row.label, count,
+                ))]);
+        }
+
+        let others_unread = unread
+            .iter()
+            .any(|(did, count)| *count > 0 && active.as_deref() != Some(did.as_str()));
+        if let Some(dot) = imp.account_unread_dot.borrow().as_ref() {
+            dot.set_visible(others_unread);
+        }
+        if let Some(btn) = imp.avatar_menu_btn.borrow().as_ref() {
+            let label = if others_unread {
+                "Account menu, another account has unread notifications"
+            } else {
+                "Account menu"
+            };
+            btn.update_property(&[gtk4::accessible::Property::Label(label)]);
+        }
+    }
+
     /// Show `count` unread on `item`'s row. Zero hides the badge.
     pub fn set_badge(&self, item: NavItem, count: u32) {
         let Some(index) = NavItem::all().iter().position(|i| *i == item) else {
@@ -495,6 +765,15 @@ fn badge_a11y_label(item: NavItem, count: u32) -> String {
     }
 }
 
+/// Another account's row, read aloud. Exact even when the badge shows 99+.
+fn account_a11y_label(label: &str, count: u32) -> String {
+    if count == 0 {
+        format!("Switch to {label}")
+    } else {
+        format!("Switch to {label}, {count} unread")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -545,6 +824,71 @@ mod tests {
         assert_eq!(sidebar.badge_count(NavItem::Mentions), 0);
     }
 
+    /// Only accounts that are not on screen carry a badge, and the dot on
+    /// the avatar follows them alone.
+    #[test]
+    fn other_accounts_carry_their_own_unread() {
+        crate::ui::with_gtk(other_accounts_carry_their_own_unread_body);
+    }
+
+    fn other_accounts_carry_their_own_unread_body() {
+        let sidebar = Sidebar::new();
+        let account = |did: &str, handle: &str| Account {
+            did: did.to_string(),
+            handle: handle.to_string(),
+            ..Default::default()
+        };
+        sidebar.set_accounts(
+            &[
+                account("did:plc:alice", "alice.test"),
+                account("did:plc:bob", "bob.test"),
+            ],
+            Some("did:plc:alice"),
+        );
+
+        let dot_visible = || {
+            sidebar
+                .imp()
+                .account_unread_dot
+                .borrow()
+                .as_ref()
+                .is_some_and(|d| d.is_visible())
+        };
+        {
+            let rows = sidebar.imp().account_rows.borrow();
+            assert_eq!(rows.len(), 1, "the active account has no badge row");
+            assert_eq!(rows[0].did, "did:plc:bob");
+        }
+
+        // The active account's unread is the nav badges' business.
+        sidebar.set_account_unread("did:plc:alice", 5);
+        assert!(!dot_visible(), "the on-screen account lit the dot");
+
+        sidebar.set_account_unread("did:plc:bob", 4);
+        assert!(dot_visible());
+        {
+            let rows = sidebar.imp().account_rows.borrow();
+            assert!(rows[0].badge.is_visible());
+            assert_eq!(rows[0].badge.label(), "4");
+        }
+
+        // A rebuild after a switch keeps the counts and moves the badge.
+        sidebar.set_accounts(
+            &[
+                account("did:plc:alice", "alice.test"),
+                account("did:plc:bob", "bob.test"),
+            ],
+            Some("did:plc:bob"),
+        );
+        sidebar.set_account_unread("did:plc:bob", 0);
+        {
+            let rows = sidebar.imp().account_rows.borrow();
+            assert_eq!(rows[0].did, "did:plc:alice");
+            assert_eq!(rows[0].badge.label(), "5");
+        }
+        assert!(dot_visible(), "Alice's unread is now the other account's");
+    }
+
     /// GTK has no getter for accessible labels, so the string handed to the
     /// rows is checked directly.
     #[test]
@@ -553,5 +897,7 @@ mod tests {
         assert_eq!(badge_a11y_label(NavItem::Mentions, 3), "Mentions, 3 unread");
         // Capped on screen, exact for the screen reader.
         assert_eq!(badge_a11y_label(NavItem::Chat, 150), "Chat, 150 unread");
+        assert_eq!(account_a11y_label("Bob", 0), "Switch to Bob");
+        assert_eq!(account_a11y_label("Bob", 150), "Switch to Bob, 150 unread");
     }
 }
@@ -0,0 +1,742 @@
+// SPDX-License-Identifier: MPL-2.0
+#![allow(clippy::collapsible_if)]
+
+//! The Create Account dialog: where the account lives, the account itself,
+//! then its email.
+//!
+//! Each step is a page on one navigation view. The server's
+//! `describeServer` answer decides what the account page asks for: the
+//! handle domains it offers, and whether it wants an invite code or a code
+//! texted to a phone. The email page comes after the account exists, so it
+//! can be skipped and done later.
+
+use crate::atproto::{NewAccount, ServerDescription};
+use crate::config::DEFAULT_PDS;
+use gtk4::glib;
+use gtk4::prelude::*;
+use gtk4::subclass::prelude::*;
+use libadwaita as adw;
+use libadwaita::prelude::*;
+use libadwaita::subclass::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The handle a name and domain make, or why they make none.
+///
+/// `domain` is as `describeServer` lists it, with its leading dot. A name
+/// typed with the domain already on, or an `@` in front, still reads.
+/// The length limits are the reference PDS's for handles on its own
+/// domains.
+pub(crate) fn full_handle(name: &str, domain: &str) -> Result<String, &'static str> {
+    let name = name.trim().trim_start_matches('@').to_ascii_lowercase();
+    let name = name.strip_suffix(domain).unwrap_or(&name);
+    if name.is_empty() {
+        return Err("Choose a handle");
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err("Handles use only letters, numbers and hyphens");
+    }
+    if name.starts_with('-') || name.ends_with('-') {
+        return Err("Handles can't start or end with a hyphen");
+    }
+    match name.len() {
+        0..3 => Err("Handles need at least 3 characters"),
+        19.. => Err("Handles can be at most 18 characters"),
+        _ => Ok(format!("{name}{domain}")),
+    }
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Default)]
+    pub struct SignupDialog {
+        pub nav_view: RefCell<Option<adw::NavigationView>>,
+        pub server_row: RefCell<Option<adw::EntryRow>>,
+        pub server_button: RefCell<Option<gtk4::Button>>,
+        pub account_page: RefCell<Option<adw::NavigationPage>>,
+        pub handle_row: RefCell<Option<adw::EntryRow>>,
+        pub domain_row: RefCell<Option<adw::ComboRow>>,
+        pub email_row: RefCell<Option<adw::EntryRow>>,
+        pub password_row: RefCell<Option<adw::PasswordEntryRow>>,
+        pub invite_row: RefCell<Option<adw::EntryRow>>,
+        pub phone_group: RefCell<Option<adw::PreferencesGroup>>,
+        pub phone_row: RefCell<Option<adw::EntryRow>>,
+        pub phone_code_row: RefCell<Option<adw::EntryRow>>,
+        pub send_phone_code_button: RefCell<Option<gtk4::Button>>,
+        pub legal_label: RefCell<Option<gtk4::Label>>,
+        pub create_button: RefCell<Option<gtk4::Button>>,
+        /// Sets Create Account's sensitivity from the rows on show
+        pub create_gate: RefCell<Option<Rc<dyn Fn()>>>,
+        pub email_page: RefCell<Option<adw::NavigationPage>>,
+        pub email_sent_label: RefCell<Option<gtk4::Label>>,
+        pub email_code_row: RefCell<Option<adw::EntryRow>>,
+        pub verify_button: RefCell<Option<gtk4::Button>>,
+        pub resend_button: RefCell<Option<gtk4::Button>>,
+        pub skip_button: RefCell<Option<gtk4::Button>>,
+        /// One per page, by page tag, so an error shows on the page that
+        /// caused it
+        pub error_labels: RefCell<Vec<(String, gtk4::Label)>>,
+        pub spinners: RefCell<Vec<gtk4::Spinner>>,
+        /// The server and handle domains `describeServer` gave
+        pub server: RefCell<Option<(String, ServerDescription)>>,
+        /// The email the account signed up with, for confirming it
+        pub email: RefCell<String>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SignupDialog {
+        const NAME: &'static str = "HangarSignupDialog";
+        type Type = super::SignupDialog;
+        type ParentType = adw::Dialog;
+    }
+
+    impl ObjectImpl for SignupDialog {
+        fn constructed(&self) {
+            self.parent_constructed();
+            let obj = self.obj();
+            obj.setup_ui();
+        }
+    }
+
+    impl WidgetImpl for SignupDialog {}
+    impl AdwDialogImpl for SignupDialog {}
+}
+
+glib::wrapper! {
+    pub struct SignupDialog(ObjectSubclass<imp::SignupDialog>)
+        @extends adw::Dialog, gtk4::Widget,
+        @implements gtk4::Accessible, gtk4::Buildable, gtk4::ConstraintTarget;
+}
+
+impl SignupDialog {
+    /// `server` prefills the hosting provider, as typed in the sign-in
+    /// dialog's custom server field.
+    pub fn new(server: Option<&str>) -> Self {
+        let dialog: Self = glib::Object::builder().build();
+        if let Some(row) = dialog.imp().server_row.borrow().as_ref() {
+            let fallback = DEFAULT_PDS.trim_start_matches("https://");
+            row.set_text(server.unwrap_or(fallback));
+        }
+        dialog
+    }
+
+    /// A page with a header, its own spinner, and an error label under
+    /// `content`.
+    fn page(&self, title: &str, tag: &str, content: &gtk4::Box) -> adw::NavigationPage {
+        let header = adw::HeaderBar::new();
+        let spinner = gtk4::Spinner::new();
+        spinner.set_visible(false);
+        spinner.update_property(&[gtk4::accessible::Property::Label("Working")]);
+        header.pack_end(&spinner);
+
+        let error_label = gtk4::Label::new(None);
+        error_label.set_halign(gtk4::Align::Center);
+        error_label.add_css_class("error");
+        error_label.set_visible(false);
+        error_label.set_wrap(true);
+        // Server messages are often one long token.
+        error_label.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
+        error_label.set_max_width_chars(48);
+        content.append(&error_label);
+
+        content.set_margin_start(24);
+        content.set_margin_end(24);
+        content.set_margin_top(24);
+        content.set_margin_bottom(24);
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_hscrollbar_policy(gtk4::PolicyType::Never);
+        scrolled.set_propagate_natural_height(true);
+        scrolled.set_child(Some(content));
+
+        let toolbar = adw::ToolbarView::new();
+        toolbar.add_top_bar(&header);
+        toolbar.set_content(Some(&scrolled));
+
+        self.imp()
+            .error_labels
+            .borrow_mut()
+            .push((tag.to_string(), error_label));
+        self.imp().spinners.borrow_mut().push(spinner);
+        let page = adw::NavigationPage::new(&toolbar, title);
+        page.set_tag(Some(tag));
+        page
+    }
+
+    /// A dimmed, centred explanation at the top of a page.
+    fn intro(text: &str) -> gtk4::Label {
+        let label = gtk4::Label::new(Some(text));
+        label.set_wrap(true);
+        label.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
+        label.set_justify(gtk4::Justification::Center);
+        label.add_css_class("dim-label");
+        label
+    }
+
+    fn setup_ui(&self) {
+        self.set_title("Create an Account");
+        self.set_content_width(420);
+
+        let nav_view = adw::NavigationView::new();
+
+        // Step one: where the account lives.
+        let server_box = gtk4::Box::new(gtk4::Orientation::Vertical, 16);
+        server_box.append(&Self::intro(
+            "Choose where your account lives. Most people use Bluesky's own server; \
+             your hosting provider can give you another.",
+        ));
+        let server_group = adw::PreferencesGroup::new();
+        let server_row = adw::EntryRow::new();
+        server_row.set_title("Hosting Provider");
+        server_row.set_input_purpose(gtk4::InputPurpose::Url);
+        server_row.set_show_apply_button(false);
+        server_group.add(&server_row);
+        server_box.append(&server_group);
+        let server_button = gtk4::Button::with_label("Next");
+        server_button.add_css_class("suggested-action");
+        server_button.add_css_class("pill");
+        server_button.set_halign(gtk4::Align::Center);
+        server_box.append(&server_button);
+        let server_page = self.page("Create an Account", "server", &server_box);
+        nav_view.add(&server_page);
+
+        let button_weak = server_button.downgrade();
+        server_row.connect_changed(move |row| {
+            if let Some(button) = button_weak.upgrade() {
+                button.set_sensitive(!row.text().trim().is_empty());
+            }
+        });
+        let button_weak = server_button.downgrade();
+        server_row.connect_entry_activated(move |_| {
+            if let Some(button) = button_weak.upgrade() {
+                if button.is_sensitive() {
+                    button.emit_clicked();
+                }
+            }
+        });
+
+        // Step two: the account.
+        let account_box = gtk4::Box::new(gtk4::Orientation::Vertical, 16);
+        let account_group = adw::PreferencesGroup::new();
+        let handle_row = adw::EntryRow::new();
+        handle_row.set_title("Handle");
+        handle_row.set_show_apply_button(false);
+        account_group.add(&handle_row);
+        let domain_row = adw::ComboRow::new();
+        domain_row.set_title("Domain");
+        account_group.add(&domain_row);
+        let email_row = adw::EntryRow::new();
+        email_row.set_title("Email");
+        email_row.set_input_purpose(gtk4::InputPurpose::Email);
+        email_row.set_show_apply_button(false);
+        account_group.add(&email_row);
+        let password_row = adw::PasswordEntryRow::new();
+        password_row.set_title("Password");
+        account_group.add(&password_row);
+        let invite_row = adw::EntryRow::new();
+        invite_row.set_title("Invite Code");
+        invite_row.set_show_apply_button(false);
+        invite_row.set_visible(false);
+        account_group.add(&invite_row);
+        account_box.append(&account_group);
+
+        // Only for servers that verify a phone before making the account.
+        let phone_group = adw::PreferencesGroup::new();
+        phone_group.set_title("Phone Verification");
+        phone_group.set_description(Some("This server texts a code before it makes an account."));
+        phone_group.set_visible(false);
+        let phone_row = adw::EntryRow::new();
+        phone_row.set_title("Phone Number");
+        phone_row.set_input_purpose(gtk4::InputPurpose::Phone);
+        phone_row.set_show_apply_button(false);
+        let send_phone_code_button = gtk4::Button::with_label("Send Code");
+        send_phone_code_button.set_valign(gtk4::Align::Center);
+        send_phone_code_button.add_css_class("flat");
+        phone_row.add_suffix(&send_phone_code_button);
+        phone_group.add(&phone_row);
+        let phone_code_row = adw::EntryRow::new();
+        phone_code_row.set_title("Code from Text");
+        phone_code_row.set_show_apply_button(false);
+        phone_group.add(&phone_code_row);
+        account_box.append(&phone_group);
+
+        // The server's terms, when it names them. Filled in per server.
+        let legal_label = gtk4::Label::new(None);
+        legal_label.set_wrap(true);
+        legal_label.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
+        legal_label.set_justify(gtk4::Justification::Center);
+        legal_label.add_css_class("dim-label");
+        legal_label.add_css_class("caption");
+        legal_label.set_visible(false);
+        legal_label.connect_activate_link(|label, uri| {
+            crate::ui::external::open_url(label, uri, "the policy");
+            glib::Propagation::Stop
+        });
+        account_box.append(&legal_label);
+
+        let create_button = gtk4::Button::with_label("Create Account");
+        create_button.add_css_class("suggested-action");
+        create_button.add_css_class("pill");
+        create_button.set_halign(gtk4::Align::Center);
+        create_button.set_sensitive(false);
+        account_box.append(&create_button);
+        let account_page = self.page("Your Account", "account", &account_box);
+
+        // Create Account waits on every field the server asked for.
+        let gate: Rc<dyn Fn()> = Rc::new({
+            let handle_row = handle_row.clone();
+            let email_row = email_row.clone();
+            let password_row = password_row.clone();
+            let invite_row = invite_row.clone();
+            let phone_group = phone_group.clone();
+            let phone_row = phone_row.clone();
+            let phone_code_row = phone_code_row.clone();
+            let create_button = create_button.downgrade();
+            move || {
+                let filled = |row: &adw::EntryRow| !row.text().trim().is_empty();
+                let ready = filled(&handle_row)
+                    && filled(&email_row)
+                    && !password_row.text().is_empty()
+                    && (!invite_row.is_visible() || filled(&invite_row))
+                    && (!phone_group.is_visible()
+                        || (filled(&phone_row) && filled(&phone_code_row)));
+                if let Some(button) = create_button.upgrade() {
+                    button.set_sensitive(ready);
+                }
+            }
+        });
+        for row in [
+            &handle_row,
+            &email_row,
+            &invite_row,
+            &phone_row,
+            &phone_code_row,
+        ] {
+            let gate = gate.clone();
+            row.connect_changed(move |_| gate());
+        }
+        let g = gate.clone();
+        password_row.connect_changed(move |_| g());
+        let button_weak = send_phone_code_button.downgrade();
+        phone_row.connect_changed(move |row| {
+            if let Some(button) = button_weak.upgrade() {
+                button.set_sensitive(!row.text().trim().is_empty());
+            }
+        });
+        send_phone_code_button.set_sensitive(false);
+
+        // Step three: confirm the email, now that the account exists.
+        let email_box = gtk4::Box::new(gtk4::Orientation::Vertical, 16);
+        let email_sent_label = Self::intro("");
+        email_box.append(&email_sent_label);
+        let email_group = adw::PreferencesGroup::new();
+        let email_code_row = adw::EntryRow::new();
+        email_code_row.set_title("Code from Email");
+        email_code_row.set_show_apply_button(false);
+        email_group.add(&email_code_row);
+        email_box.append(&email_group);
+        let verify_button = gtk4::Button::with_label("Verify");
+        verify_button.add_css_class("suggested-action");
+        verify_button.add_css_class("pill");
+        verify_button.set_halign(gtk4::Align::Center);
+        verify_button.set_sensitive(false);
+        email_box.append(&verify_button);
+        let later_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 12);
+        later_box.set_halign(gtk4::Align::Center);
+        let resend_button = gtk4::Button::with_label("Send Again");
+        resend_button.add_css_class("flat");
+        later_box.append(&resend_button);
+        let skip_button = gtk4::Button::with_label("Skip for Now");
+        skip_button.add_css_class("flat");
+        later_box.append(&skip_button);
+        email_box.append(&later_box);
+        let email_page = self.page("Verify Your Email", "email", &email_box);
+        // The account exists by now; there is nothing to go back to.
+        email_page.set_can_pop(false);
+
+        let button_weak = verify_button.downgrade();
+        email_code_row.connect_changed(move |row| {
+            if let Some(button) = button_weak.upgrade() {
+                button.set_sensitive(!row.text().trim().is_empty());
+            }
+        });
+        let button_weak = verify_button.downgrade();
+        email_code_row.connect_entry_activated(move |_| {
+            if let Some(button) = button_weak.upgrade() {
+                if button.is_sensitive() {
+                    button.emit_clicked();
+                }
+            }
+        });
+        skip_button.connect_clicked(glib::clone!(
+            #[weak(rename_to = dialog)]
+            self,
+            move |_| {
+                dialog.close();
+            }
+        ));
+
+        let imp = self.imp();
+        imp.nav_view.replace(Some(nav_view.clone()));
+        imp.server_row.replace(Some(server_row));
+        imp.server_button.replace(Some(server_button));
+        imp.account_page.replace(Some(account_page));
+        imp.handle_row.replace(Some(handle_row));
+        imp.domain_row.replace(Some(domain_row));
+        imp.email_row.replace(Some(email_row));
+        imp.password_row.replace(Some(password_row));
+        imp.invite_row.replace(Some(invite_row));
+        imp.phone_group.replace(Some(phone_group));
+        imp.phone_row.replace(Some(phone_row));
+        imp.phone_code_row.replace(Some(phone_code_row));
+        imp.send_phone_code_button
+            .replace(Some(send_phone_code_button));
+        imp.legal_label.replace(Some(legal_label));
+        imp.create_button.replace(Some(create_button));
+        imp.create_gate.replace(Some(gate));
+        imp.email_page.replace(Some(email_page));
+        imp.email_sent_label.replace(Some(email_sent_label));
+        imp.email_code_row.replace(Some(email_code_row));
+        imp.verify_button.replace(Some(verify_button));
+        imp.resend_button.replace(Some(resend_button));
+        imp.skip_button.replace(Some(skip_button));
+
+        // The dialog covers the window's toast overlay.
+        let toasts = adw::ToastOverlay::new();
+        toasts.set_child(Some(&nav_view));
+        self.set_child(Some(&toasts));
+    }
+
+    /// The hosting provider as typed.
+    pub fn server(&self) -> String {
+        self.imp()
+            .server_row
+            .borrow()
+            .as_ref()
+            .map(|e| e.text().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Fit the account page to what `server` asks for and move to it.
+    pub fn show_account_form(&self, server: &str, description: ServerDescription) {
+        let imp = self.imp();
+        if let Some(row) = imp.domain_row.borrow().as_ref() {
+            let domains: Vec<&str> = description
+                .user_domains
+                .iter()
+                .map(String::as_str)
+                .collect();
+            row.set_model(Some(&gtk4::StringList::new(&domains)));
+            row.set_selected(0);
+        }
+        if let Some(row) = imp.invite_row.borrow().as_ref() {
+            row.set_visible(description.invite_code_required);
+        }
+        if let Some(group) = imp.phone_group.borrow().as_ref() {
+            group.set_visible(description.phone_verification_required);
+        }
+        if let Some(label) = imp.legal_label.borrow().as_ref() {
+            let link = |uri: &str, text: &str| {
+                format!("<a href=\"{}\">{text}</a>", glib::markup_escape_text(uri))
+            };
+            let policies: Vec<String> = [
+                description
+                    .terms_of_service
+                    .as_deref()
+                    .map(|uri| link(uri, "Terms of Service")),
+                description
+                    .privacy_policy
+                    .as_deref()
+                    .map(|uri| link(uri, "Privacy Policy")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            label.set_visible(!policies.is_empty());
+            label.set_markup(&format!(
+                "By creating an account you agree to this server's {}.",
+                policies.join(" and ")
+            ));
+        }
+        // The rows just shown or hidden change what Create Account needs.
+        if let Some(gate) = imp.create_gate.borrow().as_ref() {
+            gate();
+        }
+        imp.server.replace(Some((server.to_string(), description)));
+
+        if let (Some(nav_view), Some(page)) = (
+            imp.nav_view.borrow().as_ref(),
+            imp.account_page.borrow().as_ref(),
+        ) {
+            if nav_view.find_page("account").is_none() {
+                nav_view.push(page);
+            }
+        }
+        if let Some(row) = imp.handle_row.borrow().as_ref() {
+            row.grab_focus();
+        }
+    }
+
+    /// The server the account page was fitted to.
+    pub fn chosen_server(&self) -> Option<String> {
+        self.imp()
+            .server
+            .borrow()
+            .as_ref()
+            .map(|(server, _)| server.clone())
+    }
+
+    /// The phone number typed, for sending the code.
+    pub fn phone(&self) -> String {
+        self.imp()
+            .phone_row
+            .borrow()
+            .as_ref()
+            .map(|e| e.text().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// The account page as `createAccount` takes it, or why it can't be
+    /// sent yet.
+    pub fn new_account(&self) -> Result<NewAccount, &'static str> {
+        let imp = self.imp();
+        let text = |row: &RefCell<Option<adw::EntryRow>>| {
+            row.borrow()
+                .as_ref()
+                .map(|e| e.text().trim().to_string())
+                .unwrap_or_default()
+        };
+        let shown = |row: &RefCell<Option<adw::EntryRow>>| {
+            row.borrow().as_ref().is_some_and(|e| e.is_visible())
+        };
+
+        let domain = imp
+            .domain_row
+            .borrow()
+            .as_ref()
+            .and_then(|row| row.selected_item())
+            .and_then(|item| item.downcast::<gtk4::StringObject>().ok())
+            .map(|item| item.string().to_string())
+            .ok_or("This server offers no handles to sign up with")?;
+        let handle = full_handle(&text(&imp.handle_row), &domain)?;
+        let email = text(&imp.email_row);
+        if !email.contains('@') {
+            return Err("Enter an email address");
+        }
+        let password = imp
+            .password_row
+            .borrow()
+            .as_ref()
+            .map(|e| e.text().to_string())
+            .unwrap_or_default();
+        let phone_shown = imp
+            .phone_group
+            .borrow()
+            .as_ref()
+            .is_some_and(|g| g.is_visible());
+
+        Ok(NewAccount {
+            handle,
+            email,
+            password,
+            invite_code: shown(&imp.invite_row).then(|| text(&imp.invite_row)),
+            verification_phone: phone_shown.then(|| text(&imp.phone_row)),
+            verification_code: phone_shown.then(|| text(&imp.phone_code_row)),
+        })
+    }
+
+    /// Move to the email step for the account just made.
+    pub fn show_email_step(&self, email: &str) {
+        let imp = self.imp();
+        imp.email.replace(email.to_string());
+        if let Some(label) = imp.email_sent_label.borrow().as_ref() {
+            label.set_text(&format!(
+                "Your account is ready. We sent a code to {email} to confirm it's yours."
+            ));
+        }
+        if let (Some(nav_view), Some(page)) = (
+            imp.nav_view.borrow().as_ref(),
+            imp.email_page.borrow().as_ref(),
+        ) {
+            nav_view.push(page);
+        }
+        if let Some(row) = imp.email_code_row.borrow().as_ref() {
+            row.grab_focus();
+        }
+    }
+
+    /// The email the account signed up with.
+    pub fn email(&self) -> String {
+        self.imp().email.borrow().clone()
+    }
+
+    /// The emailed code as typed.
+    pub fn email_code(&self) -> String {
+        self.imp()
+            .email_code_row
+            .borrow()
+            .as_ref()
+            .map(|e| e.text().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Show `message` on the page on screen.
+    pub fn show_error(&self, message: &str) {
+        let imp = self.imp();
+        let tag = imp
+            .nav_view
+            .borrow()
+            .as_ref()
+            .and_then(|nav_view| nav_view.visible_page())
+            .and_then(|page| page.tag());
+        for (page, label) in imp.error_labels.borrow().iter() {
+            label.set_text(message);
+            label.set_visible(tag.as_deref() == Some(page.as_str()));
+        }
+    }
+
+    pub fn hide_error(&self) {
+        for (_, label) in self.imp().error_labels.borrow().iter() {
+            label.set_visible(false);
+        }
+    }
+
+    /// Show a passing note over the dialog.
+    pub fn show_toast(&self, message: &str) {
+        if let Some(child) = self.child() {
+            crate::ui::external::toast(&child, message);
+        }
+    }
+
+    /// Hold every page still while a request runs.
+    pub fn set_loading(&self, loading: bool) {
+        let imp = self.imp();
+        for spinner in imp.spinners.borrow().iter() {
+            spinner.set_visible(loading);
+            if loading {
+                spinner.start();
+            } else {
+                spinner.stop();
+            }
+        }
+        if let Some(nav_view) = imp.nav_view.borrow().as_ref() {
+            if let Some(page) = nav_view.visible_page() {
+                page.set_sensitive(!loading);
+            }
+        }
+    }
+
+    /// Connect a button's click to `f`, holding the dialog weakly: the
+    /// button is its own child, so a strong capture would be a cycle
+    /// GObject cannot collect.
+    fn connect_button<F: Fn(&Self) + 'static>(&self, button: &RefCell<Option<gtk4::Button>>, f: F) {
+        if let Some(button) = button.borrow().as_ref() {
+            let dialog = self.downgrade();
+            button.connect_clicked(move |_| {
+                if let Some(dialog) = dialog.upgrade() {
+                    f(&dialog);
+                }
+            });
+        }
+    }
+
+    /// Connect callback for Next on the hosting provider page.
+    pub fn connect_server_chosen<F: Fn(&Self) + 'static>(&self, f: F) {
+        self.connect_button(&self.imp().server_button, f);
+    }
+
+    /// Connect callback for Send Code on the phone row.
+    pub fn connect_send_phone_code<F: Fn(&Self) + 'static>(&self, f: F) {
+        self.connect_button(&self.imp().send_phone_code_button, f);
+    }
+
+    /// Connect callback for Create Account.
+    pub fn connect_create<F: Fn(&Self) + 'static>(&self, f: F) {
+        self.connect_button(&self.imp().create_button, f);
+    }
+
+    /// Connect callback for Verify on the email page.
+    pub fn connect_verify_email<F: Fn(&Self) + 'static>(&self, f: F) {
+        self.connect_button(&self.imp().verify_button, f);
+    }
+
+    /// Connect callback for Send Again on the email page.
+    pub fn connect_resend_email<F: Fn(&Self) + 'static>(&self, f: F) {
+        self.connect_button(&self.imp().resend_button, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A handle reads however it was typed, and a bad one says what to
+    /// fix before the server is asked.
+    #[test]
+    fn handles_are_checked_before_the_server_sees_them() {
+        assert_eq!(
+            full_handle(" @Alice ", ".bsky.social").as_deref(),
+            Ok("alice.bsky.social")
+        );
+        assert_eq!(
+            full_handle("alice.bsky.social", ".bsky.social").as_deref(),
+            Ok("alice.bsky.social")
+        );
+        assert!(full_handle("", ".bsky.social").is_err());
+        assert!(full_handle("al", ".bsky.social").is_err());
+        assert!(full_handle("a".repeat(19).as_str(), ".bsky.social").is_err());
+        assert!(full_handle("-alice", ".bsky.social").is_err());
+        assert!(full_handle("al_ice", ".bsky.social").is_err());
+        assert!(full_handle("al.ice", ".bsky.social").is_err());
+    }
+
+    /// The account page asks for what the server wants and no more, and
+    /// the form comes back out with the chosen domain on the handle.
+    #[test]
+    fn the_account_page_follows_the_server() {
+        crate::ui::with_gtk(the_account_page_follows_the_server_body);
+    }
+
+    fn the_account_page_follows_the_server_body() {
+        let dialog = SignupDialog::new(None);
+        assert_eq!(dialog.server(), "bsky.social");
+
+        dialog.show_account_form(
+            "bsky.social",
+            ServerDescription {
+                user_domains: vec![".bsky.social".into()],
+                invite_code_required: true,
+                ..Default::default()
+            },
+        );
+        let imp = dialog.imp();
+        assert!(imp.invite_row.borrow().as_ref().unwrap().is_visible());
+        assert!(!imp.phone_group.borrow().as_ref().unwrap().is_visible());
+        assert_eq!(dialog.chosen_server().as_deref(), Some("bsky.social"));
+
+        imp.handle_row.borrow().as_ref().unwrap().set_text("alice");
+        imp.email_row
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .set_text("alice@example.com");
+        imp.password_row
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .set_text("hunter22");
+        let create = imp.create_button.borrow().as_ref().unwrap().clone();
+        assert!(!create.is_sensitive(), "the invite code is still missing");
+        imp.invite_row
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .set_text("code-123");
+        assert!(create.is_sensitive());
+
+        let account = dialog.new_account().expect("the form is complete");
+        assert_eq!(account.handle, "alice.bsky.social");
+        assert_eq!(account.invite_code.as_deref(), Some("code-123"));
+        assert_eq!(account.verification_phone, None);
+    }
+}
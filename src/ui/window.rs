@@ -5,11 +5,25 @@
 #![allow(clippy::collapsible_else_if)]
 
 use super::actor_row::{ActorObject, ActorRow};
+use super::feed_browser::FeedBrowser;
 use super::follow_list_page::{FollowListKind, FollowListPage};
+use super::list_page::ListsPage;
 use super::message_page::{MessagePage, MessagePush};
 use super::post_row::PostRow;
 use super::sidebar::Sidebar;
-use crate::atproto::{Conversation, Notification, Post, SavedFeed};
+use crate::atproto::interactions::InteractionEvent;
+use crate::atproto::moderation::ModerationEdit;
+use crate::atproto::notifications::{
+    self, ActivityFilter, CategoryPref, ChatNotify, IncludeFrom, NotificationCategory,
+    NotificationGroup, NotificationPrefEdit, NotificationPrefs,
+};
+use crate::atproto::search_query::{self, SearchQuery, SearchSort};
+use crate::atproto::{
+    Conversation, FeedGenerator, LabelVisibility, ListMember, ListPurpose, ModerationPrefs,
+    MutedWord, Notification, Post, SavedFeed, StarterPack, UserList,
+};
+use crate::atproto::{moderation, muted_words};
+use crate::cache::{GAP_FILL_SIZE, HistoryQuery, ReadingPosition};
 use gtk4::prelude::*;
 use gtk4::subclass::prelude::*;
 use gtk4::{gio, glib};
@@ -66,6 +80,10 @@ const THREAD_POSTED_MARKER: &str = "thread-posted";
 const THREAD_REPLIES_MARKER: &str = "thread-replies";
 const THREAD_SPACER_MARKER: &str = "thread-spacer";
 
+/// Marker row for a gap a refresh left in the timeline, followed by the URI
+/// of the post just above it.
+const TIMELINE_GAP_MARKER: &str = "gap:";
+
 /// How deep one section's navigation stack may get.
 ///
 /// Each of the eight sections keeps its own stack, and every page under the
@@ -75,6 +93,17 @@ const THREAD_SPACER_MARKER: &str = "thread-spacer";
 /// is never one of them: `pop_to_root` and every section switch pop to it.
 const NAV_STACK_LIMIT: usize = 10;
 
+/// The History page's empty state, before a search and after one that found
+/// nothing: title and description.
+const HISTORY_INTRO: (&str, &str) = (
+    "Search your history",
+    "Only posts Hangar has already loaded are searched, so this works offline.",
+);
+const HISTORY_NO_MATCH: (&str, &str) = (
+    "No posts found",
+    "Nothing you have seen matched. Try different words or fewer filters.",
+);
+
 /// What a Clear Cache click actually achieved.
 ///
 /// The button used to say "Cleared" before doing any work and never looked
@@ -88,6 +117,30 @@ pub enum CacheClearOutcome {
     Failed,
 }
 
+/// The History page's filter bar, read back into a [`HistoryQuery`] on
+/// every search.
+pub struct HistoryFilters {
+    author: gtk4::Entry,
+    since: gtk4::Entry,
+    until: gtk4::Entry,
+    liked: gtk4::ToggleButton,
+    bookmarked: gtk4::ToggleButton,
+}
+
+/// The search filter popover's fields. They are filled from the entry each
+/// time the popover opens and written back into it as operators.
+pub struct SearchFilterFields {
+    author: gtk4::Entry,
+    mentions: gtk4::Entry,
+    since: gtk4::Entry,
+    until: gtk4::Entry,
+    lang: gtk4::Entry,
+    domain: gtk4::Entry,
+    url: gtk4::Entry,
+    tags: gtk4::Entry,
+    top: gtk4::CheckButton,
+}
+
 /// What `push_follow_list_page` did with the request.
 pub enum FollowListPush {
     /// A new page went onto the stack and needs wiring and a first fetch.
@@ -108,9 +161,18 @@ pub struct ProfileFeedCtx {
     pub fetching: Cell<bool>,
     pub generation: Cell<u64>,
     model: gio::ListStore,
+    /// "No posts yet", or "No starter packs yet" on that tab
+    empty: gtk4::Label,
+    /// The Starter Packs tab's rows, shown in place of posts on that tab
+    packs: gtk4::ListBox,
+    starter_packs: RefCell<Vec<StarterPack>>,
 }
 
 impl ProfileFeedCtx {
+    /// The Starter Packs tab's filter. It is no `getAuthorFeed` filter;
+    /// the app fetches the account's packs for it instead.
+    pub const STARTER_PACKS: &'static str = "starter_packs";
+
     /// Back to a clean first page: tab switches and reopens both start
     /// here, stranding whatever the previous state still had in flight.
     pub fn begin_refresh(&self) {
@@ -118,6 +180,27 @@ impl ProfileFeedCtx {
         self.cursor.replace(None);
         self.fetching.set(false);
         self.model.remove_all();
+        self.packs.remove_all();
+        self.starter_packs.borrow_mut().clear();
+        let packs_tab = self.filter.get() == Self::STARTER_PACKS;
+        self.packs.set_visible(packs_tab);
+        self.empty.set_text(if packs_tab {
+            "No starter packs yet"
+        } else {
+            "No posts yet"
+        });
+        self.empty.set_visible(true);
+    }
+
+    /// A page of the Starter Packs tab landing.
+    pub fn append_starter_packs(&self, packs: Vec<StarterPack>) {
+        for pack in &packs {
+            self.packs
+                .append(&HangarWindow::build_starter_pack_row(pack));
+        }
+        self.starter_packs.borrow_mut().extend(packs);
+        self.empty
+            .set_visible(self.starter_packs.borrow().is_empty());
     }
 
     /// Posts landing from a fetch; the model stays this module's business.
@@ -127,12 +210,269 @@ impl ProfileFeedCtx {
         }
     }
 
+    /// A live first page taking over from the cached one painted while it
+    /// was out. Unlike [`Self::begin_refresh`] this keeps the generation,
+    /// since the fetch delivering it is the current one.
+    pub fn replace_posts(&self, posts: Vec<Post>) {
+        let objects: Vec<PostObject> = posts.into_iter().map(PostObject::new).collect();
+        self.model.splice(0, self.model.n_items(), &objects);
+    }
+
+    #[cfg(test)]
+    fn listed(&self) -> u32 {
+        self.model.n_items()
+    }
+}
+
+/// One hashtag page's feed state, shared the way [`ProfileFeedCtx`] is
+/// between its Top and Latest tabs, its scroll handler, and the app.
+pub struct HashtagFeedCtx {
+    /// Without its `#`, spelled as it was clicked
+    pub tag: String,
+    pub sort: Cell<SearchSort>,
+    pub cursor: RefCell<Option<String>>,
+    pub fetching: Cell<bool>,
+    pub generation: Cell<u64>,
+    model: gio::ListStore,
+}
+
+impl HashtagFeedCtx {
+    /// The search the current tab shows.
+    pub fn query(&self) -> SearchQuery {
+        SearchQuery {
+            tags: vec![self.tag.clone()],
+            sort: self.sort.get(),
+            ..Default::default()
+        }
+    }
+
+    /// As [`ProfileFeedCtx::begin_refresh`].
+    pub fn begin_refresh(&self) {
+        self.generation.set(self.generation.get() + 1);
+        self.cursor.replace(None);
+        self.fetching.set(false);
+        self.model.remove_all();
+    }
+
+    pub fn append_posts(&self, posts: Vec<Post>) {
+        for post in posts {
+            self.model.append(&PostObject::new(post));
+        }
+    }
+
+    /// As [`ProfileFeedCtx::replace_posts`].
+    pub fn replace_posts(&self, posts: Vec<Post>) {
+        let objects: Vec<PostObject> = posts.into_iter().map(PostObject::new).collect();
+        self.model.splice(0, self.model.n_items(), &objects);
+    }
+
     #[cfg(test)]
     fn listed(&self) -> u32 {
         self.model.n_items()
     }
 }
 
+/// One starter pack page's state, shared the way [`ListFeedCtx`] is
+/// between its scroll handler, its Follow All button and the app. The
+/// page opens on what a profile listing knew of the pack; the full view
+/// fills in its feeds once it lands.
+pub struct StarterPackCtx {
+    pack: RefCell<StarterPack>,
+    pub cursor: RefCell<Option<String>>,
+    pub fetching: Cell<bool>,
+    pub generation: Cell<u64>,
+    people: gio::ListStore,
+    summary: gtk4::Label,
+    description: gtk4::Label,
+    feeds: gtk4::ListBox,
+    feeds_group: gtk4::Box,
+    follow_all: gtk4::Button,
+}
+
+impl StarterPackCtx {
+    pub fn pack(&self) -> StarterPack {
+        self.pack.borrow().clone()
+    }
+
+    /// Take the full view of the pack and show it.
+    pub fn set_pack(&self, pack: StarterPack) {
+        self.summary.set_text(&pack.summary());
+        let description = pack.description.as_deref().unwrap_or_default();
+        self.description.set_text(description);
+        self.description.set_visible(!description.is_empty());
+        self.feeds.remove_all();
+        for feed in &pack.feeds {
+            let row = adw::ActionRow::new();
+            row.set_use_markup(false);
+            row.set_title(&feed.display_name);
+            if let Some(description) = feed.description.as_deref() {
+                row.set_subtitle(description);
+                row.set_subtitle_lines(2);
+            }
+            row.set_activatable(true);
+            row.add_suffix(&gtk4::Image::from_icon_name("go-next-symbolic"));
+            self.feeds.append(&row);
+        }
+        self.feeds_group.set_visible(!pack.feeds.is_empty());
+        self.pack.replace(pack);
+    }
+
+    /// As [`ProfileFeedCtx::begin_refresh`], for the member list.
+    pub fn begin_refresh(&self) {
+        self.generation.set(self.generation.get() + 1);
+        self.cursor.replace(None);
+        self.fetching.set(false);
+        self.people.remove_all();
+    }
+
+    pub fn append_members(&self, members: Vec<ListMember>) {
+        for member in members {
+            self.people.append(&ActorObject::new(member.profile));
+        }
+    }
+
+    /// Lock Follow All while its follows are out, and free it after.
+    pub fn set_following_all(&self, busy: bool) {
+        self.follow_all.set_sensitive(!busy);
+        self.follow_all
+            .set_label(if busy { "Following..." } else { "Follow All" });
+    }
+
+    /// The follows Follow All made, onto the rows already listed.
+    pub fn mark_followed(&self, follows: &[(String, String)]) {
+        for i in 0..self.people.n_items() {
+            let Some(object) = self.people.item(i).and_downcast::<ActorObject>() else {
+                continue;
+            };
+            let Some(did) = object.profile().map(|p| p.did) else {
+                continue;
+            };
+            if let Some((_, uri)) = follows.iter().find(|(d, _)| *d == did) {
+                object.set_viewer_following(Some(uri.clone()));
+                // Rebinds the row, so a visible button turns over too.
+                self.people.items_changed(i, 1, 1);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    fn listed(&self) -> u32 {
+        self.people.n_items()
+    }
+}
+
+/// A feed generator's detail page. It opens on what the browser or a
+/// starter pack knew of the feed; the full view replaces that when it
+/// lands, and again after each like or unlike.
+pub struct FeedGeneratorCtx {
+    feed: RefCell<FeedGenerator>,
+    avatar: adw::Avatar,
+    creator: gtk4::Button,
+    likes: gtk4::Label,
+    description: gtk4::Label,
+    like: gtk4::Button,
+}
+
+impl FeedGeneratorCtx {
+    pub fn feed(&self) -> FeedGenerator {
+        self.feed.borrow().clone()
+    }
+
+    /// Take a newer view of the feed and show it.
+    pub fn set_feed(&self, feed: FeedGenerator) {
+        if let Some(url) = &feed.avatar {
+            crate::ui::avatar_cache::load_avatar(self.avatar.clone(), url.clone());
+        }
+        self.creator
+            .set_label(&format!("by @{}", feed.creator.handle));
+        match feed.like_count {
+            Some(1) => self.likes.set_text("Liked by 1 person"),
+            Some(n) => self.likes.set_text(&format!("Liked by {n} people")),
+            None => self.likes.set_text(""),
+        }
+        self.likes.set_visible(feed.like_count.is_some());
+        let description = feed.description.as_deref().unwrap_or_default();
+        self.description.set_text(description);
+        self.description.set_visible(!description.is_empty());
+        self.like.set_label(if feed.viewer_like.is_some() {
+            "Unlike"
+        } else {
+            "Like"
+        });
+        self.feed.replace(feed);
+    }
+
+    /// Lock the like button while a like or unlike is out, and free it after.
+    pub fn set_liking(&self, busy: bool) {
+        self.like.set_sensitive(!busy);
+    }
+}
+
+/// Which half of a list page is showing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListTab {
+    Posts,
+    People,
+}
+
+/// One list page's state, shared the way [`HashtagFeedCtx`] is between
+/// its Posts and People tabs, its scroll handlers, and the app. The
+/// header labels ride along so an edit can show on the open page.
+pub struct ListFeedCtx {
+    list: RefCell<UserList>,
+    pub tab: Cell<ListTab>,
+    pub cursor: RefCell<Option<String>>,
+    pub fetching: Cell<bool>,
+    pub generation: Cell<u64>,
+    posts: gio::ListStore,
+    people: gio::ListStore,
+    title: gtk4::Label,
+    summary: gtk4::Label,
+    description: gtk4::Label,
+}
+
+impl ListFeedCtx {
+    pub fn list(&self) -> UserList {
+        self.list.borrow().clone()
+    }
+
+    /// Take a fresher or edited copy of the list and show it.
+    pub fn set_list(&self, list: UserList) {
+        self.title.set_text(&list.name);
+        self.summary.set_text(&list.summary());
+        let description = list.description.as_deref().unwrap_or_default();
+        self.description.set_text(description);
+        self.description.set_visible(!description.is_empty());
+        self.list.replace(list);
+    }
+
+    /// As [`ProfileFeedCtx::begin_refresh`], for whichever tab is showing.
+    pub fn begin_refresh(&self) {
+        self.generation.set(self.generation.get() + 1);
+        self.cursor.replace(None);
+        self.fetching.set(false);
+        self.posts.remove_all();
+        self.people.remove_all();
+    }
+
+    pub fn append_posts(&self, posts: Vec<Post>) {
+        for post in posts {
+            self.posts.append(&PostObject::new(post));
+        }
+    }
+
+    pub fn append_members(&self, members: Vec<ListMember>) {
+        for member in members {
+            self.people.append(&ActorObject::new(member.profile));
+        }
+    }
+
+    #[cfg(test)]
+    fn listed(&self) -> u32 {
+        self.posts.n_items() + self.people.n_items()
+    }
+}
+
 mod notification_object {
     use super::*;
 
@@ -141,7 +481,7 @@ mod notification_object {
 
         #[derive(Default)]
         pub struct NotificationObject {
-            pub notification: RefCell<Option<Notification>>,
+            pub group: RefCell<Option<NotificationGroup>>,
         }
 
         #[glib::object_subclass]
@@ -160,13 +500,23 @@ mod notification_object {
 
     impl NotificationObject {
         pub fn new(notification: Notification) -> Self {
+            Self::from_group(NotificationGroup::single(notification))
+        }
+
+        /// An Activity row, standing for every notification folded into it.
+        pub fn from_group(group: NotificationGroup) -> Self {
             let obj: Self = glib::Object::builder().build();
-            obj.imp().notification.replace(Some(notification));
+            obj.imp().group.replace(Some(group));
             obj
         }
 
+        /// The row's newest notification.
         pub fn notification(&self) -> Option<Notification> {
-            self.imp().notification.borrow().clone()
+            self.group().map(|group| group.lead)
+        }
+
+        pub fn group(&self) -> Option<NotificationGroup> {
+            self.imp().group.borrow().clone()
         }
     }
 }
@@ -232,6 +582,9 @@ mod imp {
         pub timeline_list_view: RefCell<Option<gtk4::ListView>>,
         pub load_more_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
         pub refresh_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        /// A gap row was pressed: the post above the gap, and whether the
+        /// reader came up to it from the posts below.
+        pub gap_callback: RefCell<Option<Box<dyn Fn(String, bool) + 'static>>>,
         pub like_callback: RefCell<Option<Box<dyn Fn(Post, glib::WeakRef<PostRow>) + 'static>>>,
         pub repost_callback: RefCell<Option<Box<dyn Fn(Post, glib::WeakRef<PostRow>) + 'static>>>,
         pub quote_callback: RefCell<Option<Box<dyn Fn(Post) + 'static>>>,
@@ -281,11 +634,59 @@ mod imp {
         pub feed_action: RefCell<Option<gio::SimpleAction>>,
         pub feed_changed_callback: RefCell<Option<Box<dyn Fn(SavedFeed) + 'static>>>,
         pub saved_feeds: RefCell<Vec<SavedFeed>>,
+        /// Every feed the account saved, pinned or not, in its order; none
+        /// until the server has answered, so nothing is written over a
+        /// list that was never read.
+        pub saved_feed_list: RefCell<Option<Vec<SavedFeed>>>,
+        pub my_feeds_group: RefCell<Option<adw::PreferencesGroup>>,
+        pub my_feed_rows: RefCell<Vec<adw::ActionRow>>,
+        /// Args: the whole saved feed list as it should now be.
+        pub saved_feed_list_callback: RefCell<Option<Box<dyn Fn(Vec<SavedFeed>) + 'static>>>,
+        pub discover_feeds_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        pub feed_opened_callback: RefCell<Option<Box<dyn Fn(FeedGenerator) + 'static>>>,
+        pub feed_like_callback: RefCell<Option<Box<dyn Fn(Rc<FeedGeneratorCtx>) + 'static>>>,
+        /// Whether the home feed takes feedback: its rows offer Show
+        /// More/Less Like This and report what they show and open.
+        pub feed_interactions: Cell<bool>,
+        /// Args: a home feed post and what the reader did with it.
+        pub feed_interaction_callback:
+            RefCell<Option<Box<dyn Fn(Post, InteractionEvent) + 'static>>>,
+        /// The account's muted words, as last read from or written to the
+        /// server. Every list that takes posts from the network filters
+        /// through them.
+        pub muted_words: RefCell<Vec<MutedWord>>,
+        pub muted_words_group: RefCell<Option<adw::PreferencesGroup>>,
+        pub muted_word_rows: RefCell<Vec<adw::ActionRow>>,
+        pub muted_word_save_callback: RefCell<Option<Box<dyn Fn(MutedWord) + 'static>>>,
+        pub muted_word_remove_callback: RefCell<Option<Box<dyn Fn(MutedWord) + 'static>>>,
+        /// The account's label preferences. Feeds drop what they hide;
+        /// rows cover the rest themselves.
+        pub moderation_prefs: RefCell<ModerationPrefs>,
+        pub content_filter_group: RefCell<Option<adw::PreferencesGroup>>,
+        pub labelers_group: RefCell<Option<adw::PreferencesGroup>>,
+        /// Moderation lists the account mutes or blocks
+        pub moderation_lists: RefCell<Vec<UserList>>,
+        pub moderation_lists_group: RefCell<Option<adw::PreferencesGroup>>,
+        pub moderation_list_rows: RefCell<Vec<adw::ActionRow>>,
+        /// Rows `rebuild_moderation_pref_rows` added, with the group each
+        /// went into.
+        pub moderation_pref_rows: RefCell<Vec<(adw::PreferencesGroup, gtk4::Widget)>>,
+        pub moderation_edit_callback: RefCell<Option<Box<dyn Fn(ModerationEdit) + 'static>>>,
+        /// The account's notification preferences, once fetched
+        pub notification_prefs: RefCell<Option<NotificationPrefs>>,
+        pub notification_categories_group: RefCell<Option<adw::PreferencesGroup>>,
+        pub notification_chat_group: RefCell<Option<adw::PreferencesGroup>>,
+        /// Rows `rebuild_notification_pref_rows` added, with their groups
+        pub notification_pref_rows: RefCell<Vec<(adw::PreferencesGroup, gtk4::Widget)>>,
+        pub notification_pref_edit_callback:
+            RefCell<Option<Box<dyn Fn(NotificationPrefEdit) + 'static>>>,
         pub current_feed_uri: RefCell<String>,
         // Navigation callbacks
         pub post_clicked_callback: RefCell<Option<Box<dyn Fn(Post) + 'static>>>,
         pub profile_clicked_callback: RefCell<Option<Box<dyn Fn(Profile) + 'static>>>,
         pub mention_clicked_callback: RefCell<Option<Box<dyn Fn(String) + 'static>>>,
+        /// Args: the tag, without its `#`.
+        pub tag_clicked_callback: RefCell<Option<Box<dyn Fn(String) + 'static>>>,
         /// Args: subject DID, the page's follow-URI cell, the button.
         pub follow_callback: RefCell<
             Option<
@@ -300,6 +701,37 @@ mod imp {
             RefCell<Option<Box<dyn Fn(Profile, glib::WeakRef<gtk4::Button>) + 'static>>>,
         /// Args: the page's feed context and whether this is a first page.
         pub profile_tab_callback: RefCell<Option<Box<dyn Fn(Rc<ProfileFeedCtx>, bool) + 'static>>>,
+        /// Args: the hashtag page's feed context and whether this is a
+        /// first page.
+        pub hashtag_tab_callback: RefCell<Option<Box<dyn Fn(Rc<HashtagFeedCtx>, bool) + 'static>>>,
+        /// Pins the tag to the feed selector, or unpins it.
+        pub pin_tag_callback: RefCell<Option<Box<dyn Fn(String) + 'static>>>,
+        /// Args: the account whose lists to show.
+        pub lists_clicked_callback: RefCell<Option<Box<dyn Fn(Profile) + 'static>>>,
+        pub list_opened_callback: RefCell<Option<Box<dyn Fn(UserList) + 'static>>>,
+        /// Args: the list page's context and whether this is a first page.
+        pub list_tab_callback: RefCell<Option<Box<dyn Fn(Rc<ListFeedCtx>, bool) + 'static>>>,
+        pub new_list_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        pub edit_list_callback: RefCell<Option<Box<dyn Fn(Rc<ListFeedCtx>) + 'static>>>,
+        pub delete_list_callback: RefCell<Option<Box<dyn Fn(UserList) + 'static>>>,
+        /// Pins the list's feed to the feed selector, or unpins it.
+        pub pin_list_callback: RefCell<Option<Box<dyn Fn(UserList) + 'static>>>,
+        /// Args: the account to put on or take off the user's lists.
+        pub add_to_lists_callback: RefCell<Option<Box<dyn Fn(Profile) + 'static>>>,
+        /// Args: the moderation list to mute or unmute, and its page's
+        /// context when the page asked.
+        pub list_mute_callback:
+            RefCell<Option<Box<dyn Fn(UserList, Option<Rc<ListFeedCtx>>) + 'static>>>,
+        /// As `list_mute_callback`, for blocking the list.
+        pub list_block_callback:
+            RefCell<Option<Box<dyn Fn(UserList, Option<Rc<ListFeedCtx>>) + 'static>>>,
+        /// Args: the user list to make a starter pack from.
+        pub make_starter_pack_callback: RefCell<Option<Box<dyn Fn(UserList) + 'static>>>,
+        pub starter_pack_opened_callback: RefCell<Option<Box<dyn Fn(StarterPack) + 'static>>>,
+        /// Args: the pack page's context, and whether this is a first page.
+        pub starter_pack_members_callback:
+            RefCell<Option<Box<dyn Fn(Rc<StarterPackCtx>, bool) + 'static>>>,
+        pub follow_all_callback: RefCell<Option<Box<dyn Fn(Rc<StarterPackCtx>) + 'static>>>,
         pub edit_profile_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
         /// Args: DID and the page's muted cell.
         pub mute_callback: RefCell<Option<Box<dyn Fn(String, Rc<Cell<bool>>) + 'static>>>,
@@ -324,6 +756,11 @@ mod imp {
         pub activity_nav_view: RefCell<Option<adw::NavigationView>>,
         pub activity_model: RefCell<Option<gio::ListStore>>,
         pub activity_load_more_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
+        /// The reason chip picked over the Activity list
+        pub activity_filter: Cell<ActivityFilter>,
+        pub activity_priority_toggle: RefCell<Option<gtk4::ToggleButton>>,
+        /// Reloads Activity after its filter or mode changed.
+        pub activity_filter_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
         pub activity_scrolled_window: RefCell<Option<gtk4::ScrolledWindow>>,
         pub activity_spinner: RefCell<Option<gtk4::Spinner>>,
         // Chat page state
@@ -351,6 +788,9 @@ mod imp {
         pub profile_handle_label: RefCell<Option<gtk4::Label>>,
         pub profile_bio_label: RefCell<Option<gtk4::Label>>,
         pub profile_avatar: RefCell<Option<adw::Avatar>>,
+        /// Names the label on a labeled account, under its handle.
+        pub profile_label_warning: RefCell<Option<gtk4::Box>>,
+        pub profile_label_warning_text: RefCell<Option<gtk4::Label>>,
         pub profile_followers_label: RefCell<Option<gtk4::Label>>,
         pub profile_following_label: RefCell<Option<gtk4::Label>>,
         pub profile_posts_label: RefCell<Option<gtk4::Label>>,
@@ -368,6 +808,10 @@ mod imp {
         pub bookmarks_spinner: RefCell<Option<gtk4::Spinner>>,
         pub bookmarks_overlay: RefCell<Option<gtk4::Overlay>>,
         pub bookmarks_empty_state: RefCell<Option<adw::StatusPage>>,
+        // Lists section state
+        pub lists_nav_view: RefCell<Option<adw::NavigationView>>,
+        /// The signed-in user's lists, the section's root
+        pub own_lists_page: RefCell<Option<ListsPage>>,
         // Search page state
         pub search_nav_view: RefCell<Option<adw::NavigationView>>,
         pub search_model: RefCell<Option<gio::ListStore>>,
@@ -376,12 +820,23 @@ mod imp {
         pub search_spinner: RefCell<Option<gtk4::Spinner>>,
         pub search_entry: RefCell<Option<gtk4::SearchEntry>>,
         pub search_callback: RefCell<Option<Box<dyn Fn(String) + 'static>>>,
+        // One removable chip per filter in the running search
+        pub search_chips: RefCell<Option<gtk4::FlowBox>>,
+        pub save_search_button: RefCell<Option<gtk4::Button>>,
+        pub save_search_callback: RefCell<Option<Box<dyn Fn(String) + 'static>>>,
+        pub search_filter_fields: RefCell<Option<SearchFilterFields>>,
         // People results live beside the post results on their own stack
         // page; the two lists paginate on independent cursors.
         pub search_people_model: RefCell<Option<gio::ListStore>>,
         pub search_people_load_more_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
         pub search_people_scrolled_window: RefCell<Option<gtk4::ScrolledWindow>>,
         pub search_people_spinner: RefCell<Option<gtk4::Spinner>>,
+        // The History page searches the cache, so it has no cursor or
+        // spinner: results land in one piece, straight away.
+        pub history_model: RefCell<Option<gio::ListStore>>,
+        pub history_empty_state: RefCell<Option<adw::StatusPage>>,
+        pub history_filters: RefCell<Option<HistoryFilters>>,
+        pub history_search_callback: RefCell<Option<Box<dyn Fn() + 'static>>>,
         // Toast overlay for notifications
         pub toast_overlay: RefCell<Option<adw::ToastOverlay>>,
         // Settings page
@@ -398,6 +853,10 @@ mod imp {
         /// number feeds both the banner and the Home badge so they cannot
         /// disagree.
         pub unseen_posts: Cell<usize>,
+        /// "N unread above", raised when a saved reading position is put
+        /// back, and the post it counts from
+        pub unread_above_pill: RefCell<Option<gtk4::Button>>,
+        pub unread_anchor: RefCell<Option<String>>,
         // Follower and following stat clicks, own profile and pushed pages alike
         pub follow_list_clicked_callback:
             RefCell<Option<Box<dyn Fn(Profile, FollowListKind) + 'static>>>,
@@ -549,6 +1008,14 @@ impl HangarWindow {
         search_nav_view.add(&search_page);
         main_stack.add_named(&search_nav_view, Some("search"));
 
+        // Lists section: the user's lists, and the lists and profiles
+        // they lead to
+        let lists_nav_view = adw::NavigationView::new();
+        Self::focus_after_pop(&lists_nav_view);
+        let lists_page = self.build_lists_root_page();
+        lists_nav_view.add(&lists_page);
+        main_stack.add_named(&lists_nav_view, Some("lists"));
+
         // Settings section
         let settings_page = self.build_settings_page();
         main_stack.add_named(&settings_page, Some("settings"));
@@ -594,6 +1061,7 @@ impl HangarWindow {
         imp.likes_nav_view.replace(Some(likes_nav_view));
         imp.bookmarks_nav_view.replace(Some(bookmarks_nav_view));
         imp.search_nav_view.replace(Some(search_nav_view));
+        imp.lists_nav_view.replace(Some(lists_nav_view));
 
         // Narrow windows drop the rail's captions; icons, tooltips, and
         // accessible names keep carrying the meaning. Watched by property
@@ -671,6 +1139,7 @@ impl HangarWindow {
         // Ctrl+K also focuses the entry; both land on the same page.
         general.add_shortcut(&shortcut("Search", "<Control>K <Alt>8"));
         general.add_shortcut(&shortcut("Keyboard Shortcuts", "<Control>question"));
+        general.add_shortcut(&shortcut("Quit", "<Control>q"));
 
         let navigation: gtk4::ShortcutsGroup = glib::Object::builder()
             .property("title", "Navigation")
@@ -760,8 +1229,16 @@ impl HangarWindow {
                 }
             }
         });
+        let discover_action = gio::SimpleAction::new("discover", None);
+        let win = self.downgrade();
+        discover_action.connect_activate(move |_, _| {
+            if let Some(win) = win.upgrade() {
+                win.request_discover_feeds();
+            }
+        });
         let feed_group = gio::SimpleActionGroup::new();
         feed_group.add_action(&feed_action);
+        feed_group.add_action(&discover_action);
         self.insert_action_group("feeds", Some(&feed_group));
 
         header.set_title_widget(Some(&feed_menu_btn));
@@ -804,15 +1281,26 @@ impl HangarWindow {
         let overlay = gtk4::Overlay::new();
         overlay.set_vexpand(true);
 
-        let model = gio::ListStore::new::<PostObject>();
+        // Posts, and a gap marker wherever a refresh could not reach the
+        // posts already loaded.
+        let model = gio::ListStore::new::<glib::Object>();
         let factory = gtk4::SignalListItemFactory::new();
 
-        factory.connect_setup(|_, item| {
-            let post_row = PostRow::new();
-            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() {
-                list_item.set_child(Some(&post_row));
+        // Each slot can be either row, as on thread pages: the gap row,
+        // hidden, and a PostRow.
+        factory.connect_setup(glib::clone!(
+            #[weak(rename_to = win)]
+            self,
+            move |_, item| {
+                let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() else {
+                    return;
+                };
+                let slot = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+                slot.append(&win.timeline_gap_row(list_item));
+                slot.append(&PostRow::new());
+                list_item.set_child(Some(&slot));
             }
-        });
+        ));
         Self::release_video_on_unbind(&factory);
 
         // Weak, and so is every callback below: window -> list view -> factory
@@ -821,14 +1309,32 @@ impl HangarWindow {
             #[weak(rename_to = win)]
             self,
             move |_, item| {
-                if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>()
-                    && let Some(post_object) = list_item.item().and_downcast::<PostObject>()
+                let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() else {
+                    return;
+                };
+                let is_gap = list_item
+                    .item()
+                    .and_downcast::<gtk4::StringObject>()
+                    .is_some_and(|marker| marker.string().starts_with(TIMELINE_GAP_MARKER));
+                if let Some(gap_row) = list_item.child().and_then(|slot| slot.first_child()) {
+                    gap_row.set_visible(is_gap);
+                    // A press left it insensitive; rebound, it is a fresh row.
+                    gap_row.set_sensitive(true);
+                }
+                let post_row = Self::post_row_of(list_item);
+                if let Some(post_row) = &post_row {
+                    post_row.set_visible(!is_gap);
+                }
+                if let Some(post_object) = list_item.item().and_downcast::<PostObject>()
                     && let Some(post) = post_object.post()
-                    && let Some(post_row) = list_item.child().and_downcast::<PostRow>()
+                    && let Some(post_row) = post_row
                 {
                     post_row.bind(&post);
                     post_row.set_list_position(list_item.position());
                     win.wire_post_row(&post_row, &post);
+                    if win.imp().feed_interactions.get() {
+                        win.wire_feed_interactions(&post_row, &post);
+                    }
                 }
             }
         ));
@@ -898,6 +1404,34 @@ impl HangarWindow {
         ));
         overlay.add_overlay(&new_posts_btn);
 
+        // "N unread above": where the banner sits, since the two never show
+        // at once. It only scrolls; the posts above are already loaded.
+        let unread_pill = gtk4::Button::new();
+        let pill_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 6);
+        let pill_icon = gtk4::Image::from_icon_name("go-up-symbolic");
+        pill_icon.add_css_class("banner-icon");
+        let pill_label = gtk4::Label::new(None);
+        pill_label.add_css_class("banner-label");
+        pill_box.append(&pill_icon);
+        pill_box.append(&pill_label);
+        unread_pill.set_child(Some(&pill_box));
+        unread_pill.add_css_class("suggested-action");
+        unread_pill.add_css_class("pill");
+        unread_pill.add_css_class("new-posts-banner");
+        unread_pill.set_halign(gtk4::Align::Center);
+        unread_pill.set_valign(gtk4::Align::Start);
+        unread_pill.set_margin_top(12);
+        unread_pill.set_visible(false);
+        unread_pill.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            move |_| {
+                window.hide_unread_above();
+                window.scroll_to_top();
+            }
+        ));
+        overlay.add_overlay(&unread_pill);
+
         // Loading spinner as an overlay at the bottom
         let spinner = gtk4::Spinner::new();
         spinner.update_property(&[gtk4::accessible::Property::Label("Loading")]);
@@ -949,6 +1483,7 @@ impl HangarWindow {
         imp.timeline_list_view.replace(Some(list_view.clone()));
         imp.loading_spinner.replace(Some(spinner));
         imp.new_posts_banner.replace(Some(new_posts_btn));
+        imp.unread_above_pill.replace(Some(unread_pill));
         imp.scrolled_window.replace(Some(scrolled.clone()));
 
         // Start timestamp refresh timer (every 60 seconds)
@@ -993,6 +1528,7 @@ impl HangarWindow {
                 // Auto-hide "new posts" banner when user scrolls to top
                 if value < 50.0 {
                     win.hide_new_posts_banner();
+                    win.hide_unread_above();
                 }
 
                 // Prefetch when user is at 70% scroll (30% remaining content)
@@ -1021,6 +1557,184 @@ impl HangarWindow {
             .replace(Some(Box::new(callback)));
     }
 
+    pub fn set_gap_callback<F: Fn(String, bool) + 'static>(&self, callback: F) {
+        self.imp().gap_callback.replace(Some(Box::new(callback)));
+    }
+
+    /// The "Load N more" row a timeline slot shows for a gap marker. The
+    /// marker is read at press time: the slot is rebound as the list scrolls.
+    fn timeline_gap_row(&self, list_item: &gtk4::ListItem) -> gtk4::Box {
+        let row = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        row.set_visible(false);
+        row.set_margin_top(8);
+        row.set_margin_bottom(8);
+
+        let button = gtk4::Button::with_label(&format!("Load {GAP_FILL_SIZE} more"));
+        button.add_css_class("pill");
+        button.set_halign(gtk4::Align::Center);
+        row.append(&button);
+
+        let win = self.downgrade();
+        let list_item = list_item.downgrade();
+        let row_weak = row.downgrade();
+        button.connect_clicked(move |button| {
+            let (Some(win), Some(list_item), Some(row)) =
+                (win.upgrade(), list_item.upgrade(), row_weak.upgrade())
+            else {
+                return;
+            };
+            let Some(boundary) = list_item
+                .item()
+                .and_downcast::<gtk4::StringObject>()
+                .and_then(|marker| {
+                    marker
+                        .string()
+                        .strip_prefix(TIMELINE_GAP_MARKER)
+                        .map(String::from)
+                })
+            else {
+                return;
+            };
+            // A gap in the top half of the view is one the reader scrolled up
+            // to from the older posts, which are what they are reading.
+            let from_below = win
+                .imp()
+                .scrolled_window
+                .borrow()
+                .as_ref()
+                .and_then(|scrolled| {
+                    let point =
+                        button.compute_point(scrolled, &gtk4::graphene::Point::new(0.0, 0.0))?;
+                    Some(point.y() < scrolled.height() as f32 / 2.0)
+                })
+                .unwrap_or(false);
+            row.set_sensitive(false);
+            if let Some(cb) = win.imp().gap_callback.borrow().as_ref() {
+                cb(boundary, from_below);
+            }
+        });
+        row
+    }
+
+    fn gap_marker(boundary_uri: &str) -> gtk4::StringObject {
+        gtk4::StringObject::new(&format!("{TIMELINE_GAP_MARKER}{boundary_uri}"))
+    }
+
+    /// Where the timeline lists the gap under `boundary_uri`.
+    fn gap_position(model: &gio::ListStore, boundary_uri: &str) -> Option<u32> {
+        let wanted = format!("{TIMELINE_GAP_MARKER}{boundary_uri}");
+        (0..model.n_items()).find(|&i| {
+            model
+                .item(i)
+                .and_downcast::<gtk4::StringObject>()
+                .is_some_and(|marker| marker.string() == wanted.as_str())
+        })
+    }
+
+    /// Show a gap under the post at `boundary_uri`. A post no longer listed,
+    /// muted or hidden since, has nowhere to hang one.
+    pub fn insert_gap(&self, boundary_uri: &str) {
+        let Some(model) = self.imp().timeline_model.borrow().as_ref().cloned() else {
+            return;
+        };
+        if Self::gap_position(&model, boundary_uri).is_some() {
+            return;
+        }
+        let above = (0..model.n_items()).find(|&i| {
+            model
+                .item(i)
+                .and_downcast::<PostObject>()
+                .and_then(|o| o.post())
+                .is_some_and(|post| post.uri == boundary_uri)
+        });
+        if let Some(above) = above {
+            model.insert(above + 1, &Self::gap_marker(boundary_uri));
+        }
+    }
+
+    /// Take down a gap row that has nothing behind it any more.
+    pub fn remove_gap(&self, boundary_uri: &str) {
+        if let Some(model) = self.imp().timeline_model.borrow().as_ref().cloned()
+            && let Some(at) = Self::gap_position(&model, boundary_uri)
+        {
+            model.remove(at);
+        }
+    }
+
+    /// Offer a gap again after filling it failed. Swapping in a new marker
+    /// rebinds the row, which makes it pressable again.
+    pub fn reset_gap(&self, boundary_uri: &str) {
+        if let Some(model) = self.imp().timeline_model.borrow().as_ref().cloned()
+            && let Some(at) = Self::gap_position(&model, boundary_uri)
+        {
+            model.splice(at, 1, &[Self::gap_marker(boundary_uri)]);
+        }
+    }
+
+    /// Put a filled gap's posts where its row was, with a row for whatever
+    /// is still missing under `rest_boundary`: above the new posts when it
+    /// is the same boundary, below them otherwise.
+    ///
+    /// `hold_below` keeps the posts under the gap where they are on screen,
+    /// for a reader who came up to it from there. Otherwise the new posts
+    /// open up below what the reader was looking at.
+    pub fn fill_gap(
+        &self,
+        boundary_uri: &str,
+        posts: Vec<Post>,
+        rest_boundary: Option<&str>,
+        hold_below: bool,
+    ) {
+        let posts = self.filter_feed_posts(posts);
+        let Some(model) = self.imp().timeline_model.borrow().as_ref().cloned() else {
+            return;
+        };
+        let Some(at) = Self::gap_position(&model, boundary_uri) else {
+            return;
+        };
+
+        let mut seen = std::collections::HashSet::new();
+        for i in 0..model.n_items() {
+            if let Some(post) = model
+                .item(i)
+                .and_downcast::<PostObject>()
+                .and_then(|o| o.post())
+            {
+                seen.insert(post.uri);
+            }
+        }
+        let mut rows: Vec<glib::Object> = posts
+            .into_iter()
+            .filter(|p| seen.insert(p.uri.clone()))
+            .map(|p| PostObject::new(p).upcast())
+            .collect();
+        match rest_boundary {
+            Some(rest) if rest == boundary_uri => rows.insert(0, Self::gap_marker(rest).upcast()),
+            Some(rest) => rows.push(Self::gap_marker(rest).upcast()),
+            None => {}
+        }
+
+        let adj = self
+            .imp()
+            .scrolled_window
+            .borrow()
+            .as_ref()
+            .map(|scrolled| scrolled.vadjustment());
+        let before = adj.as_ref().map(|adj| (adj.value(), adj.upper()));
+
+        // One splice, so the gap row and its posts are never listed at once.
+        model.splice(at, 1, &rows);
+
+        if hold_below && let (Some(adj), Some((value, upper))) = (adj, before) {
+            glib::idle_add_local_once(move || {
+                let height_added = adj.upper() - upper;
+                if height_added > 0.0 {
+                    adj.set_value(value + height_added);
+                }
+            });
+        }
+    }
+
     /// Make a `PostRow` factory give up its video when a row is recycled.
     ///
     /// Every `PostRow` factory needs this, the timeline's and the rest: a
@@ -1182,6 +1896,9 @@ impl HangarWindow {
         block_item.add_css_class("flat");
         block_item.add_css_class("destructive-action");
         mod_popover_box.append(&block_item);
+        let lists_item = gtk4::Button::with_label("Add to Lists...");
+        lists_item.add_css_class("flat");
+        mod_popover_box.append(&lists_item);
         let report_item = gtk4::Button::with_label("Report Account...");
         report_item.add_css_class("flat");
         mod_popover_box.append(&report_item);
@@ -1237,6 +1954,18 @@ impl HangarWindow {
             }
         });
 
+        let win = self.downgrade();
+        let profile_for_lists = profile.clone();
+        let mod_pop = mod_popover.clone();
+        lists_item.connect_clicked(move |_| {
+            mod_pop.popdown();
+            if let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().add_to_lists_callback.borrow().as_ref()
+            {
+                cb(profile_for_lists.clone());
+            }
+        });
+
         let win = self.downgrade();
         let profile_for_report = profile.clone();
         let mod_pop = mod_popover.clone();
@@ -1259,9 +1988,10 @@ impl HangarWindow {
         mod_btn
     }
 
-    /// Posts, Replies, Media, Videos. Switching clears the list and asks
-    /// the app for the tab's own feed; the shared context strands stale
-    /// fetches. Centered to sit under the centered profile header.
+    /// Posts, Replies, Media, Videos, Starter Packs. Switching clears the
+    /// list and asks the app for the tab's own feed; the shared context
+    /// strands stale fetches. Centered to sit under the centered profile
+    /// header.
     fn build_profile_tabs(&self, feed_ctx: &Rc<ProfileFeedCtx>) -> gtk4::Box {
         let tabs = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
         tabs.add_css_class("linked");
@@ -1274,6 +2004,7 @@ impl HangarWindow {
             ("Replies", "posts_with_replies"),
             ("Media", "posts_with_media"),
             ("Videos", "posts_with_video"),
+            ("Starter Packs", ProfileFeedCtx::STARTER_PACKS),
         ] {
             let tab = gtk4::ToggleButton::with_label(label);
             match &first_tab {
@@ -1302,6 +2033,49 @@ impl HangarWindow {
         tabs
     }
 
+    /// The Starter Packs tab's rows, each opening its pack. Hidden until
+    /// that tab is picked.
+    fn build_starter_pack_rows(&self, feed_ctx: &Rc<ProfileFeedCtx>) -> gtk4::ListBox {
+        let packs = feed_ctx.packs.clone();
+        packs.add_css_class("boxed-list");
+        packs.set_selection_mode(gtk4::SelectionMode::None);
+        packs.set_margin_start(12);
+        packs.set_margin_end(12);
+        packs.set_margin_bottom(12);
+        packs.set_visible(false);
+        let win = self.downgrade();
+        let ctx = Rc::downgrade(feed_ctx);
+        packs.connect_row_activated(move |_, row| {
+            let Some(ctx) = ctx.upgrade() else {
+                return;
+            };
+            let pack = usize::try_from(row.index())
+                .ok()
+                .and_then(|i| ctx.starter_packs.borrow().get(i).cloned());
+            if let Some(pack) = pack
+                && let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().starter_pack_opened_callback.borrow().as_ref()
+            {
+                cb(pack);
+            }
+        });
+        packs
+    }
+
+    /// A starter pack in a profile's listing.
+    fn build_starter_pack_row(pack: &StarterPack) -> adw::ActionRow {
+        let row = adw::ActionRow::new();
+        // Names are whatever their authors typed.
+        row.set_use_markup(false);
+        row.set_title(&pack.name);
+        row.set_subtitle(&pack.summary());
+        row.set_subtitle_lines(1);
+        row.set_activatable(true);
+        row.add_prefix(&gtk4::Image::from_icon_name("system-users-symbolic"));
+        row.add_suffix(&gtk4::Image::from_icon_name("go-next-symbolic"));
+        row
+    }
+
     /// "No posts yet", following the model so every tab can say when it
     /// has nothing.
     fn build_feed_empty_label(model: &gio::ListStore, initially_visible: bool) -> gtk4::Label {
@@ -1472,10 +2246,13 @@ impl HangarWindow {
     }
 
     /// Drop replies from a feed batch when the user has asked for a quieter
-    /// timeline.
+    /// timeline, anything tripping a muted word, and anything the account's
+    /// label preferences hide.
     ///
-    /// Main feed only. Threads, profiles and likes keep their replies.
-    fn filter_feed_posts(posts: Vec<Post>) -> Vec<Post> {
+    /// Replies go from the main feed only. Threads, profiles and likes keep
+    /// theirs.
+    fn filter_feed_posts(&self, posts: Vec<Post>) -> Vec<Post> {
+        let posts = self.without_hidden(self.without_muted(posts));
         if !crate::state::AppSettings::load().hide_replies_in_feed {
             return posts;
         }
@@ -1485,7 +2262,42 @@ impl HangarWindow {
             .collect()
     }
 
-    /// First timeline load without a cache: skeleton rows instead of a
+    /// Posts that trip none of the account's muted words.
+    fn without_muted(&self, mut posts: Vec<Post>) -> Vec<Post> {
+        let words = self.imp().muted_words.borrow();
+        if !words.is_empty() {
+            let now = chrono::Utc::now();
+            posts.retain(|post| !muted_words::post_is_muted(&words, post, now));
+        }
+        posts
+    }
+
+    /// Posts no label hides. Threads and profiles keep them, under a cover.
+    fn without_hidden(&self, mut posts: Vec<Post>) -> Vec<Post> {
+        let prefs = self.imp().moderation_prefs.borrow();
+        posts.retain(|post| !moderation::hidden_from_feeds(post, &prefs));
+        posts
+    }
+
+    /// Notifications whose post trips none of the muted words. Likes and
+    /// follows carry no post and always stay.
+    fn notifications_without_muted(
+        &self,
+        mut notifications: Vec<Notification>,
+    ) -> Vec<Notification> {
+        let words = self.imp().muted_words.borrow();
+        if !words.is_empty() {
+            let now = chrono::Utc::now();
+            notifications.retain(|n| {
+                n.post
+                    .as_ref()
+                    .is_none_or(|post| !muted_words::post_is_muted(&words, post, now))
+            });
+        }
+        notifications
+    }
+
+    /// First timeline load without a cache: skeleton rows instead of a
     /// blank pane.
     pub fn set_timeline_loading(&self, loading: bool) {
         let imp = self.imp();
@@ -1505,8 +2317,10 @@ impl HangarWindow {
     }
 
     pub fn set_posts(&self, posts: Vec<Post>) {
-        let posts = Self::filter_feed_posts(posts);
+        let posts = self.filter_feed_posts(posts);
         let empty = posts.is_empty();
+        // A new list; whatever the pill counted from is gone.
+        self.hide_unread_above();
         if let Some(model) = self.imp().timeline_model.borrow().as_ref() {
             model.remove_all();
 
@@ -1526,7 +2340,7 @@ impl HangarWindow {
     }
 
     pub fn append_posts(&self, posts: Vec<Post>) {
-        let posts = Self::filter_feed_posts(posts);
+        let posts = self.filter_feed_posts(posts);
         if let Some(model) = self.imp().timeline_model.borrow().as_ref() {
             for post in posts {
                 let post_object = PostObject::new(post);
@@ -1566,6 +2380,9 @@ impl HangarWindow {
             return;
         }
 
+        // The banner says more, and sits in the same place.
+        self.hide_unread_above();
+
         // Each poll reports only its own batch, so keep a running total
         // until the user actually looks at the top of the feed.
         let total = self.imp().unseen_posts.get().saturating_add(count);
@@ -1689,6 +2506,45 @@ impl HangarWindow {
         }
     }
 
+    /// Fold a fresh first page into the timeline. Posts already listed take
+    /// the page's counts and viewer state where they sit; the rest go on
+    /// top as [`Self::insert_posts_at_top`] puts them, holding the reader's
+    /// place.
+    ///
+    /// Returns how many went on top.
+    pub fn merge_posts(&self, posts: Vec<Post>) -> usize {
+        let posts = self.filter_feed_posts(posts);
+        if let Some(model) = self.imp().timeline_model.borrow().as_ref().cloned() {
+            let fresh: std::collections::HashMap<&str, &Post> =
+                posts.iter().map(|p| (p.uri.as_str(), p)).collect();
+            for i in 0..model.n_items() {
+                let Some(listed) = model
+                    .item(i)
+                    .and_downcast::<PostObject>()
+                    .and_then(|o| o.post())
+                else {
+                    continue;
+                };
+                // Rebinding a row tears down a playing video, so only rows
+                // whose numbers actually moved are swapped.
+                if let Some(post) = fresh.get(listed.uri.as_str())
+                    && Self::engagement_changed(&listed, post)
+                {
+                    model.splice(i, 1, &[PostObject::new((*post).clone())]);
+                }
+            }
+        }
+        self.insert_posts_at_top(posts)
+    }
+
+    fn engagement_changed(listed: &Post, fresh: &Post) -> bool {
+        listed.like_count != fresh.like_count
+            || listed.repost_count != fresh.repost_count
+            || listed.reply_count != fresh.reply_count
+            || listed.viewer_like != fresh.viewer_like
+            || listed.viewer_repost != fresh.viewer_repost
+    }
+
     /// Insert new posts at the top of the timeline without disrupting the current view.
     /// Rebuilds the model with new posts prepended, then restores scroll position.
     /// Put fresh posts at the head of the timeline.
@@ -1696,7 +2552,7 @@ impl HangarWindow {
     /// Returns how many were actually inserted, which is what the banner
     /// should count: a reordered feed re-serves posts already on screen.
     pub fn insert_posts_at_top(&self, posts: Vec<Post>) -> usize {
-        let posts = Self::filter_feed_posts(posts);
+        let posts = self.filter_feed_posts(posts);
         let Some(model) = self.imp().timeline_model.borrow().as_ref().cloned() else {
             return 0;
         };
@@ -1755,9 +2611,167 @@ impl HangarWindow {
                 }
             });
         }
+        self.refresh_unread_above();
         inserted
     }
 
+    /// The timeline's post rows on screen, with where each one's top edge
+    /// sits against the scroller's.
+    fn timeline_rows_in_view(&self) -> Vec<(PostRow, f64)> {
+        let imp = self.imp();
+        let (Some(list_view), Some(scrolled)) = (
+            imp.timeline_list_view.borrow().clone(),
+            imp.scrolled_window.borrow().clone(),
+        ) else {
+            return Vec::new();
+        };
+        let mut rows = Vec::new();
+        let mut child = list_view.first_child();
+        while let Some(widget) = child {
+            // ListItemWidget, then the slot, whose last child is the PostRow.
+            if let Some(post_row) = widget
+                .first_child()
+                .and_then(|slot| slot.last_child())
+                .and_downcast::<PostRow>()
+                && post_row.is_mapped()
+                && let Some(origin) =
+                    post_row.compute_point(&scrolled, &gtk4::graphene::Point::new(0.0, 0.0))
+            {
+                rows.push((post_row, f64::from(origin.y())));
+            }
+            child = widget.next_sibling();
+        }
+        rows
+    }
+
+    /// Where the reader is in the timeline: the post the top of the view
+    /// cuts through, or failing that the first one below it. `None` while
+    /// the timeline is not on screen.
+    pub fn reading_position(&self) -> Option<ReadingPosition> {
+        let (row, top) = self
+            .timeline_rows_in_view()
+            .into_iter()
+            .filter(|(row, top)| top + f64::from(row.height()) > 0.0)
+            .min_by(|a, b| a.1.total_cmp(&b.1))?;
+        Some(ReadingPosition {
+            post_uri: row.post_uri()?,
+            offset: top,
+        })
+    }
+
+    /// Scroll the timeline back to a saved reading position and count what
+    /// is above it. A post no longer listed leaves the view at the top.
+    ///
+    /// The list only realizes the row once it has laid out around it, which
+    /// on launch is a few frames off, so this keeps asking until it can
+    /// line the row up.
+    pub fn restore_reading_position(&self, position: &ReadingPosition) {
+        let win = self.downgrade();
+        let position = position.clone();
+        let mut tries = 0;
+        glib::timeout_add_local(std::time::Duration::from_millis(40), move || {
+            let Some(win) = win.upgrade() else {
+                return glib::ControlFlow::Break;
+            };
+            let imp = win.imp();
+            let (Some(model), Some(list_view), Some(scrolled)) = (
+                imp.timeline_model.borrow().clone(),
+                imp.timeline_list_view.borrow().clone(),
+                imp.scrolled_window.borrow().clone(),
+            ) else {
+                return glib::ControlFlow::Break;
+            };
+            // Looked up every time: a refresh may have put posts above it.
+            let Some(index) = (0..model.n_items()).find(|&i| {
+                model
+                    .item(i)
+                    .and_downcast::<PostObject>()
+                    .and_then(|o| o.post())
+                    .is_some_and(|post| post.uri == position.post_uri)
+            }) else {
+                return glib::ControlFlow::Break;
+            };
+            let row = win
+                .timeline_rows_in_view()
+                .into_iter()
+                .find(|(row, _)| row.post_uri().as_deref() == Some(position.post_uri.as_str()));
+            match row {
+                Some((_, top)) => {
+                    let adj = scrolled.vadjustment();
+                    adj.set_value(adj.value() + top - position.offset);
+                    imp.unread_anchor.replace(Some(position.post_uri.clone()));
+                    win.refresh_unread_above();
+                    glib::ControlFlow::Break
+                }
+                None if tries < 25 => {
+                    tries += 1;
+                    list_view.scroll_to(index, gtk4::ListScrollFlags::NONE, None);
+                    glib::ControlFlow::Continue
+                }
+                None => glib::ControlFlow::Break,
+            }
+        });
+    }
+
+    /// Recount the posts above the restored reading position and show the
+    /// pill with that many, or take it down when there are none.
+    fn refresh_unread_above(&self) {
+        let Some(anchor) = self.imp().unread_anchor.borrow().clone() else {
+            return;
+        };
+        let Some(model) = self.imp().timeline_model.borrow().clone() else {
+            return;
+        };
+        let mut above = 0;
+        let mut found = false;
+        for i in 0..model.n_items() {
+            let Some(post) = model
+                .item(i)
+                .and_downcast::<PostObject>()
+                .and_then(|o| o.post())
+            else {
+                continue;
+            };
+            if post.uri == anchor {
+                found = true;
+                break;
+            }
+            above += 1;
+        }
+        if !found || above == 0 {
+            self.hide_unread_above();
+            return;
+        }
+        let Some(pill) = self.imp().unread_above_pill.borrow().clone() else {
+            return;
+        };
+        let label_text = if above == 1 {
+            "1 unread above".to_string()
+        } else if above > 99 {
+            "99+ unread above".to_string()
+        } else {
+            format!("{} unread above", above)
+        };
+        if let Some(label) = pill
+            .child()
+            .and_then(|c| c.last_child())
+            .and_downcast::<gtk4::Label>()
+        {
+            label.set_label(&label_text);
+        }
+        if !pill.is_visible() {
+            pill.set_visible(true);
+            self.announce(&label_text, gtk4::AccessibleAnnouncementPriority::Medium);
+        }
+    }
+
+    pub fn hide_unread_above(&self) {
+        self.imp().unread_anchor.replace(None);
+        if let Some(pill) = self.imp().unread_above_pill.borrow().as_ref() {
+            pill.set_visible(false);
+        }
+    }
+
     /// Whether the timeline is scrolled to (or near) the top, where new
     /// posts land straight in view.
     fn timeline_at_top(&self) -> bool {
@@ -1794,17 +2808,35 @@ impl HangarWindow {
         self.rebuild_feed_list();
     }
 
+    /// The feeds in the selector, saved searches included
+    pub fn saved_feeds(&self) -> Vec<SavedFeed> {
+        self.imp().saved_feeds.borrow().clone()
+    }
+
     /// Rebuild the feed menu; the action state picks out the current one.
+    /// Saved searches follow the pinned feeds in a section of their own,
+    /// and Discover Feeds closes the menu.
     fn rebuild_feed_list(&self) {
         let Some(menu) = self.imp().feed_menu.borrow().clone() else {
             return;
         };
         menu.remove_all();
+        let searches = gio::Menu::new();
         for feed in self.imp().saved_feeds.borrow().iter() {
             let item = gio::MenuItem::new(Some(&feed.display_name), None);
             item.set_action_and_target_value(Some("feeds.select"), Some(&feed.uri.to_variant()));
-            menu.append_item(&item);
+            if feed.search_query().is_some() {
+                searches.append_item(&item);
+            } else {
+                menu.append_item(&item);
+            }
         }
+        if searches.n_items() > 0 {
+            menu.append_section(Some("Saved Searches"), &searches);
+        }
+        let discover = gio::Menu::new();
+        discover.append(Some("Discover Feeds"), Some("feeds.discover"));
+        menu.append_section(None, &discover);
     }
 
     /// Update the feed selector button label and selection state
@@ -1832,15 +2864,228 @@ impl HangarWindow {
             .replace(Some(Box::new(callback)));
     }
 
-    /// Set callback for when an @mention in post text is clicked (handle without @)
+    /// Set callback for when an @mention in post text is clicked: the DID
+    /// from its facet, or the handle without @ where the post had none
     pub fn set_mention_clicked_callback<F: Fn(String) + 'static>(&self, callback: F) {
         self.imp()
             .mention_clicked_callback
             .replace(Some(Box::new(callback)));
     }
 
+    /// Set callback for when a #hashtag in post text or a bio is clicked
+    /// (tag without #)
+    pub fn set_tag_clicked_callback<F: Fn(String) + 'static>(&self, callback: F) {
+        self.imp()
+            .tag_clicked_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Args: the hashtag page's feed context and whether this is a first
+    /// page.
+    pub fn set_hashtag_tab_callback<F>(&self, callback: F)
+    where
+        F: Fn(Rc<HashtagFeedCtx>, bool) + 'static,
+    {
+        self.imp()
+            .hashtag_tab_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    pub fn set_pin_tag_callback<F: Fn(String) + 'static>(&self, callback: F) {
+        self.imp()
+            .pin_tag_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Set callback for when a profile's lists stat is clicked
+    pub fn set_lists_clicked_callback<F: Fn(Profile) + 'static>(&self, callback: F) {
+        self.imp()
+            .lists_clicked_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Set callback for when a list is picked from a page of lists
+    pub fn set_list_opened_callback<F: Fn(UserList) + 'static>(&self, callback: F) {
+        self.imp()
+            .list_opened_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Args: the list page's context and whether this is a first page.
+    pub fn set_list_tab_callback<F>(&self, callback: F)
+    where
+        F: Fn(Rc<ListFeedCtx>, bool) + 'static,
+    {
+        self.imp()
+            .list_tab_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    pub fn set_new_list_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp()
+            .new_list_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    pub fn set_edit_list_callback<F: Fn(Rc<ListFeedCtx>) + 'static>(&self, callback: F) {
+        self.imp()
+            .edit_list_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    pub fn set_delete_list_callback<F: Fn(UserList) + 'static>(&self, callback: F) {
+        self.imp()
+            .delete_list_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    pub fn set_pin_list_callback<F: Fn(UserList) + 'static>(&self, callback: F) {
+        self.imp()
+            .pin_list_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Set callback for "Add to Lists" on a profile or an account row
+    pub fn set_add_to_lists_callback<F: Fn(Profile) + 'static>(&self, callback: F) {
+        self.imp()
+            .add_to_lists_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Args: the moderation list, and its page's context when the page
+    /// asked. The list's own viewer state says which way to toggle.
+    pub fn set_list_mute_callback<F>(&self, callback: F)
+    where
+        F: Fn(UserList, Option<Rc<ListFeedCtx>>) + 'static,
+    {
+        self.imp()
+            .list_mute_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// As [`Self::set_list_mute_callback`], for blocking the list.
+    pub fn set_list_block_callback<F>(&self, callback: F)
+    where
+        F: Fn(UserList, Option<Rc<ListFeedCtx>>) + 'static,
+    {
+        self.imp()
+            .list_block_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Set callback for "Make Starter Pack" on one of the user's lists
+    pub fn set_make_starter_pack_callback<F: Fn(UserList) + 'static>(&self, callback: F) {
+        self.imp()
+            .make_starter_pack_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Set callback for when a starter pack is picked from a profile
+    pub fn set_starter_pack_opened_callback<F: Fn(StarterPack) + 'static>(&self, callback: F) {
+        self.imp()
+            .starter_pack_opened_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Args: the pack page's context, and whether this is a first page.
+    pub fn set_starter_pack_members_callback<F>(&self, callback: F)
+    where
+        F: Fn(Rc<StarterPackCtx>, bool) + 'static,
+    {
+        self.imp()
+            .starter_pack_members_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Set callback for a starter pack's Follow All button
+    pub fn set_follow_all_callback<F: Fn(Rc<StarterPackCtx>) + 'static>(&self, callback: F) {
+        self.imp()
+            .follow_all_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Args: the account's whole saved feed list, edited. The app writes
+    /// it and calls `set_saved_feed_list` with what was kept.
+    pub fn set_saved_feed_list_callback<F: Fn(Vec<SavedFeed>) + 'static>(&self, callback: F) {
+        self.imp()
+            .saved_feed_list_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Args: a post in the home feed and what the reader did with it.
+    /// Only called while the feed takes interactions.
+    pub fn set_feed_interaction_callback<F: Fn(Post, InteractionEvent) + 'static>(
+        &self,
+        callback: F,
+    ) {
+        self.imp()
+            .feed_interaction_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Whether the feed now on the home timeline takes interactions. The
+    /// answer can land after the feed's first page, so the rows on screen
+    /// are rebound to pick it up.
+    pub fn set_feed_takes_interactions(&self, takes: bool) {
+        let imp = self.imp();
+        if imp.feed_interactions.replace(takes) == takes {
+            return;
+        }
+        if let Some(store) = imp.timeline_model.borrow().as_ref() {
+            let n = store.n_items();
+            store.items_changed(0, n, n);
+        }
+    }
+
+    fn report_feed_interaction(&self, post: &Post, event: InteractionEvent) {
+        if let Some(cb) = self.imp().feed_interaction_callback.borrow().as_ref() {
+            cb(post.clone(), event);
+        }
+    }
+
+    /// Set callback for Discover Feeds, in the feed selector or Settings
+    pub fn set_discover_feeds_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp()
+            .discover_feeds_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Set callback for when a feed generator is picked to look at
+    pub fn set_feed_opened_callback<F: Fn(FeedGenerator) + 'static>(&self, callback: F) {
+        self.imp()
+            .feed_opened_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Set callback for a feed page's Like button; the feed's own viewer
+    /// state says which way to toggle.
+    pub fn set_feed_like_callback<F: Fn(Rc<FeedGeneratorCtx>) + 'static>(&self, callback: F) {
+        self.imp()
+            .feed_like_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// The home feed's extra wiring while its generator takes feedback.
+    /// A bound row counts as seen: the list view binds only a screenful
+    /// past what shows, and the generator cares about impressions, not
+    /// pixels. Opening the post is reported before it opens.
+    fn wire_feed_interactions(&self, post_row: &PostRow, post: &Post) {
+        post_row.set_feed_feedback(true);
+        self.report_feed_interaction(post, InteractionEvent::Seen);
+        let w = self.downgrade();
+        post_row.set_post_clicked_callback(move |p| {
+            let Some(w) = w.upgrade() else {
+                return;
+            };
+            w.report_feed_interaction(&p, InteractionEvent::Clickthrough);
+            if let Some(cb) = w.imp().post_clicked_callback.borrow().as_ref() {
+                cb(p);
+            }
+        });
+    }
+
     /// Route one bound row's interactions to the window callbacks: like,
-    /// repost, quote, reply, and the post, profile, and mention
+    /// repost, quote, reply, and the post, profile, mention and hashtag
     /// navigation. Every post list wires through here; page-local subsets
     /// are how mention clicks went dead on some pages. The post click is
     /// wired even where the row body is not clickable, since embedded
@@ -1920,6 +3165,15 @@ impl HangarWindow {
                 cb(handle);
             }
         });
+        let w = self.downgrade();
+        post_row.set_tag_clicked_callback(move |tag| {
+            let Some(w) = w.upgrade() else {
+                return;
+            };
+            if let Some(cb) = w.imp().tag_clicked_callback.borrow().as_ref() {
+                cb(tag);
+            }
+        });
     }
 
     /// Set callback for when a followers or following count is clicked
@@ -1932,11 +3186,14 @@ impl HangarWindow {
             .replace(Some(Box::new(callback)));
     }
 
-    /// Push a thread view page onto the current section's navigation stack
-    pub fn push_thread_page(&self, post: &Post, thread_posts: Vec<Post>) {
+    /// Push a thread view page onto the current section's navigation stack.
+    ///
+    /// Returns whether a new page went up; a thread already in the stack is
+    /// popped back to as it was.
+    pub fn push_thread_page(&self, post: &Post, thread_posts: Vec<Post>) -> bool {
         let nav_view = self.current_nav_view();
         let Some(nav_view) = nav_view else {
-            return;
+            return false;
         };
 
         // Save current scroll position before navigating
@@ -1949,24 +3206,37 @@ impl HangarWindow {
         let tag = format!("thread:{}", post.uri);
         if nav_view.find_page(&tag).is_some() {
             nav_view.pop_to_tag(&tag);
-            return;
+            return false;
         }
 
         let page = self.build_thread_page(post, thread_posts);
         page.set_tag(Some(&tag));
         Self::push_capped(&nav_view, &page);
+        true
+    }
+
+    /// Swap the live thread in for the cached copy pushed while it loaded.
+    pub fn refresh_thread_page(&self, post: &Post, thread_posts: Vec<Post>) {
+        let tag = format!("thread:{}", post.uri);
+        if let Some(nav_view) = self.visible_page_tagged(&tag) {
+            let page = self.build_thread_page(post, thread_posts);
+            page.set_tag(Some(&tag));
+            Self::swap_visible_page(&nav_view, &page);
+        }
     }
 
-    /// Push a profile view page onto the current section's navigation stack
+    /// Push a profile view page onto the current section's navigation stack.
+    ///
+    /// Returns whether a new page went up, as for threads.
     pub fn push_profile_page(
         &self,
         profile: &Profile,
         posts: Vec<Post>,
         feed_cursor: Option<String>,
-    ) {
+    ) -> bool {
         let nav_view = self.current_nav_view();
         let Some(nav_view) = nav_view else {
-            return;
+            return false;
         };
 
         // Save current scroll position before navigating
@@ -1978,44 +3248,334 @@ impl HangarWindow {
         let tag = format!("profile:{}", profile.did);
         if nav_view.find_page(&tag).is_some() {
             nav_view.pop_to_tag(&tag);
-            return;
+            return false;
         }
 
         let page = self.build_profile_page(profile, posts, feed_cursor);
         page.set_tag(Some(&tag));
         Self::push_capped(&nav_view, &page);
+        true
     }
 
-    /// Push a followers or following list onto the current section's stack.
-    ///
-    /// Returns the list widget either way; the caller decides whether a
-    /// popped-back page still needs a fetch.
-    pub fn push_follow_list_page(
+    /// Swap the live profile in for the cached copy pushed while it loaded.
+    pub fn refresh_profile_page(
         &self,
         profile: &Profile,
-        kind: FollowListKind,
-    ) -> Option<FollowListPush> {
+        posts: Vec<Post>,
+        feed_cursor: Option<String>,
+    ) {
+        let tag = format!("profile:{}", profile.did);
+        if let Some(nav_view) = self.visible_page_tagged(&tag) {
+            let page = self.build_profile_page(profile, posts, feed_cursor);
+            page.set_tag(Some(&tag));
+            Self::swap_visible_page(&nav_view, &page);
+        }
+    }
+
+    /// Push a hashtag's page onto the current section's stack.
+    ///
+    /// Returns the page's feed context when a new page went up, for the
+    /// app to fill; a page already open for the tag is popped back to as
+    /// it was. Tags match without regard to case, as the server's do.
+    pub fn push_hashtag_page(&self, tag: &str) -> Option<Rc<HashtagFeedCtx>> {
         let nav_view = self.current_nav_view()?;
 
         self.save_scroll_position();
 
-        // Same treatment as threads and profiles: revisiting pops back
-        // instead of stacking a second copy.
-        let tag = format!("{}:{}", kind.tag_prefix(), profile.did);
-        if let Some(existing) = nav_view.find_page(&tag) {
-            nav_view.pop_to_tag(&tag);
-            // The list is the content box's last child; see the push below.
-            let list = existing
-                .child()
-                .and_then(|content| content.last_child())
-                .and_downcast::<FollowListPage>()?;
-            return Some(FollowListPush::PoppedBack(list));
+        let page_tag = format!("hashtag:{}", tag.to_lowercase());
+        if nav_view.find_page(&page_tag).is_some() {
+            nav_view.pop_to_tag(&page_tag);
+            return None;
         }
 
-        let list = FollowListPage::new(kind);
+        let feed_ctx = Rc::new(HashtagFeedCtx {
+            tag: tag.to_string(),
+            sort: Cell::new(SearchSort::Top),
+            cursor: RefCell::new(None),
+            fetching: Cell::new(false),
+            generation: Cell::new(0),
+            model: gio::ListStore::new::<PostObject>(),
+        });
+        let page = self.build_hashtag_page(&feed_ctx);
+        page.set_tag(Some(&page_tag));
+        Self::push_capped(&nav_view, &page);
+        Some(feed_ctx)
+    }
 
-        // Tapping a listed account opens their profile through the same
-        // route as everywhere else, so cycles dedupe on the profile tag.
+    /// A hashtag's posts under Top and Latest tabs, with the tag's mute
+    /// and pin in the header menu.
+    fn build_hashtag_page(&self, feed_ctx: &Rc<HashtagFeedCtx>) -> adw::NavigationPage {
+        let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        content_box.set_hexpand(true);
+
+        let title_text = format!("#{}", feed_ctx.tag);
+        let header = adw::HeaderBar::new();
+        header.set_show_start_title_buttons(false);
+        header.set_show_end_title_buttons(false);
+
+        let title = gtk4::Label::new(Some(&title_text));
+        title.add_css_class("title");
+        title.set_ellipsize(gtk4::pango::EllipsizeMode::End);
+        header.set_title_widget(Some(&title));
+
+        let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
+        header.pack_end(&window_controls);
+        header.pack_end(&self.build_hashtag_menu(&feed_ctx.tag));
+
+        content_box.append(&header);
+
+        let tabs = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+        tabs.add_css_class("linked");
+        tabs.set_halign(gtk4::Align::Center);
+        tabs.set_margin_top(12);
+        tabs.set_margin_bottom(8);
+        let mut first_tab: Option<gtk4::ToggleButton> = None;
+        for (label, sort) in [("Top", SearchSort::Top), ("Latest", SearchSort::Latest)] {
+            let tab = gtk4::ToggleButton::with_label(label);
+            match &first_tab {
+                Some(first) => tab.set_group(Some(first)),
+                None => first_tab = Some(tab.clone()),
+            }
+            tab.set_active(feed_ctx.sort.get() == sort);
+            let win = self.downgrade();
+            let ctx = feed_ctx.clone();
+            tab.connect_toggled(move |tab| {
+                if !tab.is_active() || ctx.sort.get() == sort {
+                    return;
+                }
+                ctx.sort.set(sort);
+                ctx.begin_refresh();
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().hashtag_tab_callback.borrow().as_ref()
+                {
+                    cb(ctx.clone(), true);
+                }
+            });
+            tabs.append(&tab);
+        }
+        content_box.append(&tabs);
+        content_box.append(&Self::build_feed_empty_label(&feed_ctx.model, false));
+
+        let factory = gtk4::SignalListItemFactory::new();
+        factory.connect_setup(|_, item| {
+            let post_row = PostRow::new();
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() {
+                list_item.set_child(Some(&post_row));
+            }
+        });
+        Self::release_video_on_unbind(&factory);
+
+        let win = self.downgrade();
+        factory.connect_bind(move |_, item| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>()
+                && let Some(post_object) = list_item.item().and_downcast::<PostObject>()
+                && let Some(post) = post_object.post()
+                && let Some(post_row) = list_item.child().and_downcast::<PostRow>()
+            {
+                post_row.bind(&post);
+                post_row.set_list_position(list_item.position());
+                win.wire_post_row(&post_row, &post);
+            }
+        });
+
+        let selection = gtk4::NoSelection::new(Some(feed_ctx.model.clone()));
+        let list_view = gtk4::ListView::new(Some(selection), Some(factory));
+        list_view.add_css_class("background");
+
+        // See `build_timeline` for why the list sits straight in the clamp
+        // and the clamp straight in the scroller.
+        let clamp = adw::ClampScrollable::new();
+        clamp.set_maximum_size(800);
+        clamp.set_tightening_threshold(600);
+        clamp.set_child(Some(&list_view));
+
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_vexpand(true);
+        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled.set_child(Some(&clamp));
+
+        let win = self.downgrade();
+        let ctx = feed_ctx.clone();
+        scrolled.vadjustment().connect_value_changed(move |adj| {
+            let near_bottom = adj.value() >= adj.upper() - adj.page_size() - 400.0;
+            if near_bottom
+                && ctx.cursor.borrow().is_some()
+                && !ctx.fetching.get()
+                && let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().hashtag_tab_callback.borrow().as_ref()
+            {
+                cb(ctx.clone(), false);
+            }
+        });
+        content_box.append(&scrolled);
+
+        adw::NavigationPage::new(&content_box, &title_text)
+    }
+
+    /// Mute and pin for one hashtag. The labels are read fresh each time
+    /// the menu opens: the muted words and saved feeds can change from
+    /// Settings or the feed selector while the page is open.
+    fn build_hashtag_menu(&self, tag: &str) -> gtk4::MenuButton {
+        let popover_box = gtk4::Box::new(gtk4::Orientation::Vertical, 2);
+        popover_box.set_margin_top(6);
+        popover_box.set_margin_bottom(6);
+        popover_box.set_margin_start(6);
+        popover_box.set_margin_end(6);
+
+        let mute_item = gtk4::Button::new();
+        mute_item.add_css_class("flat");
+        popover_box.append(&mute_item);
+        let pin_item = gtk4::Button::new();
+        pin_item.add_css_class("flat");
+        popover_box.append(&pin_item);
+
+        let popover = gtk4::Popover::new();
+        popover.set_child(Some(&popover_box));
+        popover.add_css_class("menu");
+        popover.set_has_arrow(false);
+
+        let win = self.downgrade();
+        let tag_for_sync = tag.to_string();
+        let mute_ref = mute_item.clone();
+        let pin_ref = pin_item.clone();
+        let sync_labels = move || {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            let (muted, pinned) = win.hashtag_state(&tag_for_sync);
+            mute_ref.set_label(&if muted {
+                format!("Unmute #{tag_for_sync}")
+            } else {
+                format!("Mute #{tag_for_sync}")
+            });
+            pin_ref.set_label(if pinned {
+                "Unpin from Feeds"
+            } else {
+                "Pin to Feeds"
+            });
+        };
+        sync_labels();
+        popover.connect_show(move |_| sync_labels());
+
+        // Muting writes a tag-only entry to the account's muted words;
+        // unmuting takes out whichever live entry catches the tag.
+        let win = self.downgrade();
+        let tag_for_mute = tag.to_string();
+        let pop = popover.clone();
+        mute_item.connect_clicked(move |_| {
+            pop.popdown();
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            let now = chrono::Utc::now();
+            let existing =
+                muted_words::word_for_tag(&win.imp().muted_words.borrow(), &tag_for_mute, now)
+                    .cloned();
+            let imp = win.imp();
+            match existing {
+                Some(word) => {
+                    if let Some(cb) = imp.muted_word_remove_callback.borrow().as_ref() {
+                        cb(word);
+                    }
+                }
+                None => {
+                    if let Some(cb) = imp.muted_word_save_callback.borrow().as_ref() {
+                        cb(muted_words::tag_word(&tag_for_mute, now));
+                    }
+                }
+            }
+        });
+
+        let win = self.downgrade();
+        let tag_for_pin = tag.to_string();
+        let pop = popover.clone();
+        pin_item.connect_clicked(move |_| {
+            pop.popdown();
+            if let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().pin_tag_callback.borrow().as_ref()
+            {
+                cb(tag_for_pin.clone());
+            }
+        });
+
+        let menu_btn = gtk4::MenuButton::new();
+        menu_btn.set_icon_name("view-more-symbolic");
+        menu_btn.add_css_class("flat");
+        menu_btn.set_tooltip_text(Some("More options"));
+        menu_btn.update_property(&[gtk4::accessible::Property::Label("More options")]);
+        menu_btn.set_popover(Some(&popover));
+        menu_btn
+    }
+
+    /// Whether `tag` is muted and whether it is pinned as a feed.
+    fn hashtag_state(&self, tag: &str) -> (bool, bool) {
+        let imp = self.imp();
+        let muted =
+            muted_words::word_for_tag(&imp.muted_words.borrow(), tag, chrono::Utc::now()).is_some();
+        let pinned_uri = SavedFeed::hashtag(tag).uri;
+        let pinned = imp
+            .saved_feeds
+            .borrow()
+            .iter()
+            .any(|feed| feed.uri.eq_ignore_ascii_case(&pinned_uri));
+        (muted, pinned)
+    }
+
+    /// The current section's stack, if the page on top is still `tag`. A
+    /// reader who already moved on keeps the cached copy rather than being
+    /// pulled back.
+    fn visible_page_tagged(&self, tag: &str) -> Option<adw::NavigationView> {
+        let nav_view = self.current_nav_view()?;
+        let visible = nav_view.visible_page()?.tag();
+        (visible.as_deref() == Some(tag)).then_some(nav_view)
+    }
+
+    /// Put `page` where the visible page is, with no transition. `replace`
+    /// does not animate and drops the page it leaves out, the same as in
+    /// [`Self::push_capped`].
+    fn swap_visible_page(nav_view: &adw::NavigationView, page: &adw::NavigationPage) {
+        let stack = nav_view.navigation_stack();
+        let depth = stack.n_items();
+        let mut pages: Vec<adw::NavigationPage> = (0..depth.saturating_sub(1))
+            .filter_map(|i| stack.item(i).and_downcast::<adw::NavigationPage>())
+            .collect();
+        pages.push(page.clone());
+        nav_view.replace(&pages);
+    }
+
+    /// Push a followers or following list onto the current section's stack.
+    ///
+    /// Returns the list widget either way; the caller decides whether a
+    /// popped-back page still needs a fetch.
+    pub fn push_follow_list_page(
+        &self,
+        profile: &Profile,
+        kind: FollowListKind,
+    ) -> Option<FollowListPush> {
+        let nav_view = self.current_nav_view()?;
+
+        self.save_scroll_position();
+
+        // Same treatment as threads and profiles: revisiting pops back
+        // instead of stacking a second copy.
+        let tag = format!("{}:{}", kind.tag_prefix(), profile.did);
+        if let Some(existing) = nav_view.find_page(&tag) {
+            nav_view.pop_to_tag(&tag);
+            // The list is the content box's last child; see the push below.
+            let list = existing
+                .child()
+                .and_then(|content| content.last_child())
+                .and_downcast::<FollowListPage>()?;
+            return Some(FollowListPush::PoppedBack(list));
+        }
+
+        let list = FollowListPage::new(kind);
+
+        // Tapping a listed account opens their profile through the same
+        // route as everywhere else, so cycles dedupe on the profile tag.
         let win = self.downgrade();
         list.set_profile_activated_callback(move |profile| {
             let Some(win) = win.upgrade() else {
@@ -2082,6 +3642,7 @@ impl HangarWindow {
             "likes" => "likes",
             "bookmarks" => "bookmarks",
             "search" => "search",
+            "lists" => "lists",
             _ => return,
         };
 
@@ -2101,6 +3662,7 @@ impl HangarWindow {
             "likes" => self.imp().likes_nav_view.borrow().clone(),
             "bookmarks" => self.imp().bookmarks_nav_view.borrow().clone(),
             "search" => self.imp().search_nav_view.borrow().clone(),
+            "lists" => self.imp().lists_nav_view.borrow().clone(),
             _ => self.imp().home_nav_view.borrow().clone(),
         }
     }
@@ -2116,6 +3678,7 @@ impl HangarWindow {
             "likes" => Some("likes"),
             "bookmarks" => Some("bookmarks"),
             "search" => Some("search"),
+            "lists" => Some("lists"),
             // Settings has no NavigationView of its own, and `nav_view_named`
             // falls back to Home's, so a tag here would unwind Home instead.
             _ => None,
@@ -2527,6 +4090,18 @@ impl HangarWindow {
             profile_header.append(&follows_you);
         }
 
+        // A list mute or block has no account-level undo; say where it
+        // comes from instead.
+        if let Some(note) = profile.list_moderation_note() {
+            let note_label = gtk4::Label::new(Some(&note));
+            note_label.add_css_class("dim-label");
+            note_label.add_css_class("caption");
+            note_label.set_halign(gtk4::Align::Center);
+            note_label.set_wrap(true);
+            note_label.set_justify(gtk4::Justification::Center);
+            profile_header.append(&note_label);
+        }
+
         // Follow/unfollow and Message, hidden on your own page. The follow
         // record URI lives in a cell shared with the app; each click hands
         // its button over and goes insensitive until the result comes back.
@@ -2534,8 +4109,18 @@ impl HangarWindow {
         // Shared with the condensed bar's copies of the buttons, so both
         // spots read the same state.
         let follow_uri = Rc::new(RefCell::new(profile.viewer_following.clone()));
-        let muted = Rc::new(Cell::new(profile.viewer_muted));
-        let blocking = Rc::new(RefCell::new(profile.viewer_blocking.clone()));
+        // The menu's mute and block are the account's own. One that came
+        // through a list shows in the note above, and the menu offers to
+        // add an account-level one.
+        let muted = Rc::new(Cell::new(
+            profile.viewer_muted && profile.muted_by_list.is_none(),
+        ));
+        let blocking = Rc::new(RefCell::new(
+            profile
+                .viewer_blocking
+                .clone()
+                .filter(|_| profile.blocking_by_list.is_none()),
+        ));
         if !own_page {
             let buttons_row = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
             buttons_row.set_halign(gtk4::Align::Center);
@@ -2593,6 +4178,7 @@ impl HangarWindow {
         ] {
             stats_row.append(&self.build_follow_stat(profile, kind, count));
         }
+        stats_row.append(&self.build_lists_stat(profile));
         profile_header.append(&stats_row);
 
         // Bio, with links. Wire text: it wraps and caps its line length,
@@ -2617,6 +4203,7 @@ impl HangarWindow {
         // the factory answers with the header widget. Same mechanism as the
         // own profile page; see `build_own_profile_content`.
         let model = gio::ListStore::new::<PostObject>();
+        let empty = Self::build_feed_empty_label(&model, no_posts);
 
         let feed_ctx = Rc::new(ProfileFeedCtx {
             did: RefCell::new(profile.did.clone()),
@@ -2625,10 +4212,14 @@ impl HangarWindow {
             fetching: Cell::new(false),
             generation: Cell::new(0),
             model: model.clone(),
+            empty: empty.clone(),
+            packs: gtk4::ListBox::new(),
+            starter_packs: RefCell::new(Vec::new()),
         });
 
         header_block.append(&self.build_profile_tabs(&feed_ctx));
-        header_block.append(&Self::build_feed_empty_label(&model, no_posts));
+        header_block.append(&empty);
+        header_block.append(&self.build_starter_pack_rows(&feed_ctx));
         let header_marker = gio::ListStore::new::<gtk4::StringObject>();
         header_marker.append(&gtk4::StringObject::new("profile-header"));
         let sections = gio::ListStore::new::<gio::ListStore>();
@@ -3041,6 +4632,7 @@ impl HangarWindow {
 
     /// Set notifications/mentions in the mentions list
     pub fn set_mentions(&self, notifications: Vec<Notification>) {
+        let notifications = self.notifications_without_muted(notifications);
         let empty = notifications.is_empty();
         if let Some(model) = self.imp().mentions_model.borrow().as_ref() {
             model.remove_all();
@@ -3060,6 +4652,7 @@ impl HangarWindow {
 
     /// Append more notifications to the mentions list
     pub fn append_mentions(&self, notifications: Vec<Notification>) {
+        let notifications = self.notifications_without_muted(notifications);
         if let Some(model) = self.imp().mentions_model.borrow().as_ref() {
             for notif in notifications {
                 model.append(&NotificationObject::new(notif));
@@ -3114,8 +4707,10 @@ impl HangarWindow {
         // Add window controls (close, minimize, maximize) to the end
         let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
         header.pack_end(&window_controls);
+        header.pack_end(&self.build_activity_priority_toggle());
 
         content_box.append(&header);
+        content_box.append(&self.build_activity_filters());
         content_box.append(&self.build_activity_list());
 
         let page = adw::NavigationPage::new(&content_box, "Activity");
@@ -3123,6 +4718,101 @@ impl HangarWindow {
         page
     }
 
+    /// The reason chips over the Activity list. Picking one empties the
+    /// list and has the app fetch that reason's own pages.
+    fn build_activity_filters(&self) -> gtk4::ScrolledWindow {
+        let chips = gtk4::Box::new(gtk4::Orientation::Horizontal, 6);
+        chips.set_halign(gtk4::Align::Center);
+        chips.set_margin_start(12);
+        chips.set_margin_end(12);
+        chips.set_margin_top(6);
+        chips.set_margin_bottom(6);
+        let mut first_chip: Option<gtk4::ToggleButton> = None;
+        for filter in ActivityFilter::ALL {
+            let chip = gtk4::ToggleButton::with_label(filter.label());
+            chip.add_css_class("filter-chip");
+            match &first_chip {
+                Some(first) => chip.set_group(Some(first)),
+                None => {
+                    chip.set_active(true);
+                    first_chip = Some(chip.clone());
+                }
+            }
+            let win = self.downgrade();
+            chip.connect_toggled(move |chip| {
+                let Some(win) = win.upgrade() else {
+                    return;
+                };
+                if !chip.is_active() || win.imp().activity_filter.get() == filter {
+                    return;
+                }
+                win.imp().activity_filter.set(filter);
+                win.reload_activity();
+            });
+            chips.append(&chip);
+        }
+
+        // A narrow window scrolls the chips rather than wrapping them.
+        let scroller = gtk4::ScrolledWindow::new();
+        scroller.set_policy(gtk4::PolicyType::Automatic, gtk4::PolicyType::Never);
+        scroller.set_child(Some(&chips));
+        scroller
+    }
+
+    /// "Only people you follow", the server's priority notifications. The
+    /// choice is kept per account, so [`Self::show_activity_page`] reads it
+    /// back: the page is built before anyone signs in.
+    fn build_activity_priority_toggle(&self) -> gtk4::ToggleButton {
+        let toggle = gtk4::ToggleButton::new();
+        toggle.set_icon_name("system-users-symbolic");
+        toggle.add_css_class("flat");
+        toggle.set_tooltip_text(Some("Only People You Follow"));
+        toggle.update_property(&[gtk4::accessible::Property::Label("Only people you follow")]);
+        let win = self.downgrade();
+        toggle.connect_toggled(move |toggle| {
+            let mut settings = crate::state::AppSettings::load();
+            if settings.activity_priority == toggle.is_active() {
+                return;
+            }
+            settings.activity_priority = toggle.is_active();
+            if let Err(e) = settings.save() {
+                eprintln!("Failed to save settings: {e}");
+            }
+            if let Some(win) = win.upgrade() {
+                win.reload_activity();
+            }
+        });
+        self.imp()
+            .activity_priority_toggle
+            .replace(Some(toggle.clone()));
+        toggle
+    }
+
+    /// Start Activity over under a new filter or mode: skeleton rows
+    /// until the app's first page lands.
+    fn reload_activity(&self) {
+        let imp = self.imp();
+        if let Some(model) = imp.activity_model.borrow().as_ref() {
+            model.remove_all();
+        }
+        Self::apply_empty_state(&imp.activity_overlay, &imp.activity_empty_state, false);
+        self.set_activity_loading(true);
+        if let Some(cb) = imp.activity_filter_callback.borrow().as_ref() {
+            cb();
+        }
+    }
+
+    /// The reason chip picked over the Activity list.
+    pub fn activity_filter(&self) -> ActivityFilter {
+        self.imp().activity_filter.get()
+    }
+
+    pub fn set_activity_filter_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp()
+            .activity_filter_callback
+            .replace(Some(Box::new(callback)));
+    }
+
     /// Build the activity list widget
     fn build_activity_list(&self) -> gtk4::Box {
         let activity_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
@@ -3148,10 +4838,11 @@ impl HangarWindow {
             };
             if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>()
                 && let Some(notif_object) = list_item.item().and_downcast::<NotificationObject>()
-                && let Some(notif) = notif_object.notification()
+                && let Some(group) = notif_object.group()
                 && let Some(row) = list_item.child().and_downcast::<ActivityRow>()
             {
-                row.bind(&notif);
+                row.bind(&group);
+                let notif = group.lead;
                 // Connect profile click
                 let profile = notif.author.clone();
                 let w = win.downgrade();
@@ -3269,17 +4960,25 @@ impl HangarWindow {
 
     /// Show the activity page (top-level navigation, instant switch)
     pub fn show_activity_page(&self) {
+        // The mode is this account's; the toggle's handler sees nothing
+        // changed and does not reload.
+        if let Some(toggle) = self.imp().activity_priority_toggle.borrow().as_ref() {
+            toggle.set_active(crate::state::AppSettings::load().activity_priority);
+        }
         self.switch_to_page("activity");
     }
 
-    /// Set notifications in the activity list
+    /// Set notifications in the activity list, likes, reposts and follows
+    /// of one subject folded into one row
     pub fn set_activity(&self, notifications: Vec<Notification>) {
+        let notifications = self.notifications_without_muted(notifications);
         let empty = notifications.is_empty();
         if let Some(model) = self.imp().activity_model.borrow().as_ref() {
-            model.remove_all();
-            for notif in notifications {
-                model.append(&NotificationObject::new(notif));
-            }
+            let objects: Vec<NotificationObject> = notifications::group(notifications)
+                .into_iter()
+                .map(NotificationObject::from_group)
+                .collect();
+            model.splice(0, model.n_items(), &objects);
         }
         let imp = self.imp();
         Self::apply_empty_state(&imp.activity_overlay, &imp.activity_empty_state, empty);
@@ -3291,12 +4990,27 @@ impl HangarWindow {
         }
     }
 
-    /// Append more notifications to the activity list
+    /// Append more notifications to the activity list. The page can carry
+    /// more of the row it lands under, which then grows in place.
     pub fn append_activity(&self, notifications: Vec<Notification>) {
+        let notifications = self.notifications_without_muted(notifications);
         if let Some(model) = self.imp().activity_model.borrow().as_ref() {
-            for notif in notifications {
-                model.append(&NotificationObject::new(notif));
-            }
+            let n = model.n_items();
+            let mut groups: Vec<NotificationGroup> = n
+                .checked_sub(1)
+                .and_then(|last| model.item(last))
+                .and_downcast::<NotificationObject>()
+                .and_then(|last| last.group())
+                .into_iter()
+                .collect();
+            let kept = groups.len();
+            let changed = notifications::fold_into(&mut groups, notifications);
+            let objects: Vec<NotificationObject> = groups
+                .into_iter()
+                .skip(kept - changed)
+                .map(NotificationObject::from_group)
+                .collect();
+            model.splice(n - changed as u32, changed as u32, &objects);
         }
     }
 
@@ -3731,6 +5445,27 @@ impl HangarWindow {
         }
     }
 
+    /// Set callback for when an account row is clicked in the avatar popover
+    pub fn set_switch_account_callback<F: Fn(String) + 'static>(&self, f: F) {
+        if let Some(sidebar) = self.imp().sidebar.borrow().as_ref() {
+            sidebar.connect_switch_account_clicked(f);
+        }
+    }
+
+    /// Set callback for when Add Account is clicked in the avatar popover
+    pub fn set_add_account_callback<F: Fn() + 'static>(&self, f: F) {
+        if let Some(sidebar) = self.imp().sidebar.borrow().as_ref() {
+            sidebar.connect_add_account_clicked(f);
+        }
+    }
+
+    /// Set callback for when an account row's sign-out button is clicked
+    pub fn set_remove_account_callback<F: Fn(String) + 'static>(&self, f: F) {
+        if let Some(sidebar) = self.imp().sidebar.borrow().as_ref() {
+            sidebar.connect_remove_account_clicked(f);
+        }
+    }
+
     /// Build the own profile page (for Profile tab in sidebar)
     fn build_own_profile_page(&self) -> adw::NavigationPage {
         let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
@@ -3806,6 +5541,7 @@ impl HangarWindow {
 
         // The same tabs and context as the drill-down pages. The DID is
         // empty until sign-in; `fetch_profile_posts` fills it.
+        let empty = Self::build_feed_empty_label(&model, true);
         let feed_ctx = Rc::new(ProfileFeedCtx {
             did: RefCell::new(String::new()),
             filter: Cell::new("posts_and_author_threads"),
@@ -3813,9 +5549,13 @@ impl HangarWindow {
             fetching: Cell::new(false),
             generation: Cell::new(0),
             model: model.clone(),
+            empty: empty.clone(),
+            packs: gtk4::ListBox::new(),
+            starter_packs: RefCell::new(Vec::new()),
         });
         header_block.append(&self.build_profile_tabs(&feed_ctx));
-        header_block.append(&Self::build_feed_empty_label(&model, true));
+        header_block.append(&empty);
+        header_block.append(&self.build_starter_pack_rows(&feed_ctx));
         self.imp()
             .own_profile_feed_ctx
             .replace(Some(feed_ctx.clone()));
@@ -4016,6 +5756,17 @@ impl HangarWindow {
         handle_label.set_ellipsize(gtk4::pango::EllipsizeMode::End);
         info_box.append(&handle_label);
 
+        // Label warning, for accounts a labeler has marked
+        let label_warning = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
+        label_warning.add_css_class("moderation-cover");
+        label_warning.set_halign(gtk4::Align::Center);
+        label_warning.set_visible(false);
+        label_warning.append(&gtk4::Image::from_icon_name("dialog-warning-symbolic"));
+        let label_warning_text = gtk4::Label::new(None);
+        label_warning_text.set_wrap(true);
+        label_warning.append(&label_warning_text);
+        info_box.append(&label_warning);
+
         // Bio/description
         let bio_label = gtk4::Label::new(None);
         bio_label.set_wrap(true);
@@ -4109,6 +5860,9 @@ impl HangarWindow {
         imp.profile_bio_label.replace(Some(bio_label));
         imp.profile_banner_picture.replace(Some(banner_picture));
         imp.profile_avatar.replace(Some(avatar));
+        imp.profile_label_warning.replace(Some(label_warning));
+        imp.profile_label_warning_text
+            .replace(Some(label_warning_text));
         imp.profile_followers_label.replace(Some(followers_count));
         imp.profile_following_label.replace(Some(following_count));
         imp.profile_posts_label.replace(Some(posts_count));
@@ -4208,7 +5962,7 @@ impl HangarWindow {
     }
 
     /// Route link activations on a bio label, matching post text: mentions
-    /// open the profile in-app, hashtags do nothing yet, and anything else
+    /// open the profile and hashtags their page, in-app, and anything else
     /// is a real URL for the browser, with a toast either way.
     fn connect_bio_links(&self, label: &gtk4::Label) {
         let win = self.downgrade();
@@ -4220,8 +5974,12 @@ impl HangarWindow {
                     cb(handle.to_string());
                 }
                 glib::Propagation::Stop
-            } else if uri.starts_with("bsky-tag://") {
-                // Styled only, like hashtags in posts.
+            } else if let Some(tag) = uri.strip_prefix("bsky-tag://") {
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().tag_clicked_callback.borrow().as_ref()
+                {
+                    cb(tag.to_string());
+                }
                 glib::Propagation::Stop
             } else {
                 crate::ui::external::open_url(label, uri, "link");
@@ -4237,9 +5995,27 @@ impl HangarWindow {
 
         let display_name = profile.display_name.as_deref().unwrap_or(&profile.handle);
 
+        // The account's own labels. Your own profile is never covered; a
+        // media label keeps the avatar and banner from loading at all.
+        let own = imp.current_user_did.borrow().as_deref() == Some(profile.did.as_str());
+        let decision = if own {
+            None
+        } else {
+            moderation::decide_profile(profile, &imp.moderation_prefs.borrow())
+        };
+        let media_covered = decision.is_some();
+        if let Some(warning) = imp.profile_label_warning.borrow().as_ref() {
+            if let (Some(decision), Some(text)) =
+                (&decision, imp.profile_label_warning_text.borrow().as_ref())
+            {
+                text.set_text(&format!("Labeled: {}", decision.name));
+            }
+            warning.set_visible(decision.is_some());
+        }
+
         // Banner, over the placeholder color when the profile has one
         if let Some(picture) = imp.profile_banner_picture.borrow().as_ref() {
-            match &profile.banner {
+            match profile.banner.as_ref().filter(|_| !media_covered) {
                 Some(url) => {
                     picture.set_visible(true);
                     crate::ui::avatar_cache::load_image_into_picture(picture.clone(), url.clone());
@@ -4271,8 +6047,11 @@ impl HangarWindow {
         // Update avatar
         if let Some(avatar) = imp.profile_avatar.borrow().as_ref() {
             avatar.set_text(Some(display_name));
-            if let Some(url) = &profile.avatar {
+            if let Some(url) = profile.avatar.as_ref().filter(|_| !media_covered) {
                 crate::ui::avatar_cache::load_avatar(avatar.clone(), url.clone());
+            } else if media_covered {
+                // Initials rather than the last profile's picture.
+                avatar.set_custom_image(None::<&gtk4::gdk::Paintable>);
             }
         }
 
@@ -4547,10 +6326,11 @@ impl HangarWindow {
             .replace(Some(Box::new(callback)));
     }
 
-    // ======== Saved Posts Page ========
+    // ======== Lists ========
 
-    /// Build the saved posts page
-    fn build_bookmarks_page(&self) -> adw::NavigationPage {
+    /// The Lists section's root: the user's own lists, with New List in
+    /// the header and in the empty state.
+    fn build_lists_root_page(&self) -> adw::NavigationPage {
         let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
         content_box.set_hexpand(true);
 
@@ -4558,33 +6338,259 @@ impl HangarWindow {
         header.set_show_start_title_buttons(false);
         header.set_show_end_title_buttons(false);
 
-        let title = gtk4::Label::new(Some("Saved"));
+        let title = gtk4::Label::new(Some("Lists"));
         title.add_css_class("title");
         header.set_title_widget(Some(&title));
 
-        // Add window controls (close, minimize, maximize) to the end
         let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
         header.pack_end(&window_controls);
 
+        let new_btn = gtk4::Button::from_icon_name("list-add-symbolic");
+        new_btn.add_css_class("flat");
+        new_btn.set_tooltip_text(Some("New List"));
+        new_btn.update_property(&[gtk4::accessible::Property::Label("New List")]);
+        let win = self.downgrade();
+        new_btn.connect_clicked(move |_| {
+            if let Some(win) = win.upgrade() {
+                win.request_new_list();
+            }
+        });
+        header.pack_start(&new_btn);
+
         content_box.append(&header);
-        content_box.append(&self.build_bookmarks_list());
 
-        let page = adw::NavigationPage::new(&content_box, "Saved");
-        page.set_tag(Some("bookmarks"));
+        let lists = ListsPage::new(true);
+        self.wire_lists_page(&lists);
+        let win = self.downgrade();
+        lists.set_create_callback(move || {
+            if let Some(win) = win.upgrade() {
+                win.request_new_list();
+            }
+        });
+        content_box.append(&lists);
+        self.imp().own_lists_page.replace(Some(lists));
+
+        let page = adw::NavigationPage::new(&content_box, "Lists");
+        page.set_tag(Some("lists"));
         page
     }
 
-    /// Build the saved posts list widget
-    fn build_bookmarks_list(&self) -> gtk4::Box {
-        let bookmarks_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
-        bookmarks_box.set_vexpand(true);
+    /// Picking a list opens it on whichever stack the page of lists is on.
+    fn wire_lists_page(&self, lists: &ListsPage) {
+        let win = self.downgrade();
+        lists.set_list_activated_callback(move |list| {
+            if let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().list_opened_callback.borrow().as_ref()
+            {
+                cb(list);
+            }
+        });
+    }
 
-        let overlay = gtk4::Overlay::new();
-        overlay.set_vexpand(true);
+    fn request_new_list(&self) {
+        if let Some(cb) = self.imp().new_list_callback.borrow().as_ref() {
+            cb();
+        }
+    }
 
-        let model = gio::ListStore::new::<PostObject>();
-        let factory = gtk4::SignalListItemFactory::new();
+    /// Show the Lists section (top-level navigation, instant switch)
+    pub fn show_lists_page(&self) {
+        self.switch_to_page("lists");
+    }
+
+    /// The Lists section's own page of lists, for the app to fill.
+    pub fn own_lists_page(&self) -> Option<ListsPage> {
+        self.imp().own_lists_page.borrow().clone()
+    }
+
+    /// Push someone's lists onto the current section's stack.
+    ///
+    /// Returns the new page for the app to wire and fill; a page already
+    /// open for the account is popped back to as it was.
+    pub fn push_lists_page(&self, profile: &Profile) -> Option<ListsPage> {
+        let nav_view = self.current_nav_view()?;
+
+        self.save_scroll_position();
+
+        let tag = format!("lists:{}", profile.did);
+        if nav_view.find_page(&tag).is_some() {
+            nav_view.pop_to_tag(&tag);
+            return None;
+        }
+
+        let lists = ListsPage::new(false);
+        self.wire_lists_page(&lists);
+
+        let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        content_box.set_hexpand(true);
+
+        let header = adw::HeaderBar::new();
+        header.set_show_start_title_buttons(false);
+        header.set_show_end_title_buttons(false);
+
+        let display_name = profile.display_name.as_deref().unwrap_or(&profile.handle);
+        let title_text = format!("Lists · {display_name}");
+        let title = gtk4::Label::new(Some(&title_text));
+        title.add_css_class("title");
+        title.set_ellipsize(gtk4::pango::EllipsizeMode::End);
+        header.set_title_widget(Some(&title));
+
+        let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
+        header.pack_end(&window_controls);
+
+        content_box.append(&header);
+        content_box.append(&lists);
+
+        let page = adw::NavigationPage::new(&content_box, &title_text);
+        page.set_tag(Some(&tag));
+        Self::push_capped(&nav_view, &page);
+        Some(lists)
+    }
+
+    /// Push a list's page onto the current section's stack.
+    ///
+    /// Returns the page's context when a new page went up, for the app to
+    /// fill; a page already open for the list is popped back to.
+    pub fn push_list_page(&self, list: &UserList) -> Option<Rc<ListFeedCtx>> {
+        let nav_view = self.current_nav_view()?;
+
+        self.save_scroll_position();
+
+        let page_tag = format!("list:{}", list.uri);
+        if nav_view.find_page(&page_tag).is_some() {
+            nav_view.pop_to_tag(&page_tag);
+            return None;
+        }
+
+        let title = gtk4::Label::new(None);
+        title.add_css_class("title");
+        title.set_ellipsize(gtk4::pango::EllipsizeMode::End);
+
+        let summary = gtk4::Label::new(None);
+        summary.add_css_class("dim-label");
+        summary.set_wrap(true);
+        summary.set_justify(gtk4::Justification::Center);
+
+        let description = gtk4::Label::new(None);
+        description.set_wrap(true);
+        description.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
+        description.set_max_width_chars(60);
+        description.set_justify(gtk4::Justification::Center);
+
+        let feed_ctx = Rc::new(ListFeedCtx {
+            list: RefCell::new(list.clone()),
+            tab: Cell::new(if list.purpose.has_feed() {
+                ListTab::Posts
+            } else {
+                ListTab::People
+            }),
+            cursor: RefCell::new(None),
+            fetching: Cell::new(false),
+            generation: Cell::new(0),
+            posts: gio::ListStore::new::<PostObject>(),
+            people: gio::ListStore::new::<ActorObject>(),
+            title,
+            summary,
+            description,
+        });
+        feed_ctx.set_list(list.clone());
+
+        let page = self.build_list_page(&feed_ctx);
+        page.set_tag(Some(&page_tag));
+        Self::push_capped(&nav_view, &page);
+        Some(feed_ctx)
+    }
+
+    /// A list's name, summary and description over its tabs: Posts and
+    /// People for a user list, People alone for the rest, which have no
+    /// feed to show.
+    fn build_list_page(&self, feed_ctx: &Rc<ListFeedCtx>) -> adw::NavigationPage {
+        let list = feed_ctx.list();
+
+        let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        content_box.set_hexpand(true);
+
+        let header = adw::HeaderBar::new();
+        header.set_show_start_title_buttons(false);
+        header.set_show_end_title_buttons(false);
+        header.set_title_widget(Some(&feed_ctx.title));
+
+        let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
+        header.pack_end(&window_controls);
+        if let Some(menu) = self.build_list_menu(feed_ctx) {
+            header.pack_end(&menu);
+        }
+
+        content_box.append(&header);
+
+        let about = gtk4::Box::new(gtk4::Orientation::Vertical, 6);
+        about.set_margin_top(12);
+        about.set_margin_start(12);
+        about.set_margin_end(12);
+        about.append(&feed_ctx.summary);
+        about.append(&feed_ctx.description);
+        content_box.append(&about);
+
+        let stack = gtk4::Stack::new();
+        stack.set_vexpand(true);
+        stack.add_named(&self.build_list_posts(feed_ctx), Some("posts"));
+        stack.add_named(&self.build_list_people(feed_ctx), Some("people"));
+        stack.set_visible_child_name(match feed_ctx.tab.get() {
+            ListTab::Posts => "posts",
+            ListTab::People => "people",
+        });
+
+        if list.purpose.has_feed() {
+            let tabs = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+            tabs.add_css_class("linked");
+            tabs.set_halign(gtk4::Align::Center);
+            tabs.set_margin_top(12);
+            tabs.set_margin_bottom(8);
+            let mut first_tab: Option<gtk4::ToggleButton> = None;
+            for (label, tab_kind, child) in [
+                ("Posts", ListTab::Posts, "posts"),
+                ("People", ListTab::People, "people"),
+            ] {
+                let tab = gtk4::ToggleButton::with_label(label);
+                match &first_tab {
+                    Some(first) => tab.set_group(Some(first)),
+                    None => first_tab = Some(tab.clone()),
+                }
+                tab.set_active(feed_ctx.tab.get() == tab_kind);
+                let win = self.downgrade();
+                let ctx = feed_ctx.clone();
+                let stack = stack.clone();
+                tab.connect_toggled(move |tab| {
+                    if !tab.is_active() || ctx.tab.get() == tab_kind {
+                        return;
+                    }
+                    ctx.tab.set(tab_kind);
+                    ctx.begin_refresh();
+                    stack.set_visible_child_name(child);
+                    if let Some(win) = win.upgrade()
+                        && let Some(cb) = win.imp().list_tab_callback.borrow().as_ref()
+                    {
+                        cb(ctx.clone(), true);
+                    }
+                });
+                tabs.append(&tab);
+            }
+            content_box.append(&tabs);
+        } else {
+            about.set_margin_bottom(8);
+        }
+
+        content_box.append(&stack);
 
+        adw::NavigationPage::new(&content_box, &list.name)
+    }
+
+    /// The Posts tab: the list's feed, built as the hashtag page's is.
+    fn build_list_posts(&self, feed_ctx: &Rc<ListFeedCtx>) -> gtk4::Box {
+        let posts_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        posts_box.append(&Self::build_feed_empty_label(&feed_ctx.posts, false));
+
+        let factory = gtk4::SignalListItemFactory::new();
         factory.connect_setup(|_, item| {
             let post_row = PostRow::new();
             if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() {
@@ -4609,284 +6615,482 @@ impl HangarWindow {
             }
         });
 
-        let selection = gtk4::NoSelection::new(Some(model.clone()));
+        let selection = gtk4::NoSelection::new(Some(feed_ctx.posts.clone()));
         let list_view = gtk4::ListView::new(Some(selection), Some(factory));
         list_view.add_css_class("background");
+        posts_box.append(&self.list_tab_scroller(&list_view, feed_ctx));
+        posts_box
+    }
 
-        // Content width, and the scrollability the list needs to virtualize.
-        // See `build_timeline`. The list has to be this widget's direct child
-        // and this widget the scroller's; a box or overlay between them breaks
-        // scrolling.
+    /// The People tab: who is on the list, each row opening their profile.
+    fn build_list_people(&self, feed_ctx: &Rc<ListFeedCtx>) -> gtk4::Box {
+        let people_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        let none = Self::build_feed_empty_label(&feed_ctx.people, false);
+        none.set_text("Nobody on this list yet");
+        people_box.append(&none);
+
+        let factory = gtk4::SignalListItemFactory::new();
+        factory.connect_setup(|_, item| {
+            let row = ActorRow::new();
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() {
+                list_item.set_child(Some(&row));
+            }
+        });
+
+        let win = self.downgrade();
+        factory.connect_bind(move |_, item| {
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>()
+                && let Some(actor_object) = list_item.item().and_downcast::<ActorObject>()
+                && let Some(profile) = actor_object.profile()
+                && let Some(row) = list_item.child().and_downcast::<ActorRow>()
+            {
+                row.bind(&profile);
+                row.set_bound_object(&actor_object);
+                let w = win.clone();
+                row.set_activated_callback(move |profile| {
+                    if let Some(w) = w.upgrade()
+                        && let Some(cb) = w.imp().profile_clicked_callback.borrow().as_ref()
+                    {
+                        cb(profile);
+                    }
+                });
+            }
+        });
+
+        let selection = gtk4::NoSelection::new(Some(feed_ctx.people.clone()));
+        let list_view = gtk4::ListView::new(Some(selection), Some(factory));
+        list_view.add_css_class("background");
+        people_box.append(&self.list_tab_scroller(&list_view, feed_ctx));
+        people_box
+    }
+
+    /// Clamp and scroller for one tab's list view; near the bottom it asks
+    /// for the showing tab's next page. See `build_timeline` for why the
+    /// chain has nothing between its links.
+    fn list_tab_scroller(
+        &self,
+        list_view: &gtk4::ListView,
+        feed_ctx: &Rc<ListFeedCtx>,
+    ) -> gtk4::ScrolledWindow {
         let clamp = adw::ClampScrollable::new();
         clamp.set_maximum_size(800);
         clamp.set_tightening_threshold(600);
-        clamp.set_child(Some(&list_view));
+        clamp.set_child(Some(list_view));
 
         let scrolled = gtk4::ScrolledWindow::new();
         scrolled.set_vexpand(true);
         scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
         scrolled.set_child(Some(&clamp));
-        overlay.set_child(Some(&scrolled));
-
-        // Loading spinner
-        let spinner = gtk4::Spinner::new();
-        spinner.update_property(&[gtk4::accessible::Property::Label("Loading")]);
-        spinner.set_visible(false);
-        spinner.set_halign(gtk4::Align::Center);
-        spinner.set_valign(gtk4::Align::End);
-        spinner.set_margin_bottom(16);
-        overlay.add_overlay(&spinner);
-
-        // Empty state (shown instead of the overlay when nothing is saved)
-        let empty_state = adw::StatusPage::new();
-        empty_state.set_icon_name(Some("user-bookmarks-symbolic"));
-        empty_state.set_title("Nothing saved yet");
-        empty_state.set_description(Some("Save a post from its menu and it will show up here."));
-        empty_state.set_vexpand(true);
-        empty_state.set_visible(false);
 
-        bookmarks_box.append(&overlay);
-        bookmarks_box.append(&empty_state);
         let win = self.downgrade();
-        let error_state = Self::error_state_page(move || {
-            let Some(win) = win.upgrade() else {
-                return;
-            };
-            if let Some(cb) = win.imp().nav_changed_callback.borrow().as_ref() {
-                cb(crate::ui::sidebar::NavItem::Bookmarks);
+        let ctx = feed_ctx.clone();
+        scrolled.vadjustment().connect_value_changed(move |adj| {
+            let near_bottom = adj.value() >= adj.upper() - adj.page_size() - 400.0;
+            if near_bottom
+                && ctx.cursor.borrow().is_some()
+                && !ctx.fetching.get()
+                && let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().list_tab_callback.borrow().as_ref()
+            {
+                cb(ctx.clone(), false);
             }
         });
-        bookmarks_box.append(&error_state);
-        self.imp().bookmarks_error_state.replace(Some(error_state));
+        scrolled
+    }
+
+    /// Pin for a user list, mute and block for a moderation list, edit
+    /// and delete for one of the user's own, and a starter pack from one
+    /// of the user's own user lists. `None` when none of those
+    /// apply. Toggle labels are read fresh each time, as the hashtag
+    /// menu's are.
+    fn build_list_menu(&self, feed_ctx: &Rc<ListFeedCtx>) -> Option<gtk4::MenuButton> {
+        let list = feed_ctx.list();
+        let own =
+            self.imp().current_user_did.borrow().as_deref() == Some(list.creator.did.as_str());
+        let pinnable = list.purpose.has_feed();
+        let moderation = list.purpose == ListPurpose::Moderation;
+        if !own && !pinnable && !moderation {
+            return None;
+        }
+
+        let popover_box = gtk4::Box::new(gtk4::Orientation::Vertical, 2);
+        popover_box.set_margin_top(6);
+        popover_box.set_margin_bottom(6);
+        popover_box.set_margin_start(6);
+        popover_box.set_margin_end(6);
+
+        let popover = gtk4::Popover::new();
+        popover.set_child(Some(&popover_box));
+        popover.add_css_class("menu");
+        popover.set_has_arrow(false);
+
+        if pinnable {
+            let pin_item = gtk4::Button::new();
+            pin_item.add_css_class("flat");
+            popover_box.append(&pin_item);
 
-        let skeleton = Self::skeleton_list();
-        bookmarks_box.append(&skeleton);
-        self.imp().bookmarks_skeleton.replace(Some(skeleton));
+            let win = self.downgrade();
+            let uri = list.uri.clone();
+            let pin_ref = pin_item.clone();
+            let sync_label = move || {
+                let Some(win) = win.upgrade() else {
+                    return;
+                };
+                pin_ref.set_label(if win.list_pinned(&uri) {
+                    "Unpin from Feeds"
+                } else {
+                    "Pin to Feeds"
+                });
+            };
+            sync_label();
+            popover.connect_show(move |_| sync_label());
 
-        // Store references
-        let imp = self.imp();
-        imp.bookmarks_model.replace(Some(model));
-        imp.bookmarks_scrolled_window
-            .replace(Some(scrolled.clone()));
-        imp.bookmarks_spinner.replace(Some(spinner));
-        imp.bookmarks_overlay.replace(Some(overlay));
-        imp.bookmarks_empty_state.replace(Some(empty_state));
+            let win = self.downgrade();
+            let ctx = feed_ctx.clone();
+            let pop = popover.clone();
+            pin_item.connect_clicked(move |_| {
+                pop.popdown();
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().pin_list_callback.borrow().as_ref()
+                {
+                    cb(ctx.list());
+                }
+            });
+        }
 
-        // Infinite scroll
-        let adj = scrolled.vadjustment();
-        let win = self.downgrade();
-        adj.connect_value_changed(move |adj| {
-            let Some(win) = win.upgrade() else {
-                return;
+        if moderation {
+            let mute_item = gtk4::Button::new();
+            mute_item.add_css_class("flat");
+            popover_box.append(&mute_item);
+            let block_item = gtk4::Button::new();
+            block_item.add_css_class("flat");
+            block_item.add_css_class("destructive-action");
+            popover_box.append(&block_item);
+
+            let ctx = feed_ctx.clone();
+            let mute_ref = mute_item.clone();
+            let block_ref = block_item.clone();
+            let sync_labels = move || {
+                let list = ctx.list();
+                mute_ref.set_label(if list.viewer_muted {
+                    "Unmute List"
+                } else {
+                    "Mute List"
+                });
+                block_ref.set_label(if list.viewer_blocked.is_some() {
+                    "Unblock List"
+                } else {
+                    "Block List..."
+                });
             };
-            let value = adj.value();
-            let upper = adj.upper();
-            let page_size = adj.page_size();
-            if value >= upper - page_size - 200.0 {
-                if let Some(cb) = win.imp().bookmarks_load_more_callback.borrow().as_ref() {
-                    cb();
+            sync_labels();
+            popover.connect_show(move |_| sync_labels());
+
+            let win = self.downgrade();
+            let ctx = feed_ctx.clone();
+            let pop = popover.clone();
+            mute_item.connect_clicked(move |_| {
+                pop.popdown();
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().list_mute_callback.borrow().as_ref()
+                {
+                    cb(ctx.list(), Some(ctx.clone()));
                 }
-            }
-        });
+            });
 
-        bookmarks_box
-    }
+            let win = self.downgrade();
+            let ctx = feed_ctx.clone();
+            let pop = popover.clone();
+            block_item.connect_clicked(move |_| {
+                pop.popdown();
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().list_block_callback.borrow().as_ref()
+                {
+                    cb(ctx.list(), Some(ctx.clone()));
+                }
+            });
+        }
 
-    /// Show the saved posts page (top-level navigation, instant switch)
-    pub fn show_bookmarks_page(&self) {
-        self.switch_to_page("bookmarks");
-    }
+        if own && pinnable {
+            let pack_item = gtk4::Button::with_label("Make Starter Pack...");
+            pack_item.add_css_class("flat");
+            popover_box.append(&pack_item);
 
-    /// Set saved posts in the list, swapping in the empty state when there
-    /// are none
-    pub fn set_bookmarks(&self, posts: Vec<Post>) {
-        let empty = posts.is_empty();
-        if let Some(model) = self.imp().bookmarks_model.borrow().as_ref() {
-            model.remove_all();
-            for post in posts {
-                model.append(&PostObject::new(post));
-            }
-        }
-        if let Some(overlay) = self.imp().bookmarks_overlay.borrow().as_ref() {
-            overlay.set_visible(!empty);
-        }
-        if let Some(empty_state) = self.imp().bookmarks_empty_state.borrow().as_ref() {
-            empty_state.set_visible(empty);
-        }
-        if let Some(err) = self.imp().bookmarks_error_state.borrow().as_ref() {
-            err.set_visible(false);
+            let win = self.downgrade();
+            let ctx = feed_ctx.clone();
+            let pop = popover.clone();
+            pack_item.connect_clicked(move |_| {
+                pop.popdown();
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().make_starter_pack_callback.borrow().as_ref()
+                {
+                    cb(ctx.list());
+                }
+            });
         }
-        if let Some(skeleton) = self.imp().bookmarks_skeleton.borrow().as_ref() {
-            skeleton.set_visible(false);
+
+        if own {
+            let edit_item = gtk4::Button::with_label("Edit List...");
+            edit_item.add_css_class("flat");
+            popover_box.append(&edit_item);
+            let delete_item = gtk4::Button::with_label("Delete List...");
+            delete_item.add_css_class("flat");
+            delete_item.add_css_class("destructive-action");
+            popover_box.append(&delete_item);
+
+            let win = self.downgrade();
+            let ctx = feed_ctx.clone();
+            let pop = popover.clone();
+            edit_item.connect_clicked(move |_| {
+                pop.popdown();
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().edit_list_callback.borrow().as_ref()
+                {
+                    cb(ctx.clone());
+                }
+            });
+
+            let win = self.downgrade();
+            let ctx = feed_ctx.clone();
+            let pop = popover.clone();
+            delete_item.connect_clicked(move |_| {
+                pop.popdown();
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().delete_list_callback.borrow().as_ref()
+                {
+                    cb(ctx.list());
+                }
+            });
         }
+
+        let menu_btn = gtk4::MenuButton::new();
+        menu_btn.set_icon_name("view-more-symbolic");
+        menu_btn.add_css_class("flat");
+        menu_btn.set_tooltip_text(Some("More options"));
+        menu_btn.update_property(&[gtk4::accessible::Property::Label("More options")]);
+        menu_btn.set_popover(Some(&popover));
+        Some(menu_btn)
     }
 
-    /// Append more saved posts to the list
-    pub fn append_bookmarks(&self, posts: Vec<Post>) {
-        if let Some(model) = self.imp().bookmarks_model.borrow().as_ref() {
-            for post in posts {
-                model.append(&PostObject::new(post));
-            }
-        }
+    /// Whether the list's feed is pinned to the feed selector.
+    pub fn list_pinned(&self, uri: &str) -> bool {
+        self.imp()
+            .saved_feeds
+            .borrow()
+            .iter()
+            .any(|feed| feed.is_list() && feed.uri == uri)
     }
 
-    /// Take an unsaved post out of the Saved list right away, if the list is
-    /// showing it. Bringing back the empty state when the last one goes.
-    pub fn remove_bookmark(&self, uri: &str) {
-        let Some(model) = self.imp().bookmarks_model.borrow().clone() else {
-            return;
-        };
-        Self::remove_post_from_store(&model, uri);
-        if model.n_items() == 0 {
-            if let Some(overlay) = self.imp().bookmarks_overlay.borrow().as_ref() {
-                overlay.set_visible(false);
-            }
-            if let Some(empty_state) = self.imp().bookmarks_empty_state.borrow().as_ref() {
-                empty_state.set_visible(true);
+    /// Take a deleted list's page off every stack it is open on, along
+    /// with whatever was pushed over it.
+    pub fn close_list_pages(&self, uri: &str) {
+        let tag = format!("list:{uri}");
+        for section in [
+            "home",
+            "mentions",
+            "activity",
+            "chat",
+            "profile",
+            "likes",
+            "bookmarks",
+            "search",
+            "lists",
+        ] {
+            let Some(nav_view) = self.nav_view_named(section) else {
+                continue;
+            };
+            let stack = nav_view.navigation_stack();
+            let pages: Vec<adw::NavigationPage> = (0..stack.n_items())
+                .filter_map(|i| stack.item(i).and_downcast::<adw::NavigationPage>())
+                .collect();
+            if let Some(at) = pages
+                .iter()
+                .position(|page| page.tag().as_deref() == Some(tag.as_str()))
+                && at > 0
+            {
+                nav_view.replace(&pages[..at]);
             }
         }
     }
 
-    /// Set loading state for the saved posts page
-    pub fn set_bookmarks_loading(&self, loading: bool) {
-        let imp = self.imp();
-        let empty = imp
-            .bookmarks_model
-            .borrow()
-            .as_ref()
-            .is_none_or(|m| m.n_items() == 0);
-        // A first load gets skeleton rows; later pages keep the corner
-        // spinner under the content they extend.
-        if let Some(skeleton) = imp.bookmarks_skeleton.borrow().as_ref() {
-            skeleton.set_visible(loading && empty);
-        }
-        if loading && empty {
-            if let Some(overlay) = imp.bookmarks_overlay.borrow().as_ref() {
-                overlay.set_visible(false);
+    /// A clickable lists stat for a pushed profile page. The profile view
+    /// carries no list count, so it is the word alone.
+    fn build_lists_stat(&self, profile: &Profile) -> gtk4::Box {
+        let win = self.downgrade();
+        let for_lists = profile.clone();
+        let stat_box = Self::clickable_stat_box(move || {
+            if let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().lists_clicked_callback.borrow().as_ref()
+            {
+                cb(for_lists.clone());
             }
-        }
-        if let Some(spinner) = imp.bookmarks_spinner.borrow().as_ref() {
-            spinner.set_visible(loading && !empty);
-            spinner.set_spinning(loading && !empty);
-        }
+        });
+        let noun_label = gtk4::Label::new(Some("lists"));
+        noun_label.add_css_class("dim-label");
+        stat_box.append(&noun_label);
+        stat_box.update_property(&[gtk4::accessible::Property::Label("Lists, opens lists")]);
+        stat_box
     }
 
-    /// Set callback for loading more saved posts
-    pub fn set_bookmarks_load_more_callback<F: Fn() + 'static>(&self, callback: F) {
-        self.imp()
-            .bookmarks_load_more_callback
-            .replace(Some(Box::new(callback)));
+    /// Push a starter pack's page onto the current section's stack.
+    ///
+    /// Returns the page's context when a new page went up, for the app to
+    /// fill; a page already open for the pack is popped back to.
+    pub fn push_starter_pack_page(&self, pack: &StarterPack) -> Option<Rc<StarterPackCtx>> {
+        let nav_view = self.current_nav_view()?;
+
+        self.save_scroll_position();
+
+        let page_tag = format!("starterpack:{}", pack.uri);
+        if nav_view.find_page(&page_tag).is_some() {
+            nav_view.pop_to_tag(&page_tag);
+            return None;
+        }
+
+        let summary = gtk4::Label::new(None);
+        summary.add_css_class("dim-label");
+        summary.set_wrap(true);
+        summary.set_justify(gtk4::Justification::Center);
+
+        let description = gtk4::Label::new(None);
+        description.set_wrap(true);
+        description.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
+        description.set_max_width_chars(60);
+        description.set_justify(gtk4::Justification::Center);
+
+        let feeds = gtk4::ListBox::new();
+        feeds.add_css_class("boxed-list");
+        feeds.set_selection_mode(gtk4::SelectionMode::None);
+
+        let feeds_group = gtk4::Box::new(gtk4::Orientation::Vertical, 6);
+        let feeds_title = gtk4::Label::new(Some("Feeds"));
+        feeds_title.add_css_class("heading");
+        feeds_title.set_halign(gtk4::Align::Start);
+        feeds_group.append(&feeds_title);
+        feeds_group.append(&feeds);
+
+        let follow_all = gtk4::Button::with_label("Follow All");
+        follow_all.add_css_class("pill");
+        follow_all.add_css_class("suggested-action");
+        follow_all.set_halign(gtk4::Align::Center);
+
+        let feed_ctx = Rc::new(StarterPackCtx {
+            pack: RefCell::new(pack.clone()),
+            cursor: RefCell::new(None),
+            fetching: Cell::new(false),
+            generation: Cell::new(0),
+            people: gio::ListStore::new::<ActorObject>(),
+            summary,
+            description,
+            feeds,
+            feeds_group,
+            follow_all,
+        });
+        feed_ctx.set_pack(pack.clone());
+
+        let page = self.build_starter_pack_page(&feed_ctx);
+        page.set_tag(Some(&page_tag));
+        Self::push_capped(&nav_view, &page);
+        Some(feed_ctx)
     }
 
-    // ======== Search Page ========
+    /// A starter pack's summary and Follow All over its feeds and people.
+    /// Feeds open their own pages; people open their profiles.
+    fn build_starter_pack_page(&self, feed_ctx: &Rc<StarterPackCtx>) -> adw::NavigationPage {
+        let pack = feed_ctx.pack();
 
-    fn build_search_page(&self) -> adw::NavigationPage {
         let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
         content_box.set_hexpand(true);
 
         let header = adw::HeaderBar::new();
         header.set_show_start_title_buttons(false);
         header.set_show_end_title_buttons(false);
-
-        let title = gtk4::Label::new(Some("Search"));
+        let title = gtk4::Label::new(Some(&pack.name));
         title.add_css_class("title");
+        title.set_ellipsize(gtk4::pango::EllipsizeMode::End);
         header.set_title_widget(Some(&title));
-
-        // Add window controls (close, minimize, maximize) to the end
         let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
         header.pack_end(&window_controls);
-
         content_box.append(&header);
-        content_box.append(&self.build_search_content());
-
-        let page = adw::NavigationPage::new(&content_box, "Search");
-        page.set_tag(Some("search"));
-        page
-    }
-
-    /// Build the search content (search bar + results list)
-    fn build_search_content(&self) -> gtk4::Box {
-        let search_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
-        search_box.set_vexpand(true);
 
-        // Search entry
-        let search_entry = gtk4::SearchEntry::new();
-        search_entry.set_placeholder_text(Some("Search…"));
-        search_entry.set_margin_start(12);
-        search_entry.set_margin_end(12);
-        search_entry.set_margin_top(12);
-        search_entry.set_margin_bottom(12);
+        let about = gtk4::Box::new(gtk4::Orientation::Vertical, 8);
+        about.set_margin_top(12);
+        about.set_margin_bottom(8);
+        about.set_margin_start(12);
+        about.set_margin_end(12);
+        about.append(&feed_ctx.summary);
+        about.append(&feed_ctx.description);
+        about.append(&feed_ctx.follow_all);
+        about.append(&feed_ctx.feeds_group);
+        content_box.append(&about);
+
+        // Weak: the context owns the button.
+        let win = self.downgrade();
+        let ctx = Rc::downgrade(feed_ctx);
+        feed_ctx.follow_all.connect_clicked(move |_| {
+            if let Some(ctx) = ctx.upgrade()
+                && let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().follow_all_callback.borrow().as_ref()
+            {
+                cb(ctx.clone());
+            }
+        });
 
-        // Connect search activation (Enter key)
         let win = self.downgrade();
-        search_entry.connect_activate(move |entry| {
-            let Some(win) = win.upgrade() else {
+        let ctx = Rc::downgrade(feed_ctx);
+        feed_ctx.feeds.connect_row_activated(move |_, row| {
+            let Some(ctx) = ctx.upgrade() else {
                 return;
             };
-            let query = entry.text().to_string();
-            if !query.is_empty() {
-                if let Some(cb) = win.imp().search_callback.borrow().as_ref() {
-                    cb(query);
-                }
+            let feed = usize::try_from(row.index())
+                .ok()
+                .and_then(|i| ctx.pack.borrow().feeds.get(i).cloned());
+            if let Some(feed) = feed
+                && let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().feed_opened_callback.borrow().as_ref()
+            {
+                cb(feed);
             }
         });
 
-        search_box.append(&search_entry);
-
-        // Store search entry reference
-        self.imp().search_entry.replace(Some(search_entry));
-
-        // One stack page per result kind. Posts and people paginate on
-        // independent cursors, so each keeps its own list and scroller.
-        let results_stack = adw::ViewStack::new();
-        results_stack.set_vexpand(true);
-
-        let switcher = adw::ViewSwitcher::new();
-        switcher.set_policy(adw::ViewSwitcherPolicy::Wide);
-        switcher.set_stack(Some(&results_stack));
-        switcher.set_halign(gtk4::Align::Center);
-        switcher.set_margin_bottom(8);
-        search_box.append(&switcher);
-
-        // Post results list in overlay (for spinner)
-        let overlay = gtk4::Overlay::new();
-        overlay.set_vexpand(true);
+        let none = Self::build_feed_empty_label(&feed_ctx.people, false);
+        none.set_text("Nobody in this starter pack yet");
+        content_box.append(&none);
 
-        let model = gio::ListStore::new::<PostObject>();
         let factory = gtk4::SignalListItemFactory::new();
-
         factory.connect_setup(|_, item| {
-            let post_row = PostRow::new();
+            let row = ActorRow::new();
             if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() {
-                list_item.set_child(Some(&post_row));
+                list_item.set_child(Some(&row));
             }
         });
-        Self::release_video_on_unbind(&factory);
 
         let win = self.downgrade();
         factory.connect_bind(move |_, item| {
-            let Some(win) = win.upgrade() else {
-                return;
-            };
             if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>()
-                && let Some(post_object) = list_item.item().and_downcast::<PostObject>()
-                && let Some(post) = post_object.post()
-                && let Some(post_row) = list_item.child().and_downcast::<PostRow>()
+                && let Some(actor_object) = list_item.item().and_downcast::<ActorObject>()
+                && let Some(profile) = actor_object.profile()
+                && let Some(row) = list_item.child().and_downcast::<ActorRow>()
             {
-                post_row.bind(&post);
-                post_row.set_list_position(list_item.position());
-                win.wire_post_row(&post_row, &post);
+                row.bind(&profile);
+                row.set_bound_object(&actor_object);
+                let w = win.clone();
+                row.set_activated_callback(move |profile| {
+                    if let Some(w) = w.upgrade()
+                        && let Some(cb) = w.imp().profile_clicked_callback.borrow().as_ref()
+                    {
+                        cb(profile);
+                    }
+                });
             }
         });
 
-        let selection = gtk4::NoSelection::new(Some(model.clone()));
+        let selection = gtk4::NoSelection::new(Some(feed_ctx.people.clone()));
         let list_view = gtk4::ListView::new(Some(selection), Some(factory));
         list_view.add_css_class("background");
 
-        // Content width, and the scrollability the list needs to virtualize.
-        // See `build_timeline`. The list has to be this widget's direct child
-        // and this widget the scroller's; a box or overlay between them breaks
-        // scrolling.
+        // See `build_timeline` for why the chain has nothing between its
+        // links.
         let clamp = adw::ClampScrollable::new();
         clamp.set_maximum_size(800);
         clamp.set_tightening_threshold(600);
@@ -4896,75 +7100,406 @@ impl HangarWindow {
         scrolled.set_vexpand(true);
         scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
         scrolled.set_child(Some(&clamp));
-        overlay.set_child(Some(&scrolled));
 
-        // Loading spinner
-        let spinner = gtk4::Spinner::new();
-        spinner.update_property(&[gtk4::accessible::Property::Label("Loading")]);
-        spinner.set_visible(false);
-        spinner.set_halign(gtk4::Align::Center);
-        spinner.set_valign(gtk4::Align::End);
-        spinner.set_margin_bottom(16);
-        overlay.add_overlay(&spinner);
+        let win = self.downgrade();
+        let ctx = feed_ctx.clone();
+        scrolled.vadjustment().connect_value_changed(move |adj| {
+            let near_bottom = adj.value() >= adj.upper() - adj.page_size() - 400.0;
+            if near_bottom
+                && ctx.cursor.borrow().is_some()
+                && !ctx.fetching.get()
+                && let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().starter_pack_members_callback.borrow().as_ref()
+            {
+                cb(ctx.clone(), false);
+            }
+        });
+        content_box.append(&scrolled);
 
-        // Inside the overlay: the stack owns this page, so the empty state
-        // cannot be a sibling the way the other lists have one.
-        let empty_state = Self::empty_state_page(
-            "system-search-symbolic",
-            "No posts found",
-            "Nothing matched this search. Try different words.",
-        );
-        overlay.add_overlay(&empty_state);
-        self.imp().search_empty_state.replace(Some(empty_state));
+        adw::NavigationPage::new(&content_box, &pack.name)
+    }
 
-        results_stack.add_titled_with_icon(&overlay, Some("posts"), "Posts", "view-list-symbolic");
+    /// Read `feed` on the home page, as if it had been picked from the
+    /// feed selector. Whatever was pushed over the timeline goes.
+    fn read_feed_on_home(&self, feed: SavedFeed) {
+        self.show_home_page();
+        self.pop_to_root();
+        self.select_nav(crate::ui::sidebar::NavItem::Home);
+        if let Some(cb) = self.imp().feed_changed_callback.borrow().as_ref() {
+            cb(feed);
+        }
+    }
 
-        // Store references
-        let imp = self.imp();
-        imp.search_model.replace(Some(model));
-        imp.search_scrolled_window.replace(Some(scrolled.clone()));
-        imp.search_spinner.replace(Some(spinner));
+    // ======== Feed Discovery ========
 
-        // Infinite scroll
-        let adj = scrolled.vadjustment();
+    /// Ask the app for Discover Feeds. Settings has no stack of its own,
+    /// so from there the browser goes up over the home timeline.
+    fn request_discover_feeds(&self) {
+        if self.current_nav_view().is_none() {
+            self.show_home_page();
+            self.select_nav(crate::ui::sidebar::NavItem::Home);
+        }
+        if let Some(cb) = self.imp().discover_feeds_callback.borrow().as_ref() {
+            cb();
+        }
+    }
+
+    /// Push Discover Feeds onto the current section's stack.
+    ///
+    /// Returns the new page for the app to wire and fill; one already open
+    /// is popped back to as it was.
+    pub fn push_feed_browser(&self) -> Option<FeedBrowser> {
+        let nav_view = self.current_nav_view()?;
+
+        self.save_scroll_position();
+
+        let tag = "discover-feeds";
+        if nav_view.find_page(tag).is_some() {
+            nav_view.pop_to_tag(tag);
+            return None;
+        }
+
+        let browser = FeedBrowser::new();
         let win = self.downgrade();
-        adj.connect_value_changed(move |adj| {
+        browser.set_feed_activated_callback(move |feed| {
+            if let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().feed_opened_callback.borrow().as_ref()
+            {
+                cb(feed);
+            }
+        });
+
+        let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        content_box.set_hexpand(true);
+
+        let header = adw::HeaderBar::new();
+        header.set_show_start_title_buttons(false);
+        header.set_show_end_title_buttons(false);
+        let title = gtk4::Label::new(Some("Discover Feeds"));
+        title.add_css_class("title");
+        header.set_title_widget(Some(&title));
+        let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
+        header.pack_end(&window_controls);
+
+        content_box.append(&header);
+        content_box.append(&browser);
+
+        let page = adw::NavigationPage::new(&content_box, "Discover Feeds");
+        page.set_tag(Some(tag));
+        Self::push_capped(&nav_view, &page);
+        Some(browser)
+    }
+
+    /// Push a feed generator's page onto the current section's stack.
+    ///
+    /// Returns the page's context when a new page went up, for the app to
+    /// fill; a page already open for the feed is popped back to.
+    pub fn push_feed_page(&self, feed: &FeedGenerator) -> Option<Rc<FeedGeneratorCtx>> {
+        let nav_view = self.current_nav_view()?;
+
+        self.save_scroll_position();
+
+        let page_tag = format!("feed:{}", feed.uri);
+        if nav_view.find_page(&page_tag).is_some() {
+            nav_view.pop_to_tag(&page_tag);
+            return None;
+        }
+
+        let avatar = adw::Avatar::new(96, Some(&feed.display_name), false);
+
+        let creator = gtk4::Button::new();
+        creator.add_css_class("flat");
+        creator.set_halign(gtk4::Align::Center);
+
+        let likes = gtk4::Label::new(None);
+        likes.add_css_class("dim-label");
+
+        let description = gtk4::Label::new(None);
+        description.set_wrap(true);
+        description.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
+        description.set_max_width_chars(60);
+        description.set_justify(gtk4::Justification::Center);
+
+        let like = gtk4::Button::new();
+        like.add_css_class("pill");
+
+        let feed_ctx = Rc::new(FeedGeneratorCtx {
+            feed: RefCell::new(feed.clone()),
+            avatar,
+            creator,
+            likes,
+            description,
+            like,
+        });
+        feed_ctx.set_feed(feed.clone());
+
+        let page = self.build_feed_page(&feed_ctx);
+        page.set_tag(Some(&page_tag));
+        Self::push_capped(&nav_view, &page);
+        Some(feed_ctx)
+    }
+
+    /// A feed's avatar, maker, likes and description, with Read Feed and
+    /// Like under them and saving and pinning in the header menu.
+    fn build_feed_page(&self, feed_ctx: &Rc<FeedGeneratorCtx>) -> adw::NavigationPage {
+        let feed = feed_ctx.feed();
+
+        let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        content_box.set_hexpand(true);
+
+        let header = adw::HeaderBar::new();
+        header.set_show_start_title_buttons(false);
+        header.set_show_end_title_buttons(false);
+        let title = gtk4::Label::new(Some(&feed.display_name));
+        title.add_css_class("title");
+        title.set_ellipsize(gtk4::pango::EllipsizeMode::End);
+        header.set_title_widget(Some(&title));
+        let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
+        header.pack_end(&window_controls);
+        header.pack_end(&self.build_saved_feed_menu(&feed));
+        content_box.append(&header);
+
+        let about = gtk4::Box::new(gtk4::Orientation::Vertical, 8);
+        about.set_margin_top(24);
+        about.set_margin_bottom(24);
+        about.set_margin_start(12);
+        about.set_margin_end(12);
+        about.append(&feed_ctx.avatar);
+        let name = gtk4::Label::new(Some(&feed.display_name));
+        name.add_css_class("title-2");
+        name.set_wrap(true);
+        name.set_justify(gtk4::Justification::Center);
+        about.append(&name);
+        about.append(&feed_ctx.creator);
+        about.append(&feed_ctx.likes);
+        about.append(&feed_ctx.description);
+
+        let actions = gtk4::Box::new(gtk4::Orientation::Horizontal, 12);
+        actions.set_halign(gtk4::Align::Center);
+        actions.set_margin_top(8);
+        let read = gtk4::Button::with_label("Read Feed");
+        read.add_css_class("pill");
+        read.add_css_class("suggested-action");
+        actions.append(&read);
+        actions.append(&feed_ctx.like);
+        about.append(&actions);
+
+        // Weak: the context owns the creator and like buttons.
+        let win = self.downgrade();
+        let ctx = Rc::downgrade(feed_ctx);
+        feed_ctx.creator.connect_clicked(move |_| {
+            if let Some(ctx) = ctx.upgrade()
+                && let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().profile_clicked_callback.borrow().as_ref()
+            {
+                cb(ctx.feed().creator);
+            }
+        });
+
+        let win = self.downgrade();
+        let ctx = Rc::downgrade(feed_ctx);
+        feed_ctx.like.connect_clicked(move |_| {
+            if let Some(ctx) = ctx.upgrade()
+                && let Some(win) = win.upgrade()
+                && let Some(cb) = win.imp().feed_like_callback.borrow().as_ref()
+            {
+                ctx.set_liking(true);
+                cb(ctx.clone());
+            }
+        });
+
+        let win = self.downgrade();
+        let ctx = Rc::downgrade(feed_ctx);
+        read.connect_clicked(move |_| {
+            if let Some(ctx) = ctx.upgrade()
+                && let Some(win) = win.upgrade()
+            {
+                let pinned = win.saved_feed_state(&ctx.feed().uri).unwrap_or(false);
+                win.read_feed_on_home(ctx.feed().as_saved_feed(pinned));
+            }
+        });
+
+        let clamp = adw::Clamp::new();
+        clamp.set_maximum_size(800);
+        clamp.set_tightening_threshold(600);
+        clamp.set_child(Some(&about));
+
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_vexpand(true);
+        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled.set_child(Some(&clamp));
+        content_box.append(&scrolled);
+
+        adw::NavigationPage::new(&content_box, &feed.display_name)
+    }
+
+    /// Save and pin for one feed. As with the hashtag menu, the labels are
+    /// read fresh each time it opens, since Settings can change the saved
+    /// feeds while the page is up.
+    fn build_saved_feed_menu(&self, feed: &FeedGenerator) -> gtk4::MenuButton {
+        let popover_box = gtk4::Box::new(gtk4::Orientation::Vertical, 2);
+        popover_box.set_margin_top(6);
+        popover_box.set_margin_bottom(6);
+        popover_box.set_margin_start(6);
+        popover_box.set_margin_end(6);
+
+        let pin_item = gtk4::Button::new();
+        pin_item.add_css_class("flat");
+        popover_box.append(&pin_item);
+        let save_item = gtk4::Button::new();
+        save_item.add_css_class("flat");
+        popover_box.append(&save_item);
+
+        let popover = gtk4::Popover::new();
+        popover.set_child(Some(&popover_box));
+        popover.add_css_class("menu");
+        popover.set_has_arrow(false);
+
+        let win = self.downgrade();
+        let uri = feed.uri.clone();
+        let pin_ref = pin_item.clone();
+        let save_ref = save_item.clone();
+        let sync_labels = move || {
             let Some(win) = win.upgrade() else {
                 return;
             };
-            let value = adj.value();
-            let upper = adj.upper();
-            let page_size = adj.page_size();
-            if value >= upper - page_size - 200.0 {
-                if let Some(cb) = win.imp().search_load_more_callback.borrow().as_ref() {
-                    cb();
+            let state = win.saved_feed_state(&uri);
+            pin_ref.set_label(if state == Some(true) {
+                "Unpin from Feeds"
+            } else {
+                "Pin to Feeds"
+            });
+            save_ref.set_label(if state.is_some() {
+                "Remove from My Feeds"
+            } else {
+                "Save to My Feeds"
+            });
+        };
+        sync_labels();
+        popover.connect_show(move |_| sync_labels());
+
+        // Pinning an unsaved feed saves it too; unpinning keeps it saved.
+        let win = self.downgrade();
+        let feed_for_pin = feed.clone();
+        let pop = popover.clone();
+        pin_item.connect_clicked(move |_| {
+            pop.popdown();
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            win.edit_saved_feed_list(|feeds| {
+                match feeds.iter_mut().find(|f| f.uri == feed_for_pin.uri) {
+                    Some(saved) => saved.pinned = !saved.pinned,
+                    None => feeds.push(feed_for_pin.as_saved_feed(true)),
                 }
-            }
+            });
         });
 
-        self.build_search_people_page(&results_stack);
-        search_box.append(&results_stack);
+        let win = self.downgrade();
+        let feed_for_save = feed.clone();
+        let pop = popover.clone();
+        save_item.connect_clicked(move |_| {
+            pop.popdown();
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            win.edit_saved_feed_list(|feeds| {
+                let before = feeds.len();
+                feeds.retain(|f| f.uri != feed_for_save.uri);
+                if feeds.len() == before {
+                    feeds.push(feed_for_save.as_saved_feed(false));
+                }
+            });
+            win.rebuild_my_feed_rows();
+        });
 
-        search_box
+        let menu_btn = gtk4::MenuButton::new();
+        menu_btn.set_icon_name("view-more-symbolic");
+        menu_btn.add_css_class("flat");
+        menu_btn.set_tooltip_text(Some("More options"));
+        menu_btn.update_property(&[gtk4::accessible::Property::Label("More options")]);
+        menu_btn.set_popover(Some(&popover));
+        menu_btn
     }
 
-    /// Build the People page of the search results stack.
-    ///
-    /// The same virtualized chain as every feed: scroller over clamp
-    /// scrollable over list view, nothing between. See `build_timeline`.
-    fn build_search_people_page(&self, results_stack: &adw::ViewStack) {
+    /// Whether the account saved the feed at `uri`, and if it did, whether
+    /// it is pinned.
+    fn saved_feed_state(&self, uri: &str) -> Option<bool> {
+        self.imp()
+            .saved_feed_list
+            .borrow()
+            .as_ref()?
+            .iter()
+            .find(|feed| feed.uri == uri)
+            .map(|feed| feed.pinned)
+    }
+
+    /// Edit the saved feed list and hand the result to the app to write.
+    /// The edit shows at once, so a second one builds on the first; if the
+    /// write fails the app reads the list back from the server.
+    fn edit_saved_feed_list(&self, edit: impl FnOnce(&mut Vec<SavedFeed>)) {
+        let Some(mut feeds) = self.imp().saved_feed_list.borrow().clone() else {
+            self.show_toast("Your feeds haven't loaded yet");
+            return;
+        };
+        edit(&mut feeds);
+        self.imp().saved_feed_list.replace(Some(feeds.clone()));
+        if let Some(cb) = self.imp().saved_feed_list_callback.borrow().as_ref() {
+            cb(feeds);
+        }
+    }
+
+    /// Take the account's saved feeds, as read or as just written.
+    pub fn set_saved_feed_list(&self, feeds: Vec<SavedFeed>) {
+        self.imp().saved_feed_list.replace(Some(feeds));
+        self.rebuild_my_feed_rows();
+    }
+
+    // ======== Saved Posts Page ========
+
+    /// Build the saved posts page
+    fn build_bookmarks_page(&self) -> adw::NavigationPage {
+        let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        content_box.set_hexpand(true);
+
+        let header = adw::HeaderBar::new();
+        header.set_show_start_title_buttons(false);
+        header.set_show_end_title_buttons(false);
+
+        let title = gtk4::Label::new(Some("Saved"));
+        title.add_css_class("title");
+        header.set_title_widget(Some(&title));
+
+        // Add window controls (close, minimize, maximize) to the end
+        let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
+        header.pack_end(&window_controls);
+
+        content_box.append(&header);
+        content_box.append(&self.build_bookmarks_list());
+
+        let page = adw::NavigationPage::new(&content_box, "Saved");
+        page.set_tag(Some("bookmarks"));
+        page
+    }
+
+    /// Build the saved posts list widget
+    fn build_bookmarks_list(&self) -> gtk4::Box {
+        let bookmarks_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        bookmarks_box.set_vexpand(true);
+
         let overlay = gtk4::Overlay::new();
         overlay.set_vexpand(true);
 
-        let model = gio::ListStore::new::<ActorObject>();
+        let model = gio::ListStore::new::<PostObject>();
         let factory = gtk4::SignalListItemFactory::new();
 
         factory.connect_setup(|_, item| {
-            let row = ActorRow::new();
+            let post_row = PostRow::new();
             if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() {
-                list_item.set_child(Some(&row));
+                list_item.set_child(Some(&post_row));
             }
         });
+        Self::release_video_on_unbind(&factory);
 
         let win = self.downgrade();
         factory.connect_bind(move |_, item| {
@@ -4972,24 +7507,13 @@ impl HangarWindow {
                 return;
             };
             if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>()
-                && let Some(actor_object) = list_item.item().and_downcast::<ActorObject>()
-                && let Some(profile) = actor_object.profile()
-                && let Some(row) = list_item.child().and_downcast::<ActorRow>()
+                && let Some(post_object) = list_item.item().and_downcast::<PostObject>()
+                && let Some(post) = post_object.post()
+                && let Some(post_row) = list_item.child().and_downcast::<PostRow>()
             {
-                row.bind(&profile);
-                row.set_bound_object(&actor_object);
-                // Open the profile in-app on click
-                let w = win.downgrade();
-                row.set_activated_callback(move |profile| {
-                    let Some(w) = w.upgrade() else {
-                        return;
-                    };
-                    w.imp()
-                        .profile_clicked_callback
-                        .borrow()
-                        .as_ref()
-                        .map(|cb| cb(profile));
-                });
+                post_row.bind(&post);
+                post_row.set_list_position(list_item.position());
+                win.wire_post_row(&post_row, &post);
             }
         });
 
@@ -4997,6 +7521,10 @@ impl HangarWindow {
         let list_view = gtk4::ListView::new(Some(selection), Some(factory));
         list_view.add_css_class("background");
 
+        // Content width, and the scrollability the list needs to virtualize.
+        // See `build_timeline`. The list has to be this widget's direct child
+        // and this widget the scroller's; a box or overlay between them breaks
+        // scrolling.
         let clamp = adw::ClampScrollable::new();
         clamp.set_maximum_size(800);
         clamp.set_tightening_threshold(600);
@@ -5017,11 +7545,40 @@ impl HangarWindow {
         spinner.set_margin_bottom(16);
         overlay.add_overlay(&spinner);
 
+        // Empty state (shown instead of the overlay when nothing is saved)
+        let empty_state = adw::StatusPage::new();
+        empty_state.set_icon_name(Some("user-bookmarks-symbolic"));
+        empty_state.set_title("Nothing saved yet");
+        empty_state.set_description(Some("Save a post from its menu and it will show up here."));
+        empty_state.set_vexpand(true);
+        empty_state.set_visible(false);
+
+        bookmarks_box.append(&overlay);
+        bookmarks_box.append(&empty_state);
+        let win = self.downgrade();
+        let error_state = Self::error_state_page(move || {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            if let Some(cb) = win.imp().nav_changed_callback.borrow().as_ref() {
+                cb(crate::ui::sidebar::NavItem::Bookmarks);
+            }
+        });
+        bookmarks_box.append(&error_state);
+        self.imp().bookmarks_error_state.replace(Some(error_state));
+
+        let skeleton = Self::skeleton_list();
+        bookmarks_box.append(&skeleton);
+        self.imp().bookmarks_skeleton.replace(Some(skeleton));
+
+        // Store references
         let imp = self.imp();
-        imp.search_people_model.replace(Some(model));
-        imp.search_people_scrolled_window
+        imp.bookmarks_model.replace(Some(model));
+        imp.bookmarks_scrolled_window
             .replace(Some(scrolled.clone()));
-        imp.search_people_spinner.replace(Some(spinner));
+        imp.bookmarks_spinner.replace(Some(spinner));
+        imp.bookmarks_overlay.replace(Some(overlay));
+        imp.bookmarks_empty_state.replace(Some(empty_state));
 
         // Infinite scroll
         let adj = scrolled.vadjustment();
@@ -5034,1068 +7591,3110 @@ impl HangarWindow {
             let upper = adj.upper();
             let page_size = adj.page_size();
             if value >= upper - page_size - 200.0 {
-                if let Some(cb) = win.imp().search_people_load_more_callback.borrow().as_ref() {
+                if let Some(cb) = win.imp().bookmarks_load_more_callback.borrow().as_ref() {
                     cb();
                 }
             }
         });
 
-        // Same overlay placement as the posts page; see there.
-        let empty_state = Self::empty_state_page(
-            "system-search-symbolic",
-            "No people found",
-            "Nothing matched this search. Try a name or handle.",
-        );
+        bookmarks_box
+    }
 
-        // Suggestions fill the dead end when the network has some.
-        let suggestions_column = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
-        suggestions_column.set_visible(false);
-        let suggestions_title = gtk4::Label::new(Some("Suggested accounts"));
-        suggestions_title.add_css_class("heading");
-        suggestions_title.set_halign(gtk4::Align::Start);
-        suggestions_title.set_margin_bottom(4);
-        suggestions_column.append(&suggestions_title);
-        let suggestions_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
-        suggestions_column.append(&suggestions_box);
-        empty_state.set_child(Some(&suggestions_column));
-
-        overlay.add_overlay(&empty_state);
-        self.imp()
-            .search_people_empty_state
-            .replace(Some(empty_state));
-        self.imp()
-            .search_suggestions_box
-            .replace(Some(suggestions_box));
-
-        results_stack.add_titled_with_icon(
-            &overlay,
-            Some("people"),
-            "People",
-            "system-users-symbolic",
-        );
-    }
-
-    /// Show the search page (top-level navigation, instant switch)
-    pub fn show_search_page(&self) {
-        self.switch_to_page("search");
+    /// Show the saved posts page (top-level navigation, instant switch)
+    pub fn show_bookmarks_page(&self) {
+        self.switch_to_page("bookmarks");
     }
 
-    /// Set search results in the search list
-    pub fn set_search_results(&self, posts: Vec<Post>) {
+    /// Set saved posts in the list, swapping in the empty state when there
+    /// are none
+    pub fn set_bookmarks(&self, posts: Vec<Post>) {
         let empty = posts.is_empty();
-        if let Some(model) = self.imp().search_model.borrow().as_ref() {
+        if let Some(model) = self.imp().bookmarks_model.borrow().as_ref() {
             model.remove_all();
             for post in posts {
                 model.append(&PostObject::new(post));
             }
         }
-        // Only a completed search lands here, so empty means no results,
-        // not no search yet.
-        if let Some(page) = self.imp().search_empty_state.borrow().as_ref() {
-            page.set_visible(empty);
+        if let Some(overlay) = self.imp().bookmarks_overlay.borrow().as_ref() {
+            overlay.set_visible(!empty);
+        }
+        if let Some(empty_state) = self.imp().bookmarks_empty_state.borrow().as_ref() {
+            empty_state.set_visible(empty);
+        }
+        if let Some(err) = self.imp().bookmarks_error_state.borrow().as_ref() {
+            err.set_visible(false);
+        }
+        if let Some(skeleton) = self.imp().bookmarks_skeleton.borrow().as_ref() {
+            skeleton.set_visible(false);
         }
     }
 
-    /// Append more search results to the list
-    pub fn append_search_results(&self, posts: Vec<Post>) {
-        if let Some(model) = self.imp().search_model.borrow().as_ref() {
+    /// Append more saved posts to the list
+    pub fn append_bookmarks(&self, posts: Vec<Post>) {
+        if let Some(model) = self.imp().bookmarks_model.borrow().as_ref() {
             for post in posts {
                 model.append(&PostObject::new(post));
             }
         }
     }
 
-    /// Set loading state for search page
-    pub fn set_search_loading(&self, loading: bool) {
-        if let Some(spinner) = self.imp().search_spinner.borrow().as_ref() {
-            spinner.set_visible(loading);
-            spinner.set_spinning(loading);
-        }
-    }
-
-    /// Set callback for loading more search results
-    pub fn set_search_load_more_callback<F: Fn() + 'static>(&self, callback: F) {
-        self.imp()
-            .search_load_more_callback
-            .replace(Some(Box::new(callback)));
-    }
-
-    /// Set callback for when a search is submitted
-    pub fn set_search_callback<F: Fn(String) + 'static>(&self, callback: F) {
-        self.imp().search_callback.replace(Some(Box::new(callback)));
-    }
-
-    /// Set people results in the search list
-    pub fn set_search_people_results(&self, profiles: Vec<Profile>) {
-        let empty = profiles.is_empty();
-        if let Some(model) = self.imp().search_people_model.borrow().as_ref() {
-            model.remove_all();
-            for profile in profiles {
-                model.append(&ActorObject::new(profile));
+    /// Take an unsaved post out of the Saved list right away, if the list is
+    /// showing it. Bringing back the empty state when the last one goes.
+    pub fn remove_bookmark(&self, uri: &str) {
+        let Some(model) = self.imp().bookmarks_model.borrow().clone() else {
+            return;
+        };
+        Self::remove_post_from_store(&model, uri);
+        if model.n_items() == 0 {
+            if let Some(overlay) = self.imp().bookmarks_overlay.borrow().as_ref() {
+                overlay.set_visible(false);
+            }
+            if let Some(empty_state) = self.imp().bookmarks_empty_state.borrow().as_ref() {
+                empty_state.set_visible(true);
             }
-        }
-        // See set_search_results.
-        if let Some(page) = self.imp().search_people_empty_state.borrow().as_ref() {
-            page.set_visible(empty);
         }
     }
 
-    /// Append more people results to the list
-    pub fn append_search_people_results(&self, profiles: Vec<Profile>) {
-        if let Some(model) = self.imp().search_people_model.borrow().as_ref() {
-            for profile in profiles {
-                model.append(&ActorObject::new(profile));
+    /// Set loading state for the saved posts page
+    pub fn set_bookmarks_loading(&self, loading: bool) {
+        let imp = self.imp();
+        let empty = imp
+            .bookmarks_model
+            .borrow()
+            .as_ref()
+            .is_none_or(|m| m.n_items() == 0);
+        // A first load gets skeleton rows; later pages keep the corner
+        // spinner under the content they extend.
+        if let Some(skeleton) = imp.bookmarks_skeleton.borrow().as_ref() {
+            skeleton.set_visible(loading && empty);
+        }
+        if loading && empty {
+            if let Some(overlay) = imp.bookmarks_overlay.borrow().as_ref() {
+                overlay.set_visible(false);
             }
         }
-    }
-
-    /// Set loading state for the people page of search
-    pub fn set_search_people_loading(&self, loading: bool) {
-        if let Some(spinner) = self.imp().search_people_spinner.borrow().as_ref() {
-            spinner.set_visible(loading);
-            spinner.set_spinning(loading);
+        if let Some(spinner) = imp.bookmarks_spinner.borrow().as_ref() {
+            spinner.set_visible(loading && !empty);
+            spinner.set_spinning(loading && !empty);
         }
     }
 
-    /// Set callback for loading more people results
-    pub fn set_search_people_load_more_callback<F: Fn() + 'static>(&self, callback: F) {
+    /// Set callback for loading more saved posts
+    pub fn set_bookmarks_load_more_callback<F: Fn() + 'static>(&self, callback: F) {
         self.imp()
-            .search_people_load_more_callback
+            .bookmarks_load_more_callback
             .replace(Some(Box::new(callback)));
     }
 
-    /// Clear search results, both pages
-    pub fn clear_search_results(&self) {
-        if let Some(model) = self.imp().search_model.borrow().as_ref() {
-            model.remove_all();
-        }
-        if let Some(model) = self.imp().search_people_model.borrow().as_ref() {
-            model.remove_all();
-        }
-        // A fresh search is not a search with no results.
-        if let Some(page) = self.imp().search_empty_state.borrow().as_ref() {
-            page.set_visible(false);
-        }
-        if let Some(page) = self.imp().search_people_empty_state.borrow().as_ref() {
-            page.set_visible(false);
-        }
-        self.set_search_suggestions(vec![]);
-    }
+    // ======== Search Page ========
 
-    /// The New Message picker: type a name, pick a person, chat.
-    fn build_new_message_dialog(&self) -> adw::Dialog {
-        let content = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+    fn build_search_page(&self) -> adw::NavigationPage {
+        let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        content_box.set_hexpand(true);
 
         let header = adw::HeaderBar::new();
-        let title = gtk4::Label::new(Some("New Message"));
+        header.set_show_start_title_buttons(false);
+        header.set_show_end_title_buttons(false);
+
+        let title = gtk4::Label::new(Some("Search"));
         title.add_css_class("title");
         header.set_title_widget(Some(&title));
-        content.append(&header);
 
-        let entry = gtk4::SearchEntry::new();
-        entry.set_placeholder_text(Some("Search people"));
-        entry.set_margin_start(12);
-        entry.set_margin_end(12);
-        entry.set_margin_top(8);
-        entry.set_margin_bottom(8);
+        // Add window controls (close, minimize, maximize) to the end
+        let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
+        header.pack_end(&window_controls);
+
+        content_box.append(&header);
+        content_box.append(&self.build_search_content());
+
+        let page = adw::NavigationPage::new(&content_box, "Search");
+        page.set_tag(Some("search"));
+        page
+    }
+
+    /// Build the search content (search bar + results list)
+    fn build_search_content(&self) -> gtk4::Box {
+        let search_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        search_box.set_vexpand(true);
+
+        // Search entry
+        let search_entry = gtk4::SearchEntry::new();
+        search_entry.set_placeholder_text(Some("Search…"));
+        search_entry.set_hexpand(true);
+
+        // Connect search activation (Enter key)
         let win = self.downgrade();
-        entry.connect_search_changed(move |entry| {
+        search_entry.connect_activate(move |entry| {
             let Some(win) = win.upgrade() else {
                 return;
             };
-            if let Some(cb) = win.imp().new_message_query_callback.borrow().as_ref() {
-                cb(entry.text().to_string());
+            let query = entry.text().to_string();
+            if !query.is_empty() {
+                if let Some(cb) = win.imp().search_callback.borrow().as_ref() {
+                    cb(query);
+                }
+            } else if let Some(cb) = win.imp().history_search_callback.borrow().as_ref() {
+                // No words still leaves the History filters to search by.
+                cb();
             }
         });
-        content.append(&entry);
 
-        let results = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
-        let scrolled = gtk4::ScrolledWindow::new();
-        scrolled.set_vexpand(true);
-        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
-        scrolled.set_child(Some(&results));
-        content.append(&scrolled);
+        let entry_row = gtk4::Box::new(gtk4::Orientation::Horizontal, 6);
+        entry_row.set_margin_start(12);
+        entry_row.set_margin_end(12);
+        entry_row.set_margin_top(12);
+        entry_row.set_margin_bottom(12);
+        entry_row.append(&search_entry);
+        search_box.append(&entry_row);
 
-        self.imp().new_message_results_box.replace(Some(results));
+        // Store search entry reference
+        self.imp().search_entry.replace(Some(search_entry));
+        entry_row.append(&self.build_search_filter_button());
 
-        let dialog = adw::Dialog::builder()
-            .title("New Message")
-            .content_width(420)
-            .content_height(480)
-            .child(&content)
-            .build();
-        // Stale state from the last opening would flash otherwise.
-        self.imp().new_message_dialog.replace(Some(dialog.clone()));
-        dialog
-    }
+        // Keep the search as a feed. Nothing to keep until one has run.
+        let save_button = gtk4::Button::from_icon_name("non-starred-symbolic");
+        save_button.set_tooltip_text(Some("Save search as a feed"));
+        save_button.set_sensitive(false);
+        let win = self.downgrade();
+        save_button.connect_clicked(move |_| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            let input = win
+                .imp()
+                .search_entry
+                .borrow()
+                .as_ref()
+                .map(|entry| entry.text().to_string())
+                .unwrap_or_default();
+            if let Some(cb) = win.imp().save_search_callback.borrow().as_ref() {
+                cb(input);
+            }
+        });
+        entry_row.append(&save_button);
+        self.imp().save_search_button.replace(Some(save_button));
+
+        let chips = gtk4::FlowBox::new();
+        chips.set_selection_mode(gtk4::SelectionMode::None);
+        chips.set_column_spacing(6);
+        chips.set_row_spacing(6);
+        chips.set_margin_start(12);
+        chips.set_margin_end(12);
+        chips.set_margin_bottom(8);
+        chips.set_visible(false);
+        search_box.append(&chips);
+        self.imp().search_chips.replace(Some(chips));
 
-    pub fn present_new_message_dialog(&self) {
-        let dialog = self.build_new_message_dialog();
-        dialog.present(Some(self));
-    }
+        // One stack page per result kind. Posts and people paginate on
+        // independent cursors, so each keeps its own list and scroller.
+        let results_stack = adw::ViewStack::new();
+        results_stack.set_vexpand(true);
 
-    /// People matching the picker's query; a row click hands the person to
-    /// the chosen callback and the dialog steps aside.
-    pub fn set_new_message_results(&self, profiles: Vec<Profile>) {
-        let Some(rows) = self.imp().new_message_results_box.borrow().clone() else {
-            return;
-        };
-        while let Some(child) = rows.first_child() {
-            rows.remove(&child);
-        }
-        let win = self.downgrade();
-        for profile in profiles {
-            let row = ActorRow::new();
-            row.bind(&profile);
-            let w = win.clone();
-            row.set_activated_callback(move |profile| {
-                let Some(w) = w.upgrade() else {
-                    return;
-                };
-                if let Some(dialog) = w.imp().new_message_dialog.borrow().as_ref() {
-                    dialog.close();
-                }
-                if let Some(cb) = w.imp().new_message_chosen_callback.borrow().as_ref() {
-                    cb(profile);
-                }
-            });
-            rows.append(&row);
-        }
-    }
+        let switcher = adw::ViewSwitcher::new();
+        switcher.set_policy(adw::ViewSwitcherPolicy::Wide);
+        switcher.set_stack(Some(&results_stack));
+        switcher.set_halign(gtk4::Align::Center);
+        switcher.set_margin_bottom(8);
+        search_box.append(&switcher);
 
-    pub fn set_new_message_query_callback<F: Fn(String) + 'static>(&self, callback: F) {
-        self.imp()
-            .new_message_query_callback
-            .replace(Some(Box::new(callback)));
-    }
+        // Post results list in overlay (for spinner)
+        let overlay = gtk4::Overlay::new();
+        overlay.set_vexpand(true);
 
-    pub fn set_new_message_chosen_callback<F: Fn(Profile) + 'static>(&self, callback: F) {
-        self.imp()
-            .new_message_chosen_callback
-            .replace(Some(Box::new(callback)));
-    }
+        let model = gio::ListStore::new::<PostObject>();
+        let factory = gtk4::SignalListItemFactory::new();
+
+        factory.connect_setup(|_, item| {
+            let post_row = PostRow::new();
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() {
+                list_item.set_child(Some(&post_row));
+            }
+        });
+        Self::release_video_on_unbind(&factory);
 
-    /// Fill the empty people search with suggested accounts. An empty list
-    /// clears them, leaving the plain no-results page.
-    pub fn set_search_suggestions(&self, profiles: Vec<Profile>) {
-        let Some(rows) = self.imp().search_suggestions_box.borrow().clone() else {
-            return;
-        };
-        while let Some(child) = rows.first_child() {
-            rows.remove(&child);
-        }
-        if let Some(column) = rows.parent() {
-            column.set_visible(!profiles.is_empty());
-        }
         let win = self.downgrade();
-        for profile in profiles {
-            let row = ActorRow::new();
-            row.bind(&profile);
-            let w = win.clone();
-            row.set_activated_callback(move |profile| {
-                let Some(w) = w.upgrade() else {
-                    return;
-                };
-                if let Some(cb) = w.imp().profile_clicked_callback.borrow().as_ref() {
-                    cb(profile);
-                }
-            });
-            rows.append(&row);
-        }
-    }
+        factory.connect_bind(move |_, item| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>()
+                && let Some(post_object) = list_item.item().and_downcast::<PostObject>()
+                && let Some(post) = post_object.post()
+                && let Some(post_row) = list_item.child().and_downcast::<PostRow>()
+            {
+                post_row.bind(&post);
+                post_row.set_list_position(list_item.position());
+                win.wire_post_row(&post_row, &post);
+            }
+        });
 
-    /// Focus the search entry
-    pub fn focus_search_entry(&self) {
-        if let Some(entry) = self.imp().search_entry.borrow().as_ref() {
-            entry.grab_focus();
-        }
-    }
+        let selection = gtk4::NoSelection::new(Some(model.clone()));
+        let list_view = gtk4::ListView::new(Some(selection), Some(factory));
+        list_view.add_css_class("background");
 
-    /// One `set_X_load_failed` per rail feed; see `apply_load_failed`.
-    pub fn set_timeline_load_failed(&self) {
-        let imp = self.imp();
-        let empty = imp
-            .timeline_model
-            .borrow()
-            .as_ref()
-            .is_none_or(|m| m.n_items() == 0);
-        Self::apply_load_failed(
-            empty,
-            &imp.timeline_overlay,
-            &imp.timeline_empty_state,
-            &imp.timeline_error_state,
-            &imp.timeline_skeleton,
-        );
-    }
+        // Content width, and the scrollability the list needs to virtualize.
+        // See `build_timeline`. The list has to be this widget's direct child
+        // and this widget the scroller's; a box or overlay between them breaks
+        // scrolling.
+        let clamp = adw::ClampScrollable::new();
+        clamp.set_maximum_size(800);
+        clamp.set_tightening_threshold(600);
+        clamp.set_child(Some(&list_view));
 
-    pub fn set_mentions_load_failed(&self) {
-        let imp = self.imp();
-        let empty = imp
-            .mentions_model
-            .borrow()
-            .as_ref()
-            .is_none_or(|m| m.n_items() == 0);
-        Self::apply_load_failed(
-            empty,
-            &imp.mentions_overlay,
-            &imp.mentions_empty_state,
-            &imp.mentions_error_state,
-            &imp.mentions_skeleton,
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_vexpand(true);
+        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled.set_child(Some(&clamp));
+        overlay.set_child(Some(&scrolled));
+
+        // Loading spinner
+        let spinner = gtk4::Spinner::new();
+        spinner.update_property(&[gtk4::accessible::Property::Label("Loading")]);
+        spinner.set_visible(false);
+        spinner.set_halign(gtk4::Align::Center);
+        spinner.set_valign(gtk4::Align::End);
+        spinner.set_margin_bottom(16);
+        overlay.add_overlay(&spinner);
+
+        // Inside the overlay: the stack owns this page, so the empty state
+        // cannot be a sibling the way the other lists have one.
+        let empty_state = Self::empty_state_page(
+            "system-search-symbolic",
+            "No posts found",
+            "Nothing matched this search. Try different words.",
         );
-    }
+        overlay.add_overlay(&empty_state);
+        self.imp().search_empty_state.replace(Some(empty_state));
 
-    pub fn set_activity_load_failed(&self) {
+        results_stack.add_titled_with_icon(&overlay, Some("posts"), "Posts", "view-list-symbolic");
+
+        // Store references
         let imp = self.imp();
-        let empty = imp
-            .activity_model
-            .borrow()
-            .as_ref()
-            .is_none_or(|m| m.n_items() == 0);
-        Self::apply_load_failed(
-            empty,
-            &imp.activity_overlay,
-            &imp.activity_empty_state,
-            &imp.activity_error_state,
-            &imp.activity_skeleton,
-        );
+        imp.search_model.replace(Some(model));
+        imp.search_scrolled_window.replace(Some(scrolled.clone()));
+        imp.search_spinner.replace(Some(spinner));
+
+        // Infinite scroll
+        let adj = scrolled.vadjustment();
+        let win = self.downgrade();
+        adj.connect_value_changed(move |adj| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            let value = adj.value();
+            let upper = adj.upper();
+            let page_size = adj.page_size();
+            if value >= upper - page_size - 200.0 {
+                if let Some(cb) = win.imp().search_load_more_callback.borrow().as_ref() {
+                    cb();
+                }
+            }
+        });
+
+        self.build_search_people_page(&results_stack);
+        self.build_search_history_page(&results_stack);
+        search_box.append(&results_stack);
+
+        search_box
     }
 
-    pub fn set_likes_load_failed(&self) {
-        let imp = self.imp();
-        let empty = imp
-            .likes_model
-            .borrow()
-            .as_ref()
-            .is_none_or(|m| m.n_items() == 0);
-        Self::apply_load_failed(
-            empty,
-            &imp.likes_overlay,
-            &imp.likes_empty_state,
-            &imp.likes_error_state,
-            &imp.likes_skeleton,
-        );
-    }
+    /// Build the People page of the search results stack.
+    ///
+    /// The same virtualized chain as every feed: scroller over clamp
+    /// scrollable over list view, nothing between. See `build_timeline`.
+    fn build_search_people_page(&self, results_stack: &adw::ViewStack) {
+        let overlay = gtk4::Overlay::new();
+        overlay.set_vexpand(true);
+
+        let model = gio::ListStore::new::<ActorObject>();
+        let factory = gtk4::SignalListItemFactory::new();
+
+        factory.connect_setup(|_, item| {
+            let row = ActorRow::new();
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() {
+                list_item.set_child(Some(&row));
+            }
+        });
+
+        let win = self.downgrade();
+        factory.connect_bind(move |_, item| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>()
+                && let Some(actor_object) = list_item.item().and_downcast::<ActorObject>()
+                && let Some(profile) = actor_object.profile()
+                && let Some(row) = list_item.child().and_downcast::<ActorRow>()
+            {
+                row.bind(&profile);
+                row.set_bound_object(&actor_object);
+                // Open the profile in-app on click
+                let w = win.downgrade();
+                row.set_activated_callback(move |profile| {
+                    let Some(w) = w.upgrade() else {
+                        return;
+                    };
+                    w.imp()
+                        .profile_clicked_callback
+                        .borrow()
+                        .as_ref()
+                        .map(|cb| cb(profile));
+                });
+            }
+        });
+
+        let selection = gtk4::NoSelection::new(Some(model.clone()));
+        let list_view = gtk4::ListView::new(Some(selection), Some(factory));
+        list_view.add_css_class("background");
+
+        let clamp = adw::ClampScrollable::new();
+        clamp.set_maximum_size(800);
+        clamp.set_tightening_threshold(600);
+        clamp.set_child(Some(&list_view));
+
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_vexpand(true);
+        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled.set_child(Some(&clamp));
+        overlay.set_child(Some(&scrolled));
+
+        // Loading spinner
+        let spinner = gtk4::Spinner::new();
+        spinner.update_property(&[gtk4::accessible::Property::Label("Loading")]);
+        spinner.set_visible(false);
+        spinner.set_halign(gtk4::Align::Center);
+        spinner.set_valign(gtk4::Align::End);
+        spinner.set_margin_bottom(16);
+        overlay.add_overlay(&spinner);
+
+        let imp = self.imp();
+        imp.search_people_model.replace(Some(model));
+        imp.search_people_scrolled_window
+            .replace(Some(scrolled.clone()));
+        imp.search_people_spinner.replace(Some(spinner));
+
+        // Infinite scroll
+        let adj = scrolled.vadjustment();
+        let win = self.downgrade();
+        adj.connect_value_changed(move |adj| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            let value = adj.value();
+            let upper = adj.upper();
+            let page_size = adj.page_size();
+            if value >= upper - page_size - 200.0 {
+                if let Some(cb) = win.imp().search_people_load_more_callback.borrow().as_ref() {
+                    cb();
+                }
+            }
+        });
+
+        // Same overlay placement as the posts page; see there.
+        let empty_state = Self::empty_state_page(
+            "system-search-symbolic",
+            "No people found",
+            "Nothing matched this search. Try a name or handle.",
+        );
+
+        // Suggestions fill the dead end when the network has some.
+        let suggestions_column = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
+        suggestions_column.set_visible(false);
+        let suggestions_title = gtk4::Label::new(Some("Suggested accounts"));
+        suggestions_title.add_css_class("heading");
+        suggestions_title.set_halign(gtk4::Align::Start);
+        suggestions_title.set_margin_bottom(4);
+        suggestions_column.append(&suggestions_title);
+        let suggestions_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        suggestions_column.append(&suggestions_box);
+        empty_state.set_child(Some(&suggestions_column));
+
+        overlay.add_overlay(&empty_state);
+        self.imp()
+            .search_people_empty_state
+            .replace(Some(empty_state));
+        self.imp()
+            .search_suggestions_box
+            .replace(Some(suggestions_box));
+
+        results_stack.add_titled_with_icon(
+            &overlay,
+            Some("people"),
+            "People",
+            "system-users-symbolic",
+        );
+    }
+
+    /// The filter popover beside the search entry: one field per
+    /// `searchPosts` filter, opened on what the entry already says and
+    /// written back into it as operators when the search runs.
+    fn build_search_filter_button(&self) -> gtk4::MenuButton {
+        let grid = gtk4::Grid::new();
+        grid.set_row_spacing(6);
+        grid.set_column_spacing(12);
+        grid.set_margin_start(6);
+        grid.set_margin_end(6);
+        grid.set_margin_top(6);
+        grid.set_margin_bottom(6);
+
+        let field = |row: i32, label: &str, placeholder: &str| {
+            let title = gtk4::Label::new(Some(label));
+            title.set_halign(gtk4::Align::End);
+            title.add_css_class("dim-label");
+            let entry = gtk4::Entry::new();
+            entry.set_placeholder_text(Some(placeholder));
+            entry.set_width_chars(22);
+            title.set_mnemonic_widget(Some(&entry));
+            grid.attach(&title, 0, row, 1, 1);
+            grid.attach(&entry, 1, row, 1, 1);
+            entry
+        };
+        let fields = SearchFilterFields {
+            author: field(0, "From", "handle.bsky.social"),
+            mentions: field(1, "Mentions", "handle.bsky.social"),
+            since: field(2, "Since", "YYYY-MM-DD"),
+            until: field(3, "Until", "YYYY-MM-DD"),
+            lang: field(4, "Language", "en"),
+            domain: field(5, "Links to site", "example.com"),
+            url: field(6, "Links to page", "https://…"),
+            tags: field(7, "Hashtags", "#rust #gtk"),
+            top: gtk4::CheckButton::with_label("Top posts first"),
+        };
+        grid.attach(&fields.top, 1, 8, 1, 1);
+
+        let apply = gtk4::Button::with_label("Search");
+        apply.add_css_class("suggested-action");
+        apply.set_halign(gtk4::Align::End);
+        grid.attach(&apply, 1, 9, 1, 1);
+
+        let popover = gtk4::Popover::new();
+        popover.set_child(Some(&grid));
+
+        // Open on the filters the entry already carries.
+        let win = self.downgrade();
+        popover.connect_show(move |_| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            let imp = win.imp();
+            let query = imp
+                .search_entry
+                .borrow()
+                .as_ref()
+                .map(|entry| SearchQuery::parse(&entry.text()))
+                .unwrap_or_default();
+            if let Some(fields) = imp.search_filter_fields.borrow().as_ref() {
+                let day = |d: Option<chrono::NaiveDate>| {
+                    d.map(|d| d.format("%Y-%m-%d").to_string())
+                        .unwrap_or_default()
+                };
+                fields
+                    .author
+                    .set_text(query.author.as_deref().unwrap_or_default());
+                fields
+                    .mentions
+                    .set_text(query.mentions.as_deref().unwrap_or_default());
+                fields.since.set_text(&day(query.since));
+                fields.until.set_text(&day(query.until));
+                fields
+                    .lang
+                    .set_text(query.lang.as_deref().unwrap_or_default());
+                fields
+                    .domain
+                    .set_text(query.domain.as_deref().unwrap_or_default());
+                fields
+                    .url
+                    .set_text(query.url.as_deref().unwrap_or_default());
+                let tags: Vec<String> = query.tags.iter().map(|t| format!("#{t}")).collect();
+                fields.tags.set_text(&tags.join(" "));
+                fields.top.set_active(query.sort == SearchSort::Top);
+                for entry in [&fields.since, &fields.until] {
+                    entry.remove_css_class("error");
+                }
+            }
+        });
+
+        let win = self.downgrade();
+        let pop = popover.clone();
+        apply.connect_clicked(move |_| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            let Some(query) = win.search_filters_query() else {
+                // A date that does not parse is marked; fix it first.
+                return;
+            };
+            pop.popdown();
+            win.run_search_query(&query);
+        });
+
+        // Enter in any field runs the search, as it does in the entry.
+        for entry in [
+            &fields.author,
+            &fields.mentions,
+            &fields.since,
+            &fields.until,
+            &fields.lang,
+            &fields.domain,
+            &fields.url,
+            &fields.tags,
+        ] {
+            let apply = apply.clone();
+            entry.connect_activate(move |_| apply.emit_clicked());
+        }
+        self.imp().search_filter_fields.replace(Some(fields));
+
+        let button = gtk4::MenuButton::new();
+        button.set_icon_name("funnel-symbolic");
+        button.set_tooltip_text(Some("Search filters"));
+        button.update_property(&[gtk4::accessible::Property::Label("Search filters")]);
+        button.set_popover(Some(&popover));
+        button
+    }
+
+    /// The entry's words with the popover's filters, or `None` while a date
+    /// there does not parse.
+    fn search_filters_query(&self) -> Option<SearchQuery> {
+        let imp = self.imp();
+        let text = imp
+            .search_entry
+            .borrow()
+            .as_ref()
+            .map(|entry| SearchQuery::parse(&entry.text()).text)
+            .unwrap_or_default();
+        let fields = imp.search_filter_fields.borrow();
+        let fields = fields.as_ref()?;
+
+        let mut days = [None, None];
+        let mut valid = true;
+        for (slot, entry) in days.iter_mut().zip([&fields.since, &fields.until]) {
+            let value = entry.text();
+            let value = value.trim();
+            *slot = search_query::parse_day(value);
+            let bad = !value.is_empty() && slot.is_none();
+            if bad {
+                entry.add_css_class("error");
+            } else {
+                entry.remove_css_class("error");
+            }
+            valid &= !bad;
+        }
+        if !valid {
+            return None;
+        }
+
+        let value = |entry: &gtk4::Entry| {
+            let value = entry.text().trim().trim_start_matches('@').to_string();
+            (!value.is_empty()).then_some(value)
+        };
+        let mut tags: Vec<String> = Vec::new();
+        for tag in fields.tags.text().split([' ', ',']) {
+            let tag = tag.trim_start_matches('#');
+            if !tag.is_empty() && !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                tags.push(tag.to_string());
+            }
+        }
+        // Through parse so the fields are held to what an operator typed in
+        // the entry would be.
+        let query = SearchQuery {
+            text,
+            author: value(&fields.author),
+            mentions: value(&fields.mentions),
+            since: days[0],
+            until: days[1],
+            lang: value(&fields.lang).map(|l| l.to_ascii_lowercase()),
+            domain: value(&fields.domain),
+            url: value(&fields.url),
+            tags,
+            sort: if fields.top.is_active() {
+                SearchSort::Top
+            } else {
+                SearchSort::Latest
+            },
+        };
+        Some(SearchQuery::parse(&query.to_input()))
+    }
+
+    /// Show whether the search that ran is one of the saved feeds.
+    pub fn set_search_saved(&self, saved: bool) {
+        if let Some(button) = self.imp().save_search_button.borrow().as_ref() {
+            button.set_sensitive(true);
+            button.set_icon_name(if saved {
+                "starred-symbolic"
+            } else {
+                "non-starred-symbolic"
+            });
+            let label = if saved {
+                "Remove saved search"
+            } else {
+                "Save search as a feed"
+            };
+            button.set_tooltip_text(Some(label));
+            button.update_property(&[gtk4::accessible::Property::Label(label)]);
+        }
+    }
+
+    /// Set callback for saving or unsaving the search in the entry
+    pub fn set_save_search_callback<F: Fn(String) + 'static>(&self, callback: F) {
+        self.imp()
+            .save_search_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Write `query` into the entry and search it. A query with nothing left
+    /// in it just clears the page.
+    fn run_search_query(&self, query: &SearchQuery) {
+        let input = query.to_input();
+        if let Some(entry) = self.imp().search_entry.borrow().as_ref() {
+            entry.set_text(&input);
+        }
+        if query.is_empty() {
+            self.clear_search_results();
+            self.set_search_filters(query);
+            return;
+        }
+        if let Some(cb) = self.imp().search_callback.borrow().as_ref() {
+            cb(input);
+        }
+    }
+
+    /// Show one chip per filter of the search that is running. Each takes
+    /// its filter off and searches again.
+    pub fn set_search_filters(&self, query: &SearchQuery) {
+        let Some(chips) = self.imp().search_chips.borrow().clone() else {
+            return;
+        };
+        while let Some(child) = chips.first_child() {
+            chips.remove(&child);
+        }
+        let filters = query.filters();
+        chips.set_visible(!filters.is_empty());
+        for (filter, label) in filters {
+            let content = gtk4::Box::new(gtk4::Orientation::Horizontal, 4);
+            content.append(&gtk4::Label::new(Some(&label)));
+            content.append(&gtk4::Image::from_icon_name("window-close-symbolic"));
+            let chip = gtk4::Button::new();
+            chip.set_child(Some(&content));
+            chip.add_css_class("search-chip");
+            chip.set_tooltip_text(Some("Remove filter"));
+            chip.update_property(&[gtk4::accessible::Property::Label(&format!(
+                "Remove filter: {label}"
+            ))]);
+            let win = self.downgrade();
+            let rest = query.without(&filter);
+            chip.connect_clicked(move |_| {
+                if let Some(win) = win.upgrade() {
+                    win.run_search_query(&rest);
+                }
+            });
+            chips.append(&chip);
+        }
+    }
+
+    /// Build the In your history page of the search results stack: posts
+    /// already in the cache, searched on this machine, under a bar of
+    /// filters the network search has no equivalent for.
+    fn build_search_history_page(&self, results_stack: &adw::ViewStack) {
+        let page = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+
+        let author = gtk4::Entry::new();
+        author.set_placeholder_text(Some("Author"));
+        author.set_hexpand(true);
+        let since = gtk4::Entry::new();
+        since.set_placeholder_text(Some("From YYYY-MM-DD"));
+        since.set_width_chars(16);
+        let until = gtk4::Entry::new();
+        until.set_placeholder_text(Some("To YYYY-MM-DD"));
+        until.set_width_chars(16);
+        let liked = gtk4::ToggleButton::with_label("Liked");
+        let bookmarked = gtk4::ToggleButton::with_label("Saved");
+
+        let filter_bar = gtk4::Box::new(gtk4::Orientation::Vertical, 6);
+        filter_bar.set_margin_start(12);
+        filter_bar.set_margin_end(12);
+        filter_bar.set_margin_bottom(8);
+        filter_bar.append(&author);
+        let second_row = gtk4::Box::new(gtk4::Orientation::Horizontal, 6);
+        second_row.append(&since);
+        second_row.append(&until);
+        let toggles = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+        toggles.add_css_class("linked");
+        toggles.set_hexpand(true);
+        toggles.set_halign(gtk4::Align::End);
+        toggles.append(&liked);
+        toggles.append(&bookmarked);
+        second_row.append(&toggles);
+        filter_bar.append(&second_row);
+
+        // Any filter change searches again; Enter in an entry is the change
+        // for text, so a half-typed date does not search on every key.
+        for entry in [&author, &since, &until] {
+            let win = self.downgrade();
+            entry.connect_activate(move |_| {
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().history_search_callback.borrow().as_ref()
+                {
+                    cb();
+                }
+            });
+        }
+        for toggle in [&liked, &bookmarked] {
+            let win = self.downgrade();
+            toggle.connect_toggled(move |_| {
+                if let Some(win) = win.upgrade()
+                    && let Some(cb) = win.imp().history_search_callback.borrow().as_ref()
+                {
+                    cb();
+                }
+            });
+        }
+        page.append(&filter_bar);
+
+        let overlay = gtk4::Overlay::new();
+        overlay.set_vexpand(true);
+
+        let model = gio::ListStore::new::<PostObject>();
+        let factory = gtk4::SignalListItemFactory::new();
+
+        factory.connect_setup(|_, item| {
+            let post_row = PostRow::new();
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>() {
+                list_item.set_child(Some(&post_row));
+            }
+        });
+        Self::release_video_on_unbind(&factory);
+
+        let win = self.downgrade();
+        factory.connect_bind(move |_, item| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            if let Some(list_item) = item.downcast_ref::<gtk4::ListItem>()
+                && let Some(post_object) = list_item.item().and_downcast::<PostObject>()
+                && let Some(post) = post_object.post()
+                && let Some(post_row) = list_item.child().and_downcast::<PostRow>()
+            {
+                post_row.bind(&post);
+                post_row.set_list_position(list_item.position());
+                win.wire_post_row(&post_row, &post);
+            }
+        });
+
+        let selection = gtk4::NoSelection::new(Some(model.clone()));
+        let list_view = gtk4::ListView::new(Some(selection), Some(factory));
+        list_view.add_css_class("background");
+
+        // The same chain as every list; see `build_timeline`. The filter bar
+        // sits above the scroller, not between it and the list.
+        let clamp = adw::ClampScrollable::new();
+        clamp.set_maximum_size(800);
+        clamp.set_tightening_threshold(600);
+        clamp.set_child(Some(&list_view));
+
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_vexpand(true);
+        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled.set_child(Some(&clamp));
+        overlay.set_child(Some(&scrolled));
+
+        // Up before any search, to say what this page is for.
+        let empty_state = Self::empty_state_page(
+            "document-open-recent-symbolic",
+            HISTORY_INTRO.0,
+            HISTORY_INTRO.1,
+        );
+        empty_state.set_visible(true);
+        overlay.add_overlay(&empty_state);
+        page.append(&overlay);
+
+        let imp = self.imp();
+        imp.history_model.replace(Some(model));
+        imp.history_empty_state.replace(Some(empty_state));
+        imp.history_filters.replace(Some(HistoryFilters {
+            author,
+            since,
+            until,
+            liked,
+            bookmarked,
+        }));
+
+        results_stack.add_titled_with_icon(
+            &page,
+            Some("history"),
+            "In your history",
+            "document-open-recent-symbolic",
+        );
+    }
+
+    /// What the History page searches for: the words in the search entry
+    /// and whatever its filters are set to.
+    pub fn history_query(&self) -> HistoryQuery {
+        let imp = self.imp();
+        let text = imp
+            .search_entry
+            .borrow()
+            .as_ref()
+            .map(|entry| entry.text().to_string())
+            .unwrap_or_default();
+        let filters = imp.history_filters.borrow();
+        let Some(filters) = filters.as_ref() else {
+            return HistoryQuery {
+                text,
+                ..Default::default()
+            };
+        };
+        let author = filters.author.text().trim().to_string();
+        HistoryQuery {
+            text,
+            author: (!author.is_empty()).then_some(author),
+            since: Self::history_date(&filters.since),
+            until: Self::history_date(&filters.until),
+            liked: filters.liked.is_active(),
+            bookmarked: filters.bookmarked.is_active(),
+        }
+    }
+
+    /// The day in a History date filter, or `None` when it is blank. A day
+    /// that does not parse marks the entry and is left out of the search.
+    fn history_date(entry: &gtk4::Entry) -> Option<chrono::NaiveDate> {
+        let text = entry.text();
+        let text = text.trim();
+        let date = chrono::NaiveDate::parse_from_str(text, "%Y-%m-%d").ok();
+        if text.is_empty() || date.is_some() {
+            entry.remove_css_class("error");
+        } else {
+            entry.add_css_class("error");
+        }
+        date
+    }
+
+    /// Set the History page's results. An empty list is a search that found
+    /// nothing.
+    pub fn set_history_results(&self, posts: Vec<Post>) {
+        let posts = self.without_hidden(self.without_muted(posts));
+        let empty = posts.is_empty();
+        if let Some(model) = self.imp().history_model.borrow().as_ref() {
+            model.remove_all();
+            for post in posts {
+                model.append(&PostObject::new(post));
+            }
+        }
+        if let Some(page) = self.imp().history_empty_state.borrow().as_ref() {
+            page.set_title(HISTORY_NO_MATCH.0);
+            page.set_description(Some(HISTORY_NO_MATCH.1));
+            page.set_visible(empty);
+        }
+    }
+
+    /// Empty the History page back to its introduction, for a search with
+    /// nothing to search by.
+    pub fn clear_history_results(&self) {
+        if let Some(model) = self.imp().history_model.borrow().as_ref() {
+            model.remove_all();
+        }
+        if let Some(page) = self.imp().history_empty_state.borrow().as_ref() {
+            page.set_title(HISTORY_INTRO.0);
+            page.set_description(Some(HISTORY_INTRO.1));
+            page.set_visible(true);
+        }
+    }
+
+    /// Set callback for when the History page should search again
+    pub fn set_history_search_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp()
+            .history_search_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Show the search page (top-level navigation, instant switch)
+    pub fn show_search_page(&self) {
+        self.switch_to_page("search");
+    }
+
+    /// Set search results in the search list
+    pub fn set_search_results(&self, posts: Vec<Post>) {
+        let posts = self.without_hidden(self.without_muted(posts));
+        let empty = posts.is_empty();
+        if let Some(model) = self.imp().search_model.borrow().as_ref() {
+            model.remove_all();
+            for post in posts {
+                model.append(&PostObject::new(post));
+            }
+        }
+        // Only a completed search lands here, so empty means no results,
+        // not no search yet.
+        if let Some(page) = self.imp().search_empty_state.borrow().as_ref() {
+            page.set_visible(empty);
+        }
+    }
+
+    /// Append more search results to the list
+    pub fn append_search_results(&self, posts: Vec<Post>) {
+        let posts = self.without_hidden(self.without_muted(posts));
+        if let Some(model) = self.imp().search_model.borrow().as_ref() {
+            for post in posts {
+                model.append(&PostObject::new(post));
+            }
+        }
+    }
+
+    /// Set loading state for search page
+    pub fn set_search_loading(&self, loading: bool) {
+        if let Some(spinner) = self.imp().search_spinner.borrow().as_ref() {
+            spinner.set_visible(loading);
+            spinner.set_spinning(loading);
+        }
+    }
+
+    /// Set callback for loading more search results
+    pub fn set_search_load_more_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp()
+            .search_load_more_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Set callback for when a search is submitted
+    pub fn set_search_callback<F: Fn(String) + 'static>(&self, callback: F) {
+        self.imp().search_callback.replace(Some(Box::new(callback)));
+    }
+
+    /// Set people results in the search list
+    pub fn set_search_people_results(&self, profiles: Vec<Profile>) {
+        let empty = profiles.is_empty();
+        if let Some(model) = self.imp().search_people_model.borrow().as_ref() {
+            model.remove_all();
+            for profile in profiles {
+                model.append(&ActorObject::new(profile));
+            }
+        }
+        // See set_search_results.
+        if let Some(page) = self.imp().search_people_empty_state.borrow().as_ref() {
+            page.set_visible(empty);
+        }
+    }
+
+    /// Append more people results to the list
+    pub fn append_search_people_results(&self, profiles: Vec<Profile>) {
+        if let Some(model) = self.imp().search_people_model.borrow().as_ref() {
+            for profile in profiles {
+                model.append(&ActorObject::new(profile));
+            }
+        }
+    }
+
+    /// Set loading state for the people page of search
+    pub fn set_search_people_loading(&self, loading: bool) {
+        if let Some(spinner) = self.imp().search_people_spinner.borrow().as_ref() {
+            spinner.set_visible(loading);
+            spinner.set_spinning(loading);
+        }
+    }
+
+    /// Set callback for loading more people results
+    pub fn set_search_people_load_more_callback<F: Fn() + 'static>(&self, callback: F) {
+        self.imp()
+            .search_people_load_more_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Clear search results, both pages
+    pub fn clear_search_results(&self) {
+        if let Some(model) = self.imp().search_model.borrow().as_ref() {
+            model.remove_all();
+        }
+        if let Some(model) = self.imp().search_people_model.borrow().as_ref() {
+            model.remove_all();
+        }
+        // A fresh search is not a search with no results.
+        if let Some(page) = self.imp().search_empty_state.borrow().as_ref() {
+            page.set_visible(false);
+        }
+        if let Some(page) = self.imp().search_people_empty_state.borrow().as_ref() {
+            page.set_visible(false);
+        }
+        self.set_search_suggestions(vec![]);
+    }
+
+    /// The New Message picker: type a name, pick a person, chat.
+    fn build_new_message_dialog(&self) -> adw::Dialog {
+        let content = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+
+        let header = adw::HeaderBar::new();
+        let title = gtk4::Label::new(Some("New Message"));
+        title.add_css_class("title");
+        header.set_title_widget(Some(&title));
+        content.append(&header);
+
+        let entry = gtk4::SearchEntry::new();
+        entry.set_placeholder_text(Some("Search people"));
+        entry.set_margin_start(12);
+        entry.set_margin_end(12);
+        entry.set_margin_top(8);
+        entry.set_margin_bottom(8);
+        let win = self.downgrade();
+        entry.connect_search_changed(move |entry| {
+            let Some(win) = win.upgrade() else {
+                return;
+            };
+            if let Some(cb) = win.imp().new_message_query_callback.borrow().as_ref() {
+                cb(entry.text().to_string());
+            }
+        });
+        content.append(&entry);
+
+        let results = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        let scrolled = gtk4::ScrolledWindow::new();
+        scrolled.set_vexpand(true);
+        scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        scrolled.set_child(Some(&results));
+        content.append(&scrolled);
+
+        self.imp().new_message_results_box.replace(Some(results));
+
+        let dialog = adw::Dialog::builder()
+            .title("New Message")
+            .content_width(420)
+            .content_height(480)
+            .child(&content)
+            .build();
+        // Stale state from the last opening would flash otherwise.
+        self.imp().new_message_dialog.replace(Some(dialog.clone()));
+        dialog
+    }
+
+    pub fn present_new_message_dialog(&self) {
+        let dialog = self.build_new_message_dialog();
+        dialog.present(Some(self));
+    }
+
+    /// People matching the picker's query; a row click hands the person to
+    /// the chosen callback and the dialog steps aside.
+    pub fn set_new_message_results(&self, profiles: Vec<Profile>) {
+        let Some(rows) = self.imp().new_message_results_box.borrow().clone() else {
+            return;
+        };
+        while let Some(child) = rows.first_child() {
+            rows.remove(&child);
+        }
+        let win = self.downgrade();
+        for profile in profiles {
+            let row = ActorRow::new();
+            row.bind(&profile);
+            let w = win.clone();
+            row.set_activated_callback(move |profile| {
+                let Some(w) = w.upgrade() else {
+                    return;
+                };
+                if let Some(dialog) = w.imp().new_message_dialog.borrow().as_ref() {
+                    dialog.close();
+                }
+                if let Some(cb) = w.imp().new_message_chosen_callback.borrow().as_ref() {
+                    cb(profile);
+                }
+            });
+            rows.append(&row);
+        }
+    }
+
+    pub fn set_new_message_query_callback<F: Fn(String) + 'static>(&self, callback: F) {
+        self.imp()
+            .new_message_query_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    pub fn set_new_message_chosen_callback<F: Fn(Profile) + 'static>(&self, callback: F) {
+        self.imp()
+            .new_message_chosen_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// Fill the empty people search with suggested accounts. An empty list
+    /// clears them, leaving the plain no-results page.
+    pub fn set_search_suggestions(&self, profiles: Vec<Profile>) {
+        let Some(rows) = self.imp().search_suggestions_box.borrow().clone() else {
+            return;
+        };
+        while let Some(child) = rows.first_child() {
+            rows.remove(&child);
+        }
+        if let Some(column) = rows.parent() {
+            column.set_visible(!profiles.is_empty());
+        }
+        let win = self.downgrade();
+        for profile in profiles {
+            let row = ActorRow::new();
+            row.bind(&profile);
+            let w = win.clone();
+            row.set_activated_callback(move |profile| {
+                let Some(w) = w.upgrade() else {
+                    return;
+                };
+                if let Some(cb) = w.imp().profile_clicked_callback.borrow().as_ref() {
+                    cb(profile);
+                }
+            });
+            rows.append(&row);
+        }
+    }
+
+    /// Focus the search entry
+    pub fn focus_search_entry(&self) {
+        if let Some(entry) = self.imp().search_entry.borrow().as_ref() {
+            entry.grab_focus();
+        }
+    }
+
+    /// One `set_X_load_failed` per rail feed; see `apply_load_failed`.
+    pub fn set_timeline_load_failed(&self) {
+        let imp = self.imp();
+        let empty = imp
+            .timeline_model
+            .borrow()
+            .as_ref()
+            .is_none_or(|m| m.n_items() == 0);
+        Self::apply_load_failed(
+            empty,
+            &imp.timeline_overlay,
+            &imp.timeline_empty_state,
+            &imp.timeline_error_state,
+            &imp.timeline_skeleton,
+        );
+    }
+
+    pub fn set_mentions_load_failed(&self) {
+        let imp = self.imp();
+        let empty = imp
+            .mentions_model
+            .borrow()
+            .as_ref()
+            .is_none_or(|m| m.n_items() == 0);
+        Self::apply_load_failed(
+            empty,
+            &imp.mentions_overlay,
+            &imp.mentions_empty_state,
+            &imp.mentions_error_state,
+            &imp.mentions_skeleton,
+        );
+    }
+
+    pub fn set_activity_load_failed(&self) {
+        let imp = self.imp();
+        let empty = imp
+            .activity_model
+            .borrow()
+            .as_ref()
+            .is_none_or(|m| m.n_items() == 0);
+        Self::apply_load_failed(
+            empty,
+            &imp.activity_overlay,
+            &imp.activity_empty_state,
+            &imp.activity_error_state,
+            &imp.activity_skeleton,
+        );
+    }
+
+    pub fn set_likes_load_failed(&self) {
+        let imp = self.imp();
+        let empty = imp
+            .likes_model
+            .borrow()
+            .as_ref()
+            .is_none_or(|m| m.n_items() == 0);
+        Self::apply_load_failed(
+            empty,
+            &imp.likes_overlay,
+            &imp.likes_empty_state,
+            &imp.likes_error_state,
+            &imp.likes_skeleton,
+        );
+    }
+
+    pub fn set_bookmarks_load_failed(&self) {
+        let imp = self.imp();
+        let empty = imp
+            .bookmarks_model
+            .borrow()
+            .as_ref()
+            .is_none_or(|m| m.n_items() == 0);
+        Self::apply_load_failed(
+            empty,
+            &imp.bookmarks_overlay,
+            &imp.bookmarks_empty_state,
+            &imp.bookmarks_error_state,
+            &imp.bookmarks_skeleton,
+        );
+    }
+
+    pub fn set_conversations_load_failed(&self) {
+        let imp = self.imp();
+        let empty = imp
+            .chat_model
+            .borrow()
+            .as_ref()
+            .is_none_or(|m| m.n_items() == 0);
+        Self::apply_load_failed(
+            empty,
+            &imp.chat_overlay,
+            &imp.chat_empty_state,
+            &imp.chat_error_state,
+            &imp.chat_skeleton,
+        );
+    }
+
+    /// Show or clear the offline banner, saying so aloud either way.
+    pub fn set_offline(&self, offline: bool) {
+        let Some(banner) = self.imp().offline_banner.borrow().clone() else {
+            return;
+        };
+        if banner.is_revealed() == offline {
+            return;
+        }
+        banner.set_revealed(offline);
+        self.announce(
+            if offline {
+                "You're offline"
+            } else {
+                "Back online"
+            },
+            gtk4::AccessibleAnnouncementPriority::Medium,
+        );
+    }
+
+    /// Show a toast notification
+    pub fn show_toast(&self, message: &str) {
+        if let Some(overlay) = self.imp().toast_overlay.borrow().as_ref() {
+            let toast = adw::Toast::new(message);
+            toast.set_timeout(3); // 3 seconds
+            // No announce of our own: the toast overlay already speaks the
+            // message, and saying it here made Orca read every toast twice.
+            overlay.add_toast(toast);
+        }
+    }
+
+    /// Show a toast with an action button
+    pub fn show_toast_with_action(
+        &self,
+        message: &str,
+        button_label: &str,
+        action: impl Fn() + 'static,
+    ) {
+        if let Some(overlay) = self.imp().toast_overlay.borrow().as_ref() {
+            let toast = adw::Toast::new(message);
+            toast.set_timeout(5); // 5 seconds for actionable toasts
+            toast.set_button_label(Some(button_label));
+            toast.connect_button_clicked(move |_| {
+                action();
+            });
+            overlay.add_toast(toast);
+        }
+    }
+
+    // ======== Post removal ========
+
+    /// Take a deleted post out of every list that is showing it.
+    ///
+    /// Walks the widget tree rather than keeping a registry: a pushed thread
+    /// or profile page builds a model nothing else holds. A virtualized list
+    /// is edited through its model only; a `PostRow` met outside any
+    /// `GtkListView` is a loose row and comes straight out of its box.
+    pub fn remove_post(&self, uri: &str) {
+        let mut thread_rows = Vec::new();
+        Self::collect_post_removals(self.upcast_ref::<gtk4::Widget>(), uri, &mut thread_rows);
+        for row in thread_rows {
+            if let Some(parent) = row.parent().and_downcast::<gtk4::Box>() {
+                parent.remove(&row);
+            }
+        }
+        self.pop_thread_pages_for(uri);
+    }
+
+    /// Close any open thread page rooted at a deleted post. Without this,
+    /// deleting the root left the thread page up with nothing to show.
+    fn pop_thread_pages_for(&self, uri: &str) {
+        let tag = format!("thread:{uri}");
+        for section in [
+            "home",
+            "mentions",
+            "activity",
+            "chat",
+            "profile",
+            "likes",
+            "bookmarks",
+            "search",
+            "lists",
+        ] {
+            let Some(nav_view) = self.nav_view_named(section) else {
+                continue;
+            };
+            let Some(page) = nav_view.find_page(&tag) else {
+                continue;
+            };
+            // Pop to the page underneath, taking the thread page and anything
+            // stacked on top of it off in one transition.
+            let stack = nav_view.navigation_stack();
+            let index = (0..stack.n_items())
+                .find(|&i| stack.item(i).as_ref() == Some(page.upcast_ref::<glib::Object>()));
+            let Some(index) = index else { continue };
+            if index == 0 {
+                continue;
+            }
+            if let Some(below) = stack.item(index - 1).and_downcast::<adw::NavigationPage>() {
+                nav_view.pop_to_page(&below);
+            }
+        }
+    }
+
+    /// Remove `uri` from every post model under `widget`. Rows inside a
+    /// `GtkListView` are pooled, so those subtrees are never descended into;
+    /// matching rows found elsewhere are collected for the caller to unparent.
+    fn collect_post_removals(widget: &gtk4::Widget, uri: &str, thread_rows: &mut Vec<PostRow>) {
+        if let Some(list) = widget.downcast_ref::<gtk4::ListView>() {
+            let model = list
+                .model()
+                .and_then(|m| m.downcast::<gtk4::NoSelection>().ok())
+                .and_then(|ns| ns.model());
+            if let Some(model) = model {
+                if let Some(store) = model.downcast_ref::<gio::ListStore>() {
+                    Self::remove_post_from_store(store, uri);
+                } else if let Some(flat) = model.downcast_ref::<gtk4::FlattenListModel>() {
+                    // The profile and thread pages: a store of stores, with
+                    // the posts in some of them and marker rows in the rest.
+                    if let Some(sections) = flat.model() {
+                        for i in 0..sections.n_items() {
+                            if let Some(store) = sections.item(i).and_downcast::<gio::ListStore>() {
+                                Self::remove_post_from_store(&store, uri);
+                            }
+                        }
+                    }
+                }
+            }
+            return;
+        }
+        if let Some(row) = widget.downcast_ref::<PostRow>() {
+            if row.post_uri().as_deref() == Some(uri) {
+                thread_rows.push(row.clone());
+            }
+            return;
+        }
+        let mut child = widget.first_child();
+        while let Some(c) = child {
+            child = c.next_sibling();
+            Self::collect_post_removals(&c, uri, thread_rows);
+        }
+    }
+
+    fn remove_post_from_store(store: &gio::ListStore, uri: &str) {
+        let mut i = store.n_items();
+        while i > 0 {
+            i -= 1;
+            let matches = store
+                .item(i)
+                .and_downcast::<PostObject>()
+                .and_then(|o| o.post())
+                .is_some_and(|p| p.uri == uri);
+            if matches {
+                store.remove(i);
+            }
+        }
+    }
+
+    // ======== Settings Page ========
+
+    fn build_settings_page(&self) -> adw::ToolbarView {
+        use crate::state::AppSettings;
+
+        // Header bar with back button and "Settings" title
+        let header = adw::HeaderBar::new();
+        header.set_show_start_title_buttons(false);
+        header.set_show_end_title_buttons(false);
+        header.set_title_widget(Some(&adw::WindowTitle::new("Settings", "")));
+
+        // Back button
+        let back_btn = gtk4::Button::from_icon_name("go-previous-symbolic");
+        back_btn.add_css_class("flat");
+        back_btn.set_tooltip_text(Some("Back"));
+        back_btn.update_property(&[gtk4::accessible::Property::Label("Back to previous page")]);
+        let window_weak = self.downgrade();
+        back_btn.connect_clicked(move |_| {
+            if let Some(window) = window_weak.upgrade() {
+                window.leave_settings();
+            }
+        });
+        header.pack_start(&back_btn);
+
+        let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
+        header.pack_end(&window_controls);
+
+        // Split layout: category list on the left, one page per category right
+        let split_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+        split_box.set_vexpand(true);
+
+        // ---- Settings sidebar ----
+        let sidebar_scrolled = gtk4::ScrolledWindow::new();
+        sidebar_scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
+        sidebar_scrolled.set_vexpand(true);
+        // Fixed width for the settings sidebar
+        sidebar_scrolled.set_size_request(200, -1);
+
+        let sidebar_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        sidebar_box.add_css_class("settings-sidebar");
+
+        let sidebar_list = gtk4::ListBox::new();
+        sidebar_list.set_selection_mode(gtk4::SelectionMode::Single);
+        sidebar_list.add_css_class("navigation-sidebar");
+        sidebar_list.update_property(&[gtk4::accessible::Property::Label("Settings categories")]);
+
+        // ---- Content stack ----
+        let content_stack = adw::ViewStack::new();
+        content_stack.set_hexpand(true);
+        content_stack.set_vexpand(true);
+
+        // Load current settings once
+        let current_settings = AppSettings::load();
+
+        // Sidebar order.
+        let pages = [
+            self.build_settings_feed_page(&current_settings),
+            self.build_settings_display_page(&current_settings),
+            self.build_settings_accessibility_page(&current_settings),
+            self.build_settings_notifications_page(&current_settings),
+            self.build_settings_alerts_page(),
+            self.build_settings_moderation_page(),
+            self.build_settings_account_page(),
+        ];
+
+        for page in &pages {
+            // AdwPreferencesPage carries its own name, title and icon, so the
+            // sidebar row and stack page come from one source. A page that
+            // forgets set_name or set_icon_name would silently vanish from
+            // both, so warn instead of skipping.
+            let (Some(name), Some(icon_name)) = (page.name(), page.icon_name()) else {
+                debug_assert!(
+                    false,
+                    "settings page \"{}\" has no name or no icon and cannot be shown",
+                    page.title()
+                );
+                eprintln!(
+                    "hangar: settings page \"{}\" has no name or no icon and was not added",
+                    page.title()
+                );
+                continue;
+            };
+            let label = page.title();
+            content_stack.add_titled_with_icon(page, Some(&name), &label, &icon_name);
+
+            let row = gtk4::ListBoxRow::new();
+            row.set_widget_name(&name);
+            let row_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 10);
+            row_box.set_margin_top(8);
+            row_box.set_margin_bottom(8);
+            row_box.set_margin_start(12);
+            row_box.set_margin_end(12);
+            let icon = gtk4::Image::from_icon_name(&icon_name);
+            icon.set_pixel_size(18);
+            row_box.append(&icon);
+            let lbl = gtk4::Label::new(Some(&label));
+            lbl.set_halign(gtk4::Align::Start);
+            row_box.append(&lbl);
+            row.set_child(Some(&row_box));
+            row.update_property(&[gtk4::accessible::Property::Label(&label)]);
+            sidebar_list.append(&row);
+        }
+
+        sidebar_box.append(&sidebar_list);
+        sidebar_scrolled.set_child(Some(&sidebar_box));
+
+        // Separator between sidebar and content
+        let separator = gtk4::Separator::new(gtk4::Orientation::Vertical);
+
+        // Selection rather than activation, so arrowing through the categories
+        // shows each one without needing a second keypress.
+        let stack_ref = content_stack.clone();
+        sidebar_list.connect_row_selected(move |_, row| {
+            if let Some(row) = row {
+                let name = row.widget_name();
+                stack_ref.set_visible_child_name(&name);
+            }
+        });
+
+        // Select first row by default
+        if let Some(first_row) = sidebar_list.row_at_index(0) {
+            sidebar_list.select_row(Some(&first_row));
+        }
+
+        split_box.append(&sidebar_scrolled);
+        split_box.append(&separator);
+        split_box.append(&content_stack);
+
+        // ToolbarView rather than a plain Box, so the header picks up the
+        // shading that says the page under it has scrolled.
+        let toolbar = adw::ToolbarView::new();
+        toolbar.add_top_bar(&header);
+        toolbar.set_content(Some(&split_box));
+        toolbar
+    }
+
+    /// Build the Feed category of the settings page
+    fn build_settings_feed_page(
+        &self,
+        current_settings: &crate::state::AppSettings,
+    ) -> adw::PreferencesPage {
+        let page = adw::PreferencesPage::new();
+        page.set_name(Some("feed"));
+        page.set_title("Feed");
+        page.set_icon_name(Some("view-list-symbolic"));
+
+        // ---- My Feeds ----
+        // Filled by `set_saved_feed_list` once the account's preferences
+        // arrive, as the muted words are.
+        let my_feeds_group = adw::PreferencesGroup::new();
+        my_feeds_group.set_title("My Feeds");
+        my_feeds_group.set_description(Some(
+            "Drag to reorder. Pinned feeds appear in the feed selector. Saved to your account, so other apps follow it too.",
+        ));
+        let discover_btn = gtk4::Button::with_label("Discover Feeds");
+        discover_btn.add_css_class("flat");
+        discover_btn.set_valign(gtk4::Align::Center);
+        let window_weak = self.downgrade();
+        discover_btn.connect_clicked(move |_| {
+            if let Some(window) = window_weak.upgrade() {
+                window.request_discover_feeds();
+            }
+        });
+        my_feeds_group.set_header_suffix(Some(&discover_btn));
+        page.add(&my_feeds_group);
+        self.imp().my_feeds_group.replace(Some(my_feeds_group));
+        self.rebuild_my_feed_rows();
+
+        let feed_group = adw::PreferencesGroup::new();
+        feed_group.set_title("Timeline");
+        feed_group.set_description(Some(
+            "Controls what appears in your main timeline. Threads, profiles and likes are unaffected.",
+        ));
+
+        let hide_replies_row = adw::ActionRow::builder()
+            .title("Hide Replies")
+            .subtitle("Show only top-level posts, the way bsky.app does")
+            .build();
+
+        let hide_replies_switch = gtk4::Switch::new();
+        hide_replies_switch.set_valign(gtk4::Align::Center);
+        hide_replies_switch.set_active(current_settings.hide_replies_in_feed);
+        hide_replies_switch.set_tooltip_text(Some("Toggle replies in the main feed"));
+        hide_replies_switch
+            .update_property(&[gtk4::accessible::Property::Label("Hide replies in feed")]);
+
+        let window_weak = self.downgrade();
+        hide_replies_switch.connect_state_set(move |_switch, state| {
+            let mut settings = crate::state::AppSettings::load();
+            settings.hide_replies_in_feed = state;
+            if let Err(e) = settings.save() {
+                eprintln!("Failed to save settings: {e}");
+            }
+
+            // `filter_feed_posts` runs as a batch enters the model, so writing
+            // the file alone left the timeline as it was until the next fetch.
+            // Refetch.
+            if let Some(window) = window_weak.upgrade() {
+                window.refresh_feed();
+            }
+            glib::Propagation::Proceed
+        });
+
+        hide_replies_row.add_suffix(&hide_replies_switch);
+        hide_replies_row.set_activatable_widget(Some(&hide_replies_switch));
+        feed_group.add(&hide_replies_row);
+
+        page.add(&feed_group);
+
+        // ---- Media ----
+        let media_group = adw::PreferencesGroup::new();
+        media_group.set_title("Media");
+        media_group.set_description(Some(
+            "Applies to the main timeline. Threads, profiles and search always wait for you to press play.",
+        ));
+
+        let autoplay_labels: Vec<&str> = crate::state::VideoAutoplay::ALL
+            .iter()
+            .map(|mode| mode.label())
+            .collect();
+        let autoplay_model = gtk4::StringList::new(&autoplay_labels);
+        let autoplay_row = adw::ComboRow::builder()
+            .title("Autoplay Videos")
+            .subtitle("Play the video nearest the middle of the feed as you scroll")
+            .model(&autoplay_model)
+            .build();
+        let selected = crate::state::VideoAutoplay::ALL
+            .iter()
+            .position(|mode| *mode == current_settings.video_autoplay)
+            .unwrap_or(0);
+        autoplay_row.set_selected(selected as u32);
+        autoplay_row.update_property(&[gtk4::accessible::Property::Label(
+            "Autoplay videos in the timeline",
+        )]);
+
+        let window_weak = self.downgrade();
+        autoplay_row.connect_selected_notify(move |row| {
+            let mode = crate::state::VideoAutoplay::ALL
+                .get(row.selected() as usize)
+                .copied()
+                .unwrap_or_default();
+
+            let mut settings = crate::state::AppSettings::load();
+            settings.video_autoplay = mode;
+            if let Err(e) = settings.save() {
+                eprintln!("Failed to save settings: {e}");
+            }
+
+            // No refetch needed here, unlike Hide Replies. The feed
+            // contents are the same and the director applies this live.
+            if let Some(window) = window_weak.upgrade() {
+                let director = window.imp().video_director.borrow().clone();
+                if let Some(director) = director {
+                    director.set_autoplay(mode);
+                }
+            }
+        });
 
-    pub fn set_bookmarks_load_failed(&self) {
-        let imp = self.imp();
-        let empty = imp
-            .bookmarks_model
-            .borrow()
-            .as_ref()
-            .is_none_or(|m| m.n_items() == 0);
-        Self::apply_load_failed(
-            empty,
-            &imp.bookmarks_overlay,
-            &imp.bookmarks_empty_state,
-            &imp.bookmarks_error_state,
-            &imp.bookmarks_skeleton,
-        );
-    }
+        media_group.add(&autoplay_row);
+        page.add(&media_group);
 
-    pub fn set_conversations_load_failed(&self) {
-        let imp = self.imp();
-        let empty = imp
-            .chat_model
-            .borrow()
-            .as_ref()
-            .is_none_or(|m| m.n_items() == 0);
-        Self::apply_load_failed(
-            empty,
-            &imp.chat_overlay,
-            &imp.chat_empty_state,
-            &imp.chat_error_state,
-            &imp.chat_skeleton,
-        );
+        page
     }
 
-    /// Show or clear the offline banner, saying so aloud either way.
-    pub fn set_offline(&self, offline: bool) {
-        let Some(banner) = self.imp().offline_banner.borrow().clone() else {
+    /// One row per saved feed, in the account's order. Rows drag onto each
+    /// other to reorder; the switch pins and the button removes. Following
+    /// is the app's home whatever the account says, so its row only moves.
+    fn rebuild_my_feed_rows(&self) {
+        let imp = self.imp();
+        let Some(group) = imp.my_feeds_group.borrow().clone() else {
             return;
         };
-        if banner.is_revealed() == offline {
-            return;
+        for row in imp.my_feed_rows.take() {
+            group.remove(&row);
         }
-        banner.set_revealed(offline);
-        self.announce(
-            if offline {
-                "You're offline"
-            } else {
-                "Back online"
-            },
-            gtk4::AccessibleAnnouncementPriority::Medium,
-        );
-    }
 
-    /// Show a toast notification
-    pub fn show_toast(&self, message: &str) {
-        if let Some(overlay) = self.imp().toast_overlay.borrow().as_ref() {
-            let toast = adw::Toast::new(message);
-            toast.set_timeout(3); // 3 seconds
-            // No announce of our own: the toast overlay already speaks the
-            // message, and saying it here made Orca read every toast twice.
-            overlay.add_toast(toast);
+        let Some(feeds) = imp.saved_feed_list.borrow().clone() else {
+            return;
+        };
+        let mut rows = Vec::new();
+        if feeds.is_empty() {
+            let row = adw::ActionRow::builder()
+                .title("No saved feeds")
+                .subtitle("Use Discover Feeds to find some")
+                .build();
+            row.add_css_class("dim-label");
+            rows.push(row);
+        }
+        for (index, feed) in feeds.iter().enumerate() {
+            // Names are whatever the feed's maker typed, so no markup.
+            let row = adw::ActionRow::builder()
+                .title(feed.display_name.as_str())
+                .subtitle(if feed.is_home() {
+                    "Posts from people you follow"
+                } else if feed.is_list() {
+                    "List"
+                } else {
+                    "Feed"
+                })
+                .use_markup(false)
+                .build();
+
+            let handle = gtk4::Image::from_icon_name("list-drag-handle-symbolic");
+            handle.add_css_class("dim-label");
+            row.add_prefix(&handle);
+
+            let drag = gtk4::DragSource::new();
+            drag.set_actions(gtk4::gdk::DragAction::MOVE);
+            let uri = feed.uri.clone();
+            drag.connect_prepare(move |_, _, _| {
+                Some(gtk4::gdk::ContentProvider::for_value(&uri.to_value()))
+            });
+            let row_ref = row.clone();
+            drag.connect_drag_begin(move |source, _| {
+                source.set_icon(Some(&gtk4::WidgetPaintable::new(Some(&row_ref))), 0, 0);
+            });
+            row.add_controller(drag);
+
+            let drop_target =
+                gtk4::DropTarget::new(glib::Type::STRING, gtk4::gdk::DragAction::MOVE);
+            let window_weak = self.downgrade();
+            drop_target.connect_drop(move |_, value, _, _| {
+                let (Some(window), Ok(uri)) = (window_weak.upgrade(), value.get::<String>()) else {
+                    return false;
+                };
+                window.edit_saved_feed_list(|feeds| {
+                    if let Some(from) = feeds.iter().position(|f| f.uri == uri) {
+                        let moved = feeds.remove(from);
+                        feeds.insert(index.min(feeds.len()), moved);
+                    }
+                });
+                window.rebuild_my_feed_rows();
+                true
+            });
+            row.add_controller(drop_target);
+
+            if !feed.is_home() {
+                let pin_switch = gtk4::Switch::new();
+                pin_switch.set_valign(gtk4::Align::Center);
+                pin_switch.set_active(feed.pinned);
+                pin_switch.set_tooltip_text(Some("Pin to the feed selector"));
+                pin_switch.update_property(&[gtk4::accessible::Property::Label(&format!(
+                    "Pin {}",
+                    feed.display_name
+                ))]);
+                let window_weak = self.downgrade();
+                let uri = feed.uri.clone();
+                pin_switch.connect_state_set(move |_, state| {
+                    if let Some(window) = window_weak.upgrade() {
+                        window.edit_saved_feed_list(|feeds| {
+                            if let Some(feed) = feeds.iter_mut().find(|f| f.uri == uri) {
+                                feed.pinned = state;
+                            }
+                        });
+                    }
+                    glib::Propagation::Proceed
+                });
+                row.add_suffix(&pin_switch);
+
+                let remove_btn = gtk4::Button::from_icon_name("user-trash-symbolic");
+                remove_btn.add_css_class("flat");
+                remove_btn.set_valign(gtk4::Align::Center);
+                remove_btn.set_tooltip_text(Some("Remove"));
+                remove_btn.update_property(&[gtk4::accessible::Property::Label(&format!(
+                    "Remove {} from my feeds",
+                    feed.display_name
+                ))]);
+                let window_weak = self.downgrade();
+                let uri = feed.uri.clone();
+                remove_btn.connect_clicked(move |_| {
+                    if let Some(window) = window_weak.upgrade() {
+                        window.edit_saved_feed_list(|feeds| feeds.retain(|f| f.uri != uri));
+                        window.rebuild_my_feed_rows();
+                    }
+                });
+                row.add_suffix(&remove_btn);
+            }
+            rows.push(row);
         }
-    }
 
-    /// Show a toast with an action button
-    pub fn show_toast_with_action(
-        &self,
-        message: &str,
-        button_label: &str,
-        action: impl Fn() + 'static,
-    ) {
-        if let Some(overlay) = self.imp().toast_overlay.borrow().as_ref() {
-            let toast = adw::Toast::new(message);
-            toast.set_timeout(5); // 5 seconds for actionable toasts
-            toast.set_button_label(Some(button_label));
-            toast.connect_button_clicked(move |_| {
-                action();
-            });
-            overlay.add_toast(toast);
+        for row in &rows {
+            group.add(row);
         }
+        imp.my_feed_rows.replace(rows);
     }
 
-    // ======== Post removal ========
+    /// Build the Display category of the settings page
+    fn build_settings_display_page(
+        &self,
+        current_settings: &crate::state::AppSettings,
+    ) -> adw::PreferencesPage {
+        use crate::state::FontSize;
 
-    /// Take a deleted post out of every list that is showing it.
-    ///
-    /// Walks the widget tree rather than keeping a registry: a pushed thread
-    /// or profile page builds a model nothing else holds. A virtualized list
-    /// is edited through its model only; a `PostRow` met outside any
-    /// `GtkListView` is a loose row and comes straight out of its box.
-    pub fn remove_post(&self, uri: &str) {
-        let mut thread_rows = Vec::new();
-        Self::collect_post_removals(self.upcast_ref::<gtk4::Widget>(), uri, &mut thread_rows);
-        for row in thread_rows {
-            if let Some(parent) = row.parent().and_downcast::<gtk4::Box>() {
-                parent.remove(&row);
-            }
-        }
-        self.pop_thread_pages_for(uri);
-    }
+        let page = adw::PreferencesPage::new();
+        page.set_name(Some("display"));
+        page.set_title("Display");
+        page.set_icon_name(Some("preferences-desktop-appearance-symbolic"));
 
-    /// Close any open thread page rooted at a deleted post. Without this,
-    /// deleting the root left the thread page up with nothing to show.
-    fn pop_thread_pages_for(&self, uri: &str) {
-        let tag = format!("thread:{uri}");
-        for section in [
-            "home",
-            "mentions",
-            "activity",
-            "chat",
-            "profile",
-            "likes",
-            "bookmarks",
-            "search",
-        ] {
-            let Some(nav_view) = self.nav_view_named(section) else {
-                continue;
-            };
-            let Some(page) = nav_view.find_page(&tag) else {
-                continue;
-            };
-            // Pop to the page underneath, taking the thread page and anything
-            // stacked on top of it off in one transition.
-            let stack = nav_view.navigation_stack();
-            let index = (0..stack.n_items())
-                .find(|&i| stack.item(i).as_ref() == Some(page.upcast_ref::<glib::Object>()));
-            let Some(index) = index else { continue };
-            if index == 0 {
-                continue;
-            }
-            if let Some(below) = stack.item(index - 1).and_downcast::<adw::NavigationPage>() {
-                nav_view.pop_to_page(&below);
-            }
-        }
-    }
+        // ---- Appearance section ----
+        let appearance_group = adw::PreferencesGroup::new();
+        appearance_group.set_title("Appearance");
 
-    /// Remove `uri` from every post model under `widget`. Rows inside a
-    /// `GtkListView` are pooled, so those subtrees are never descended into;
-    /// matching rows found elsewhere are collected for the caller to unparent.
-    fn collect_post_removals(widget: &gtk4::Widget, uri: &str, thread_rows: &mut Vec<PostRow>) {
-        if let Some(list) = widget.downcast_ref::<gtk4::ListView>() {
-            let model = list
-                .model()
-                .and_then(|m| m.downcast::<gtk4::NoSelection>().ok())
-                .and_then(|ns| ns.model());
-            if let Some(model) = model {
-                if let Some(store) = model.downcast_ref::<gio::ListStore>() {
-                    Self::remove_post_from_store(store, uri);
-                } else if let Some(flat) = model.downcast_ref::<gtk4::FlattenListModel>() {
-                    // The profile and thread pages: a store of stores, with
-                    // the posts in some of them and marker rows in the rest.
-                    if let Some(sections) = flat.model() {
-                        for i in 0..sections.n_items() {
-                            if let Some(store) = sections.item(i).and_downcast::<gio::ListStore>() {
-                                Self::remove_post_from_store(&store, uri);
-                            }
-                        }
-                    }
-                }
-            }
-            return;
-        }
-        if let Some(row) = widget.downcast_ref::<PostRow>() {
-            if row.post_uri().as_deref() == Some(uri) {
-                thread_rows.push(row.clone());
-            }
-            return;
-        }
-        let mut child = widget.first_child();
-        while let Some(c) = child {
-            child = c.next_sibling();
-            Self::collect_post_removals(&c, uri, thread_rows);
-        }
-    }
+        let scheme_model = gtk4::StringList::new(&["System", "Light", "Dark"]);
+        let scheme_row = adw::ComboRow::builder()
+            .title("Color Scheme")
+            .model(&scheme_model)
+            .build();
 
-    fn remove_post_from_store(store: &gio::ListStore, uri: &str) {
-        let mut i = store.n_items();
-        while i > 0 {
-            i -= 1;
-            let matches = store
-                .item(i)
-                .and_downcast::<PostObject>()
-                .and_then(|o| o.post())
-                .is_some_and(|p| p.uri == uri);
-            if matches {
-                store.remove(i);
-            }
-        }
-    }
+        let active_index = match current_settings.color_scheme {
+            crate::state::ColorScheme::System => 0u32,
+            crate::state::ColorScheme::Light => 1,
+            crate::state::ColorScheme::Dark => 2,
+        };
+        scheme_row.set_selected(active_index);
 
-    // ======== Settings Page ========
+        scheme_row.update_property(&[gtk4::accessible::Property::Label("Color scheme preference")]);
 
-    fn build_settings_page(&self) -> adw::ToolbarView {
-        use crate::state::AppSettings;
+        let window_weak = self.downgrade();
+        scheme_row.connect_selected_notify(move |row| {
+            let scheme = match row.selected() {
+                1 => crate::state::ColorScheme::Light,
+                2 => crate::state::ColorScheme::Dark,
+                _ => crate::state::ColorScheme::System,
+            };
 
-        // Header bar with back button and "Settings" title
-        let header = adw::HeaderBar::new();
-        header.set_show_start_title_buttons(false);
-        header.set_show_end_title_buttons(false);
-        header.set_title_widget(Some(&adw::WindowTitle::new("Settings", "")));
+            let mut settings = crate::state::AppSettings::load();
+            settings.color_scheme = scheme;
+            if let Err(e) = settings.save() {
+                eprintln!("Failed to save settings: {e}");
+            }
 
-        // Back button
-        let back_btn = gtk4::Button::from_icon_name("go-previous-symbolic");
-        back_btn.add_css_class("flat");
-        back_btn.set_tooltip_text(Some("Back"));
-        back_btn.update_property(&[gtk4::accessible::Property::Label("Back to previous page")]);
-        let window_weak = self.downgrade();
-        back_btn.connect_clicked(move |_| {
             if let Some(window) = window_weak.upgrade() {
-                window.leave_settings();
+                window.apply_color_scheme(scheme);
             }
         });
-        header.pack_start(&back_btn);
 
-        let window_controls = gtk4::WindowControls::new(gtk4::PackType::End);
-        header.pack_end(&window_controls);
+        appearance_group.add(&scheme_row);
+        page.add(&appearance_group);
 
-        // Split layout: category list on the left, one page per category right
-        let split_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
-        split_box.set_vexpand(true);
+        // ---- Post Text Size section ----
+        let display_group = adw::PreferencesGroup::new();
+        display_group.set_title("Post Text Size");
+        display_group.set_description(Some("Applies to posts in your feed and the compose view."));
 
-        // ---- Settings sidebar ----
-        let sidebar_scrolled = gtk4::ScrolledWindow::new();
-        sidebar_scrolled.set_policy(gtk4::PolicyType::Never, gtk4::PolicyType::Automatic);
-        sidebar_scrolled.set_vexpand(true);
-        // Fixed width for the settings sidebar
-        sidebar_scrolled.set_size_request(200, -1);
+        // Text Size label row
+        let size_label_row = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+        size_label_row.set_margin_top(8);
+        let size_title = gtk4::Label::new(Some("Size:"));
+        size_title.set_halign(gtk4::Align::Start);
+        size_title.add_css_class("heading");
+        size_label_row.append(&size_title);
+        let size_spacer = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+        size_spacer.set_hexpand(true);
+        size_label_row.append(&size_spacer);
+        let size_value_label = gtk4::Label::new(Some("Default"));
+        size_value_label.add_css_class("dim-label");
+        size_label_row.append(&size_value_label);
 
-        let sidebar_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
-        sidebar_box.add_css_class("settings-sidebar");
+        // Slider
+        let scale = gtk4::Scale::with_range(
+            gtk4::Orientation::Horizontal,
+            FontSize::MIN,
+            FontSize::MAX,
+            FontSize::STEP,
+        );
+        scale.set_draw_value(false);
+        scale.set_hexpand(true);
+        scale.set_margin_top(4);
+        scale.set_margin_bottom(4);
+        // The "Size:" label sits in its own row, so the slider is not named
+        // by anything; without this it is an unnamed slider to a screen reader.
+        scale.update_relation(&[gtk4::accessible::Relation::LabelledBy(&[
+            size_title.upcast_ref()
+        ])]);
 
-        let sidebar_list = gtk4::ListBox::new();
-        sidebar_list.set_selection_mode(gtk4::SelectionMode::Single);
-        sidebar_list.add_css_class("navigation-sidebar");
-        sidebar_list.update_property(&[gtk4::accessible::Property::Label("Settings categories")]);
+        // Add marks at each step
+        for &step in FontSize::STEPS {
+            scale.add_mark(step, gtk4::PositionType::Bottom, None);
+        }
 
-        // ---- Content stack ----
-        let content_stack = adw::ViewStack::new();
-        content_stack.set_hexpand(true);
-        content_stack.set_vexpand(true);
+        // Load current font size
+        scale.set_value(current_settings.font_size.scale_factor());
+        size_value_label.set_text(current_settings.font_size.label());
 
-        // Load current settings once
-        let current_settings = AppSettings::load();
+        // ---- Preview post ----
+        let preview_frame = gtk4::Frame::new(None);
+        preview_frame.set_margin_top(16);
+        preview_frame.add_css_class("card");
 
-        // Sidebar order.
-        let pages = [
-            self.build_settings_feed_page(&current_settings),
-            self.build_settings_display_page(&current_settings),
-            self.build_settings_accessibility_page(&current_settings),
-            self.build_settings_account_page(),
-        ];
+        let preview_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 10);
+        preview_box.set_margin_top(12);
+        preview_box.set_margin_bottom(12);
+        preview_box.set_margin_start(12);
+        preview_box.set_margin_end(12);
+        preview_box.add_css_class("text-size-preview");
 
-        for page in &pages {
-            // AdwPreferencesPage carries its own name, title and icon, so the
-            // sidebar row and stack page come from one source. A page that
-            // forgets set_name or set_icon_name would silently vanish from
-            // both, so warn instead of skipping.
-            let (Some(name), Some(icon_name)) = (page.name(), page.icon_name()) else {
-                debug_assert!(
-                    false,
-                    "settings page \"{}\" has no name or no icon and cannot be shown",
-                    page.title()
-                );
-                eprintln!(
-                    "hangar: settings page \"{}\" has no name or no icon and was not added",
-                    page.title()
-                );
-                continue;
-            };
-            let label = page.title();
-            content_stack.add_titled_with_icon(page, Some(&name), &label, &icon_name);
+        // Preview avatar
+        let preview_avatar = adw::Avatar::new(42, Some("Hangar"), true);
+        let avatar_col = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        avatar_col.set_valign(gtk4::Align::Start);
+        avatar_col.append(&preview_avatar);
+        preview_box.append(&avatar_col);
 
-            let row = gtk4::ListBoxRow::new();
-            row.set_widget_name(&name);
-            let row_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 10);
-            row_box.set_margin_top(8);
-            row_box.set_margin_bottom(8);
-            row_box.set_margin_start(12);
-            row_box.set_margin_end(12);
-            let icon = gtk4::Image::from_icon_name(&icon_name);
-            icon.set_pixel_size(18);
-            row_box.append(&icon);
-            let lbl = gtk4::Label::new(Some(&label));
-            lbl.set_halign(gtk4::Align::Start);
-            row_box.append(&lbl);
-            row.set_child(Some(&row_box));
-            row.update_property(&[gtk4::accessible::Property::Label(&label)]);
-            sidebar_list.append(&row);
-        }
+        // Preview content
+        let preview_content = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
+        preview_content.set_hexpand(true);
 
-        sidebar_box.append(&sidebar_list);
-        sidebar_scrolled.set_child(Some(&sidebar_box));
+        // Preview header
+        let preview_header = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+        let preview_name = gtk4::Label::new(Some("Hangar for Bluesky"));
+        preview_name.set_halign(gtk4::Align::Start);
+        preview_name.add_css_class("preview-heading");
+        preview_header.append(&preview_name);
+        let preview_handle = gtk4::Label::new(Some("  @hangar.app"));
+        preview_handle.add_css_class("dim-label");
+        preview_handle.add_css_class("preview-caption");
+        preview_header.append(&preview_handle);
+        let preview_dot = gtk4::Label::new(Some("  \u{00b7}  "));
+        preview_dot.add_css_class("dim-label");
+        preview_dot.add_css_class("preview-caption");
+        preview_header.append(&preview_dot);
+        let preview_time = gtk4::Label::new(Some("2m"));
+        preview_time.add_css_class("dim-label");
+        preview_time.add_css_class("preview-caption");
+        preview_header.append(&preview_time);
+        preview_content.append(&preview_header);
 
-        // Separator between sidebar and content
-        let separator = gtk4::Separator::new(gtk4::Orientation::Vertical);
+        // Preview body text
+        let preview_body = gtk4::Label::new(Some(
+            "This is a preview of how posts will look at this text size. Adjust the slider above to find your preferred reading size.",
+        ));
+        preview_body.set_halign(gtk4::Align::Fill);
+        preview_body.set_hexpand(true);
+        preview_body.set_wrap(true);
+        preview_body.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
+        preview_body.set_xalign(0.0);
+        preview_body.add_css_class("preview-body");
+        preview_content.append(&preview_body);
 
-        // Selection rather than activation, so arrowing through the categories
-        // shows each one without needing a second keypress.
-        let stack_ref = content_stack.clone();
-        sidebar_list.connect_row_selected(move |_, row| {
-            if let Some(row) = row {
-                let name = row.widget_name();
-                stack_ref.set_visible_child_name(&name);
-            }
-        });
+        // Preview action bar
+        let preview_actions = gtk4::Box::new(gtk4::Orientation::Horizontal, 16);
+        preview_actions.set_margin_top(8);
+        let reply_icon = gtk4::Image::from_icon_name("mail-reply-sender-symbolic");
+        reply_icon.add_css_class("dim-label");
+        reply_icon.set_pixel_size(16);
+        preview_actions.append(&reply_icon);
+        let repost_icon = gtk4::Image::from_icon_name("media-playlist-repeat-symbolic");
+        repost_icon.add_css_class("dim-label");
+        repost_icon.set_pixel_size(16);
+        preview_actions.append(&repost_icon);
+        let like_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 4);
+        let like_icon = gtk4::Image::from_icon_name("emote-love-symbolic");
+        like_icon.add_css_class("dim-label");
+        like_icon.set_pixel_size(16);
+        like_box.append(&like_icon);
+        let like_count = gtk4::Label::new(Some("42"));
+        like_count.add_css_class("dim-label");
+        like_count.add_css_class("preview-caption");
+        like_box.append(&like_count);
+        preview_actions.append(&like_box);
+        preview_content.append(&preview_actions);
 
-        // Select first row by default
-        if let Some(first_row) = sidebar_list.row_at_index(0) {
-            sidebar_list.select_row(Some(&first_row));
-        }
+        preview_box.append(&preview_content);
+        preview_frame.set_child(Some(&preview_box));
 
-        split_box.append(&sidebar_scrolled);
-        split_box.append(&separator);
-        split_box.append(&content_stack);
+        // Assemble the display group using a custom layout box
+        let display_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        display_box.append(&size_label_row);
+        display_box.append(&scale);
+        display_box.append(&preview_frame);
+        display_group.add(&display_box);
+        page.add(&display_group);
 
-        // ToolbarView rather than a plain Box, so the header picks up the
-        // shading that says the page under it has scrolled.
-        let toolbar = adw::ToolbarView::new();
-        toolbar.add_top_bar(&header);
-        toolbar.set_content(Some(&split_box));
-        toolbar
+        // Handle slider value changes
+        let window_weak = self.downgrade();
+        let label_ref = size_value_label.clone();
+        scale.connect_value_changed(move |s| {
+            let value = s.value();
+            let snapped = (value / FontSize::STEP).round() * FontSize::STEP;
+            let font_size = FontSize(snapped);
+
+            label_ref.set_text(font_size.label());
+
+            let mut settings = crate::state::AppSettings::load();
+            settings.font_size = font_size;
+            if let Err(e) = settings.save() {
+                eprintln!("Failed to save settings: {e}");
+            }
+
+            if let Some(window) = window_weak.upgrade() {
+                window.apply_font_size(font_size);
+                if let Some(cb) = window.imp().font_size_changed_callback.borrow().as_ref() {
+                    cb(font_size);
+                }
+            }
+        });
+
+        page
     }
 
-    /// Build the Feed category of the settings page
-    fn build_settings_feed_page(
+    /// Build the Accessibility category of the settings page
+    fn build_settings_accessibility_page(
         &self,
         current_settings: &crate::state::AppSettings,
     ) -> adw::PreferencesPage {
         let page = adw::PreferencesPage::new();
-        page.set_name(Some("feed"));
-        page.set_title("Feed");
-        page.set_icon_name(Some("view-list-symbolic"));
+        page.set_name(Some("accessibility"));
+        page.set_title("Accessibility");
+        page.set_icon_name(Some("preferences-desktop-accessibility-symbolic"));
 
-        let feed_group = adw::PreferencesGroup::new();
-        feed_group.set_title("Timeline");
-        feed_group.set_description(Some(
-            "Controls what appears in your main timeline. Threads, profiles and likes are unaffected.",
+        // ---- Motion section ----
+        let motion_group = adw::PreferencesGroup::new();
+        motion_group.set_title("Motion");
+        motion_group.set_description(Some(
+            "System reduced-motion preference is always respected. This toggle forces reduced motion regardless of system setting.",
         ));
 
-        let hide_replies_row = adw::ActionRow::builder()
-            .title("Hide Replies")
-            .subtitle("Show only top-level posts, the way bsky.app does")
+        // Reduce Motion toggle using ActionRow
+        let reduce_motion_row = adw::ActionRow::builder()
+            .title("Reduce Motion")
+            .subtitle("Disable animations and transitions")
             .build();
 
-        let hide_replies_switch = gtk4::Switch::new();
-        hide_replies_switch.set_valign(gtk4::Align::Center);
-        hide_replies_switch.set_active(current_settings.hide_replies_in_feed);
-        hide_replies_switch.set_tooltip_text(Some("Toggle replies in the main feed"));
-        hide_replies_switch
-            .update_property(&[gtk4::accessible::Property::Label("Hide replies in feed")]);
+        let reduce_motion_switch = gtk4::Switch::new();
+        reduce_motion_switch.set_valign(gtk4::Align::Center);
+        reduce_motion_switch.set_active(current_settings.reduce_motion);
+        reduce_motion_switch.set_tooltip_text(Some("Toggle reduced motion"));
+        reduce_motion_switch.update_property(&[gtk4::accessible::Property::Label("Reduce motion")]);
 
         let window_weak = self.downgrade();
-        hide_replies_switch.connect_state_set(move |_switch, state| {
+        reduce_motion_switch.connect_state_set(move |_switch, state| {
             let mut settings = crate::state::AppSettings::load();
-            settings.hide_replies_in_feed = state;
+            settings.reduce_motion = state;
             if let Err(e) = settings.save() {
                 eprintln!("Failed to save settings: {e}");
             }
 
-            // `filter_feed_posts` runs as a batch enters the model, so writing
-            // the file alone left the timeline as it was until the next fetch.
-            // Refetch.
             if let Some(window) = window_weak.upgrade() {
-                window.refresh_feed();
+                window.apply_reduce_motion(state);
             }
             glib::Propagation::Proceed
         });
 
-        hide_replies_row.add_suffix(&hide_replies_switch);
-        hide_replies_row.set_activatable_widget(Some(&hide_replies_switch));
-        feed_group.add(&hide_replies_row);
-
-        page.add(&feed_group);
+        reduce_motion_row.add_suffix(&reduce_motion_switch);
+        reduce_motion_row.set_activatable_widget(Some(&reduce_motion_switch));
+        motion_group.add(&reduce_motion_row);
+        page.add(&motion_group);
 
-        // ---- Media ----
+        // Media
         let media_group = adw::PreferencesGroup::new();
         media_group.set_title("Media");
-        media_group.set_description(Some(
-            "Applies to the main timeline. Threads, profiles and search always wait for you to press play.",
-        ));
 
-        let autoplay_labels: Vec<&str> = crate::state::VideoAutoplay::ALL
-            .iter()
-            .map(|mode| mode.label())
-            .collect();
-        let autoplay_model = gtk4::StringList::new(&autoplay_labels);
-        let autoplay_row = adw::ComboRow::builder()
-            .title("Autoplay Videos")
-            .subtitle("Play the video nearest the middle of the feed as you scroll")
-            .model(&autoplay_model)
+        let require_alt_row = adw::ActionRow::builder()
+            .title("Require Alt Text")
+            .subtitle("Posting waits until every attached image is described")
             .build();
-        let selected = crate::state::VideoAutoplay::ALL
-            .iter()
-            .position(|mode| *mode == current_settings.video_autoplay)
-            .unwrap_or(0);
-        autoplay_row.set_selected(selected as u32);
-        autoplay_row.update_property(&[gtk4::accessible::Property::Label(
-            "Autoplay videos in the timeline",
-        )]);
 
-        let window_weak = self.downgrade();
-        autoplay_row.connect_selected_notify(move |row| {
-            let mode = crate::state::VideoAutoplay::ALL
-                .get(row.selected() as usize)
-                .copied()
-                .unwrap_or_default();
+        let require_alt_switch = gtk4::Switch::new();
+        require_alt_switch.set_valign(gtk4::Align::Center);
+        require_alt_switch.set_active(current_settings.require_alt_text);
+        require_alt_switch
+            .update_property(&[gtk4::accessible::Property::Label("Require alt text")]);
 
+        require_alt_switch.connect_state_set(move |_switch, state| {
             let mut settings = crate::state::AppSettings::load();
-            settings.video_autoplay = mode;
+            settings.require_alt_text = state;
             if let Err(e) = settings.save() {
                 eprintln!("Failed to save settings: {e}");
             }
-
-            // No refetch needed here, unlike Hide Replies. The feed
-            // contents are the same and the director applies this live.
-            if let Some(window) = window_weak.upgrade() {
-                let director = window.imp().video_director.borrow().clone();
-                if let Some(director) = director {
-                    director.set_autoplay(mode);
-                }
-            }
+            // Compose dialogs read this when they open; nothing live to touch.
+            glib::Propagation::Proceed
         });
 
-        media_group.add(&autoplay_row);
+        require_alt_row.add_suffix(&require_alt_switch);
+        require_alt_row.set_activatable_widget(Some(&require_alt_switch));
+        media_group.add(&require_alt_row);
         page.add(&media_group);
 
         page
     }
 
-    /// Build the Display category of the settings page
-    fn build_settings_display_page(
+    /// Build the Notifications category of the settings page
+    fn build_settings_notifications_page(
         &self,
         current_settings: &crate::state::AppSettings,
     ) -> adw::PreferencesPage {
-        use crate::state::FontSize;
+        use crate::state::{DesktopNotifications, QuietHours};
 
         let page = adw::PreferencesPage::new();
-        page.set_name(Some("display"));
-        page.set_title("Display");
-        page.set_icon_name(Some("preferences-desktop-appearance-symbolic"));
+        page.set_name(Some("notifications"));
+        page.set_title("Notifications");
+        page.set_icon_name(Some("preferences-system-notifications-symbolic"));
 
-        // ---- Appearance section ----
-        let appearance_group = adw::PreferencesGroup::new();
-        appearance_group.set_title("Appearance");
+        let prefs = current_settings.notifications;
 
-        let scheme_model = gtk4::StringList::new(&["System", "Light", "Dark"]);
-        let scheme_row = adw::ComboRow::builder()
-            .title("Color Scheme")
-            .model(&scheme_model)
+        // ---- Per-reason toggles ----
+        let reasons_group = adw::PreferencesGroup::new();
+        reasons_group.set_title("Desktop Notifications");
+        reasons_group.set_description(Some(
+            "Shown while Hangar is in the background. Likes and reposts only update the Activity badge.",
+        ));
+
+        let toggles: [(&str, &str, bool, fn(&mut DesktopNotifications, bool)); 5] = [
+            (
+                "Mentions",
+                "Posts that mention you",
+                prefs.mentions,
+                |n, on| n.mentions = on,
+            ),
+            (
+                "Replies",
+                "Replies to your posts",
+                prefs.replies,
+                |n, on| n.replies = on,
+            ),
+            ("Quotes", "Posts that quote yours", prefs.quotes, |n, on| {
+                n.quotes = on
+            }),
+            (
+                "New Followers",
+                "Accounts that follow you",
+                prefs.follows,
+                |n, on| n.follows = on,
+            ),
+            (
+                "Messages",
+                "Chat messages, except in muted conversations",
+                prefs.messages,
+                |n, on| n.messages = on,
+            ),
+        ];
+        for (title, subtitle, active, set) in toggles {
+            let row = adw::ActionRow::builder()
+                .title(title)
+                .subtitle(subtitle)
+                .build();
+            let switch = gtk4::Switch::new();
+            switch.set_valign(gtk4::Align::Center);
+            switch.set_active(active);
+            switch.update_property(&[gtk4::accessible::Property::Label(&format!(
+                "Notify for {}",
+                title.to_lowercase()
+            ))]);
+            switch.connect_state_set(move |_switch, state| {
+                let mut settings = crate::state::AppSettings::load();
+                set(&mut settings.notifications, state);
+                if let Err(e) = settings.save() {
+                    eprintln!("Failed to save settings: {e}");
+                }
+                // The unread poll reads these each time it raises anything.
+                glib::Propagation::Proceed
+            });
+            row.add_suffix(&switch);
+            row.set_activatable_widget(Some(&switch));
+            reasons_group.add(&row);
+        }
+        page.add(&reasons_group);
+
+        // ---- Quiet hours ----
+        let quiet_group = adw::PreferencesGroup::new();
+        quiet_group.set_title("Quiet Hours");
+        quiet_group.set_description(Some(
+            "Nothing is shown during these hours. The badges still update.",
+        ));
+
+        let quiet = prefs.quiet_hours;
+        let shown = quiet.unwrap_or(QuietHours::DEFAULT);
+
+        let quiet_row = adw::ActionRow::builder()
+            .title("Quiet Hours")
+            .subtitle("Silence notifications every day between two times")
             .build();
+        let quiet_switch = gtk4::Switch::new();
+        quiet_switch.set_valign(gtk4::Align::Center);
+        quiet_switch.set_active(quiet.is_some());
+        quiet_switch.update_property(&[gtk4::accessible::Property::Label("Quiet hours")]);
+        quiet_row.add_suffix(&quiet_switch);
+        quiet_row.set_activatable_widget(Some(&quiet_switch));
+        quiet_group.add(&quiet_row);
+
+        // Whole hours. The setting keeps minutes, so finer steps can come
+        // later without a migration.
+        let hour_labels: Vec<String> = (0..24).map(|h| format!("{h:02}:00")).collect();
+        let hour_labels: Vec<&str> = hour_labels.iter().map(String::as_str).collect();
+        let hour_row = |title: &str, a11y: &str, minute: u16| {
+            let row = adw::ComboRow::builder()
+                .title(title)
+                .model(&gtk4::StringList::new(&hour_labels))
+                .build();
+            row.set_selected(u32::from(minute / 60).min(23));
+            row.set_sensitive(quiet.is_some());
+            row.update_property(&[gtk4::accessible::Property::Label(a11y)]);
+            row
+        };
+        let start_row = hour_row("From", "Quiet hours start", shown.start);
+        let end_row = hour_row("Until", "Quiet hours end", shown.end);
+        quiet_group.add(&start_row);
+        quiet_group.add(&end_row);
+
+        // Every change writes the whole window from what the rows show, so
+        // the switch and both times cannot disagree on disk. Weak, because
+        // each of the three handlers holds this and each widget holds one.
+        let save_quiet = {
+            let quiet_switch = quiet_switch.downgrade();
+            let start_row = start_row.downgrade();
+            let end_row = end_row.downgrade();
+            move || {
+                let (Some(quiet_switch), Some(start_row), Some(end_row)) = (
+                    quiet_switch.upgrade(),
+                    start_row.upgrade(),
+                    end_row.upgrade(),
+                ) else {
+                    return;
+                };
+                start_row.set_sensitive(quiet_switch.is_active());
+                end_row.set_sensitive(quiet_switch.is_active());
 
-        let active_index = match current_settings.color_scheme {
-            crate::state::ColorScheme::System => 0u32,
-            crate::state::ColorScheme::Light => 1,
-            crate::state::ColorScheme::Dark => 2,
+                let mut settings = crate::state::AppSettings::load();
+                settings.notifications.quiet_hours = quiet_switch.is_active().then(|| QuietHours {
+                    start: start_row.selected() as u16 * 60,
+                    end: end_row.selected() as u16 * 60,
+                });
+                if let Err(e) = settings.save() {
+                    eprintln!("Failed to save settings: {e}");
+                }
+            }
         };
-        scheme_row.set_selected(active_index);
 
-        scheme_row.update_property(&[gtk4::accessible::Property::Label("Color scheme preference")]);
+        {
+            let save_quiet = save_quiet.clone();
+            quiet_switch.connect_active_notify(move |_| save_quiet());
+        }
+        {
+            let save_quiet = save_quiet.clone();
+            start_row.connect_selected_notify(move |_| save_quiet());
+        }
+        end_row.connect_selected_notify(move |_| save_quiet());
+
+        page.add(&quiet_group);
+
+        // ---- Background ----
+        let background_group = adw::PreferencesGroup::new();
+        background_group.set_title("Background");
+        background_group.set_description(Some(
+            "Keep checking for notifications and messages with the window closed. Ctrl+Q quits.",
+        ));
 
+        let background_row = adw::ActionRow::builder()
+            .title("Run in Background")
+            .subtitle("Closing the window leaves Hangar running")
+            .build();
+        let background_switch = gtk4::Switch::new();
+        background_switch.set_valign(gtk4::Align::Center);
+        let run_in_background = crate::state::AppSettings::shared_run_in_background();
+        background_switch.set_active(run_in_background);
+        background_switch
+            .update_property(&[gtk4::accessible::Property::Label("Run in background")]);
+        background_row.add_suffix(&background_switch);
+        background_row.set_activatable_widget(Some(&background_switch));
+        background_group.add(&background_row);
+
+        let autostart_row = adw::ActionRow::builder()
+            .title("Start at Login")
+            .subtitle("Start in the background when you log in")
+            .build();
+        let autostart_switch = gtk4::Switch::new();
+        autostart_switch.set_valign(gtk4::Align::Center);
+        autostart_switch.set_active(crate::background::autostart_enabled());
+        autostart_switch.update_property(&[gtk4::accessible::Property::Label("Start at login")]);
+        autostart_row.add_suffix(&autostart_switch);
+        autostart_row.set_activatable_widget(Some(&autostart_switch));
+        // Starting at login only makes sense for an app that stays running.
+        autostart_row.set_sensitive(run_in_background);
+        background_group.add(&autostart_row);
+
+        // The entry is the setting: the switch reads the file, and a write
+        // that fails puts the switch back to what is on disk.
         let window_weak = self.downgrade();
-        scheme_row.connect_selected_notify(move |row| {
-            let scheme = match row.selected() {
-                1 => crate::state::ColorScheme::Light,
-                2 => crate::state::ColorScheme::Dark,
-                _ => crate::state::ColorScheme::System,
-            };
+        autostart_switch.connect_active_notify(move |switch| {
+            let wanted = switch.is_active();
+            if let Err(e) = crate::background::set_autostart(wanted) {
+                eprintln!("hangar: {e}");
+                if let Some(window) = window_weak.upgrade() {
+                    window.show_toast("Couldn't change Start at Login");
+                }
+                let actual = crate::background::autostart_enabled();
+                if actual != wanted {
+                    switch.set_active(actual);
+                }
+            }
+        });
 
-            let mut settings = crate::state::AppSettings::load();
-            settings.color_scheme = scheme;
-            if let Err(e) = settings.save() {
+        background_switch.connect_active_notify(move |switch| {
+            let on = switch.is_active();
+            if let Err(e) = crate::state::AppSettings::set_shared_run_in_background(on) {
                 eprintln!("Failed to save settings: {e}");
             }
+            autostart_row.set_sensitive(on);
+            // A login launch would only hold an app that quits on close.
+            if !on {
+                autostart_switch.set_active(false);
+            }
+        });
+
+        page.add(&background_group);
+
+        page
+    }
+
+    /// Build the Alerts category of the settings page: which notifications
+    /// the server lists and pushes at all. Filled by
+    /// `set_notification_prefs`, since the page is built before sign-in.
+    fn build_settings_alerts_page(&self) -> adw::PreferencesPage {
+        let page = adw::PreferencesPage::new();
+        page.set_name(Some("alerts"));
+        page.set_title("Alerts");
+        page.set_icon_name(Some("alarm-symbolic"));
+
+        let categories_group = adw::PreferencesGroup::new();
+        categories_group.set_title("Notify Me About");
+        categories_group.set_description(Some(
+            "What Bluesky lists in Activity and pushes to your phone. Saved to your account, so other apps follow it too.",
+        ));
+        page.add(&categories_group);
+
+        let chat_group = adw::PreferencesGroup::new();
+        chat_group.set_title("Chat");
+        page.add(&chat_group);
+
+        let imp = self.imp();
+        imp.notification_categories_group
+            .replace(Some(categories_group));
+        imp.notification_chat_group.replace(Some(chat_group));
+        self.rebuild_notification_pref_rows();
+
+        page
+    }
+
+    /// Hand a notification preference change to the app, which writes it
+    /// and calls `set_notification_prefs` with what the server kept.
+    fn request_notification_pref_edit(&self, edit: NotificationPrefEdit) {
+        if let Some(cb) = self.imp().notification_pref_edit_callback.borrow().as_ref() {
+            cb(edit);
+        }
+    }
+
+    pub fn set_notification_pref_edit_callback<F: Fn(NotificationPrefEdit) + 'static>(
+        &self,
+        callback: F,
+    ) {
+        self.imp()
+            .notification_pref_edit_callback
+            .replace(Some(Box::new(callback)));
+    }
+
+    /// One category's row: who from, in the app, and pushed. Every change
+    /// sends the whole category as the row now shows it.
+    fn notification_category_row(
+        &self,
+        category: NotificationCategory,
+        pref: CategoryPref,
+    ) -> adw::ExpanderRow {
+        let row = adw::ExpanderRow::builder()
+            .title(category.label())
+            .subtitle(pref.summary())
+            .build();
+        row.set_widget_name(category.key());
+
+        // Weak, because the controls this hands out live inside the row.
+        let current = Cell::new(pref);
+        let send: Rc<dyn Fn(&dyn Fn(&mut CategoryPref))> = {
+            let window_weak = self.downgrade();
+            let row_weak = row.downgrade();
+            Rc::new(move |change: &dyn Fn(&mut CategoryPref)| {
+                let mut pref = current.get();
+                change(&mut pref);
+                current.set(pref);
+                if let Some(row) = row_weak.upgrade() {
+                    row.set_subtitle(&pref.summary());
+                }
+                if let Some(window) = window_weak.upgrade() {
+                    window.request_notification_pref_edit(NotificationPrefEdit::Category(
+                        category, pref,
+                    ));
+                }
+            })
+        };
+
+        // Connected after the initial values, so building asks for nothing.
+        if let Some(include) = pref.include {
+            const FROM: [IncludeFrom; 2] = [IncludeFrom::Everyone, IncludeFrom::Follows];
+            let from_row = adw::ComboRow::builder()
+                .title("From")
+                .model(&gtk4::StringList::new(&FROM.map(IncludeFrom::label)))
+                .build();
+            from_row
+                .set_selected(FROM.iter().position(|from| *from == include).unwrap_or(0) as u32);
+            let send = send.clone();
+            from_row.connect_selected_notify(move |from_row| {
+                if let Some(from) = FROM.get(from_row.selected() as usize).copied() {
+                    send(&|pref: &mut CategoryPref| pref.include = Some(from));
+                }
+            });
+            row.add_row(&from_row);
+        }
+
+        let in_app_row = adw::SwitchRow::builder()
+            .title("In the App")
+            .subtitle("Listed in Activity and Mentions")
+            .active(pref.in_app)
+            .build();
+        let send_in_app = send.clone();
+        in_app_row.connect_active_notify(move |in_app_row| {
+            let on = in_app_row.is_active();
+            send_in_app(&|pref: &mut CategoryPref| pref.in_app = on);
+        });
+        row.add_row(&in_app_row);
+
+        let push_row = adw::SwitchRow::builder()
+            .title("Push Notifications")
+            .subtitle("Sent to the Bluesky app on your phone")
+            .active(pref.push)
+            .build();
+        push_row.connect_active_notify(move |push_row| {
+            let on = push_row.is_active();
+            send(&|pref: &mut CategoryPref| pref.push = on);
+        });
+        row.add_row(&push_row);
+
+        row
+    }
+
+    /// List the notification preferences as the server has them.
+    fn rebuild_notification_pref_rows(&self) {
+        let imp = self.imp();
+        let (Some(categories_group), Some(chat_group)) = (
+            imp.notification_categories_group.borrow().clone(),
+            imp.notification_chat_group.borrow().clone(),
+        ) else {
+            return;
+        };
+        // Every saved change comes back through here; the row being
+        // changed stays open.
+        let mut expanded = Vec::new();
+        for (group, row) in imp.notification_pref_rows.take() {
+            if let Some(expander) = row.downcast_ref::<adw::ExpanderRow>()
+                && expander.is_expanded()
+            {
+                expanded.push(expander.widget_name());
+            }
+            group.remove(&row);
+        }
+
+        let Some(prefs) = imp.notification_prefs.borrow().clone() else {
+            return;
+        };
+        let mut rows: Vec<(adw::PreferencesGroup, gtk4::Widget)> = Vec::new();
+        for (category, pref) in prefs.categories {
+            let row = self.notification_category_row(category, pref);
+            row.set_expanded(expanded.iter().any(|name| name.as_str() == category.key()));
+            rows.push((categories_group.clone(), row.upcast()));
+        }
+
+        let chat_row = adw::ComboRow::builder()
+            .title("Notify About Messages From")
+            .model(&gtk4::StringList::new(
+                &ChatNotify::ALL.map(ChatNotify::label),
+            ))
+            .build();
+        chat_row.set_selected(
+            ChatNotify::ALL
+                .iter()
+                .position(|chat| *chat == prefs.chat)
+                .unwrap_or(0) as u32,
+        );
+        let window_weak = self.downgrade();
+        chat_row.connect_selected_notify(move |chat_row| {
+            let (Some(window), Some(chat)) = (
+                window_weak.upgrade(),
+                ChatNotify::ALL.get(chat_row.selected() as usize).copied(),
+            ) else {
+                return;
+            };
+            window.request_notification_pref_edit(NotificationPrefEdit::Chat(chat));
+        });
+        rows.push((chat_group.clone(), chat_row.upcast()));
+
+        for (group, row) in &rows {
+            group.add(row);
+        }
+        imp.notification_pref_rows.replace(rows);
+    }
+
+    /// Show the account's notification preferences in Settings.
+    pub fn set_notification_prefs(&self, prefs: NotificationPrefs) {
+        self.imp().notification_prefs.replace(Some(prefs));
+        self.rebuild_notification_pref_rows();
+    }
 
+    /// Build the Moderation category of the settings page
+    fn build_settings_moderation_page(&self) -> adw::PreferencesPage {
+        let page = adw::PreferencesPage::new();
+        page.set_name(Some("moderation"));
+        page.set_title("Moderation");
+        page.set_icon_name(Some("action-unavailable-symbolic"));
+
+        // ---- Muted words ----
+        // Filled by `set_muted_words` once the account's preferences arrive;
+        // the page is built before anyone signs in.
+        let muted_group = adw::PreferencesGroup::new();
+        muted_group.set_title("Muted Words &amp; Tags");
+        muted_group.set_description(Some(
+            "Posts containing these are hidden from your feeds, notifications and search. Saved to your account, so other apps hide them too.",
+        ));
+
+        let add_btn = gtk4::Button::from_icon_name("list-add-symbolic");
+        add_btn.add_css_class("flat");
+        add_btn.set_valign(gtk4::Align::Center);
+        add_btn.set_tooltip_text(Some("Add a muted word"));
+        add_btn.update_property(&[gtk4::accessible::Property::Label("Add a muted word")]);
+        let window_weak = self.downgrade();
+        add_btn.connect_clicked(move |_| {
+            if let Some(window) = window_weak.upgrade() {
+                window.present_muted_word_dialog(None);
+            }
+        });
+        muted_group.set_header_suffix(Some(&add_btn));
+
+        page.add(&muted_group);
+        self.imp().muted_words_group.replace(Some(muted_group));
+        self.rebuild_muted_word_rows();
+
+        // ---- Moderation lists ----
+        // Filled by `set_moderation_lists` each time Settings opens.
+        let lists_group = adw::PreferencesGroup::new();
+        lists_group.set_title("Moderation Lists");
+        lists_group.set_description(Some(
+            "Lists you mute or block. Everyone on them is muted or blocked for as long as the list has them.",
+        ));
+        page.add(&lists_group);
+        self.imp().moderation_lists_group.replace(Some(lists_group));
+        self.rebuild_moderation_list_rows();
+
+        // ---- Content filters ----
+        // Bluesky's own labels. Filled by `set_moderation_prefs`, like the
+        // muted words.
+        let filter_group = adw::PreferencesGroup::new();
+        filter_group.set_title("Content Filters");
+        filter_group.set_description(Some(
+            "How labeled posts show. Saved to your account, so other apps follow them too.",
+        ));
+        page.add(&filter_group);
+        self.imp().content_filter_group.replace(Some(filter_group));
+
+        // ---- Labelers ----
+        let labelers_group = adw::PreferencesGroup::new();
+        labelers_group.set_title("Labelers");
+        labelers_group.set_description(Some(
+            "Moderation services beyond Bluesky's own. Each applies labels of its own, set here one by one.",
+        ));
+        let add_labeler_btn = gtk4::Button::from_icon_name("list-add-symbolic");
+        add_labeler_btn.add_css_class("flat");
+        add_labeler_btn.set_valign(gtk4::Align::Center);
+        add_labeler_btn.set_tooltip_text(Some("Subscribe to a labeler"));
+        add_labeler_btn
+            .update_property(&[gtk4::accessible::Property::Label("Subscribe to a labeler")]);
+        let window_weak = self.downgrade();
+        add_labeler_btn.connect_clicked(move |_| {
             if let Some(window) = window_weak.upgrade() {
-                window.apply_color_scheme(scheme);
+                window.present_labeler_dialog();
             }
         });
+        labelers_group.set_header_suffix(Some(&add_labeler_btn));
+        page.add(&labelers_group);
+        self.imp().labelers_group.replace(Some(labelers_group));
+        self.rebuild_moderation_pref_rows();
 
-        appearance_group.add(&scheme_row);
-        page.add(&appearance_group);
+        page
+    }
 
-        // ---- Post Text Size section ----
-        let display_group = adw::PreferencesGroup::new();
-        display_group.set_title("Post Text Size");
-        display_group.set_description(Some("Applies to posts in your feed and the compose view."));
+    /// Hand a moderation settings change to the app, which writes it to
+    /// the server and calls `set_moderation_prefs` with the result.
+    fn request_moderation_edit(&self, edit: ModerationEdit) {
+        if let Some(cb) = self.imp().moderation_edit_callback.borrow().as_ref() {
+            cb(edit);
+        }
+    }
 
-        // Text Size label row
-        let size_label_row = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
-        size_label_row.set_margin_top(8);
-        let size_title = gtk4::Label::new(Some("Size:"));
-        size_title.set_halign(gtk4::Align::Start);
-        size_title.add_css_class("heading");
-        size_label_row.append(&size_title);
-        let size_spacer = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
-        size_spacer.set_hexpand(true);
-        size_label_row.append(&size_spacer);
-        let size_value_label = gtk4::Label::new(Some("Default"));
-        size_value_label.add_css_class("dim-label");
-        size_label_row.append(&size_value_label);
+    pub fn set_moderation_edit_callback<F: Fn(ModerationEdit) + 'static>(&self, callback: F) {
+        self.imp()
+            .moderation_edit_callback
+            .replace(Some(Box::new(callback)));
+    }
 
-        // Slider
-        let scale = gtk4::Scale::with_range(
-            gtk4::Orientation::Horizontal,
-            FontSize::MIN,
-            FontSize::MAX,
-            FontSize::STEP,
+    /// One label's Show/Warn/Hide row. Changing it asks for the edit.
+    fn label_visibility_row(
+        &self,
+        title: &str,
+        subtitle: Option<&str>,
+        current: LabelVisibility,
+        labeler: Option<String>,
+        label: String,
+    ) -> adw::ComboRow {
+        const CHOICES: [(LabelVisibility, &str); 3] = [
+            (LabelVisibility::Ignore, "Show"),
+            (LabelVisibility::Warn, "Warn"),
+            (LabelVisibility::Hide, "Hide"),
+        ];
+        // Labelers name their own labels, so no markup.
+        let row = adw::ComboRow::builder()
+            .title(title)
+            .use_markup(false)
+            .model(&gtk4::StringList::new(&CHOICES.map(|(_, name)| name)))
+            .build();
+        if let Some(subtitle) = subtitle.filter(|s| !s.is_empty()) {
+            row.set_subtitle(subtitle);
+        }
+        row.set_selected(
+            CHOICES
+                .iter()
+                .position(|(visibility, _)| *visibility == current)
+                .unwrap_or(0) as u32,
         );
-        scale.set_draw_value(false);
-        scale.set_hexpand(true);
-        scale.set_margin_top(4);
-        scale.set_margin_bottom(4);
-        // The "Size:" label sits in its own row, so the slider is not named
-        // by anything; without this it is an unnamed slider to a screen reader.
-        scale.update_relation(&[gtk4::accessible::Relation::LabelledBy(&[
-            size_title.upcast_ref()
-        ])]);
+        // Connected after the initial selection, so building asks for nothing.
+        let window_weak = self.downgrade();
+        row.connect_selected_notify(move |row| {
+            let Some(window) = window_weak.upgrade() else {
+                return;
+            };
+            let Some((visibility, _)) = CHOICES.get(row.selected() as usize) else {
+                return;
+            };
+            window.request_moderation_edit(ModerationEdit::LabelVisibility {
+                labeler: labeler.clone(),
+                label: label.clone(),
+                visibility: *visibility,
+            });
+        });
+        row
+    }
 
-        // Add marks at each step
-        for &step in FontSize::STEPS {
-            scale.add_mark(step, gtk4::PositionType::Bottom, None);
+    /// List the content filters and subscribed labelers as the account's
+    /// preferences have them.
+    fn rebuild_moderation_pref_rows(&self) {
+        let imp = self.imp();
+        let (Some(filter_group), Some(labelers_group)) = (
+            imp.content_filter_group.borrow().clone(),
+            imp.labelers_group.borrow().clone(),
+        ) else {
+            return;
+        };
+        for (group, row) in imp.moderation_pref_rows.take() {
+            group.remove(&row);
         }
 
-        // Load current font size
-        scale.set_value(current_settings.font_size.scale_factor());
-        size_value_label.set_text(current_settings.font_size.label());
-
-        // ---- Preview post ----
-        let preview_frame = gtk4::Frame::new(None);
-        preview_frame.set_margin_top(16);
-        preview_frame.add_css_class("card");
+        let prefs = imp.moderation_prefs.borrow().clone();
+        let languages: Vec<&str> = prefs.languages.iter().map(String::as_str).collect();
+        let mut rows: Vec<(adw::PreferencesGroup, gtk4::Widget)> = Vec::new();
 
-        let preview_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 10);
-        preview_box.set_margin_top(12);
-        preview_box.set_margin_bottom(12);
-        preview_box.set_margin_start(12);
-        preview_box.set_margin_end(12);
-        preview_box.add_css_class("text-size-preview");
+        let adult_row = adw::SwitchRow::builder()
+            .title("Adult Content")
+            .subtitle("Off hides adult labels with no way to show them")
+            .active(prefs.adult_content_enabled)
+            .build();
+        let window_weak = self.downgrade();
+        adult_row.connect_active_notify(move |row| {
+            if let Some(window) = window_weak.upgrade() {
+                window.request_moderation_edit(ModerationEdit::AdultContent(row.is_active()));
+            }
+        });
+        rows.push((filter_group.clone(), adult_row.upcast()));
 
-        // Preview avatar
-        let preview_avatar = adw::Avatar::new(42, Some("Hangar"), true);
-        let avatar_col = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
-        avatar_col.set_valign(gtk4::Align::Start);
-        avatar_col.append(&preview_avatar);
-        preview_box.append(&avatar_col);
+        for (value, name, default) in moderation::configurable_global_labels() {
+            let current = prefs
+                .label_visibility
+                .get(value)
+                .copied()
+                .unwrap_or(default);
+            let row = self.label_visibility_row(name, None, current, None, value.to_string());
+            // Every configurable global label is an adult one.
+            row.set_sensitive(prefs.adult_content_enabled);
+            rows.push((filter_group.clone(), row.upcast()));
+        }
+
+        if prefs.labelers.is_empty() {
+            let row = adw::ActionRow::builder()
+                .title("No labelers")
+                .subtitle("Use the add button to subscribe to one by its handle")
+                .build();
+            row.add_css_class("dim-label");
+            rows.push((labelers_group.clone(), row.upcast()));
+        }
+        for labeler in &prefs.labelers {
+            let name = labeler
+                .creator
+                .display_name
+                .as_deref()
+                .filter(|n| !n.is_empty())
+                .unwrap_or(&labeler.creator.handle);
+            let expander = adw::ExpanderRow::builder()
+                .title(name)
+                .subtitle(format!("@{}", labeler.creator.handle))
+                .use_markup(false)
+                .build();
+
+            let remove_btn = gtk4::Button::from_icon_name("user-trash-symbolic");
+            remove_btn.add_css_class("flat");
+            remove_btn.set_valign(gtk4::Align::Center);
+            remove_btn.set_tooltip_text(Some("Unsubscribe"));
+            remove_btn.update_property(&[gtk4::accessible::Property::Label(&format!(
+                "Unsubscribe from {name}"
+            ))]);
+            let window_weak = self.downgrade();
+            let did = labeler.did.clone();
+            remove_btn.connect_clicked(move |btn| {
+                let Some(window) = window_weak.upgrade() else {
+                    return;
+                };
+                // One request per click; the list is rebuilt when the
+                // server answers.
+                btn.set_sensitive(false);
+                window.request_moderation_edit(ModerationEdit::Unsubscribe(did.clone()));
+            });
+            expander.add_suffix(&remove_btn);
+
+            if labeler.definitions.is_empty() {
+                let row = adw::ActionRow::builder()
+                    .title("Defines no labels of its own")
+                    .build();
+                row.add_css_class("dim-label");
+                expander.add_row(&row);
+            }
+            for def in &labeler.definitions {
+                let strings = def.strings(&languages);
+                let current = prefs
+                    .labeler_label_visibility
+                    .get(&(labeler.did.clone(), def.identifier.clone()))
+                    .copied()
+                    .unwrap_or(def.default_setting);
+                let row = self.label_visibility_row(
+                    &def.name(&languages),
+                    strings.map(|s| s.description.as_str()),
+                    current,
+                    Some(labeler.did.clone()),
+                    def.identifier.clone(),
+                );
+                row.set_sensitive(!def.adult_only || prefs.adult_content_enabled);
+                expander.add_row(&row);
+            }
+            rows.push((labelers_group.clone(), expander.upcast()));
+        }
 
-        // Preview content
-        let preview_content = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
-        preview_content.set_hexpand(true);
+        for (group, row) in &rows {
+            group.add(row);
+        }
+        imp.moderation_pref_rows.replace(rows);
+    }
 
-        // Preview header
-        let preview_header = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
-        let preview_name = gtk4::Label::new(Some("Hangar for Bluesky"));
-        preview_name.set_halign(gtk4::Align::Start);
-        preview_name.add_css_class("preview-heading");
-        preview_header.append(&preview_name);
-        let preview_handle = gtk4::Label::new(Some("  @hangar.app"));
-        preview_handle.add_css_class("dim-label");
-        preview_handle.add_css_class("preview-caption");
-        preview_header.append(&preview_handle);
-        let preview_dot = gtk4::Label::new(Some("  \u{00b7}  "));
-        preview_dot.add_css_class("dim-label");
-        preview_dot.add_css_class("preview-caption");
-        preview_header.append(&preview_dot);
-        let preview_time = gtk4::Label::new(Some("2m"));
-        preview_time.add_css_class("dim-label");
-        preview_time.add_css_class("preview-caption");
-        preview_header.append(&preview_time);
-        preview_content.append(&preview_header);
+    /// Ask for a labeler by handle; Subscribe hands it to the app.
+    fn present_labeler_dialog(&self) {
+        let content = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
 
-        // Preview body text
-        let preview_body = gtk4::Label::new(Some(
-            "This is a preview of how posts will look at this text size. Adjust the slider above to find your preferred reading size.",
-        ));
-        preview_body.set_halign(gtk4::Align::Fill);
-        preview_body.set_hexpand(true);
-        preview_body.set_wrap(true);
-        preview_body.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
-        preview_body.set_xalign(0.0);
-        preview_body.add_css_class("preview-body");
-        preview_content.append(&preview_body);
+        let header = adw::HeaderBar::new();
+        let title = gtk4::Label::new(Some("Subscribe to Labeler"));
+        title.add_css_class("title");
+        header.set_title_widget(Some(&title));
+        let subscribe = gtk4::Button::with_label("Subscribe");
+        subscribe.add_css_class("suggested-action");
+        subscribe.set_sensitive(false);
+        header.pack_end(&subscribe);
+        content.append(&header);
 
-        // Preview action bar
-        let preview_actions = gtk4::Box::new(gtk4::Orientation::Horizontal, 16);
-        preview_actions.set_margin_top(8);
-        let reply_icon = gtk4::Image::from_icon_name("mail-reply-sender-symbolic");
-        reply_icon.add_css_class("dim-label");
-        reply_icon.set_pixel_size(16);
-        preview_actions.append(&reply_icon);
-        let repost_icon = gtk4::Image::from_icon_name("media-playlist-repeat-symbolic");
-        repost_icon.add_css_class("dim-label");
-        repost_icon.set_pixel_size(16);
-        preview_actions.append(&repost_icon);
-        let like_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 4);
-        let like_icon = gtk4::Image::from_icon_name("emote-love-symbolic");
-        like_icon.add_css_class("dim-label");
-        like_icon.set_pixel_size(16);
-        like_box.append(&like_icon);
-        let like_count = gtk4::Label::new(Some("42"));
-        like_count.add_css_class("dim-label");
-        like_count.add_css_class("preview-caption");
-        like_box.append(&like_count);
-        preview_actions.append(&like_box);
-        preview_content.append(&preview_actions);
+        let group = adw::PreferencesGroup::new();
+        group.set_margin_start(16);
+        group.set_margin_end(16);
+        group.set_margin_top(12);
+        group.set_margin_bottom(16);
+        let entry = adw::EntryRow::builder().title("Handle or DID").build();
+        let subscribe_ref = subscribe.clone();
+        entry.connect_changed(move |row| {
+            subscribe_ref.set_sensitive(!row.text().trim().is_empty());
+        });
+        group.add(&entry);
+        content.append(&group);
 
-        preview_box.append(&preview_content);
-        preview_frame.set_child(Some(&preview_box));
+        let dialog = adw::Dialog::builder()
+            .title("Subscribe to Labeler")
+            .content_width(400)
+            .child(&content)
+            .build();
 
-        // Assemble the display group using a custom layout box
-        let display_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
-        display_box.append(&size_label_row);
-        display_box.append(&scale);
-        display_box.append(&preview_frame);
-        display_group.add(&display_box);
-        page.add(&display_group);
+        subscribe.connect_clicked(glib::clone!(
+            #[weak(rename_to = window)]
+            self,
+            #[weak]
+            dialog,
+            #[weak]
+            entry,
+            move |_| {
+                let actor = entry.text().trim().to_string();
+                if actor.is_empty() {
+                    return;
+                }
+                window.request_moderation_edit(ModerationEdit::Subscribe(actor));
+                dialog.close();
+            }
+        ));
 
-        // Handle slider value changes
-        let window_weak = self.downgrade();
-        let label_ref = size_value_label.clone();
-        scale.connect_value_changed(move |s| {
-            let value = s.value();
-            let snapped = (value / FontSize::STEP).round() * FontSize::STEP;
-            let font_size = FontSize(snapped);
+        dialog.present(Some(self));
+    }
 
-            label_ref.set_text(font_size.label());
+    /// The account's muted words changed: list them in Settings and take
+    /// anything they now catch off screen.
+    /// The moderation lists the account mutes or blocks, for Settings.
+    pub fn set_moderation_lists(&self, lists: Vec<UserList>) {
+        self.imp().moderation_lists.replace(lists);
+        self.rebuild_moderation_list_rows();
+    }
 
-            let mut settings = crate::state::AppSettings::load();
-            settings.font_size = font_size;
-            if let Err(e) = settings.save() {
-                eprintln!("Failed to save settings: {e}");
+    /// A list's mute or block changed: Settings gains it, updates it, or
+    /// lets it go once it is neither.
+    pub fn update_moderation_list(&self, list: UserList) {
+        {
+            let mut lists = self.imp().moderation_lists.borrow_mut();
+            let subscribed = list.viewer_muted || list.viewer_blocked.is_some();
+            match lists.iter().position(|known| known.uri == list.uri) {
+                Some(at) if subscribed => lists[at] = list,
+                Some(at) => {
+                    lists.remove(at);
+                }
+                None if subscribed => lists.push(list),
+                None => {}
             }
+        }
+        self.rebuild_moderation_list_rows();
+    }
 
-            if let Some(window) = window_weak.upgrade() {
-                window.apply_font_size(font_size);
-                if let Some(cb) = window.imp().font_size_changed_callback.borrow().as_ref() {
-                    cb(font_size);
+    /// One row per subscribed list, with a button to undo each of its
+    /// mute and block.
+    fn rebuild_moderation_list_rows(&self) {
+        let imp = self.imp();
+        let Some(group) = imp.moderation_lists_group.borrow().clone() else {
+            return;
+        };
+        for row in imp.moderation_list_rows.take() {
+            group.remove(&row);
+        }
+
+        let lists = imp.moderation_lists.borrow().clone();
+        let mut rows = Vec::new();
+        if lists.is_empty() {
+            let row = adw::ActionRow::builder()
+                .title("No moderation lists")
+                .subtitle("Mute or block a moderation list from its page")
+                .build();
+            row.add_css_class("dim-label");
+            rows.push(row);
+        }
+        for list in lists {
+            // List names are whatever their makers typed, so no markup.
+            let row = adw::ActionRow::builder()
+                .title(list.name.as_str())
+                .subtitle(list.summary())
+                .use_markup(false)
+                .build();
+
+            let undo = [
+                ("Unmute", list.viewer_muted, false),
+                ("Unblock", list.viewer_blocked.is_some(), true),
+            ];
+            for (label, applies, block) in undo {
+                if !applies {
+                    continue;
                 }
+                let btn = gtk4::Button::with_label(label);
+                btn.add_css_class("flat");
+                btn.set_valign(gtk4::Align::Center);
+                btn.update_property(&[gtk4::accessible::Property::Label(&format!(
+                    "{label} list {}",
+                    list.name
+                ))]);
+                let window_weak = self.downgrade();
+                let list = list.clone();
+                btn.connect_clicked(move |btn| {
+                    let Some(window) = window_weak.upgrade() else {
+                        return;
+                    };
+                    // The rows are rebuilt when the server answers.
+                    btn.set_sensitive(false);
+                    let callback = if block {
+                        window.imp().list_block_callback.borrow()
+                    } else {
+                        window.imp().list_mute_callback.borrow()
+                    };
+                    if let Some(cb) = callback.as_ref() {
+                        cb(list.clone(), None);
+                    }
+                });
+                row.add_suffix(&btn);
             }
-        });
+            rows.push(row);
+        }
 
-        page
+        for row in &rows {
+            group.add(row);
+        }
+        imp.moderation_list_rows.replace(rows);
     }
 
-    /// Build the Accessibility category of the settings page
-    fn build_settings_accessibility_page(
-        &self,
-        current_settings: &crate::state::AppSettings,
-    ) -> adw::PreferencesPage {
-        let page = adw::PreferencesPage::new();
-        page.set_name(Some("accessibility"));
-        page.set_title("Accessibility");
-        page.set_icon_name(Some("preferences-desktop-accessibility-symbolic"));
+    pub fn set_muted_words(&self, words: Vec<MutedWord>) {
+        self.imp().muted_words.replace(words);
+        self.rebuild_muted_word_rows();
+        self.drop_muted_from_lists();
+    }
 
-        // ---- Motion section ----
-        let motion_group = adw::PreferencesGroup::new();
-        motion_group.set_title("Motion");
-        motion_group.set_description(Some(
-            "System reduced-motion preference is always respected. This toggle forces reduced motion regardless of system setting.",
-        ));
+    /// The account's label preferences arrived or changed: drop what they
+    /// now hide from the feeds and rebind every row so covers follow.
+    pub fn set_moderation_prefs(&self, mut prefs: ModerationPrefs) {
+        let imp = self.imp();
+        prefs.languages = glib::language_names()
+            .iter()
+            .map(|lang| lang.to_string())
+            .collect();
+        crate::ui::post_row::set_moderation_prefs(prefs.clone());
+        imp.moderation_prefs.replace(prefs.clone());
+        self.rebuild_moderation_pref_rows();
+
+        for model in [&imp.timeline_model, &imp.search_model, &imp.history_model] {
+            if let Some(store) = model.borrow().as_ref() {
+                Self::retain_in_store(store, |obj| {
+                    obj.downcast_ref::<PostObject>()
+                        .and_then(|o| o.post())
+                        .is_none_or(|post| !moderation::hidden_from_feeds(&post, &prefs))
+                });
+            }
+        }
+        for model in [
+            &imp.timeline_model,
+            &imp.search_model,
+            &imp.history_model,
+            &imp.profile_page_model,
+            &imp.likes_model,
+            &imp.bookmarks_model,
+            &imp.mentions_model,
+            &imp.activity_model,
+        ] {
+            if let Some(store) = model.borrow().as_ref() {
+                let n = store.n_items();
+                store.items_changed(0, n, n);
+            }
+        }
+        let profile = imp.current_profile.borrow().clone();
+        if let Some(profile) = profile {
+            self.update_profile_header(&profile);
+        }
+    }
 
-        // Reduce Motion toggle using ActionRow
-        let reduce_motion_row = adw::ActionRow::builder()
-            .title("Reduce Motion")
-            .subtitle("Disable animations and transitions")
-            .build();
+    pub fn set_muted_word_save_callback<F: Fn(MutedWord) + 'static>(&self, callback: F) {
+        self.imp()
+            .muted_word_save_callback
+            .replace(Some(Box::new(callback)));
+    }
 
-        let reduce_motion_switch = gtk4::Switch::new();
-        reduce_motion_switch.set_valign(gtk4::Align::Center);
-        reduce_motion_switch.set_active(current_settings.reduce_motion);
-        reduce_motion_switch.set_tooltip_text(Some("Toggle reduced motion"));
-        reduce_motion_switch.update_property(&[gtk4::accessible::Property::Label("Reduce motion")]);
+    pub fn set_muted_word_remove_callback<F: Fn(MutedWord) + 'static>(&self, callback: F) {
+        self.imp()
+            .muted_word_remove_callback
+            .replace(Some(Box::new(callback)));
+    }
 
+    /// The add/edit dialog. Saving hands the word to the app, which writes
+    /// it to the server and calls `set_muted_words` with the result.
+    fn present_muted_word_dialog(&self, existing: Option<&MutedWord>) {
         let window_weak = self.downgrade();
-        reduce_motion_switch.connect_state_set(move |_switch, state| {
-            let mut settings = crate::state::AppSettings::load();
-            settings.reduce_motion = state;
-            if let Err(e) = settings.save() {
-                eprintln!("Failed to save settings: {e}");
-            }
-
+        crate::ui::muted_word_dialog::present(self, existing, move |word| {
             if let Some(window) = window_weak.upgrade() {
-                window.apply_reduce_motion(state);
+                if let Some(cb) = window.imp().muted_word_save_callback.borrow().as_ref() {
+                    cb(word);
+                }
             }
-            glib::Propagation::Proceed
         });
+    }
 
-        reduce_motion_row.add_suffix(&reduce_motion_switch);
-        reduce_motion_row.set_activatable_widget(Some(&reduce_motion_switch));
-        motion_group.add(&reduce_motion_row);
-        page.add(&motion_group);
+    fn rebuild_muted_word_rows(&self) {
+        let imp = self.imp();
+        let Some(group) = imp.muted_words_group.borrow().clone() else {
+            return;
+        };
+        for row in imp.muted_word_rows.take() {
+            group.remove(&row);
+        }
 
-        // Media
-        let media_group = adw::PreferencesGroup::new();
-        media_group.set_title("Media");
+        let words = imp.muted_words.borrow().clone();
+        let now = chrono::Utc::now();
+        let mut rows = Vec::new();
+        if words.is_empty() {
+            let row = adw::ActionRow::builder()
+                .title("No muted words")
+                .subtitle("Use the add button to hide a word, phrase or hashtag")
+                .build();
+            row.add_css_class("dim-label");
+            rows.push(row);
+        }
+        for word in words {
+            // Values are whatever the user typed, so no markup.
+            let row = adw::ActionRow::builder()
+                .title(word.value.as_str())
+                .subtitle(crate::ui::muted_word_dialog::summary(&word, now))
+                .use_markup(false)
+                .build();
+
+            let edit_btn = gtk4::Button::from_icon_name("document-edit-symbolic");
+            edit_btn.add_css_class("flat");
+            edit_btn.set_valign(gtk4::Align::Center);
+            edit_btn.set_tooltip_text(Some("Edit"));
+            edit_btn.update_property(&[gtk4::accessible::Property::Label(&format!(
+                "Edit muted word {}",
+                word.value
+            ))]);
+            let window_weak = self.downgrade();
+            let word_for_edit = word.clone();
+            edit_btn.connect_clicked(move |_| {
+                if let Some(window) = window_weak.upgrade() {
+                    window.present_muted_word_dialog(Some(&word_for_edit));
+                }
+            });
+            row.add_suffix(&edit_btn);
+
+            let remove_btn = gtk4::Button::from_icon_name("user-trash-symbolic");
+            remove_btn.add_css_class("flat");
+            remove_btn.set_valign(gtk4::Align::Center);
+            remove_btn.set_tooltip_text(Some("Remove"));
+            remove_btn.update_property(&[gtk4::accessible::Property::Label(&format!(
+                "Remove muted word {}",
+                word.value
+            ))]);
+            let window_weak = self.downgrade();
+            remove_btn.connect_clicked(move |btn| {
+                let Some(window) = window_weak.upgrade() else {
+                    return;
+                };
+                // One removal per click; the list is rebuilt when the
+                // server answers.
+                btn.set_sensitive(false);
+                if let Some(cb) = window.imp().muted_word_remove_callback.borrow().as_ref() {
+                    cb(word.clone());
+                }
+            });
+            row.add_suffix(&remove_btn);
+            rows.push(row);
+        }
 
-        let require_alt_row = adw::ActionRow::builder()
-            .title("Require Alt Text")
-            .subtitle("Posting waits until every attached image is described")
-            .build();
+        for row in &rows {
+            group.add(row);
+        }
+        imp.muted_word_rows.replace(rows);
+    }
 
-        let require_alt_switch = gtk4::Switch::new();
-        require_alt_switch.set_valign(gtk4::Align::Center);
-        require_alt_switch.set_active(current_settings.require_alt_text);
-        require_alt_switch
-            .update_property(&[gtk4::accessible::Property::Label("Require alt text")]);
+    /// Filtering happens as batches enter a list, so words added after a
+    /// list loaded would otherwise leave its matches up until the next
+    /// fetch.
+    fn drop_muted_from_lists(&self) {
+        let imp = self.imp();
+        let words = imp.muted_words.borrow().clone();
+        if words.is_empty() {
+            return;
+        }
+        let now = chrono::Utc::now();
 
-        require_alt_switch.connect_state_set(move |_switch, state| {
-            let mut settings = crate::state::AppSettings::load();
-            settings.require_alt_text = state;
-            if let Err(e) = settings.save() {
-                eprintln!("Failed to save settings: {e}");
+        for model in [&imp.timeline_model, &imp.search_model, &imp.history_model] {
+            if let Some(store) = model.borrow().as_ref() {
+                Self::retain_in_store(store, |obj| {
+                    obj.downcast_ref::<PostObject>()
+                        .and_then(|o| o.post())
+                        .is_none_or(|post| !muted_words::post_is_muted(&words, &post, now))
+                });
             }
-            // Compose dialogs read this when they open; nothing live to touch.
-            glib::Propagation::Proceed
-        });
-
-        require_alt_row.add_suffix(&require_alt_switch);
-        require_alt_row.set_activatable_widget(Some(&require_alt_switch));
-        media_group.add(&require_alt_row);
-        page.add(&media_group);
+        }
+        for model in [&imp.mentions_model, &imp.activity_model] {
+            if let Some(store) = model.borrow().as_ref() {
+                Self::retain_in_store(store, |obj| {
+                    obj.downcast_ref::<NotificationObject>()
+                        .and_then(|o| o.notification())
+                        .and_then(|n| n.post)
+                        .is_none_or(|post| !muted_words::post_is_muted(&words, &post, now))
+                });
+            }
+        }
+    }
 
-        page
+    fn retain_in_store(store: &gio::ListStore, keep: impl Fn(&glib::Object) -> bool) {
+        let mut i = store.n_items();
+        while i > 0 {
+            i -= 1;
+            if store.item(i).is_some_and(|obj| !keep(&obj)) {
+                store.remove(i);
+            }
+        }
     }
 
     /// Build the Account category of the settings page
@@ -6130,7 +10729,7 @@ impl HangarWindow {
 
         let moderation_row = adw::ActionRow::builder()
             .title("Moderation")
-            .subtitle("Manage muted words, accounts, and lists")
+            .subtitle("Manage muted accounts and lists")
             .activatable(true)
             .build();
         moderation_row.add_suffix(&gtk4::Image::from_icon_name("window-new-symbolic"));
@@ -6271,6 +10870,7 @@ impl HangarWindow {
                 "likes" => Some(crate::ui::sidebar::NavItem::Likes),
                 "bookmarks" => Some(crate::ui::sidebar::NavItem::Bookmarks),
                 "search" => Some(crate::ui::sidebar::NavItem::Search),
+                "lists" => Some(crate::ui::sidebar::NavItem::Lists),
                 _ => Some(crate::ui::sidebar::NavItem::Home),
             };
             // Selecting a row programmatically does not emit `row-activated`,
@@ -6714,9 +11314,11 @@ use mention_row::MentionRow;
 /// A row widget for displaying activity notifications (likes, follows, reposts, etc.)
 mod activity_row {
     use super::*;
-    use crate::atproto::Notification;
     use crate::ui::avatar_cache;
 
+    /// Most extra faces shown over a grouped row
+    const MAX_OTHER_AVATARS: usize = 6;
+
     mod imp {
         use super::*;
         use std::cell::RefCell;
@@ -6725,6 +11327,8 @@ mod activity_row {
         pub struct ActivityRow {
             pub avatar: RefCell<Option<adw::Avatar>>,
             pub badge_icon: RefCell<Option<gtk4::Image>>,
+            /// The rest of a grouped row's accounts, small
+            pub others_box: RefCell<Option<gtk4::Box>>,
             pub action_label: RefCell<Option<gtk4::Label>>,
             pub time_label: RefCell<Option<gtk4::Label>>,
             pub post_card: RefCell<Option<gtk4::Box>>,
@@ -6830,6 +11434,11 @@ mod activity_row {
             let content_box = gtk4::Box::new(gtk4::Orientation::Vertical, 8);
             content_box.set_hexpand(true);
 
+            // Everyone else in a grouped row, the way bsky.app stacks them
+            let others_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 4);
+            others_box.set_visible(false);
+            content_box.append(&others_box);
+
             // Header: action label + time
             let header_box = gtk4::Box::new(gtk4::Orientation::Horizontal, 6);
 
@@ -6909,6 +11518,7 @@ mod activity_row {
             let imp = self.imp();
             imp.avatar.replace(Some(avatar));
             imp.badge_icon.replace(Some(badge_icon));
+            imp.others_box.replace(Some(others_box));
             imp.action_label.replace(Some(action_label));
             imp.time_label.replace(Some(time_label));
             imp.post_card.replace(Some(post_card));
@@ -6917,8 +11527,9 @@ mod activity_row {
             imp.post_text_label.replace(Some(post_text_label));
         }
 
-        pub fn bind(&self, notification: &Notification) {
+        pub fn bind(&self, group: &NotificationGroup) {
             let imp = self.imp();
+            let notification = &group.lead;
 
             let display_name = notification
                 .author
@@ -6938,12 +11549,13 @@ mod activity_row {
             // button; emblem-favorite went missing from newer icon themes
             // and rendered as the broken-image glyph.
             let icon_name = match notification.reason.as_str() {
-                "like" => "emote-love-symbolic",
-                "repost" => "media-playlist-repeat-symbolic",
-                "follow" => "system-users-symbolic",
+                "like" | "like-via-repost" => "emote-love-symbolic",
+                "repost" | "repost-via-repost" => "media-playlist-repeat-symbolic",
+                "follow" | "starterpack-joined" => "system-users-symbolic",
                 "mention" => "chat-message-new-symbolic",
                 "reply" => "mail-reply-sender-symbolic",
                 "quote" => "edit-copy-symbolic",
+                "verified" | "unverified" => "emblem-ok-symbolic",
                 _ => "dialog-information-symbolic",
             };
 
@@ -6954,29 +11566,34 @@ mod activity_row {
                 badge.remove_css_class("reposted");
                 badge.remove_css_class("followed");
                 match notification.reason.as_str() {
-                    "like" => badge.add_css_class("liked"),
-                    "repost" => badge.add_css_class("reposted"),
-                    "follow" => badge.add_css_class("followed"),
+                    "like" | "like-via-repost" => badge.add_css_class("liked"),
+                    "repost" | "repost-via-repost" => badge.add_css_class("reposted"),
+                    "follow" | "starterpack-joined" => badge.add_css_class("followed"),
                     _ => {}
                 }
             }
 
-            // Action label, Pango markup so the name is bold
+            // The rest of the group, a recycled row's faces cleared first
+            if let Some(others_box) = imp.others_box.borrow().as_ref() {
+                while let Some(child) = others_box.first_child() {
+                    others_box.remove(&child);
+                }
+                for profile in group.others.iter().take(MAX_OTHER_AVATARS) {
+                    let name = profile.display_name.as_deref().unwrap_or(&profile.handle);
+                    let avatar = adw::Avatar::new(24, Some(name), true);
+                    avatar.set_tooltip_text(Some(name));
+                    if let Some(url) = &profile.avatar {
+                        avatar_cache::load_avatar(avatar.clone(), url.clone());
+                    }
+                    others_box.append(&avatar);
+                }
+                others_box.set_visible(!group.others.is_empty());
+            }
+
+            // Action label, Pango markup so the names are bold
             if let Some(label) = imp.action_label.borrow().as_ref() {
-                let action_suffix = match notification.reason.as_str() {
-                    "like" => "liked your post",
-                    "repost" => "reposted your post",
-                    "follow" => "followed you",
-                    "mention" => "mentioned you",
-                    "reply" => "replied to your post",
-                    "quote" => "quoted your post",
-                    other => other,
-                };
-                // Escape display name for markup and make it bold
-                let escaped_name = glib::markup_escape_text(display_name);
-                let markup = format!("<b>{}</b> {}", escaped_name, action_suffix);
                 label.set_use_markup(true);
-                label.set_label(&markup);
+                label.set_label(&action_markup(display_name, group));
             }
 
             // Time
@@ -7060,6 +11677,17 @@ mod activity_row {
                 .replace(Some(Box::new(callback)));
         }
 
+        /// The row's label text with its markup taken off.
+        #[cfg(test)]
+        pub fn action_text(&self) -> String {
+            self.imp()
+                .action_label
+                .borrow()
+                .as_ref()
+                .map(|label| label.text().to_string())
+                .unwrap_or_default()
+        }
+
         /// Forget the row-click handler. See [`super::MentionRow::clear_clicked`]:
         /// a follow notification, which has no post, otherwise inherits the
         /// handler of whatever was bound to this recycled row before it.
@@ -7087,6 +11715,22 @@ mod activity_row {
             Self::new()
         }
     }
+
+    /// "<b>Alice</b> liked your post", naming a second account outright and
+    /// counting any more: "<b>Alice</b> and 12 others liked your post".
+    fn action_markup(lead_name: &str, group: &NotificationGroup) -> String {
+        let phrase = notifications::reason_phrase(&group.lead.reason);
+        let lead = glib::markup_escape_text(lead_name);
+        match group.others.as_slice() {
+            [] => format!("<b>{lead}</b> {phrase}"),
+            [other] => {
+                let name = other.display_name.as_deref().unwrap_or(&other.handle);
+                let name = glib::markup_escape_text(name);
+                format!("<b>{lead}</b> and <b>{name}</b> {phrase}")
+            }
+            others => format!("<b>{lead}</b> and {} others {phrase}", others.len()),
+        }
+    }
 }
 
 use activity_row::ActivityRow;
@@ -7375,6 +12019,7 @@ mod tests {
             uri: "at://did:plc:test/app.bsky.feed.post/n".into(),
             cid: "cid".into(),
             reason: if with_post { "mention" } else { "follow" }.into(),
+            reason_subject: None,
             indexed_at: "2026-01-01T00:00:00Z".into(),
             is_read: false,
             post: with_post.then(|| Post {
@@ -7393,6 +12038,9 @@ mod tests {
                 viewer_bookmarked: None,
                 repost_reason: None,
                 reply_context: None,
+                labels: Vec::new(),
+                feed_context: None,
+                facets: Vec::new(),
             }),
             author,
         }
@@ -7421,6 +12069,25 @@ mod tests {
             viewer_bookmarked: None,
             repost_reason: None,
             reply_context: None,
+            labels: Vec::new(),
+            feed_context: None,
+            facets: Vec::new(),
+        }
+    }
+
+    /// A feed generator nobody has liked yet.
+    fn a_feed_generator(name: &str) -> FeedGenerator {
+        FeedGenerator {
+            uri: format!("at://did:plc:gen/app.bsky.feed.generator/{name}"),
+            cid: "cid".into(),
+            did: "did:web:gen.example".into(),
+            display_name: name.into(),
+            description: None,
+            avatar: None,
+            creator: Profile::minimal("did:plc:gen".into(), "gen.bsky.social".into(), None, None),
+            like_count: Some(0),
+            viewer_like: None,
+            accepts_interactions: false,
         }
     }
 
@@ -7568,13 +12235,63 @@ mod tests {
         assert_eq!(fired.get(), 0);
 
         let row = ActivityRow::new();
-        row.bind(&notification(true));
+        row.bind(&NotificationGroup::single(notification(true)));
         row.connect_clicked(|_| {});
-        row.bind(&notification(false));
+        row.bind(&NotificationGroup::single(notification(false)));
         row.clear_clicked();
         assert!(row.imp().clicked_callback.borrow().is_none());
     }
 
+    /// Likes of one post share a row across pages, and a reason chip
+    /// empties the list for the app to fetch that reason alone.
+    #[test]
+    fn activity_groups_likes_and_refetches_by_reason() {
+        crate::ui::with_gtk(activity_groups_likes_and_refetches_by_reason_body);
+    }
+
+    fn activity_groups_likes_and_refetches_by_reason_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+        let reloads = Rc::new(Cell::new(0));
+        let count = reloads.clone();
+        window.set_activity_filter_callback(move || count.set(count.get() + 1));
+
+        let like = |who: &str, hour: u32| {
+            let mut n = notification(true);
+            n.author.did = format!("did:plc:{who}");
+            n.reason = "like".into();
+            n.reason_subject = Some("at://did:plc:test/app.bsky.feed.post/p".into());
+            n.indexed_at = format!("2026-01-01T{hour:02}:00:00Z");
+            n
+        };
+        window.set_activity(vec![like("a", 12), like("b", 11)]);
+        window.append_activity(vec![like("c", 10), notification(false)]);
+
+        let model = window.imp().activity_model.borrow().clone().unwrap();
+        assert_eq!(model.n_items(), 2, "the later like joined the row above it");
+        let group = model
+            .item(0)
+            .and_downcast::<NotificationObject>()
+            .and_then(|object| object.group())
+            .unwrap();
+        let row = ActivityRow::new();
+        row.bind(&group);
+        assert_eq!(row.action_text(), "Someone and 2 others liked your post");
+
+        let mut widgets = Vec::new();
+        walk(window.upcast_ref(), 0, &mut widgets);
+        let follows = widgets
+            .iter()
+            .find_map(|(_, w)| {
+                let chip = w.downcast_ref::<gtk4::ToggleButton>()?;
+                (chip.label().as_deref() == Some("Follows")).then(|| chip.clone())
+            })
+            .expect("a Follows chip");
+        follows.set_active(true);
+        assert_eq!(window.activity_filter(), ActivityFilter::Follows);
+        assert_eq!(reloads.get(), 1);
+        assert_eq!(model.n_items(), 0, "likes do not linger under Follows");
+    }
+
     /// A closed window must actually drop.
     ///
     /// window -> list view -> factory -> `bind` closure is a cycle if the
@@ -8124,6 +12841,101 @@ mod tests {
         window.destroy();
     }
 
+    /// Each running filter gets a chip, and removing one writes the query
+    /// without it back into the entry and searches that.
+    #[test]
+    fn a_search_chip_takes_its_filter_off() {
+        crate::ui::with_gtk(a_search_chip_takes_its_filter_off_body);
+    }
+
+    fn a_search_chip_takes_its_filter_off_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+        let imp = window.imp();
+        let searched = Rc::new(RefCell::new(Vec::new()));
+        let log = searched.clone();
+        window.set_search_callback(move |input| log.borrow_mut().push(input));
+
+        let query = SearchQuery::parse("gtk from:alice tag:rust sort:top");
+        window.set_search_filters(&query);
+        let chips = imp.search_chips.borrow().clone().unwrap();
+        let mut buttons = Vec::new();
+        let mut child = chips.first_child();
+        while let Some(flow_child) = child {
+            child = flow_child.next_sibling();
+            if let Some(button) = flow_child.first_child().and_downcast::<gtk4::Button>() {
+                buttons.push(button);
+            }
+        }
+        assert_eq!(buttons.len(), 3, "From, #rust and Top posts");
+        assert!(chips.is_visible());
+
+        buttons[0].emit_clicked();
+        assert_eq!(*searched.borrow(), ["gtk tag:rust sort:top"]);
+        let entry = imp.search_entry.borrow().clone().unwrap();
+        assert_eq!(entry.text().as_str(), "gtk tag:rust sort:top");
+
+        window.set_search_filters(&SearchQuery::parse("gtk"));
+        assert!(!chips.is_visible());
+
+        window.destroy();
+    }
+
+    /// The History filters read back into the query as typed; a date that
+    /// does not parse is marked and left out rather than searching nothing,
+    /// and flipping a toggle searches again.
+    #[test]
+    fn history_filters_read_back_into_the_query() {
+        crate::ui::with_gtk(history_filters_read_back_into_the_query_body);
+    }
+
+    fn history_filters_read_back_into_the_query_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+        let imp = window.imp();
+        let searches = Rc::new(Cell::new(0));
+        let counter = searches.clone();
+        window.set_history_search_callback(move || counter.set(counter.get() + 1));
+
+        imp.search_entry
+            .borrow()
+            .as_ref()
+            .expect("the search page built an entry")
+            .set_text("heron");
+        {
+            let filters = imp.history_filters.borrow();
+            let filters = filters
+                .as_ref()
+                .expect("the history page built its filters");
+            filters.author.set_text(" @someone.bsky.social ");
+            filters.since.set_text("2026-03-01");
+            filters.until.set_text("March");
+            filters.liked.set_active(true);
+        }
+        assert_eq!(searches.get(), 1, "the Liked toggle searches again");
+
+        let query = window.history_query();
+        assert_eq!(query.text, "heron");
+        assert_eq!(query.author.as_deref(), Some("@someone.bsky.social"));
+        assert_eq!(query.since, chrono::NaiveDate::from_ymd_opt(2026, 3, 1));
+        assert_eq!(query.until, None);
+        assert!(query.liked && !query.bookmarked);
+        let filters = imp.history_filters.borrow();
+        let filters = filters.as_ref().unwrap();
+        assert!(filters.until.has_css_class("error"));
+        assert!(!filters.since.has_css_class("error"));
+        drop(filters);
+
+        let empty_state = imp.history_empty_state.borrow().clone().unwrap();
+        window.set_history_results(vec![a_post("one")]);
+        assert!(!empty_state.is_visible());
+        window.set_history_results(vec![]);
+        assert!(empty_state.is_visible());
+        assert_eq!(empty_state.title().as_str(), HISTORY_NO_MATCH.0);
+        window.clear_history_results();
+        assert_eq!(empty_state.title().as_str(), HISTORY_INTRO.0);
+
+        window.destroy();
+    }
+
     /// A section's stack stops growing, and never loses its root.
     ///
     /// Every page under the visible one keeps its model and its list alive,
@@ -8189,58 +13001,303 @@ mod tests {
         window.destroy();
     }
 
-    /// Clear Cache reports the outcome it got, not the one it hoped for.
+    /// Clear Cache reports the outcome it got, not the one it hoped for.
+    #[test]
+    fn clear_cache_reports_what_actually_happened() {
+        crate::ui::with_gtk(clear_cache_reports_what_actually_happened_body);
+    }
+
+    fn clear_cache_reports_what_actually_happened_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+
+        // Nobody signed in: there is no per-account cache, and the app is
+        // never asked to delete one.
+        let calls = Rc::new(Cell::new(0u32));
+        let answer = Rc::new(Cell::new(CacheClearOutcome::Cleared));
+        let counted = calls.clone();
+        let answered = answer.clone();
+        window.set_clear_cache_callback(move || {
+            counted.set(counted.get() + 1);
+            answered.get()
+        });
+        assert_eq!(window.clear_cache(), CacheClearOutcome::NothingToClear);
+        assert_eq!(calls.get(), 0, "a signed-out window asked for a delete");
+
+        window.set_current_user_did("did:plc:me");
+        window.set_posts(vec![a_post("cached")]);
+        let model = window
+            .imp()
+            .timeline_model
+            .borrow()
+            .clone()
+            .expect("the timeline has a model");
+        assert_eq!(model.n_items(), 1);
+
+        // A failed delete leaves the posts alone: they are still being served.
+        answer.set(CacheClearOutcome::Failed);
+        assert_eq!(window.clear_cache(), CacheClearOutcome::Failed);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(
+            model.n_items(),
+            1,
+            "a failed clear must not pretend the posts are gone"
+        );
+
+        answer.set(CacheClearOutcome::Cleared);
+        assert_eq!(window.clear_cache(), CacheClearOutcome::Cleared);
+        assert_eq!(calls.get(), 2);
+        assert_eq!(
+            model.n_items(),
+            0,
+            "the cleared posts stayed on screen, which is what made the old \
+             handler look like it had lied"
+        );
+
+        window.destroy();
+    }
+
+    /// Label preferences reach both the feeds and Settings: a post they
+    /// hide never enters the timeline, subscribed labelers get a row each,
+    /// and a changed row asks the app for exactly that change.
+    #[test]
+    fn moderation_prefs_filter_feeds_and_fill_settings() {
+        crate::ui::with_gtk(moderation_prefs_filter_feeds_and_fill_settings_body);
+    }
+
+    fn moderation_prefs_filter_feeds_and_fill_settings_body() {
+        use crate::atproto::{Label, LabelerService};
+
+        let window: HangarWindow = glib::Object::builder().build();
+        let imp = window.imp();
+        let edits: Rc<RefCell<Vec<ModerationEdit>>> = Rc::default();
+        let sink = Rc::clone(&edits);
+        window.set_moderation_edit_callback(move |edit| sink.borrow_mut().push(edit));
+
+        // Adult content is off until the account says otherwise.
+        let mut labeled = a_post("labeled");
+        labeled.labels = vec![Label {
+            src: moderation::BLUESKY_MODERATION_DID.into(),
+            val: "porn".into(),
+            neg: false,
+        }];
+        window.set_posts(vec![a_post("plain"), labeled]);
+        let model = imp
+            .timeline_model
+            .borrow()
+            .clone()
+            .expect("a timeline model");
+        assert_eq!(model.n_items(), 1, "a hidden post never enters the feed");
+
+        window.set_moderation_prefs(ModerationPrefs {
+            adult_content_enabled: true,
+            labelers: vec![LabelerService {
+                did: "did:plc:labeler".into(),
+                creator: Profile::minimal(
+                    "did:plc:labeler".into(),
+                    "labeler.test".into(),
+                    Some("Spoiler <Patrol>".into()),
+                    None,
+                ),
+                definitions: Vec::new(),
+            }],
+            ..ModerationPrefs::default()
+        });
+        let rows = imp.moderation_pref_rows.borrow().clone();
+        let labeler = rows
+            .iter()
+            .find_map(|(_, row)| row.downcast_ref::<adw::ExpanderRow>().cloned())
+            .expect("the subscribed labeler has a row");
+        assert_eq!(labeler.title(), "Spoiler <Patrol>");
+        assert!(
+            edits.borrow().is_empty(),
+            "building the rows asks for nothing"
+        );
+
+        let filter = rows
+            .iter()
+            .find_map(|(_, row)| row.downcast_ref::<adw::ComboRow>().cloned())
+            .expect("a content filter row");
+        assert!(filter.is_sensitive(), "adult content is on");
+        filter.set_selected(2);
+        assert_eq!(
+            *edits.borrow(),
+            vec![ModerationEdit::LabelVisibility {
+                labeler: None,
+                label: "porn".into(),
+                visibility: LabelVisibility::Hide,
+            }]
+        );
+
+        window.set_moderation_prefs(ModerationPrefs::default());
+        window.destroy();
+    }
+
+    /// Settings lists each muted or blocked list once, with an undo for
+    /// each, and lets a list go when it is neither any more.
+    #[test]
+    fn moderation_lists_come_and_go_in_settings() {
+        crate::ui::with_gtk(moderation_lists_come_and_go_in_settings_body);
+    }
+
+    fn moderation_lists_come_and_go_in_settings_body() {
+        use crate::atproto::ListPurpose;
+
+        let window: HangarWindow = glib::Object::builder().build();
+        let imp = window.imp();
+        let unblocked: Rc<RefCell<Vec<(String, bool)>>> = Rc::default();
+        let sink = Rc::clone(&unblocked);
+        window.set_list_block_callback(move |list, ctx| {
+            sink.borrow_mut().push((list.uri, ctx.is_some()));
+        });
+
+        let spam = UserList {
+            uri: "at://did:plc:mod/app.bsky.graph.list/spam".into(),
+            name: "Spam <bots>".into(),
+            purpose: ListPurpose::Moderation,
+            description: None,
+            avatar: None,
+            creator: Profile::minimal("did:plc:mod".into(), "mod.test".into(), None, None),
+            item_count: None,
+            viewer_muted: true,
+            viewer_blocked: Some("at://did:plc:me/app.bsky.graph.listblock/1".into()),
+        };
+        window.set_moderation_lists(vec![spam.clone()]);
+        let rows = imp.moderation_list_rows.borrow().clone();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].title(), "Spam <bots>");
+        assert!(rows[0].subtitle().unwrap().ends_with("Blocked"));
+
+        let mut widgets = Vec::new();
+        walk(rows[0].upcast_ref(), 0, &mut widgets);
+        let undo = |label: &str| {
+            widgets.iter().find_map(|(_, w)| {
+                let b = w.downcast_ref::<gtk4::Button>()?;
+                (b.label().as_deref() == Some(label)).then(|| b.clone())
+            })
+        };
+        assert!(undo("Unmute").is_some());
+        undo("Unblock")
+            .expect("a blocked list can be unblocked")
+            .emit_clicked();
+        assert_eq!(
+            unblocked.borrow().as_slice(),
+            [(spam.uri.clone(), false)],
+            "Settings has no list page to hand over"
+        );
+
+        let mut muted_only = spam.clone();
+        muted_only.viewer_blocked = None;
+        window.update_moderation_list(muted_only.clone());
+        assert_eq!(imp.moderation_list_rows.borrow().len(), 1);
+        muted_only.viewer_muted = false;
+        window.update_moderation_list(muted_only);
+        assert_eq!(
+            imp.moderation_list_rows.borrow()[0].title(),
+            "No moderation lists"
+        );
+
+        // And on a profile, the list is named as the reason.
+        let mut hidden = Profile::minimal("did:plc:x".into(), "x.test".into(), None, None);
+        assert_eq!(hidden.list_moderation_note(), None);
+        hidden.muted_by_list = Some(crate::atproto::ListRef {
+            uri: spam.uri.clone(),
+            name: "Spam".into(),
+        });
+        assert_eq!(
+            hidden.list_moderation_note().as_deref(),
+            Some("Muted by the list Spam")
+        );
+
+        window.destroy();
+    }
+
+    /// Alerts lists a row per category the server sent; a switch sends its
+    /// whole category, and the server's answer keeps that row open.
     #[test]
-    fn clear_cache_reports_what_actually_happened() {
-        crate::ui::with_gtk(clear_cache_reports_what_actually_happened_body);
+    fn alerts_send_one_category_and_stay_open() {
+        crate::ui::with_gtk(alerts_send_one_category_and_stay_open_body);
     }
 
-    fn clear_cache_reports_what_actually_happened_body() {
+    fn alerts_send_one_category_and_stay_open_body() {
         let window: HangarWindow = glib::Object::builder().build();
+        let edits: Rc<RefCell<Vec<NotificationPrefEdit>>> = Rc::default();
+        let sink = Rc::clone(&edits);
+        window.set_notification_pref_edit_callback(move |edit| sink.borrow_mut().push(edit));
+
+        let like = CategoryPref {
+            include: Some(IncludeFrom::Follows),
+            in_app: true,
+            push: true,
+        };
+        let verified = CategoryPref {
+            include: None,
+            in_app: true,
+            push: false,
+        };
+        let prefs = NotificationPrefs {
+            chat: ChatNotify::Accepted,
+            categories: vec![
+                (NotificationCategory::Like, like),
+                (NotificationCategory::Verified, verified),
+            ],
+        };
+        window.set_notification_prefs(prefs.clone());
 
-        // Nobody signed in: there is no per-account cache, and the app is
-        // never asked to delete one.
-        let calls = Rc::new(Cell::new(0u32));
-        let answer = Rc::new(Cell::new(CacheClearOutcome::Cleared));
-        let counted = calls.clone();
-        let answered = answer.clone();
-        window.set_clear_cache_callback(move || {
-            counted.set(counted.get() + 1);
-            answered.get()
-        });
-        assert_eq!(window.clear_cache(), CacheClearOutcome::NothingToClear);
-        assert_eq!(calls.get(), 0, "a signed-out window asked for a delete");
-
-        window.set_current_user_did("did:plc:me");
-        window.set_posts(vec![a_post("cached")]);
-        let model = window
-            .imp()
-            .timeline_model
-            .borrow()
-            .clone()
-            .expect("the timeline has a model");
-        assert_eq!(model.n_items(), 1);
-
-        // A failed delete leaves the posts alone: they are still being served.
-        answer.set(CacheClearOutcome::Failed);
-        assert_eq!(window.clear_cache(), CacheClearOutcome::Failed);
-        assert_eq!(calls.get(), 1);
-        assert_eq!(
-            model.n_items(),
-            1,
-            "a failed clear must not pretend the posts are gone"
+        let expander = |key: &str| {
+            window
+                .imp()
+                .notification_pref_rows
+                .borrow()
+                .iter()
+                .find_map(|(_, row)| {
+                    let row = row.downcast_ref::<adw::ExpanderRow>()?;
+                    (row.widget_name() == key).then(|| row.clone())
+                })
+                .unwrap_or_else(|| panic!("no {key} row"))
+        };
+        let likes = expander("like");
+        assert_eq!(likes.subtitle(), "People you follow · In app · Push");
+        let mut widgets = Vec::new();
+        walk(expander("verified").upcast_ref(), 0, &mut widgets);
+        assert!(
+            !widgets.iter().any(|(_, w)| w.is::<adw::ComboRow>()),
+            "verification has no audience to pick"
+        );
+        assert!(
+            edits.borrow().is_empty(),
+            "building the rows asks for nothing"
         );
 
-        answer.set(CacheClearOutcome::Cleared);
-        assert_eq!(window.clear_cache(), CacheClearOutcome::Cleared);
-        assert_eq!(calls.get(), 2);
+        likes.set_expanded(true);
+        let mut widgets = Vec::new();
+        walk(likes.upcast_ref(), 0, &mut widgets);
+        let push = widgets
+            .iter()
+            .find_map(|(_, w)| {
+                let row = w.downcast_ref::<adw::SwitchRow>()?;
+                (row.title() == "Push Notifications").then(|| row.clone())
+            })
+            .expect("a push switch");
+        push.set_active(false);
+        let sent = CategoryPref {
+            push: false,
+            ..like
+        };
         assert_eq!(
-            model.n_items(),
-            0,
-            "the cleared posts stayed on screen, which is what made the old \
-             handler look like it had lied"
+            *edits.borrow(),
+            vec![NotificationPrefEdit::Category(
+                NotificationCategory::Like,
+                sent
+            )]
         );
+        assert_eq!(likes.subtitle(), "People you follow · In app");
 
+        window.set_notification_prefs(prefs);
+        assert!(
+            expander("like").is_expanded(),
+            "the row being changed stays open"
+        );
+        assert!(!expander("verified").is_expanded());
         window.destroy();
     }
 
@@ -8746,7 +13803,24 @@ mod tests {
         ]);
 
         let menu = window.imp().feed_menu.borrow().clone().unwrap();
-        assert_eq!(menu.n_items(), 2, "one menu item per saved feed");
+        assert_eq!(
+            menu.n_items(),
+            3,
+            "one menu item per saved feed, then Discover Feeds"
+        );
+
+        let mut with_search = window.saved_feeds();
+        with_search.push(SavedFeed::saved_search("hangar lang:en"));
+        window.set_saved_feeds(with_search);
+        assert_eq!(menu.n_items(), 4, "saved searches sit in one section");
+        let section = menu
+            .item_link(2, gio::MENU_LINK_SECTION)
+            .expect("the saved searches section follows the feeds");
+        assert_eq!(section.n_items(), 1);
+        window.set_saved_feeds(vec![
+            feed("", "Following"),
+            feed("at://did:plc:x/app.bsky.feed.generator/hot", "Hot"),
+        ]);
 
         window.set_current_feed_name("Hot", "at://did:plc:x/app.bsky.feed.generator/hot");
         let action = window.imp().feed_action.borrow().clone().unwrap();
@@ -8950,7 +14024,7 @@ mod tests {
     }
 
     /// The own profile page shares the drill-down tab machinery: the same
-    /// four tabs, and a context whose DID waits for sign-in.
+    /// tabs, and a context whose DID waits for sign-in.
     #[test]
     fn the_own_profile_shares_the_tab_machinery() {
         crate::ui::with_gtk(the_own_profile_shares_the_tab_machinery_body);
@@ -9104,6 +14178,375 @@ mod tests {
         window.destroy();
     }
 
+    /// A hashtag opens one page per tag whatever its case, its tabs ask
+    /// for their own sort, and its menu mutes and pins the tag itself.
+    #[test]
+    fn a_hashtag_page_switches_sort_and_mutes_its_tag() {
+        crate::ui::with_gtk(a_hashtag_page_switches_sort_and_mutes_its_tag_body);
+    }
+
+    fn a_hashtag_page_switches_sort_and_mutes_its_tag_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+        let asked: Rc<RefCell<Vec<(SearchQuery, bool)>>> = Rc::default();
+        let sink = asked.clone();
+        window.set_hashtag_tab_callback(move |ctx, first| {
+            sink.borrow_mut().push((ctx.query(), first));
+        });
+        let saved: Rc<RefCell<Vec<MutedWord>>> = Rc::default();
+        let sink = saved.clone();
+        window.set_muted_word_save_callback(move |word| sink.borrow_mut().push(word));
+
+        let ctx = window
+            .push_hashtag_page("Rust")
+            .expect("a new tag pushes a page");
+        assert_eq!(ctx.query().to_input(), "tag:Rust sort:top");
+        assert!(
+            window.push_hashtag_page("rust").is_none(),
+            "the same tag in another case pops back to its page"
+        );
+
+        let nav_view = window.imp().home_nav_view.borrow().clone().unwrap();
+        let page = nav_view.find_page("hashtag:rust").unwrap();
+        assert_eq!(page.title(), "#Rust");
+        let mut widgets = Vec::new();
+        walk(&page.upcast::<gtk4::Widget>(), 0, &mut widgets);
+        let button = |label: &str| -> gtk4::Button {
+            widgets
+                .iter()
+                .find_map(|(_, w)| {
+                    let b = w.downcast_ref::<gtk4::Button>()?;
+                    (b.label().as_deref() == Some(label)).then(|| b.clone())
+                })
+                .unwrap_or_else(|| panic!("no {label} button"))
+        };
+        let tab = |label: &str| button(label).downcast::<gtk4::ToggleButton>().unwrap();
+
+        assert!(tab("Top").is_active(), "Top is the opening tab");
+        ctx.append_posts(vec![a_post("t1")]);
+        tab("Latest").set_active(true);
+        assert_eq!(
+            asked.borrow().as_slice(),
+            [(SearchQuery::parse("tag:Rust"), true)],
+            "Latest asks for the tag's newest posts as a first page"
+        );
+        assert_eq!(ctx.listed(), 0, "the Top posts left with their tab");
+
+        assert_eq!(window.hashtag_state("rust"), (false, false));
+        // The menu offers what the tag is not yet.
+        button("Pin to Feeds");
+        button("Mute #Rust").emit_clicked();
+        assert_eq!(saved.borrow().len(), 1);
+        assert_eq!(saved.borrow()[0].value, "Rust");
+        assert_eq!(
+            saved.borrow()[0].targets,
+            [crate::atproto::MutedWordTarget::Tag],
+            "muting a tag leaves the word in plain text alone"
+        );
+
+        window.set_muted_words(saved.borrow().clone());
+        let mut feeds = window.saved_feeds();
+        feeds.push(SavedFeed::hashtag("rust"));
+        window.set_saved_feeds(feeds);
+        assert_eq!(window.hashtag_state("Rust"), (true, true));
+        assert_eq!(SavedFeed::hashtag("rust").display_name, "#rust");
+
+        window.destroy();
+    }
+
+    /// A list opens one page per list. Your own user list gets both tabs
+    /// and the full menu; someone else's moderation list is people only,
+    /// with nothing to pin, edit or delete.
+    #[test]
+    fn a_list_page_shows_tabs_and_menu_by_kind_and_owner() {
+        crate::ui::with_gtk(a_list_page_shows_tabs_and_menu_by_kind_and_owner_body);
+    }
+
+    fn a_list_page_shows_tabs_and_menu_by_kind_and_owner_body() {
+        use crate::atproto::ListPurpose;
+
+        let window: HangarWindow = glib::Object::builder().build();
+        window.set_current_user_did("did:plc:me");
+        let asked: Rc<RefCell<Vec<(ListTab, bool)>>> = Rc::default();
+        let sink = asked.clone();
+        window.set_list_tab_callback(move |ctx, first| {
+            sink.borrow_mut().push((ctx.tab.get(), first));
+        });
+        let deleted: Rc<RefCell<Vec<String>>> = Rc::default();
+        let sink = deleted.clone();
+        window.set_delete_list_callback(move |list| sink.borrow_mut().push(list.uri));
+
+        let list_of = |creator: &str, rkey: &str, purpose: ListPurpose| UserList {
+            uri: format!("at://{creator}/app.bsky.graph.list/{rkey}"),
+            name: format!("List {rkey}"),
+            purpose,
+            description: None,
+            avatar: None,
+            creator: Profile::minimal(creator.into(), "someone.bsky.social".into(), None, None),
+            item_count: Some(2),
+            viewer_muted: false,
+            viewer_blocked: None,
+        };
+        let own = list_of("did:plc:me", "own", ListPurpose::Curate);
+
+        let ctx = window
+            .push_list_page(&own)
+            .expect("a new list pushes a page");
+        assert_eq!(
+            ctx.tab.get(),
+            ListTab::Posts,
+            "a user list opens on its posts"
+        );
+        assert!(
+            window.push_list_page(&own).is_none(),
+            "the same list pops back to its page"
+        );
+
+        let nav_view = window.imp().home_nav_view.borrow().clone().unwrap();
+        let tag = format!("list:{}", own.uri);
+        let page = nav_view.find_page(&tag).unwrap();
+        assert_eq!(page.title(), "List own");
+        let mut widgets = Vec::new();
+        walk(&page.upcast::<gtk4::Widget>(), 0, &mut widgets);
+        let button = |label: &str| {
+            widgets.iter().find_map(|(_, w)| {
+                let b = w.downcast_ref::<gtk4::Button>()?;
+                (b.label().as_deref() == Some(label)).then(|| b.clone())
+            })
+        };
+
+        ctx.append_posts(vec![a_post("l1")]);
+        button("People")
+            .and_then(|b| b.downcast::<gtk4::ToggleButton>().ok())
+            .expect("a user list has a People tab")
+            .set_active(true);
+        assert_eq!(asked.borrow().as_slice(), [(ListTab::People, true)]);
+        assert_eq!(ctx.listed(), 0, "the posts left with their tab");
+
+        assert!(button("Pin to Feeds").is_some());
+        assert!(button("Make Starter Pack...").is_some());
+        assert!(button("Edit List...").is_some());
+        button("Delete List...")
+            .expect("your own list can be deleted")
+            .emit_clicked();
+        assert_eq!(deleted.borrow().as_slice(), [own.uri.clone()]);
+
+        let modlist = list_of("did:plc:other", "mod", ListPurpose::Moderation);
+        let ctx = window.push_list_page(&modlist).unwrap();
+        assert_eq!(ctx.tab.get(), ListTab::People);
+        let page = nav_view
+            .find_page(&format!("list:{}", modlist.uri))
+            .unwrap();
+        let mut widgets = Vec::new();
+        walk(&page.upcast::<gtk4::Widget>(), 0, &mut widgets);
+        let has = |label: &str| {
+            widgets.iter().any(|(_, w)| {
+                w.downcast_ref::<gtk4::Button>()
+                    .is_some_and(|b| b.label().as_deref() == Some(label))
+            })
+        };
+        assert!(!has("Posts"), "a moderation list has no feed");
+        assert!(!has("Pin to Feeds") && !has("Delete List..."));
+        assert!(!has("Make Starter Pack..."), "only your own user lists");
+
+        // Deleting the first list takes its page and everything over it.
+        window.close_list_pages(&own.uri);
+        assert!(nav_view.find_page(&tag).is_none());
+        assert!(
+            nav_view
+                .find_page(&format!("list:{}", modlist.uri))
+                .is_none()
+        );
+
+        window.destroy();
+    }
+
+    /// A profile's Starter Packs tab lists packs instead of posts, and a
+    /// pack's page shows its feeds, pages its people and hands Follow All
+    /// to the app, whose follows land on the rows already listed.
+    #[test]
+    fn starter_packs_open_from_a_profile_and_follow_all() {
+        crate::ui::with_gtk(starter_packs_open_from_a_profile_and_follow_all_body);
+    }
+
+    fn starter_packs_open_from_a_profile_and_follow_all_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+        let ctx_cell: Rc<RefCell<Option<Rc<ProfileFeedCtx>>>> = Rc::default();
+        let stash = ctx_cell.clone();
+        window.set_profile_tab_callback(move |ctx, _| {
+            stash.borrow_mut().replace(ctx);
+        });
+        let opened: Rc<RefCell<Vec<StarterPack>>> = Rc::default();
+        let sink = opened.clone();
+        window.set_starter_pack_opened_callback(move |pack| sink.borrow_mut().push(pack));
+
+        let creator =
+            Profile::minimal("did:plc:made".into(), "made.bsky.social".into(), None, None);
+        window.push_profile_page(&creator, vec![a_post("p1")], None);
+        let nav_view = window.imp().home_nav_view.borrow().clone().unwrap();
+        let page = nav_view.find_page("profile:did:plc:made").unwrap();
+        let mut widgets = Vec::new();
+        walk(&page.upcast::<gtk4::Widget>(), 0, &mut widgets);
+        widgets
+            .iter()
+            .find_map(|(_, w)| {
+                let t = w.downcast_ref::<gtk4::ToggleButton>()?;
+                (t.label().as_deref() == Some("Starter Packs")).then(|| t.clone())
+            })
+            .expect("a profile has a Starter Packs tab")
+            .set_active(true);
+        let ctx = ctx_cell
+            .borrow()
+            .clone()
+            .expect("the tab asked for its page");
+        assert_eq!(ctx.filter.get(), ProfileFeedCtx::STARTER_PACKS);
+        assert_eq!(ctx.listed(), 0, "the posts left with their tab");
+        assert!(ctx.packs.is_visible());
+        assert_eq!(ctx.empty.text(), "No starter packs yet");
+
+        let pack = StarterPack {
+            uri: "at://did:plc:made/app.bsky.graph.starterpack/p".into(),
+            name: "Birders".into(),
+            description: Some("People who look up".into()),
+            creator: creator.clone(),
+            list_uri: Some("at://did:plc:made/app.bsky.graph.list/ref".into()),
+            item_count: Some(2),
+            joined_count: None,
+            feeds: Vec::new(),
+        };
+        ctx.append_starter_packs(vec![pack.clone()]);
+        assert!(!ctx.empty.is_visible());
+        ctx.packs.row_at_index(0).unwrap().emit_activate();
+        assert_eq!(opened.borrow().len(), 1);
+
+        let following_all: Rc<Cell<u32>> = Rc::default();
+        let count = following_all.clone();
+        window.set_follow_all_callback(move |_| count.set(count.get() + 1));
+        let pack_ctx = window
+            .push_starter_pack_page(&pack)
+            .expect("a new pack pushes a page");
+        assert!(window.push_starter_pack_page(&pack).is_none());
+        assert!(
+            !pack_ctx.feeds_group.is_visible(),
+            "no feeds until the full view"
+        );
+
+        let mut full = pack.clone();
+        full.feeds = vec![a_feed_generator("birds")];
+        pack_ctx.set_pack(full);
+        assert!(pack_ctx.feeds_group.is_visible());
+        assert!(pack_ctx.summary.text().contains("1 feed"));
+        let feeds_opened: Rc<RefCell<Vec<String>>> = Rc::default();
+        let sink = feeds_opened.clone();
+        window.set_feed_opened_callback(move |feed| sink.borrow_mut().push(feed.display_name));
+        pack_ctx.feeds.row_at_index(0).unwrap().emit_activate();
+        assert_eq!(
+            feeds_opened.borrow().as_slice(),
+            ["birds"],
+            "a pack's feed opens its own page"
+        );
+
+        let member = |did: &str| ListMember {
+            item_uri: format!("at://did:plc:made/app.bsky.graph.listitem/{did}"),
+            profile: Profile::minimal(did.into(), format!("{did}.test"), None, None),
+        };
+        pack_ctx.append_members(vec![member("did:plc:a"), member("did:plc:b")]);
+        assert_eq!(pack_ctx.listed(), 2);
+
+        pack_ctx.follow_all.emit_clicked();
+        assert_eq!(following_all.get(), 1);
+        pack_ctx.set_following_all(true);
+        assert!(!pack_ctx.follow_all.is_sensitive());
+        pack_ctx.set_following_all(false);
+        pack_ctx.mark_followed(&[(
+            "did:plc:b".into(),
+            "at://did:plc:me/app.bsky.graph.follow/1".into(),
+        )]);
+        let followed: Vec<Option<String>> = (0..2)
+            .filter_map(|i| pack_ctx.people.item(i).and_downcast::<ActorObject>())
+            .map(|object| object.profile().unwrap().viewer_following)
+            .collect();
+        assert_eq!(
+            followed,
+            [None, Some("at://did:plc:me/app.bsky.graph.follow/1".into())]
+        );
+
+        window.destroy();
+    }
+
+    /// Saved feed edits wait for the account's list, then go out whole
+    /// and show at once; a feed page likes through the app and says
+    /// whether it is saved.
+    #[test]
+    fn saved_feeds_are_edited_whole_and_feed_pages_like() {
+        crate::ui::with_gtk(saved_feeds_are_edited_whole_and_feed_pages_like_body);
+    }
+
+    fn saved_feeds_are_edited_whole_and_feed_pages_like_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+        let imp = window.imp();
+        let written: Rc<RefCell<Vec<Vec<String>>>> = Rc::default();
+        let sink = written.clone();
+        window.set_saved_feed_list_callback(move |feeds| {
+            sink.borrow_mut()
+                .push(feeds.into_iter().map(|f| f.display_name).collect());
+        });
+
+        window.edit_saved_feed_list(|feeds| feeds.clear());
+        assert!(
+            written.borrow().is_empty(),
+            "nothing is written over a list never read"
+        );
+
+        let art = a_feed_generator("art");
+        let news = a_feed_generator("news");
+        window.set_saved_feed_list(vec![
+            SavedFeed::home(),
+            art.as_saved_feed(true),
+            news.as_saved_feed(false),
+        ]);
+        assert_eq!(imp.my_feed_rows.borrow().len(), 3);
+        assert_eq!(window.saved_feed_state(&art.uri), Some(true));
+        assert_eq!(window.saved_feed_state(&news.uri), Some(false));
+
+        window.edit_saved_feed_list(|feeds| {
+            let moved = feeds.remove(2);
+            feeds.insert(0, moved);
+        });
+        assert_eq!(
+            written.borrow().last().unwrap(),
+            &["news", "Following", "art"]
+        );
+        window.edit_saved_feed_list(|feeds| feeds.retain(|f| f.display_name != "art"));
+        assert_eq!(
+            written.borrow().last().unwrap(),
+            &["news", "Following"],
+            "the second edit builds on the first"
+        );
+        assert_eq!(window.saved_feed_state(&art.uri), None);
+
+        let likes: Rc<Cell<u32>> = Rc::default();
+        let count = likes.clone();
+        window.set_feed_like_callback(move |_| count.set(count.get() + 1));
+        let ctx = window
+            .push_feed_page(&art)
+            .expect("a new feed pushes a page");
+        assert!(window.push_feed_page(&art).is_none());
+        assert_eq!(ctx.like.label().as_deref(), Some("Like"));
+        ctx.like.emit_clicked();
+        assert_eq!(likes.get(), 1);
+        assert!(!ctx.like.is_sensitive(), "one like at a time");
+
+        let mut liked = art.clone();
+        liked.viewer_like = Some("at://did:plc:me/app.bsky.feed.like/1".into());
+        liked.like_count = Some(1);
+        ctx.set_feed(liked);
+        ctx.set_liking(false);
+        assert_eq!(ctx.like.label().as_deref(), Some("Unlike"));
+        assert_eq!(ctx.likes.text(), "Liked by 1 person");
+
+        window.destroy();
+    }
+
     /// A poll that finds new posts must not disturb the reader.
     ///
     /// The old path cleared the model and re-appended everything, which
@@ -9161,6 +14604,122 @@ mod tests {
         window.destroy();
     }
 
+    /// A refresh landing on a feed painted from the cache keeps every row
+    /// below the fresh page. Counts move in place and only unseen posts go
+    /// on top; the old path replaced the list with the fresh page.
+    #[test]
+    fn a_refresh_merges_into_the_cached_feed() {
+        crate::ui::with_gtk(a_refresh_merges_into_the_cached_feed_body);
+    }
+
+    fn a_refresh_merges_into_the_cached_feed_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+        window.set_posts((0..80).map(|i| a_post(&format!("p{i}"))).collect());
+        let model = window.imp().timeline_model.borrow().clone().unwrap();
+
+        let mut liked = a_post("p0");
+        liked.like_count = Some(7);
+        let inserted = window.merge_posts(vec![a_post("new"), liked, a_post("p1")]);
+        assert_eq!(inserted, 1);
+        assert_eq!(model.n_items(), 81, "the older cached rows stayed");
+
+        let at = |i: u32| {
+            model
+                .item(i)
+                .and_downcast::<PostObject>()
+                .and_then(|o| o.post())
+                .unwrap()
+        };
+        assert!(at(0).uri.ends_with("/new"));
+        assert!(at(1).uri.ends_with("/p0"));
+        assert_eq!(at(1).like_count, Some(7), "the count moved in place");
+        assert!(at(80).uri.ends_with("/p79"));
+
+        window.destroy();
+    }
+
+    /// A gap row hangs under its boundary post and its posts take its place.
+    /// Filled from above, what is still missing goes below them; filled from
+    /// below, it stays above them.
+    #[test]
+    fn a_gap_row_fills_where_it_stands() {
+        crate::ui::with_gtk(a_gap_row_fills_where_it_stands_body);
+    }
+
+    fn a_gap_row_fills_where_it_stands_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+        window.set_posts(vec![a_post("new"), a_post("old")]);
+        let model = window.imp().timeline_model.borrow().clone().unwrap();
+        let rows = || -> Vec<String> {
+            (0..model.n_items())
+                .map(|i| {
+                    let item = model.item(i).unwrap();
+                    match item.downcast_ref::<PostObject>() {
+                        Some(o) => o.post().unwrap().uri.rsplit('/').next().unwrap().into(),
+                        None => "gap".into(),
+                    }
+                })
+                .collect()
+        };
+
+        let boundary = a_post("new").uri;
+        window.insert_gap(&boundary);
+        window.insert_gap(&boundary);
+        assert_eq!(rows(), ["new", "gap", "old"], "one row per gap");
+
+        let g1 = a_post("g1").uri;
+        window.fill_gap(&boundary, vec![a_post("g1")], Some(&g1), false);
+        assert_eq!(rows(), ["new", "g1", "gap", "old"]);
+
+        window.fill_gap(&g1, vec![a_post("g3"), a_post("old")], Some(&g1), true);
+        assert_eq!(
+            rows(),
+            ["new", "g1", "gap", "g3", "old"],
+            "a listed post is not listed twice"
+        );
+
+        window.fill_gap(&g1, vec![a_post("g2")], None, false);
+        assert_eq!(rows(), ["new", "g1", "g2", "g3", "old"]);
+
+        window.destroy();
+    }
+
+    /// The pill counts the posts above the one the reader was left at, and
+    /// keeps counting as a refresh puts more on top.
+    #[test]
+    fn the_unread_pill_counts_posts_above_the_reading_position() {
+        crate::ui::with_gtk(the_unread_pill_counts_posts_above_the_reading_position_body);
+    }
+
+    fn the_unread_pill_counts_posts_above_the_reading_position_body() {
+        let window: HangarWindow = glib::Object::builder().build();
+        window.set_posts(vec![a_post("c"), a_post("b"), a_post("a")]);
+        let imp = window.imp();
+        let pill = imp.unread_above_pill.borrow().clone().unwrap();
+        let label = || {
+            pill.child()
+                .and_then(|c| c.last_child())
+                .and_downcast::<gtk4::Label>()
+                .unwrap()
+                .label()
+                .to_string()
+        };
+
+        imp.unread_anchor.replace(Some(a_post("b").uri));
+        window.refresh_unread_above();
+        assert!(pill.is_visible());
+        assert_eq!(label(), "1 unread above");
+
+        window.insert_posts_at_top(vec![a_post("e"), a_post("d")]);
+        assert_eq!(label(), "3 unread above");
+
+        window.set_posts(vec![a_post("z")]);
+        assert!(!pill.is_visible(), "a new list has nothing to count from");
+        assert!(imp.unread_anchor.borrow().is_none());
+
+        window.destroy();
+    }
+
     /// A pushed profile page holds one list whose first row is the header
     /// marker, so the whole page scrolls; the condensed bar waits unrevealed
     /// on top of the scroller.
@@ -9262,7 +14821,7 @@ mod tests {
     }
 
     /// The shortcuts reference lists every accelerator the application
-    /// registers: the general four plus back and the eight sections.
+    /// registers: the general five plus back and the eight sections.
     #[test]
     fn the_shortcuts_window_lists_the_registered_accelerators() {
         crate::ui::with_gtk(the_shortcuts_window_lists_the_registered_accelerators_body);
@@ -9281,10 +14840,11 @@ mod tests {
             .collect();
         assert_eq!(
             titles.len(),
-            19,
-            "4 general + back + 7 sections + 7 post keys"
+            20,
+            "5 general + back + 7 sections + 7 post keys"
         );
         assert!(titles.contains("Refresh"));
+        assert!(titles.contains("Quit"));
         assert!(titles.contains("Saved"));
         assert!(titles.contains("Search"), "one entry carries both keys");
         assert!(titles.contains("Like"), "the post keys are listed");